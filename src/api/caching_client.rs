@@ -0,0 +1,702 @@
+//! API client decorator that answers from the SQLite cache when it can
+
+use crate::api::client_trait::ClickUpApi;
+use crate::cache::CacheManager;
+use crate::models::{
+    ClickUpSpace, Comment, CommentsCursor, CommentsPage, CreateCommentRequest, CreateTaskRequest,
+    Document, DocumentFilters, Folder, LinkedTask, List, Page, RecurrenceConfig, SprintOverview, Tag, Task,
+    TaskFilters, TaskStatus, TimeEntry, TimeInStatus, UpdateCommentRequest, UpdateSpaceRequest, UpdateTaskRequest,
+    User, View, ViewTasksResponse, Workspace, WorkspaceView,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Wraps another [`ClickUpApi`] and serves cached data in front of it.
+///
+/// Cached:
+/// - folder status workflows, kept until replaced
+/// - task summaries for [`batch_get_tasks`](ClickUpApi::batch_get_tasks),
+///   for `ttl`, refreshed whenever a task is fetched
+/// - the first page of a task's comments, for `ttl`, when the whole
+///   thread fits on that page. Once expired, the cached copy is brought up to
+///   date with only the comments changed since, when the inner client can
+///   filter that way; otherwise the comments are fetched again in full.
+///   Comments deleted elsewhere stay until the copy is next replaced.
+///
+/// Comment writes invalidate the affected task's cached comments. Every other
+/// call goes straight to the inner client. Cache failures are logged and fall
+/// through to the inner client.
+pub struct CachingClient<T: ClickUpApi> {
+    inner: T,
+    cache: Mutex<CacheManager>,
+    ttl: Duration,
+}
+
+impl<T: ClickUpApi> CachingClient<T> {
+    pub fn new(inner: T, cache: CacheManager, ttl: Duration) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(cache),
+            ttl,
+        }
+    }
+
+    /// The wrapped client
+    #[allow(dead_code)]
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Run `f` against the cache, logging and swallowing its errors
+    ///
+    /// The lock is never held across an await.
+    fn with_cache<R>(
+        &self,
+        what: &str,
+        f: impl FnOnce(&mut CacheManager) -> Result<R>,
+    ) -> Option<R> {
+        let mut cache = match self.cache.lock() {
+            Ok(cache) => cache,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        match f(&mut cache) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                tracing::warn!("Cache {} failed: {:#}", what, e);
+                None
+            }
+        }
+    }
+
+    /// Cached top-level comments of a task, if fresh
+    fn cached_comments(&self, task_id: &str) -> Option<Vec<Comment>> {
+        let ttl_secs = self.ttl.as_secs() as i64;
+        self.with_cache("comment lookup", |cache| {
+            if !cache.is_cache_valid(task_id, ttl_secs)? {
+                return Ok(None);
+            }
+            let top_level = cache
+                .get_comments(task_id)?
+                .into_iter()
+                .filter(|c| c.parent_id.is_none())
+                .collect();
+            Ok(Some(top_level))
+        })
+        .flatten()
+    }
+
+    /// Top-level comments of a task, from its expired cached copy merged
+    /// with what changed since; None when there's no copy or the inner
+    /// client can't say what changed
+    async fn synced_comments(&self, task_id: &str) -> Option<Vec<Comment>> {
+        let since = self
+            .with_cache("comment sync lookup", |cache| {
+                cache.comments_synced_through(task_id)
+            })
+            .flatten()?;
+        let changed = match self
+            .inner
+            .get_task_comments_updated_since(task_id, since)
+            .await
+        {
+            Ok(changed) => changed?,
+            Err(e) => {
+                tracing::warn!("Comment sync failed, fetching in full: {:#}", e);
+                return None;
+            }
+        };
+        self.with_cache("comment merge", |cache| {
+            cache.merge_comments(task_id, &changed)?;
+            Ok(cache
+                .get_comments(task_id)?
+                .into_iter()
+                .filter(|c| c.parent_id.is_none())
+                .collect())
+        })
+    }
+}
+
+#[async_trait]
+impl<T: ClickUpApi> ClickUpApi for CachingClient<T> {
+    async fn get_workspaces(&self) -> Result<Vec<Workspace>> {
+        self.inner.get_workspaces().await
+    }
+
+    async fn get_current_user(&self) -> Result<User> {
+        self.inner.get_current_user().await
+    }
+
+    async fn get_spaces(&self, team_id: &str) -> Result<Vec<ClickUpSpace>> {
+        self.inner.get_spaces(team_id).await
+    }
+
+    async fn get_space(&self, space_id: &str) -> Result<ClickUpSpace> {
+        self.inner.get_space(space_id).await
+    }
+
+    async fn create_space(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<ClickUpSpace> {
+        self.inner.create_space(workspace_id, name, private).await
+    }
+
+    async fn update_space(
+        &self,
+        space_id: &str,
+        update: &UpdateSpaceRequest,
+    ) -> Result<ClickUpSpace> {
+        self.inner.update_space(space_id, update).await
+    }
+
+    async fn create_space_tag(
+        &self,
+        space_id: &str,
+        name: &str,
+        bg_color: &str,
+        fg_color: &str,
+    ) -> Result<Tag> {
+        self.inner
+            .create_space_tag(space_id, name, bg_color, fg_color)
+            .await
+    }
+
+    async fn delete_space_tag(&self, space_id: &str, tag_name: &str) -> Result<()> {
+        self.inner.delete_space_tag(space_id, tag_name).await
+    }
+
+    async fn tag_task(&self, task_id: &str, tag_name: &str) -> Result<()> {
+        self.inner.tag_task(task_id, tag_name).await
+    }
+
+    async fn add_task_link(&self, task_id: &str, links_to_task_id: &str) -> Result<LinkedTask> {
+        self.inner.add_task_link(task_id, links_to_task_id).await
+    }
+
+    async fn remove_task_link(&self, task_id: &str, link_id: &str) -> Result<()> {
+        self.inner.remove_task_link(task_id, link_id).await
+    }
+
+    async fn get_folders(&self, space_id: &str) -> Result<Vec<Folder>> {
+        self.inner.get_folders(space_id).await
+    }
+
+    async fn create_folder(&self, space_id: &str, name: &str) -> Result<Folder> {
+        self.inner.create_folder(space_id, name).await
+    }
+
+    async fn get_lists_in_folder(
+        &self,
+        folder_id: &str,
+        archived: Option<bool>,
+    ) -> Result<Vec<List>> {
+        self.inner.get_lists_in_folder(folder_id, archived).await
+    }
+
+    async fn get_lists_in_space(
+        &self,
+        space_id: &str,
+        archived: Option<bool>,
+    ) -> Result<Vec<List>> {
+        self.inner.get_lists_in_space(space_id, archived).await
+    }
+
+    async fn get_list(&self, list_id: &str) -> Result<List> {
+        self.inner.get_list(list_id).await
+    }
+
+    async fn get_tasks(&self, list_id: &str, filters: &TaskFilters) -> Result<Vec<Task>> {
+        self.inner.get_tasks(list_id, filters).await
+    }
+
+    fn stream_tasks(&self, list_id: &str, filters: &TaskFilters) -> BoxStream<'_, Result<Task>> {
+        self.inner.stream_tasks(list_id, filters)
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Task> {
+        self.inner.get_task(task_id).await
+    }
+
+    async fn get_task_by_custom_id(&self, custom_id: &str, team_id: &str) -> Result<Task> {
+        self.inner.get_task_by_custom_id(custom_id, team_id).await
+    }
+
+    /// Tasks cached within `ttl` come back as the summary the cache keeps;
+    /// the rest are fetched and cached. Tasks ClickUp no longer has are
+    /// left out.
+    async fn batch_get_tasks(&self, task_ids: &[String]) -> Result<Vec<Task>> {
+        let ttl_secs = self.ttl.as_secs() as i64;
+        let cached: Vec<Option<Task>> = task_ids
+            .iter()
+            .map(|id| {
+                self.with_cache("task lookup", |cache| cache.get_task(id, ttl_secs))
+                    .flatten()
+            })
+            .collect();
+        let missing: Vec<String> = task_ids
+            .iter()
+            .zip(&cached)
+            .filter(|(_, task)| task.is_none())
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        let fetched = self.inner.batch_get_tasks(&missing).await?;
+        for task in &fetched {
+            self.with_cache("task store", |cache| cache.cache_task(task));
+        }
+        let mut fetched: HashMap<String, Task> =
+            fetched.into_iter().map(|task| (task.id.clone(), task)).collect();
+        Ok(task_ids
+            .iter()
+            .zip(cached)
+            .filter_map(|(id, task)| task.or_else(|| fetched.remove(id)))
+            .collect())
+    }
+
+    async fn create_task(&self, list_id: &str, task: &CreateTaskRequest) -> Result<Task> {
+        self.inner.create_task(list_id, task).await
+    }
+
+    async fn update_task(&self, task_id: &str, task: &UpdateTaskRequest) -> Result<Task> {
+        self.inner.update_task(task_id, task).await
+    }
+
+    async fn set_task_recurrence(&self, task_id: &str, config: &RecurrenceConfig) -> Result<Task> {
+        self.inner.set_task_recurrence(task_id, config).await
+    }
+
+    async fn delete_task(&self, task_id: &str) -> Result<()> {
+        self.inner.delete_task(task_id).await
+    }
+
+    async fn get_list_members(&self, list_id: &str) -> Result<Vec<User>> {
+        self.inner.get_list_members(list_id).await
+    }
+
+    async fn get_list_statuses(&self, list_id: &str) -> Result<Vec<TaskStatus>> {
+        self.inner.get_list_statuses(list_id).await
+    }
+
+    async fn get_folder_statuses(&self, folder_id: &str) -> Result<Vec<TaskStatus>> {
+        let cached = self
+            .with_cache("folder status lookup", |cache| {
+                cache.get_folder_statuses(folder_id)
+            })
+            .flatten();
+        if let Some(statuses) = cached {
+            return Ok(statuses);
+        }
+        let statuses = self.inner.get_folder_statuses(folder_id).await?;
+        self.with_cache("folder status store", |cache| {
+            cache.cache_folder_statuses(folder_id, &statuses)
+        });
+        Ok(statuses)
+    }
+
+    async fn search_docs(&self, filters: &DocumentFilters) -> Result<Vec<Document>> {
+        self.inner.search_docs(filters).await
+    }
+
+    async fn get_document(&self, doc_id: &str) -> Result<Document> {
+        self.inner.get_document(doc_id).await
+    }
+
+    async fn get_document_page(&self, doc_id: &str, page_id: &str) -> Result<Page> {
+        self.inner.get_document_page(doc_id, page_id).await
+    }
+
+    async fn get_doc_pages(&self, doc_id: &str) -> Result<Vec<Page>> {
+        self.inner.get_doc_pages(doc_id).await
+    }
+
+    async fn get_page(&self, page_id: &str) -> Result<Page> {
+        self.inner.get_page(page_id).await
+    }
+
+    async fn get_task_comments(
+        &self,
+        task_id: &str,
+        start: Option<CommentsCursor>,
+        limit: Option<u32>,
+    ) -> Result<CommentsPage> {
+        // Only the first page is cached; later pages always come from the API
+        if start.is_some() {
+            return self.inner.get_task_comments(task_id, start, limit).await;
+        }
+        if let Some(comments) = self.cached_comments(task_id) {
+            return Ok(CommentsPage::from_batch(comments, limit));
+        }
+        if let Some(comments) = self.synced_comments(task_id).await {
+            return Ok(CommentsPage::from_batch(comments, limit));
+        }
+        let page = self.inner.get_task_comments(task_id, None, limit).await?;
+        // A partial thread would hide the later pages on the next hit
+        if page.next_cursor.is_none() {
+            self.with_cache("comment store", |cache| {
+                cache.cache_comments(task_id, &page.comments)
+            });
+        }
+        Ok(page)
+    }
+
+    async fn get_task_comments_updated_since(
+        &self,
+        task_id: &str,
+        since: i64,
+    ) -> Result<Option<Vec<Comment>>> {
+        self.inner.get_task_comments_updated_since(task_id, since).await
+    }
+
+    async fn get_comment_replies(&self, comment_id: &str) -> Result<Vec<Comment>> {
+        self.inner.get_comment_replies(comment_id).await
+    }
+
+    async fn get_comment(&self, comment_id: &str) -> Result<Comment> {
+        self.inner.get_comment(comment_id).await
+    }
+
+    async fn create_comment(
+        &self,
+        task_id: &str,
+        comment: &CreateCommentRequest,
+    ) -> Result<Comment> {
+        let created = self.inner.create_comment(task_id, comment).await?;
+        self.with_cache("comment invalidation", |cache| {
+            cache.clear_comments(task_id)
+        });
+        Ok(created)
+    }
+
+    async fn create_comment_reply(
+        &self,
+        parent_comment_id: &str,
+        comment: &CreateCommentRequest,
+    ) -> Result<Comment> {
+        let created = self
+            .inner
+            .create_comment_reply(parent_comment_id, comment)
+            .await?;
+        // The parent's reply count changed
+        self.with_cache("comment invalidation", |cache| {
+            cache.clear_comments_containing(parent_comment_id)
+        });
+        Ok(created)
+    }
+
+    async fn update_comment(
+        &self,
+        comment_id: &str,
+        comment: &UpdateCommentRequest,
+    ) -> Result<Comment> {
+        let updated = self.inner.update_comment(comment_id, comment).await?;
+        self.with_cache("comment invalidation", |cache| {
+            cache.clear_comments_containing(comment_id)
+        });
+        Ok(updated)
+    }
+
+    async fn delete_comment(&self, comment_id: &str) -> Result<()> {
+        self.inner.delete_comment(comment_id).await?;
+        self.with_cache("comment invalidation", |cache| {
+            cache.mark_comment_deleted(comment_id)
+        });
+        Ok(())
+    }
+
+    async fn send_request(&self, method: &str, url: &str, body_json: Option<&str>) -> Result<()> {
+        self.inner.send_request(method, url, body_json).await
+    }
+
+    async fn get_list_views(&self, list_id: &str) -> Result<Vec<View>> {
+        self.inner.get_list_views(list_id).await
+    }
+
+    async fn get_workspace_views(&self, workspace_id: &str) -> Result<Vec<WorkspaceView>> {
+        self.inner.get_workspace_views(workspace_id).await
+    }
+
+    async fn get_view_tasks_page(&self, view_id: &str, page: u32) -> Result<ViewTasksResponse> {
+        self.inner.get_view_tasks_page(view_id, page).await
+    }
+
+    async fn get_sprint_overview(&self, view_id: &str) -> Result<SprintOverview> {
+        self.inner.get_sprint_overview(view_id).await
+    }
+
+    async fn get_time_entries(&self, task_id: &str) -> Result<Vec<TimeEntry>> {
+        self.inner.get_time_entries(task_id).await
+    }
+
+    async fn get_task_time_in_status(&self, task_id: &str) -> Result<Vec<TimeInStatus>> {
+        self.inner.get_task_time_in_status(task_id).await
+    }
+
+    async fn get_time_entries_for_user(
+        &self,
+        workspace_id: &str,
+        from: i64,
+        to: i64,
+        user_id: Option<&str>,
+    ) -> Result<Vec<TimeEntry>> {
+        self.inner
+            .get_time_entries_for_user(workspace_id, from, to, user_id)
+            .await
+    }
+
+    async fn get_tasks_with_assignee(
+        &self,
+        list_id: &str,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<Task>> {
+        self.inner
+            .get_tasks_with_assignee(list_id, user_id, limit)
+            .await
+    }
+
+    async fn get_my_tasks(&self, workspace_id: &str, filters: &TaskFilters) -> Result<Vec<Task>> {
+        self.inner.get_my_tasks(workspace_id, filters).await
+    }
+
+    async fn get_tasks_for_workspace(
+        &self,
+        workspace_id: &str,
+        filters: &TaskFilters,
+    ) -> Result<Vec<Task>> {
+        self.inner
+            .get_tasks_for_workspace(workspace_id, filters)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock_client::MockClickUpClient;
+
+    fn comment(id: &str) -> Comment {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "comment_text": format!("Comment {}", id),
+            "date": "1700000000000",
+            "reply_count": 2
+        }))
+        .unwrap()
+    }
+
+    fn status(name: &str) -> TaskStatus {
+        serde_json::from_value(serde_json::json!({ "status": name })).unwrap()
+    }
+
+    fn caching(mock: MockClickUpClient) -> (CachingClient<MockClickUpClient>, tempfile::TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = CacheManager::new(dir.path().join("cache.db")).unwrap();
+        let client = CachingClient::new(mock, cache, Duration::from_secs(300));
+        (client, dir)
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_tasks_fetches_only_uncached() {
+        let task = |id: &str| Task {
+            id: id.to_string(),
+            name: format!("Task {}", id),
+            list: Some(crate::models::ListReference {
+                id: "list-1".to_string(),
+                name: None,
+                access: None,
+            }),
+            ..Default::default()
+        };
+        let (client, _dir) = caching(
+            MockClickUpClient::new()
+                .with_task_by_id(task("t1"))
+                .with_task_by_id(task("t2")),
+        );
+        let ids = vec!["t1".to_string(), "t2".to_string()];
+
+        let first = client.batch_get_tasks(&ids).await.unwrap();
+        assert_eq!(
+            first.iter().map(|t| &t.id).collect::<Vec<_>>(),
+            ["t1", "t2"]
+        );
+        assert_eq!(client.inner().requested_task_ids(), ["t1", "t2"]);
+
+        let second = client.batch_get_tasks(&ids).await.unwrap();
+        assert_eq!(second[1].name, "Task t2");
+        assert_eq!(client.inner().requested_task_ids().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_tasks_refetches_expired_and_skips_deleted() {
+        let task = |id: &str, name: &str| Task {
+            id: id.to_string(),
+            name: name.to_string(),
+            list: Some(crate::models::ListReference {
+                id: "list-1".to_string(),
+                name: None,
+                access: None,
+            }),
+            ..Default::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = CacheManager::new(dir.path().join("cache.db")).unwrap();
+        cache.cache_task(&task("t1", "Old name")).unwrap();
+        let client = CachingClient::new(
+            MockClickUpClient::new()
+                .with_task_by_id(task("t1", "New name"))
+                .with_task_error("API error (404 Not Found): Task not found".to_string()),
+            cache,
+            Duration::ZERO,
+        );
+        let ids = vec!["gone".to_string(), "t1".to_string()];
+
+        let tasks = client.batch_get_tasks(&ids).await.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].name, "New name");
+        assert_eq!(client.inner().requested_task_ids(), ["gone", "t1"]);
+    }
+
+    #[tokio::test]
+    async fn test_uncached_calls_delegate() {
+        let (client, _dir) = caching(MockClickUpClient::new().with_tasks(vec![Task {
+            id: "task-1".to_string(),
+            ..Default::default()
+        }]));
+
+        let tasks = client
+            .get_tasks("list-1", &TaskFilters::default())
+            .await
+            .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(client.inner().requested_task_pages(), vec![0]);
+    }
+
+    #[tokio::test]
+    async fn test_comments_served_from_cache_until_invalidated() {
+        let (client, _dir) = caching(
+            MockClickUpClient::new()
+                .with_task_comments(vec![comment("1"), comment("2")])
+                .with_update_comment_response(comment("1")),
+        );
+
+        let first = client
+            .get_task_comments("task-1", None, None)
+            .await
+            .unwrap();
+        let second = client
+            .get_task_comments("task-1", None, None)
+            .await
+            .unwrap();
+        assert_eq!(first.comments.len(), 2);
+        assert_eq!(second.comments.len(), 2);
+        // Cached comments keep their reply counts
+        assert_eq!(second.comments[0].reply_count, Some(2));
+        assert_eq!(client.inner().requested_comment_pages(), vec![None]);
+
+        // Editing a comment drops the task's cached comments
+        client
+            .update_comment(
+                "1",
+                &UpdateCommentRequest {
+                    comment_text: Some("Edited".to_string()),
+                    assigned: None,
+                    assignee: None,
+                    assigned_commenter: None,
+                },
+            )
+            .await
+            .unwrap();
+        client
+            .get_task_comments("task-1", None, None)
+            .await
+            .unwrap();
+        assert_eq!(client.inner().requested_comment_pages(), vec![None, None]);
+    }
+
+    #[tokio::test]
+    async fn test_expired_comments_merge_only_what_changed() {
+        let mut edited = comment("1");
+        edited.text = "Edited".to_string();
+        edited.updated_at = Some(1_700_000_005_000);
+        let mut added = comment("3");
+        added.created_at = Some(1_700_000_009_000);
+        let dir = tempfile::tempdir().unwrap();
+        let cache = CacheManager::new(dir.path().join("cache.db")).unwrap();
+        // Zero TTL: the cached copy is always expired
+        let client = CachingClient::new(
+            MockClickUpClient::new()
+                .with_task_comments(vec![comment("1"), comment("2")])
+                .with_comments_updated_since(vec![edited, added]),
+            cache,
+            Duration::ZERO,
+        );
+
+        client
+            .get_task_comments("task-1", None, None)
+            .await
+            .unwrap();
+        let synced = client
+            .get_task_comments("task-1", None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(client.inner().requested_comment_pages(), vec![None]);
+        assert_eq!(
+            client.inner().recorded_calls(),
+            ["get_task_comments_updated_since task-1 1700000000000"]
+        );
+        let texts: Vec<&str> = synced.comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts.len(), 3);
+        assert_eq!(texts[0], "Comment 3");
+        assert!(texts.contains(&"Edited"));
+        assert!(texts.contains(&"Comment 2"));
+    }
+
+    #[tokio::test]
+    async fn test_expired_comments_fetched_in_full_without_filtering() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = CacheManager::new(dir.path().join("cache.db")).unwrap();
+        let client = CachingClient::new(
+            MockClickUpClient::new().with_task_comments(vec![comment("1")]),
+            cache,
+            Duration::ZERO,
+        );
+
+        for _ in 0..2 {
+            client
+                .get_task_comments("task-1", None, None)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(client.inner().requested_comment_pages(), vec![None, None]);
+    }
+
+    #[tokio::test]
+    async fn test_folder_statuses_short_circuit_after_first_fetch() {
+        let (client, _dir) = caching(
+            MockClickUpClient::new().with_folder_statuses(vec![status("intake"), status("done")]),
+        );
+
+        let first = client.get_folder_statuses("folder-1").await.unwrap();
+        let second = client.get_folder_statuses("folder-1").await.unwrap();
+        assert_eq!(first, second);
+        assert_eq!(
+            client.inner().recorded_calls(),
+            vec!["get_folder_statuses folder-1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_boxed_shares_as_trait_object() {
+        let (client, _dir) = caching(MockClickUpClient::new());
+        let shared: std::sync::Arc<dyn ClickUpApi> = client.boxed();
+        let _also_shared = shared.clone();
+    }
+}
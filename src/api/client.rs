@@ -7,24 +7,42 @@
 //! a browser-based authorization flow.
 
 use crate::api::client_trait::ClickUpApi;
-use crate::api::endpoints::ApiEndpoints;
+use crate::api::endpoints::{ApiEndpoints, BASE_URL};
+use crate::api::request_queue::{is_offline_error, is_outcome_unknown, QueuedOffline};
+use crate::api::task_stream::{read_tasks, ChunkReader};
+use crate::cache::CacheManager;
 use crate::models::TaskFilters;
 use crate::models::{
-    ClickUpSpace as Space, Comment, CommentsResponse, CreateCommentRequest, CreateTaskRequest,
-    Document, DocumentFilters, DocumentPagesResponse, DocumentsResponse, Folder, FoldersResponse,
-    List, ListsResponse, MembersResponse, Page, PageResponse,
-    SpacesResponse, Task, TasksResponse, UpdateCommentRequest, UpdateTaskRequest, User,
-    UserResponse, Workspace, WorkspacesResponse,
+    ClickUpSpace as Space, Comment, CommentsCursor, CommentsPage, CommentsResponse, CreateCommentRequest, CreateTaskRequest,
+    Document, DocumentFilters, DocumentPagesResponse, DocumentResponse, DocumentsResponse, Folder, FoldersResponse,
+    LinkedTask, List, ListsResponse, MembersResponse, Page, PageResponse, RecurrenceConfig,
+    SpacesResponse, SprintDates, SprintOverview, Tag, Task, TaskLinkResponse, TaskStatus, TaskTimeResponse, TasksResponse, TimeEntriesResponse, TimeEntry, TimeInStatus, TimeInStatusResponse, UpdateCommentRequest, UpdateSpaceRequest, UpdateTaskRequest, User,
+    UserResponse, View, ListViewsResponse, ViewResponse, ViewTasksResponse, Workspace, WorkspaceView,
+    WorkspaceViewsResponse, WorkspacesResponse, VIEW_PARENT_LIST,
 };
+use crate::utils::deserializers::from_json_str;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
 use reqwest::{Client, Response};
 use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+/// Tasks parsed ahead of the consumer of [`ClickUpClient::stream_tasks`]
+const TASKS_STREAM_BUFFER: usize = 32;
 
 /// ClickUp API client
 pub struct ClickUpClient {
     client: Client,
     token: String,
+    /// Where to save response bodies that fail to parse, if anywhere
+    dump_dir: Option<PathBuf>,
+    /// ID of the authenticated user, cached from the first `get_current_user`
+    current_user_id: std::sync::Mutex<Option<i64>>,
+    /// Where task and comment writes go when ClickUp can't be reached
+    request_queue: Option<std::sync::Mutex<CacheManager>>,
+    /// Negotiate markdown task descriptions instead of plain text
+    prefer_markdown: bool,
 }
 
 impl ClickUpClient {
@@ -35,7 +53,64 @@ impl ClickUpClient {
             .build()
             .expect("Failed to create HTTP client");
 
-        Self { client, token }
+        Self {
+            client,
+            token,
+            dump_dir: None,
+            current_user_id: std::sync::Mutex::new(None),
+            request_queue: None,
+            prefer_markdown: false,
+        }
+    }
+
+    /// Ask for task descriptions as markdown (`markdown_description`)
+    pub fn with_markdown(mut self, prefer_markdown: bool) -> Self {
+        self.prefer_markdown = prefer_markdown;
+        self
+    }
+
+    /// Save the raw body of any response that fails to parse into `dir`
+    pub fn with_response_dumps(mut self, dir: PathBuf) -> Self {
+        self.dump_dir = Some(dir);
+        self
+    }
+
+    /// Queue task and comment writes made while offline in `cache`
+    pub fn with_request_queue(mut self, cache: CacheManager) -> Self {
+        self.request_queue = Some(std::sync::Mutex::new(cache));
+        self
+    }
+
+    /// Queue a write that failed because ClickUp couldn't be reached
+    ///
+    /// The write is then reported as [`QueuedOffline`]. Other results, and
+    /// every result without a queue, are returned as they are. A timed out
+    /// POST isn't queued: ClickUp may have created the item, and sending it
+    /// again would create a second one.
+    fn queue_if_offline<T, B: serde::Serialize + ?Sized>(
+        &self,
+        result: Result<T>,
+        method: reqwest::Method,
+        url: &str,
+        body: Option<&B>,
+    ) -> Result<T> {
+        let (Err(e), Some(queue)) = (&result, &self.request_queue) else {
+            return result;
+        };
+        if !is_offline_error(e) || (method == reqwest::Method::POST && is_outcome_unknown(e)) {
+            return result;
+        }
+        let body_json = body.map(serde_json::to_string).transpose()?;
+        let mut queue = queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        queue
+            .queue_request(method.as_str(), url, body_json.as_deref())
+            .context("Failed to queue request for later")?;
+        tracing::info!("Offline, queued {} {}", method, url);
+        Err(QueuedOffline {
+            method: method.to_string(),
+            url: url.to_string(),
+        }
+        .into())
     }
 
     /// Build a request with authentication headers
@@ -43,7 +118,16 @@ impl ClickUpClient {
         self.client
             .request(method, &url)
             .header("Authorization", &self.token)
-            .header("Accept", "application/json")
+            .header("Accept", self.accept_header())
+    }
+
+    /// Accept header, asking for markdown content when preferred
+    fn accept_header(&self) -> &'static str {
+        if self.prefer_markdown {
+            "application/json; markdown=true"
+        } else {
+            "application/json"
+        }
     }
 
     /// Execute a request and parse the response
@@ -70,13 +154,7 @@ impl ClickUpClient {
         // Log the raw response for debugging (but not for comments to avoid logging sensitive data)
         tracing::debug!("API response body: {}", body);
 
-        // Parse the JSON with enhanced error reporting
-        // Use serde_path_to_error to get field-level diagnostics
-        let mut deserializer = serde_json::Deserializer::from_str(&body);
-        serde_path_to_error::deserialize(&mut deserializer).context(format!(
-            "Failed to parse response: {}",
-            body.chars().take(200).collect::<String>()
-        ))
+        parse_body(&body, self.dump_dir.as_deref())
     }
 
     // ==================== Workspace/Team ====================
@@ -98,9 +176,19 @@ impl ClickUpClient {
         let response = self
             .execute::<UserResponse>(self.request(reqwest::Method::GET, url))
             .await?;
+        *self.current_user_id.lock().unwrap() = Some(response.user.id);
         Ok(response.user)
     }
 
+    /// ID of the authenticated user, fetched once and then cached
+    async fn current_user_id(&self) -> Result<i64> {
+        let cached = *self.current_user_id.lock().unwrap();
+        match cached {
+            Some(id) => Ok(id),
+            None => Ok(self.get_current_user().await?.id),
+        }
+    }
+
     // ==================== Spaces ====================
 
     /// Get all spaces in a team/workspace
@@ -120,6 +208,75 @@ impl ClickUpClient {
             .await
     }
 
+    /// Create a space in a workspace
+    pub async fn create_space(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<Space> {
+        let url = ApiEndpoints::spaces(workspace_id);
+        let body = serde_json::json!({
+            "name": name,
+            "multiple_assignees": true,
+            "private": private,
+        });
+        self.execute::<Space>(self.request(reqwest::Method::POST, url).json(&body))
+            .await
+    }
+
+    /// Change a space's settings
+    ///
+    /// ClickUp expects every setting in the request, so those not being
+    /// changed are sent with the space's current values.
+    pub async fn update_space(&self, space_id: &str, update: &UpdateSpaceRequest) -> Result<Space> {
+        let current = self.get_space(space_id).await?;
+        let body = serde_json::json!({
+            "name": update.name.as_ref().unwrap_or(&current.name),
+            "color": update.color.as_ref().or(current.color.as_ref()),
+            "private": update.private.unwrap_or(current.private),
+        });
+        let url = ApiEndpoints::space(space_id);
+        self.execute::<Space>(self.request(reqwest::Method::PUT, url).json(&body))
+            .await
+    }
+
+    /// Create a tag in a space
+    ///
+    /// ClickUp answers with an empty object, so the tag is built from what
+    /// was sent.
+    pub async fn create_space_tag(
+        &self,
+        space_id: &str,
+        name: &str,
+        bg_color: &str,
+        fg_color: &str,
+    ) -> Result<Tag> {
+        let url = ApiEndpoints::space_tags(space_id);
+        let body = serde_json::json!({
+            "tag": { "name": name, "tag_bg": bg_color, "tag_fg": fg_color }
+        });
+        self.execute::<serde_json::Value>(self.request(reqwest::Method::POST, url).json(&body))
+            .await?;
+        Ok(Tag {
+            id: None,
+            name: name.to_string(),
+            color: None,
+            tag_fg: Some(fg_color.to_string()),
+            tag_bg: Some(bg_color.to_string()),
+            creator: None,
+        })
+    }
+
+    /// Delete a tag from a space
+    #[allow(dead_code)]
+    pub async fn delete_space_tag(&self, space_id: &str, tag_name: &str) -> Result<()> {
+        let url = ApiEndpoints::space_tag(space_id, tag_name);
+        self.execute::<serde_json::Value>(self.request(reqwest::Method::DELETE, url))
+            .await?;
+        Ok(())
+    }
+
     // ==================== Folders ====================
 
     /// Get all folders in a space
@@ -131,6 +288,14 @@ impl ClickUpClient {
         Ok(response.folders)
     }
 
+    /// Create a folder in a space
+    pub async fn create_folder(&self, space_id: &str, name: &str) -> Result<Folder> {
+        let url = ApiEndpoints::folders(space_id);
+        let body = serde_json::json!({ "name": name });
+        self.execute::<Folder>(self.request(reqwest::Method::POST, url).json(&body))
+            .await
+    }
+
     // ==================== Lists ====================
 
     /// Get all lists in a folder
@@ -165,6 +330,13 @@ impl ClickUpClient {
         Ok(response.lists)
     }
 
+    /// Get a single list
+    pub async fn get_list(&self, list_id: &str) -> Result<List> {
+        let url = ApiEndpoints::list(list_id);
+        self.execute::<List>(self.request(reqwest::Method::GET, url))
+            .await
+    }
+
     // ==================== Tasks ====================
 
     /// Get all tasks in a list
@@ -178,9 +350,85 @@ impl ClickUpClient {
         Ok(response.tasks)
     }
 
+    /// Get a page of tasks in a list, each as soon as it has been read
+    ///
+    /// The request starts right away, and the body is parsed on a blocking
+    /// thread as its chunks arrive. A body that fails to parse ends the
+    /// stream with the error; unlike [`get_tasks`](Self::get_tasks) it is not
+    /// saved for debugging, since it was never held in full.
+    pub fn stream_tasks(&self, list_id: &str, filters: &TaskFilters) -> BoxStream<'static, Result<Task>> {
+        let url = ApiEndpoints::tasks_in_list(list_id, &filters.to_query_string());
+        tracing::debug!("Streaming tasks from list {} with URL: {}", list_id, url);
+        let request = self.request(reqwest::Method::GET, url);
+        let slim = filters.slim;
+        let (tx, rx) = tokio::sync::mpsc::channel::<Result<Task>>(TASKS_STREAM_BUFFER);
+
+        tokio::spawn(async move {
+            let mut response = match request.send().await.context("Request failed") {
+                Ok(response) => response,
+                Err(e) => {
+                    let _ = tx.send(Err(e)).await;
+                    return;
+                }
+            };
+            let status = response.status();
+            if !status.is_success() {
+                let error_text = response.text().await.unwrap_or_default();
+                let _ = tx
+                    .send(Err(anyhow::anyhow!("API error ({}): {}", status, error_text)))
+                    .await;
+                return;
+            }
+
+            let (chunk_tx, chunk_rx) = std::sync::mpsc::channel();
+            let parser = tokio::task::spawn_blocking(move || {
+                let result = read_tasks(ChunkReader::new(chunk_rx), slim, |task| {
+                    tx.blocking_send(Ok(task)).is_ok()
+                });
+                if let Err(e) = result {
+                    let _ = tx.blocking_send(Err(
+                        anyhow::Error::new(e).context("Failed to parse streamed tasks")
+                    ));
+                }
+            });
+            loop {
+                let chunk = match response.chunk().await {
+                    Ok(Some(chunk)) => Ok(chunk.to_vec()),
+                    Ok(None) => break,
+                    Err(e) => Err(std::io::Error::other(e)),
+                };
+                let failed = chunk.is_err();
+                // The parser is gone once the stream is dropped
+                if chunk_tx.send(chunk).is_err() || failed {
+                    break;
+                }
+            }
+            drop(chunk_tx);
+            let _ = parser.await;
+        });
+
+        futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|task| (task, rx))
+        })
+        .boxed()
+    }
+
     /// Get a single task
     pub async fn get_task(&self, task_id: &str) -> Result<Task> {
-        let url = ApiEndpoints::task(task_id);
+        let mut url = ApiEndpoints::task(task_id);
+        if self.prefer_markdown {
+            url.push_str("?include_markdown_description=true");
+        }
+        self.execute::<Task>(self.request(reqwest::Method::GET, url))
+            .await
+    }
+
+    /// Get a task by its custom ID (e.g. `DEV-42`) in workspace `team_id`
+    pub async fn get_task_by_custom_id(&self, custom_id: &str, team_id: &str) -> Result<Task> {
+        let mut url = ApiEndpoints::task_by_custom_id(custom_id, team_id);
+        if self.prefer_markdown {
+            url.push_str("&include_markdown_description=true");
+        }
         self.execute::<Task>(self.request(reqwest::Method::GET, url))
             .await
     }
@@ -189,15 +437,31 @@ impl ClickUpClient {
     #[allow(dead_code)]
     pub async fn create_task(&self, list_id: &str, task: &CreateTaskRequest) -> Result<Task> {
         let url = ApiEndpoints::tasks_in_list(list_id, "");
-        self.execute::<Task>(self.request(reqwest::Method::POST, url).json(task))
-            .await
+        let result = self
+            .execute::<Task>(self.request(reqwest::Method::POST, url.clone()).json(task))
+            .await;
+        self.queue_if_offline(result, reqwest::Method::POST, &url, Some(task))
     }
 
     /// Update a task
     #[allow(dead_code)]
     pub async fn update_task(&self, task_id: &str, task: &UpdateTaskRequest) -> Result<Task> {
         let url = ApiEndpoints::task(task_id);
-        self.execute::<Task>(self.request(reqwest::Method::PUT, url).json(task))
+        let result = self
+            .execute::<Task>(self.request(reqwest::Method::PUT, url.clone()).json(task))
+            .await;
+        self.queue_if_offline(result, reqwest::Method::PUT, &url, Some(task))
+    }
+
+    /// Make a task repeat on the given schedule
+    pub async fn set_task_recurrence(
+        &self,
+        task_id: &str,
+        config: &RecurrenceConfig,
+    ) -> Result<Task> {
+        let url = ApiEndpoints::task(task_id);
+        let body = serde_json::json!({ "recurrence": config });
+        self.execute::<Task>(self.request(reqwest::Method::PUT, url).json(&body))
             .await
     }
 
@@ -205,11 +469,13 @@ impl ClickUpClient {
     #[allow(dead_code)]
     pub async fn delete_task(&self, task_id: &str) -> Result<()> {
         let url = ApiEndpoints::task(task_id);
-        let response = self
-            .request(reqwest::Method::DELETE, url)
+        let sent = self
+            .request(reqwest::Method::DELETE, url.clone())
             .send()
             .await
-            .context("Request failed")?;
+            .context("Request failed");
+        let response =
+            self.queue_if_offline(sent, reqwest::Method::DELETE, &url, None::<&()>)?;
 
         // DELETE endpoints typically return 200/204 with empty or minimal response
         // Just check for HTTP success, don't try to parse the body
@@ -221,6 +487,46 @@ impl ClickUpClient {
         Ok(())
     }
 
+    /// Add an existing space tag to a task
+    pub async fn tag_task(&self, task_id: &str, tag_name: &str) -> Result<()> {
+        let url = ApiEndpoints::task_tag(task_id, tag_name);
+        self.execute::<serde_json::Value>(self.request(reqwest::Method::POST, url))
+            .await?;
+        Ok(())
+    }
+
+    /// Link a task to another, returning the new link
+    pub async fn add_task_link(&self, task_id: &str, links_to_task_id: &str) -> Result<LinkedTask> {
+        let url = ApiEndpoints::task_link(task_id, links_to_task_id);
+        let response = self
+            .execute::<TaskLinkResponse>(self.request(reqwest::Method::POST, url))
+            .await?;
+        // ClickUp returns the whole task; pick out the link just made
+        let link = response
+            .task
+            .linked_tasks
+            .into_iter()
+            .find(|l| l.other_task_id(task_id) == Some(links_to_task_id));
+        Ok(link.unwrap_or_else(|| LinkedTask {
+            id: None,
+            task_id: Some(task_id.to_string()),
+            link_id: Some(links_to_task_id.to_string()),
+            name: None,
+            status: None,
+            date_created: None,
+            userid: None,
+            workspace_id: None,
+        }))
+    }
+
+    /// Remove a task's link to `link_id`, the task at the other end
+    pub async fn remove_task_link(&self, task_id: &str, link_id: &str) -> Result<()> {
+        let url = ApiEndpoints::task_link(task_id, link_id);
+        self.execute::<serde_json::Value>(self.request(reqwest::Method::DELETE, url))
+            .await?;
+        Ok(())
+    }
+
     // ==================== Members ====================
 
     /// Get all members who can access a list
@@ -233,6 +539,23 @@ impl ClickUpClient {
         Ok(response.members)
     }
 
+    /// Get the status workflow of a list, in workflow order
+    pub async fn get_list_statuses(&self, list_id: &str) -> Result<Vec<TaskStatus>> {
+        let mut statuses = self.get_list(list_id).await?.statuses;
+        statuses.sort_by_key(|s| s.orderindex.unwrap_or(u32::MAX));
+        Ok(statuses)
+    }
+
+    /// Get a folder's own status workflow, in workflow order
+    pub async fn get_folder_statuses(&self, folder_id: &str) -> Result<Vec<TaskStatus>> {
+        let url = ApiEndpoints::folder(folder_id);
+        let mut folder = self
+            .execute::<Folder>(self.request(reqwest::Method::GET, url))
+            .await?;
+        folder.statuses.sort_by_key(|s| s.orderindex.unwrap_or(u32::MAX));
+        Ok(folder.statuses)
+    }
+
     // ==================== Documents ====================
 
     /// Search documents
@@ -245,6 +568,28 @@ impl ClickUpClient {
         Ok(response.docs)
     }
 
+    /// Get a document by ID, with its pages
+    pub async fn get_document(&self, doc_id: &str) -> Result<Document> {
+        let url = ApiEndpoints::doc(doc_id);
+        let response = self
+            .execute::<DocumentResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        let mut doc = response.doc;
+        if doc.pages.is_empty() {
+            doc.pages = self.get_doc_pages(doc_id).await?;
+        }
+        Ok(doc)
+    }
+
+    /// Get one page of a document
+    pub async fn get_document_page(&self, doc_id: &str, page_id: &str) -> Result<Page> {
+        let url = ApiEndpoints::doc_page(doc_id, page_id);
+        let response = self
+            .execute::<PageResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.page)
+    }
+
     /// Get all pages in a document
     #[allow(dead_code)]
     pub async fn get_doc_pages(&self, doc_id: &str) -> Result<Vec<Page>> {
@@ -267,13 +612,23 @@ impl ClickUpClient {
 
     // ==================== Comments ====================
 
-    /// Get all comments for a task (top-level only)
-    pub async fn get_task_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
-        let url = ApiEndpoints::task_comments(task_id);
+    /// Get a page of a task's top-level comments, newest first, continuing
+    /// after the `start` cursor (ClickUp's `start`/`start_id` parameters)
+    pub async fn get_task_comments(
+        &self,
+        task_id: &str,
+        start: Option<CommentsCursor>,
+        limit: Option<u32>,
+    ) -> Result<CommentsPage> {
+        let mut url = ApiEndpoints::task_comments(task_id);
+        if let Some(start) = start {
+            url.push('?');
+            url.push_str(&start.query());
+        }
         let response = self
             .execute::<CommentsResponse>(self.request(reqwest::Method::GET, url))
             .await?;
-        Ok(response.comments)
+        Ok(CommentsPage::from_batch(response.comments, limit))
     }
 
     /// Get replies to a specific comment (threaded comments)
@@ -285,6 +640,13 @@ impl ClickUpClient {
         Ok(response.comments)
     }
 
+    /// Get a single comment
+    pub async fn get_comment(&self, comment_id: &str) -> Result<Comment> {
+        let url = ApiEndpoints::comment(comment_id);
+        self.execute::<Comment>(self.request(reqwest::Method::GET, url))
+            .await
+    }
+
     /// Create a new comment on a task (top-level)
     pub async fn create_comment(
         &self,
@@ -292,10 +654,10 @@ impl ClickUpClient {
         comment: &CreateCommentRequest,
     ) -> Result<Comment> {
         let url = ApiEndpoints::task_comments(task_id);
-        let response = self
-            .execute::<Comment>(self.request(reqwest::Method::POST, url).json(comment))
-            .await?;
-        Ok(response)
+        let result = self
+            .execute::<Comment>(self.request(reqwest::Method::POST, url.clone()).json(comment))
+            .await;
+        self.queue_if_offline(result, reqwest::Method::POST, &url, Some(comment))
     }
 
     /// Create a reply to an existing comment (threaded)
@@ -305,10 +667,10 @@ impl ClickUpClient {
         comment: &CreateCommentRequest,
     ) -> Result<Comment> {
         let url = ApiEndpoints::comment_replies(parent_comment_id);
-        let response = self
-            .execute::<Comment>(self.request(reqwest::Method::POST, url).json(comment))
-            .await?;
-        Ok(response)
+        let result = self
+            .execute::<Comment>(self.request(reqwest::Method::POST, url.clone()).json(comment))
+            .await;
+        self.queue_if_offline(result, reqwest::Method::POST, &url, Some(comment))
     }
 
     /// Update a comment
@@ -318,10 +680,154 @@ impl ClickUpClient {
         comment: &UpdateCommentRequest,
     ) -> Result<Comment> {
         let url = ApiEndpoints::comment(comment_id);
+        let result = self
+            .execute::<Comment>(self.request(reqwest::Method::PUT, url.clone()).json(comment))
+            .await;
+        self.queue_if_offline(result, reqwest::Method::PUT, &url, Some(comment))
+    }
+
+    /// Delete a comment
+    pub async fn delete_comment(&self, comment_id: &str) -> Result<()> {
+        let url = ApiEndpoints::comment(comment_id);
+        let response = self
+            .request(reqwest::Method::DELETE, url)
+            .send()
+            .await
+            .context("Request failed")?;
+
+        // Like task deletion, only the HTTP status matters
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("API error ({}): {}", status, error_text);
+        }
+        Ok(())
+    }
+
+    // ==================== Request Queue ====================
+
+    /// Send a queued request as it was saved, checking only the HTTP status
+    ///
+    /// Only ClickUp API URLs are sent, so a tampered queue can't send the
+    /// token elsewhere.
+    pub async fn send_request(&self, method: &str, url: &str, body_json: Option<&str>) -> Result<()> {
+        anyhow::ensure!(
+            url.starts_with(&format!("{}/", BASE_URL)),
+            "Refusing to send a queued request outside the ClickUp API: {}",
+            url
+        );
+        let method = reqwest::Method::from_bytes(method.as_bytes())
+            .with_context(|| format!("Invalid HTTP method {}", method))?;
+        let mut request = self.request(method, url.to_string());
+        if let Some(body) = body_json {
+            request = request
+                .header("Content-Type", "application/json")
+                .body(body.to_string());
+        }
+        let response = request.send().await.context("Request failed")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("API error ({}): {}", status, error_text);
+        }
+        Ok(())
+    }
+
+    // ==================== Views ====================
+
+    /// Get the views saved on a list
+    pub async fn get_list_views(&self, list_id: &str) -> Result<Vec<View>> {
+        let url = ApiEndpoints::list_views(list_id);
+        let response = self
+            .execute::<ListViewsResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.views)
+    }
+
+    /// Get the views saved at the workspace level
+    pub async fn get_workspace_views(&self, workspace_id: &str) -> Result<Vec<WorkspaceView>> {
+        let url = ApiEndpoints::workspace_views(workspace_id);
+        let response = self
+            .execute::<WorkspaceViewsResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.views)
+    }
+
+    /// Get one page of the tasks shown in a view
+    pub async fn get_view_tasks_page(&self, view_id: &str, page: u32) -> Result<ViewTasksResponse> {
+        let url = ApiEndpoints::view_tasks(view_id, page);
+        self.execute::<ViewTasksResponse>(self.request(reqwest::Method::GET, url))
+            .await
+    }
+
+    // ==================== Sprints ====================
+
+    /// Get the sprint overview for a sprint view
+    ///
+    /// Fetches the view, the dates of the sprint list it is saved on and all
+    /// of its task pages, then aggregates points, completion and burndown from
+    /// the tasks.
+    pub async fn get_sprint_overview(&self, view_id: &str) -> Result<SprintOverview> {
+        let url = ApiEndpoints::view(view_id);
+        let view = self
+            .execute::<ViewResponse>(self.request(reqwest::Method::GET, url))
+            .await?
+            .view;
+
+        let dates = match &view.parent {
+            Some(parent) if parent.parent_type == Some(VIEW_PARENT_LIST) => {
+                let url = ApiEndpoints::list(&parent.id);
+                self.execute::<SprintDates>(self.request(reqwest::Method::GET, url))
+                    .await?
+            }
+            _ => SprintDates::default(),
+        };
+
+        let tasks = ClickUpApi::get_view_tasks(self, view_id).await?;
+        tracing::debug!("Fetched {} tasks for sprint view {}", tasks.len(), view_id);
+
+        let now = chrono::Utc::now().timestamp_millis();
+        Ok(SprintOverview::from_tasks(&view.name, dates, &tasks, now))
+    }
+
+    // ==================== Time Tracking ====================
+
+    /// Get the time logged on a task, by every user, oldest first
+    pub async fn get_time_entries(&self, task_id: &str) -> Result<Vec<TimeEntry>> {
+        let url = ApiEndpoints::task_time(task_id);
+        let response = self
+            .execute::<TaskTimeResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.into_entries(task_id))
+    }
+
+    /// Get how long a task spent in each status, in workflow order
+    pub async fn get_task_time_in_status(&self, task_id: &str) -> Result<Vec<TimeInStatus>> {
+        let url = ApiEndpoints::task_time_in_status(task_id);
         let response = self
-            .execute::<Comment>(self.request(reqwest::Method::PUT, url).json(comment))
+            .execute::<TimeInStatusResponse>(self.request(reqwest::Method::GET, url))
             .await?;
-        Ok(response)
+        Ok(response.into_durations())
+    }
+
+    /// Get time entries in a workspace that started between `from` and `to` (ms)
+    pub async fn get_time_entries_for_user(
+        &self,
+        workspace_id: &str,
+        from: i64,
+        to: i64,
+        user_id: Option<&str>,
+    ) -> Result<Vec<TimeEntry>> {
+        let mut query = format!("?start_date={}&end_date={}", from, to);
+        if let Some(user_id) = user_id {
+            query.push_str(&format!("&assignee={}", user_id));
+        }
+        let url = ApiEndpoints::time_entries(workspace_id, &query);
+        let response = self
+            .execute::<TimeEntriesResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.data)
     }
 
     // ==================== Assigned Tasks ====
@@ -333,10 +839,12 @@ impl ClickUpClient {
         limit: Option<i32>,
     ) -> Result<Vec<Task>> {
         // Use ClickUp API's assignees filter parameter
-        let mut filters = TaskFilters::default();
-        filters.assignees = vec![user_id as i64];
-        // Include closed tasks so users can see all their assigned work
-        filters.include_closed = Some(true);
+        let mut filters = TaskFilters {
+            assignees: vec![user_id as i64],
+            // Include closed tasks so users can see all their assigned work
+            include_closed: Some(true),
+            ..Default::default()
+        };
         // ClickUp API uses 'limit' parameter to control how many tasks to return (default: 100)
         // 'page' is for pagination page number
         if let Some(l) = limit {
@@ -369,6 +877,35 @@ impl ClickUpClient {
 
         Ok(tasks)
     }
+
+    /// Get tasks assigned to the current user across all lists of a workspace
+    ///
+    /// The current user is added to `filters.assignees` when no assignee is given.
+    pub async fn get_my_tasks(
+        &self,
+        workspace_id: &str,
+        filters: &TaskFilters,
+    ) -> Result<Vec<Task>> {
+        let mut filters = filters.clone();
+        if filters.assignees.is_empty() {
+            filters.assignees.push(self.current_user_id().await?);
+        }
+        self.get_tasks_for_workspace(workspace_id, &filters).await
+    }
+
+    /// Get the tasks matching `filters` across all lists of a workspace
+    pub async fn get_tasks_for_workspace(
+        &self,
+        workspace_id: &str,
+        filters: &TaskFilters,
+    ) -> Result<Vec<Task>> {
+        let url = ApiEndpoints::tasks_in_team(workspace_id, &filters.to_query_string());
+        tracing::debug!("Fetching tasks in workspace {} with URL: {}", workspace_id, url);
+        let response = self
+            .execute::<TasksResponse>(self.request(reqwest::Method::GET, url))
+            .await?;
+        Ok(response.tasks)
+    }
 }
 
 /// Macro to generate trait implementation that delegates to inherent methods
@@ -392,10 +929,61 @@ macro_rules! impl_clickup_api {
                 self.get_space(space_id).await
             }
 
+            async fn create_space(
+                &self,
+                workspace_id: &str,
+                name: &str,
+                private: bool,
+            ) -> Result<Space> {
+                self.create_space(workspace_id, name, private).await
+            }
+
+            async fn update_space(
+                &self,
+                space_id: &str,
+                update: &UpdateSpaceRequest,
+            ) -> Result<Space> {
+                self.update_space(space_id, update).await
+            }
+
+            async fn create_space_tag(
+                &self,
+                space_id: &str,
+                name: &str,
+                bg_color: &str,
+                fg_color: &str,
+            ) -> Result<Tag> {
+                self.create_space_tag(space_id, name, bg_color, fg_color).await
+            }
+
+            async fn delete_space_tag(&self, space_id: &str, tag_name: &str) -> Result<()> {
+                self.delete_space_tag(space_id, tag_name).await
+            }
+
+            async fn tag_task(&self, task_id: &str, tag_name: &str) -> Result<()> {
+                self.tag_task(task_id, tag_name).await
+            }
+
+            async fn add_task_link(
+                &self,
+                task_id: &str,
+                links_to_task_id: &str,
+            ) -> Result<LinkedTask> {
+                self.add_task_link(task_id, links_to_task_id).await
+            }
+
+            async fn remove_task_link(&self, task_id: &str, link_id: &str) -> Result<()> {
+                self.remove_task_link(task_id, link_id).await
+            }
+
             async fn get_folders(&self, space_id: &str) -> Result<Vec<Folder>> {
                 self.get_folders(space_id).await
             }
 
+            async fn create_folder(&self, space_id: &str, name: &str) -> Result<Folder> {
+                self.create_folder(space_id, name).await
+            }
+
             async fn get_lists_in_folder(
                 &self,
                 folder_id: &str,
@@ -412,14 +1000,26 @@ macro_rules! impl_clickup_api {
                 self.get_lists_in_space(space_id, archived).await
             }
 
+            async fn get_list(&self, list_id: &str) -> Result<List> {
+                self.get_list(list_id).await
+            }
+
             async fn get_tasks(&self, list_id: &str, filters: &TaskFilters) -> Result<Vec<Task>> {
                 self.get_tasks(list_id, filters).await
             }
 
+            fn stream_tasks(&self, list_id: &str, filters: &TaskFilters) -> BoxStream<'_, Result<Task>> {
+                self.stream_tasks(list_id, filters)
+            }
+
             async fn get_task(&self, task_id: &str) -> Result<Task> {
                 self.get_task(task_id).await
             }
 
+            async fn get_task_by_custom_id(&self, custom_id: &str, team_id: &str) -> Result<Task> {
+                self.get_task_by_custom_id(custom_id, team_id).await
+            }
+
             async fn create_task(&self, list_id: &str, task: &CreateTaskRequest) -> Result<Task> {
                 self.create_task(list_id, task).await
             }
@@ -428,6 +1028,10 @@ macro_rules! impl_clickup_api {
                 self.update_task(task_id, task).await
             }
 
+            async fn set_task_recurrence(&self, task_id: &str, config: &RecurrenceConfig) -> Result<Task> {
+                self.set_task_recurrence(task_id, config).await
+            }
+
             async fn delete_task(&self, task_id: &str) -> Result<()> {
                 self.delete_task(task_id).await
             }
@@ -436,10 +1040,26 @@ macro_rules! impl_clickup_api {
                 self.get_list_members(list_id).await
             }
 
+            async fn get_list_statuses(&self, list_id: &str) -> Result<Vec<TaskStatus>> {
+                self.get_list_statuses(list_id).await
+            }
+
+            async fn get_folder_statuses(&self, folder_id: &str) -> Result<Vec<TaskStatus>> {
+                self.get_folder_statuses(folder_id).await
+            }
+
             async fn search_docs(&self, filters: &DocumentFilters) -> Result<Vec<Document>> {
                 self.search_docs(filters).await
             }
 
+            async fn get_document(&self, doc_id: &str) -> Result<Document> {
+                self.get_document(doc_id).await
+            }
+
+            async fn get_document_page(&self, doc_id: &str, page_id: &str) -> Result<Page> {
+                self.get_document_page(doc_id, page_id).await
+            }
+
             async fn get_doc_pages(&self, doc_id: &str) -> Result<Vec<Page>> {
                 self.get_doc_pages(doc_id).await
             }
@@ -448,14 +1068,23 @@ macro_rules! impl_clickup_api {
                 self.get_page(page_id).await
             }
 
-            async fn get_task_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
-                self.get_task_comments(task_id).await
+            async fn get_task_comments(
+                &self,
+                task_id: &str,
+                start: Option<CommentsCursor>,
+                limit: Option<u32>,
+            ) -> Result<CommentsPage> {
+                self.get_task_comments(task_id, start, limit).await
             }
 
             async fn get_comment_replies(&self, comment_id: &str) -> Result<Vec<Comment>> {
                 self.get_comment_replies(comment_id).await
             }
 
+            async fn get_comment(&self, comment_id: &str) -> Result<Comment> {
+                self.get_comment(comment_id).await
+            }
+
             async fn create_comment(
                 &self,
                 task_id: &str,
@@ -480,6 +1109,58 @@ macro_rules! impl_clickup_api {
                 self.update_comment(comment_id, comment).await
             }
 
+            async fn delete_comment(&self, comment_id: &str) -> Result<()> {
+                self.delete_comment(comment_id).await
+            }
+
+            async fn send_request(
+                &self,
+                method: &str,
+                url: &str,
+                body_json: Option<&str>,
+            ) -> Result<()> {
+                self.send_request(method, url, body_json).await
+            }
+
+            async fn get_list_views(&self, list_id: &str) -> Result<Vec<View>> {
+                self.get_list_views(list_id).await
+            }
+
+            async fn get_workspace_views(&self, workspace_id: &str) -> Result<Vec<WorkspaceView>> {
+                self.get_workspace_views(workspace_id).await
+            }
+
+            async fn get_view_tasks_page(
+                &self,
+                view_id: &str,
+                page: u32,
+            ) -> Result<ViewTasksResponse> {
+                self.get_view_tasks_page(view_id, page).await
+            }
+
+            async fn get_sprint_overview(&self, view_id: &str) -> Result<SprintOverview> {
+                self.get_sprint_overview(view_id).await
+            }
+
+            async fn get_time_entries(&self, task_id: &str) -> Result<Vec<TimeEntry>> {
+                self.get_time_entries(task_id).await
+            }
+
+            async fn get_task_time_in_status(&self, task_id: &str) -> Result<Vec<TimeInStatus>> {
+                self.get_task_time_in_status(task_id).await
+            }
+
+            async fn get_time_entries_for_user(
+                &self,
+                workspace_id: &str,
+                from: i64,
+                to: i64,
+                user_id: Option<&str>,
+            ) -> Result<Vec<TimeEntry>> {
+                self.get_time_entries_for_user(workspace_id, from, to, user_id)
+                    .await
+            }
+
             async fn get_tasks_with_assignee(
                 &self,
                 list_id: &str,
@@ -488,6 +1169,22 @@ macro_rules! impl_clickup_api {
             ) -> Result<Vec<Task>> {
                 self.get_tasks_with_assignee(list_id, user_id, limit).await
             }
+
+            async fn get_my_tasks(
+                &self,
+                workspace_id: &str,
+                filters: &TaskFilters,
+            ) -> Result<Vec<Task>> {
+                self.get_my_tasks(workspace_id, filters).await
+            }
+
+            async fn get_tasks_for_workspace(
+                &self,
+                workspace_id: &str,
+                filters: &TaskFilters,
+            ) -> Result<Vec<Task>> {
+                self.get_tasks_for_workspace(workspace_id, filters).await
+            }
         }
     };
 }
@@ -495,11 +1192,78 @@ macro_rules! impl_clickup_api {
 // Implement ClickUpApi trait for ClickUpClient
 impl_clickup_api!(ClickUpClient);
 
+/// Parse a response body, naming the failing field path on error
+///
+/// With a `dump_dir`, a body that fails to parse is saved there and the file
+/// path is added to the error.
+fn parse_body<T: DeserializeOwned>(body: &str, dump_dir: Option<&Path>) -> Result<T> {
+    // Keep the JSON path of the failing field for diagnostics
+    let err = match from_json_str(body) {
+        Ok(value) => return Ok(value),
+        Err(err) => err,
+    };
+
+    let path = err.path().to_string();
+    let saved = match dump_dir.map(|dir| dump_response(dir, body)) {
+        Some(Ok(file)) => {
+            tracing::warn!("Saved unparseable response to {}", file.display());
+            format!(" (raw response saved to {})", file.display())
+        }
+        Some(Err(e)) => {
+            tracing::warn!("Failed to save unparseable response: {:#}", e);
+            String::new()
+        }
+        None => String::new(),
+    };
+
+    Err(anyhow::Error::new(err.into_inner()).context(format!(
+        "Failed to parse response at `{}`{}: {}",
+        path,
+        saved,
+        body.chars().take(200).collect::<String>()
+    )))
+}
+
+/// ClickUp's reason for refusing a request, e.g. "Space name taken (PROJ_017)"
+///
+/// Read from the `err` and `ECODE` fields of an `API error` body; `None` for
+/// other errors, or bodies that don't say.
+pub fn api_error_message(error: &anyhow::Error) -> Option<String> {
+    let text = format!("{:#}", error);
+    let (_, body) = text.split_once("API error (")?.1.split_once("): ")?;
+    let body: serde_json::Value = serde_json::from_str(body.trim()).ok()?;
+    let message = body.get("err")?.as_str()?.trim();
+    if message.is_empty() {
+        return None;
+    }
+    Some(match body.get("ECODE").and_then(|code| code.as_str()) {
+        Some(code) => format!("{} ({})", message, code),
+        None => message.to_string(),
+    })
+}
+
+/// Write a response body to a new timestamped file in `dir`
+fn dump_response(dir: &Path, body: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir).context("Failed to create response dump directory")?;
+    let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S%.3f");
+    let file = dir.join(format!("response-{}.json", stamp));
+    std::fs::write(&file, body).context("Failed to write response dump")?;
+    Ok(file)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn test_accept_header_negotiates_markdown() {
+        let client = ClickUpClient::new("token".to_string());
+        assert_eq!(client.accept_header(), "application/json");
+        let client = client.with_markdown(true);
+        assert_eq!(client.accept_header(), "application/json; markdown=true");
+    }
+
     #[test]
     fn test_parse_error_includes_field_path() {
         // This test demonstrates that serde_path_to_error provides field-level diagnostics
@@ -528,6 +1292,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_body_dumps_malformed_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let body = r#"{"id": "123", "comment_text": "Test", "user": {"id": "not-a-number"}}"#;
+
+        let err = parse_body::<Comment>(body, Some(dir.path())).unwrap_err();
+        let message = format!("{:#}", err);
+
+        assert!(
+            message.contains("Failed to parse response at `user.id`"),
+            "Error should name the failing field path: {}",
+            message
+        );
+        assert!(message.contains("raw response saved to"), "{}", message);
+
+        let dumps: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        assert_eq!(dumps.len(), 1);
+        assert_eq!(std::fs::read_to_string(&dumps[0]).unwrap(), body);
+    }
+
+    #[test]
+    fn test_parse_body_without_dump_dir_only_reports() {
+        let err = parse_body::<Comment>(r#"{"id": ["x"]}"#, None).unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains("at `id`"), "{}", message);
+        assert!(!message.contains("saved to"));
+    }
+
     #[test]
     fn test_parse_error_with_nested_field() {
         // Test error reporting for nested fields (e.g., in User object)
@@ -555,4 +1350,25 @@ mod tests {
             err_msg
         );
     }
+
+    #[test]
+    fn test_api_error_message_reads_clickup_reason() {
+        let error = anyhow::anyhow!(
+            "{}",
+            r#"API error (400 Bad Request): {"err":"Space name taken","ECODE":"PROJ_017"}"#
+        )
+        .context("Failed to create space");
+        assert_eq!(
+            api_error_message(&error).as_deref(),
+            Some("Space name taken (PROJ_017)")
+        );
+
+        let without_code =
+            anyhow::anyhow!("{}", r#"API error (403 Forbidden): {"err":"Not on your plan"}"#);
+        assert_eq!(api_error_message(&without_code).as_deref(), Some("Not on your plan"));
+
+        let html = anyhow::anyhow!("API error (502 Bad Gateway): <html>");
+        assert_eq!(api_error_message(&html), None);
+        assert_eq!(api_error_message(&anyhow::anyhow!("Request failed")), None);
+    }
 }
@@ -1,11 +1,18 @@
 //! Trait definition for ClickUp API client to enable mocking
 
 use crate::models::{
-    ClickUpSpace, Comment, CreateCommentRequest, CreateTaskRequest, Document, DocumentFilters,
-    Folder, List, Page, Task, TaskFilters, UpdateCommentRequest, UpdateTaskRequest,
-    User, Workspace,
+    BulkArchiveResult, BulkDeleteResult, ClickUpSpace, Comment, CommentsCursor, CommentsPage,
+    CreateCommentRequest, CreateTaskRequest, Document, DocumentFilters, Features, Folder, LinkedTask, List,
+    Page, RecurrenceConfig, SprintOverview, Tag, Task, TaskFilters, TaskStatus, TimeEntry,
+    TimeInStatus, UpdateCommentRequest, UpdateSpaceRequest, UpdateTaskRequest, User, View, ViewTasksResponse, WorkspaceView,
+    Workspace, BATCH_GET_TASKS_SIZE, BULK_ARCHIVE_BATCH_SIZE, BULK_DELETE_BATCH_SIZE,
+    TASKS_PAGE_SIZE,
 };
+use crate::api::request_queue::is_outcome_unknown;
+use crate::utils::ClickUpUrlGenerator;
 use anyhow::Result;
+use futures::stream::{BoxStream, StreamExt};
+use std::sync::Arc;
 
 /// Authentication result containing the API token
 #[derive(Clone)]
@@ -16,8 +23,23 @@ pub struct AuthToken {
 
 /// Trait defining the ClickUp API interface for dependency injection
 /// This enables mocking the API for headless testing
+///
+/// Implementations must be `Send + Sync`: the TUI shares one client as an
+/// `Arc<dyn ClickUpApi>` across the tokio tasks it spawns (see [`Self::boxed`]).
+///
+/// Parameters are borrowed rather than generic (`impl Into<String>` and the
+/// like) so the trait stays usable as a trait object. Wrappers can forward
+/// them as-is; [`CachingClient`](crate::api::CachingClient) is an example.
 #[async_trait::async_trait]
 pub trait ClickUpApi: Send + Sync {
+    /// Share this client the way the TUI holds it
+    fn boxed(self) -> Arc<dyn ClickUpApi>
+    where
+        Self: Sized + 'static,
+    {
+        Arc::new(self)
+    }
+
     // ==================== Workspace/Team ====================
 
     /// Get all authorized workspaces
@@ -37,11 +59,51 @@ pub trait ClickUpApi: Send + Sync {
     #[allow(dead_code)]
     async fn get_space(&self, space_id: &str) -> Result<ClickUpSpace>;
 
+    /// Features available in a workspace, merged from its spaces' `features`
+    ///
+    /// See [`Features::of_spaces`]: a feature counts as off only when every
+    /// space listing it has it off.
+    async fn get_workspace_features(&self, workspace_id: &str) -> Result<Features> {
+        let spaces = self.get_spaces(workspace_id).await?;
+        Ok(Features::of_spaces(spaces.iter().map(|s| &s.features)))
+    }
+
+    /// Create a space in a workspace
+    async fn create_space(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<ClickUpSpace>;
+
+    /// Change a space's name, color or privacy
+    async fn update_space(
+        &self,
+        space_id: &str,
+        update: &UpdateSpaceRequest,
+    ) -> Result<ClickUpSpace>;
+
+    /// Create a tag in a space, colored with hex `bg_color` and `fg_color`
+    async fn create_space_tag(
+        &self,
+        space_id: &str,
+        name: &str,
+        bg_color: &str,
+        fg_color: &str,
+    ) -> Result<Tag>;
+
+    /// Delete a tag from a space, and so from every task in it
+    #[allow(dead_code)]
+    async fn delete_space_tag(&self, space_id: &str, tag_name: &str) -> Result<()>;
+
     // ==================== Folders ====================
 
     /// Get all folders in a space
     async fn get_folders(&self, space_id: &str) -> Result<Vec<Folder>>;
 
+    /// Create a folder in a space
+    async fn create_folder(&self, space_id: &str, name: &str) -> Result<Folder>;
+
     // ==================== Lists ====================
 
     /// Get all lists in a folder
@@ -55,14 +117,143 @@ pub trait ClickUpApi: Send + Sync {
     async fn get_lists_in_space(&self, space_id: &str, archived: Option<bool>)
         -> Result<Vec<List>>;
 
+    /// Get a single list
+    async fn get_list(&self, list_id: &str) -> Result<List>;
+
     // ==================== Tasks ====================
 
     /// Get all tasks in a list
     async fn get_tasks(&self, list_id: &str, filters: &TaskFilters) -> Result<Vec<Task>>;
 
+    /// The tasks in a list across pages, each as soon as it has been read
+    ///
+    /// Pages through [`stream_tasks`](Self::stream_tasks) from `filters.page`
+    /// on, fetching at most `max_pages` pages; a page shorter than
+    /// [`TASKS_PAGE_SIZE`] is the last one, see
+    /// [`TaskPages::cut_short`](crate::models::TaskPages::cut_short). The
+    /// stream ends after the first error.
+    fn stream_all_tasks(
+        &self,
+        list_id: &str,
+        filters: &TaskFilters,
+        max_pages: u32,
+    ) -> BoxStream<'_, Result<Task>> {
+        struct Paging<'a> {
+            list_id: String,
+            filters: TaskFilters,
+            next_page: u32,
+            end_page: u32,
+            current: Option<BoxStream<'a, Result<Task>>>,
+            received: usize,
+        }
+        let first_page = filters.page.unwrap_or(0);
+        let paging = Paging {
+            list_id: list_id.to_string(),
+            filters: filters.clone(),
+            next_page: first_page,
+            end_page: first_page + max_pages.max(1),
+            current: None,
+            received: 0,
+        };
+        futures::stream::unfold(paging, move |mut paging| async move {
+            loop {
+                if let Some(stream) = paging.current.as_mut() {
+                    match stream.next().await {
+                        Some(Ok(task)) => {
+                            paging.received += 1;
+                            return Some((Ok(task), paging));
+                        }
+                        Some(Err(e)) => {
+                            paging.current = None;
+                            paging.next_page = paging.end_page;
+                            return Some((Err(e), paging));
+                        }
+                        None if paging.received < TASKS_PAGE_SIZE => return None,
+                        None => paging.received = 0,
+                    }
+                }
+                if paging.next_page >= paging.end_page {
+                    return None;
+                }
+                let page_filters = TaskFilters {
+                    page: Some(paging.next_page),
+                    ..paging.filters.clone()
+                };
+                paging.current = Some(self.stream_tasks(&paging.list_id, &page_filters));
+                paging.next_page += 1;
+            }
+        })
+        .boxed()
+    }
+
+    /// Get one page of a list's tasks (`filters.page`), each as soon as it
+    /// has been read
+    ///
+    /// By default the page is fetched whole, slimmed when `filters.slim`
+    /// asks for it, and then handed over task by task;
+    /// [`ClickUpClient`](crate::api::ClickUpClient) parses the response as it
+    /// arrives instead.
+    fn stream_tasks(&self, list_id: &str, filters: &TaskFilters) -> BoxStream<'_, Result<Task>> {
+        let list_id = list_id.to_string();
+        let filters = filters.clone();
+        let slim = filters.slim;
+        futures::stream::once(async move { self.get_tasks(&list_id, &filters).await })
+            .flat_map(move |result| {
+                let items: Vec<Result<Task>> = match result {
+                    Ok(mut tasks) => {
+                        if slim {
+                            tasks.iter_mut().for_each(Task::slim);
+                        }
+                        tasks.into_iter().map(Ok).collect()
+                    }
+                    Err(e) => vec![Err(e)],
+                };
+                futures::stream::iter(items)
+            })
+            .boxed()
+    }
+
     /// Get a single task
     async fn get_task(&self, task_id: &str) -> Result<Task>;
 
+    /// Get a task by its custom ID (e.g. `DEV-42`) in workspace `team_id`
+    async fn get_task_by_custom_id(&self, custom_id: &str, team_id: &str) -> Result<Task>;
+
+    /// Get the task a ClickUp task URL points at
+    ///
+    /// See [`ClickUpUrlGenerator::extract_task_id`] for the accepted formats.
+    /// Custom IDs in workspace-prefixed links are looked up in that workspace.
+    async fn get_task_from_url(&self, url: &str) -> Result<Task> {
+        if let Some((workspace_id, custom_id)) = ClickUpUrlGenerator::extract_custom_task_id(url) {
+            return self.get_task_by_custom_id(&custom_id, &workspace_id).await;
+        }
+        let Some(task_id) = ClickUpUrlGenerator::extract_task_id(url) else {
+            anyhow::bail!("Not a ClickUp task URL: {}", url.trim());
+        };
+        self.get_task(&task_id).await
+    }
+
+    /// Get several tasks by ID, in the order given
+    ///
+    /// ClickUp has no bulk task endpoint, so the tasks are fetched one by one,
+    /// [`BATCH_GET_TASKS_SIZE`] at a time. Tasks ClickUp no longer has (404)
+    /// are left out; any other failure fails the batch.
+    async fn batch_get_tasks(&self, task_ids: &[String]) -> Result<Vec<Task>> {
+        let mut tasks = Vec::with_capacity(task_ids.len());
+        for batch in task_ids.chunks(BATCH_GET_TASKS_SIZE) {
+            let fetched =
+                futures::future::join_all(batch.iter().map(|id| self.get_task(id))).await;
+            for result in fetched {
+                match result {
+                    Ok(task) => tasks.push(task),
+                    Err(e) if is_not_found(&e) => tracing::debug!("Skipping missing task: {:#}", e),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(tasks)
+    }
+
     /// Create a new task
     #[allow(dead_code)]
     async fn create_task(&self, list_id: &str, task: &CreateTaskRequest) -> Result<Task>;
@@ -71,20 +262,107 @@ pub trait ClickUpApi: Send + Sync {
     #[allow(dead_code)]
     async fn update_task(&self, task_id: &str, task: &UpdateTaskRequest) -> Result<Task>;
 
+    /// Make a task repeat on the given schedule
+    #[allow(dead_code)]
+    async fn set_task_recurrence(&self, task_id: &str, config: &RecurrenceConfig) -> Result<Task>;
+
     /// Delete a task
     #[allow(dead_code)]
     async fn delete_task(&self, task_id: &str) -> Result<()>;
 
+    /// Delete several tasks, [`BULK_DELETE_BATCH_SIZE`] at a time.
+    ///
+    /// A failed deletion does not stop the others; it is reported in
+    /// [`BulkDeleteResult::failed`].
+    async fn bulk_delete_tasks(&self, task_ids: &[String]) -> Result<BulkDeleteResult> {
+        let mut result = BulkDeleteResult::default();
+        for batch in task_ids.chunks(BULK_DELETE_BATCH_SIZE) {
+            let outcomes =
+                futures::future::join_all(batch.iter().map(|id| self.delete_task(id))).await;
+            for (id, outcome) in batch.iter().zip(outcomes) {
+                match outcome {
+                    Ok(()) => result.deleted.push(id.clone()),
+                    Err(e) => result.failed.push((id.clone(), e.to_string())),
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Archive a task, hiding it from lists unless archived tasks are asked for
+    async fn archive_task(&self, task_id: &str) -> Result<()> {
+        self.update_task(task_id, &UpdateTaskRequest::set_archived(true))
+            .await
+            .map(|_| ())
+    }
+
+    /// Bring an archived task back
+    async fn unarchive_task(&self, task_id: &str) -> Result<()> {
+        self.update_task(task_id, &UpdateTaskRequest::set_archived(false))
+            .await
+            .map(|_| ())
+    }
+
+    /// Archive or unarchive several tasks, [`BULK_ARCHIVE_BATCH_SIZE`] at a time.
+    ///
+    /// A failed update does not stop the others; it is reported in
+    /// [`BulkArchiveResult::failed`].
+    async fn bulk_archive_tasks(
+        &self,
+        task_ids: &[String],
+        archived: bool,
+    ) -> Result<BulkArchiveResult> {
+        let update = UpdateTaskRequest::set_archived(archived);
+        let mut result = BulkArchiveResult {
+            archived,
+            ..Default::default()
+        };
+        for batch in task_ids.chunks(BULK_ARCHIVE_BATCH_SIZE) {
+            let outcomes =
+                futures::future::join_all(batch.iter().map(|id| self.update_task(id, &update)))
+                    .await;
+            for (id, outcome) in batch.iter().zip(outcomes) {
+                match outcome {
+                    Ok(_) => result.updated.push(id.clone()),
+                    Err(e) => result.failed.push((id.clone(), e.to_string())),
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Add an existing space tag to a task
+    async fn tag_task(&self, task_id: &str, tag_name: &str) -> Result<()>;
+
+    /// Link a task to another, returning the new link
+    async fn add_task_link(&self, task_id: &str, links_to_task_id: &str) -> Result<LinkedTask>;
+
+    /// Remove a task's link to `link_id`, the task at the other end
+    async fn remove_task_link(&self, task_id: &str, link_id: &str) -> Result<()>;
+
     // ==================== Members ====================
 
     /// Get all members who can access a list
     async fn get_list_members(&self, list_id: &str) -> Result<Vec<User>>;
 
+    /// Get the status workflow of a list, in workflow order
+    async fn get_list_statuses(&self, list_id: &str) -> Result<Vec<TaskStatus>>;
+
+    /// Get a folder's own status workflow, in workflow order (empty when it
+    /// uses the space's statuses)
+    async fn get_folder_statuses(&self, folder_id: &str) -> Result<Vec<TaskStatus>>;
+
     // ==================== Documents ====================
 
     /// Search documents
     async fn search_docs(&self, filters: &DocumentFilters) -> Result<Vec<Document>>;
 
+    /// Get a document by ID, with its pages
+    async fn get_document(&self, doc_id: &str) -> Result<Document>;
+
+    /// Get one page of a document
+    async fn get_document_page(&self, doc_id: &str, page_id: &str) -> Result<Page>;
+
     /// Get all pages in a document
     #[allow(dead_code)]
     async fn get_doc_pages(&self, doc_id: &str) -> Result<Vec<Page>>;
@@ -95,14 +373,50 @@ pub trait ClickUpApi: Send + Sync {
 
     // ==================== Comments ====================
 
-    /// Get all comments for a task (top-level only)
-    #[allow(dead_code)]
-    async fn get_task_comments(&self, task_id: &str) -> Result<Vec<Comment>>;
+    /// Get a page of a task's top-level comments, newest first
+    ///
+    /// `start` is the cursor from the previous page; `limit` caps the page size.
+    async fn get_task_comments(
+        &self,
+        task_id: &str,
+        start: Option<CommentsCursor>,
+        limit: Option<u32>,
+    ) -> Result<CommentsPage>;
+
+    /// Get all of a task's top-level comments, following the page cursors
+    async fn get_all_task_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
+        let mut comments = Vec::new();
+        let mut start = None;
+        loop {
+            let page = self.get_task_comments(task_id, start, None).await?;
+            comments.extend(page.comments);
+            match page.next_cursor {
+                Some(cursor) if Some(cursor) != start => start = Some(cursor),
+                _ => return Ok(comments),
+            }
+        }
+    }
+
+    /// A task's comments created or edited after `since` (ms), or None when
+    /// the API can't filter comments that way
+    ///
+    /// ClickUp's comment endpoint pages by creation date only, so the HTTP
+    /// client keeps this default and callers fetch the comments in full.
+    async fn get_task_comments_updated_since(
+        &self,
+        _task_id: &str,
+        _since: i64,
+    ) -> Result<Option<Vec<Comment>>> {
+        Ok(None)
+    }
 
     /// Get replies to a specific comment (threaded comments)
     #[allow(dead_code)]
     async fn get_comment_replies(&self, comment_id: &str) -> Result<Vec<Comment>>;
 
+    /// Get a single comment, top-level or reply
+    async fn get_comment(&self, comment_id: &str) -> Result<Comment>;
+
     /// Create a new comment on a task (top-level)
     async fn create_comment(
         &self,
@@ -125,6 +439,129 @@ pub trait ClickUpApi: Send + Sync {
         comment: &UpdateCommentRequest,
     ) -> Result<Comment>;
 
+    /// Create a comment, or a reply when `comment.parent_id` is set, looking
+    /// for it when the request failed without saying whether it was made
+    ///
+    /// A create that timed out may still have reached ClickUp, and reporting
+    /// it as failed invites a retry that posts it twice. The task's newest
+    /// comments (or the thread's replies) are fetched instead, and the one
+    /// the request made is the result; see [`CreateCommentRequest::find_created`].
+    async fn create_comment_checked(
+        &self,
+        task_id: &str,
+        comment: &CreateCommentRequest,
+    ) -> Result<Comment> {
+        let sent_at = chrono::Utc::now().timestamp_millis();
+        let result = match &comment.parent_id {
+            Some(parent_id) => self.create_comment_reply(parent_id, comment).await,
+            None => self.create_comment(task_id, comment).await,
+        };
+        let error = match result {
+            Err(e) if is_outcome_unknown(&e) => e,
+            result => return result,
+        };
+        let comments = match &comment.parent_id {
+            Some(parent_id) => self.get_comment_replies(parent_id).await,
+            None => self
+                .get_task_comments(task_id, None, None)
+                .await
+                .map(|page| page.comments),
+        };
+        let author_id = self.get_current_user().await.ok().map(|user| user.id);
+        let created = comments
+            .ok()
+            .and_then(|comments| comment.find_created(&comments, author_id, sent_at).cloned());
+        match created {
+            Some(created) => {
+                tracing::info!("Comment {} was created despite: {:#}", created.id, error);
+                Ok(created)
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Update a comment, checking whether the change was made when the
+    /// request failed without saying
+    ///
+    /// The comment is fetched again; when it already has the change, that
+    /// copy is the result.
+    async fn update_comment_checked(
+        &self,
+        comment_id: &str,
+        comment: &UpdateCommentRequest,
+    ) -> Result<Comment> {
+        let error = match self.update_comment(comment_id, comment).await {
+            Err(e) if is_outcome_unknown(&e) => e,
+            result => return result,
+        };
+        match self.get_comment(comment_id).await {
+            Ok(current) if comment.applied_to(&current) => {
+                tracing::info!("Comment {} was updated despite: {:#}", comment_id, error);
+                Ok(current)
+            }
+            _ => Err(error),
+        }
+    }
+
+    /// Delete a comment
+    async fn delete_comment(&self, comment_id: &str) -> Result<()>;
+
+    // ==================== Request Queue ====================
+
+    /// Send a request saved while offline (see [`crate::api::request_queue`])
+    async fn send_request(&self, method: &str, url: &str, body_json: Option<&str>) -> Result<()>;
+
+    // ==================== Views ====================
+
+    /// Get the views saved on a list
+    async fn get_list_views(&self, list_id: &str) -> Result<Vec<View>>;
+
+    /// Get the views saved at the workspace level, across all its spaces
+    async fn get_workspace_views(&self, workspace_id: &str) -> Result<Vec<WorkspaceView>>;
+
+    /// Get one page (from 0) of the tasks shown in a view
+    async fn get_view_tasks_page(&self, view_id: &str, page: u32) -> Result<ViewTasksResponse>;
+
+    /// Get every task shown in a view, following its pages to the last one
+    async fn get_view_tasks(&self, view_id: &str) -> Result<Vec<Task>> {
+        let mut tasks = Vec::new();
+        let mut page = 0;
+        loop {
+            let response = self.get_view_tasks_page(view_id, page).await?;
+            let empty = response.tasks.is_empty();
+            tasks.extend(response.tasks);
+            if response.last_page || empty {
+                return Ok(tasks);
+            }
+            page += 1;
+        }
+    }
+
+    // ==================== Sprints ====================
+
+    /// Get the sprint overview for a sprint view (ClickUp sprints are views)
+    async fn get_sprint_overview(&self, view_id: &str) -> Result<SprintOverview>;
+
+    // ==================== Time Tracking ====================
+
+    /// Get the time logged on a task, by every user, oldest first
+    async fn get_time_entries(&self, task_id: &str) -> Result<Vec<TimeEntry>>;
+
+    /// Get how long a task spent in each status, in workflow order
+    async fn get_task_time_in_status(&self, task_id: &str) -> Result<Vec<TimeInStatus>>;
+
+    /// Get time entries in a workspace that started between `from` and `to`
+    /// (ms since epoch).
+    ///
+    /// Without `user_id`, ClickUp returns the authenticated user's entries.
+    async fn get_time_entries_for_user(
+        &self,
+        workspace_id: &str,
+        from: i64,
+        to: i64,
+        user_id: Option<&str>,
+    ) -> Result<Vec<TimeEntry>>;
+
     // ==================== Assigned Tasks ====================
 
     /// Get tasks assigned to a specific user from a list
@@ -134,4 +571,21 @@ pub trait ClickUpApi: Send + Sync {
         user_id: i32,
         limit: Option<i32>,
     ) -> Result<Vec<Task>>;
+
+    /// Get tasks assigned to the current user across all lists of a workspace
+    async fn get_my_tasks(&self, workspace_id: &str, filters: &TaskFilters) -> Result<Vec<Task>>;
+
+    /// Get the tasks matching `filters` across all lists of a workspace
+    ///
+    /// Use `filters.assignees` to aggregate one person's work.
+    async fn get_tasks_for_workspace(
+        &self,
+        workspace_id: &str,
+        filters: &TaskFilters,
+    ) -> Result<Vec<Task>>;
+}
+
+/// Whether a request failed because ClickUp has nothing at that ID (404)
+fn is_not_found(error: &anyhow::Error) -> bool {
+    format!("{:#}", error).contains("API error (404")
 }
@@ -31,6 +31,19 @@ impl ApiEndpoints {
         format!("{}/space/{}", BASE_URL, space_id)
     }
 
+    pub fn space_tags(space_id: &str) -> String {
+        format!("{}/space/{}/tag", BASE_URL, space_id)
+    }
+
+    pub fn space_tag(space_id: &str, tag_name: &str) -> String {
+        format!(
+            "{}/space/{}/tag/{}",
+            BASE_URL,
+            space_id,
+            urlencoding::encode(tag_name)
+        )
+    }
+
     // Folder endpoints
     pub fn folders(space_id: &str) -> String {
         format!("{}/space/{}/folder", BASE_URL, space_id)
@@ -66,19 +79,68 @@ impl ApiEndpoints {
         format!("{}/task/{}", BASE_URL, task_id)
     }
 
+    /// A task by its custom ID, which ClickUp only resolves within a workspace
+    pub fn task_by_custom_id(custom_id: &str, team_id: &str) -> String {
+        format!(
+            "{}/task/{}?custom_task_ids=true&team_id={}",
+            BASE_URL,
+            urlencoding::encode(custom_id),
+            team_id
+        )
+    }
+
+    pub fn task_tag(task_id: &str, tag_name: &str) -> String {
+        format!(
+            "{}/task/{}/tag/{}",
+            BASE_URL,
+            task_id,
+            urlencoding::encode(tag_name)
+        )
+    }
+
+    pub fn task_link(task_id: &str, links_to: &str) -> String {
+        format!(
+            "{}/task/{}/link/{}",
+            BASE_URL,
+            task_id,
+            urlencoding::encode(links_to)
+        )
+    }
+
     pub fn tasks_in_team(team_id: &str, query: &str) -> String {
         format!("{}/team/{}/task{}", BASE_URL, team_id, query)
     }
 
+    // Time tracking endpoints
+    pub fn task_time(task_id: &str) -> String {
+        format!("{}/task/{}/time", BASE_URL, task_id)
+    }
+
+    pub fn task_time_in_status(task_id: &str) -> String {
+        format!("{}/task/{}/time_in_status", BASE_URL, task_id)
+    }
+
+    pub fn time_entries(team_id: &str, query: &str) -> String {
+        format!("{}/team/{}/time_entries{}", BASE_URL, team_id, query)
+    }
+
     // Document endpoints
     pub fn docs(query: &str) -> String {
         format!("{}/docs{}", BASE_URL, query)
     }
 
+    pub fn doc(doc_id: &str) -> String {
+        format!("{}/doc/{}", BASE_URL, doc_id)
+    }
+
     pub fn doc_pages(doc_id: &str) -> String {
         format!("{}/doc/{}/pages", BASE_URL, doc_id)
     }
 
+    pub fn doc_page(doc_id: &str, page_id: &str) -> String {
+        format!("{}/doc/{}/page/{}", BASE_URL, doc_id, page_id)
+    }
+
     pub fn page(page_id: &str) -> String {
         format!("{}/page/{}", BASE_URL, page_id)
     }
@@ -96,6 +158,23 @@ impl ApiEndpoints {
         format!("{}/comment/{}/reply", BASE_URL, comment_id)
     }
 
+    // View endpoints
+    pub fn view(view_id: &str) -> String {
+        format!("{}/view/{}", BASE_URL, view_id)
+    }
+
+    pub fn list_views(list_id: &str) -> String {
+        format!("{}/list/{}/view", BASE_URL, list_id)
+    }
+
+    pub fn workspace_views(workspace_id: &str) -> String {
+        format!("{}/team/{}/view", BASE_URL, workspace_id)
+    }
+
+    pub fn view_tasks(view_id: &str, page: u32) -> String {
+        format!("{}/view/{}/task?page={}", BASE_URL, view_id, page)
+    }
+
     // Notification endpoints
     pub fn notifications(workspace_id: &str) -> String {
         format!("{}/team/{}/notifications", BASE_URL, workspace_id)
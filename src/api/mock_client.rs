@@ -2,9 +2,10 @@
 
 use crate::api::client_trait::ClickUpApi;
 use crate::models::{
-    ClickUpSpace, Comment, CreateCommentRequest, CreateTaskRequest, Document, DocumentFilters,
-    Folder, List, Page, Task, TaskFilters, UpdateCommentRequest, UpdateTaskRequest, User,
-    Workspace,
+    ClickUpSpace, Comment, CommentsCursor, CommentsPage, CreateCommentRequest, CreateTaskRequest,
+    Document, DocumentFilters, Folder, LinkedTask, List, Page, RecurrenceConfig, SprintOverview, Tag, Task,
+    TaskFilters, TaskStatus, TimeEntry, TimeInStatus, UpdateCommentRequest, UpdateSpaceRequest, UpdateTaskRequest,
+    User, View, ViewTasksResponse, Workspace, WorkspaceView, COMMENTS_PAGE_SIZE,
 };
 use anyhow::{anyhow, Result};
 
@@ -52,20 +53,38 @@ pub struct MockClickUpClient {
     pub spaces_response: Option<Result<Vec<ClickUpSpace>>>,
     /// Override for get_folders response
     pub folders_response: Option<Result<Vec<Folder>>>,
+    /// Override for create_space response
+    pub create_space_response: Option<Result<ClickUpSpace>>,
+    /// Override for update_space response
+    pub update_space_response: Option<Result<ClickUpSpace>>,
+    /// Override for create_folder response
+    pub create_folder_response: Option<Result<Folder>>,
+    /// Error returned by the tag calls, which otherwise succeed
+    pub tag_error: Option<String>,
+    /// Error returned by the task link calls, which otherwise succeed
+    pub task_link_error: Option<String>,
     /// Override for get_lists_in_folder response
     pub lists_in_folder_response: Option<Result<Vec<List>>>,
     /// Override for get_lists_in_space response
     pub lists_in_space_response: Option<Result<Vec<List>>>,
     /// Override for get_tasks response
     pub tasks_response: Option<Result<Vec<Task>>>,
+    /// Paged get_tasks responses (index = page); takes precedence over tasks_response
+    pub task_pages_response: Option<Vec<Vec<Task>>>,
     /// Override for get_task response
     pub task_response: Option<Result<Task>>,
+    /// Tasks returned by get_task for their ID, ahead of `task_response`
+    pub tasks_by_id: std::collections::HashMap<String, Task>,
     /// Override for create_task response
     pub create_task_response: Option<Result<Task>>,
     /// Override for update_task response
     pub update_task_response: Option<Result<Task>>,
     /// Override for delete_task response (raw JSON body string)
     pub delete_task_response: Option<Result<String>>,
+    /// Task IDs whose deletion fails regardless of `delete_task_response`
+    pub delete_task_failures: std::collections::HashSet<String>,
+    /// Task IDs whose update fails regardless of `update_task_response`
+    pub update_task_failures: std::collections::HashSet<String>,
     /// Override for search_docs response
     pub search_docs_response: Option<Result<Vec<Document>>>,
     /// Override for get_doc_pages response
@@ -74,6 +93,9 @@ pub struct MockClickUpClient {
     pub page_response: Option<Result<Page>>,
     /// Override for get_task_comments response
     pub task_comments_response: Option<Result<Vec<Comment>>>,
+    /// Comments returned by get_task_comments_updated_since; unset means the
+    /// mock, like the HTTP client, can't filter by update time
+    pub comments_updated_since_response: Option<Vec<Comment>>,
     /// Override for get_comment_replies response (maps comment_id -> replies)
     pub comment_replies_response: Option<std::collections::HashMap<String, Result<Vec<Comment>>>>,
     /// Override for create_comment response
@@ -82,12 +104,56 @@ pub struct MockClickUpClient {
     pub create_comment_reply_response: Option<Result<Comment>>,
     /// Override for update_comment response
     pub update_comment_response: Option<Result<Comment>>,
+    /// Override for delete_comment response
+    pub delete_comment_response: Option<Result<()>>,
     /// Override for get_tasks_with_assignee response
     pub tasks_with_assignee_response: Option<Result<Vec<Task>>>,
     /// Override for get_current_user response
     pub current_user_response: Option<Result<User>>,
+    /// Override for get_my_tasks response
+    pub my_tasks_response: Option<Result<Vec<Task>>>,
+    /// Tasks across the workspace; get_tasks_for_workspace applies the assignee filter
+    pub workspace_tasks_response: Option<Result<Vec<Task>>>,
     /// Override for get_list_members response
     pub list_members_response: Option<Result<Vec<User>>>,
+    /// Override for get_list_statuses response
+    pub list_statuses_response: Option<Result<Vec<TaskStatus>>>,
+    /// Override for get_folder_statuses response
+    pub folder_statuses_response: Option<Result<Vec<TaskStatus>>>,
+    /// Override for send_request response
+    pub send_request_response: Option<Result<()>>,
+    /// Override for get_list_views response
+    pub list_views_response: Option<Result<Vec<View>>>,
+    /// Override for get_workspace_views response
+    pub workspace_views_response: Option<Result<Vec<WorkspaceView>>>,
+    /// Pages of tasks served by get_view_tasks_page; the last one is marked last
+    pub view_task_pages_response: Option<Result<Vec<Vec<Task>>>>,
+    /// Override for get_sprint_overview response
+    pub sprint_overview_response: Option<Result<SprintOverview>>,
+    /// Override for get_time_entries response
+    pub task_time_entries_response: Option<Result<Vec<TimeEntry>>>,
+    /// Override for get_time_entries_for_user response
+    pub workspace_time_entries_response: Option<Result<Vec<TimeEntry>>>,
+    /// Override for get_task_time_in_status response
+    pub time_in_status_response: Option<Result<Vec<TimeInStatus>>>,
+    /// Mutating calls received, in order (e.g. "delete_comment comment-1")
+    calls: std::sync::Mutex<Vec<String>>,
+    /// Pages requested through get_tasks, in order
+    task_page_requests: std::sync::Mutex<Vec<u32>>,
+    /// Query strings of get_tasks calls, in order
+    task_queries: std::sync::Mutex<Vec<String>>,
+    /// Cursor ids (`start_id`) requested through get_task_comments, in order
+    comment_page_requests: std::sync::Mutex<Vec<Option<i64>>>,
+    /// Task IDs requested through get_task, in order
+    task_requests: std::sync::Mutex<Vec<String>>,
+    /// Task IDs requested through get_task_time_in_status, in order
+    time_in_status_requests: std::sync::Mutex<Vec<String>>,
+    /// Document IDs requested through get_document, in order
+    document_requests: std::sync::Mutex<Vec<String>>,
+    /// Bodies sent through create_comment and create_comment_reply, in order
+    comment_create_requests: std::sync::Mutex<Vec<CreateCommentRequest>>,
+    /// Comment IDs and bodies sent through update_comment, in order
+    comment_update_requests: std::sync::Mutex<Vec<(String, UpdateCommentRequest)>>,
 }
 
 #[allow(dead_code)]
@@ -98,26 +164,108 @@ impl MockClickUpClient {
             workspaces_response: None,
             spaces_response: None,
             folders_response: None,
+            create_space_response: None,
+            update_space_response: None,
+            create_folder_response: None,
+            tag_error: None,
+            task_link_error: None,
             lists_in_folder_response: None,
             lists_in_space_response: None,
             tasks_response: None,
+            task_pages_response: None,
             task_response: None,
+            tasks_by_id: std::collections::HashMap::new(),
             create_task_response: None,
             update_task_response: None,
             delete_task_response: None,
+            delete_task_failures: std::collections::HashSet::new(),
+            update_task_failures: std::collections::HashSet::new(),
             search_docs_response: None,
             doc_pages_response: None,
             page_response: None,
             task_comments_response: None,
+            comments_updated_since_response: None,
             comment_replies_response: None,
             create_comment_response: None,
             create_comment_reply_response: None,
             update_comment_response: None,
+            delete_comment_response: None,
             tasks_with_assignee_response: None,
             current_user_response: None,
+            my_tasks_response: None,
+            workspace_tasks_response: None,
             list_members_response: None,
+            list_statuses_response: None,
+            folder_statuses_response: None,
+            send_request_response: None,
+            list_views_response: None,
+            workspace_views_response: None,
+            view_task_pages_response: None,
+            sprint_overview_response: None,
+            task_time_entries_response: None,
+            workspace_time_entries_response: None,
+            time_in_status_response: None,
+            calls: std::sync::Mutex::new(Vec::new()),
+            task_page_requests: std::sync::Mutex::new(Vec::new()),
+            task_queries: std::sync::Mutex::new(Vec::new()),
+            comment_page_requests: std::sync::Mutex::new(Vec::new()),
+            task_requests: std::sync::Mutex::new(Vec::new()),
+            time_in_status_requests: std::sync::Mutex::new(Vec::new()),
+            document_requests: std::sync::Mutex::new(Vec::new()),
+            comment_create_requests: std::sync::Mutex::new(Vec::new()),
+            comment_update_requests: std::sync::Mutex::new(Vec::new()),
         }
     }
+
+    /// Mutating calls received so far, in order
+    pub fn recorded_calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Pages requested through get_tasks so far, in order
+    pub fn requested_task_pages(&self) -> Vec<u32> {
+        self.task_page_requests.lock().unwrap().clone()
+    }
+
+    /// Query strings sent through get_tasks so far, in order
+    pub fn requested_task_queries(&self) -> Vec<String> {
+        self.task_queries.lock().unwrap().clone()
+    }
+
+    /// Task IDs requested through get_task so far, in order
+    pub fn requested_task_ids(&self) -> Vec<String> {
+        self.task_requests.lock().unwrap().clone()
+    }
+
+    /// Task IDs requested through get_task_time_in_status so far, in order
+    pub fn requested_time_in_status(&self) -> Vec<String> {
+        self.time_in_status_requests.lock().unwrap().clone()
+    }
+
+    /// Bodies sent through create_comment and create_comment_reply so far, in order
+    pub fn requested_comment_creates(&self) -> Vec<CreateCommentRequest> {
+        self.comment_create_requests.lock().unwrap().clone()
+    }
+
+    /// Comment IDs and bodies sent through update_comment so far, in order
+    pub fn requested_comment_updates(&self) -> Vec<(String, UpdateCommentRequest)> {
+        self.comment_update_requests.lock().unwrap().clone()
+    }
+
+    /// Document IDs requested through get_document so far, in order
+    pub fn requested_documents(&self) -> Vec<String> {
+        self.document_requests.lock().unwrap().clone()
+    }
+
+    /// Cursor ids (`start_id`) requested through get_task_comments so far, in order
+    pub fn requested_comment_pages(&self) -> Vec<Option<i64>> {
+        self.comment_page_requests.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+
     /// Set the workspaces response
     pub fn with_workspaces(mut self, workspaces: Vec<Workspace>) -> Self {
         self.workspaces_response = Some(Ok(workspaces));
@@ -142,6 +290,43 @@ impl MockClickUpClient {
         self
     }
 
+    /// Set the space returned by create_space
+    pub fn with_create_space_response(mut self, space: ClickUpSpace) -> Self {
+        self.create_space_response = Some(Ok(space));
+        self
+    }
+
+    /// Set the space returned by update_space
+    pub fn with_update_space_response(mut self, space: ClickUpSpace) -> Self {
+        self.update_space_response = Some(Ok(space));
+        self
+    }
+
+    /// Make creating and updating spaces fail with `error`
+    pub fn with_space_error(mut self, error: &str) -> Self {
+        self.create_space_response = Some(Err(anyhow!(error.to_string())));
+        self.update_space_response = Some(Err(anyhow!(error.to_string())));
+        self
+    }
+
+    /// Set the folder returned by create_folder
+    pub fn with_create_folder_response(mut self, folder: Folder) -> Self {
+        self.create_folder_response = Some(Ok(folder));
+        self
+    }
+
+    /// Make creating, deleting and adding tags fail with `error`
+    pub fn with_tag_error(mut self, error: String) -> Self {
+        self.tag_error = Some(error);
+        self
+    }
+
+    /// Make adding and removing task links fail with `error`
+    pub fn with_task_link_error(mut self, error: String) -> Self {
+        self.task_link_error = Some(error);
+        self
+    }
+
     /// Set the lists in folder response
     pub fn with_lists_in_folder(mut self, lists: Vec<List>) -> Self {
         self.lists_in_folder_response = Some(Ok(lists));
@@ -160,12 +345,31 @@ impl MockClickUpClient {
         self
     }
 
+    /// Set paged tasks responses; pages past the end are empty
+    pub fn with_task_pages(mut self, pages: Vec<Vec<Task>>) -> Self {
+        self.task_pages_response = Some(pages);
+        self
+    }
+
     /// Set the task response
     pub fn with_task(mut self, task: Task) -> Self {
         self.task_response = Some(Ok(task));
         self
     }
 
+    /// Make get_task fail with `error` for IDs not set with
+    /// [`with_task_by_id`](Self::with_task_by_id)
+    pub fn with_task_error(mut self, error: String) -> Self {
+        self.task_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Return `task` from get_task for its own ID
+    pub fn with_task_by_id(mut self, task: Task) -> Self {
+        self.tasks_by_id.insert(task.id.clone(), task);
+        self
+    }
+
     /// Set the create task response
     pub fn with_create_task_response(mut self, task: Task) -> Self {
         self.create_task_response = Some(Ok(task));
@@ -196,12 +400,30 @@ impl MockClickUpClient {
         self
     }
 
+    /// Make deleting `task_id` fail while other deletions succeed
+    pub fn with_delete_task_failure_for(mut self, task_id: &str) -> Self {
+        self.delete_task_failures.insert(task_id.to_string());
+        self
+    }
+
+    /// Make updating `task_id` fail while other updates succeed
+    pub fn with_update_task_failure_for(mut self, task_id: &str) -> Self {
+        self.update_task_failures.insert(task_id.to_string());
+        self
+    }
+
     /// Set the documents response
     pub fn with_documents(mut self, documents: Vec<Document>) -> Self {
         self.search_docs_response = Some(Ok(documents));
         self
     }
 
+    /// Set the search docs error
+    pub fn with_documents_error(mut self, error: String) -> Self {
+        self.search_docs_response = Some(Err(anyhow!(error)));
+        self
+    }
+
     /// Set the pages response
     pub fn with_pages(mut self, pages: Vec<Page>) -> Self {
         self.doc_pages_response = Some(Ok(pages));
@@ -214,18 +436,48 @@ impl MockClickUpClient {
         self
     }
 
+    /// Answer get_task_comments_updated_since with `comments`
+    pub fn with_comments_updated_since(mut self, comments: Vec<Comment>) -> Self {
+        self.comments_updated_since_response = Some(comments);
+        self
+    }
+
     /// Set the create comment response
     pub fn with_create_comment_response(mut self, comment: Comment) -> Self {
         self.create_comment_response = Some(Ok(comment));
         self
     }
 
+    /// Fail create_comment with `msg`
+    pub fn with_create_comment_error(mut self, msg: &str) -> Self {
+        self.create_comment_response = Some(Err(anyhow!("{}", msg)));
+        self
+    }
+
     /// Set the update comment response
     pub fn with_update_comment_response(mut self, comment: Comment) -> Self {
         self.update_comment_response = Some(Ok(comment));
         self
     }
 
+    /// Fail update_comment with `msg`
+    pub fn with_update_comment_error(mut self, msg: &str) -> Self {
+        self.update_comment_response = Some(Err(anyhow!("{}", msg)));
+        self
+    }
+
+    /// Set the delete comment response to succeed
+    pub fn with_delete_comment_success(mut self) -> Self {
+        self.delete_comment_response = Some(Ok(()));
+        self
+    }
+
+    /// Set the delete comment error
+    pub fn with_delete_comment_error(mut self, msg: &str) -> Self {
+        self.delete_comment_response = Some(Err(anyhow!("{}", msg)));
+        self
+    }
+
     /// Set the comment replies response for a specific comment
     pub fn with_comment_replies(mut self, comment_id: &str, replies: Vec<Comment>) -> Self {
         if self.comment_replies_response.is_none() {
@@ -254,6 +506,132 @@ impl MockClickUpClient {
         self.list_members_response = Some(Err(anyhow!(error)));
         self
     }
+
+    /// Set the list statuses response
+    pub fn with_list_statuses(mut self, statuses: Vec<TaskStatus>) -> Self {
+        self.list_statuses_response = Some(Ok(statuses));
+        self
+    }
+
+    /// Set the list statuses error
+    pub fn with_list_statuses_error(mut self, error: String) -> Self {
+        self.list_statuses_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the folder statuses response
+    pub fn with_folder_statuses(mut self, statuses: Vec<TaskStatus>) -> Self {
+        self.folder_statuses_response = Some(Ok(statuses));
+        self
+    }
+
+    /// Make send_request succeed
+    pub fn with_send_request_success(mut self) -> Self {
+        self.send_request_response = Some(Ok(()));
+        self
+    }
+
+    /// Set the send_request error
+    pub fn with_send_request_error(mut self, msg: &str) -> Self {
+        self.send_request_response = Some(Err(anyhow!("{}", msg)));
+        self
+    }
+
+    /// Set the views returned for any list
+    pub fn with_list_views(mut self, views: Vec<View>) -> Self {
+        self.list_views_response = Some(Ok(views));
+        self
+    }
+
+    /// Set the list views error
+    pub fn with_list_views_error(mut self, error: String) -> Self {
+        self.list_views_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the views returned for any workspace
+    pub fn with_workspace_views(mut self, views: Vec<WorkspaceView>) -> Self {
+        self.workspace_views_response = Some(Ok(views));
+        self
+    }
+
+    /// Set the workspace views error
+    pub fn with_workspace_views_error(mut self, error: String) -> Self {
+        self.workspace_views_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the pages of tasks shown in any view
+    pub fn with_view_task_pages(mut self, pages: Vec<Vec<Task>>) -> Self {
+        self.view_task_pages_response = Some(Ok(pages));
+        self
+    }
+
+    /// Set the view tasks error
+    pub fn with_view_tasks_error(mut self, error: String) -> Self {
+        self.view_task_pages_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the tasks assigned to the current user
+    pub fn with_my_tasks(mut self, tasks: Vec<Task>) -> Self {
+        self.my_tasks_response = Some(Ok(tasks));
+        self
+    }
+
+    /// Set the assigned tasks error
+    pub fn with_my_tasks_error(mut self, error: String) -> Self {
+        self.my_tasks_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the tasks of the whole workspace, across every list
+    pub fn with_workspace_tasks(mut self, tasks: Vec<Task>) -> Self {
+        self.workspace_tasks_response = Some(Ok(tasks));
+        self
+    }
+
+    /// Set the sprint overview response
+    pub fn with_sprint_overview(mut self, overview: SprintOverview) -> Self {
+        self.sprint_overview_response = Some(Ok(overview));
+        self
+    }
+
+    /// Set the sprint overview error
+    pub fn with_sprint_overview_error(mut self, error: String) -> Self {
+        self.sprint_overview_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Set the time entries returned for any task
+    pub fn with_task_time_entries(mut self, entries: Vec<TimeEntry>) -> Self {
+        self.task_time_entries_response = Some(Ok(entries));
+        self
+    }
+
+    /// Set the task time entries error
+    pub fn with_task_time_entries_error(mut self, error: String) -> Self {
+        self.task_time_entries_response = Some(Err(anyhow!(error)));
+        self
+    }
+
+    /// Fail get_time_entries_for_user with `error`
+    pub fn with_workspace_time_entries_error(mut self, error: &str) -> Self {
+        self.workspace_time_entries_response = Some(Err(anyhow!(error.to_string())));
+        self
+    }
+
+    /// Set the workspace time entries (filtered by range and user on request)
+    pub fn with_workspace_time_entries(mut self, entries: Vec<TimeEntry>) -> Self {
+        self.workspace_time_entries_response = Some(Ok(entries));
+        self
+    }
+
+    /// Set the time in status returned for any task
+    pub fn with_time_in_status(mut self, durations: Vec<TimeInStatus>) -> Self {
+        self.time_in_status_response = Some(Ok(durations));
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -292,10 +670,106 @@ impl ClickUpApi for MockClickUpClient {
         }
     }
 
+    async fn create_space(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<ClickUpSpace> {
+        self.record(format!(
+            "create_space {} {} private={}",
+            workspace_id, name, private
+        ));
+        return_response(&self.create_space_response, "Create space not configured")
+    }
+
+    async fn update_space(
+        &self,
+        space_id: &str,
+        update: &UpdateSpaceRequest,
+    ) -> Result<ClickUpSpace> {
+        self.record(format!(
+            "update_space {} {}",
+            space_id,
+            serde_json::to_string(update)?
+        ));
+        return_response(&self.update_space_response, "Update space not configured")
+    }
+
+    async fn create_space_tag(
+        &self,
+        space_id: &str,
+        name: &str,
+        bg_color: &str,
+        fg_color: &str,
+    ) -> Result<Tag> {
+        self.record(format!(
+            "create_space_tag {} {} {} {}",
+            space_id, name, bg_color, fg_color
+        ));
+        if let Some(error) = &self.tag_error {
+            return Err(anyhow!(error.clone()));
+        }
+        Ok(Tag {
+            id: None,
+            name: name.to_string(),
+            color: None,
+            tag_fg: Some(fg_color.to_string()),
+            tag_bg: Some(bg_color.to_string()),
+            creator: None,
+        })
+    }
+
+    async fn delete_space_tag(&self, space_id: &str, tag_name: &str) -> Result<()> {
+        self.record(format!("delete_space_tag {} {}", space_id, tag_name));
+        match &self.tag_error {
+            Some(error) => Err(anyhow!(error.clone())),
+            None => Ok(()),
+        }
+    }
+
+    async fn tag_task(&self, task_id: &str, tag_name: &str) -> Result<()> {
+        self.record(format!("tag_task {} {}", task_id, tag_name));
+        match &self.tag_error {
+            Some(error) => Err(anyhow!(error.clone())),
+            None => Ok(()),
+        }
+    }
+
+    async fn add_task_link(&self, task_id: &str, links_to_task_id: &str) -> Result<LinkedTask> {
+        self.record(format!("add_task_link {} {}", task_id, links_to_task_id));
+        if let Some(error) = &self.task_link_error {
+            return Err(anyhow!(error.clone()));
+        }
+        Ok(LinkedTask {
+            id: None,
+            task_id: Some(task_id.to_string()),
+            link_id: Some(links_to_task_id.to_string()),
+            name: None,
+            status: None,
+            date_created: None,
+            userid: None,
+            workspace_id: None,
+        })
+    }
+
+    async fn remove_task_link(&self, task_id: &str, link_id: &str) -> Result<()> {
+        self.record(format!("remove_task_link {} {}", task_id, link_id));
+        match &self.task_link_error {
+            Some(error) => Err(anyhow!(error.clone())),
+            None => Ok(()),
+        }
+    }
+
     async fn get_folders(&self, _space_id: &str) -> Result<Vec<Folder>> {
         return_vec_response(&self.folders_response)
     }
 
+    async fn create_folder(&self, space_id: &str, name: &str) -> Result<Folder> {
+        self.record(format!("create_folder {} {}", space_id, name));
+        return_response(&self.create_folder_response, "Create folder not configured")
+    }
+
     async fn get_lists_in_folder(
         &self,
         _folder_id: &str,
@@ -312,19 +786,64 @@ impl ClickUpApi for MockClickUpClient {
         return_vec_response(&self.lists_in_space_response)
     }
 
-    async fn get_tasks(&self, _list_id: &str, _filters: &TaskFilters) -> Result<Vec<Task>> {
+    async fn get_list(&self, list_id: &str) -> Result<List> {
+        self.record(format!("get_list {}", list_id));
+        [&self.lists_in_folder_response, &self.lists_in_space_response]
+            .into_iter()
+            .flatten()
+            .filter_map(|lists| lists.as_ref().ok())
+            .flatten()
+            .find(|list| list.id == list_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("List not found"))
+    }
+
+    async fn get_tasks(&self, _list_id: &str, filters: &TaskFilters) -> Result<Vec<Task>> {
+        let page = filters.page.unwrap_or(0);
+        self.task_page_requests.lock().unwrap().push(page);
+        self.task_queries
+            .lock()
+            .unwrap()
+            .push(filters.to_query_string());
+        if let Some(pages) = &self.task_pages_response {
+            return Ok(pages.get(page as usize).cloned().unwrap_or_default());
+        }
         return_vec_response(&self.tasks_response)
     }
 
-    async fn get_task(&self, _task_id: &str) -> Result<Task> {
+    async fn get_task(&self, task_id: &str) -> Result<Task> {
+        self.task_requests.lock().unwrap().push(task_id.to_string());
+        if let Some(task) = self.tasks_by_id.get(task_id) {
+            return Ok(task.clone());
+        }
         return_response(&self.task_response, "Task not found")
     }
 
-    async fn create_task(&self, _list_id: &str, _task: &CreateTaskRequest) -> Result<Task> {
+    async fn get_task_by_custom_id(&self, custom_id: &str, team_id: &str) -> Result<Task> {
+        self.record(format!("get_task_by_custom_id {} {}", custom_id, team_id));
+        self.tasks_by_id
+            .values()
+            .find(|task| task.custom_id.as_deref() == Some(custom_id))
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Task {} not found", custom_id))
+    }
+
+    async fn create_task(&self, list_id: &str, _task: &CreateTaskRequest) -> Result<Task> {
+        self.record(format!("create_task {}", list_id));
         return_response(&self.create_task_response, "Create task not configured")
     }
 
-    async fn update_task(&self, _task_id: &str, update: &UpdateTaskRequest) -> Result<Task> {
+    async fn update_task(&self, task_id: &str, update: &UpdateTaskRequest) -> Result<Task> {
+        match (&update.status, update.archived) {
+            (Some(status), _) => self.record(format!("update_task {} status={}", task_id, status)),
+            (None, Some(archived)) => {
+                self.record(format!("update_task {} archived={}", task_id, archived))
+            }
+            (None, None) => self.record(format!("update_task {}", task_id)),
+        }
+        if self.update_task_failures.contains(task_id) {
+            anyhow::bail!("Task {} could not be updated", task_id);
+        }
         let mut task = return_response(&self.update_task_response, "Update task not configured")?;
 
         // Merge update request fields into the task
@@ -362,11 +881,29 @@ impl ClickUpApi for MockClickUpClient {
         if let Some(due) = update.due_date {
             task.due_date = Some(due);
         }
+        if let Some(archived) = update.archived {
+            task.archived = Some(archived);
+        }
 
         Ok(task)
     }
 
-    async fn delete_task(&self, _task_id: &str) -> Result<()> {
+    async fn set_task_recurrence(&self, task_id: &str, config: &RecurrenceConfig) -> Result<Task> {
+        self.record(format!(
+            "set_task_recurrence {} {}",
+            task_id,
+            config.label()
+        ));
+        let mut task = return_response(&self.update_task_response, "Update task not configured")?;
+        task.recurrence = Some(config.clone());
+        Ok(task)
+    }
+
+    async fn delete_task(&self, task_id: &str) -> Result<()> {
+        self.record(format!("delete_task {}", task_id));
+        if self.delete_task_failures.contains(task_id) {
+            anyhow::bail!("Task {} could not be deleted", task_id);
+        }
         match &self.delete_task_response {
             Some(Ok(json)) => {
                 // Parse the JSON body like the real client does
@@ -382,8 +919,41 @@ impl ClickUpApi for MockClickUpClient {
         }
     }
 
-    async fn search_docs(&self, _filters: &DocumentFilters) -> Result<Vec<Document>> {
-        return_vec_response(&self.search_docs_response)
+    async fn search_docs(&self, filters: &DocumentFilters) -> Result<Vec<Document>> {
+        let docs = return_vec_response(&self.search_docs_response)?;
+
+        // Honour the space filter for documents that carry a space reference
+        Ok(match &filters.space_id {
+            Some(space_id) => docs
+                .into_iter()
+                .filter(|d| d.space.as_ref().is_none_or(|s| &s.id == space_id))
+                .collect(),
+            None => docs,
+        })
+    }
+
+    async fn get_document(&self, doc_id: &str) -> Result<Document> {
+        self.document_requests
+            .lock()
+            .unwrap()
+            .push(doc_id.to_string());
+        // Documents come from the search response, pages from the pages response
+        let mut doc = return_vec_response(&self.search_docs_response)?
+            .into_iter()
+            .find(|d| d.id == doc_id)
+            .ok_or_else(|| anyhow!("Document not found: {}", doc_id))?;
+        if doc.pages.is_empty() {
+            doc.pages = return_vec_response(&self.doc_pages_response)?;
+        }
+        Ok(doc)
+    }
+
+    async fn get_document_page(&self, _doc_id: &str, page_id: &str) -> Result<Page> {
+        let pages = return_vec_response(&self.doc_pages_response)?;
+        match pages.into_iter().find(|p| p.id == page_id) {
+            Some(page) => Ok(page),
+            None => return_response(&self.page_response, "Page not found"),
+        }
     }
 
     async fn get_doc_pages(&self, _doc_id: &str) -> Result<Vec<Page>> {
@@ -394,11 +964,41 @@ impl ClickUpApi for MockClickUpClient {
         return_response(&self.page_response, "Page not found")
     }
 
-    async fn get_task_comments(&self, _task_id: &str) -> Result<Vec<Comment>> {
-        return_vec_response(&self.task_comments_response)
+    async fn get_task_comments(
+        &self,
+        _task_id: &str,
+        start: Option<CommentsCursor>,
+        limit: Option<u32>,
+    ) -> Result<CommentsPage> {
+        self.comment_page_requests
+            .lock()
+            .unwrap()
+            .push(start.map(|cursor| cursor.start_id));
+        let comments = return_vec_response(&self.task_comments_response)?;
+        // Like the API, a page starts after the comment with the cursor id
+        let skip = match start {
+            Some(cursor) => comments
+                .iter()
+                .position(|c| c.id == cursor.start_id.to_string())
+                .map_or(comments.len(), |i| i + 1),
+            None => 0,
+        };
+        let page_size = limit.unwrap_or(COMMENTS_PAGE_SIZE) as usize;
+        let batch = comments.into_iter().skip(skip).take(page_size).collect();
+        Ok(CommentsPage::from_batch(batch, limit))
+    }
+
+    async fn get_task_comments_updated_since(
+        &self,
+        task_id: &str,
+        since: i64,
+    ) -> Result<Option<Vec<Comment>>> {
+        self.record(format!("get_task_comments_updated_since {} {}", task_id, since));
+        Ok(self.comments_updated_since_response.clone())
     }
 
     async fn get_comment_replies(&self, comment_id: &str) -> Result<Vec<Comment>> {
+        self.record(format!("get_comment_replies {}", comment_id));
         match &self.comment_replies_response {
             Some(map) => match map.get(comment_id) {
                 Some(Ok(replies)) => Ok(replies.clone()),
@@ -409,11 +1009,37 @@ impl ClickUpApi for MockClickUpClient {
         }
     }
 
+    /// Found among the configured task comments and replies
+    async fn get_comment(&self, comment_id: &str) -> Result<Comment> {
+        self.record(format!("get_comment {}", comment_id));
+        let comments = return_vec_response(&self.task_comments_response)?;
+        let replies = self
+            .comment_replies_response
+            .iter()
+            .flat_map(|map| map.values())
+            .filter_map(|replies| replies.as_ref().ok())
+            .flatten();
+        comments
+            .iter()
+            .chain(replies)
+            .find(|c| c.id == comment_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Comment {} not found", comment_id))
+    }
+
     async fn create_comment(
         &self,
-        _task_id: &str,
-        _comment: &CreateCommentRequest,
+        task_id: &str,
+        comment: &CreateCommentRequest,
     ) -> Result<Comment> {
+        self.record(format!(
+            "create_comment {} {}",
+            task_id, comment.comment_text
+        ));
+        self.comment_create_requests
+            .lock()
+            .unwrap()
+            .push(comment.clone());
         return_response(
             &self.create_comment_response,
             "Create comment not configured",
@@ -422,9 +1048,17 @@ impl ClickUpApi for MockClickUpClient {
 
     async fn create_comment_reply(
         &self,
-        _parent_comment_id: &str,
-        _comment: &CreateCommentRequest,
+        parent_comment_id: &str,
+        comment: &CreateCommentRequest,
     ) -> Result<Comment> {
+        self.record(format!(
+            "create_comment_reply {} {}",
+            parent_comment_id, comment.comment_text
+        ));
+        self.comment_create_requests
+            .lock()
+            .unwrap()
+            .push(comment.clone());
         return_response(
             &self.create_comment_reply_response,
             "Create comment reply not configured",
@@ -433,15 +1067,85 @@ impl ClickUpApi for MockClickUpClient {
 
     async fn update_comment(
         &self,
-        _comment_id: &str,
-        _comment: &UpdateCommentRequest,
+        comment_id: &str,
+        comment: &UpdateCommentRequest,
     ) -> Result<Comment> {
+        self.record(format!("update_comment {}", comment_id));
+        self.comment_update_requests
+            .lock()
+            .unwrap()
+            .push((comment_id.to_string(), comment.clone()));
         return_response(
             &self.update_comment_response,
             "Update comment not configured",
         )
     }
 
+    async fn delete_comment(&self, comment_id: &str) -> Result<()> {
+        self.record(format!("delete_comment {}", comment_id));
+        match &self.delete_comment_response {
+            Some(Ok(())) => Ok(()),
+            Some(Err(e)) => Err(anyhow!(e.to_string())),
+            None => Err(anyhow!("Delete comment not configured")),
+        }
+    }
+
+    async fn send_request(&self, method: &str, url: &str, _body_json: Option<&str>) -> Result<()> {
+        self.record(format!("send_request {} {}", method, url));
+        return_unit_response(&self.send_request_response, "Send request not configured")
+    }
+
+    async fn get_list_views(&self, _list_id: &str) -> Result<Vec<View>> {
+        return_vec_response(&self.list_views_response)
+    }
+
+    async fn get_workspace_views(&self, workspace_id: &str) -> Result<Vec<WorkspaceView>> {
+        self.record(format!("get_workspace_views {}", workspace_id));
+        return_vec_response(&self.workspace_views_response)
+    }
+
+    async fn get_view_tasks_page(&self, view_id: &str, page: u32) -> Result<ViewTasksResponse> {
+        self.record(format!("get_view_tasks_page {} {}", view_id, page));
+        let pages = return_vec_response(&self.view_task_pages_response)?;
+        Ok(ViewTasksResponse {
+            tasks: pages.get(page as usize).cloned().unwrap_or_default(),
+            last_page: page as usize + 1 >= pages.len(),
+        })
+    }
+
+    async fn get_sprint_overview(&self, _view_id: &str) -> Result<SprintOverview> {
+        return_response(&self.sprint_overview_response, "Sprint view not found")
+    }
+
+    async fn get_time_entries(&self, _task_id: &str) -> Result<Vec<TimeEntry>> {
+        return_vec_response(&self.task_time_entries_response)
+    }
+
+    async fn get_task_time_in_status(&self, task_id: &str) -> Result<Vec<TimeInStatus>> {
+        self.time_in_status_requests
+            .lock()
+            .unwrap()
+            .push(task_id.to_string());
+        return_vec_response(&self.time_in_status_response)
+    }
+
+    async fn get_time_entries_for_user(
+        &self,
+        _workspace_id: &str,
+        from: i64,
+        to: i64,
+        user_id: Option<&str>,
+    ) -> Result<Vec<TimeEntry>> {
+        let entries = return_vec_response(&self.workspace_time_entries_response)?;
+        Ok(entries
+            .into_iter()
+            .filter(|e| e.start.is_some_and(|start| start >= from && start <= to))
+            .filter(|e| {
+                user_id.is_none_or(|id| e.user.as_ref().is_some_and(|u| u.id.to_string() == id))
+            })
+            .collect())
+    }
+
     async fn get_tasks_with_assignee(
         &self,
         _list_id: &str,
@@ -451,7 +1155,42 @@ impl ClickUpApi for MockClickUpClient {
         return_vec_response(&self.tasks_with_assignee_response)
     }
 
+    async fn get_my_tasks(&self, _workspace_id: &str, _filters: &TaskFilters) -> Result<Vec<Task>> {
+        return_vec_response(&self.my_tasks_response)
+    }
+
+    async fn get_tasks_for_workspace(
+        &self,
+        _workspace_id: &str,
+        filters: &TaskFilters,
+    ) -> Result<Vec<Task>> {
+        self.task_queries
+            .lock()
+            .unwrap()
+            .push(filters.to_query_string());
+        let tasks = return_vec_response(&self.workspace_tasks_response)?;
+        Ok(tasks
+            .into_iter()
+            .filter(|task| {
+                filters.assignees.is_empty()
+                    || task
+                        .assignees
+                        .iter()
+                        .any(|user| filters.assignees.contains(&user.id))
+            })
+            .collect())
+    }
+
     async fn get_list_members(&self, _list_id: &str) -> Result<Vec<User>> {
         return_vec_response(&self.list_members_response)
     }
+
+    async fn get_list_statuses(&self, _list_id: &str) -> Result<Vec<TaskStatus>> {
+        return_vec_response(&self.list_statuses_response)
+    }
+
+    async fn get_folder_statuses(&self, folder_id: &str) -> Result<Vec<TaskStatus>> {
+        self.record(format!("get_folder_statuses {}", folder_id));
+        return_vec_response(&self.folder_statuses_response)
+    }
 }
@@ -1,11 +1,16 @@
 //! ClickUp API client module
 
 pub mod auth;
+pub mod caching_client;
 pub mod client;
 pub mod client_trait;
 pub mod endpoints;
 pub mod mock_client;
+pub mod request_queue;
+pub mod task_stream;
 
 pub use auth::AuthManager;
+pub use caching_client::CachingClient;
 pub use client::ClickUpClient;
 pub use client_trait::ClickUpApi;
+pub use request_queue::drain_queue;
@@ -0,0 +1,181 @@
+//! Writes made while offline, and their replay
+//!
+//! When a [`ClickUpClient`](super::ClickUpClient) has a request queue, task
+//! and comment writes that fail because ClickUp can't be reached are saved to
+//! the cache database instead and reported as [`QueuedOffline`].
+//! [`drain_queue`] sends them once the network is back.
+
+use crate::api::ClickUpApi;
+use crate::cache::{CacheManager, QueuedRequest};
+use anyhow::Result;
+
+/// A write that couldn't reach ClickUp and was queued to be sent later
+#[derive(Debug, thiserror::Error)]
+#[error("Offline: {method} {url} queued, run `clickdown debug drain-queue` once back online")]
+pub struct QueuedOffline {
+    pub method: String,
+    pub url: String,
+}
+
+/// Whether a request failed because ClickUp couldn't be reached at all
+/// (no connection or a timeout), as opposed to ClickUp answering with an error
+pub fn is_offline_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || e.is_timeout())
+    })
+}
+
+/// Whether a request failed without saying whether ClickUp acted on it: it
+/// timed out waiting for the answer, or a gateway in front of ClickUp gave
+/// up on it (502 or 504)
+///
+/// Such a write may have been made, so sending it again can repeat it.
+pub fn is_outcome_unknown(error: &anyhow::Error) -> bool {
+    let timed_out = error.chain().any(|cause| {
+        cause
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_timeout())
+    });
+    let text = format!("{:#}", error);
+    timed_out || ["API error (502", "API error (504"].iter().any(|s| text.contains(s))
+}
+
+/// What [`drain_queue`] did
+#[derive(Debug, Default)]
+pub struct DrainReport {
+    /// Requests ClickUp accepted
+    pub sent: usize,
+    /// Requests ClickUp rejected, with its error; they are not retried
+    pub rejected: Vec<(QueuedRequest, String)>,
+    /// Requests still queued because the network was down again
+    pub remaining: usize,
+}
+
+/// Send the queued requests oldest first, removing each once ClickUp answered
+///
+/// A request ClickUp rejects is dropped, since sending it again would fail
+/// the same way. Losing the network stops the drain with the rest still
+/// queued, in order.
+pub async fn drain_queue(api: &dyn ClickUpApi, cache: &mut CacheManager) -> Result<DrainReport> {
+    let queued = cache.queued_requests()?;
+    let mut report = DrainReport::default();
+    for (index, request) in queued.iter().enumerate() {
+        match api
+            .send_request(&request.method, &request.url, request.body_json.as_deref())
+            .await
+        {
+            Ok(()) => report.sent += 1,
+            Err(e) if is_offline_error(&e) => {
+                report.remaining = queued.len() - index;
+                break;
+            }
+            Err(e) => report.rejected.push((request.clone(), format!("{:#}", e))),
+        }
+        cache.remove_queued_request(request.id)?;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mock_client::MockClickUpClient;
+
+    fn test_cache(dir: &tempfile::TempDir) -> CacheManager {
+        CacheManager::new(dir.path().join("cache.db")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused_is_offline() {
+        // Nothing listens on port 1
+        let error = reqwest::get("http://127.0.0.1:1/").await.unwrap_err();
+        assert!(is_offline_error(
+            &anyhow::Error::new(error).context("Request failed")
+        ));
+        assert!(!is_offline_error(&anyhow::anyhow!(
+            "API error (404): not found"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_timeouts_and_gateway_errors_leave_the_outcome_unknown() {
+        // Takes the connection but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let _connection = listener.accept().await;
+            std::future::pending::<()>().await
+        });
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let error = client
+            .get(format!("http://{}/", addr))
+            .send()
+            .await
+            .unwrap_err();
+        let error = anyhow::Error::new(error).context("Request failed");
+        assert!(is_outcome_unknown(&error));
+        assert!(is_offline_error(&error));
+
+        assert!(is_outcome_unknown(&anyhow::anyhow!(
+            "API error (504 Gateway Timeout): upstream timed out"
+        )));
+        assert!(!is_outcome_unknown(&anyhow::anyhow!(
+            "API error (400 Bad Request): {{}}"
+        )));
+        let refused = reqwest::get("http://127.0.0.1:1/").await.unwrap_err();
+        assert!(!is_outcome_unknown(&anyhow::Error::new(refused)));
+    }
+
+    #[tokio::test]
+    async fn test_drain_sends_oldest_first_and_empties_queue() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = test_cache(&dir);
+        cache
+            .queue_request(
+                "POST",
+                "https://api.clickup.com/api/v2/task/t1/comment",
+                Some("{}"),
+            )
+            .unwrap();
+        cache
+            .queue_request("DELETE", "https://api.clickup.com/api/v2/task/t2", None)
+            .unwrap();
+        let mock = MockClickUpClient::new().with_send_request_success();
+
+        let report = drain_queue(&mock, &mut cache).await.unwrap();
+
+        assert_eq!(report.sent, 2);
+        assert!(report.rejected.is_empty());
+        assert_eq!(report.remaining, 0);
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                "send_request POST https://api.clickup.com/api/v2/task/t1/comment",
+                "send_request DELETE https://api.clickup.com/api/v2/task/t2",
+            ]
+        );
+        assert_eq!(cache.queued_request_count().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_drops_rejected_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut cache = test_cache(&dir);
+        cache
+            .queue_request("DELETE", "https://api.clickup.com/api/v2/task/gone", None)
+            .unwrap();
+        let mock = MockClickUpClient::new().with_send_request_error("API error (404): not found");
+
+        let report = drain_queue(&mock, &mut cache).await.unwrap();
+
+        assert_eq!(report.sent, 0);
+        assert_eq!(report.rejected.len(), 1);
+        assert!(report.rejected[0].1.contains("404"));
+        assert_eq!(cache.queued_request_count().unwrap(), 0);
+    }
+}
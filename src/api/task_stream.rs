@@ -0,0 +1,230 @@
+//! Tasks read one by one from a response body as it arrives
+//!
+//! A page of a large list takes a while to download in full. Instead of
+//! waiting for it, [`ClickUpClient::stream_tasks`](super::ClickUpClient::stream_tasks)
+//! feeds the body's chunks through a [`ChunkReader`] into [`read_tasks`],
+//! which hands over each element of the `tasks` array as soon as it is
+//! parsed.
+
+use crate::models::{SlimTask, Task};
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use std::fmt;
+use std::io::{self, Read};
+use std::sync::mpsc::Receiver;
+
+/// A blocking [`Read`] over body chunks sent from the async side
+///
+/// Reading ends when the sender is dropped; a chunk that is an error fails
+/// the read with it, and the body ends there.
+pub struct ChunkReader {
+    chunks: Receiver<io::Result<Vec<u8>>>,
+    current: Vec<u8>,
+    offset: usize,
+    failed: bool,
+}
+
+impl ChunkReader {
+    pub fn new(chunks: Receiver<io::Result<Vec<u8>>>) -> Self {
+        Self {
+            chunks,
+            current: Vec::new(),
+            offset: 0,
+            failed: false,
+        }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.offset == self.current.len() {
+            if self.failed {
+                return Ok(0);
+            }
+            match self.chunks.recv() {
+                Ok(Ok(chunk)) => {
+                    self.current = chunk;
+                    self.offset = 0;
+                }
+                Ok(Err(e)) => {
+                    self.failed = true;
+                    return Err(e);
+                }
+                Err(_) => return Ok(0),
+            }
+        }
+        let n = buf.len().min(self.current.len() - self.offset);
+        buf[..n].copy_from_slice(&self.current[self.offset..self.offset + n]);
+        self.offset += n;
+        Ok(n)
+    }
+}
+
+/// Parse a `{"tasks": [...]}` body from `reader`, passing each task to
+/// `on_task` as soon as it is read. Other fields are skipped, and with
+/// `slim` so are the custom field payloads a [`SlimTask`] leaves out.
+///
+/// Parsing stops early, with an error, when `on_task` returns false.
+pub fn read_tasks<R: Read>(
+    reader: R,
+    slim: bool,
+    on_task: impl FnMut(Task) -> bool,
+) -> Result<(), serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    deserializer.deserialize_map(ResponseVisitor { slim, on_task })?;
+    deserializer.end()
+}
+
+/// The response object: its `tasks` go to `on_task`
+struct ResponseVisitor<F> {
+    slim: bool,
+    on_task: F,
+}
+
+impl<'de, F: FnMut(Task) -> bool> Visitor<'de> for ResponseVisitor<F> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a tasks response object")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(mut self, mut map: A) -> Result<(), A::Error> {
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "tasks" {
+                map.next_value_seed(TasksSeed {
+                    slim: self.slim,
+                    on_task: &mut self.on_task,
+                })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The `tasks` array, or null for none
+struct TasksSeed<'f, F> {
+    slim: bool,
+    on_task: &'f mut F,
+}
+
+impl<'de, F: FnMut(Task) -> bool> DeserializeSeed<'de> for TasksSeed<'_, F> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, F: FnMut(Task) -> bool> Visitor<'de> for TasksSeed<'_, F> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of tasks")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        loop {
+            let task = if self.slim {
+                seq.next_element::<SlimTask>()
+                    .map(|task| task.map(|SlimTask(task)| task))
+            } else {
+                seq.next_element::<Task>()
+            };
+            let task = task
+                .map_err(|e| de::Error::custom(format_args!("tasks[{}]: {}", index, e)))?;
+            let Some(task) = task else {
+                return Ok(());
+            };
+            if !(self.on_task)(task) {
+                return Err(de::Error::custom("task stream closed"));
+            }
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    fn body() -> String {
+        serde_json::json!({
+            "tasks": [
+                { "id": "1", "name": "First" },
+                { "id": "2", "name": "Second" },
+                { "id": "3", "name": "Third" }
+            ],
+            "last_page": true
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_read_tasks_across_chunk_boundaries() {
+        // Chunks split mid-token, the way a slow response arrives
+        let (tx, rx) = mpsc::channel();
+        for chunk in body().as_bytes().chunks(7) {
+            tx.send(Ok(chunk.to_vec())).unwrap();
+        }
+        drop(tx);
+
+        let mut names = Vec::new();
+        read_tasks(ChunkReader::new(rx), false, |task| {
+            names.push(task.name);
+            true
+        })
+        .unwrap();
+        assert_eq!(names, ["First", "Second", "Third"]);
+    }
+
+    #[test]
+    fn test_read_tasks_passes_on_tasks_before_a_bad_one() {
+        let body = r#"{"tasks": [{"id": "1", "name": "First"}, {"id": 2, "name": []}]}"#;
+        let mut ids = Vec::new();
+        let err = read_tasks(body.as_bytes(), false, |task| {
+            ids.push(task.id);
+            true
+        })
+        .unwrap_err();
+        assert_eq!(ids, ["1"]);
+        assert!(err.to_string().starts_with("tasks[1]:"), "{}", err);
+    }
+
+    #[test]
+    fn test_read_tasks_stops_when_the_receiver_is_gone() {
+        let mut seen = 0;
+        let result = read_tasks(body().as_bytes(), false, |_| {
+            seen += 1;
+            false
+        });
+        assert!(result.is_err());
+        assert_eq!(seen, 1);
+    }
+
+    #[test]
+    fn test_read_tasks_null_and_cut_off_bodies() {
+        read_tasks(r#"{"tasks": null}"#.as_bytes(), false, |_| panic!("no tasks")).unwrap();
+
+        // A connection dropped mid-body is an error, after the tasks read so far
+        let (tx, rx) = mpsc::channel();
+        let body = body();
+        tx.send(Ok(body.as_bytes()[..60].to_vec())).unwrap();
+        tx.send(Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset")))
+            .unwrap();
+        let mut seen = 0;
+        let err = read_tasks(ChunkReader::new(rx), false, |_| {
+            seen += 1;
+            true
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("reset"), "{}", err);
+        assert_eq!(seen, 1);
+    }
+}
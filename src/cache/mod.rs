@@ -2,15 +2,36 @@
 
 pub mod schema;
 
+use crate::config::CommentOrder;
 #[allow(dead_code)]
-use crate::models::{Comment, SessionState};
+use crate::models::{Comment, ListReference, Priority, SessionState, Task, TaskStatus, Workspace};
+use crate::utils::deserializers::from_json_str;
 use anyhow::{Context, Result};
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// A write request saved while offline, to be sent later
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedRequest {
+    pub id: i64,
+    /// HTTP method, e.g. "POST"
+    pub method: String,
+    pub url: String,
+    /// Request body, if it has one
+    pub body_json: Option<String>,
+    /// When it was queued (seconds since the Unix epoch)
+    pub queued_at: i64,
+}
 
 /// Cache manager for storing ClickUp data locally
+///
+/// Clones share one database connection, so the API client's request queue
+/// and comment cache can use the app's.
+#[derive(Clone)]
 pub struct CacheManager {
-    conn: Connection,
+    conn: Arc<Mutex<Connection>>,
 }
 
 impl CacheManager {
@@ -23,26 +44,111 @@ impl CacheManager {
 
         let conn = Connection::open(&db_path).context("Failed to open database")?;
 
-        let manager = Self { conn };
+        let manager = Self {
+            conn: Arc::new(Mutex::new(conn)),
+        };
         manager.init_schema()?;
         Ok(manager)
     }
 
+    /// The shared connection; a panic while it was held leaves it usable
+    fn conn(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Initialize the database schema
     fn init_schema(&self) -> Result<()> {
-        self.conn.execute_batch(schema::INIT_SQL)?;
+        self.conn().execute_batch(schema::INIT_SQL)?;
 
         // Migration: Add parent_id column to task_comments if it doesn't exist
         // SQLite doesn't support ADD COLUMN IF NOT EXISTS, so we catch the error
         let _ = self
-            .conn
+            .conn()
             .execute("ALTER TABLE task_comments ADD COLUMN parent_id TEXT", []);
 
+        // Migration: Add deleted_at column used to tombstone deleted comments
+        let _ = self
+            .conn()
+            .execute("ALTER TABLE task_comments ADD COLUMN deleted_at INTEGER", []);
+
+        // Migration: Add reply_count column so cached threads keep their reply badges
+        let _ = self
+            .conn()
+            .execute("ALTER TABLE task_comments ADD COLUMN reply_count INTEGER", []);
+
+        // Migration: Add fetched_at column so cached tasks can say how old they are
+        let _ = self
+            .conn()
+            .execute("ALTER TABLE tasks ADD COLUMN fetched_at INTEGER", []);
+
+        // Migration: Add the columns cached workspaces need to be shown as loaded
+        let _ = self
+            .conn()
+            .execute("ALTER TABLE workspaces ADD COLUMN member_count INTEGER", []);
+        let _ = self
+            .conn()
+            .execute("ALTER TABLE workspaces ADD COLUMN fetched_at INTEGER", []);
+
         Ok(())
     }
 
     // ==================== Workspaces ====================
 
+    /// Replace the cached workspaces with the ones just loaded
+    pub fn cache_workspaces(&mut self, workspaces: &[Workspace]) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM workspaces", [])?;
+        let now = unix_now();
+        for workspace in workspaces {
+            tx.execute(
+                "INSERT INTO workspaces (id, name, color, member_count, fetched_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    workspace.id,
+                    workspace.name,
+                    workspace.color,
+                    workspace.member_count,
+                    now
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Cached workspaces, in the order they were loaded
+    pub fn get_workspaces(&self) -> Result<Vec<Workspace>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare("SELECT id, name, color, member_count FROM workspaces ORDER BY rowid")?;
+        let workspaces = stmt.query_map([], |row| {
+            Ok(Workspace {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                color: row.get(2)?,
+                avatar: None,
+                member_count: row.get(3)?,
+            })
+        })?;
+        workspaces
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read cached workspaces")
+    }
+
+    /// Whether workspaces were cached less than `ttl_seconds` ago
+    pub fn is_workspace_cache_valid(&self, ttl_seconds: u64) -> Result<bool> {
+        let fetched_at: Option<i64> = self
+            .conn()
+            .query_row("SELECT MAX(fetched_at) FROM workspaces", [], |row| row.get(0))
+            .context("Failed to read cached workspace age")?;
+        Ok(fetched_at.is_some_and(|at| unix_now() - at < ttl_seconds as i64))
+    }
+
+    /// Forget the cached workspaces, e.g. when the token changes
+    pub fn clear_workspaces(&mut self) -> Result<()> {
+        self.conn().execute("DELETE FROM workspaces", [])?;
+        Ok(())
+    }
+
     // ==================== Spaces ====================
 
     // ==================== Folders ====================
@@ -51,57 +157,260 @@ impl CacheManager {
 
     // ==================== Tasks ====================
 
+    /// Cache the summary of a task shown in task lists
+    ///
+    /// Tasks without a list are skipped, since every cached task belongs to one.
+    /// The list is cached too if it isn't already, to satisfy the foreign key.
+    pub fn cache_task(&mut self, task: &Task) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        insert_task(&tx, task, unix_now())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Replace the cached tasks of a list with the ones just loaded
+    pub fn cache_list_tasks(&mut self, list_id: &str, tasks: &[Task]) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM tasks WHERE list_id = ?1", [list_id])?;
+        let now = unix_now();
+        for task in tasks {
+            insert_task(&tx, task, now)?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Cached task summaries of a list, as last loaded
+    pub fn get_list_tasks(&self, list_id: &str) -> Result<Vec<Task>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT t.id, t.list_id, l.name, t.name, t.status, t.priority, t.due_date, t.created_at, t.updated_at
+             FROM tasks t LEFT JOIN lists l ON l.id = t.list_id WHERE t.list_id = ?1 ORDER BY t.rowid",
+        )?;
+        let tasks = stmt.query_map([list_id], task_from_row)?;
+        tasks
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read cached tasks")
+    }
+
+    /// When the tasks of a list were last cached (seconds since the Unix epoch)
+    pub fn list_tasks_fetched_at(&self, list_id: &str) -> Result<Option<i64>> {
+        self.conn()
+            .query_row(
+                "SELECT MAX(fetched_at) FROM tasks WHERE list_id = ?1",
+                [list_id],
+                |row| row.get(0),
+            )
+            .context("Failed to read cached task age")
+    }
+
+    /// Name of a cached task, if it has been cached
+    pub fn get_task_name(&self, task_id: &str) -> Result<Option<String>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare("SELECT name FROM tasks WHERE id = ?1")?;
+        match stmt.query_row([task_id], |row| row.get(0)) {
+            Ok(name) => Ok(Some(name)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e).context("Failed to read cached task"),
+        }
+    }
+
+    /// The cached summary of a task, if it was cached less than `ttl_secs` ago
+    ///
+    /// Only the columns kept by [`cache_task`](Self::cache_task) are filled in.
+    pub fn get_task(&self, task_id: &str, ttl_secs: i64) -> Result<Option<Task>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT t.id, t.list_id, l.name, t.name, t.status, t.priority, t.due_date, t.created_at, t.updated_at
+             FROM tasks t LEFT JOIN lists l ON l.id = t.list_id WHERE t.id = ?1 AND t.fetched_at > ?2",
+        )?;
+        let row = stmt.query_row(params![task_id, unix_now() - ttl_secs], task_from_row);
+        match row {
+            Ok(task) => Ok(Some(task)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e).context("Failed to read cached task"),
+        }
+    }
+
+    /// Cache a folder's status workflow (empty when it uses the space's)
+    pub fn cache_folder_statuses(&mut self, folder_id: &str, statuses: &[TaskStatus]) -> Result<()> {
+        let json = serde_json::to_string(statuses).context("Failed to serialize folder statuses")?;
+        self.conn().execute(
+            "INSERT OR REPLACE INTO folder_statuses (folder_id, statuses, fetched_at) VALUES (?1, ?2, ?3)",
+            params![folder_id, json, unix_now()],
+        )?;
+        Ok(())
+    }
+
+    /// A folder's cached status workflow, or None if it was never cached
+    pub fn get_folder_statuses(&self, folder_id: &str) -> Result<Option<Vec<TaskStatus>>> {
+        let json: Option<String> = self
+            .conn()
+            .query_row(
+                "SELECT statuses FROM folder_statuses WHERE folder_id = ?1",
+                [folder_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        json.map(|json| from_json_str(&json).context("Failed to deserialize folder statuses"))
+            .transpose()
+    }
+
+    /// Cache a list's status workflow
+    pub fn cache_list_statuses(&mut self, list_id: &str, statuses: &[TaskStatus]) -> Result<()> {
+        let json = serde_json::to_string(statuses).context("Failed to serialize list statuses")?;
+        self.conn().execute(
+            "INSERT OR REPLACE INTO list_statuses (list_id, statuses, fetched_at) VALUES (?1, ?2, ?3)",
+            params![list_id, json, unix_now()],
+        )?;
+        Ok(())
+    }
+
+    /// A list's cached status workflow, or None if it was never cached
+    pub fn get_list_statuses(&self, list_id: &str) -> Result<Option<Vec<TaskStatus>>> {
+        let json: Option<String> = self
+            .conn()
+            .query_row(
+                "SELECT statuses FROM list_statuses WHERE list_id = ?1",
+                [list_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        json.map(|json| from_json_str(&json).context("Failed to deserialize list statuses"))
+            .transpose()
+    }
+
+    /// Record that a task was opened, replacing any earlier access time
+    pub fn record_task_access(&mut self, task_id: &str, list_id: &str) -> Result<()> {
+        self.conn().execute(
+            "INSERT OR REPLACE INTO task_access_log (task_id, list_id, accessed_at) VALUES (?1, ?2, ?3)",
+            params![task_id, list_id, unix_now_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// The most recently opened tasks as (task_id, list_id), newest first
+    pub fn get_recent_tasks(&self, limit: usize) -> Result<Vec<(String, String)>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT task_id, list_id FROM task_access_log ORDER BY accessed_at DESC, rowid DESC LIMIT ?1",
+        )?;
+        let recent = stmt.query_map([limit as i64], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        recent
+            .collect::<Result<Vec<_>, _>>()
+            .context("Failed to read recent tasks")
+    }
+
+    /// Record when a task's comments were last viewed (milliseconds since epoch)
+    pub fn set_last_viewed(&mut self, task_id: &str, ts: i64) -> Result<()> {
+        self.conn().execute(
+            "INSERT OR REPLACE INTO task_comment_views (task_id, viewed_at) VALUES (?1, ?2)",
+            params![task_id, ts],
+        )?;
+        Ok(())
+    }
+
+    /// When a task's comments were last viewed, if ever
+    pub fn get_last_viewed(&self, task_id: &str) -> Result<Option<i64>> {
+        self.conn()
+            .query_row(
+                "SELECT viewed_at FROM task_comment_views WHERE task_id = ?1",
+                [task_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("Failed to read last viewed time")
+    }
+
     // ==================== Comments ====================
 
     /// Cache comments for a task
     ///
     /// Stores comments in the task_comments table with the current timestamp.
-    /// Existing comments for the task are deleted before inserting new ones.
+    /// Existing comments for the task are deleted before inserting new ones,
+    /// except tombstoned comments, which stay deleted until purged. A comment
+    /// listed twice is stored once, as its last copy.
     #[allow(dead_code)]
     pub fn cache_comments(&mut self, task_id: &str, comments: &[Comment]) -> Result<()> {
-        let tx = self.conn.transaction()?;
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
 
-        // Delete existing comments for this task
-        tx.execute("DELETE FROM task_comments WHERE task_id = ?1", [task_id])?;
+        // Delete existing live comments for this task
+        tx.execute(
+            "DELETE FROM task_comments WHERE task_id = ?1 AND deleted_at IS NULL",
+            [task_id],
+        )?;
+        tx.execute("DELETE FROM task_comment_sync WHERE task_id = ?1", [task_id])?;
+        record_comment_sync(&tx, task_id, comments)?;
 
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
+        let now = unix_now();
 
         for comment in comments {
-            let commenter_id = comment.commenter.as_ref().map(|c| c.id);
-            let commenter_name = comment.commenter.as_ref().map(|c| c.username.clone());
+            upsert_comment(&tx, task_id, comment, now)?;
+        }
 
-            tx.execute(
-                "INSERT INTO task_comments (comment_id, task_id, text, commenter_id, commenter_name, created_at, updated_at, fetched_at, parent_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                params![
-                    comment.id,
-                    task_id,
-                    comment.text,
-                    commenter_id,
-                    commenter_name,
-                    comment.created_at,
-                    comment.updated_at,
-                    now,
-                    comment.parent_id,
-                ],
-            )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Merge comments changed since the last sync into a task's cache
+    ///
+    /// New comments are added and cached ones are updated in place; the
+    /// rest are kept, and all of them count as fetched now. Tombstoned
+    /// comments stay deleted.
+    pub fn merge_comments(&mut self, task_id: &str, changed: &[Comment]) -> Result<()> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        let now = unix_now();
+
+        for comment in changed {
+            upsert_comment(&tx, task_id, comment, now)?;
         }
+        tx.execute(
+            "UPDATE task_comments SET fetched_at = ?1 WHERE task_id = ?2 AND deleted_at IS NULL",
+            params![now, task_id],
+        )?;
+        record_comment_sync(&tx, task_id, changed)?;
 
         tx.commit()?;
         Ok(())
     }
 
+    /// Newest comment change (ms) seen for a task, None if its comments
+    /// have never been cached in full
+    pub fn comments_synced_through(&self, task_id: &str) -> Result<Option<i64>> {
+        self.conn()
+            .query_row(
+                "SELECT max_updated_at FROM task_comment_sync WHERE task_id = ?1",
+                [task_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("Failed to read comment sync time")
+    }
+
     /// Get cached comments for a task
     ///
     /// Retrieves comments from the task_comments table, ordered by created_at DESC.
-    /// Returns an empty vector if no comments are cached.
+    /// Tombstoned comments are excluded. Returns an empty vector if no comments are cached.
     #[allow(dead_code)]
     pub fn get_comments(&self, task_id: &str) -> Result<Vec<Comment>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT comment_id, text, commenter_id, commenter_name, created_at, updated_at, parent_id FROM task_comments WHERE task_id = ?1 ORDER BY created_at DESC",
-        )?;
+        self.get_comments_in_order(task_id, CommentOrder::Newest)
+    }
+
+    /// Get cached comments for a task, ordered by created_at in `order`
+    pub fn get_comments_in_order(&self, task_id: &str, order: CommentOrder) -> Result<Vec<Comment>> {
+        let direction = match order {
+            CommentOrder::Newest => "DESC",
+            CommentOrder::Oldest => "ASC",
+        };
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT comment_id, text, commenter_id, commenter_name, created_at, updated_at, parent_id, reply_count FROM task_comments WHERE task_id = ?1 AND deleted_at IS NULL ORDER BY created_at {}",
+            direction
+        ))?;
 
         let comments = stmt.query_map((task_id,), |row| {
             let commenter_id: Option<i64> = row.get(2)?;
@@ -131,6 +440,7 @@ impl CacheManager {
                 assigned: false,
                 reaction: String::new(),
                 parent_id: row.get(6)?,
+                reply_count: row.get(7)?,
             })
         })?;
 
@@ -145,29 +455,72 @@ impl CacheManager {
     /// Returns false if no comments are cached or if the cache has expired.
     #[allow(dead_code)]
     pub fn is_cache_valid(&self, task_id: &str, ttl_secs: i64) -> Result<bool> {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
-
-        let mut stmt = self
-            .conn
-            .prepare("SELECT MAX(fetched_at) FROM task_comments WHERE task_id = ?1")?;
-        let max_fetched: Option<i64> = stmt.query_row([task_id], |row| row.get(0))?;
-
-        match max_fetched {
-            Some(fetched_at) => Ok((now - fetched_at) < ttl_secs),
+        match self.comments_fetched_at(task_id)? {
+            Some(fetched_at) => Ok((unix_now() - fetched_at) < ttl_secs),
             None => Ok(false),
         }
     }
 
+    /// When a task's comments were last cached (seconds since the Unix epoch)
+    pub fn comments_fetched_at(&self, task_id: &str) -> Result<Option<i64>> {
+        self.conn()
+            .query_row(
+                "SELECT MAX(fetched_at) FROM task_comments WHERE task_id = ?1",
+                [task_id],
+                |row| row.get(0),
+            )
+            .context("Failed to read cached comment age")
+    }
+
+    /// Mark a cached comment as deleted
+    ///
+    /// The row is kept as a tombstone so that a refresh returning stale data
+    /// cannot resurrect it. Use `purge_deleted_comments` to remove tombstones.
+    pub fn mark_comment_deleted(&mut self, comment_id: &str) -> Result<()> {
+        self.conn().execute(
+            "UPDATE task_comments SET deleted_at = ?1 WHERE comment_id = ?2",
+            params![unix_now(), comment_id],
+        )?;
+        Ok(())
+    }
+
+    /// Permanently remove comments tombstoned longer than `older_than` ago
+    ///
+    /// Returns the number of rows removed.
+    #[allow(dead_code)]
+    pub fn purge_deleted_comments(&mut self, older_than: Duration) -> Result<usize> {
+        let cutoff = unix_now() - older_than.as_secs() as i64;
+        let purged = self.conn().execute(
+            "DELETE FROM task_comments WHERE deleted_at IS NOT NULL AND deleted_at <= ?1",
+            [cutoff],
+        )?;
+        Ok(purged)
+    }
+
     /// Clear cached comments for a task
     ///
     /// Removes all comments associated with the given task ID.
     #[allow(dead_code)]
     pub fn clear_comments(&mut self, task_id: &str) -> Result<()> {
-        self.conn
+        self.conn()
             .execute("DELETE FROM task_comments WHERE task_id = ?1", [task_id])?;
+        self.conn()
+            .execute("DELETE FROM task_comment_sync WHERE task_id = ?1", [task_id])?;
+        Ok(())
+    }
+
+    /// Clear the cached comments of the task a comment belongs to
+    ///
+    /// Used when a comment changes and only its ID is known.
+    pub fn clear_comments_containing(&mut self, comment_id: &str) -> Result<()> {
+        self.conn().execute(
+            "DELETE FROM task_comment_sync WHERE task_id = (SELECT task_id FROM task_comments WHERE comment_id = ?1)",
+            [comment_id],
+        )?;
+        self.conn().execute(
+            "DELETE FROM task_comments WHERE task_id = (SELECT task_id FROM task_comments WHERE comment_id = ?1)",
+            [comment_id],
+        )?;
         Ok(())
     }
 
@@ -176,7 +529,8 @@ impl CacheManager {
     /// Removes all comments from the cache.
     #[allow(dead_code)]
     pub fn clear_all_comments(&mut self) -> Result<()> {
-        self.conn.execute("DELETE FROM task_comments", [])?;
+        self.conn().execute("DELETE FROM task_comments", [])?;
+        self.conn().execute("DELETE FROM task_comment_sync", [])?;
         Ok(())
     }
 
@@ -187,7 +541,8 @@ impl CacheManager {
     /// Persists the user's current navigation state as JSON in the session_state table.
     /// The state is stored with the key 'current_session'.
     pub fn save_session_state(&mut self, state: &SessionState) -> Result<()> {
-        let tx = self.conn.transaction()?;
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
 
         // Clear existing session state
         tx.execute("DELETE FROM session_state", [])?;
@@ -210,16 +565,15 @@ impl CacheManager {
     /// Retrieves the user's last saved navigation state.
     /// Returns Ok(None) if no session state exists (first launch).
     pub fn load_session_state(&self) -> Result<Option<SessionState>> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT value FROM session_state WHERE key = ?1")?;
+        let conn = self.conn();
+        let mut stmt = conn.prepare("SELECT value FROM session_state WHERE key = ?1")?;
 
         let result: Result<String, _> = stmt.query_row(["current_session"], |row| row.get(0));
 
         match result {
             Ok(json) => {
                 let state: SessionState =
-                    serde_json::from_str(&json).context("Failed to deserialize session state")?;
+                    from_json_str(&json).context("Failed to deserialize session state")?;
                 Ok(Some(state))
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
@@ -233,11 +587,192 @@ impl CacheManager {
     /// Used when logging out or when restored state is invalid.
     #[allow(dead_code)]
     pub fn clear_session_state(&mut self) -> Result<()> {
-        self.conn.execute("DELETE FROM session_state", [])?;
+        self.conn().execute("DELETE FROM session_state", [])?;
+        Ok(())
+    }
+
+    // ==================== Request Queue ====================
+
+    /// Queue a write request to send once the network is back
+    ///
+    /// Returns the ID of the queued request.
+    pub fn queue_request(
+        &mut self,
+        method: &str,
+        url: &str,
+        body_json: Option<&str>,
+    ) -> Result<i64> {
+        let conn = self.conn();
+        conn.execute(
+            "INSERT INTO request_queue (method, url, body_json, queued_at) VALUES (?1, ?2, ?3, ?4)",
+            params![method, url, body_json, unix_now()],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Queued requests, oldest first
+    pub fn queued_requests(&self) -> Result<Vec<QueuedRequest>> {
+        let conn = self.conn();
+        let mut stmt = conn.prepare(
+            "SELECT id, method, url, body_json, queued_at FROM request_queue ORDER BY id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok(QueuedRequest {
+                id: row.get(0)?,
+                method: row.get(1)?,
+                url: row.get(2)?,
+                body_json: row.get(3)?,
+                queued_at: row.get(4)?,
+            })
+        })?;
+        rows.collect::<rusqlite::Result<_>>()
+            .context("Failed to read request queue")
+    }
+
+    /// Number of queued requests
+    pub fn queued_request_count(&self) -> Result<usize> {
+        let count: i64 = self
+            .conn()
+            .query_row("SELECT COUNT(*) FROM request_queue", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    /// Remove a request from the queue once it has been sent (or given up on)
+    pub fn remove_queued_request(&mut self, id: i64) -> Result<()> {
+        self.conn()
+            .execute("DELETE FROM request_queue WHERE id = ?1", [id])?;
         Ok(())
     }
 }
 
+/// Write a task summary, and its list if not yet cached
+///
+/// Tasks without a list are skipped, since every cached task belongs to one.
+fn insert_task(tx: &rusqlite::Transaction, task: &Task, fetched_at: i64) -> Result<()> {
+    let Some(list) = &task.list else {
+        return Ok(());
+    };
+    tx.execute(
+        "INSERT OR IGNORE INTO lists (id, name) VALUES (?1, ?2)",
+        params![list.id, list.name.clone().unwrap_or_default()],
+    )?;
+    tx.execute(
+        "INSERT OR REPLACE INTO tasks (id, list_id, name, status, priority, due_date, created_at, updated_at, fetched_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            task.id,
+            list.id,
+            task.name,
+            task.status.as_ref().map(|s| s.status.clone()),
+            task.priority.as_ref().map(|p| p.priority.clone()),
+            task.due_date,
+            task.created_at,
+            task.updated_at,
+            fetched_at,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Insert a comment, or update the cached one with its id unless it is
+/// tombstoned
+fn upsert_comment(
+    tx: &rusqlite::Transaction,
+    task_id: &str,
+    comment: &Comment,
+    fetched_at: i64,
+) -> Result<()> {
+    let commenter_id = comment.commenter.as_ref().map(|c| c.id);
+    let commenter_name = comment.commenter.as_ref().map(|c| c.username.clone());
+
+    tx.execute(
+        "INSERT INTO task_comments (comment_id, task_id, text, commenter_id, commenter_name, created_at, updated_at, fetched_at, parent_id, reply_count) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+         ON CONFLICT(comment_id) DO UPDATE SET text = excluded.text, commenter_id = excluded.commenter_id, commenter_name = excluded.commenter_name, created_at = excluded.created_at, updated_at = excluded.updated_at, fetched_at = excluded.fetched_at, parent_id = excluded.parent_id, reply_count = excluded.reply_count
+         WHERE task_comments.deleted_at IS NULL",
+        params![
+            comment.id,
+            task_id,
+            comment.text,
+            commenter_id,
+            commenter_name,
+            comment.created_at,
+            comment.updated_at,
+            fetched_at,
+            comment.parent_id,
+            comment.reply_count,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Raise a task's comment sync point to the newest change among `comments`
+fn record_comment_sync(
+    tx: &rusqlite::Transaction,
+    task_id: &str,
+    comments: &[Comment],
+) -> Result<()> {
+    let Some(newest) = comments
+        .iter()
+        .filter_map(|c| c.updated_at.or(c.created_at))
+        .max()
+    else {
+        return Ok(());
+    };
+    tx.execute(
+        "INSERT INTO task_comment_sync (task_id, max_updated_at) VALUES (?1, ?2)
+         ON CONFLICT(task_id) DO UPDATE SET max_updated_at = MAX(max_updated_at, excluded.max_updated_at)",
+        params![task_id, newest],
+    )?;
+    Ok(())
+}
+
+/// Task summary from a row of (id, list_id, list name, name, status,
+/// priority, due_date, created_at, updated_at)
+fn task_from_row(row: &rusqlite::Row) -> rusqlite::Result<Task> {
+    let status: Option<String> = row.get(4)?;
+    let priority: Option<String> = row.get(5)?;
+    Ok(Task {
+        id: row.get(0)?,
+        list: Some(ListReference {
+            id: row.get(1)?,
+            name: row.get(2)?,
+            access: None,
+        }),
+        name: row.get(3)?,
+        status: status.map(|status| TaskStatus {
+            id: None,
+            status,
+            color: None,
+            type_field: None,
+            orderindex: None,
+            status_group: None,
+        }),
+        priority: priority.map(|priority| Priority {
+            priority,
+            color: None,
+        }),
+        due_date: row.get(6)?,
+        created_at: row.get(7)?,
+        updated_at: row.get(8)?,
+        ..Task::default()
+    })
+}
+
+/// Current time in seconds since the Unix epoch
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+/// Current time in milliseconds since the Unix epoch
+fn unix_now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -246,7 +781,9 @@ mod tests {
     fn create_test_cache() -> CacheManager {
         // Use :memory: for in-memory database
         let conn = Connection::open(":memory:").unwrap();
-        let cache = CacheManager { conn };
+        let cache = CacheManager {
+            conn: Arc::new(Mutex::new(conn)),
+        };
         cache.init_schema().unwrap();
         cache
     }
@@ -363,4 +900,276 @@ mod tests {
         let loaded = cache.load_session_state().unwrap();
         assert!(loaded.is_none());
     }
+
+    #[test]
+    fn test_recent_tasks_newest_first_without_duplicates() {
+        let mut cache = create_test_cache();
+
+        cache.record_task_access("task-1", "list-a").unwrap();
+        cache.record_task_access("task-2", "list-b").unwrap();
+        cache.record_task_access("task-3", "list-a").unwrap();
+        // Reopening a task moves it to the front instead of adding a row
+        cache.record_task_access("task-1", "list-a").unwrap();
+
+        assert_eq!(
+            cache.get_recent_tasks(10).unwrap(),
+            vec![
+                ("task-1".to_string(), "list-a".to_string()),
+                ("task-3".to_string(), "list-a".to_string()),
+                ("task-2".to_string(), "list-b".to_string()),
+            ]
+        );
+        assert_eq!(cache.get_recent_tasks(2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_folder_statuses_round_trip() {
+        let mut cache = create_test_cache();
+        let statuses: Vec<TaskStatus> = serde_json::from_str(
+            r##"[{"status": "backlog", "color": "#ccc", "orderindex": 0},
+                {"status": "shipped", "color": "#0f0", "orderindex": 1}]"##,
+        )
+        .unwrap();
+
+        assert_eq!(cache.get_folder_statuses("folder-1").unwrap(), None);
+
+        cache.cache_folder_statuses("folder-1", &statuses).unwrap();
+        assert_eq!(cache.get_folder_statuses("folder-1").unwrap(), Some(statuses));
+
+        // Folders without overrides are cached as empty, not missing
+        cache.cache_folder_statuses("folder-2", &[]).unwrap();
+        assert_eq!(cache.get_folder_statuses("folder-2").unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_list_statuses_round_trip() {
+        let mut cache = create_test_cache();
+        let statuses: Vec<TaskStatus> = serde_json::from_str(
+            r##"[{"id": "s1", "status": "open", "type": "open", "color": "#ccc", "orderindex": 0},
+                {"id": "s2", "status": "closed", "type": "closed", "color": "#0f0", "orderindex": 1}]"##,
+        )
+        .unwrap();
+
+        assert_eq!(cache.get_list_statuses("list-1").unwrap(), None);
+        cache.cache_list_statuses("list-1", &statuses).unwrap();
+        assert_eq!(cache.get_list_statuses("list-1").unwrap(), Some(statuses));
+        assert_eq!(cache.get_list_statuses("list-2").unwrap(), None);
+    }
+
+    #[test]
+    fn test_workspaces_round_trip_and_expire() {
+        let mut cache = create_test_cache();
+        let workspace = |id: &str, name: &str| Workspace {
+            id: id.to_string(),
+            name: name.to_string(),
+            color: Some("#7b68ee".to_string()),
+            avatar: None,
+            member_count: Some(4),
+        };
+        assert!(!cache.is_workspace_cache_valid(3600).unwrap());
+
+        let workspaces = vec![workspace("w2", "Work"), workspace("w1", "Home")];
+        cache.cache_workspaces(&workspaces).unwrap();
+        assert_eq!(cache.get_workspaces().unwrap(), workspaces);
+        assert!(cache.is_workspace_cache_valid(3600).unwrap());
+        assert!(!cache.is_workspace_cache_valid(0).unwrap());
+
+        cache.clear_workspaces().unwrap();
+        assert!(cache.get_workspaces().unwrap().is_empty());
+        assert!(!cache.is_workspace_cache_valid(3600).unwrap());
+    }
+
+    #[test]
+    fn test_last_viewed_round_trip() {
+        let mut cache = create_test_cache();
+
+        assert_eq!(cache.get_last_viewed("task-1").unwrap(), None);
+
+        cache.set_last_viewed("task-1", 1_700_000_000_000).unwrap();
+        assert_eq!(
+            cache.get_last_viewed("task-1").unwrap(),
+            Some(1_700_000_000_000)
+        );
+
+        // A later view replaces the earlier one
+        cache.set_last_viewed("task-1", 1_700_000_500_000).unwrap();
+        assert_eq!(
+            cache.get_last_viewed("task-1").unwrap(),
+            Some(1_700_000_500_000)
+        );
+        assert_eq!(cache.get_last_viewed("task-2").unwrap(), None);
+    }
+
+    #[test]
+    fn test_cache_task_name() {
+        let mut cache = create_test_cache();
+        let task: Task = serde_json::from_str(
+            r#"{"id": "task-1", "name": "Fix login", "list": {"id": "list-a"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(cache.get_task_name("task-1").unwrap(), None);
+        cache.cache_task(&task).unwrap();
+        assert_eq!(
+            cache.get_task_name("task-1").unwrap().as_deref(),
+            Some("Fix login")
+        );
+    }
+
+    #[test]
+    fn test_list_tasks_replaced_on_each_load() {
+        let mut cache = create_test_cache();
+        let task = |id: &str, list: &str| -> Task {
+            serde_json::from_str(&format!(
+                r#"{{"id": "{}", "name": "Task {}", "list": {{"id": "{}"}}}}"#,
+                id, id, list
+            ))
+            .unwrap()
+        };
+
+        assert!(cache.get_list_tasks("list-a").unwrap().is_empty());
+        assert_eq!(cache.list_tasks_fetched_at("list-a").unwrap(), None);
+
+        cache
+            .cache_list_tasks("list-a", &[task("t1", "list-a"), task("t2", "list-a")])
+            .unwrap();
+        cache.cache_list_tasks("list-b", &[task("t3", "list-b")]).unwrap();
+        cache.cache_list_tasks("list-a", &[task("t2", "list-a")]).unwrap();
+
+        let cached = cache.get_list_tasks("list-a").unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].id, "t2");
+        assert_eq!(cached[0].name, "Task t2");
+        assert!(cache.list_tasks_fetched_at("list-a").unwrap().is_some());
+        assert_eq!(cache.get_list_tasks("list-b").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_clones_share_the_connection() {
+        let cache = create_test_cache();
+        let mut queue = cache.clone();
+        queue
+            .queue_request("POST", "https://api.clickup.com/api/v2/comment/c1/reply", None)
+            .unwrap();
+        assert_eq!(cache.queued_request_count().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_request_queue_oldest_first() {
+        let mut cache = create_test_cache();
+        assert_eq!(cache.queued_request_count().unwrap(), 0);
+
+        let body = r#"{"name":"A"}"#;
+        let first = cache
+            .queue_request("POST", "https://api.clickup.com/api/v2/list/l1/task", Some(body))
+            .unwrap();
+        let second = cache
+            .queue_request("DELETE", "https://api.clickup.com/api/v2/task/t1", None)
+            .unwrap();
+
+        let queued = cache.queued_requests().unwrap();
+        assert_eq!(queued.len(), 2);
+        assert_eq!(queued[0].id, first);
+        assert_eq!(queued[0].method, "POST");
+        assert_eq!(queued[0].body_json.as_deref(), Some(body));
+        assert_eq!(queued[1].id, second);
+        assert_eq!(queued[1].body_json, None);
+
+        cache.remove_queued_request(first).unwrap();
+        assert_eq!(cache.queued_request_count().unwrap(), 1);
+        assert_eq!(cache.queued_requests().unwrap()[0].id, second);
+    }
+
+    #[test]
+    fn test_cached_task_summary_round_trips() {
+        let mut cache = create_test_cache();
+        let task: Task = serde_json::from_str(
+            r#"{
+                "id": "task-1",
+                "name": "Fix login",
+                "status": {"status": "in progress"},
+                "priority": {"priority": "high"},
+                "due_date": "1700000000000",
+                "list": {"id": "list-a", "name": "Bugs"}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(cache.get_task("task-1", 300).unwrap(), None);
+        cache.cache_task(&task).unwrap();
+        assert_eq!(cache.get_task("task-1", 0).unwrap(), None, "expired");
+
+        let cached = cache.get_task("task-1", 300).unwrap().unwrap();
+        assert_eq!(cached.name, "Fix login");
+        assert_eq!(cached.status.unwrap().status, "in progress");
+        assert_eq!(cached.priority.unwrap().priority, "high");
+        assert_eq!(cached.due_date, Some(1700000000000));
+        assert_eq!(cached.list.unwrap().id, "list-a");
+    }
+
+    #[test]
+    fn test_merge_comments_adds_new_and_updates_existing() {
+        let mut cache = create_test_cache();
+        let comment = |id: &str, text: &str, created_at: i64, updated_at: i64| -> Comment {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "comment_text": text,
+                "date": created_at,
+                "date_updated": updated_at
+            }))
+            .unwrap()
+        };
+        cache
+            .cache_comments(
+                "task-1",
+                &[comment("1", "First", 1_000, 1_000), comment("2", "Second", 2_000, 2_500)],
+            )
+            .unwrap();
+        assert_eq!(cache.comments_synced_through("task-1").unwrap(), Some(2_500));
+
+        cache
+            .merge_comments(
+                "task-1",
+                &[comment("1", "First, edited", 1_000, 4_000), comment("3", "Third", 3_000, 3_000)],
+            )
+            .unwrap();
+
+        let comments = cache.get_comments("task-1").unwrap();
+        let summary: Vec<(&str, &str)> = comments
+            .iter()
+            .map(|c| (c.id.as_str(), c.text.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [("3", "Third"), ("2", "Second"), ("1", "First, edited")]
+        );
+        assert_eq!(cache.comments_synced_through("task-1").unwrap(), Some(4_000));
+
+        // A deleted comment isn't brought back by a stale change
+        cache.mark_comment_deleted("3").unwrap();
+        cache
+            .merge_comments("task-1", &[comment("3", "Third", 3_000, 3_000)])
+            .unwrap();
+        assert_eq!(cache.get_comments("task-1").unwrap().len(), 2);
+        assert_eq!(cache.comments_synced_through("task-1").unwrap(), Some(4_000));
+
+        cache.clear_comments("task-1").unwrap();
+        assert_eq!(cache.comments_synced_through("task-1").unwrap(), None);
+    }
+
+    #[test]
+    fn test_cache_comments_stores_a_repeated_id_once() {
+        let mut cache = create_test_cache();
+        let comment = |text: &str| -> Comment {
+            serde_json::from_value(serde_json::json!({ "id": "1", "comment_text": text }))
+                .unwrap()
+        };
+        cache
+            .cache_comments("task-1", &[comment("Draft"), comment("Final")])
+            .unwrap();
+
+        let comments = cache.get_comments("task-1").unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].text, "Final");
+    }
 }
@@ -71,18 +71,64 @@ CREATE TABLE IF NOT EXISTS task_comments (
     created_at INTEGER,
     updated_at INTEGER,
     fetched_at INTEGER NOT NULL,
-    parent_id TEXT
+    parent_id TEXT,
+    deleted_at INTEGER
 );
 CREATE INDEX IF NOT EXISTS idx_task_comments_task ON task_comments(task_id);
 CREATE INDEX IF NOT EXISTS idx_task_comments_fetched ON task_comments(fetched_at);
 CREATE INDEX IF NOT EXISTS idx_task_comments_parent ON task_comments(parent_id);
 
+-- Newest comment change (updated_at, or created_at when never edited, in ms)
+-- seen for each task, from which its cached comments are brought up to date
+CREATE TABLE IF NOT EXISTS task_comment_sync (
+    task_id TEXT PRIMARY KEY,
+    max_updated_at INTEGER NOT NULL
+);
+
+-- Status workflows of folders, stored as JSON (an empty array means the
+-- folder uses its space's statuses)
+CREATE TABLE IF NOT EXISTS folder_statuses (
+    folder_id TEXT PRIMARY KEY,
+    statuses TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL
+);
+
+-- Status workflows of lists, stored as JSON in workflow order
+CREATE TABLE IF NOT EXISTS list_statuses (
+    list_id TEXT PRIMARY KEY,
+    statuses TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL
+);
+
+-- Most recent time each task was opened, for the Recent Tasks screen
+CREATE TABLE IF NOT EXISTS task_access_log (
+    task_id TEXT PRIMARY KEY,
+    list_id TEXT NOT NULL,
+    accessed_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_task_access_log_accessed ON task_access_log(accessed_at);
+
+-- When each task's comments were last viewed, for unread indicators
+CREATE TABLE IF NOT EXISTS task_comment_views (
+    task_id TEXT PRIMARY KEY,
+    viewed_at INTEGER NOT NULL
+);
+
 -- Session state table for persisting navigation state across sessions
 CREATE TABLE IF NOT EXISTS session_state (
     key TEXT PRIMARY KEY,
     value TEXT NOT NULL
 );
 
+-- Writes made while offline, replayed oldest first by `debug drain-queue`
+CREATE TABLE IF NOT EXISTS request_queue (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    method TEXT NOT NULL,
+    url TEXT NOT NULL,
+    body_json TEXT,
+    queued_at INTEGER NOT NULL
+);
+
 -- Migration: Drop tables removed in favor of per-list filtering
 DROP TABLE IF EXISTS assigned_tasks;
 DROP TABLE IF EXISTS assigned_comments;
@@ -2,12 +2,18 @@
 //!
 //! Handles parsing of command-line arguments for the debug subcommand.
 
+use crate::config::share::ImportMode;
+use crate::config::StartScreen;
+use crate::utils::{ParsedUrl, UrlParser};
+use chrono::{DateTime, Days, NaiveDate, TimeDelta, TimeZone};
 use std::env;
+use std::path::PathBuf;
 
 /// Exit codes for CLI operations
 pub mod exit_codes {
     pub const SUCCESS: i32 = 0;
     pub const GENERAL_ERROR: i32 = 1;
+    pub const INVALID_ARGS: i32 = 2;
     pub const AUTH_ERROR: i32 = 3;
     pub const NETWORK_ERROR: i32 = 4;
 }
@@ -17,6 +23,28 @@ pub mod exit_codes {
 pub struct CliArgs {
     /// The debug operation to run (if any)
     pub debug_command: Option<DebugCommand>,
+    /// Resource to open directly in the TUI (ClickUp URL or task ID)
+    pub open_target: Option<OpenTarget>,
+    /// Skip the first-launch onboarding wizard and show token input directly
+    pub no_wizard: bool,
+    /// Screen to start on, overriding the `start_screen` config
+    pub start_screen: Option<StartScreen>,
+}
+
+/// A resource to open on startup, parsed from a ClickUp URL or ID
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenTarget {
+    /// Open a task's detail screen
+    Task { task_id: String },
+    /// Open a task by an ID that belongs to a workspace, such as a custom ID
+    WorkspaceTask {
+        workspace_id: String,
+        task_id: String,
+    },
+    /// Open a list's task screen
+    List { list_id: String },
+    /// Open a document
+    Document { doc_id: String },
 }
 
 /// Debug subcommand structure
@@ -28,6 +56,8 @@ pub struct DebugCommand {
     pub json: bool,
     /// Enable verbose logging
     pub verbose: bool,
+    /// Save responses that fail to parse to the cache dir
+    pub dump_responses: bool,
     /// Override token (for testing)
     pub token_override: Option<String>,
     /// Comment text (for create/update operations)
@@ -38,6 +68,8 @@ pub struct DebugCommand {
     pub assignee: Option<String>,
     /// Assigned commenter user ID (optional)
     pub assigned_commenter: Option<String>,
+    /// Skip confirmation prompts for destructive operations
+    pub yes: bool,
 }
 
 /// Available debug operations
@@ -49,60 +81,292 @@ pub enum DebugOperation {
     Tasks { list_id: String },
     /// Search documents
     Docs { query: String },
+    /// Get a document and its pages, or one page with `page_id`
+    Doc {
+        doc_id: String,
+        page_id: Option<String>,
+    },
     /// Check authentication status
     AuthStatus,
+    /// Check that the token, directories, cache, API and terminal are usable
+    VerifyEnv,
     /// Show help
     Help,
     /// Get current user info
     CurrentUser,
     /// List spaces in a workspace
     Spaces { workspace_id: String },
+    /// Show the features turned on in a workspace's spaces
+    Features { workspace_id: String },
     /// List folders in a space
     Folders { space_id: String },
+    /// Create a space in a workspace
+    CreateSpace {
+        workspace_id: String,
+        name: String,
+        private: bool,
+    },
+    /// Change a space's name, color or privacy; unset settings stay as they are
+    UpdateSpace {
+        space_id: String,
+        name: Option<String>,
+        color: Option<String>,
+        private: Option<bool>,
+    },
+    /// Create a folder in a space
+    CreateFolder { space_id: String, name: String },
     /// List lists in a folder or space
     Lists { id: String, in_space: bool },
     /// Get a single task
     Task { task_id: String },
+    /// Get a task by its custom ID (e.g. `DEV-42`) in a workspace
+    TaskByCustomId {
+        custom_id: String,
+        workspace_id: String,
+    },
+    /// Get the task a ClickUp task URL points at
+    OpenUrl { url: String },
     /// Explore full hierarchy
     Explore { workspace_id: String },
-    /// Get comments for a task
-    Comments { task_id: String },
+    /// Get the newest page of a task's comments, or every page with `all`
+    Comments { task_id: String, all: bool },
+    /// Get a single comment
+    Comment { comment_id: String },
     /// Create a new comment on a task
     CreateComment { task_id: String },
     /// Create a reply to an existing comment
     CreateReply { comment_id: String },
     /// Update an existing comment
     UpdateComment { comment_id: String },
+    /// Delete a comment
+    DeleteComment { comment_id: String },
+    /// List the views saved on a list
+    Views { list_id: String },
+    /// List the tasks shown in a view
+    ViewTasks { view_id: String },
+    /// Send the writes queued while offline
+    DrainQueue,
+    /// Show sprint overview for a sprint view
+    SprintOverview { view_id: String },
+    /// List tasks assigned to the current user across a workspace
+    MyTasks { workspace_id: String },
+    /// Show how long a task spent in each status
+    TimeInStatus { task_id: String },
+    /// Summarise logged time per day and task
+    Timesheet {
+        workspace_id: String,
+        range: DateRange,
+        user: Option<String>,
+    },
+    /// Summarise my tasks updated since a cutoff for a standup
+    Standup { workspace_id: String, since: Since },
+    /// Print the config without secrets or machine settings, or write it to `output`
+    ConfigExport { output: Option<PathBuf> },
+    /// Preview and apply a shared config file on top of the local one
+    ConfigImport { path: PathBuf, mode: ImportMode },
+}
+
+/// Start of a standup: a span back from now, or the start of a day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Since {
+    Ago(TimeDelta),
+    Day(NaiveDate),
+}
+
+impl Default for Since {
+    fn default() -> Self {
+        Self::Ago(TimeDelta::hours(24))
+    }
+}
+
+impl Since {
+    /// The cutoff in ms since epoch, with days starting at midnight in `now`'s zone
+    pub fn cutoff_millis<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> i64 {
+        match self {
+            Self::Ago(span) => (now.clone() - *span).timestamp_millis(),
+            Self::Day(day) => DateRange { from: *day, to: *day }.millis_in(&now.timezone()).0,
+        }
+    }
+}
+
+/// Parse a `--since` value: hours or days back (`24h`, `3d`) or a date (YYYY-MM-DD)
+pub fn parse_since(value: &str) -> Result<Since, String> {
+    let value = value.trim();
+    let span = |digits: &str, unit: fn(i64) -> TimeDelta| {
+        digits.parse::<i64>().ok().filter(|n| *n > 0).map(unit)
+    };
+    let parsed = if let Some(hours) = value.strip_suffix('h') {
+        span(hours, TimeDelta::hours).map(Since::Ago)
+    } else if let Some(days) = value.strip_suffix('d') {
+        span(days, TimeDelta::days).map(Since::Ago)
+    } else {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").ok().map(Since::Day)
+    };
+    parsed.ok_or_else(|| {
+        format!(
+            "--since must be hours or days back (e.g. 24h, 3d) or a date in YYYY-MM-DD format, got '{}'",
+            value
+        )
+    })
+}
+
+/// Parse a `--color` value: a hex color, with or without the leading `#`
+pub fn parse_hex_color(value: &str) -> Result<String, String> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(format!("#{}", hex.to_ascii_lowercase()))
+    } else {
+        Err(format!(
+            "--color must be a hex color like #7b68ee, got '{}'",
+            value
+        ))
+    }
+}
+
+/// Inclusive range of calendar days
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateRange {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl DateRange {
+    /// First and last millisecond of the range in `tz`
+    pub fn millis_in<Tz: TimeZone>(&self, tz: &Tz) -> (i64, i64) {
+        let start_of = |date: NaiveDate| {
+            tz.from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+                .earliest()
+                .map(|dt| dt.timestamp_millis())
+                .unwrap_or_else(|| {
+                    date.and_hms_opt(0, 0, 0)
+                        .unwrap()
+                        .and_utc()
+                        .timestamp_millis()
+                })
+        };
+        let after_last = self.to.checked_add_days(Days::new(1)).unwrap_or(self.to);
+        (start_of(self.from), start_of(after_last) - 1)
+    }
+}
+
+/// Parse `--from`/`--to` values (YYYY-MM-DD) into a range, rejecting inverted ranges
+pub fn parse_date_range(from: &str, to: &str) -> Result<DateRange, String> {
+    let parse = |flag: &str, value: &str| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+            format!(
+                "{} must be a date in YYYY-MM-DD format, got '{}'",
+                flag, value
+            )
+        })
+    };
+    let range = DateRange {
+        from: parse("--from", from)?,
+        to: parse("--to", to)?,
+    };
+    if range.from > range.to {
+        return Err(format!(
+            "--from ({}) is after --to ({})",
+            range.from, range.to
+        ));
+    }
+    Ok(range)
 }
 
 /// Parse CLI arguments from environment
 pub fn parse_args() -> Result<CliArgs, String> {
     let args: Vec<String> = env::args().collect();
+    parse_args_from(&args[1..])
+}
 
-    // Skip program name
-    if args.len() < 2 {
+/// Parse CLI arguments (without the program name)
+pub fn parse_args_from(args: &[String]) -> Result<CliArgs, String> {
+    let mut cli_args = CliArgs {
+        debug_command: None,
+        open_target: None,
+        no_wizard: false,
+        start_screen: None,
+    };
+
+    match args.first().map(String::as_str) {
         // No subcommand - run TUI mode
-        return Ok(CliArgs {
-            debug_command: None,
-        });
+        None => return Ok(cli_args),
+        Some("debug") => {
+            cli_args.debug_command = Some(parse_debug_command(&args[1..])?);
+            return Ok(cli_args);
+        }
+        // Show help and run TUI
+        Some("--help" | "-h" | "help") => return Ok(cli_args),
+        Some(_) => {}
     }
 
-    let subcommand = &args[1];
-
-    match subcommand.as_str() {
-        "debug" => {
-            let debug_cmd = parse_debug_command(&args[2..])?;
-            Ok(CliArgs {
-                debug_command: Some(debug_cmd),
-            })
+    // TUI mode: flags and an optional ClickUp URL or task ID to open directly
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--no-wizard" => cli_args.no_wizard = true,
+            "--start" => {
+                let value = args.next().ok_or("--start requires a value")?;
+                cli_args.start_screen = Some(value.parse()?);
+            }
+            _ if arg.starts_with('-') => return Err(format!("Unknown option: {}", arg)),
+            _ if cli_args.open_target.is_some() => {
+                return Err(format!("Unexpected argument: {}", arg));
+            }
+            _ => {
+                let target = parse_open_target(arg)
+                    .map_err(|e| format!("Unknown subcommand or target '{}': {}", arg, e))?;
+                cli_args.open_target = Some(target);
+            }
         }
-        "--help" | "-h" | "help" => {
-            // Show help and run TUI
-            Ok(CliArgs {
-                debug_command: None,
-            })
+    }
+
+    Ok(cli_args)
+}
+
+/// Parse a deep-link argument into an `OpenTarget`
+///
+/// Accepts full ClickUp URLs (with or without scheme), the `t/{task_id}` and
+/// `d/{doc_id}` shorthands, and bare task IDs.
+pub fn parse_open_target(arg: &str) -> Result<OpenTarget, String> {
+    let arg = arg.trim();
+    if arg.is_empty() {
+        return Err("empty target".to_string());
+    }
+
+    let url = if let Some(rest) = arg
+        .strip_prefix("https://")
+        .or_else(|| arg.strip_prefix("http://"))
+    {
+        format!("https://{}", rest)
+    } else if arg.starts_with("app.clickup.com") {
+        format!("https://{}", arg)
+    } else if arg.starts_with("t/") || arg.starts_with("d/") {
+        format!("https://app.clickup.com/{}", arg)
+    } else if arg
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Ok(OpenTarget::Task {
+            task_id: arg.to_string(),
+        });
+    } else {
+        return Err("not a ClickUp URL or task ID".to_string());
+    };
+
+    match UrlParser::parse(&url).map_err(|e| e.to_string())? {
+        ParsedUrl::Task { task_id } | ParsedUrl::Comment { task_id, .. } => {
+            Ok(OpenTarget::Task { task_id })
         }
-        _ => Err(format!("Unknown subcommand: {}", subcommand)),
+        ParsedUrl::WorkspaceTask {
+            workspace_id,
+            task_id,
+        } => Ok(OpenTarget::WorkspaceTask {
+            workspace_id,
+            task_id,
+        }),
+        ParsedUrl::List { list_id, .. } => Ok(OpenTarget::List { list_id }),
+        ParsedUrl::Document { doc_id } => Ok(OpenTarget::Document { doc_id }),
+        other => Err(format!("unsupported link target: {:?}", other)),
     }
 }
 
@@ -114,22 +378,39 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
             operation: DebugOperation::Help,
             json: false,
             verbose: false,
+            dump_responses: false,
             token_override: None,
             text: None,
             parent_id: None,
             assignee: None,
             assigned_commenter: None,
+            yes: false,
         });
     }
 
     let mut operation: Option<DebugOperation> = None;
     let mut json = false;
     let mut verbose = false;
+    let mut dump_responses = false;
     let mut token_override: Option<String> = None;
     let mut text: Option<String> = None;
     let mut parent_id: Option<String> = None;
     let mut assignee: Option<String> = None;
     let mut assigned_commenter: Option<String> = None;
+    let mut yes = false;
+    let mut from: Option<String> = None;
+    let mut to: Option<String> = None;
+    let mut user: Option<String> = None;
+    let mut name: Option<String> = None;
+    let mut private: Option<bool> = None;
+    let mut color: Option<String> = None;
+    // A plain switch, read up front so `comments` is built whole wherever
+    // it comes on the command line
+    let all = args.iter().any(|arg| arg == "--all");
+    let mut page: Option<String> = None;
+    let mut since: Option<String> = None;
+    let mut output: Option<PathBuf> = None;
+    let mut import_mode: Option<ImportMode> = None;
 
     let mut i = 0;
     while i < args.len() {
@@ -138,6 +419,51 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
         match arg.as_str() {
             "--json" => json = true,
             "--verbose" | "-v" => verbose = true,
+            "--dump-responses" => dump_responses = true,
+            "--yes" | "-y" => yes = true,
+            "--private" => {
+                // A bare `--private` means true; `--private false` makes a space public
+                private = Some(match args.get(i + 1).map(String::as_str) {
+                    Some("true") => {
+                        i += 1;
+                        true
+                    }
+                    Some("false") => {
+                        i += 1;
+                        false
+                    }
+                    _ => true,
+                });
+            }
+            "--color" => {
+                if i + 1 >= args.len() {
+                    return Err("--color requires a value".to_string());
+                }
+                color = Some(parse_hex_color(&args[i + 1])?);
+                i += 1;
+            }
+            "--all" => {}
+            "--page" => {
+                if i + 1 >= args.len() {
+                    return Err("--page requires a value".to_string());
+                }
+                page = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--since" => {
+                if i + 1 >= args.len() {
+                    return Err("--since requires a value".to_string());
+                }
+                since = Some(args[i + 1].clone());
+                i += 1;
+            }
+            "--name" => {
+                if i + 1 >= args.len() {
+                    return Err("--name requires a value".to_string());
+                }
+                name = Some(args[i + 1].clone());
+                i += 1;
+            }
             "--text" => {
                 if i + 1 >= args.len() {
                     return Err("--text requires a value".to_string());
@@ -166,6 +492,36 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 assigned_commenter = Some(args[i + 1].clone());
                 i += 1;
             }
+            "--from" | "--to" | "--user" => {
+                if i + 1 >= args.len() {
+                    return Err(format!("{} requires a value", arg));
+                }
+                let value = Some(args[i + 1].clone());
+                match arg.as_str() {
+                    "--from" => from = value,
+                    "--to" => to = value,
+                    _ => user = value,
+                }
+                i += 1;
+            }
+            "--output" => {
+                if i + 1 >= args.len() {
+                    return Err("--output requires a path".to_string());
+                }
+                output = Some(PathBuf::from(&args[i + 1]));
+                i += 1;
+            }
+            "--merge" | "--replace" => {
+                let mode = if arg == "--merge" {
+                    ImportMode::Merge
+                } else {
+                    ImportMode::Replace
+                };
+                if import_mode.is_some_and(|m| m != mode) {
+                    return Err("--merge and --replace can't be combined".to_string());
+                }
+                import_mode = Some(mode);
+            }
             "--token" => {
                 if i + 1 >= args.len() {
                     return Err("--token requires a value".to_string());
@@ -179,6 +535,12 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 }
                 operation = Some(DebugOperation::Workspaces);
             }
+            "drain-queue" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                operation = Some(DebugOperation::DrainQueue);
+            }
             "tasks" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -203,12 +565,31 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 });
                 i += 1; // Skip next arg
             }
+            "doc" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("doc requires a doc_id argument".to_string());
+                }
+                operation = Some(DebugOperation::Doc {
+                    doc_id: args[i + 1].clone(),
+                    page_id: None,
+                });
+                i += 1;
+            }
             "auth-status" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
                 }
                 operation = Some(DebugOperation::AuthStatus);
             }
+            "verify-env" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                operation = Some(DebugOperation::VerifyEnv);
+            }
             "current-user" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -227,6 +608,18 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 });
                 i += 1; // Skip next arg
             }
+            "features" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("features requires a workspace_id argument".to_string());
+                }
+                operation = Some(DebugOperation::Features {
+                    workspace_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
             "folders" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -239,6 +632,49 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 });
                 i += 1; // Skip next arg
             }
+            "create-space" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("create-space requires a workspace_id argument".to_string());
+                }
+                // The name and privacy are filled in once the flags have been read
+                operation = Some(DebugOperation::CreateSpace {
+                    workspace_id: args[i + 1].clone(),
+                    name: String::new(),
+                    private: false,
+                });
+                i += 1;
+            }
+            "update-space" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("update-space requires a space_id argument".to_string());
+                }
+                operation = Some(DebugOperation::UpdateSpace {
+                    space_id: args[i + 1].clone(),
+                    name: None,
+                    color: None,
+                    private: None,
+                });
+                i += 1;
+            }
+            "create-folder" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("create-folder requires a space_id argument".to_string());
+                }
+                operation = Some(DebugOperation::CreateFolder {
+                    space_id: args[i + 1].clone(),
+                    name: String::new(),
+                });
+                i += 1;
+            }
             "lists" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -269,6 +705,34 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 });
                 i += 1; // Skip next arg
             }
+            "task-by-custom-id" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 2 >= args.len() {
+                    return Err(
+                        "task-by-custom-id requires custom_id and workspace_id arguments"
+                            .to_string(),
+                    );
+                }
+                operation = Some(DebugOperation::TaskByCustomId {
+                    custom_id: args[i + 1].clone(),
+                    workspace_id: args[i + 2].clone(),
+                });
+                i += 2;
+            }
+            "open-url" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("open-url requires a url argument".to_string());
+                }
+                operation = Some(DebugOperation::OpenUrl {
+                    url: args[i + 1].clone(),
+                });
+                i += 1; // Skip next arg
+            }
             "explore" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -290,9 +754,22 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 }
                 operation = Some(DebugOperation::Comments {
                     task_id: args[i + 1].clone(),
+                    all,
                 });
                 i += 1; // Skip next arg
             }
+            "comment" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("comment requires a comment_id argument".to_string());
+                }
+                operation = Some(DebugOperation::Comment {
+                    comment_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
             "create-comment" => {
                 if operation.is_some() {
                     return Err("Multiple operations specified".to_string());
@@ -329,6 +806,132 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 });
                 i += 1;
             }
+            "delete-comment" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("delete-comment requires a comment_id argument".to_string());
+                }
+                operation = Some(DebugOperation::DeleteComment {
+                    comment_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "views" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("views requires a list_id argument".to_string());
+                }
+                operation = Some(DebugOperation::Views {
+                    list_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "view-tasks" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("view-tasks requires a view_id argument".to_string());
+                }
+                operation = Some(DebugOperation::ViewTasks {
+                    view_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "sprint" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("sprint requires a view_id argument".to_string());
+                }
+                operation = Some(DebugOperation::SprintOverview {
+                    view_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "time-in-status" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("time-in-status requires a task_id argument".to_string());
+                }
+                operation = Some(DebugOperation::TimeInStatus {
+                    task_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "my-tasks" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("my-tasks requires a workspace_id argument".to_string());
+                }
+                operation = Some(DebugOperation::MyTasks {
+                    workspace_id: args[i + 1].clone(),
+                });
+                i += 1;
+            }
+            "timesheet" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("timesheet requires a workspace_id argument".to_string());
+                }
+                // The range is filled in once --from/--to have been read
+                let today = chrono::Local::now().date_naive();
+                operation = Some(DebugOperation::Timesheet {
+                    workspace_id: args[i + 1].clone(),
+                    range: DateRange {
+                        from: today,
+                        to: today,
+                    },
+                    user: None,
+                });
+                i += 1;
+            }
+            "standup" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                if i + 1 >= args.len() {
+                    return Err("standup requires a workspace_id argument".to_string());
+                }
+                operation = Some(DebugOperation::Standup {
+                    workspace_id: args[i + 1].clone(),
+                    since: Since::default(),
+                });
+                i += 1;
+            }
+            "config" => {
+                if operation.is_some() {
+                    return Err("Multiple operations specified".to_string());
+                }
+                match args.get(i + 1).map(String::as_str) {
+                    Some("export") => {
+                        operation = Some(DebugOperation::ConfigExport { output: None });
+                        i += 1;
+                    }
+                    Some("import") => {
+                        let Some(path) = args.get(i + 2) else {
+                            return Err("config import requires a path argument".to_string());
+                        };
+                        operation = Some(DebugOperation::ConfigImport {
+                            path: PathBuf::from(path),
+                            mode: ImportMode::default(),
+                        });
+                        i += 2;
+                    }
+                    _ => return Err("config requires export or import".to_string()),
+                }
+            }
             "--help" | "-h" => {
                 operation = Some(DebugOperation::Help);
             }
@@ -344,7 +947,7 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
         i += 1;
     }
 
-    let op = operation.unwrap_or(DebugOperation::Help);
+    let mut op = operation.unwrap_or(DebugOperation::Help);
 
     // Validate comment operation arguments
     match &op {
@@ -358,6 +961,87 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
                 return Err("--text cannot be empty".to_string());
             }
         }
+        DebugOperation::CreateSpace { .. } | DebugOperation::CreateFolder { .. } => {
+            let new_name = match name.as_deref().map(str::trim) {
+                Some(new_name) if !new_name.is_empty() => new_name.to_string(),
+                Some(_) => return Err("--name cannot be empty".to_string()),
+                None => return Err("--name is required for this operation".to_string()),
+            };
+            match &mut op {
+                DebugOperation::CreateSpace {
+                    name: op_name,
+                    private: op_private,
+                    ..
+                } => {
+                    *op_name = new_name;
+                    *op_private = private.unwrap_or(false);
+                }
+                DebugOperation::CreateFolder { name: op_name, .. } => *op_name = new_name,
+                _ => {}
+            }
+        }
+        DebugOperation::UpdateSpace { .. } => {
+            if name.is_none() && color.is_none() && private.is_none() {
+                return Err("update-space needs at least one of --name, --color or --private".to_string());
+            }
+            if name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+                return Err("--name cannot be empty".to_string());
+            }
+            if let DebugOperation::UpdateSpace {
+                name: op_name,
+                color: op_color,
+                private: op_private,
+                ..
+            } = &mut op
+            {
+                *op_name = name.map(|n| n.trim().to_string());
+                *op_color = color;
+                *op_private = private;
+            }
+        }
+        DebugOperation::Doc { .. } => {
+            if let DebugOperation::Doc { page_id, .. } = &mut op {
+                *page_id = page;
+            }
+        }
+        DebugOperation::Timesheet { .. } => {
+            let (Some(from), Some(to)) = (&from, &to) else {
+                return Err("--from and --to are required for timesheet".to_string());
+            };
+            let parsed_range = parse_date_range(from, to)?;
+            if let DebugOperation::Timesheet {
+                range,
+                user: op_user,
+                ..
+            } = &mut op
+            {
+                *range = parsed_range;
+                *op_user = user;
+            }
+        }
+        DebugOperation::Standup { .. } => {
+            if let (DebugOperation::Standup { since: op_since, .. }, Some(since)) =
+                (&mut op, &since)
+            {
+                *op_since = parse_since(since)?;
+            }
+        }
+        DebugOperation::ConfigExport { .. } => {
+            if import_mode.is_some() {
+                return Err("--merge and --replace only apply to config import".to_string());
+            }
+            if let DebugOperation::ConfigExport { output: op_output } = &mut op {
+                *op_output = output;
+            }
+        }
+        DebugOperation::ConfigImport { .. } => {
+            if output.is_some() {
+                return Err("--output only applies to config export".to_string());
+            }
+            if let DebugOperation::ConfigImport { mode, .. } = &mut op {
+                *mode = import_mode.unwrap_or_default();
+            }
+        }
         _ => {}
     }
 
@@ -365,11 +1049,13 @@ fn parse_debug_command(args: &[String]) -> Result<DebugCommand, String> {
         operation: op,
         json,
         verbose,
+        dump_responses,
         token_override,
         text,
         parent_id,
         assignee,
         assigned_commenter,
+        yes,
     })
 }
 
@@ -379,37 +1065,76 @@ pub fn print_usage() {
     eprintln!();
     eprintln!("USAGE:");
     eprintln!("    clickdown                    Run in TUI mode");
+    eprintln!("    clickdown <url|task_id>      Open a ClickUp task, list or doc in the TUI");
+    eprintln!("    clickdown --no-wizard        Skip the first-launch setup wizard");
+    eprintln!(
+        "    clickdown --start <screen>   Start on: {}",
+        StartScreen::VALID_VALUES
+    );
     eprintln!("    clickdown debug <operation> [OPTIONS]");
     eprintln!();
     eprintln!("DEBUG OPERATIONS:");
     eprintln!("    workspaces              List all authorized workspaces");
     eprintln!("    tasks <list_id>         Fetch tasks from a list");
     eprintln!("    docs <query>            Search documents");
+    eprintln!("    doc <doc_id>            Get a document and its pages (--page <id> for one page)");
     eprintln!("    auth-status             Check authentication status");
+    eprintln!("    verify-env              Check token, config, cache, API and terminal");
     eprintln!("    current-user            Get current user info");
     eprintln!("    spaces <workspace_id>   List spaces in a workspace");
+    eprintln!("    features <workspace_id> Show which features (time tracking, ...) are on");
     eprintln!("    folders <space_id>      List folders in a space");
+    eprintln!("    create-space <workspace_id> Create a space (--name required, --private)");
+    eprintln!("    update-space <space_id>   Change a space (--name, --color, --private true|false)");
+    eprintln!("    create-folder <space_id>  Create a folder in a space (--name required)");
     eprintln!(
         "    lists <id>              List lists in a folder (use --in-space for space lists)"
     );
     eprintln!("    task <task_id>          Get a single task");
-    eprintln!("    comments <task_id>      Get comments for a task");
+    eprintln!("    task-by-custom-id <custom_id> <workspace_id>  Get a task by custom ID (e.g. DEV-42)");
+    eprintln!("    open-url <url>          Get the task a ClickUp task URL points at");
+    eprintln!("    comments <task_id>      Get the newest comments on a task (--all for every page)");
+    eprintln!("    comment <comment_id>    Get a single comment");
     eprintln!("    explore <workspace_id>  Explore full hierarchy (spaces->folders->lists->tasks)");
     eprintln!("    create-comment <task_id>  Create a new comment (--text required)");
     eprintln!("    create-reply <comment_id> Create a reply to a comment (--text required)");
     eprintln!("    update-comment <comment_id> Update an existing comment (--text required)");
+    eprintln!("    delete-comment <comment_id> Delete a comment (asks first unless --yes)");
+    eprintln!("    drain-queue             Send the task and comment changes made while offline");
+    eprintln!("    views <list_id>         List the views saved on a list");
+    eprintln!("    view-tasks <view_id>    List every task shown in a view");
+    eprintln!("    sprint <view_id>        Show sprint overview (points, burndown, velocity)");
+    eprintln!("    my-tasks <workspace_id> Tasks assigned to you across the workspace");
+    eprintln!("    time-in-status <task_id> How long a task spent in each status");
+    eprintln!("    timesheet <workspace_id> Logged time per day and task (--from/--to required)");
+    eprintln!("    standup <workspace_id>  Markdown summary of your recent work (--since, default 24h)");
+    eprintln!("    config export           Print the config to share, without token or machine paths");
+    eprintln!("    config import <path>    Preview and apply a shared config (--merge or --replace)");
     eprintln!();
     eprintln!("OPTIONS:");
     eprintln!("    --json                  Output in JSON format");
     eprintln!("    --verbose, -v           Enable verbose logging");
+    eprintln!("    --dump-responses        Save responses that fail to parse to the cache dir");
     eprintln!("    --token <token>         Override stored token (for testing)");
+    eprintln!("    --yes, -y               Don't ask before destructive operations");
     eprintln!(
         "    --in-space              Use with 'lists' to list space lists instead of folder lists"
     );
     eprintln!("    --text <text>           Comment text (for create/update operations)");
+    eprintln!("    --name <name>           Name of the space or folder to create, or the new space name");
+    eprintln!("    --private [true|false]  Make the space private (false makes it public)");
+    eprintln!("    --color <#rrggbb>       New space color");
+    eprintln!("    --all                   Use with 'comments' to page through every comment");
     eprintln!("    --parent-id <id>        Parent comment ID (for threaded comments)");
     eprintln!("    --assignee <user_id>    Assign comment to user");
     eprintln!("    --assigned-commenter <user_id>  Set who assigned the comment");
+    eprintln!("    --from <YYYY-MM-DD>     First day of the timesheet");
+    eprintln!("    --to <YYYY-MM-DD>       Last day of the timesheet (inclusive)");
+    eprintln!("    --user <user_id>        Timesheet for another user (default: you)");
+    eprintln!("    --since <24h|YYYY-MM-DD> Start of the standup: hours/days back or a date");
+    eprintln!("    --output <path>         Use with 'config export' to write to a file");
+    eprintln!("    --merge                 Import keeps settings the file doesn't set (default)");
+    eprintln!("    --replace               Import resets settings the file doesn't set");
     eprintln!("    --help, -h              Show this help message");
     eprintln!();
     eprintln!("EXIT CODES:");
@@ -423,15 +1148,26 @@ pub fn print_usage() {
     eprintln!("    clickdown debug workspaces");
     eprintln!("    clickdown debug tasks list123 --json");
     eprintln!("    clickdown debug auth-status --verbose");
+    eprintln!("    clickdown debug verify-env");
     eprintln!("    clickdown debug spaces 26408409 --json");
     eprintln!("    clickdown debug folders space123 --json");
+    eprintln!("    clickdown debug create-space 26408409 --name \"Marketing\" --private");
+    eprintln!("    clickdown debug update-space space123 --color \"#7b68ee\" --private false");
+    eprintln!("    clickdown debug create-folder space123 --name \"Q3 Campaigns\" --json");
     eprintln!("    clickdown debug lists folder123 --json");
     eprintln!("    clickdown debug task task123 --json");
+    eprintln!("    clickdown debug open-url https://app.clickup.com/t/abc123def");
     eprintln!("    clickdown debug comments task123 --json");
     eprintln!("    clickdown debug explore 26408409");
     eprintln!("    clickdown debug create-comment task123 --text \"Hello world\"");
     eprintln!("    clickdown debug create-reply comment456 --text \"Reply text\" --json");
     eprintln!("    clickdown debug update-comment comment789 --text \"Updated\" --verbose");
+    eprintln!("    clickdown debug delete-comment comment789 --yes");
+    eprintln!("    clickdown debug timesheet 26408409 --from 2024-03-04 --to 2024-03-08");
+    eprintln!("    clickdown debug standup 26408409 --since 2024-03-04");
+    eprintln!("    clickdown debug my-tasks 26408409 --json");
+    eprintln!("    clickdown debug config export --output team.toml");
+    eprintln!("    clickdown debug config import team.toml --replace --yes");
 }
 
 #[cfg(test)]
@@ -444,7 +1180,602 @@ mod tests {
 
     #[test]
     fn test_parse_debug_workspaces() {
-        let _args = vec!["debug".to_string(), "workspaces".to_string()];
+        let _args = ["debug".to_string(), "workspaces".to_string()];
         // Would need to mock env::args for full test
     }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_no_wizard_flag() {
+        let parsed = super::parse_args_from(&args(&["--no-wizard"])).unwrap();
+        assert!(parsed.no_wizard);
+        assert!(parsed.debug_command.is_none());
+        assert!(parsed.open_target.is_none());
+
+        let parsed = super::parse_args_from(&args(&["86b2xyz", "--no-wizard"])).unwrap();
+        assert!(parsed.no_wizard);
+        assert_eq!(parsed.open_target, Some(task("86b2xyz")));
+
+        assert!(!super::parse_args_from(&[]).unwrap().no_wizard);
+    }
+
+    #[test]
+    fn test_parse_start_screen() {
+        let parsed = super::parse_args_from(&args(&["--start", "my-tasks"])).unwrap();
+        assert_eq!(parsed.start_screen, Some(super::StartScreen::MyTasks));
+
+        let parsed = super::parse_args_from(&args(&["--start", "workspaces", "86b2xyz"])).unwrap();
+        assert_eq!(parsed.start_screen, Some(super::StartScreen::Workspaces));
+        assert_eq!(parsed.open_target, Some(task("86b2xyz")));
+
+        let err = super::parse_args_from(&args(&["--start", "home"])).unwrap_err();
+        assert!(err.contains(super::StartScreen::VALID_VALUES), "{}", err);
+        assert!(super::parse_args_from(&args(&["--start"])).is_err());
+        assert!(super::parse_args_from(&[]).unwrap().start_screen.is_none());
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_option_and_extra_target() {
+        assert!(super::parse_args_from(&args(&["--bogus"])).is_err());
+        assert!(super::parse_args_from(&args(&["abc", "def"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_env() {
+        let parsed = super::parse_args_from(&args(&["debug", "verify-env"])).unwrap();
+        assert!(matches!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::VerifyEnv
+        ));
+    }
+
+    #[test]
+    fn test_parse_open_url() {
+        let url = "https://app.clickup.com/t/abc123def";
+        let parsed = super::parse_args_from(&args(&["debug", "open-url", url])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::OpenUrl {
+                url: url.to_string()
+            }
+        );
+        assert!(super::parse_args_from(&args(&["debug", "open-url"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_task_by_custom_id() {
+        let parsed =
+            super::parse_args_from(&args(&["debug", "task-by-custom-id", "DEV-42", "ws-1"]))
+                .unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::TaskByCustomId {
+                custom_id: "DEV-42".to_string(),
+                workspace_id: "ws-1".to_string(),
+            }
+        );
+        assert!(super::parse_args_from(&args(&["debug", "task-by-custom-id", "DEV-42"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_dump_responses_flag() {
+        let parsed =
+            super::parse_args_from(&args(&["debug", "task", "t1", "--dump-responses"])).unwrap();
+        assert!(parsed.debug_command.unwrap().dump_responses);
+
+        let parsed = super::parse_args_from(&args(&["debug", "task", "t1"])).unwrap();
+        assert!(!parsed.debug_command.unwrap().dump_responses);
+    }
+
+    #[test]
+    fn test_parse_drain_queue() {
+        let parsed = super::parse_args_from(&args(&["debug", "drain-queue"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::DrainQueue
+        );
+    }
+
+    #[test]
+    fn test_parse_views_and_view_tasks() {
+        let parsed = super::parse_args_from(&args(&["debug", "views", "list1"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Views {
+                list_id: "list1".to_string()
+            }
+        );
+
+        let parsed =
+            super::parse_args_from(&args(&["debug", "view-tasks", "view1", "--json"])).unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert!(command.json);
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::ViewTasks {
+                view_id: "view1".to_string()
+            }
+        );
+
+        assert!(super::parse_args_from(&args(&["debug", "view-tasks"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_time_in_status() {
+        let parsed =
+            super::parse_args_from(&args(&["debug", "time-in-status", "t1", "--json"])).unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert!(command.json);
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::TimeInStatus {
+                task_id: "t1".to_string()
+            }
+        );
+
+        assert!(super::parse_args_from(&args(&["debug", "time-in-status"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_update_space() {
+        let parsed = super::parse_args_from(&args(&[
+            "debug",
+            "update-space",
+            "sp1",
+            "--name",
+            " Ops ",
+            "--color",
+            "7B68EE",
+            "--private",
+            "false",
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::UpdateSpace {
+                space_id: "sp1".to_string(),
+                name: Some("Ops".to_string()),
+                color: Some("#7b68ee".to_string()),
+                private: Some(false),
+            }
+        );
+
+        let parsed =
+            super::parse_args_from(&args(&["debug", "update-space", "sp1", "--private"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::UpdateSpace {
+                space_id: "sp1".to_string(),
+                name: None,
+                color: None,
+                private: Some(true),
+            }
+        );
+
+        assert!(super::parse_args_from(&args(&["debug", "update-space", "sp1"])).is_err());
+        assert!(super::parse_args_from(&args(&[
+            "debug",
+            "update-space",
+            "sp1",
+            "--color",
+            "blue"
+        ]))
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_create_space_and_folder() {
+        let parsed = super::parse_args_from(&args(&[
+            "debug",
+            "create-space",
+            "ws1",
+            "--name",
+            "Marketing",
+            "--private",
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::CreateSpace {
+                workspace_id: "ws1".to_string(),
+                name: "Marketing".to_string(),
+                private: true,
+            }
+        );
+
+        let parsed = super::parse_args_from(&args(&[
+            "debug",
+            "create-folder",
+            "sp1",
+            "--name",
+            "Q3",
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::CreateFolder {
+                space_id: "sp1".to_string(),
+                name: "Q3".to_string(),
+            }
+        );
+
+        let parsed = super::parse_args_from(&args(&[
+            "debug",
+            "create-space",
+            "ws1",
+            "--private",
+            "false",
+            "--name",
+            "Open",
+        ]))
+        .unwrap();
+        assert!(matches!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::CreateSpace { private: false, .. }
+        ));
+
+        assert!(super::parse_args_from(&args(&["debug", "create-folder", "sp1"])).is_err());
+        assert!(
+            super::parse_args_from(&args(&["debug", "create-space", "ws1", "--name", " "]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_delete_comment_with_yes() {
+        let parsed =
+            super::parse_args_from(&args(&["debug", "delete-comment", "c1", "--yes"])).unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::DeleteComment {
+                comment_id: "c1".to_string()
+            }
+        );
+        assert!(command.yes);
+
+        let parsed = super::parse_args_from(&args(&["debug", "-y", "workspaces"])).unwrap();
+        assert!(parsed.debug_command.unwrap().yes);
+        let parsed = super::parse_args_from(&args(&["debug", "delete-comment", "c1"])).unwrap();
+        assert!(!parsed.debug_command.unwrap().yes);
+    }
+
+    #[test]
+    fn test_parse_doc() {
+        let parsed = super::parse_args_from(&args(&["debug", "doc", "d1"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Doc {
+                doc_id: "d1".to_string(),
+                page_id: None,
+            }
+        );
+
+        let parsed =
+            super::parse_args_from(&args(&["debug", "doc", "d1", "--page", "p2"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Doc {
+                doc_id: "d1".to_string(),
+                page_id: Some("p2".to_string()),
+            }
+        );
+
+        assert!(super::parse_args_from(&args(&["debug", "doc"])).is_err());
+        assert!(super::parse_args_from(&args(&["debug", "doc", "d1", "--page"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_config_export_and_import() {
+        use std::path::PathBuf;
+
+        let parsed = super::parse_args_from(&args(&["debug", "config", "export"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::ConfigExport { output: None }
+        );
+        let parsed = super::parse_args_from(&args(&[
+            "debug", "config", "export", "--output", "team.toml",
+        ]))
+        .unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::ConfigExport {
+                output: Some(PathBuf::from("team.toml"))
+            }
+        );
+
+        let parsed =
+            super::parse_args_from(&args(&["debug", "config", "import", "team.toml"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::ConfigImport {
+                path: PathBuf::from("team.toml"),
+                mode: super::ImportMode::Merge,
+            }
+        );
+        let parsed = super::parse_args_from(&args(&[
+            "debug", "config", "import", "team.toml", "--replace", "--yes",
+        ]))
+        .unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::ConfigImport {
+                path: PathBuf::from("team.toml"),
+                mode: super::ImportMode::Replace,
+            }
+        );
+        assert!(command.yes);
+
+        for invalid in [
+            &["debug", "config"][..],
+            &["debug", "config", "show"],
+            &["debug", "config", "import"],
+            &["debug", "config", "export", "--output"],
+            &["debug", "config", "export", "--merge"],
+            &["debug", "config", "import", "a.toml", "--output", "b.toml"],
+            &["debug", "config", "import", "a.toml", "--merge", "--replace"],
+        ] {
+            assert!(super::parse_args_from(&args(invalid)).is_err(), "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_parse_comments_all() {
+        let parsed =
+            super::parse_args_from(&args(&["debug", "comments", "t1", "--all"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Comments {
+                task_id: "t1".to_string(),
+                all: true,
+            }
+        );
+
+        let parsed =
+            super::parse_args_from(&args(&["debug", "--all", "comments", "t1"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Comments {
+                task_id: "t1".to_string(),
+                all: true,
+            }
+        );
+
+        let parsed = super::parse_args_from(&args(&["debug", "comments", "t1"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Comments {
+                task_id: "t1".to_string(),
+                all: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_single_comment() {
+        let parsed = super::parse_args_from(&args(&["debug", "comment", "c1", "--json"])).unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert!(command.json);
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::Comment {
+                comment_id: "c1".to_string(),
+            }
+        );
+
+        assert!(super::parse_args_from(&args(&["debug", "comment"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_features() {
+        let parsed = super::parse_args_from(&args(&["debug", "features", "ws-1"])).unwrap();
+        assert_eq!(
+            parsed.debug_command.unwrap().operation,
+            super::DebugOperation::Features {
+                workspace_id: "ws-1".to_string(),
+            }
+        );
+        assert!(super::parse_args_from(&args(&["debug", "features"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_timesheet() {
+        let parsed = super::parse_args_from(&args(&[
+            "debug",
+            "timesheet",
+            "ws1",
+            "--from",
+            "2024-03-04",
+            "--to",
+            "2024-03-08",
+            "--user",
+            "42",
+            "--json",
+        ]))
+        .unwrap();
+        let command = parsed.debug_command.unwrap();
+        assert!(command.json);
+        assert_eq!(
+            command.operation,
+            super::DebugOperation::Timesheet {
+                workspace_id: "ws1".to_string(),
+                range: super::parse_date_range("2024-03-04", "2024-03-08").unwrap(),
+                user: Some("42".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_timesheet_requires_valid_range() {
+        let timesheet = |from: &str, to: &str| {
+            super::parse_args_from(&args(&[
+                "debug",
+                "timesheet",
+                "ws1",
+                "--from",
+                from,
+                "--to",
+                to,
+            ]))
+        };
+        assert!(timesheet("2024-03-08", "2024-03-04").is_err());
+        assert!(timesheet("2024-03-04", "next friday").is_err());
+        assert!(timesheet("2024-03-04", "2024-03-04").is_ok());
+        assert!(super::parse_args_from(&args(&["debug", "timesheet", "ws1"])).is_err());
+    }
+
+    #[test]
+    fn test_date_range_millis_cover_whole_days() {
+        let range = super::parse_date_range("2024-03-04", "2024-03-05").unwrap();
+        let (from, to) = range.millis_in(&chrono::Utc);
+        assert_eq!(from, 1_709_510_400_000);
+        assert_eq!(to, 1_709_510_400_000 + 2 * 24 * 60 * 60 * 1000 - 1);
+    }
+
+    #[test]
+    fn test_parse_standup_since() {
+        let standup = |extra: &[&str]| {
+            let mut argv = vec!["debug", "standup", "ws1"];
+            argv.extend_from_slice(extra);
+            super::parse_args_from(&args(&argv)).map(|p| p.debug_command.unwrap().operation)
+        };
+        let with_since = |since: super::Since| super::DebugOperation::Standup {
+            workspace_id: "ws1".to_string(),
+            since,
+        };
+
+        assert_eq!(
+            standup(&[]),
+            Ok(with_since(super::Since::Ago(chrono::TimeDelta::hours(24))))
+        );
+        assert_eq!(
+            standup(&["--since", "3d"]),
+            Ok(with_since(super::Since::Ago(chrono::TimeDelta::days(3))))
+        );
+        assert_eq!(
+            standup(&["--since", "2024-03-04"]),
+            Ok(with_since(super::Since::Day(
+                chrono::NaiveDate::from_ymd_opt(2024, 3, 4).unwrap()
+            )))
+        );
+        assert!(standup(&["--since", "yesterday"]).is_err());
+        assert!(standup(&["--since", "0h"]).is_err());
+        assert!(super::parse_args_from(&args(&["debug", "standup"])).is_err());
+    }
+
+    #[test]
+    fn test_since_cutoff_millis() {
+        use chrono::TimeZone;
+        let now = chrono::Utc.timestamp_millis_opt(1_709_600_000_000).unwrap();
+        assert_eq!(
+            super::parse_since("24h").unwrap().cutoff_millis(&now),
+            1_709_600_000_000 - 24 * 60 * 60 * 1000
+        );
+        assert_eq!(
+            super::parse_since("2024-03-04").unwrap().cutoff_millis(&now),
+            1_709_510_400_000
+        );
+    }
+
+    use super::{parse_open_target, OpenTarget};
+
+    fn task(id: &str) -> OpenTarget {
+        OpenTarget::Task {
+            task_id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_open_target_short_task_url() {
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/t/86b2xyz"),
+            Ok(task("86b2xyz"))
+        );
+    }
+
+    #[test]
+    fn test_open_target_task_url_without_scheme_or_https() {
+        assert_eq!(
+            parse_open_target("app.clickup.com/t/86b2xyz"),
+            Ok(task("86b2xyz"))
+        );
+        assert_eq!(
+            parse_open_target("http://app.clickup.com/t/86b2xyz"),
+            Ok(task("86b2xyz"))
+        );
+    }
+
+    #[test]
+    fn test_open_target_task_shorthand_and_bare_id() {
+        assert_eq!(parse_open_target("t/86b2xyz"), Ok(task("86b2xyz")));
+        assert_eq!(parse_open_target("86b2xyz"), Ok(task("86b2xyz")));
+    }
+
+    #[test]
+    fn test_open_target_workspace_prefixed_custom_id() {
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/t/9012345/PROJ-42"),
+            Ok(OpenTarget::WorkspaceTask {
+                workspace_id: "9012345".to_string(),
+                task_id: "PROJ-42".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_open_target_long_form_task_and_comment_urls() {
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/ws1/l/list1/t/86b2xyz"),
+            Ok(task("86b2xyz"))
+        );
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/t/86b2xyz?comment=90"),
+            Ok(task("86b2xyz"))
+        );
+    }
+
+    #[test]
+    fn test_open_target_list_urls() {
+        let list = OpenTarget::List {
+            list_id: "901234567".to_string(),
+        };
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/9012345/v/li/901234567"),
+            Ok(list.clone())
+        );
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/9012345/v/l/6-901234567-1"),
+            Ok(list)
+        );
+    }
+
+    #[test]
+    fn test_open_target_doc_urls() {
+        let doc = OpenTarget::Document {
+            doc_id: "8cdu0-1234".to_string(),
+        };
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/d/8cdu0-1234"),
+            Ok(doc.clone())
+        );
+        assert_eq!(parse_open_target("d/8cdu0-1234"), Ok(doc.clone()));
+        assert_eq!(
+            parse_open_target("https://app.clickup.com/9012345/v/dc/8cdu0-1234/8cdu0-5678"),
+            Ok(doc)
+        );
+    }
+
+    #[test]
+    fn test_open_target_rejects_invalid_input() {
+        assert!(parse_open_target("").is_err());
+        assert!(parse_open_target("not a task").is_err());
+        assert!(parse_open_target("https://example.com/t/86b2xyz").is_err());
+        // Workspace/space links are not openable targets
+        assert!(parse_open_target("https://app.clickup.com/9012345").is_err());
+    }
 }
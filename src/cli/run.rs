@@ -2,11 +2,173 @@
 //!
 //! Handles running the CLI debug mode operations.
 
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::api::{AuthManager, ClickUpApi, ClickUpClient};
+use crate::cache::CacheManager;
 use crate::cli::args::{exit_codes, DebugCommand, DebugOperation};
 use crate::commands::DebugOperations;
+use crate::config::share::{self, ImportMode};
+use crate::config::{AppConfig, ConfigManager};
+use crate::models::UpdateSpaceRequest;
+
+/// Outcome of asking before a destructive operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Confirmation {
+    /// Go ahead: `--yes` was given or the user answered yes
+    Confirmed,
+    /// The user answered anything but yes
+    Declined,
+    /// Nobody can be asked (stdin is not a terminal) and `--yes` is missing
+    NeedsYes,
+}
+
+/// Ask `prompt` with a y/N question on `output` and read the answer from
+/// `input`, unless `yes` skips the question
+///
+/// `interactive` says whether `input` is a terminal; when it isn't, the
+/// answer can't come from a person, so `--yes` is required instead.
+pub fn confirm<R: BufRead, W: Write>(
+    prompt: &str,
+    yes: bool,
+    interactive: bool,
+    input: &mut R,
+    output: &mut W,
+) -> io::Result<Confirmation> {
+    if yes {
+        return Ok(Confirmation::Confirmed);
+    }
+    if !interactive {
+        return Ok(Confirmation::NeedsYes);
+    }
+
+    write!(output, "{} [y/N] ", prompt)?;
+    output.flush()?;
+    let mut answer = String::new();
+    input.read_line(&mut answer)?;
+    Ok(match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Confirmation::Confirmed,
+        _ => Confirmation::Declined,
+    })
+}
+
+/// The question to ask before `operation`, `None` when it needs no confirmation
+fn confirmation_prompt(operation: &DebugOperation) -> Option<String> {
+    match operation {
+        DebugOperation::DeleteComment { comment_id } => {
+            Some(format!("Delete comment {}?", comment_id))
+        }
+        _ => None,
+    }
+}
+
+/// Ask `prompt` on the terminal, or take `--yes` for an answer
+///
+/// Returns the exit code to stop with unless the answer is yes.
+fn ask_to_proceed(prompt: &str, yes: bool) -> Result<(), i32> {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    match confirm(prompt, yes, interactive, &mut stdin.lock(), &mut io::stderr()) {
+        Ok(Confirmation::Confirmed) => Ok(()),
+        Ok(Confirmation::Declined) => {
+            eprintln!("Cancelled.");
+            Err(exit_codes::GENERAL_ERROR)
+        }
+        Ok(Confirmation::NeedsYes) => {
+            eprintln!(
+                "Refusing to run a destructive operation without confirmation: \
+                 stdin is not a terminal. Pass --yes to proceed."
+            );
+            Err(exit_codes::INVALID_ARGS)
+        }
+        Err(e) => {
+            eprintln!("Failed to read confirmation: {}", e);
+            Err(exit_codes::GENERAL_ERROR)
+        }
+    }
+}
+
+/// `debug config export`: print the config to share, or write it to `output`
+fn export_config(output: Option<&Path>) -> i32 {
+    let exported = ConfigManager::load_config().and_then(|config| share::export(&config));
+    let exported = match exported {
+        Ok(exported) => exported,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            return exit_codes::GENERAL_ERROR;
+        }
+    };
+    match output {
+        None => {
+            print!("{}", exported);
+            exit_codes::SUCCESS
+        }
+        Some(path) => match std::fs::write(path, exported) {
+            Ok(()) => {
+                eprintln!("Config exported to {}", path.display());
+                exit_codes::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("Failed to write {}: {}", path.display(), e);
+                exit_codes::GENERAL_ERROR
+            }
+        },
+    }
+}
+
+/// `debug config import`: show what importing `path` changes in the local
+/// config, then write it once confirmed
+fn import_config(path: &Path, mode: ImportMode, yes: bool) -> i32 {
+    let imported = std::fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|content| share::parse_import(&content));
+    let imported = match imported {
+        Ok(imported) => imported,
+        Err(e) => {
+            eprintln!("Invalid config {}: {:#}", path.display(), e);
+            return exit_codes::INVALID_ARGS;
+        }
+    };
+    let local = ConfigManager::config_path()
+        .and_then(|config_path| Ok((share::read_local(&config_path)?, config_path)));
+    let (local, config_path) = match local {
+        Ok(local) => local,
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            return exit_codes::GENERAL_ERROR;
+        }
+    };
+
+    let merged = share::merge(&local, &imported, mode);
+    let changes = share::diff(&local, &merged);
+    if changes.is_empty() {
+        println!("{} already matches {}", config_path.display(), path.display());
+        return exit_codes::SUCCESS;
+    }
+    println!("Changes to {}:", config_path.display());
+    for change in &changes {
+        println!("  {}", change);
+    }
+    if let Err(code) = ask_to_proceed(&format!("Apply {} change(s)?", changes.len()), yes) {
+        return code;
+    }
+    match share::write(&config_path, &merged) {
+        Ok(Some(backup)) => {
+            println!("Config updated; the previous one is kept as {}", backup.display());
+            exit_codes::SUCCESS
+        }
+        Ok(None) => {
+            println!("Config written to {}", config_path.display());
+            exit_codes::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {:#}", e);
+            exit_codes::GENERAL_ERROR
+        }
+    }
+}
 
 /// Run the CLI with the given arguments
 /// Returns the exit code as an i32
@@ -31,9 +193,44 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
         tracing::info!("Using override token");
     }
 
+    // Config sharing works on local files only (no auth needed)
+    match &command.operation {
+        DebugOperation::ConfigExport { output } => return export_config(output.as_deref()),
+        DebugOperation::ConfigImport { path, mode } => {
+            return import_config(path, *mode, command.yes)
+        }
+        _ => {}
+    }
+
+    // Destructive operations need --yes or an answer before anything runs
+    if let Some(prompt) = confirmation_prompt(&command.operation) {
+        if let Err(code) = ask_to_proceed(&prompt, command.yes) {
+            return code;
+        }
+    }
+
     // Initialize auth manager
     let auth = AuthManager::default();
 
+    // Environment checks report a missing token instead of requiring one
+    if matches!(command.operation, DebugOperation::VerifyEnv) {
+        let token = command
+            .token_override
+            .clone()
+            .or_else(|| auth.load_token().unwrap_or(None))
+            .unwrap_or_default();
+        let api = ClickUpClient::new(token).boxed();
+        let debug_ops = DebugOperations::new(api, auth, command.token_override.clone());
+        return match debug_ops.verify_environment().await {
+            Ok(true) => exit_codes::SUCCESS,
+            Ok(false) => exit_codes::GENERAL_ERROR,
+            Err(e) => {
+                eprintln!("Error verifying environment: {}", e);
+                exit_codes::GENERAL_ERROR
+            }
+        };
+    }
+
     // Get token (use override if provided)
     let token = match command.token_override.clone() {
         Some(override_token) => override_token,
@@ -52,11 +249,23 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
     };
 
     // Create API client
-    let client = ClickUpClient::new(token);
+    let mut client = ClickUpClient::new(token);
+    if command.dump_responses {
+        match ConfigManager::cache_dir() {
+            Ok(dir) => client = client.with_response_dumps(dir.join("responses")),
+            Err(e) => tracing::warn!("Response dumps disabled: {:#}", e),
+        }
+    }
     let api: Arc<dyn ClickUpApi> = Arc::new(client);
 
+    let config = ConfigManager::load_config().unwrap_or_else(|e| {
+        tracing::warn!("Using default settings: {:#}", e);
+        AppConfig::default()
+    });
+
     // Create debug operations handler
-    let debug_ops = DebugOperations::new(api, auth, command.token_override.clone());
+    let debug_ops = DebugOperations::new(api, auth, command.token_override.clone())
+        .with_comment_order(config.comment_order);
 
     // Execute the operation
     let result = match command.operation {
@@ -81,6 +290,18 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 debug_ops.search_docs(query).await
             }
         }
+        DebugOperation::Doc {
+            ref doc_id,
+            ref page_id,
+        } => {
+            if command.json {
+                debug_ops
+                    .get_document_json(doc_id, page_id.as_deref())
+                    .await
+            } else {
+                debug_ops.get_document(doc_id, page_id.as_deref()).await
+            }
+        }
         DebugOperation::AuthStatus => match debug_ops.check_auth_status().await {
             Ok(true) => return exit_codes::SUCCESS,
             Ok(false) => return exit_codes::AUTH_ERROR,
@@ -103,6 +324,13 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 debug_ops.list_spaces(workspace_id).await
             }
         }
+        DebugOperation::Features { ref workspace_id } => {
+            if command.json {
+                debug_ops.workspace_features_json(workspace_id).await
+            } else {
+                debug_ops.workspace_features(workspace_id).await
+            }
+        }
         DebugOperation::Folders { ref space_id } => {
             if command.json {
                 debug_ops.list_folders_json(space_id).await
@@ -110,6 +338,46 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 debug_ops.list_folders(space_id).await
             }
         }
+        DebugOperation::CreateSpace {
+            ref workspace_id,
+            ref name,
+            private,
+        } => {
+            if command.json {
+                debug_ops
+                    .create_space_json(workspace_id, name, private)
+                    .await
+            } else {
+                debug_ops.create_space(workspace_id, name, private).await
+            }
+        }
+        DebugOperation::UpdateSpace {
+            ref space_id,
+            ref name,
+            ref color,
+            private,
+        } => {
+            let update = UpdateSpaceRequest {
+                name: name.clone(),
+                color: color.clone(),
+                private,
+            };
+            if command.json {
+                debug_ops.update_space_json(space_id, &update).await
+            } else {
+                debug_ops.update_space(space_id, &update).await
+            }
+        }
+        DebugOperation::CreateFolder {
+            ref space_id,
+            ref name,
+        } => {
+            if command.json {
+                debug_ops.create_folder_json(space_id, name).await
+            } else {
+                debug_ops.create_folder(space_id, name).await
+            }
+        }
         DebugOperation::Lists { ref id, in_space } => {
             if command.json {
                 debug_ops.list_lists_json(id, in_space).await
@@ -127,14 +395,38 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 debug_ops.get_task_json(task_id).await
             }
         }
+        DebugOperation::TaskByCustomId {
+            ref custom_id,
+            ref workspace_id,
+        } => {
+            if command.json {
+                debug_ops.task_by_custom_id_json(custom_id, workspace_id).await
+            } else {
+                debug_ops.task_by_custom_id(custom_id, workspace_id).await
+            }
+        }
+        DebugOperation::OpenUrl { ref url } => {
+            if command.json {
+                debug_ops.open_url_json(url).await
+            } else {
+                debug_ops.open_url(url).await
+            }
+        }
         DebugOperation::Explore { ref workspace_id } => {
             debug_ops.explore_hierarchy(workspace_id).await
         }
-        DebugOperation::Comments { ref task_id } => {
+        DebugOperation::Comments { ref task_id, all } => {
             if command.json {
-                debug_ops.get_comments_json(task_id).await
+                debug_ops.get_comments_json(task_id, all).await
             } else {
-                debug_ops.get_comments(task_id).await
+                debug_ops.get_comments(task_id, all).await
+            }
+        }
+        DebugOperation::Comment { ref comment_id } => {
+            if command.json {
+                debug_ops.get_comment_json(comment_id).await
+            } else {
+                debug_ops.get_comment(comment_id).await
             }
         }
         DebugOperation::CreateComment { ref task_id } => {
@@ -189,7 +481,89 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 debug_ops.update_comment(comment_id, text).await
             }
         }
-        DebugOperation::Help => {
+        DebugOperation::DeleteComment { ref comment_id } => {
+            if command.json {
+                debug_ops.delete_comment_json(comment_id).await
+            } else {
+                debug_ops.delete_comment(comment_id).await
+            }
+        }
+        DebugOperation::DrainQueue => {
+            match ConfigManager::database_path().and_then(CacheManager::new) {
+                Ok(mut cache) => {
+                    if command.json {
+                        debug_ops.drain_queue_json(&mut cache).await
+                    } else {
+                        debug_ops.drain_queue(&mut cache).await
+                    }
+                }
+                Err(e) => Err(e.into()),
+            }
+        }
+        DebugOperation::Views { ref list_id } => {
+            if command.json {
+                debug_ops.list_views_json(list_id).await
+            } else {
+                debug_ops.list_views(list_id).await
+            }
+        }
+        DebugOperation::ViewTasks { ref view_id } => {
+            if command.json {
+                debug_ops.view_tasks_json(view_id).await
+            } else {
+                debug_ops.view_tasks(view_id).await
+            }
+        }
+        DebugOperation::SprintOverview { ref view_id } => {
+            if command.json {
+                debug_ops.sprint_overview_json(view_id).await
+            } else {
+                debug_ops.sprint_overview(view_id).await
+            }
+        }
+        DebugOperation::TimeInStatus { ref task_id } => {
+            if command.json {
+                debug_ops.time_in_status_json(task_id).await
+            } else {
+                debug_ops.time_in_status(task_id).await
+            }
+        }
+        DebugOperation::MyTasks { ref workspace_id } => {
+            if command.json {
+                debug_ops.my_tasks_json(workspace_id).await
+            } else {
+                debug_ops.my_tasks(workspace_id).await
+            }
+        }
+        DebugOperation::Timesheet {
+            ref workspace_id,
+            range,
+            ref user,
+        } => {
+            if command.json {
+                debug_ops
+                    .timesheet_json(workspace_id, range, user.as_deref())
+                    .await
+            } else {
+                debug_ops
+                    .timesheet(workspace_id, range, user.as_deref())
+                    .await
+            }
+        }
+        DebugOperation::Standup {
+            ref workspace_id,
+            since,
+        } => {
+            if command.json {
+                debug_ops.standup_json(workspace_id, since).await
+            } else {
+                debug_ops.standup(workspace_id, since).await
+            }
+        }
+        DebugOperation::Help
+        | DebugOperation::VerifyEnv
+        | DebugOperation::ConfigExport { .. }
+        | DebugOperation::ConfigImport { .. } => {
             // Already handled above
             return exit_codes::SUCCESS;
         }
@@ -213,8 +587,6 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
                 || err_msg.contains("timeout")
             {
                 exit_codes::NETWORK_ERROR
-            } else if err_msg.contains("not found") || err_msg.contains("404") {
-                exit_codes::GENERAL_ERROR
             } else {
                 exit_codes::GENERAL_ERROR
             };
@@ -229,3 +601,64 @@ pub async fn run_cli(command: DebugCommand) -> i32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ask(yes: bool, interactive: bool, answer: &str) -> (Confirmation, String) {
+        let mut input = answer.as_bytes();
+        let mut output = Vec::new();
+        let result = confirm(
+            "Delete comment c1?",
+            yes,
+            interactive,
+            &mut input,
+            &mut output,
+        )
+        .unwrap();
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_confirm_reads_answer_from_terminal() {
+        let (result, output) = ask(false, true, "y\n");
+        assert_eq!(result, Confirmation::Confirmed);
+        assert_eq!(output, "Delete comment c1? [y/N] ");
+
+        assert_eq!(ask(false, true, "YES\n").0, Confirmation::Confirmed);
+        assert_eq!(ask(false, true, "n\n").0, Confirmation::Declined);
+        // Enter or end of input takes the default, no
+        assert_eq!(ask(false, true, "\n").0, Confirmation::Declined);
+        assert_eq!(ask(false, true, "").0, Confirmation::Declined);
+    }
+
+    #[test]
+    fn test_confirm_yes_skips_prompt() {
+        let (result, output) = ask(true, false, "");
+        assert_eq!(result, Confirmation::Confirmed);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_confirm_without_terminal_needs_yes() {
+        let (result, output) = ask(false, false, "y\n");
+        assert_eq!(result, Confirmation::NeedsYes);
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_only_destructive_operations_ask() {
+        let delete = DebugOperation::DeleteComment {
+            comment_id: "c1".to_string(),
+        };
+        assert_eq!(
+            confirmation_prompt(&delete).as_deref(),
+            Some("Delete comment c1?")
+        );
+        let update = DebugOperation::UpdateComment {
+            comment_id: "c1".to_string(),
+        };
+        assert!(confirmation_prompt(&update).is_none());
+    }
+}
@@ -2,9 +2,18 @@
 //!
 //! Implements the actual data-fetching operations for debug commands.
 
-use crate::api::{AuthManager, ClickUpApi};
+use crate::api::client::api_error_message;
+use crate::api::{drain_queue, AuthManager, ClickUpApi};
+use crate::cache::CacheManager;
+use crate::cli::args::{DateRange, Since};
+use crate::commands::verify_env::{self, EnvPaths};
+use crate::config::CommentOrder;
 use crate::models::document::DocumentFilters;
+use crate::models::standup::{Standup, StandupTask};
 use crate::models::task::TaskFilters;
+use crate::models::time_entry::{aggregate_by_day, format_duration, DayTotal};
+use crate::models::time_in_status::format_status_duration;
+use crate::models::{ClickUpSpace, Feature, Task, UpdateSpaceRequest};
 use std::sync::Arc;
 
 /// Exit codes for CLI operations
@@ -24,6 +33,8 @@ pub struct DebugOperations {
     auth: AuthManager,
     #[allow(dead_code)]
     token_override: Option<String>,
+    /// Order comments are printed in
+    comment_order: CommentOrder,
 }
 
 impl DebugOperations {
@@ -37,9 +48,16 @@ impl DebugOperations {
             api,
             auth,
             token_override,
+            comment_order: CommentOrder::default(),
         }
     }
 
+    /// Print comments in `order` instead of the default
+    pub fn with_comment_order(mut self, order: CommentOrder) -> Self {
+        self.comment_order = order;
+        self
+    }
+
     /// Get the API client, using override token if provided
     fn get_api(&self) -> Arc<dyn ClickUpApi> {
         Arc::clone(&self.api)
@@ -116,6 +134,54 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// List tasks assigned to the current user across a workspace
+    pub async fn my_tasks(&self, workspace_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let tasks = api
+            .get_my_tasks(workspace_id, &TaskFilters::default())
+            .await?;
+
+        if tasks.is_empty() {
+            println!("No tasks assigned to you in workspace {}.", workspace_id);
+            return Ok(());
+        }
+
+        for task in &tasks {
+            let status = task
+                .status
+                .as_ref()
+                .map(|s| s.status.as_str())
+                .unwrap_or("unknown");
+            let list = task
+                .list
+                .as_ref()
+                .and_then(|l| l.name.as_deref())
+                .unwrap_or("unknown list");
+            println!(
+                "{} - {} [status: {}, list: {}]",
+                task.id, task.name, status, list
+            );
+        }
+
+        Ok(())
+    }
+
+    /// List tasks assigned to the current user as JSON
+    pub async fn my_tasks_json(
+        &self,
+        workspace_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let tasks = api
+            .get_my_tasks(workspace_id, &TaskFilters::default())
+            .await?;
+
+        let json = serde_json::to_string_pretty(&tasks)?;
+        println!("{}", json);
+
+        Ok(())
+    }
+
     /// Search documents
     pub async fn search_docs(&self, query: &str) -> Result<(), Box<dyn std::error::Error>> {
         let api = self.get_api();
@@ -152,6 +218,57 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// Print a document's pages, or just `page_id`, as markdown
+    pub async fn get_document(
+        &self,
+        doc_id: &str,
+        page_id: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let (title, pages) = match page_id {
+            Some(page_id) => {
+                let page = api.get_document_page(doc_id, page_id).await?;
+                (page.name.clone(), vec![page])
+            }
+            None => {
+                let doc = api.get_document(doc_id).await?;
+                (doc.name, doc.pages)
+            }
+        };
+
+        println!("=== {} ===\n", title);
+        if pages.is_empty() {
+            println!("(no pages)");
+        }
+        for page in &pages {
+            let content = page
+                .content_markdown
+                .as_deref()
+                .or(page.content.as_deref())
+                .unwrap_or("");
+            println!("## {} ({})\n\n{}\n", page.name, page.id, content);
+        }
+
+        Ok(())
+    }
+
+    /// Print a document with its pages, or just `page_id`, as JSON
+    pub async fn get_document_json(
+        &self,
+        doc_id: &str,
+        page_id: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let json = match page_id {
+            Some(page_id) => {
+                serde_json::to_string_pretty(&api.get_document_page(doc_id, page_id).await?)?
+            }
+            None => serde_json::to_string_pretty(&api.get_document(doc_id).await?)?,
+        };
+        println!("{}", json);
+        Ok(())
+    }
+
     /// Get a single task
     pub async fn get_task_json(&self, task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
         let api = self.get_api();
@@ -161,6 +278,78 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// Show the task a ClickUp task URL points at
+    pub async fn open_url(&self, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let task = api.get_task_from_url(url).await?;
+        print_task_summary(&task);
+        Ok(())
+    }
+
+    /// Show the task a ClickUp task URL points at as JSON
+    pub async fn open_url_json(&self, url: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let task = api.get_task_from_url(url).await?;
+        let json = serde_json::to_string_pretty(&task)?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Show the task with custom ID `custom_id` in a workspace
+    pub async fn task_by_custom_id(
+        &self,
+        custom_id: &str,
+        workspace_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let task = api.get_task_by_custom_id(custom_id, workspace_id).await?;
+        print_task_summary(&task);
+        Ok(())
+    }
+
+    /// Show the task with custom ID `custom_id` in a workspace as JSON
+    pub async fn task_by_custom_id_json(
+        &self,
+        custom_id: &str,
+        workspace_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let task = api.get_task_by_custom_id(custom_id, workspace_id).await?;
+        let json = serde_json::to_string_pretty(&task)?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Check the token, directories, cache database, API, terminal and disk
+    /// space, printing one line per check
+    ///
+    /// Returns whether every check passed.
+    pub async fn verify_environment(&self) -> Result<bool, Box<dyn std::error::Error>> {
+        let token = match &self.token_override {
+            Some(token) => Some(token.clone()),
+            None => self.auth.load_token().unwrap_or(None),
+        };
+        let paths = EnvPaths::from_system()?;
+        let term = std::env::var("TERM").ok();
+        Ok(self
+            .print_env_checks(token.as_deref(), &paths, term.as_deref())
+            .await)
+    }
+
+    /// Run the environment checks against the given paths and print them
+    pub async fn print_env_checks(
+        &self,
+        token: Option<&str>,
+        paths: &EnvPaths,
+        term: Option<&str>,
+    ) -> bool {
+        let checks = verify_env::run_checks(self.api.as_ref(), token, paths, term).await;
+        for check in &checks {
+            println!("{}", check);
+        }
+        checks.iter().all(|check| check.passed)
+    }
+
     /// Check authentication status
     pub async fn check_auth_status(&self) -> Result<bool, Box<dyn std::error::Error>> {
         // Try to get workspaces to verify auth
@@ -233,6 +422,40 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// List the features turned on in a workspace
+    pub async fn workspace_features(
+        &self,
+        workspace_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let features = api.get_workspace_features(workspace_id).await?;
+
+        if features.iter().next().is_none() {
+            println!("No features reported for workspace {}.", workspace_id);
+            return Ok(());
+        }
+
+        for (key, on) in features.iter() {
+            println!("{:<24} {}", key, if on { "on" } else { "off" });
+        }
+
+        Ok(())
+    }
+
+    /// List the features turned on in a workspace as JSON
+    pub async fn workspace_features_json(
+        &self,
+        workspace_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let features = api.get_workspace_features(workspace_id).await?;
+
+        let json = serde_json::to_string_pretty(&features)?;
+        println!("{}", json);
+
+        Ok(())
+    }
+
     /// List folders in a space
     pub async fn list_folders(&self, space_id: &str) -> Result<(), Box<dyn std::error::Error>> {
         let api = self.get_api();
@@ -265,6 +488,90 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// Create a space (human-readable)
+    pub async fn create_space(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let space = self
+            .get_api()
+            .create_space(workspace_id, name, private)
+            .await
+            .map_err(|e| explain_rejection("create space", e))?;
+        println!("Space created: {}", space_summary(&space));
+        Ok(())
+    }
+
+    /// Create a space (JSON)
+    pub async fn create_space_json(
+        &self,
+        workspace_id: &str,
+        name: &str,
+        private: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let space = self
+            .get_api()
+            .create_space(workspace_id, name, private)
+            .await
+            .map_err(|e| explain_rejection("create space", e))?;
+        println!("{}", serde_json::to_string_pretty(&space)?);
+        Ok(())
+    }
+
+    /// Change a space's settings (human-readable)
+    pub async fn update_space(
+        &self,
+        space_id: &str,
+        update: &UpdateSpaceRequest,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let space = self
+            .get_api()
+            .update_space(space_id, update)
+            .await
+            .map_err(|e| explain_rejection("update space", e))?;
+        println!("Space updated: {}", space_summary(&space));
+        Ok(())
+    }
+
+    /// Change a space's settings (JSON)
+    pub async fn update_space_json(
+        &self,
+        space_id: &str,
+        update: &UpdateSpaceRequest,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let space = self
+            .get_api()
+            .update_space(space_id, update)
+            .await
+            .map_err(|e| explain_rejection("update space", e))?;
+        println!("{}", serde_json::to_string_pretty(&space)?);
+        Ok(())
+    }
+
+    /// Create a folder (human-readable)
+    pub async fn create_folder(
+        &self,
+        space_id: &str,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let folder = self.get_api().create_folder(space_id, name).await?;
+        println!("Folder created: {} (ID: {})", folder.name, folder.id);
+        Ok(())
+    }
+
+    /// Create a folder (JSON)
+    pub async fn create_folder_json(
+        &self,
+        space_id: &str,
+        name: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let folder = self.get_api().create_folder(space_id, name).await?;
+        println!("{}", serde_json::to_string_pretty(&folder)?);
+        Ok(())
+    }
+
     /// List lists in a folder
     pub async fn list_lists_in_folder(
         &self,
@@ -352,9 +659,12 @@ impl DebugOperations {
     }
 
     /// Get comments for a task (human-readable)
-    pub async fn get_comments(&self, task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let api = self.get_api();
-        let comments = api.get_task_comments(task_id).await?;
+    pub async fn get_comments(
+        &self,
+        task_id: &str,
+        all: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (comments, more) = self.fetch_comments(task_id, all).await?;
         println!("=== Comments for task {} ===\n", task_id);
         for comment in &comments {
             println!(
@@ -368,18 +678,71 @@ impl DebugOperations {
                     .unwrap_or("unknown")
             );
         }
+        if more {
+            println!("\n(older comments not shown; use --all to fetch every page)");
+        }
         Ok(())
     }
 
-    /// Get comments for a task (JSON)
-    pub async fn get_comments_json(&self, task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// Fetch the newest page of a task's comments, or every page when `all`
+    /// is set, in the configured order, and whether older comments were left out
+    async fn fetch_comments(
+        &self,
+        task_id: &str,
+        all: bool,
+    ) -> anyhow::Result<(Vec<crate::models::Comment>, bool)> {
         let api = self.get_api();
-        let comments = api.get_task_comments(task_id).await?;
+        let (mut comments, more) = if all {
+            (api.get_all_task_comments(task_id).await?, false)
+        } else {
+            let page = api.get_task_comments(task_id, None, None).await?;
+            (page.comments, page.next_cursor.is_some())
+        };
+        self.comment_order.arrange(&mut comments);
+        Ok((comments, more))
+    }
+
+    /// Get comments for a task (JSON)
+    pub async fn get_comments_json(
+        &self,
+        task_id: &str,
+        all: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (comments, _) = self.fetch_comments(task_id, all).await?;
         let json = serde_json::to_string_pretty(&comments)?;
         println!("{}", json);
         Ok(())
     }
 
+    /// Get a single comment (human-readable)
+    pub async fn get_comment(&self, comment_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let comment = self.get_api().get_comment(comment_id).await?;
+        println!(
+            "[{}] {} ({})",
+            comment.id,
+            comment.text,
+            comment
+                .commenter
+                .as_ref()
+                .map(|c| c.username.as_str())
+                .unwrap_or("unknown")
+        );
+        if let Some(parent_id) = &comment.parent_id {
+            println!("  reply to {}", parent_id);
+        }
+        Ok(())
+    }
+
+    /// Get a single comment (JSON)
+    pub async fn get_comment_json(
+        &self,
+        comment_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let comment = self.get_api().get_comment(comment_id).await?;
+        println!("{}", serde_json::to_string_pretty(&comment)?);
+        Ok(())
+    }
+
     /// Update a comment (human-readable)
     pub async fn update_comment(
         &self,
@@ -417,6 +780,27 @@ impl DebugOperations {
         Ok(())
     }
 
+    /// Delete a comment (human-readable)
+    pub async fn delete_comment(&self, comment_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.get_api().delete_comment(comment_id).await?;
+        println!("Comment deleted: {}", comment_id);
+        Ok(())
+    }
+
+    /// Delete a comment (JSON)
+    pub async fn delete_comment_json(
+        &self,
+        comment_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.get_api().delete_comment(comment_id).await?;
+        let json = serde_json::to_string_pretty(&serde_json::json!({
+            "deleted": true,
+            "comment_id": comment_id,
+        }))?;
+        println!("{}", json);
+        Ok(())
+    }
+
     /// Create a comment on a task (human-readable)
     pub async fn create_comment(
         &self,
@@ -508,4 +892,396 @@ impl DebugOperations {
         println!("{}", json);
         Ok(())
     }
+
+    /// Send the writes queued while offline, reporting those ClickUp rejected
+    ///
+    /// Fails when the network drops again before the queue is empty.
+    pub async fn drain_queue(
+        &self,
+        cache: &mut CacheManager,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let report = drain_queue(api.as_ref(), cache).await?;
+
+        for (request, error) in &report.rejected {
+            println!("Rejected: {} {} ({})", request.method, request.url, error);
+        }
+        println!(
+            "Sent {} queued request(s), {} rejected.",
+            report.sent,
+            report.rejected.len()
+        );
+        if report.remaining > 0 {
+            return Err(format!(
+                "Still offline: {} request(s) remain queued",
+                report.remaining
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Send the writes queued while offline, reporting as JSON
+    pub async fn drain_queue_json(
+        &self,
+        cache: &mut CacheManager,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let report = drain_queue(api.as_ref(), cache).await?;
+
+        let rejected: Vec<_> = report
+            .rejected
+            .iter()
+            .map(|(request, error)| {
+                serde_json::json!({
+                    "method": request.method,
+                    "url": request.url,
+                    "queued_at": request.queued_at,
+                    "error": error,
+                })
+            })
+            .collect();
+        let json = serde_json::json!({
+            "sent": report.sent,
+            "rejected": rejected,
+            "remaining": report.remaining,
+        });
+        println!("{}", serde_json::to_string_pretty(&json)?);
+
+        Ok(())
+    }
+
+    /// List the views saved on a list
+    pub async fn list_views(&self, list_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let views = api.get_list_views(list_id).await?;
+
+        if views.is_empty() {
+            println!("No views saved on list {}.", list_id);
+            return Ok(());
+        }
+
+        for view in &views {
+            println!(
+                "{} - {} [type: {}]",
+                view.id,
+                view.name,
+                view.view_type.as_deref().unwrap_or("unknown")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// List the views saved on a list as JSON
+    pub async fn list_views_json(&self, list_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let views = api.get_list_views(list_id).await?;
+
+        let json = serde_json::to_string_pretty(&views)?;
+        println!("{}", json);
+
+        Ok(())
+    }
+
+    /// List every task shown in a view, across its pages
+    pub async fn view_tasks(&self, view_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let tasks = api.get_view_tasks(view_id).await?;
+
+        if tasks.is_empty() {
+            println!("No tasks in view {}.", view_id);
+            return Ok(());
+        }
+
+        for task in &tasks {
+            let status = task
+                .status
+                .as_ref()
+                .map(|s| s.status.as_str())
+                .unwrap_or("unknown");
+            println!("{} - {} [status: {}]", task.id, task.name, status);
+        }
+
+        Ok(())
+    }
+
+    /// List every task shown in a view as JSON
+    pub async fn view_tasks_json(&self, view_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let tasks = api.get_view_tasks(view_id).await?;
+
+        let json = serde_json::to_string_pretty(&tasks)?;
+        println!("{}", json);
+
+        Ok(())
+    }
+
+    /// Show sprint overview for a sprint view (human-readable)
+    pub async fn sprint_overview(&self, view_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let overview = api.get_sprint_overview(view_id).await?;
+        println!("=== Sprint: {} ===\n", overview.name);
+        println!(
+            "Dates:    {} -> {} ({} days)",
+            crate::utils::format_timestamp(overview.start_date),
+            crate::utils::format_timestamp(overview.end_date),
+            overview.sprint_days()
+        );
+        println!(
+            "Points:   {}/{} ({}%)",
+            overview.completed_points,
+            overview.total_points,
+            overview.completion_percent()
+        );
+        println!(
+            "Tasks:    {}/{}",
+            overview.tasks_completed, overview.tasks_total
+        );
+        println!("Velocity: {:.1} pts/day", overview.velocity);
+        Ok(())
+    }
+
+    /// Show sprint overview for a sprint view (JSON)
+    pub async fn sprint_overview_json(
+        &self,
+        view_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let overview = api.get_sprint_overview(view_id).await?;
+        let json = serde_json::to_string_pretty(&overview)?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Show how long a task spent in each status, in workflow order
+    pub async fn time_in_status(&self, task_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let durations = api.get_task_time_in_status(task_id).await?;
+
+        if durations.is_empty() {
+            println!("No status history for task {}.", task_id);
+            return Ok(());
+        }
+
+        println!("=== Time in status for task {} ===\n", task_id);
+        let width = durations
+            .iter()
+            .map(|d| d.status.status.chars().count())
+            .max()
+            .unwrap_or(0);
+        for entry in &durations {
+            println!(
+                "{:<width$}  {}",
+                entry.status.status,
+                format_status_duration(entry.duration_ms),
+                width = width
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Show how long a task spent in each status as JSON
+    pub async fn time_in_status_json(
+        &self,
+        task_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let durations = api.get_task_time_in_status(task_id).await?;
+        let json = serde_json::to_string_pretty(&durations)?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Fetch the time entries in `range` and total them per local day and task
+    async fn timesheet_days(
+        &self,
+        workspace_id: &str,
+        range: DateRange,
+        user: Option<&str>,
+    ) -> Result<Vec<DayTotal>, Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let (from, to) = range.millis_in(&chrono::Local);
+        let entries = match api.get_time_entries_for_user(workspace_id, from, to, user).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                return Err(self
+                    .explain_missing_feature(workspace_id, Feature::TimeTracking, "load time entries", e)
+                    .await)
+            }
+        };
+        Ok(aggregate_by_day(&entries, &chrono::Local))
+    }
+
+    /// Explain a failed request as the plan lacking `feature` when the
+    /// workspace has it off; otherwise as ClickUp's reason, if it gave one
+    async fn explain_missing_feature(
+        &self,
+        workspace_id: &str,
+        feature: Feature,
+        action: &str,
+        error: anyhow::Error,
+    ) -> Box<dyn std::error::Error> {
+        match self.get_api().get_workspace_features(workspace_id).await {
+            Ok(features) if !features.allows(feature) => format!(
+                "Failed to {}: {} is not available on your plan",
+                action,
+                feature.label()
+            )
+            .into(),
+            _ => explain_rejection(action, error),
+        }
+    }
+
+    /// Show logged time per day and task as a table
+    pub async fn timesheet(
+        &self,
+        workspace_id: &str,
+        range: DateRange,
+        user: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let days = self.timesheet_days(workspace_id, range, user).await?;
+        println!("=== Timesheet: {} to {} ===\n", range.from, range.to);
+
+        if days.is_empty() {
+            println!("No time logged.");
+            return Ok(());
+        }
+
+        for day in &days {
+            println!(
+                "{:<50} {:>9}",
+                day.date.format("%a %Y-%m-%d"),
+                format_duration(day.total_ms)
+            );
+            for task in &day.tasks {
+                let name: String = task.task_name.chars().take(46).collect();
+                println!("  {:<48} {:>9}", name, format_duration(task.total_ms));
+            }
+        }
+        let total: i64 = days.iter().map(|d| d.total_ms).sum();
+        println!("\n{:<50} {:>9}", "Total", format_duration(total));
+        Ok(())
+    }
+
+    /// Show logged time per day and task as JSON
+    pub async fn timesheet_json(
+        &self,
+        workspace_id: &str,
+        range: DateRange,
+        user: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let days = self.timesheet_days(workspace_id, range, user).await?;
+        let total_ms: i64 = days.iter().map(|d| d.total_ms).sum();
+        let json = serde_json::to_string_pretty(&serde_json::json!({
+            "from": range.from,
+            "to": range.to,
+            "total_ms": total_ms,
+            "days": days,
+        }))?;
+        println!("{}", json);
+        Ok(())
+    }
+
+    /// Fetch my tasks updated since `since` and group them for a standup
+    async fn standup_groups(
+        &self,
+        workspace_id: &str,
+        since: Since,
+    ) -> Result<(i64, Standup), Box<dyn std::error::Error>> {
+        let api = self.get_api();
+        let cutoff = since.cutoff_millis(&chrono::Local::now());
+        let filters = TaskFilters {
+            date_updated_gt: Some(cutoff),
+            include_closed: Some(true),
+            subtasks: Some(true),
+            ..Default::default()
+        };
+        let tasks = api.get_my_tasks(workspace_id, &filters).await?;
+        Ok((cutoff, Standup::from_tasks(tasks, cutoff)))
+    }
+
+    /// Show my recent work as Markdown, ready to paste into chat
+    pub async fn standup(
+        &self,
+        workspace_id: &str,
+        since: Since,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (_, standup) = self.standup_groups(workspace_id, since).await?;
+        if standup.is_empty() {
+            println!("No tasks assigned to you were finished, worked on or added in that time.");
+            return Ok(());
+        }
+        print!("{}", standup.to_markdown(workspace_id));
+        Ok(())
+    }
+
+    /// Show my recent work grouped for a standup as JSON
+    pub async fn standup_json(
+        &self,
+        workspace_id: &str,
+        since: Since,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (cutoff, standup) = self.standup_groups(workspace_id, since).await?;
+        let group = |tasks: &[crate::models::Task]| -> Vec<StandupTask> {
+            tasks
+                .iter()
+                .map(|task| StandupTask::from_task(workspace_id, task))
+                .collect()
+        };
+        let json = serde_json::to_string_pretty(&serde_json::json!({
+            "since": cutoff,
+            "done": group(&standup.done),
+            "in_progress": group(&standup.in_progress),
+            "newly_assigned": group(&standup.newly_assigned),
+        }))?;
+        println!("{}", json);
+        Ok(())
+    }
+}
+
+/// Print a task's ID, name, status, list and URL, one per line
+fn print_task_summary(task: &Task) {
+    let status = task
+        .status
+        .as_ref()
+        .map(|s| s.status.as_str())
+        .unwrap_or("unknown");
+    let list = task
+        .list
+        .as_ref()
+        .and_then(|l| l.name.as_deref())
+        .unwrap_or("unknown list");
+    println!("{} - {}", task.id, task.name);
+    if let Some(custom_id) = &task.custom_id {
+        println!("  Custom ID: {}", custom_id);
+    }
+    println!("  Status: {}", status);
+    println!("  List:   {}", list);
+    if let Some(url) = &task.url {
+        println!("  URL:    {}", url);
+    }
+}
+
+/// Name, ID and settings of a space, e.g. "Marketing (ID: 90, private, color #7b68ee)"
+fn space_summary(space: &ClickUpSpace) -> String {
+    let mut details = vec![format!("ID: {}", space.id)];
+    if space.private {
+        details.push("private".to_string());
+    }
+    if let Some(color) = &space.color {
+        details.push(format!("color {}", color));
+    }
+    format!("{} ({})", space.name, details.join(", "))
+}
+
+/// Lead with ClickUp's reason when it refused the request, such as a
+/// duplicate name or a feature the plan doesn't include
+fn explain_rejection(action: &str, error: anyhow::Error) -> Box<dyn std::error::Error> {
+    match api_error_message(&error) {
+        Some(reason) => format!("Failed to {}: {}", action, reason).into(),
+        None => error.into(),
+    }
 }
@@ -3,5 +3,6 @@
 //! Provides data-fetching operations used by both CLI and potentially TUI.
 
 pub mod debug_ops;
+pub mod verify_env;
 
 pub use debug_ops::DebugOperations;
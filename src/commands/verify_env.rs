@@ -0,0 +1,325 @@
+//! Environment checks for `clickdown debug verify-env`
+//!
+//! Each check reports a one-line [`EnvCheck`]; the command fails when any of
+//! them does.
+
+use crate::api::ClickUpApi;
+use crate::config::{CacheOverrides, ConfigManager};
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OpenFlags};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Free space below which the cache directory is reported as too full
+pub const MIN_CACHE_FREE_BYTES: u64 = 50 * 1024 * 1024;
+
+/// How long to wait for the ClickUp API before reporting it unreachable
+pub const API_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// TERM values whose terminals can't be relied on to draw Unicode
+const NON_UNICODE_TERMS: &[&str] = &["dumb", "linux", "vt100", "vt220"];
+
+/// Outcome of a single environment check
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvCheck {
+    pub passed: bool,
+    pub message: String,
+}
+
+impl EnvCheck {
+    fn pass(message: impl Into<String>) -> Self {
+        Self {
+            passed: true,
+            message: message.into(),
+        }
+    }
+
+    fn fail(message: impl Into<String>) -> Self {
+        Self {
+            passed: false,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for EnvCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = if self.passed { '✓' } else { '✗' };
+        write!(f, "{} {}", mark, self.message)
+    }
+}
+
+/// Directories and files the checks look at
+#[derive(Debug, Clone)]
+pub struct EnvPaths {
+    pub config_dir: PathBuf,
+    pub cache_dir: PathBuf,
+    pub database: PathBuf,
+}
+
+impl EnvPaths {
+    /// The paths ClickDown uses on this system (nothing is created)
+    pub fn from_system() -> Result<Self> {
+        let config_dir = dirs::config_dir()
+            .context("Failed to get config directory")?
+            .join("clickdown");
+        let config = ConfigManager::config_for_paths();
+        let overrides = CacheOverrides::from_env();
+        let cache_dir = ConfigManager::locate_cache_dir(&config, &overrides)?;
+        Ok(Self {
+            config_dir,
+            database: cache_dir.join(ConfigManager::database_file_name(&config, &overrides)),
+            cache_dir,
+        })
+    }
+}
+
+/// Run every check, in the order they are reported
+pub async fn run_checks(
+    api: &dyn ClickUpApi,
+    token: Option<&str>,
+    paths: &EnvPaths,
+    term: Option<&str>,
+) -> Vec<EnvCheck> {
+    let mut checks = vec![check_token(token)];
+    checks.extend(check_directories(paths));
+    checks.push(check_database(&paths.database));
+    checks.push(match token.filter(|t| !t.is_empty()) {
+        Some(_) => check_api(api, API_CHECK_TIMEOUT).await,
+        None => EnvCheck::fail("ClickUp API not checked: no API token"),
+    });
+    checks.push(check_terminal(term));
+    checks.push(check_disk_space(&paths.cache_dir));
+    checks
+}
+
+/// Whether an API token is available
+pub fn check_token(token: Option<&str>) -> EnvCheck {
+    match token {
+        Some(t) if !t.trim().is_empty() => EnvCheck::pass("API token configured"),
+        _ => EnvCheck::fail("API token not configured"),
+    }
+}
+
+/// Whether the config directory exists and the cache directory is writable
+pub fn check_directories(paths: &EnvPaths) -> Vec<EnvCheck> {
+    let config = if paths.config_dir.is_dir() {
+        EnvCheck::pass(format!(
+            "Config directory exists: {}",
+            paths.config_dir.display()
+        ))
+    } else {
+        EnvCheck::fail(format!(
+            "Config directory missing: {}",
+            paths.config_dir.display()
+        ))
+    };
+
+    let cache = if !paths.cache_dir.is_dir() {
+        EnvCheck::fail(format!(
+            "Cache directory missing: {}",
+            paths.cache_dir.display()
+        ))
+    } else if is_writable_dir(&paths.cache_dir) {
+        EnvCheck::pass(format!(
+            "Cache directory writable: {}",
+            paths.cache_dir.display()
+        ))
+    } else {
+        EnvCheck::fail(format!(
+            "Cache directory not writable: {}",
+            paths.cache_dir.display()
+        ))
+    };
+
+    vec![config, cache]
+}
+
+/// Write and remove a probe file, the only portable test for write access
+fn is_writable_dir(dir: &Path) -> bool {
+    let probe = dir.join(".clickdown-verify-env");
+    let writable = std::fs::write(&probe, b"").is_ok();
+    let _ = std::fs::remove_file(&probe);
+    writable
+}
+
+/// Whether the cache database can be opened and written
+///
+/// A missing database passes: it is created on first run.
+pub fn check_database(database: &Path) -> EnvCheck {
+    if !database.exists() {
+        return EnvCheck::pass(format!(
+            "SQLite database not created yet: {}",
+            database.display()
+        ));
+    }
+    match probe_database(database) {
+        Ok(()) => EnvCheck::pass(format!("SQLite database writable: {}", database.display())),
+        Err(e) => EnvCheck::fail(format!(
+            "SQLite database not writable: {} ({:#})",
+            database.display(),
+            e
+        )),
+    }
+}
+
+/// Create a table inside a transaction that is rolled back
+fn probe_database(database: &Path) -> Result<()> {
+    let mut conn = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_WRITE)?;
+    let tx = conn.transaction()?;
+    tx.execute_batch("CREATE TABLE verify_env_probe (id INTEGER)")?;
+    tx.rollback()?;
+    Ok(())
+}
+
+/// Whether the ClickUp API answers within `timeout`
+pub async fn check_api(api: &dyn ClickUpApi, timeout: Duration) -> EnvCheck {
+    match tokio::time::timeout(timeout, api.get_current_user()).await {
+        Ok(Ok(user)) => EnvCheck::pass(format!(
+            "ClickUp API reachable (signed in as {})",
+            user.username
+        )),
+        Ok(Err(e)) => EnvCheck::fail(format!("ClickUp API request failed: {:#}", e)),
+        Err(_) => EnvCheck::fail(format!(
+            "ClickUp API did not respond within {}s",
+            timeout.as_secs()
+        )),
+    }
+}
+
+/// Whether `TERM` names a terminal that can draw Unicode
+pub fn check_terminal(term: Option<&str>) -> EnvCheck {
+    match term {
+        None | Some("") => EnvCheck::fail("Terminal type unknown: TERM is not set"),
+        Some(term) if NON_UNICODE_TERMS.contains(&term) => {
+            EnvCheck::fail(format!("Terminal may not support Unicode (TERM={})", term))
+        }
+        Some(term) => EnvCheck::pass(format!("Terminal supports Unicode (TERM={})", term)),
+    }
+}
+
+/// Whether the cache directory's filesystem has room for the cache
+///
+/// Where free space can't be read on this platform it is reported as
+/// unknown without failing the check.
+pub fn check_disk_space(cache_dir: &Path) -> EnvCheck {
+    match free_bytes(cache_dir) {
+        Ok(Some(free)) => disk_space_check(free, cache_dir),
+        Ok(None) => EnvCheck::pass(format!(
+            "Free disk space unknown for {} (not checked on this platform)",
+            cache_dir.display()
+        )),
+        Err(e) => EnvCheck::fail(format!(
+            "Free disk space unknown for {}: {}",
+            cache_dir.display(),
+            e
+        )),
+    }
+}
+
+fn disk_space_check(free: u64, cache_dir: &Path) -> EnvCheck {
+    if free >= MIN_CACHE_FREE_BYTES {
+        EnvCheck::pass(format!(
+            "{} free for the cache in {}",
+            format_bytes(free),
+            cache_dir.display()
+        ))
+    } else {
+        EnvCheck::fail(format!(
+            "Low disk space for the cache: {} free in {}",
+            format_bytes(free),
+            cache_dir.display()
+        ))
+    }
+}
+
+/// Bytes free to unprivileged users on `path`'s filesystem, None where the
+/// platform isn't supported
+#[cfg(unix)]
+fn free_bytes(path: &Path) -> std::io::Result<Option<u64>> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `c_path` is NUL-terminated and `stat` is a valid out pointer
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    #[allow(clippy::unnecessary_cast)]
+    Ok(Some(stat.f_bavail as u64 * stat.f_frsize as u64))
+}
+
+#[cfg(not(unix))]
+fn free_bytes(_path: &Path) -> std::io::Result<Option<u64>> {
+    Ok(None)
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    const GB: f64 = MB * 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= GB {
+        format!("{:.1} GB", bytes / GB)
+    } else {
+        format!("{:.1} MB", bytes / MB)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_display_marks() {
+        assert_eq!(
+            check_token(Some("pk_123")).to_string(),
+            "✓ API token configured"
+        );
+        assert_eq!(
+            check_token(Some("  ")).to_string(),
+            "✗ API token not configured"
+        );
+        assert!(!check_token(None).passed);
+    }
+
+    #[test]
+    fn test_check_terminal() {
+        assert!(check_terminal(Some("xterm-256color")).passed);
+        assert!(!check_terminal(Some("dumb")).passed);
+        assert!(!check_terminal(None).passed);
+    }
+
+    #[test]
+    fn test_check_directories_and_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = EnvPaths {
+            config_dir: dir.path().join("missing"),
+            cache_dir: dir.path().to_path_buf(),
+            database: dir.path().join("cache.db"),
+        };
+
+        let checks = check_directories(&paths);
+        assert!(!checks[0].passed, "{}", checks[0]);
+        assert!(checks[1].passed, "{}", checks[1]);
+
+        assert!(check_database(&paths.database).passed);
+        crate::cache::CacheManager::new(paths.database.clone()).unwrap();
+        let check = check_database(&paths.database);
+        assert!(check.passed, "{}", check);
+        assert!(check.message.starts_with("SQLite database writable"));
+    }
+
+    #[test]
+    fn test_disk_space_threshold() {
+        let dir = Path::new("/cache");
+        assert!(disk_space_check(MIN_CACHE_FREE_BYTES, dir).passed);
+        let low = disk_space_check(MIN_CACHE_FREE_BYTES - 1, dir);
+        assert!(!low.passed);
+        assert_eq!(
+            low.message,
+            "Low disk space for the cache: 50.0 MB free in /cache"
+        );
+    }
+}
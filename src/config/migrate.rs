@@ -0,0 +1,132 @@
+//! Step-by-step upgrades of older `config.toml` layouts
+//!
+//! Files carry a top-level `version`; files from before versioning have none
+//! and count as version 0. Each step works on the raw TOML table, so keys this
+//! build doesn't know about survive the upgrade.
+
+use toml::{Table, Value};
+
+/// Upgrade steps: `MIGRATIONS[n]` turns a version `n` table into version `n + 1`
+const MIGRATIONS: &[fn(&mut Table)] = &[v0_to_v1];
+
+/// Version written by this build
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Keys the upgrade steps write that this build doesn't read yet
+pub const UNREAD_KEYS: &[&str] = &["window", "theme"];
+
+/// The version a config table declares (0 when it has none)
+pub fn version_of(table: &Table) -> u32 {
+    table
+        .get("version")
+        .and_then(Value::as_integer)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Upgrade `table` from `from` to [`CURRENT_VERSION`], one step at a time
+///
+/// Returns whether any step changed the table; the `version` key, set
+/// either way, doesn't count. Tables from a newer version are left alone.
+pub fn migrate(table: &mut Table, from: u32) -> bool {
+    let before = table.clone();
+    for step in MIGRATIONS.iter().skip(from as usize) {
+        step(table);
+    }
+    let changed = *table != before;
+    if from < CURRENT_VERSION {
+        table.insert(
+            "version".to_string(),
+            Value::Integer(CURRENT_VERSION.into()),
+        );
+    }
+    changed
+}
+
+/// Version 0 → 1: group the window size under `[window]` and turn a plain
+/// `theme = "dark"` into a `[theme]` table
+fn v0_to_v1(table: &mut Table) {
+    for (old, new) in [("window_width", "width"), ("window_height", "height")] {
+        if let Some(value) = table.remove(old) {
+            table_entry(table, "window").insert(new.to_string(), value);
+        }
+    }
+    if let Some(Value::String(name)) = table.get("theme").cloned() {
+        let mut theme = Table::new();
+        theme.insert("name".to_string(), Value::String(name));
+        table.insert("theme".to_string(), Value::Table(theme));
+    }
+}
+
+/// The sub-table `key`, created when missing or replaced when not a table
+fn table_entry<'a>(table: &'a mut Table, key: &str) -> &'a mut Table {
+    let entry = table
+        .entry(key.to_string())
+        .or_insert_with(|| Value::Table(Table::new()));
+    if !entry.is_table() {
+        *entry = Value::Table(Table::new());
+    }
+    entry.as_table_mut().expect("just made a table")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(toml: &str) -> Table {
+        toml.parse().unwrap()
+    }
+
+    #[test]
+    fn test_v0_window_size_and_theme_upgraded() {
+        let mut config =
+            table("max_task_pages = 4\nwindow_width = 120\nwindow_height = 40\ntheme = \"dark\"\n");
+
+        let from = version_of(&config);
+        assert!(migrate(&mut config, from));
+        assert_eq!(
+            config,
+            table(
+                "max_task_pages = 4\nversion = 1\n\
+                 [window]\nwidth = 120\nheight = 40\n\
+                 [theme]\nname = \"dark\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_unversioned_current_layout_only_gains_version() {
+        let mut config = table("status_clear_secs = 5\n[ui]\naccessibility_mode = true\n");
+
+        let from = version_of(&config);
+        assert!(!migrate(&mut config, from));
+        assert_eq!(version_of(&config), CURRENT_VERSION);
+        assert_eq!(config["status_clear_secs"].as_integer(), Some(5));
+    }
+
+    #[test]
+    fn test_current_and_future_versions_untouched() {
+        let current = table(&format!(
+            "version = {}\ntheme = \"dark\"\n",
+            CURRENT_VERSION
+        ));
+        let mut migrated = current.clone();
+        assert!(!migrate(&mut migrated, CURRENT_VERSION));
+        assert_eq!(migrated, current);
+
+        let future = table("version = 99\nwindow_width = 80\n");
+        let mut migrated = future.clone();
+        assert!(!migrate(&mut migrated, 99));
+        assert_eq!(migrated, future);
+    }
+
+    #[test]
+    fn test_existing_window_table_is_kept() {
+        let mut config = table("window_height = 40\n[window]\nmaximized = true\n");
+        migrate(&mut config, 0);
+        assert_eq!(
+            config["window"],
+            Value::Table(table("maximized = true\nheight = 40\n"))
+        );
+    }
+}
@@ -1,31 +1,858 @@
 //! Configuration management module
 
+pub mod migrate;
+pub mod share;
+
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Default cap on task pages fetched when opening a list
+pub const DEFAULT_MAX_TASK_PAGES: u32 = 10;
+
+/// Default number of lines a comment shows before it is collapsed
+pub const DEFAULT_COMMENT_COLLAPSE_LINES: usize = 8;
+
+/// Default seconds cached workspaces are shown on startup without waiting
+pub const DEFAULT_WORKSPACE_CACHE_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// Default seconds a status bar message stays up
+pub const DEFAULT_STATUS_CLEAR_SECS: u64 = 3;
+
+/// Default terminal width at which task detail switches to two columns
+pub const DEFAULT_MIN_WIDTH_FOR_TWO_COLUMN: u32 = 120;
+
+/// Environment variable overriding the cache directory
+pub const CACHE_DIR_ENV: &str = "CLICKDOWN_CACHE_DIR";
+
+/// Environment variable selecting the profile, see [`AppConfig::profile`]
+pub const PROFILE_ENV: &str = "CLICKDOWN_PROFILE";
+
+/// Screen shown when the TUI starts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum StartScreen {
+    /// The workspace list
+    Workspaces,
+    /// Wherever the previous session left off
+    #[default]
+    LastLocation,
+    /// Tasks assigned to the current user
+    MyTasks,
+}
+
+impl StartScreen {
+    /// Accepted spellings, for error and help messages
+    pub const VALID_VALUES: &'static str = "workspaces, last-location, my-tasks";
+}
+
+impl FromStr for StartScreen {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "workspaces" => Ok(Self::Workspaces),
+            "last-location" => Ok(Self::LastLocation),
+            "my-tasks" => Ok(Self::MyTasks),
+            _ => Err(format!(
+                "invalid start screen '{}' (expected one of: {})",
+                s,
+                Self::VALID_VALUES
+            )),
+        }
+    }
+}
+
+impl fmt::Display for StartScreen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Workspaces => write!(f, "workspaces"),
+            Self::LastLocation => write!(f, "last-location"),
+            Self::MyTasks => write!(f, "my-tasks"),
+        }
+    }
+}
+
+impl TryFrom<String> for StartScreen {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<StartScreen> for String {
+    fn from(value: StartScreen) -> Self {
+        value.to_string()
+    }
+}
+
+/// Order of a task's top-level comments, everywhere they are listed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentOrder {
+    /// Newest comment first
+    Newest,
+    /// Oldest comment first, reading down in the order they were written
+    #[default]
+    Oldest,
+}
+
+impl CommentOrder {
+    /// Put `newest_first` items (the order ClickUp returns them in) in this order
+    pub fn arrange<T>(self, newest_first: &mut [T]) {
+        if self == Self::Oldest {
+            newest_first.reverse();
+        }
+    }
+}
+
+/// First day of the week in the due-date calendar
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+impl WeekStart {
+    pub fn weekday(self) -> chrono::Weekday {
+        match self {
+            Self::Monday => chrono::Weekday::Mon,
+            Self::Sunday => chrono::Weekday::Sun,
+        }
+    }
+}
+
+/// How much room each task takes in the task list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskListDensity {
+    /// Tasks under status group headers, with priority and flags
+    #[default]
+    Comfortable,
+    /// One line per task (ID, name and status) without group headers
+    Compact,
+}
+
+impl TaskListDensity {
+    /// The other density
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Comfortable => Self::Compact,
+            Self::Compact => Self::Comfortable,
+        }
+    }
+}
+
+/// User settings read from `config.toml` in the clickdown config directory.
+///
+/// Every field is optional in the file; missing fields use their defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// Layout version of the file, see [`migrate`]
+    pub version: u32,
+    /// Maximum number of task pages (100 tasks each) fetched for one list
+    pub max_task_pages: u32,
+    /// Screen shown on startup (overridden by `--start`)
+    pub start_screen: StartScreen,
+    /// Open the workspace directly when the account has only one
+    pub auto_select_single_workspace: bool,
+    /// Pager command for reading a task outside the TUI (falls back to `$PAGER`)
+    pub pager: Option<String>,
+    /// Editor for a task's description (falls back to `$EDITOR`)
+    pub editor: Option<String>,
+    /// Comments longer than this many wrapped lines start collapsed (0 never collapses)
+    pub comment_collapse_lines: usize,
+    /// Whether comments are listed newest or oldest first (`newest`/`oldest`)
+    pub comment_order: CommentOrder,
+    /// Fetch every thread's replies along with the comments; when off,
+    /// a thread's replies are fetched when it is opened
+    pub prefetch_comment_replies: bool,
+    /// Focus the comments pane when a task with comments is opened, rather
+    /// than the task form
+    pub comment_focus_on_open: bool,
+    /// Ask before deleting a comment; turn off to delete on `x` straight away
+    pub confirm_comment_delete: bool,
+    /// Serve task comments and Recent Tasks summaries from the local cache
+    /// for this many seconds (unset: always fetch)
+    pub comment_cache_ttl_secs: Option<u64>,
+    /// Show workspaces cached less than this many seconds ago on startup,
+    /// refreshing them in the background (0: always wait for ClickUp)
+    pub workspace_cache_ttl_secs: u64,
+    /// Ask ClickUp for task descriptions as markdown rather than plain text
+    pub prefer_markdown: bool,
+    /// Load lists without markdown descriptions and drop custom field data
+    /// the UI doesn't render, for lists with huge rich-text fields
+    pub slim_tasks: bool,
+    /// Clear transient status messages after this many seconds (0 never clears)
+    pub status_clear_secs: u64,
+    /// Directory for the cache database and response dumps
+    /// (unset: the platform cache directory)
+    pub cache_dir: Option<PathBuf>,
+    /// Profile name; each profile keeps its own `cache-<profile>.db`
+    pub profile: Option<String>,
+    /// Layout and display settings (the `[ui]` table)
+    pub ui: UiConfig,
+}
+
+/// Layout and display settings, read from the `[ui]` table of `config.toml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiConfig {
+    /// Terminal width (columns) from which task detail uses two columns
+    pub min_width_for_two_column: u32,
+    /// Render screens as plain, linearized text for screen readers
+    pub accessibility_mode: bool,
+    /// Prefix sidebar items with Nerd Font icons instead of letters; on by
+    /// default in builds with the `nerd-fonts` feature
+    pub use_nerd_fonts: bool,
+    /// Show the workspace hierarchy as an expandable tree in the sidebar
+    /// instead of one level at a time
+    pub sidebar_tree: bool,
+    /// Column the due-date calendar starts its weeks on (`monday`/`sunday`)
+    pub week_start: WeekStart,
+    /// Task list layout (`comfortable`/`compact`), toggled with `D`
+    pub task_list_density: TaskListDensity,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            min_width_for_two_column: DEFAULT_MIN_WIDTH_FOR_TWO_COLUMN,
+            accessibility_mode: false,
+            use_nerd_fonts: cfg!(feature = "nerd-fonts"),
+            sidebar_tree: false,
+            week_start: WeekStart::default(),
+            task_list_density: TaskListDensity::default(),
+        }
+    }
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            version: migrate::CURRENT_VERSION,
+            max_task_pages: DEFAULT_MAX_TASK_PAGES,
+            start_screen: StartScreen::default(),
+            auto_select_single_workspace: true,
+            pager: None,
+            editor: None,
+            comment_collapse_lines: DEFAULT_COMMENT_COLLAPSE_LINES,
+            comment_order: CommentOrder::default(),
+            prefetch_comment_replies: true,
+            comment_focus_on_open: false,
+            confirm_comment_delete: true,
+            comment_cache_ttl_secs: None,
+            workspace_cache_ttl_secs: DEFAULT_WORKSPACE_CACHE_TTL_SECS,
+            prefer_markdown: true,
+            slim_tasks: false,
+            status_clear_secs: DEFAULT_STATUS_CLEAR_SECS,
+            cache_dir: None,
+            profile: None,
+            ui: UiConfig::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Pager used when none is configured and `$PAGER` is unset
+    pub const DEFAULT_PAGER: &'static str = "less -R";
+
+    /// Resolve the pager command: config, then `$PAGER`, then `less -R`
+    pub fn pager_command(&self) -> String {
+        self.pager
+            .clone()
+            .or_else(|| std::env::var("PAGER").ok())
+            .filter(|cmd| !cmd.trim().is_empty())
+            .unwrap_or_else(|| Self::DEFAULT_PAGER.to_string())
+    }
+
+    /// Editor used when none is configured and `$EDITOR` is unset
+    pub const DEFAULT_EDITOR: &'static str = "vi";
+
+    /// Resolve the editor command: config, then `$EDITOR`, then `vi`
+    pub fn editor_command(&self) -> String {
+        self.editor
+            .clone()
+            .or_else(|| std::env::var("EDITOR").ok())
+            .filter(|cmd| !cmd.trim().is_empty())
+            .unwrap_or_else(|| Self::DEFAULT_EDITOR.to_string())
+    }
+}
+
+/// Cache settings from the environment, which take precedence over the config
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheOverrides {
+    /// `$CLICKDOWN_CACHE_DIR`
+    pub cache_dir: Option<PathBuf>,
+    /// `$CLICKDOWN_PROFILE`
+    pub profile: Option<String>,
+}
+
+impl CacheOverrides {
+    /// Read the overrides from the environment, ignoring empty values
+    pub fn from_env() -> Self {
+        Self {
+            cache_dir: std::env::var_os(CACHE_DIR_ENV)
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+            profile: std::env::var(PROFILE_ENV).ok(),
+        }
+    }
+}
 
 /// Configuration manager - provides utility functions for config/cache paths
 pub struct ConfigManager;
 
 impl ConfigManager {
-    /// Get the cache directory path
+    /// Get the cache directory path, creating it if needed
     pub fn cache_dir() -> Result<PathBuf> {
-        let cache_dir = dirs::cache_dir()
-            .context("Failed to get cache directory")?
-            .join("clickdown");
+        let dir = Self::locate_cache_dir(&Self::config_for_paths(), &CacheOverrides::from_env())?;
+        Self::create_dir(dir)
+    }
+
+    /// Get the database path, creating its directory if needed
+    pub fn database_path() -> Result<PathBuf> {
+        Self::database_path_for(&Self::config_for_paths(), &CacheOverrides::from_env())
+    }
 
-        std::fs::create_dir_all(&cache_dir).context("Failed to create cache directory")?;
+    /// The database path for `config` and `overrides`, creating its directory
+    pub fn database_path_for(config: &AppConfig, overrides: &CacheOverrides) -> Result<PathBuf> {
+        let dir = Self::create_dir(Self::locate_cache_dir(config, overrides)?)?;
+        Ok(dir.join(Self::database_file_name(config, overrides)))
+    }
 
-        Ok(cache_dir)
+    /// The cache directory: the override, then `cache_dir`, then the
+    /// platform cache directory (nothing is created)
+    pub fn locate_cache_dir(config: &AppConfig, overrides: &CacheOverrides) -> Result<PathBuf> {
+        match overrides.cache_dir.clone().or_else(|| config.cache_dir.clone()) {
+            Some(dir) => Ok(dir),
+            None => Ok(dirs::cache_dir()
+                .context("Failed to get cache directory")?
+                .join("clickdown")),
+        }
     }
 
-    /// Get the database path
-    pub fn database_path() -> Result<PathBuf> {
-        Ok(Self::cache_dir()?.join("cache.db"))
+    /// `cache.db`, or `cache-<profile>.db` when a profile is selected
+    ///
+    /// Characters that don't belong in a file name are replaced with `_`.
+    pub fn database_file_name(config: &AppConfig, overrides: &CacheOverrides) -> String {
+        let profile = overrides
+            .profile
+            .as_deref()
+            .or(config.profile.as_deref())
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+        match profile {
+            Some(profile) => {
+                let safe: String = profile
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                            c
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect();
+                format!("cache-{}.db", safe)
+            }
+            None => "cache.db".to_string(),
+        }
+    }
+
+    /// The config the cache paths are read from; a broken file is reported
+    /// when the config is loaded for the rest of the settings
+    pub fn config_for_paths() -> AppConfig {
+        Self::load_config().unwrap_or_default()
+    }
+
+    fn create_dir(dir: PathBuf) -> Result<PathBuf> {
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create cache directory {}", dir.display()))?;
+        Ok(dir)
+    }
+
+    /// Get the config file path
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(dirs::config_dir()
+            .context("Failed to get config directory")?
+            .join("clickdown")
+            .join("config.toml"))
+    }
+
+    /// Load the user config, falling back to defaults when the file doesn't exist
+    pub fn load_config() -> Result<AppConfig> {
+        Self::load_config_from(&Self::config_path()?)
+    }
+
+    /// Load a config file, falling back to defaults when it doesn't exist
+    ///
+    /// Files from an older version are upgraded first; when that changes
+    /// anything, the original is kept in a timestamped backup (see
+    /// [`backup_path`]) and the upgraded file written in its place. The
+    /// upgraded file is re-serialized, so comments in it are only kept in
+    /// the backup. Files from a newer version load whatever settings this
+    /// version understands.
+    pub fn load_config_from(path: &Path) -> Result<AppConfig> {
+        if !path.exists() {
+            return Ok(AppConfig::default());
+        }
+        let parse_error = || format!("Failed to parse config file {}", path.display());
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let mut table: toml::Table = toml::from_str(&content).with_context(parse_error)?;
+
+        let version = migrate::version_of(&table);
+        if version > migrate::CURRENT_VERSION {
+            tracing::warn!(
+                "{} is from a newer clickdown (config version {}, this one reads {}); \
+                 ignoring settings it can't read",
+                path.display(),
+                version,
+                migrate::CURRENT_VERSION
+            );
+            return Ok(Self::load_leniently(table));
+        }
+        if migrate::migrate(&mut table, version) {
+            Self::save_migrated(path, &content, &table);
+        }
+        toml::Value::Table(table)
+            .try_into()
+            .with_context(parse_error)
+    }
+
+    /// Keep `original` in a backup next to `path`, then write the upgraded config
+    ///
+    /// Failing to write only costs redoing the upgrade on the next start.
+    fn save_migrated(path: &Path, original: &str, table: &toml::Table) {
+        let backup = backup_path(path);
+        let result = std::fs::write(&backup, original)
+            .with_context(|| format!("Failed to back up config to {}", backup.display()))
+            .and_then(|()| Ok(toml::to_string(table)?))
+            .and_then(|upgraded| {
+                std::fs::write(path, upgraded)
+                    .with_context(|| format!("Failed to write {}", path.display()))
+            });
+        match result {
+            Ok(()) => tracing::info!(
+                "Upgraded {} to config version {} (original saved as {})",
+                path.display(),
+                migrate::CURRENT_VERSION,
+                backup.display()
+            ),
+            Err(e) => tracing::warn!("Config upgraded for this run only: {:#}", e),
+        }
+    }
+
+    /// Settings from a newer config, skipping each top-level one that this
+    /// version can't read
+    fn load_leniently(table: toml::Table) -> AppConfig {
+        let readable: toml::Table = table
+            .into_iter()
+            .filter(|(key, value)| {
+                let single = toml::Table::from_iter([(key.clone(), value.clone())]);
+                match toml::Value::Table(single).try_into::<AppConfig>() {
+                    Ok(_) => true,
+                    Err(e) => {
+                        tracing::warn!("Ignoring config setting `{}`: {}", key, e);
+                        false
+                    }
+                }
+            })
+            .collect();
+        toml::Value::Table(readable).try_into().unwrap_or_default()
     }
 }
 
+/// Where to back up `path` before rewriting it, e.g.
+/// `config.toml.20261016-101500.bak`
+///
+/// Each backup gets its own file, so an earlier one is never overwritten.
+pub(crate) fn backup_path(path: &Path) -> PathBuf {
+    let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let mut backup = path.with_extension(format!("toml.{}.bak", stamp));
+    let mut n = 1;
+    while backup.exists() {
+        backup = path.with_extension(format!("toml.{}_{}.bak", stamp, n));
+        n += 1;
+    }
+    backup
+}
+
 impl Default for ConfigManager {
     fn default() -> Self {
         ConfigManager
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Backups in `dir`, oldest first
+    pub(crate) fn backups(dir: &Path) -> Vec<PathBuf> {
+        let mut backups: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.to_string_lossy().ends_with(".bak"))
+            .collect();
+        backups.sort();
+        backups
+    }
+
+    #[test]
+    fn test_missing_config_file_uses_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ConfigManager::load_config_from(&dir.path().join("config.toml")).unwrap();
+        assert_eq!(config, AppConfig::default());
+    }
+
+    #[test]
+    fn test_config_file_overrides_max_task_pages() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "max_task_pages = 3\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.max_task_pages, 3);
+    }
+
+    #[test]
+    fn test_config_file_start_screen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "start_screen = \"my-tasks\"\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.start_screen, StartScreen::MyTasks);
+
+        // There are no favorites to start on
+        std::fs::write(&path, "start_screen = \"favorite:2\"\n").unwrap();
+        let err = ConfigManager::load_config_from(&path).unwrap_err();
+        assert!(format!("{:#}", err).contains(StartScreen::VALID_VALUES), "{:#}", err);
+    }
+
+    #[test]
+    fn test_config_file_prefetch_comment_replies() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "prefetch_comment_replies = false\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(!config.prefetch_comment_replies);
+        assert!(AppConfig::default().prefetch_comment_replies);
+    }
+
+    #[test]
+    fn test_config_file_comment_focus_on_open() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "comment_focus_on_open = true\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(config.comment_focus_on_open);
+        assert!(!AppConfig::default().comment_focus_on_open);
+    }
+
+    #[test]
+    fn test_config_file_confirm_comment_delete() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "confirm_comment_delete = false\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(!config.confirm_comment_delete);
+        assert!(AppConfig::default().confirm_comment_delete);
+    }
+
+    #[test]
+    fn test_config_file_prefer_markdown() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "prefer_markdown = false\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(!config.prefer_markdown);
+        assert!(AppConfig::default().prefer_markdown);
+    }
+
+    #[test]
+    fn test_config_file_slim_tasks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "slim_tasks = true\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(config.slim_tasks);
+        assert!(!AppConfig::default().slim_tasks);
+    }
+
+    #[test]
+    fn test_config_file_comment_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "comment_order = \"newest\"\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.comment_order, CommentOrder::Newest);
+        assert_eq!(AppConfig::default().comment_order, CommentOrder::Oldest);
+
+        std::fs::write(&path, "comment_order = \"latest\"\n").unwrap();
+        assert!(ConfigManager::load_config_from(&path).is_err());
+    }
+
+    #[test]
+    fn test_comment_order_arrange() {
+        let mut items = vec![3, 2, 1];
+        CommentOrder::Newest.arrange(&mut items);
+        assert_eq!(items, vec![3, 2, 1]);
+        CommentOrder::Oldest.arrange(&mut items);
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_config_file_auto_select_single_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "auto_select_single_workspace = false\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert!(!config.auto_select_single_workspace);
+        assert!(AppConfig::default().auto_select_single_workspace);
+    }
+
+    #[test]
+    fn test_config_file_pager_overrides_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "pager = \"bat --plain\"\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.pager.as_deref(), Some("bat --plain"));
+        assert_eq!(config.pager_command(), "bat --plain");
+    }
+
+    #[test]
+    fn test_config_file_editor_overrides_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "editor = \"nvim -n\"\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.editor_command(), "nvim -n");
+    }
+
+    #[test]
+    fn test_config_file_comment_cache_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "comment_cache_ttl_secs = 120\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.comment_cache_ttl_secs, Some(120));
+        assert_eq!(AppConfig::default().comment_cache_ttl_secs, None);
+    }
+
+    #[test]
+    fn test_config_file_status_clear_secs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "status_clear_secs = 0\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.status_clear_secs, 0);
+        assert_eq!(
+            AppConfig::default().status_clear_secs,
+            DEFAULT_STATUS_CLEAR_SECS
+        );
+    }
+
+    #[test]
+    fn test_config_file_ui_table() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[ui]\nmin_width_for_two_column = 160\nuse_nerd_fonts = true\nweek_start = \"sunday\"\n\
+             task_list_density = \"compact\"\n",
+        )
+        .unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.ui.min_width_for_two_column, 160);
+        assert!(!config.ui.accessibility_mode);
+        assert!(config.ui.use_nerd_fonts);
+        assert!(!config.ui.sidebar_tree);
+        assert_eq!(config.ui.week_start, WeekStart::Sunday);
+        assert_eq!(AppConfig::default().ui.week_start, WeekStart::Monday);
+        assert_eq!(config.ui.task_list_density, TaskListDensity::Compact);
+        assert_eq!(
+            AppConfig::default().ui.task_list_density,
+            TaskListDensity::Comfortable
+        );
+        assert_eq!(
+            AppConfig::default().ui.min_width_for_two_column,
+            DEFAULT_MIN_WIDTH_FOR_TWO_COLUMN
+        );
+    }
+
+    #[test]
+    fn test_invalid_start_screen_lists_valid_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "start_screen = \"inbox\"\n").unwrap();
+
+        let err = ConfigManager::load_config_from(&path).unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(
+            message.contains("invalid start screen 'inbox'"),
+            "{}",
+            message
+        );
+        assert!(message.contains(StartScreen::VALID_VALUES), "{}", message);
+    }
+
+    #[test]
+    fn test_start_screen_round_trips() {
+        for value in ["workspaces", "last-location", "my-tasks"] {
+            let screen: StartScreen = value.parse().unwrap();
+            assert_eq!(screen.to_string(), value);
+        }
+        assert!("favorite:1".parse::<StartScreen>().is_err());
+    }
+
+    #[test]
+    fn test_legacy_config_upgraded_with_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let legacy = "max_task_pages = 4\nwindow_width = 120\ntheme = \"dark\"\n";
+        std::fs::write(&path, legacy).unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.max_task_pages, 4);
+        assert_eq!(config.version, migrate::CURRENT_VERSION);
+
+        // The original is kept and the file now has the current layout
+        let [backup] = &backups(dir.path())[..] else {
+            panic!("expected one backup");
+        };
+        assert_eq!(std::fs::read_to_string(backup).unwrap(), legacy);
+        let upgraded: toml::Table = std::fs::read_to_string(&path).unwrap().parse().unwrap();
+        assert_eq!(migrate::version_of(&upgraded), migrate::CURRENT_VERSION);
+        assert_eq!(upgraded["window"]["width"].as_integer(), Some(120));
+        assert_eq!(upgraded["theme"]["name"].as_str(), Some("dark"));
+
+        // Loading again changes nothing
+        assert_eq!(ConfigManager::load_config_from(&path).unwrap(), config);
+        assert_eq!(backups(dir.path()).len(), 1);
+
+        // A later upgrade keeps its own backup beside the first
+        let older = "window_height = 40\n";
+        std::fs::write(&path, older).unwrap();
+        ConfigManager::load_config_from(&path).unwrap();
+        let kept: Vec<String> = backups(dir.path())
+            .iter()
+            .map(|backup| std::fs::read_to_string(backup).unwrap())
+            .collect();
+        assert_eq!(kept, [legacy, older]);
+    }
+
+    #[test]
+    fn test_unversioned_current_layout_left_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let content = "# my settings\nstatus_clear_secs = 5\n[ui]\naccessibility_mode = true\n";
+        std::fs::write(&path, content).unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.status_clear_secs, 5);
+        assert!(config.ui.accessibility_mode);
+        // Nothing to upgrade: no rewrite (which would drop the comment), no backup
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+        assert!(backups(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_newer_config_loads_leniently() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let content = "version = 99\nmax_task_pages = 3\nstatus_clear_secs = \"soon\"\n\
+                       new_setting = true\n";
+        std::fs::write(&path, content).unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+        assert_eq!(config.version, 99);
+        assert_eq!(config.max_task_pages, 3);
+        assert_eq!(config.status_clear_secs, DEFAULT_STATUS_CLEAR_SECS);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+    }
+
+    #[test]
+    fn test_config_file_cache_dir_and_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "cache_dir = \"/tmp/clickdown-cache\"\nprofile = \"work\"\n").unwrap();
+
+        let config = ConfigManager::load_config_from(&path).unwrap();
+
+        assert_eq!(config.cache_dir, Some(PathBuf::from("/tmp/clickdown-cache")));
+        assert_eq!(config.profile.as_deref(), Some("work"));
+    }
+
+    #[test]
+    fn test_database_path_honors_cache_dir_and_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = AppConfig {
+            cache_dir: Some(dir.path().join("from-config")),
+            profile: Some("work".to_string()),
+            ..AppConfig::default()
+        };
+
+        let path = ConfigManager::database_path_for(&config, &CacheOverrides::default()).unwrap();
+        assert_eq!(path, dir.path().join("from-config").join("cache-work.db"));
+        assert!(dir.path().join("from-config").is_dir());
+
+        let overrides = CacheOverrides {
+            cache_dir: Some(dir.path().join("from-env").join("nested")),
+            profile: Some("client a".to_string()),
+        };
+        let path = ConfigManager::database_path_for(&config, &overrides).unwrap();
+        assert_eq!(
+            path,
+            dir.path().join("from-env").join("nested").join("cache-client_a.db"),
+            "Environment wins over the config"
+        );
+        assert!(dir.path().join("from-env").join("nested").is_dir());
+    }
+
+    #[test]
+    fn test_database_file_name_without_profile() {
+        let overrides = CacheOverrides {
+            cache_dir: None,
+            profile: Some("  ".to_string()),
+        };
+        assert_eq!(
+            ConfigManager::database_file_name(&AppConfig::default(), &CacheOverrides::default()),
+            "cache.db"
+        );
+        assert_eq!(
+            ConfigManager::database_file_name(&AppConfig::default(), &overrides),
+            "cache.db"
+        );
+    }
+
+    #[test]
+    fn test_invalid_config_file_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "max_task_pages = \"many\"\n").unwrap();
+
+        assert!(ConfigManager::load_config_from(&path).is_err());
+    }
+}
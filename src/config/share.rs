@@ -0,0 +1,400 @@
+//! Sharing `config.toml` with a team
+//!
+//! `clickdown debug config export` writes the settings without anything secret
+//! or tied to this machine; `debug config import` applies such a file on top of
+//! the local one. Both sides are handled as raw TOML tables rather than
+//! [`AppConfig`]s: only the table knows which keys a file actually sets, and
+//! keys this build doesn't know about survive an import.
+
+use super::{migrate, AppConfig};
+use anyhow::{anyhow, bail, Context, Result};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Keys that are never exported or imported, should one be in a file
+pub const SECRET_KEYS: &[&str] = &["api_token"];
+
+/// Keys describing this machine: not exported, and always kept from the
+/// local config on import
+pub const MACHINE_KEYS: &[&str] = &["cache_dir", "profile"];
+
+/// How an imported config is combined with the local one
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Imported keys win; keys only set locally are kept
+    #[default]
+    Merge,
+    /// The imported file replaces the local one, apart from secret and
+    /// machine keys
+    Replace,
+}
+
+/// `config` as TOML to share, without secret and machine keys
+pub fn export(config: &AppConfig) -> Result<String> {
+    let Value::Table(mut table) = Value::try_from(config).context("Failed to serialize config")?
+    else {
+        bail!("Config did not serialize to a table");
+    };
+    for key in SECRET_KEYS.iter().chain(MACHINE_KEYS) {
+        table.remove(*key);
+    }
+    Ok(toml::to_string(&table)?)
+}
+
+/// Read a file to import: upgraded to the current layout, secrets and
+/// settings this build doesn't read dropped, and checked to be a config this
+/// version fully understands
+///
+/// Errors name the offending key.
+pub fn parse_import(content: &str) -> Result<Table> {
+    let mut table: Table = toml::from_str(content).context("Not a valid TOML file")?;
+    let version = migrate::version_of(&table);
+    if version > migrate::CURRENT_VERSION {
+        bail!(
+            "`version`: file is from a newer clickdown (config version {}, this one reads {})",
+            version,
+            migrate::CURRENT_VERSION
+        );
+    }
+    migrate::migrate(&mut table, version);
+    for key in SECRET_KEYS.iter().chain(migrate::UNREAD_KEYS) {
+        table.remove(*key);
+    }
+    validate(&table)?;
+    Ok(table)
+}
+
+/// Check every key of `table`: values must have the right type and unknown
+/// keys (typos, mostly) are rejected
+pub fn validate(table: &Table) -> Result<AppConfig> {
+    let config: AppConfig = serde_path_to_error::deserialize(Value::Table(table.clone()))
+        .map_err(|e| anyhow!("`{}`: {}", e.path(), e.inner().message()))?;
+    // Whatever the config doesn't write back is a key it didn't read
+    let Value::Table(known) = Value::try_from(&config)? else {
+        bail!("Config did not serialize to a table");
+    };
+    if let Some(key) = unknown_key(table, &known, "") {
+        bail!("`{}`: unknown setting", key);
+    }
+    Ok(config)
+}
+
+/// The first key of `table` missing from `known`, dotted from `prefix`
+fn unknown_key(table: &Table, known: &Table, prefix: &str) -> Option<String> {
+    table.iter().find_map(|(key, value)| {
+        let path = format!("{}{}", prefix, key);
+        match (value, known.get(key)) {
+            (_, None) => Some(path),
+            (Value::Table(inner), Some(Value::Table(known_inner))) => {
+                unknown_key(inner, known_inner, &format!("{}.", path))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Combine the `local` config table with an `imported` one
+///
+/// With [`ImportMode::Merge`] imported keys win and keys only set locally are
+/// kept, table by table, so `[ui]` merges key by key. With
+/// [`ImportMode::Replace`] the imported table is taken as is. Either way
+/// [`SECRET_KEYS`] and [`MACHINE_KEYS`] keep their local values, or stay unset.
+pub fn merge(local: &Table, imported: &Table, mode: ImportMode) -> Table {
+    let mut merged = match mode {
+        ImportMode::Merge => merge_tables(local, imported),
+        ImportMode::Replace => imported.clone(),
+    };
+    for key in SECRET_KEYS.iter().chain(MACHINE_KEYS) {
+        match local.get(*key) {
+            Some(value) => merged.insert(key.to_string(), value.clone()),
+            None => merged.remove(*key),
+        };
+    }
+    merged
+}
+
+fn merge_tables(local: &Table, imported: &Table) -> Table {
+    let mut merged = local.clone();
+    for (key, value) in imported {
+        let value = match (merged.get(key), value) {
+            (Some(Value::Table(ours)), Value::Table(theirs)) => {
+                Value::Table(merge_tables(ours, theirs))
+            }
+            _ => value.clone(),
+        };
+        merged.insert(key.clone(), value);
+    }
+    merged
+}
+
+/// One setting an import changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted key, e.g. `ui.sidebar_tree`
+    pub key: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.before, &self.after) {
+            (Some(before), Some(after)) => write!(f, "~ {} = {} -> {}", self.key, before, after),
+            (None, Some(after)) => write!(f, "+ {} = {}", self.key, after),
+            (Some(before), None) => write!(f, "- {} = {}", self.key, before),
+            (None, None) => write!(f, "  {}", self.key),
+        }
+    }
+}
+
+/// The settings that differ between `before` and `after`, sorted by key
+pub fn diff(before: &Table, after: &Table) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+    diff_into(before, after, "", &mut changes);
+    changes
+}
+
+fn diff_into(before: &Table, after: &Table, prefix: &str, changes: &mut Vec<ConfigChange>) {
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    for key in keys {
+        let path = format!("{}{}", prefix, key);
+        match (before.get(key), after.get(key)) {
+            (Some(Value::Table(old)), Some(Value::Table(new))) => {
+                diff_into(old, new, &format!("{}.", path), changes)
+            }
+            (old, new) if old != new => changes.push(ConfigChange {
+                key: path,
+                before: old.cloned(),
+                after: new.cloned(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// The local config file as a table in the current layout (empty when missing)
+pub fn read_local(path: &Path) -> Result<Table> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+    let mut table: Table = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+    let version = migrate::version_of(&table);
+    if version > migrate::CURRENT_VERSION {
+        bail!(
+            "{} is from a newer clickdown (config version {}); not changing it",
+            path.display(),
+            version
+        );
+    }
+    migrate::migrate(&mut table, version);
+    Ok(table)
+}
+
+/// Write `table` to `path`, keeping the previous file in a timestamped
+/// backup (see [`backup_path`](super::backup_path)); returns the backup, if
+/// there was a file to keep
+pub fn write(path: &Path, table: &Table) -> Result<Option<PathBuf>> {
+    let mut backup = None;
+    if path.exists() {
+        let kept = super::backup_path(path);
+        std::fs::copy(path, &kept)
+            .with_context(|| format!("Failed to back up config to {}", kept.display()))?;
+        backup = Some(kept);
+    } else if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    std::fs::write(path, toml::to_string(table)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(backup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(toml: &str) -> Table {
+        toml.parse().unwrap()
+    }
+
+    #[test]
+    fn test_merge_imported_keys_win_and_local_only_keys_stay() {
+        let local = table("max_task_pages = 3\npager = \"bat\"\nslim_tasks = true\n");
+        let imported = table("max_task_pages = 5\ncomment_order = \"newest\"\n");
+
+        let merged = merge(&local, &imported, ImportMode::Merge);
+        assert_eq!(
+            merged,
+            table(
+                "max_task_pages = 5\npager = \"bat\"\nslim_tasks = true\n\
+                 comment_order = \"newest\"\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_merge_nested_tables_key_by_key() {
+        let local = table("[ui]\naccessibility_mode = true\nsidebar_tree = false\n");
+        let imported = table("[ui]\nsidebar_tree = true\nmin_width_for_two_column = 100\n");
+
+        let merged = merge(&local, &imported, ImportMode::Merge);
+        assert_eq!(
+            merged,
+            table(
+                "[ui]\naccessibility_mode = true\nsidebar_tree = true\n\
+                 min_width_for_two_column = 100\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_merge_value_replaces_table_and_table_replaces_value() {
+        let merged = merge(
+            &table("theme = \"dark\"\n[window]\nwidth = 80\n"),
+            &table("window = 3\n[theme]\nname = \"light\"\n"),
+            ImportMode::Merge,
+        );
+        assert_eq!(merged, table("window = 3\n[theme]\nname = \"light\"\n"));
+    }
+
+    #[test]
+    fn test_replace_drops_local_only_keys() {
+        let local = table("max_task_pages = 3\npager = \"bat\"\n[ui]\naccessibility_mode = true\n");
+        let imported = table("max_task_pages = 5\n[ui]\nsidebar_tree = true\n");
+
+        let merged = merge(&local, &imported, ImportMode::Replace);
+        assert_eq!(merged, imported);
+    }
+
+    #[test]
+    fn test_machine_and_secret_keys_stay_local_in_both_modes() {
+        let local = table("cache_dir = \"/home/me/cache\"\napi_token = \"pk_mine\"\n");
+        let imported = table("cache_dir = \"/tmp/theirs\"\nprofile = \"work\"\napi_token = \"pk_theirs\"\n");
+
+        for mode in [ImportMode::Merge, ImportMode::Replace] {
+            let merged = merge(&local, &imported, mode);
+            assert_eq!(merged, local, "{:?}", mode);
+        }
+
+        // Unset locally stays unset
+        let merged = merge(&Table::new(), &imported, ImportMode::Merge);
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn test_merge_with_empty_sides() {
+        let config = table("max_task_pages = 3\n[ui]\nsidebar_tree = true\n");
+        assert_eq!(merge(&Table::new(), &config, ImportMode::Merge), config);
+        assert_eq!(merge(&config, &Table::new(), ImportMode::Merge), config);
+        assert!(merge(&config, &Table::new(), ImportMode::Replace).is_empty());
+    }
+
+    #[test]
+    fn test_merge_keeps_unknown_local_keys() {
+        let local = table("from_newer_build = 1\n");
+        let merged = merge(&local, &table("max_task_pages = 2\n"), ImportMode::Merge);
+        assert_eq!(merged.get("from_newer_build"), Some(&Value::Integer(1)));
+    }
+
+    #[test]
+    fn test_parse_import_validates_and_names_the_key() {
+        let imported = parse_import("max_task_pages = 4\n[ui]\nsidebar_tree = true\n").unwrap();
+        assert_eq!(imported.get("max_task_pages"), Some(&Value::Integer(4)));
+
+        let error = parse_import("max_task_pages = \"many\"\n").unwrap_err();
+        assert!(format!("{:#}", error).contains("`max_task_pages`"), "{:#}", error);
+
+        let error = parse_import("[ui]\nsidebar_tree = 1\n").unwrap_err();
+        assert!(format!("{:#}", error).contains("`ui.sidebar_tree`"), "{:#}", error);
+
+        let error = parse_import("start_screen = \"inbox\"\n").unwrap_err();
+        assert!(format!("{:#}", error).contains("`start_screen`"), "{:#}", error);
+
+        let error = parse_import("max_tasks_pages = 4\n").unwrap_err();
+        assert_eq!(format!("{:#}", error), "`max_tasks_pages`: unknown setting");
+
+        let error = parse_import("[ui]\nsidebar = true\n").unwrap_err();
+        assert_eq!(format!("{:#}", error), "`ui.sidebar`: unknown setting");
+
+        assert!(parse_import("max_task_pages = ").is_err());
+        assert!(parse_import("version = 99\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_import_drops_secrets_and_upgrades() {
+        let imported = parse_import(
+            "api_token = \"pk_1\"\nslim_tasks = true\nwindow_width = 100\ntheme = \"dark\"\n",
+        )
+        .unwrap();
+        assert_eq!(imported.get("api_token"), None);
+        assert_eq!(imported.get("slim_tasks"), Some(&Value::Boolean(true)));
+        // The upgraded `[window]` and `[theme]` aren't read by this build
+        assert_eq!(imported.get("window"), None);
+        assert_eq!(imported.get("theme"), None);
+        assert_eq!(
+            imported.get("version"),
+            Some(&Value::Integer(migrate::CURRENT_VERSION.into()))
+        );
+    }
+
+    #[test]
+    fn test_export_strips_secret_and_machine_keys() {
+        let config = AppConfig {
+            cache_dir: Some("/home/me/cache".into()),
+            profile: Some("work".to_string()),
+            max_task_pages: 4,
+            ..AppConfig::default()
+        };
+        let exported = export(&config).unwrap();
+        assert!(!exported.contains("cache_dir"));
+        assert!(!exported.contains("profile"));
+        assert!(exported.contains("max_task_pages = 4"));
+
+        // What's exported imports cleanly
+        let imported = parse_import(&exported).unwrap();
+        let back = validate(&imported).unwrap();
+        assert_eq!(back.max_task_pages, 4);
+        assert_eq!(back.profile, None);
+    }
+
+    #[test]
+    fn test_diff_lists_changes_by_dotted_key() {
+        let before = table("max_task_pages = 3\npager = \"bat\"\n[ui]\nsidebar_tree = false\n");
+        let after = table("max_task_pages = 3\nslim_tasks = true\n[ui]\nsidebar_tree = true\n");
+
+        let lines: Vec<String> = diff(&before, &after).iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "- pager = \"bat\"",
+                "+ slim_tasks = true",
+                "~ ui.sidebar_tree = false -> true",
+            ]
+        );
+        assert!(diff(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_write_keeps_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clickdown").join("config.toml");
+        let backups = || crate::config::tests::backups(path.parent().unwrap());
+        assert_eq!(write(&path, &table("max_task_pages = 3\n")).unwrap(), None);
+        assert!(backups().is_empty());
+
+        write(&path, &table("max_task_pages = 5\n")).unwrap();
+        write(&path, &table("max_task_pages = 7\n")).unwrap();
+        assert_eq!(read_local(&path).unwrap().get("max_task_pages"), Some(&Value::Integer(7)));
+        // Each write keeps its own backup
+        let kept: Vec<String> = backups()
+            .iter()
+            .map(|backup| std::fs::read_to_string(backup).unwrap())
+            .collect();
+        assert_eq!(kept, ["max_task_pages = 3\n", "max_task_pages = 5\n"]);
+    }
+}
@@ -24,11 +24,14 @@ async fn main() -> Result<()> {
     init_logging();
 
     // Parse CLI arguments
-    let args = cli::args::parse_args().map_err(|e| {
-        eprintln!("Error parsing arguments: {}", e);
-        cli::args::print_usage();
-        anyhow::anyhow!("Invalid arguments")
-    })?;
+    let args = match cli::args::parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Error parsing arguments: {}", e);
+            cli::args::print_usage();
+            std::process::exit(cli::args::exit_codes::INVALID_ARGS);
+        }
+    };
 
     match args.debug_command {
         Some(cmd) => {
@@ -41,6 +44,15 @@ async fn main() -> Result<()> {
             // Run in TUI mode
             tracing::info!("Starting ClickDown TUI...");
             let mut app = tui::app::TuiApp::new()?;
+            if args.no_wizard {
+                app.set_onboarding_wizard(false);
+            }
+            if let Some(start) = args.start_screen {
+                app.apply_start_screen(start);
+            }
+            if let Some(target) = args.open_target {
+                app.open_target(target);
+            }
             app.run()?;
         }
     }
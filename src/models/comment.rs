@@ -20,16 +20,17 @@
 //!
 //! # Debugging Parse Errors
 //!
-//! If parsing fails, use CLI debug mode with `--verbose` to see the full API response:
+//! If parsing fails, use CLI debug mode with `--verbose` to see the full API response,
+//! or `--dump-responses` to save the body that failed to a file in the cache dir:
 //! ```bash
-//! clickdown debug create-reply <comment_id> --text "Test" --verbose
+//! clickdown debug create-reply <comment_id> --text "Test" --dump-responses
 //! ```
 //!
 //! The error message will include the field path (via `serde_path_to_error`) to help
-//! identify which field caused the failure.
+//! identify which field caused the failure, and the path of any saved response.
 
 use crate::utils::deserializers::{
-    flexible_string, flexible_timestamp, null_to_empty_string, null_to_false,
+    flexible_int, flexible_string, flexible_timestamp, null_to_empty_string, null_to_false,
 };
 use serde::{Deserialize, Serialize};
 
@@ -106,6 +107,13 @@ pub struct Comment {
     /// Parent comment ID for threaded replies (None for top-level comments)
     #[serde(default, rename = "parent_id", skip_serializing_if = "Option::is_none")]
     pub parent_id: Option<String>,
+    /// Number of replies reported by the API, available before the replies are fetched
+    #[serde(
+        default,
+        deserialize_with = "flexible_int",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reply_count: Option<i32>,
 }
 
 /// User reference in comment context
@@ -119,6 +127,65 @@ pub struct CommentsResponse {
     pub comments: Vec<Comment>,
 }
 
+/// Comments returned per page by `/task/{task_id}/comment`
+pub const COMMENTS_PAGE_SIZE: u32 = 25;
+
+/// Where the next page of comments starts
+///
+/// ClickUp returns comments newest first; a page continues after the comment
+/// with this date (`start`) and id (`start_id`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentsCursor {
+    pub start: Option<i64>,
+    pub start_id: i64,
+}
+
+impl CommentsCursor {
+    /// Cursor continuing after `comment`, or None if its id isn't numeric
+    pub fn after(comment: &Comment) -> Option<Self> {
+        Some(Self {
+            start: comment.created_at,
+            start_id: comment.id.parse().ok()?,
+        })
+    }
+
+    /// Query string for `/task/{task_id}/comment`
+    pub fn query(&self) -> String {
+        match self.start {
+            Some(start) => format!("start={}&start_id={}", start, self.start_id),
+            None => format!("start_id={}", self.start_id),
+        }
+    }
+}
+
+/// One page of a task's top-level comments, newest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommentsPage {
+    pub comments: Vec<Comment>,
+    /// Cursor for the next (older) page, None on the last page
+    pub next_cursor: Option<CommentsCursor>,
+}
+
+impl CommentsPage {
+    /// Build a page from a fetched batch, keeping at most `limit` comments.
+    ///
+    /// A batch shorter than the page size is the last page.
+    pub fn from_batch(mut comments: Vec<Comment>, limit: Option<u32>) -> Self {
+        let page_size = limit.unwrap_or(COMMENTS_PAGE_SIZE).max(1) as usize;
+        let full_page = comments.len() >= page_size;
+        comments.truncate(page_size);
+        let next_cursor = if full_page {
+            comments.last().and_then(CommentsCursor::after)
+        } else {
+            None
+        };
+        Self {
+            comments,
+            next_cursor,
+        }
+    }
+}
+
 /// Request body for creating a comment
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateCommentRequest {
@@ -131,6 +198,31 @@ pub struct CreateCommentRequest {
     pub parent_id: Option<String>,
 }
 
+/// How much earlier than the request ClickUp's clock may date a comment it
+/// created, allowing for the two clocks disagreeing
+const CREATED_COMMENT_SKEW_MS: i64 = 5 * 60 * 1000;
+
+impl CreateCommentRequest {
+    /// The comment among `comments` this request created, if any: the same
+    /// text, by `author_id` when known, dated no earlier than `sent_at` (ms)
+    /// less some clock skew. The newest such comment wins.
+    pub fn find_created<'a>(
+        &self,
+        comments: &'a [Comment],
+        author_id: Option<i64>,
+        sent_at: i64,
+    ) -> Option<&'a Comment> {
+        comments
+            .iter()
+            .filter(|c| c.text.trim() == self.comment_text.trim())
+            .filter(|c| {
+                author_id.is_none_or(|id| c.commenter.as_ref().is_some_and(|u| u.id == id))
+            })
+            .filter(|c| c.created_at.is_some_and(|at| at >= sent_at - CREATED_COMMENT_SKEW_MS))
+            .max_by_key(|c| c.created_at)
+    }
+}
+
 /// Request body for updating a comment
 #[derive(Debug, Clone, Serialize)]
 pub struct UpdateCommentRequest {
@@ -144,9 +236,74 @@ pub struct UpdateCommentRequest {
     pub assigned_commenter: Option<i64>,
 }
 
+impl UpdateCommentRequest {
+    /// Whether `comment` already has the text, resolved state and assignee
+    /// this request sets
+    pub fn applied_to(&self, comment: &Comment) -> bool {
+        self.comment_text
+            .as_ref()
+            .is_none_or(|text| text.trim() == comment.text.trim())
+            && self.assigned.is_none_or(|assigned| assigned == comment.assigned)
+            && self.assignee.is_none_or(|id| {
+                comment
+                    .assigned_commenter
+                    .as_ref()
+                    .is_some_and(|user| user.id == id)
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    fn numbered_comments(count: usize) -> Vec<Comment> {
+        (1..=count)
+            .map(|i| Comment {
+                id: i.to_string(),
+                text: String::new(),
+                text_preview: String::new(),
+                commenter: None,
+                created_at: None,
+                updated_at: None,
+                assigned_commenter: None,
+                assigned_by: None,
+                assigned: false,
+                reaction: String::new(),
+                parent_id: None,
+                reply_count: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_comments_page_cursor_only_on_full_page() {
+        let page = CommentsPage::from_batch(numbered_comments(25), None);
+        assert_eq!(page.comments.len(), 25);
+        assert_eq!(page.next_cursor.map(|c| c.start_id), Some(25));
+
+        let page = CommentsPage::from_batch(numbered_comments(7), None);
+        assert_eq!(page.next_cursor, None);
+
+        let page = CommentsPage::from_batch(numbered_comments(10), Some(4));
+        assert_eq!(page.comments.len(), 4);
+        assert_eq!(page.next_cursor.map(|c| c.start_id), Some(4));
+    }
+
+    #[test]
+    fn test_comments_cursor_query_has_start_and_start_id() {
+        let mut comment = numbered_comments(3).pop().unwrap();
+        comment.created_at = Some(1700000000000);
+        let cursor = CommentsCursor::after(&comment).unwrap();
+        assert_eq!(cursor.query(), "start=1700000000000&start_id=3");
+
+        comment.created_at = None;
+        let cursor = CommentsCursor::after(&comment).unwrap();
+        assert_eq!(cursor.query(), "start_id=3");
+
+        comment.id = "not-a-number".to_string();
+        assert!(CommentsCursor::after(&comment).is_none());
+    }
     use serde_json;
 
     #[test]
@@ -260,6 +417,17 @@ mod tests {
         assert_eq!(comment.created_at, Some(1234567890));
     }
 
+    #[test]
+    fn test_comment_reply_count() {
+        let json = r#"{"id": "123", "comment_text": "Test", "reply_count": "3"}"#;
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(comment.reply_count, Some(3));
+
+        let json = r#"{"id": "123", "comment_text": "Test"}"#;
+        let comment: Comment = serde_json::from_str(json).unwrap();
+        assert_eq!(comment.reply_count, None);
+    }
+
     #[test]
     fn test_comment_reactions_array() {
         // Note: reactions array deserialization not yet implemented
@@ -480,4 +648,60 @@ mod tests {
         let comment: Comment = serde_json::from_str(json).unwrap();
         assert_eq!(comment.id, "");
     }
+
+    #[test]
+    fn test_find_created_comment_by_text_author_and_date() {
+        let comment = |id: &str, text: &str, author: i64, at: i64| {
+            let json = serde_json::json!({
+                "id": id,
+                "comment_text": text,
+                "user": { "id": author, "username": "u" },
+                "date": at.to_string(),
+            });
+            serde_json::from_value::<Comment>(json).unwrap()
+        };
+        let sent_at = 1_700_000_000_000;
+        let comments = vec![
+            comment("other-author", "Done", 2, sent_at + 500),
+            comment("created", "Done\n", 1, sent_at + 1_000),
+            comment("before", "Done", 1, sent_at - CREATED_COMMENT_SKEW_MS - 1),
+            comment("other-text", "Not done", 1, sent_at + 2_000),
+        ];
+        let request = CreateCommentRequest {
+            comment_text: "Done".to_string(),
+            assignee: None,
+            assigned_commenter: None,
+            parent_id: None,
+        };
+
+        let found = request.find_created(&comments, Some(1), sent_at);
+        assert_eq!(found.map(|c| c.id.as_str()), Some("created"));
+        // Without the author, the newest match wins
+        let found = request.find_created(&comments, None, sent_at);
+        assert_eq!(found.map(|c| c.id.as_str()), Some("created"));
+        assert!(request.find_created(&comments[2..], Some(1), sent_at).is_none());
+    }
+
+    #[test]
+    fn test_update_applied_to_comment() {
+        let comment: Comment = serde_json::from_value(serde_json::json!({
+            "id": "c1",
+            "comment_text": "New text",
+            "assignee": { "id": 7, "username": "a" },
+            "resolved": false,
+        }))
+        .unwrap();
+        let update = |text: Option<&str>, assigned, assignee| UpdateCommentRequest {
+            comment_text: text.map(str::to_string),
+            assigned,
+            assignee,
+            assigned_commenter: None,
+        };
+
+        assert!(update(Some("New text"), None, None).applied_to(&comment));
+        assert!(update(None, Some(false), Some(7)).applied_to(&comment));
+        assert!(!update(Some("Old text"), None, None).applied_to(&comment));
+        assert!(!update(None, Some(true), None).applied_to(&comment));
+        assert!(!update(None, None, Some(8)).applied_to(&comment));
+    }
 }
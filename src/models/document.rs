@@ -55,6 +55,12 @@ pub struct DocumentsResponse {
     pub docs: Vec<Document>,
 }
 
+/// API response for getting a single document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentResponse {
+    pub doc: Document,
+}
+
 /// API response for getting document pages
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentPagesResponse {
@@ -0,0 +1,171 @@
+//! Features a workspace's plan and settings turn on
+//!
+//! Spaces carry a `features` block, e.g. `"time_tracking": {"enabled": false}`,
+//! with one entry per ClickApp. Which entries appear depends on the plan and
+//! changes as ClickUp adds ClickApps, so any of them may be missing.
+
+use serde::de::Deserializer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A feature some actions depend on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Logging time on tasks
+    TimeTracking,
+    /// Tags on tasks
+    Tags,
+    /// More than one assignee per task
+    MultipleAssignees,
+}
+
+impl Feature {
+    /// Key of the feature in a `features` block
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::TimeTracking => "time_tracking",
+            Self::Tags => "tags",
+            Self::MultipleAssignees => "multiple_assignees",
+        }
+    }
+
+    /// Name of the feature in messages, e.g. "time tracking"
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TimeTracking => "time tracking",
+            Self::Tags => "tags",
+            Self::MultipleAssignees => "multiple assignees",
+        }
+    }
+}
+
+/// Which features are on, by key; features not listed are unknown
+///
+/// Reads both ClickUp's `{"enabled": bool}` entries and plain booleans (the
+/// way it is serialized), skipping entries that are neither.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct Features {
+    enabled: BTreeMap<String, bool>,
+}
+
+impl<'de> Deserialize<'de> for Features {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = Option::<BTreeMap<String, serde_json::Value>>::deserialize(deserializer)?;
+        let enabled = entries
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let on = match &value {
+                    serde_json::Value::Bool(on) => Some(*on),
+                    other => other.get("enabled").and_then(serde_json::Value::as_bool),
+                };
+                on.map(|on| (key, on))
+            })
+            .collect();
+        Ok(Self { enabled })
+    }
+}
+
+impl Features {
+    /// Whether `feature` is known to be on or off; `None` when not listed
+    pub fn get(&self, feature: Feature) -> Option<bool> {
+        self.enabled.get(feature.key()).copied()
+    }
+
+    /// Whether actions using `feature` should be offered
+    ///
+    /// Only a feature known to be off is refused: an unknown one is left
+    /// for ClickUp to reject rather than hiding actions that would work.
+    pub fn allows(&self, feature: Feature) -> bool {
+        self.get(feature) != Some(false)
+    }
+
+    /// A workspace's features from its spaces: on when any space has it on,
+    /// off when every space listing it has it off
+    pub fn of_spaces<'a>(spaces: impl IntoIterator<Item = &'a Features>) -> Self {
+        let mut merged = Self::default();
+        for features in spaces {
+            for (key, on) in &features.enabled {
+                *merged.enabled.entry(key.clone()).or_insert(false) |= on;
+            }
+        }
+        merged
+    }
+
+    /// Each listed feature by key, in key order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.enabled.iter().map(|(key, on)| (key.as_str(), *on))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ClickUpSpace;
+
+    fn space(json: &str) -> ClickUpSpace {
+        serde_json::from_str(json).unwrap()
+    }
+
+    const FREE_PLAN_SPACE: &str = include_str!("../../tests/fixtures/space_free_plan.json");
+    const BUSINESS_PLAN_SPACE: &str = include_str!("../../tests/fixtures/space_business_plan.json");
+
+    #[test]
+    fn test_free_plan_space_features() {
+        let features = space(FREE_PLAN_SPACE).features;
+        assert_eq!(features.get(Feature::TimeTracking), Some(false));
+        assert!(!features.allows(Feature::TimeTracking));
+        assert!(features.allows(Feature::Tags));
+        // Not listed on the free plan: unknown, so still offered
+        assert_eq!(features.get(Feature::MultipleAssignees), None);
+        assert!(features.allows(Feature::MultipleAssignees));
+    }
+
+    #[test]
+    fn test_business_plan_space_features() {
+        let features = space(BUSINESS_PLAN_SPACE).features;
+        assert_eq!(features.get(Feature::TimeTracking), Some(true));
+        assert_eq!(features.get(Feature::Tags), Some(true));
+        assert_eq!(features.get(Feature::MultipleAssignees), Some(true));
+    }
+
+    #[test]
+    fn test_missing_null_and_odd_feature_entries() {
+        assert_eq!(space(r#"{"id": "1", "name": "S"}"#).features, Features::default());
+        let features = space(
+            r#"{"id": "1", "name": "S", "features": {
+                "time_tracking": {"harvest": false},
+                "tags": true,
+                "points": null,
+                "sprints": {"enabled": "yes"}
+            }}"#,
+        )
+        .features;
+        assert_eq!(features.iter().collect::<Vec<_>>(), [("tags", true)]);
+        assert!(features.allows(Feature::TimeTracking));
+
+        let features: Features = serde_json::from_str("null").unwrap();
+        assert_eq!(features, Features::default());
+    }
+
+    #[test]
+    fn test_serialized_features_read_back() {
+        let features = space(FREE_PLAN_SPACE).features;
+        let json = serde_json::to_string(&features).unwrap();
+        assert_eq!(serde_json::from_str::<Features>(&json).unwrap(), features);
+    }
+
+    #[test]
+    fn test_workspace_features_merge_spaces() {
+        let free = space(FREE_PLAN_SPACE).features;
+        let mut tracked = Features::default();
+        tracked.enabled.insert("time_tracking".to_string(), true);
+
+        let merged = Features::of_spaces([&free, &tracked]);
+        assert_eq!(merged.get(Feature::TimeTracking), Some(true));
+        assert_eq!(merged.get(Feature::Tags), Some(true));
+        assert_eq!(merged.get(Feature::MultipleAssignees), None);
+        assert_eq!(Features::of_spaces([]), Features::default());
+    }
+}
@@ -2,21 +2,42 @@
 
 pub mod comment;
 pub mod document;
+pub mod features;
+pub mod permission;
 pub mod session;
+pub mod sprint;
+pub mod standup;
 pub mod task;
+pub mod time_entry;
+pub mod time_in_status;
 pub mod user;
+pub mod view;
 pub mod workspace;
 
 // Export specific types to avoid name conflicts with iced
-pub use comment::{Comment, CommentsResponse, CreateCommentRequest, UpdateCommentRequest};
+pub use comment::{
+    Comment, CommentsCursor, CommentsPage, CommentsResponse, CreateCommentRequest,
+    UpdateCommentRequest, COMMENTS_PAGE_SIZE,
+};
 pub use document::{
-    Document, DocumentFilters, DocumentPagesResponse, DocumentsResponse, Page, PageResponse,
+    Document, DocumentFilters, DocumentPagesResponse, DocumentResponse, DocumentsResponse, Page,
+    PageResponse,
 };
+pub use features::{Feature, Features};
+pub use permission::Capabilities;
 pub use session::SessionState;
+pub use sprint::{SprintDates, SprintOverview};
 pub use task::*;
+pub use time_entry::{TaskTimeResponse, TimeEntriesResponse, TimeEntry};
+pub use time_in_status::{TimeInStatus, TimeInStatusResponse};
 pub use user::MembersResponse;
 pub use user::User;
+pub use user::GroupAssignee;
+pub use view::{
+    ListViewsResponse, View, ViewResponse, ViewTasksResponse, WorkspaceView,
+    WorkspaceViewsResponse, VIEW_PARENT_LIST,
+};
 pub use workspace::{
     Folder, FolderReference as ClickUpFolderReference, FoldersResponse, List, ListsResponse,
-    Space as ClickUpSpace, SpacesResponse, UserResponse, Workspace, WorkspacesResponse,
+    Space as ClickUpSpace, SpacesResponse, UpdateSpaceRequest, UserResponse, Workspace, WorkspacesResponse,
 };
@@ -0,0 +1,131 @@
+//! What the token may do with an item
+//!
+//! ClickUp reports a token's access to a task as `permission_level`: one of
+//! "read", "comment", "edit" or "create", each allowing everything the ones
+//! before it do. Guests shared on a single task see `access: false` on the
+//! list, folder and space it lives in.
+
+/// How much the token may do with an item, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PermissionLevel {
+    /// View only
+    Read,
+    /// View and comment
+    Comment,
+    /// Comment and change the item
+    Edit,
+    /// Everything, including creating and deleting
+    Create,
+}
+
+impl PermissionLevel {
+    /// Parse a `permission_level` value; `None` for values this version doesn't know
+    pub fn from_api(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "read" | "view" => Some(Self::Read),
+            "comment" => Some(Self::Comment),
+            "edit" => Some(Self::Edit),
+            "create" | "full" => Some(Self::Create),
+            _ => None,
+        }
+    }
+
+    /// The actions this level allows
+    pub fn capabilities(self) -> Capabilities {
+        Capabilities {
+            comment: self >= Self::Comment,
+            edit: self >= Self::Edit,
+            delete: self >= Self::Create,
+        }
+    }
+}
+
+/// Actions the token may take on an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Add comments and replies, and change its own comments
+    pub comment: bool,
+    /// Change the item: fields, status, assignees, and others' comments
+    pub edit: bool,
+    /// Delete the item
+    pub delete: bool,
+}
+
+impl Capabilities {
+    /// Everything allowed; used when ClickUp doesn't say
+    pub const FULL: Self = Self {
+        comment: true,
+        edit: true,
+        delete: true,
+    };
+
+    /// Nothing but viewing
+    pub const READ_ONLY: Self = Self {
+        comment: false,
+        edit: false,
+        delete: false,
+    };
+
+    /// Capabilities for a `permission_level` value
+    ///
+    /// A missing or unknown level allows everything, leaving ClickUp to
+    /// refuse what it must rather than hiding actions that would work.
+    pub fn from_permission_level(level: Option<&str>) -> Self {
+        level
+            .and_then(PermissionLevel::from_api)
+            .map_or(Self::FULL, PermissionLevel::capabilities)
+    }
+
+    /// Whether nothing but viewing is allowed
+    pub fn is_read_only(&self) -> bool {
+        !self.comment && !self.edit && !self.delete
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels_parse_case_insensitively() {
+        assert_eq!(PermissionLevel::from_api("read"), Some(PermissionLevel::Read));
+        assert_eq!(PermissionLevel::from_api("Comment"), Some(PermissionLevel::Comment));
+        assert_eq!(PermissionLevel::from_api(" EDIT "), Some(PermissionLevel::Edit));
+        assert_eq!(PermissionLevel::from_api("create"), Some(PermissionLevel::Create));
+        assert_eq!(PermissionLevel::from_api("owner"), None);
+        assert_eq!(PermissionLevel::from_api(""), None);
+    }
+
+    #[test]
+    fn test_each_level_allows_the_ones_before_it() {
+        let caps = |level: &str| Capabilities::from_permission_level(Some(level));
+
+        assert!(caps("read").is_read_only());
+        assert_eq!(
+            caps("comment"),
+            Capabilities {
+                comment: true,
+                edit: false,
+                delete: false
+            }
+        );
+        assert_eq!(
+            caps("edit"),
+            Capabilities {
+                comment: true,
+                edit: true,
+                delete: false
+            }
+        );
+        assert_eq!(caps("create"), Capabilities::FULL);
+    }
+
+    #[test]
+    fn test_missing_or_unknown_level_allows_everything() {
+        assert_eq!(Capabilities::from_permission_level(None), Capabilities::FULL);
+        assert_eq!(
+            Capabilities::from_permission_level(Some("superuser")),
+            Capabilities::FULL
+        );
+    }
+}
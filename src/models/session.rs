@@ -34,6 +34,7 @@ impl SessionState {
     /// Create a new SessionState from the current TuiApp state
     ///
     /// This captures the current navigation context for later restoration.
+    #[allow(clippy::too_many_arguments)]
     pub fn from_app(
         screen: &crate::tui::app::Screen,
         workspace_id: Option<String>,
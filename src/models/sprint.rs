@@ -0,0 +1,235 @@
+//! Sprint overview model
+//!
+//! ClickUp implements sprints as views, so the overview is computed from the
+//! tasks returned by a sprint view rather than fetched from a dedicated endpoint.
+//! The sprint's dates come from the sprint list the view is saved on.
+
+use crate::models::task::Task;
+use crate::utils::deserializers::flexible_timestamp;
+use serde::{Deserialize, Serialize};
+
+/// One day in milliseconds (ClickUp timestamps are in ms)
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Start and due date set on a sprint list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SprintDates {
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub start_date: Option<i64>,
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub due_date: Option<i64>,
+}
+
+/// Aggregated sprint statistics for a sprint view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SprintOverview {
+    pub name: String,
+    /// Sprint start (ms since epoch)
+    pub start_date: i64,
+    /// Sprint end (ms since epoch)
+    pub end_date: i64,
+    pub total_points: f32,
+    pub completed_points: f32,
+    pub tasks_total: u32,
+    pub tasks_completed: u32,
+    /// Average points completed per elapsed sprint day
+    pub velocity: f32,
+    /// Remaining points at the end of each elapsed sprint day (index 0 = sprint start)
+    #[serde(default)]
+    pub burndown: Vec<f32>,
+    /// Points completed on each elapsed sprint day
+    #[serde(default)]
+    pub daily_completed: Vec<f32>,
+}
+
+impl SprintOverview {
+    /// Build an overview from the sprint list's dates and the tasks in a sprint view.
+    ///
+    /// A date missing from the sprint list falls back to the earliest task start
+    /// date or the latest task due date. Tasks without story points count as zero
+    /// points but still count as tasks.
+    pub fn from_tasks(name: &str, dates: SprintDates, tasks: &[Task], now: i64) -> Self {
+        let start_date = dates
+            .start_date
+            .or_else(|| tasks.iter().filter_map(|t| t.start_date.or(t.created_at)).min())
+            .unwrap_or(now);
+        let end_date = dates
+            .due_date
+            .or_else(|| tasks.iter().filter_map(|t| t.due_date).max())
+            .unwrap_or(now)
+            .max(start_date);
+
+        let total_points: f32 = tasks.iter().map(task_points).sum();
+        let completed: Vec<(f32, i64)> = tasks
+            .iter()
+            .filter(|t| is_completed(t))
+            .map(|t| (task_points(t), t.done_at.or(t.closed_at).unwrap_or(now)))
+            .collect();
+        let completed_points: f32 = completed.iter().map(|(points, _)| points).sum();
+
+        let sprint_days = days_between(start_date, end_date);
+        let elapsed_days = days_between(start_date, now.min(end_date)).min(sprint_days);
+
+        let mut daily_completed = vec![0.0; elapsed_days];
+        for (points, at) in &completed {
+            let day = ((at - start_date).max(0) / DAY_MS) as usize;
+            if let Some(slot) = daily_completed.get_mut(day.min(elapsed_days.saturating_sub(1))) {
+                *slot += points;
+            }
+        }
+
+        let mut burndown = Vec::with_capacity(elapsed_days + 1);
+        let mut remaining = total_points;
+        burndown.push(remaining);
+        for points in &daily_completed {
+            remaining -= points;
+            burndown.push(remaining.max(0.0));
+        }
+
+        Self {
+            name: name.to_string(),
+            start_date,
+            end_date,
+            total_points,
+            completed_points,
+            tasks_total: tasks.len() as u32,
+            tasks_completed: completed.len() as u32,
+            velocity: completed_points / elapsed_days.max(1) as f32,
+            burndown,
+            daily_completed,
+        }
+    }
+
+    /// Total number of days in the sprint (at least one)
+    pub fn sprint_days(&self) -> usize {
+        days_between(self.start_date, self.end_date)
+    }
+
+    /// Percentage of points completed (0-100)
+    pub fn completion_percent(&self) -> u16 {
+        if self.total_points <= 0.0 {
+            return 0;
+        }
+        ((self.completed_points / self.total_points) * 100.0).round() as u16
+    }
+}
+
+/// Story points of a task, treating missing points as zero
+fn task_points(task: &Task) -> f32 {
+    task.points.unwrap_or(0) as f32
+}
+
+/// Whether a task counts as completed for sprint purposes
+fn is_completed(task: &Task) -> bool {
+    let closed_status = task
+        .status
+        .as_ref()
+        .and_then(|s| s.type_field.as_deref())
+        .is_some_and(|t| t == "closed" || t == "done");
+    closed_status || task.done_at.is_some() || task.closed_at.is_some()
+}
+
+/// Number of started days between two timestamps (at least one)
+fn days_between(start: i64, end: i64) -> usize {
+    let span = (end - start).max(0);
+    (((span + DAY_MS - 1) / DAY_MS) as usize).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaskStatus;
+
+    const START: i64 = 1_700_000_000_000;
+
+    fn sprint_task(points: i32, done_day: Option<i64>) -> Task {
+        Task {
+            id: format!("t-{}", points),
+            name: "Sprint task".to_string(),
+            start_date: Some(START),
+            due_date: Some(START + 10 * DAY_MS),
+            points: Some(points),
+            done_at: done_day.map(|d| START + d * DAY_MS + 1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_tasks_computes_totals_and_velocity() {
+        let tasks = vec![
+            sprint_task(5, Some(0)),
+            sprint_task(3, Some(2)),
+            sprint_task(8, None),
+        ];
+
+        let overview = SprintOverview::from_tasks("Sprint 1", SprintDates::default(), &tasks, START + 4 * DAY_MS);
+
+        assert_eq!(overview.tasks_total, 3);
+        assert_eq!(overview.tasks_completed, 2);
+        assert_eq!(overview.total_points, 16.0);
+        assert_eq!(overview.completed_points, 8.0);
+        assert_eq!(overview.sprint_days(), 10);
+        assert_eq!(overview.velocity, 2.0);
+        assert_eq!(overview.completion_percent(), 50);
+    }
+
+    #[test]
+    fn test_from_tasks_builds_burndown_for_elapsed_days() {
+        let tasks = vec![sprint_task(5, Some(0)), sprint_task(3, Some(2))];
+
+        let overview = SprintOverview::from_tasks("Sprint 1", SprintDates::default(), &tasks, START + 3 * DAY_MS);
+
+        assert_eq!(overview.daily_completed, vec![5.0, 0.0, 3.0]);
+        assert_eq!(overview.burndown, vec![8.0, 3.0, 3.0, 0.0]);
+    }
+
+    #[test]
+    fn test_closed_status_counts_as_completed() {
+        let mut task = sprint_task(2, None);
+        task.status = Some(TaskStatus {
+            id: None,
+            status: "shipped".to_string(),
+            color: None,
+            type_field: Some("closed".to_string()),
+            orderindex: None,
+            status_group: None,
+        });
+
+        let overview = SprintOverview::from_tasks("Sprint", SprintDates::default(), &[task], START + DAY_MS);
+
+        assert_eq!(overview.tasks_completed, 1);
+        assert_eq!(overview.completed_points, 2.0);
+    }
+
+    #[test]
+    fn test_sprint_list_dates_set_the_window() {
+        // Undated task finished early: the window still follows the sprint list
+        let mut task = sprint_task(4, Some(1));
+        task.start_date = None;
+        task.due_date = None;
+        let dates: SprintDates = serde_json::from_str(&format!(
+            r#"{{"id": "l1", "name": "Sprint 3", "start_date": "{}", "due_date": {}}}"#,
+            START - DAY_MS,
+            START + 13 * DAY_MS
+        ))
+        .unwrap();
+
+        let overview = SprintOverview::from_tasks("Sprint 3", dates, &[task], START + 3 * DAY_MS);
+
+        assert_eq!(overview.start_date, START - DAY_MS);
+        assert_eq!(overview.end_date, START + 13 * DAY_MS);
+        assert_eq!(overview.sprint_days(), 14);
+        assert_eq!(overview.daily_completed, vec![0.0, 0.0, 4.0, 0.0]);
+        assert_eq!(overview.burndown, vec![4.0, 4.0, 4.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_tasks_empty_view() {
+        let overview = SprintOverview::from_tasks("Empty", SprintDates::default(), &[], START);
+
+        assert_eq!(overview.tasks_total, 0);
+        assert_eq!(overview.velocity, 0.0);
+        assert_eq!(overview.completion_percent(), 0);
+        assert_eq!(overview.burndown, vec![0.0, 0.0]);
+    }
+}
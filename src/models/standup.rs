@@ -0,0 +1,217 @@
+//! Standup summaries of recent work
+//!
+//! The tasks assigned to the user and updated since a cutoff are sorted into
+//! what was finished, what is under way and what is new. ClickUp doesn't say
+//! when a task was assigned, so a task counts as newly assigned when it was
+//! created after the cutoff.
+
+use crate::models::task::{get_status_group_priority, resolve_status_group, StatusGroupPriority};
+use crate::models::Task;
+use crate::utils::url_generator::{ClickUpUrlGenerator, UrlGenerator};
+use serde::Serialize;
+
+/// Recent tasks grouped for a standup, each group most recently updated first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Standup {
+    /// Finished after the cutoff
+    pub done: Vec<Task>,
+    /// In an in-progress status
+    pub in_progress: Vec<Task>,
+    /// Created after the cutoff and not yet finished
+    pub newly_assigned: Vec<Task>,
+}
+
+/// A task as listed in a standup
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StandupTask {
+    pub id: String,
+    pub name: String,
+    pub status: Option<String>,
+    pub url: String,
+}
+
+impl Standup {
+    /// Group `tasks` updated since `cutoff` (ms since epoch)
+    ///
+    /// A task finished before the cutoff but touched since is left out, as
+    /// are tasks that are neither finished, under way nor new.
+    pub fn from_tasks(mut tasks: Vec<Task>, cutoff: i64) -> Self {
+        tasks.sort_by_key(|t| std::cmp::Reverse(t.updated_at.unwrap_or(i64::MIN)));
+
+        let mut standup = Self::default();
+        for task in tasks {
+            let group = get_status_group_priority(&resolve_status_group(task.status.as_ref()));
+            if task.is_finished() {
+                // Without a finish time, the update that brought it here is the best guess
+                let finished_at = task.done_at.or(task.closed_at).or(task.updated_at);
+                if finished_at.is_some_and(|at| at >= cutoff) {
+                    standup.done.push(task);
+                }
+            } else if task.created_at.is_some_and(|at| at >= cutoff) {
+                standup.newly_assigned.push(task);
+            } else if group == StatusGroupPriority::InProgress {
+                standup.in_progress.push(task);
+            }
+        }
+        standup
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.done.is_empty() && self.in_progress.is_empty() && self.newly_assigned.is_empty()
+    }
+
+    /// The groups as (title, tasks), in the order they are reported
+    pub fn groups(&self) -> [(&'static str, &[Task]); 3] {
+        [
+            ("Done", &self.done),
+            ("In Progress", &self.in_progress),
+            ("Newly Assigned", &self.newly_assigned),
+        ]
+    }
+
+    /// Markdown with one bulleted section per group and each task linked
+    pub fn to_markdown(&self, workspace_id: &str) -> String {
+        let mut out = String::new();
+        for (title, tasks) in self.groups() {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("**{}**\n", title));
+            if tasks.is_empty() {
+                out.push_str("- Nothing\n");
+            }
+            for task in tasks {
+                out.push_str(&format!(
+                    "- [{}]({})\n",
+                    escape_link_text(&task.name),
+                    task_url(workspace_id, task)
+                ));
+            }
+        }
+        out
+    }
+}
+
+impl StandupTask {
+    pub fn from_task(workspace_id: &str, task: &Task) -> Self {
+        Self {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            status: task.status.as_ref().map(|s| s.status.clone()),
+            url: task_url(workspace_id, task),
+        }
+    }
+}
+
+/// Link to a task, in the long form when its list is known
+fn task_url(workspace_id: &str, task: &Task) -> String {
+    let list_id = task.list.as_ref().map(|l| l.id.as_str()).unwrap_or("");
+    ClickUpUrlGenerator::task_url(workspace_id, list_id, &task.id)
+        .unwrap_or_else(|_| format!("https://app.clickup.com/t/{}", task.id))
+}
+
+/// Keep brackets in a task name from ending the link text early
+fn escape_link_text(text: &str) -> String {
+    text.replace('[', "\\[").replace(']', "\\]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaskStatus;
+
+    const CUTOFF: i64 = 1_709_510_400_000;
+    const HOUR_MS: i64 = 60 * 60 * 1000;
+
+    fn task(id: &str, group: &str, created_at: i64, updated_at: i64) -> Task {
+        Task {
+            id: id.to_string(),
+            name: format!("Task {}", id),
+            status: Some(TaskStatus {
+                id: None,
+                status: group.to_string(),
+                color: None,
+                type_field: None,
+                orderindex: None,
+                status_group: Some(group.to_string()),
+            }),
+            created_at: Some(created_at),
+            updated_at: Some(updated_at),
+            ..Default::default()
+        }
+    }
+
+    fn ids(tasks: &[Task]) -> Vec<&str> {
+        tasks.iter().map(|t| t.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_groups_done_in_progress_and_new() {
+        let before = CUTOFF - 48 * HOUR_MS;
+        let after = CUTOFF + HOUR_MS;
+        let mut done = task("done", "done", before, after);
+        done.done_at = Some(after);
+        let tasks = vec![
+            done,
+            task("doing", "in progress", before, after),
+            task("new", "to do", after, after + HOUR_MS),
+            task("new-doing", "in progress", after, after),
+            task("stale-todo", "to do", before, after),
+        ];
+
+        let standup = Standup::from_tasks(tasks, CUTOFF);
+
+        assert_eq!(ids(&standup.done), vec!["done"]);
+        assert_eq!(ids(&standup.in_progress), vec!["doing"]);
+        assert_eq!(ids(&standup.newly_assigned), vec!["new", "new-doing"]);
+    }
+
+    #[test]
+    fn test_task_done_before_cutoff_but_updated_after_is_left_out() {
+        let mut task = task("old", "done", CUTOFF - 72 * HOUR_MS, CUTOFF + HOUR_MS);
+        task.done_at = Some(CUTOFF - 24 * HOUR_MS);
+
+        let standup = Standup::from_tasks(vec![task], CUTOFF);
+
+        assert!(standup.is_empty());
+    }
+
+    #[test]
+    fn test_closed_status_type_counts_as_done() {
+        let mut closed = task("closed", "shipped", CUTOFF - HOUR_MS, CUTOFF + HOUR_MS);
+        closed.status.as_mut().unwrap().status_group = None;
+        closed.status.as_mut().unwrap().type_field = Some("closed".to_string());
+        closed.closed_at = Some(CUTOFF + HOUR_MS);
+
+        let standup = Standup::from_tasks(vec![closed], CUTOFF);
+
+        assert_eq!(ids(&standup.done), vec!["closed"]);
+    }
+
+    #[test]
+    fn test_groups_list_most_recently_updated_first() {
+        let tasks = vec![
+            task("a", "in progress", CUTOFF - HOUR_MS, CUTOFF + HOUR_MS),
+            task("b", "in progress", CUTOFF - HOUR_MS, CUTOFF + 2 * HOUR_MS),
+        ];
+
+        let standup = Standup::from_tasks(tasks, CUTOFF);
+
+        assert_eq!(ids(&standup.in_progress), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn test_markdown_links_each_task() {
+        let mut doing = task("t1", "in progress", CUTOFF - HOUR_MS, CUTOFF + HOUR_MS);
+        doing.name = "Fix [urgent] bug".to_string();
+        let standup = Standup::from_tasks(vec![doing], CUTOFF);
+
+        let markdown = standup.to_markdown("ws1");
+
+        assert!(markdown.starts_with("**Done**\n- Nothing\n"));
+        assert!(markdown.contains(
+            "**In Progress**\n- [Fix \\[urgent\\] bug](https://app.clickup.com/t/t1)\n"
+        ));
+        assert!(markdown.contains("**Newly Assigned**\n- Nothing\n"));
+    }
+}
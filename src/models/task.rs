@@ -1,9 +1,10 @@
 //! Task models
 
 use crate::utils::deserializers::{
-    flexible_i64, flexible_int, flexible_resolved, flexible_timestamp, null_to_empty_vec,
-    null_to_false,
+    flexible_i64, flexible_int, flexible_resolved, flexible_timestamp, null_to_empty_string,
+    null_to_empty_vec, null_to_false,
 };
+use crate::models::permission::Capabilities;
 use serde::{Deserialize, Serialize};
 
 /// Flexible description type that can be either a plain string or an object
@@ -109,7 +110,7 @@ pub struct Task {
         deserialize_with = "null_to_empty_vec",
         rename = "group_assignees"
     )]
-    pub group_assignees: Vec<User>,
+    pub group_assignees: Vec<GroupAssignee>,
     #[serde(default, deserialize_with = "null_to_empty_vec")]
     pub watchers: Vec<User>,
     #[serde(default, deserialize_with = "null_to_empty_vec")]
@@ -126,6 +127,8 @@ pub struct Task {
     pub due_date: Option<i64>,
     #[serde(default, deserialize_with = "flexible_timestamp")]
     pub start_date: Option<i64>,
+    #[serde(default)]
+    pub recurrence: Option<RecurrenceConfig>,
     #[serde(default, deserialize_with = "flexible_int")]
     pub points: Option<i32>,
     #[serde(default, deserialize_with = "null_to_empty_vec")]
@@ -164,6 +167,88 @@ pub struct Task {
     pub time_spent: Option<i64>,
 }
 
+impl Task {
+    /// Description source for display: the markdown description when it
+    /// was requested and returned, otherwise the plain description
+    pub fn description_source(&self) -> Option<String> {
+        self.markdown_description
+            .clone()
+            .filter(|markdown| !markdown.is_empty())
+            .or_else(|| self.description.as_ref().map(TaskDescription::as_text))
+    }
+
+    /// Assignee names for display: users first, then groups as "@handle"
+    pub fn assignee_labels(&self) -> Vec<String> {
+        self.assignees
+            .iter()
+            .map(|u| u.username.clone())
+            .chain(self.group_assignees.iter().map(GroupAssignee::mention))
+            .collect()
+    }
+
+    /// Whether the task is in a done or closed status
+    pub fn is_finished(&self) -> bool {
+        get_status_group_priority(&resolve_status_group(self.status.as_ref()))
+            == StatusGroupPriority::Done
+            || self
+                .status
+                .as_ref()
+                .and_then(|s| s.type_field.as_deref())
+                .is_some_and(|kind| kind == "done" || kind == "closed")
+    }
+
+    /// Drop custom field payloads the UI never renders (see `CustomField::slim`)
+    pub fn slim(&mut self) {
+        self.custom_fields.iter_mut().for_each(CustomField::slim);
+    }
+
+    /// What the token may do with this task: its `permission_level`, or
+    /// read-only when it has no access to the task's list, folder or space
+    pub fn capabilities(&self) -> Capabilities {
+        if self.permission_level.is_some() {
+            return Capabilities::from_permission_level(self.permission_level.as_deref());
+        }
+        let no_access = [
+            self.list.as_ref().and_then(|l| l.access),
+            self.folder.as_ref().and_then(|f| f.access),
+            self.space.as_ref().and_then(|s| s.access),
+        ]
+        .contains(&Some(false));
+        if no_access {
+            Capabilities::READ_ONLY
+        } else {
+            Capabilities::FULL
+        }
+    }
+
+    /// Dependencies and links to other tasks, dependencies first
+    ///
+    /// A dependency names this task on one side and the other task on the
+    /// other; which side this task is on says whether it blocks or waits.
+    pub fn related_tasks(&self) -> Vec<RelatedTask> {
+        let dependencies = self.dependencies.iter().filter_map(|d| {
+            let (other, kind) = if d.task_id.as_deref() == Some(self.id.as_str()) {
+                (d.depends_on.clone()?, LinkKind::WaitingOn)
+            } else {
+                (d.task_id.clone()?, LinkKind::Blocking)
+            };
+            Some(RelatedTask {
+                task_id: other,
+                name: d.name.clone(),
+                kind,
+            })
+        });
+        let links = self.linked_tasks.iter().filter_map(|l| {
+            Some(RelatedTask {
+                task_id: l.other_task_id(&self.id)?.to_string(),
+                name: l.name.clone(),
+                kind: LinkKind::Related,
+            })
+        });
+        dependencies.chain(links).collect()
+    }
+}
+
 /// Task status
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskStatus {
@@ -180,9 +265,41 @@ pub struct TaskStatus {
     pub status_group: Option<String>,
 }
 
+/// The status after `current` in `statuses` (in workflow order), wrapping
+/// from the last back to the first
+///
+/// Names match case-insensitively, as tasks and lists don't always agree on
+/// case; an unknown `current` gives the first status.
+#[allow(dead_code)]
+pub fn next_status<'a>(statuses: &'a [TaskStatus], current: &str) -> Option<&'a TaskStatus> {
+    step_status(statuses, current, true)
+}
+
+/// The status before `current` in `statuses`, wrapping from the first to the
+/// last; an unknown `current` gives the last status
+#[allow(dead_code)]
+pub fn previous_status<'a>(statuses: &'a [TaskStatus], current: &str) -> Option<&'a TaskStatus> {
+    step_status(statuses, current, false)
+}
+
+fn step_status<'a>(statuses: &'a [TaskStatus], current: &str, forward: bool) -> Option<&'a TaskStatus> {
+    let len = statuses.len();
+    let position = statuses
+        .iter()
+        .position(|s| s.status.eq_ignore_ascii_case(current));
+    let index = match position {
+        Some(i) if forward => (i + 1) % len,
+        Some(i) => (i + len - 1) % len,
+        None if forward => 0,
+        None => len.checked_sub(1)?,
+    };
+    statuses.get(index)
+}
+
 /// User/Assignee reference
 /// Re-exported from crate::models::User for backwards compatibility
 pub use crate::models::user::User;
+use crate::models::GroupAssignee;
 
 /// Task checklist item
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -300,6 +417,61 @@ pub struct LinkedTask {
     pub workspace_id: Option<String>,
 }
 
+impl LinkedTask {
+    /// The task at the other end of the link from `task_id`
+    pub fn other_task_id(&self, task_id: &str) -> Option<&str> {
+        match (self.task_id.as_deref(), self.link_id.as_deref()) {
+            (Some(from), Some(to)) if from == task_id => Some(to),
+            (Some(from), _) => Some(from),
+            (None, to) => to,
+        }
+    }
+}
+
+/// How a task relates to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// The other task waits on this one
+    Blocking,
+    /// This task waits on the other one
+    WaitingOn,
+    /// A plain link, with no order between the two
+    Related,
+}
+
+impl LinkKind {
+    pub fn icon(self) -> &'static str {
+        match self {
+            Self::Blocking => "⛔",
+            Self::WaitingOn => "⏳",
+            Self::Related => "🔗",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Blocking => "blocking",
+            Self::WaitingOn => "blocked by",
+            Self::Related => "related",
+        }
+    }
+}
+
+/// Another task related to a task, see [`Task::related_tasks`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedTask {
+    pub task_id: String,
+    /// Only known when ClickUp sends it
+    pub name: Option<String>,
+    pub kind: LinkKind,
+}
+
+/// API response for linking two tasks: the task the link was added to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskLinkResponse {
+    pub task: Task,
+}
+
 /// Task reference (for parent tasks)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskReference {
@@ -315,6 +487,46 @@ pub struct Priority {
     pub color: Option<String>,
 }
 
+/// How a recurring task repeats
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecurrenceConfig {
+    /// Repeat unit: "daily", "weekly", "monthly" or "yearly"
+    #[serde(default, rename = "type", deserialize_with = "null_to_empty_string")]
+    pub type_field: String,
+    /// Number of units between occurrences
+    #[serde(default = "RecurrenceConfig::default_period")]
+    pub period: u32,
+    /// Stop after this many occurrences (None repeats indefinitely)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_count: Option<u32>,
+}
+
+impl RecurrenceConfig {
+    fn default_period() -> u32 {
+        1
+    }
+
+    /// Short description for badges, e.g. "weekly" or "every 2 weeks, 5 times"
+    pub fn label(&self) -> String {
+        let unit = match self.type_field.as_str() {
+            "daily" => "day",
+            "weekly" => "week",
+            "monthly" => "month",
+            "yearly" => "year",
+            other => other,
+        };
+        let mut label = if self.period <= 1 {
+            self.type_field.clone()
+        } else {
+            format!("every {} {}s", self.period, unit)
+        };
+        if let Some(count) = self.end_count {
+            label.push_str(&format!(", {} times", count));
+        }
+        label
+    }
+}
+
 /// Custom field value
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomField {
@@ -340,6 +552,140 @@ pub struct CustomField {
     pub required: Option<bool>,
 }
 
+impl CustomField {
+    /// Drop what the UI doesn't render: the rich-text value always, and the
+    /// type config except a drop-down's option ids, indexes and names
+    pub fn slim(&mut self) {
+        use serde_json::{json, Map, Value};
+
+        self.value_richtext = None;
+        let config = self.type_config.take();
+        if self.type_field.as_deref() != Some("drop_down") {
+            return;
+        }
+        let options: Vec<Value> = config
+            .as_ref()
+            .and_then(|c| c.get("options")?.as_array())
+            .map(|options| {
+                options
+                    .iter()
+                    .map(|option| {
+                        let kept: Map<String, Value> = ["id", "orderindex", "name"]
+                            .iter()
+                            .filter_map(|key| Some((key.to_string(), option.get(key)?.clone())))
+                            .collect();
+                        Value::Object(kept)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.type_config = Some(json!({ "options": options }));
+    }
+}
+
+/// A task parsed without the custom field payloads the UI never renders
+///
+/// Same result as [`Task::slim`] after a full parse, but the rich-text
+/// values and unused type config are skipped as they are read instead of
+/// being built and then dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlimTask(pub Task);
+
+impl<'de> Deserialize<'de> for SlimTask {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields {
+            #[serde(default, deserialize_with = "null_to_empty_vec")]
+            custom_fields: Vec<SlimCustomField>,
+            #[serde(flatten)]
+            task: Task,
+        }
+
+        let Fields {
+            custom_fields,
+            mut task,
+        } = Fields::deserialize(deserializer)?;
+        task.custom_fields = custom_fields.into_iter().map(CustomField::from).collect();
+        Ok(SlimTask(task))
+    }
+}
+
+/// A custom field with only what [`CustomField::slim`] keeps; anything else,
+/// `value_richtext` included, is skipped unread
+#[derive(Deserialize)]
+struct SlimCustomField {
+    id: String,
+    name: String,
+    #[serde(default, rename = "type")]
+    type_field: Option<String>,
+    #[serde(default)]
+    value: Option<serde_json::Value>,
+    #[serde(default)]
+    type_config: Option<SlimTypeConfig>,
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    date_created: Option<i64>,
+    #[serde(default)]
+    hide_from_guests: Option<bool>,
+    #[serde(default)]
+    required: Option<bool>,
+}
+
+#[derive(Deserialize)]
+struct SlimTypeConfig {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    options: Vec<SlimOption>,
+}
+
+#[derive(Deserialize)]
+struct SlimOption {
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+    #[serde(default)]
+    orderindex: Option<serde_json::Value>,
+    #[serde(default)]
+    name: Option<serde_json::Value>,
+}
+
+impl From<SlimCustomField> for CustomField {
+    fn from(field: SlimCustomField) -> Self {
+        use serde_json::{json, Map, Value};
+
+        let type_config = if field.type_field.as_deref() == Some("drop_down") {
+            let options: Vec<Value> = field
+                .type_config
+                .map(|config| config.options)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|option| {
+                    let kept: Map<String, Value> = [
+                        ("id", option.id),
+                        ("orderindex", option.orderindex),
+                        ("name", option.name),
+                    ]
+                    .into_iter()
+                    .filter_map(|(key, value)| Some((key.to_string(), value?)))
+                    .collect();
+                    Value::Object(kept)
+                })
+                .collect();
+            Some(json!({ "options": options }))
+        } else {
+            None
+        };
+        CustomField {
+            id: field.id,
+            name: field.name,
+            type_field: field.type_field,
+            value: field.value,
+            value_richtext: None,
+            type_config,
+            date_created: field.date_created,
+            hide_from_guests: field.hide_from_guests,
+            required: field.required,
+        }
+    }
+}
+
 /// Attachment
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Attachment {
@@ -428,6 +774,67 @@ pub struct TasksResponse {
     pub tasks: Vec<Task>,
 }
 
+/// Number of tasks ClickUp returns per page of a list
+pub const TASKS_PAGE_SIZE: usize = 100;
+
+/// Tasks collected across pages of a list
+#[derive(Debug, Clone, Default)]
+pub struct TaskPages {
+    pub tasks: Vec<Task>,
+    /// The page cap was reached while full pages were still coming back
+    pub truncated: bool,
+}
+
+impl TaskPages {
+    /// Whether `count` tasks read from at most `max_pages` pages may have
+    /// left some unread: paging stops at the first short page, so only when
+    /// every page came back full
+    pub fn cut_short(count: usize, max_pages: u32) -> bool {
+        count >= max_pages.max(1) as usize * TASKS_PAGE_SIZE
+    }
+}
+
+/// Number of task deletions sent to the API at once
+pub const BULK_DELETE_BATCH_SIZE: usize = 10;
+
+/// Number of single-task fetches sent to the API at once when loading tasks by ID
+pub const BATCH_GET_TASKS_SIZE: usize = 10;
+
+/// Outcome of deleting several tasks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BulkDeleteResult {
+    pub deleted: Vec<String>,
+    /// Task ID and error message for each deletion that failed
+    pub failed: Vec<(String, String)>,
+}
+
+/// Number of task archive updates sent to the API at once
+pub const BULK_ARCHIVE_BATCH_SIZE: usize = 10;
+
+/// Outcome of archiving or unarchiving several tasks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BulkArchiveResult {
+    /// Whether the tasks were archived or unarchived
+    pub archived: bool,
+    pub updated: Vec<String>,
+    /// Task ID and error message for each update that failed
+    pub failed: Vec<(String, String)>,
+}
+
+impl BulkArchiveResult {
+    /// Apply the updated tasks' new archived flag to a loaded task list
+    ///
+    /// Archived tasks are dropped unless archived tasks are being shown.
+    pub fn apply_to(&self, tasks: &mut Vec<Task>, show_archived: bool) {
+        for task in tasks.iter_mut().filter(|t| self.updated.contains(&t.id)) {
+            task.archived = Some(self.archived);
+        }
+        if !show_archived {
+            tasks.retain(|t| t.archived != Some(true));
+        }
+    }
+}
+
 /// Parameters for filtering tasks
 #[derive(Debug, Clone, Default)]
 pub struct TaskFilters {
@@ -446,6 +853,8 @@ pub struct TaskFilters {
     pub due_date_lt: Option<i64>,
     /// Include closed tasks in results
     pub include_closed: Option<bool>,
+    /// Parse the tasks as [`SlimTask`]s; not sent to the API
+    pub slim: bool,
 }
 
 impl TaskFilters {
@@ -475,6 +884,66 @@ impl TaskFilters {
     }
 }
 
+/// How the task list is ordered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskSortMode {
+    /// Status group, then most recently updated
+    #[default]
+    Status,
+    /// Soonest due date first
+    DueDate,
+    /// Newest first
+    Created,
+    /// Most recently updated first
+    Updated,
+    /// Most urgent first (the API cannot order by priority)
+    Priority,
+}
+
+impl TaskSortMode {
+    /// Cycle to the next mode
+    pub fn next(self) -> Self {
+        match self {
+            Self::Status => Self::DueDate,
+            Self::DueDate => Self::Created,
+            Self::Created => Self::Updated,
+            Self::Updated => Self::Priority,
+            Self::Priority => Self::Status,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::DueDate => "due date",
+            Self::Created => "created",
+            Self::Updated => "updated",
+            Self::Priority => "priority",
+        }
+    }
+
+    /// The API's `order_by` value and `reverse` flag, or None for modes
+    /// sorted client-side
+    ///
+    /// The API orders descending by default, so soonest-due-first is reversed.
+    pub fn server_order(self) -> Option<(&'static str, bool)> {
+        match self {
+            Self::DueDate => Some(("due_date", true)),
+            Self::Created => Some(("created", false)),
+            Self::Updated => Some(("updated", false)),
+            Self::Status | Self::Priority => None,
+        }
+    }
+
+    /// Ask the API for this mode's ordering, if it supports it
+    pub fn apply(self, filters: &mut TaskFilters) {
+        if let Some((order_by, reverse)) = self.server_order() {
+            filters.order_by = Some(order_by.to_string());
+            filters.reverse = Some(reverse);
+        }
+    }
+}
+
 /// Request body for creating a task
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateTaskRequest {
@@ -491,6 +960,47 @@ pub struct CreateTaskRequest {
     pub due_date: Option<i64>,
 }
 
+/// Maximum length (in characters) of a task title derived from a comment
+const COMMENT_TASK_TITLE_MAX: usize = 60;
+
+impl CreateTaskRequest {
+    /// Build a task request from a comment
+    ///
+    /// The comment's first line becomes the title (truncated with an ellipsis),
+    /// and the full comment text becomes the description. When `source_url` is
+    /// given, a link back to the originating task is appended.
+    pub fn from_comment(comment: &crate::models::Comment, source_url: Option<&str>) -> Self {
+        let text = comment.text.trim();
+        let first_line = text.lines().next().unwrap_or("").trim();
+
+        let name = if first_line.is_empty() {
+            "Task from comment".to_string()
+        } else if first_line.chars().count() > COMMENT_TASK_TITLE_MAX {
+            let truncated: String = first_line
+                .chars()
+                .take(COMMENT_TASK_TITLE_MAX - 1)
+                .collect();
+            format!("{}…", truncated.trim_end())
+        } else {
+            first_line.to_string()
+        };
+
+        let mut description = text.to_string();
+        if let Some(url) = source_url {
+            description.push_str(&format!("\n\nCreated from a comment on {}", url));
+        }
+
+        Self {
+            name,
+            description: Some(description),
+            status: None,
+            priority: None,
+            assignees: None,
+            due_date: None,
+        }
+    }
+}
+
 /// Request body for updating assignees
 /// ClickUp API expects: {"add": [ids], "rem": [ids]}
 #[derive(Debug, Clone, Serialize)]
@@ -532,6 +1042,23 @@ pub struct UpdateTaskRequest {
     pub assignees: Option<AssigneesUpdate>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+}
+
+impl UpdateTaskRequest {
+    /// An update that only archives or unarchives the task
+    pub fn set_archived(archived: bool) -> Self {
+        Self {
+            name: None,
+            description: None,
+            status: None,
+            priority: None,
+            assignees: None,
+            due_date: None,
+            archived: Some(archived),
+        }
+    }
 }
 
 /// Status group priority for sorting
@@ -555,6 +1082,18 @@ pub fn get_status_group_priority(status_group: &str) -> StatusGroupPriority {
     }
 }
 
+/// Whether `text` looks like a custom task ID such as `DEV-42`: capital
+/// letters, a dash, then digits
+pub fn is_custom_task_id(text: &str) -> bool {
+    let Some((prefix, number)) = text.split_once('-') else {
+        return false;
+    };
+    !prefix.is_empty()
+        && prefix.bytes().all(|b| b.is_ascii_uppercase())
+        && !number.is_empty()
+        && number.bytes().all(|b| b.is_ascii_digit())
+}
+
 /// Resolve the canonical status group name from a `TaskStatus`.
 ///
 /// Resolution order:
@@ -623,10 +1162,93 @@ pub fn sort_tasks(mut tasks: Vec<Task>) -> Vec<Task> {
     tasks
 }
 
+/// Rank of a priority name, most urgent first; unset priorities come last
+fn priority_rank(priority: &Option<Priority>) -> u8 {
+    match priority.as_ref().map(|p| p.priority.as_str()) {
+        Some("urgent") => 0,
+        Some("high") => 1,
+        Some("normal") => 2,
+        Some("low") => 3,
+        _ => 4,
+    }
+}
+
+/// Sort tasks for a sort mode, keeping status groups in priority order
+///
+/// Server-ordered modes are sorted here too, so results fetched without
+/// `order_by` (e.g. the assigned filter) still come out right. The sort is
+/// stable, so ties keep the server's order.
+pub fn sort_tasks_by(mut tasks: Vec<Task>, mode: TaskSortMode) -> Vec<Task> {
+    use std::cmp::Reverse;
+    let group = |task: &Task| get_task_sort_key(task).0;
+    match mode {
+        TaskSortMode::Status => return sort_tasks(tasks),
+        // Undated tasks last
+        TaskSortMode::DueDate => {
+            tasks.sort_by_key(|t| (group(t), t.due_date.is_none(), t.due_date))
+        }
+        TaskSortMode::Created => tasks.sort_by_key(|t| (group(t), Reverse(t.created_at))),
+        TaskSortMode::Updated => tasks.sort_by_key(|t| (group(t), Reverse(t.updated_at))),
+        TaskSortMode::Priority => tasks.sort_by_key(|t| {
+            (group(t), priority_rank(&t.priority), Reverse(t.updated_at))
+        }),
+    }
+    tasks
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn workflow() -> Vec<TaskStatus> {
+        serde_json::from_str(
+            r##"[{"id": "s1", "status": "to do", "type": "open", "color": "#d3d3d3", "orderindex": 0},
+                {"id": "s2", "status": "in progress", "type": "custom", "color": "#4194f6", "orderindex": 1},
+                {"id": "s3", "status": "complete", "type": "closed", "color": "#6bc950", "orderindex": 2}]"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_is_custom_task_id() {
+        assert!(is_custom_task_id("DEV-42"));
+        assert!(is_custom_task_id("PROJ-1"));
+        for text in ["dev-42", "DEV-", "-42", "DEV42", "DEV-4a", "86b2xyz", "DEV-42-1"] {
+            assert!(!is_custom_task_id(text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_next_status_steps_and_wraps() {
+        let statuses = workflow();
+        let next = |current| next_status(&statuses, current).map(|s| s.status.as_str());
+        assert_eq!(next("to do"), Some("in progress"));
+        assert_eq!(next("In Progress"), Some("complete"));
+        assert_eq!(next("complete"), Some("to do"));
+        assert_eq!(next("unknown"), Some("to do"));
+        assert_eq!(next_status(&statuses, "to do").unwrap().color.as_deref(), Some("#4194f6"));
+    }
+
+    #[test]
+    fn test_previous_status_steps_and_wraps() {
+        let statuses = workflow();
+        let previous = |current| previous_status(&statuses, current).map(|s| s.status.as_str());
+        assert_eq!(previous("complete"), Some("in progress"));
+        assert_eq!(previous("to do"), Some("complete"));
+        assert_eq!(previous("unknown"), Some("complete"));
+    }
+
+    #[test]
+    fn test_status_cycling_edge_cases() {
+        assert_eq!(next_status(&[], "to do"), None);
+        assert_eq!(previous_status(&[], "to do"), None);
+
+        // A single status wraps onto itself
+        let only = &workflow()[..1];
+        assert_eq!(next_status(only, "to do").unwrap().status, "to do");
+        assert_eq!(previous_status(only, "to do").unwrap().status, "to do");
+    }
+
     fn create_task_with_status_and_updated_at(
         id: &str,
         status_group: Option<&str>,
@@ -666,6 +1288,7 @@ mod tests {
             priority: None,
             due_date: None,
             start_date: None,
+            recurrence: None,
             points: None,
             custom_fields: vec![],
             attachments: vec![],
@@ -685,6 +1308,168 @@ mod tests {
         }
     }
 
+    fn comment_with_text(text: &str) -> crate::models::Comment {
+        crate::models::Comment {
+            id: "c1".to_string(),
+            text: text.to_string(),
+            text_preview: String::new(),
+            commenter: None,
+            created_at: None,
+            updated_at: None,
+            assigned_commenter: None,
+            assigned_by: None,
+            assigned: false,
+            reaction: String::new(),
+            parent_id: None,
+            reply_count: None,
+        }
+    }
+
+    #[test]
+    fn test_description_source_prefers_markdown() {
+        let mut task = Task {
+            description: Some(TaskDescription::Plain("plain text".to_string())),
+            ..Task::default()
+        };
+        assert_eq!(task.description_source().as_deref(), Some("plain text"));
+
+        task.markdown_description = Some(String::new());
+        assert_eq!(task.description_source().as_deref(), Some("plain text"));
+
+        task.markdown_description = Some("**bold**".to_string());
+        assert_eq!(task.description_source().as_deref(), Some("**bold**"));
+
+        assert_eq!(Task::default().description_source(), None);
+    }
+
+    #[test]
+    fn test_capabilities_from_permission_level_and_access() {
+        let task: Task = serde_json::from_value(serde_json::json!({
+            "id": "t1",
+            "name": "Task",
+            "permission_level": "comment",
+            "list": {"id": "l1", "access": false}
+        }))
+        .unwrap();
+        let caps = task.capabilities();
+        assert!(caps.comment);
+        assert!(!caps.edit);
+
+        // Without a level, no access to the list means read-only
+        let task = Task {
+            permission_level: None,
+            ..task
+        };
+        assert!(task.capabilities().is_read_only());
+
+        assert_eq!(Task::default().capabilities(), Capabilities::FULL);
+    }
+
+    #[test]
+    fn test_slim_keeps_only_rendered_custom_field_data() {
+        let mut task: Task = serde_json::from_value(slim_fixture()).unwrap();
+        task.slim();
+        assert_slim(&task);
+    }
+
+    fn slim_fixture() -> serde_json::Value {
+        serde_json::json!({
+            "id": "t1",
+            "name": "Task",
+            "custom_fields": [
+                {
+                    "id": "cf1",
+                    "name": "Notes",
+                    "type": "text",
+                    "value": "short",
+                    "value_richtext": {"ops": [{"insert": "huge"}]},
+                    "type_config": {"default": "x".repeat(100)}
+                },
+                {
+                    "id": "cf2",
+                    "name": "Stage",
+                    "type": "drop_down",
+                    "value": 1,
+                    "type_config": {"options": [
+                        {"id": "a", "orderindex": 0, "name": "Draft", "color": "#fff"},
+                        {"id": "b", "orderindex": 1, "name": "Final", "label": "x"}
+                    ], "sorting": "manual"}
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_slim_task_parses_like_a_slimmed_task() {
+        let SlimTask(task) = serde_json::from_value(slim_fixture()).unwrap();
+        assert_eq!(task.id, "t1");
+        assert_slim(&task);
+    }
+
+    fn assert_slim(task: &Task) {
+        let notes = &task.custom_fields[0];
+        assert_eq!(notes.value, Some(serde_json::json!("short")));
+        assert!(notes.value_richtext.is_none());
+        assert!(notes.type_config.is_none());
+        assert_eq!(
+            task.custom_fields[1].type_config,
+            Some(serde_json::json!({"options": [
+                {"id": "a", "orderindex": 0, "name": "Draft"},
+                {"id": "b", "orderindex": 1, "name": "Final"}
+            ]}))
+        );
+    }
+
+    #[test]
+    fn test_create_task_request_from_comment() {
+        let comment = comment_with_text("Fix the login redirect\nIt loops on Safari.");
+
+        let request =
+            CreateTaskRequest::from_comment(&comment, Some("https://app.clickup.com/t/abc"));
+
+        assert_eq!(request.name, "Fix the login redirect");
+        assert_eq!(
+            request.description.as_deref(),
+            Some(
+                "Fix the login redirect\nIt loops on Safari.\n\n\
+                 Created from a comment on https://app.clickup.com/t/abc"
+            )
+        );
+        assert!(request.status.is_none());
+        assert!(request.assignees.is_none());
+    }
+
+    #[test]
+    fn test_create_task_request_from_comment_truncates_title() {
+        let long_line = "word ".repeat(30);
+        let comment = comment_with_text(&long_line);
+
+        let request = CreateTaskRequest::from_comment(&comment, None);
+
+        assert!(request.name.chars().count() <= COMMENT_TASK_TITLE_MAX);
+        assert!(request.name.ends_with('…'));
+        assert_eq!(request.description.as_deref(), Some(long_line.trim()));
+    }
+
+    #[test]
+    fn test_create_task_request_from_empty_comment() {
+        let request = CreateTaskRequest::from_comment(&comment_with_text("   "), None);
+        assert_eq!(request.name, "Task from comment");
+    }
+
+    #[test]
+    fn test_recurrence_label() {
+        let recurrence = |type_field: &str, period, end_count| RecurrenceConfig {
+            type_field: type_field.to_string(),
+            period,
+            end_count,
+        };
+
+        assert_eq!(recurrence("weekly", 1, None).label(), "weekly");
+        assert_eq!(recurrence("weekly", 2, None).label(), "every 2 weeks");
+        assert_eq!(recurrence("daily", 3, Some(5)).label(), "every 3 days, 5 times");
+    }
+
     #[test]
     fn test_status_group_priority_mapping() {
         assert_eq!(
@@ -860,8 +1645,10 @@ mod tests {
     #[test]
     fn test_task_filters_assignees_format() {
         // Test that assignees are formatted as array values (assignees[]=123&assignees[]=456)
-        let mut filters = TaskFilters::default();
-        filters.assignees = vec![123, 456, 789];
+        let filters = TaskFilters {
+            assignees: vec![123, 456, 789],
+            ..Default::default()
+        };
 
         let query = filters.to_query_string();
 
@@ -874,8 +1661,10 @@ mod tests {
 
     #[test]
     fn test_task_filters_single_assignee() {
-        let mut filters = TaskFilters::default();
-        filters.assignees = vec![123];
+        let filters = TaskFilters {
+            assignees: vec![123],
+            ..Default::default()
+        };
 
         let query = filters.to_query_string();
 
@@ -893,9 +1682,11 @@ mod tests {
 
     #[test]
     fn test_task_filters_with_limit() {
-        let mut filters = TaskFilters::default();
-        filters.assignees = vec![123];
-        filters.limit = Some(100);
+        let filters = TaskFilters {
+            assignees: vec![123],
+            limit: Some(100),
+            ..Default::default()
+        };
 
         let query = filters.to_query_string();
 
@@ -904,12 +1695,58 @@ mod tests {
         assert!(query.contains("limit=100"));
     }
 
+    #[test]
+    fn test_set_archived_request_only_sends_the_flag() {
+        let json = serde_json::to_value(UpdateTaskRequest::set_archived(true)).unwrap();
+        assert_eq!(json, serde_json::json!({ "archived": true }));
+
+        let json = serde_json::to_value(UpdateTaskRequest::set_archived(false)).unwrap();
+        assert_eq!(json, serde_json::json!({ "archived": false }));
+    }
+
+    #[test]
+    fn test_bulk_archive_result_hides_archived_tasks() {
+        let task = |id: &str, archived: Option<bool>| Task {
+            archived,
+            ..create_task_with_status_and_updated_at(id, None, None)
+        };
+        let loaded = vec![task("t1", None), task("t2", None), task("t3", Some(false))];
+        let result = BulkArchiveResult {
+            archived: true,
+            updated: vec!["t1".to_string(), "t3".to_string()],
+            failed: vec![("t2".to_string(), "API error (500)".to_string())],
+        };
+
+        let mut tasks = loaded.clone();
+        result.apply_to(&mut tasks, false);
+        let ids: Vec<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["t2"], "Only the failed task stays");
+
+        let mut tasks = loaded;
+        result.apply_to(&mut tasks, true);
+        assert_eq!(tasks.len(), 3, "Shown archived tasks stay in the list");
+        assert_eq!(tasks[0].archived, Some(true));
+        assert_eq!(tasks[1].archived, None);
+
+        // Unarchiving keeps the tasks either way
+        let unarchive = BulkArchiveResult {
+            archived: false,
+            updated: vec!["t1".to_string()],
+            failed: Vec::new(),
+        };
+        unarchive.apply_to(&mut tasks, false);
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].archived, Some(false));
+    }
+
     #[test]
     fn test_task_filters_mixed_with_assignees() {
-        let mut filters = TaskFilters::default();
-        filters.archived = Some(false);
-        filters.assignees = vec![123, 456];
-        filters.statuses = vec!["todo".to_string(), "in progress".to_string()];
+        let filters = TaskFilters {
+            archived: Some(false),
+            assignees: vec![123, 456],
+            statuses: vec!["todo".to_string(), "in progress".to_string()],
+            ..Default::default()
+        };
 
         let query = filters.to_query_string();
 
@@ -922,9 +1759,11 @@ mod tests {
 
     #[test]
     fn test_task_filters_assigned_with_include_closed() {
-        let mut filters = TaskFilters::default();
-        filters.assignees = vec![42];
-        filters.include_closed = Some(true);
+        let filters = TaskFilters {
+            assignees: vec![42],
+            include_closed: Some(true),
+            ..Default::default()
+        };
 
         let query = filters.to_query_string();
 
@@ -939,4 +1778,106 @@ mod tests {
             query
         );
     }
+
+    #[test]
+    fn test_sort_mode_sets_server_order() {
+        let mut filters = TaskFilters::default();
+        TaskSortMode::DueDate.apply(&mut filters);
+        let query = filters.to_query_string();
+        assert!(query.contains("order_by=due_date"), "{}", query);
+        assert!(query.contains("reverse=true"), "{}", query);
+
+        let mut filters = TaskFilters::default();
+        TaskSortMode::Updated.apply(&mut filters);
+        let query = filters.to_query_string();
+        assert!(query.contains("order_by=updated"), "{}", query);
+        assert!(query.contains("reverse=false"), "{}", query);
+    }
+
+    #[test]
+    fn test_client_sorted_modes_leave_filters_alone() {
+        for mode in [TaskSortMode::Status, TaskSortMode::Priority] {
+            let mut filters = TaskFilters::default();
+            mode.apply(&mut filters);
+            let query = filters.to_query_string();
+            assert!(!query.contains("order_by"), "{:?}: {}", mode, query);
+            assert!(!query.contains("reverse"), "{:?}: {}", mode, query);
+        }
+    }
+
+    #[test]
+    fn test_sort_tasks_by_due_date_within_groups() {
+        let with_due = |id: &str, group: &str, due_date: Option<i64>| Task {
+            due_date,
+            ..create_task_with_status_and_updated_at(id, Some(group), Some(1000))
+        };
+        let tasks = vec![
+            with_due("todo-undated", "todo", None),
+            with_due("wip-late", "in_progress", Some(3000)),
+            with_due("todo-soon", "todo", Some(1000)),
+            with_due("wip-soon", "in_progress", Some(2000)),
+            // Same due date as todo-soon: keeps the server's order
+            with_due("todo-soon-2", "todo", Some(1000)),
+        ];
+
+        let sorted = sort_tasks_by(tasks, TaskSortMode::DueDate);
+        let ids: Vec<&str> = sorted.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["wip-soon", "wip-late", "todo-soon", "todo-soon-2", "todo-undated"]
+        );
+    }
+
+    #[test]
+    fn test_sort_tasks_by_priority_within_groups() {
+        let with_priority = |id: &str, priority: Option<&str>| Task {
+            priority: priority.map(|p| Priority {
+                priority: p.to_string(),
+                color: None,
+            }),
+            ..create_task_with_status_and_updated_at(id, Some("todo"), Some(1000))
+        };
+        let tasks = vec![
+            with_priority("none", None),
+            with_priority("low", Some("low")),
+            with_priority("urgent", Some("urgent")),
+            with_priority("high", Some("high")),
+        ];
+
+        let sorted = sort_tasks_by(tasks, TaskSortMode::Priority);
+        let ids: Vec<&str> = sorted.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["urgent", "high", "low", "none"]);
+    }
+
+    #[test]
+    fn test_related_tasks_read_dependencies_from_either_side() {
+        let json = r#"{
+            "id": "t1",
+            "name": "Task",
+            "dependencies": [
+                {"task_id": "t1", "depends_on": "t2", "type": 1},
+                {"task_id": "t3", "depends_on": "t1", "type": 0}
+            ],
+            "linked_tasks": [
+                {"task_id": "t1", "link_id": "t4"},
+                {"task_id": "t5", "link_id": "t1"}
+            ]
+        }"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+
+        let related: Vec<(String, LinkKind)> = task
+            .related_tasks()
+            .into_iter()
+            .map(|r| (r.task_id, r.kind))
+            .collect();
+        assert_eq!(
+            related,
+            vec![
+                ("t2".to_string(), LinkKind::WaitingOn),
+                ("t3".to_string(), LinkKind::Blocking),
+                ("t4".to_string(), LinkKind::Related),
+                ("t5".to_string(), LinkKind::Related),
+            ]
+        );
+    }
 }
@@ -0,0 +1,297 @@
+//! Time tracking models
+//!
+//! Workspace time entries come from `/team/{id}/time_entries`; a task's tracked
+//! time comes from the older `/task/{id}/time` endpoint, which groups intervals
+//! by user. Both are normalised into [`TimeEntry`].
+
+use crate::models::User;
+use crate::utils::deserializers::{
+    flexible_i64, flexible_string, flexible_timestamp, null_to_empty_vec,
+};
+use chrono::{NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Task a time entry was logged against
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeEntryTask {
+    #[serde(default, deserialize_with = "flexible_string")]
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// A block of logged time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeEntry {
+    #[serde(default, deserialize_with = "flexible_string")]
+    pub id: String,
+    #[serde(default)]
+    pub task: Option<TimeEntryTask>,
+    #[serde(default)]
+    pub user: Option<User>,
+    /// Start (ms since epoch)
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub start: Option<i64>,
+    /// End (ms since epoch); missing while the timer is running
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub end: Option<i64>,
+    /// Duration in ms; ClickUp reports a negative value while the timer is running
+    #[serde(default, deserialize_with = "flexible_i64")]
+    pub duration: Option<i64>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl TimeEntry {
+    /// Logged duration in ms, or None while the timer is still running
+    pub fn logged_ms(&self) -> Option<i64> {
+        match self.duration {
+            Some(ms) if ms >= 0 => Some(ms),
+            Some(_) => None,
+            None => match (self.start, self.end) {
+                (Some(start), Some(end)) => Some((end - start).max(0)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Display name of the user who logged the entry
+    pub fn user_name(&self) -> &str {
+        self.user
+            .as_ref()
+            .map(|u| u.username.as_str())
+            .unwrap_or("Unknown")
+    }
+}
+
+/// Response of `/team/{team_id}/time_entries`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeEntriesResponse {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub data: Vec<TimeEntry>,
+}
+
+/// One interval of a task's tracked time
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrackedInterval {
+    #[serde(default, deserialize_with = "flexible_string")]
+    pub id: String,
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub start: Option<i64>,
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub end: Option<i64>,
+    #[serde(default, deserialize_with = "flexible_i64")]
+    pub time: Option<i64>,
+}
+
+/// A user's tracked time on a task
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrackedTime {
+    #[serde(default)]
+    pub user: Option<User>,
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub intervals: Vec<TrackedInterval>,
+}
+
+/// Response of `/task/{task_id}/time`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaskTimeResponse {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub data: Vec<TrackedTime>,
+}
+
+impl TaskTimeResponse {
+    /// Flatten the per-user intervals into entries for `task_id`, oldest first
+    pub fn into_entries(self, task_id: &str) -> Vec<TimeEntry> {
+        let mut entries: Vec<TimeEntry> = self
+            .data
+            .into_iter()
+            .flat_map(|tracked| {
+                let user = tracked.user;
+                tracked
+                    .intervals
+                    .into_iter()
+                    .map(move |interval| TimeEntry {
+                        id: interval.id,
+                        task: Some(TimeEntryTask {
+                            id: task_id.to_string(),
+                            name: None,
+                        }),
+                        user: user.clone(),
+                        start: interval.start,
+                        end: interval.end,
+                        duration: interval.time,
+                        description: None,
+                    })
+            })
+            .collect();
+        entries.sort_by_key(|e| e.start.unwrap_or(0));
+        entries
+    }
+}
+
+/// Time logged against one task on one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TaskTotal {
+    pub task_id: String,
+    pub task_name: String,
+    pub total_ms: i64,
+}
+
+/// Time logged on one day
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DayTotal {
+    pub date: NaiveDate,
+    pub total_ms: i64,
+    /// Per-task totals, largest first
+    pub tasks: Vec<TaskTotal>,
+}
+
+/// Format a duration in ms as e.g. "2h 05m" or "45m"
+pub fn format_duration(ms: i64) -> String {
+    let minutes = ms.max(0) / 60_000;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Sum entries per day (by start time in `tz`) and per task, oldest day first.
+///
+/// Running entries and entries without a start are skipped.
+pub fn aggregate_by_day<Tz: TimeZone>(entries: &[TimeEntry], tz: &Tz) -> Vec<DayTotal> {
+    let mut days: Vec<DayTotal> = Vec::new();
+    for entry in entries {
+        let (Some(start), Some(ms)) = (entry.start, entry.logged_ms()) else {
+            continue;
+        };
+        let Some(date) = tz
+            .timestamp_millis_opt(start)
+            .single()
+            .map(|dt| dt.date_naive())
+        else {
+            continue;
+        };
+        let (task_id, task_name) = match &entry.task {
+            Some(task) => (
+                task.id.clone(),
+                task.name.clone().unwrap_or_else(|| task.id.clone()),
+            ),
+            None => (String::new(), "(no task)".to_string()),
+        };
+
+        let day = match days.iter_mut().position(|d| d.date == date) {
+            Some(i) => &mut days[i],
+            None => {
+                days.push(DayTotal {
+                    date,
+                    total_ms: 0,
+                    tasks: Vec::new(),
+                });
+                days.last_mut().unwrap()
+            }
+        };
+        day.total_ms += ms;
+        match day.tasks.iter_mut().find(|t| t.task_id == task_id) {
+            Some(task) => task.total_ms += ms,
+            None => day.tasks.push(TaskTotal {
+                task_id,
+                task_name,
+                total_ms: ms,
+            }),
+        }
+    }
+
+    days.sort_by_key(|d| d.date);
+    for day in &mut days {
+        day.tasks.sort_by_key(|t| std::cmp::Reverse(t.total_ms));
+    }
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    const HOUR_MS: i64 = 60 * 60 * 1000;
+    /// 2024-03-04 00:00:00 UTC
+    const MONDAY: i64 = 1_709_510_400_000;
+
+    fn entry(task_id: &str, start: i64, duration: i64) -> TimeEntry {
+        TimeEntry {
+            id: format!("{}-{}", task_id, start),
+            task: Some(TimeEntryTask {
+                id: task_id.to_string(),
+                name: Some(format!("Task {}", task_id)),
+            }),
+            user: None,
+            start: Some(start),
+            end: Some(start + duration),
+            duration: Some(duration),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(0), "0m");
+        assert_eq!(format_duration(45 * 60_000), "45m");
+        assert_eq!(format_duration(2 * HOUR_MS + 5 * 60_000), "2h 05m");
+        assert_eq!(format_duration(59_999), "0m");
+        assert_eq!(format_duration(-HOUR_MS), "0m");
+    }
+
+    #[test]
+    fn test_aggregate_by_day_sums_per_day_and_task() {
+        let entries = vec![
+            entry("a", MONDAY + 9 * HOUR_MS, HOUR_MS),
+            entry("b", MONDAY + 24 * HOUR_MS + 9 * HOUR_MS, 30 * 60_000),
+            entry("b", MONDAY + 10 * HOUR_MS, 2 * HOUR_MS),
+            entry("a", MONDAY + 14 * HOUR_MS, HOUR_MS / 2),
+        ];
+
+        let days = aggregate_by_day(&entries, &Utc);
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].date, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+        assert_eq!(days[0].total_ms, 3 * HOUR_MS + HOUR_MS / 2);
+        assert_eq!(days[0].tasks[0].task_id, "b");
+        assert_eq!(days[0].tasks[0].total_ms, 2 * HOUR_MS);
+        assert_eq!(days[0].tasks[1].total_ms, HOUR_MS + HOUR_MS / 2);
+        assert_eq!(days[1].date, NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+        assert_eq!(days[1].total_ms, 30 * 60_000);
+    }
+
+    #[test]
+    fn test_aggregate_by_day_skips_running_entries() {
+        let mut running = entry("a", MONDAY, 0);
+        running.duration = Some(-MONDAY);
+        running.end = None;
+
+        assert!(aggregate_by_day(&[running], &Utc).is_empty());
+    }
+
+    #[test]
+    fn test_task_time_response_flattens_intervals() {
+        let json = r#"{"data": [{
+            "user": {"id": 1, "username": "alice"},
+            "time": 5400000,
+            "intervals": [
+                {"id": "i2", "start": "1709550000000", "end": "1709551800000", "time": "1800000"},
+                {"id": "i1", "start": "1709542800000", "end": "1709546400000", "time": "3600000"}
+            ]
+        }]}"#;
+
+        let response: TaskTimeResponse = serde_json::from_str(json).unwrap();
+        let entries = response.into_entries("task-1");
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "i1");
+        assert_eq!(entries[0].user_name(), "alice");
+        assert_eq!(entries[0].logged_ms(), Some(3_600_000));
+        assert_eq!(entries[1].task.as_ref().unwrap().id, "task-1");
+    }
+}
@@ -0,0 +1,147 @@
+//! Time a task spent in each status
+//!
+//! Comes from `/task/{id}/time_in_status`, which reports minutes per status
+//! in `status_history`, plus the status the task is in now.
+
+use crate::models::TaskStatus;
+use crate::utils::deserializers::{flexible_i64, null_to_empty_vec};
+use serde::{Deserialize, Serialize};
+
+/// How long a task spent in one status
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeInStatus {
+    pub status: TaskStatus,
+    pub duration_ms: i64,
+    /// Position of the status in its list's workflow; the position in the
+    /// task's history when ClickUp doesn't say
+    pub order_index: u32,
+}
+
+/// Minutes spent in a status
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StatusTotalTime {
+    #[serde(default, deserialize_with = "flexible_i64")]
+    pub by_minute: Option<i64>,
+}
+
+/// One status in a task's history
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatusHistoryEntry {
+    #[serde(flatten)]
+    pub status: TaskStatus,
+    #[serde(default)]
+    pub total_time: StatusTotalTime,
+}
+
+/// Response of `/task/{task_id}/time_in_status`
+#[derive(Debug, Clone, Deserialize)]
+pub struct TimeInStatusResponse {
+    #[serde(default)]
+    pub current_status: Option<StatusHistoryEntry>,
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub status_history: Vec<StatusHistoryEntry>,
+}
+
+impl TimeInStatusResponse {
+    /// Durations per status in workflow order; just the current status when
+    /// the task has no history yet
+    pub fn into_durations(self) -> Vec<TimeInStatus> {
+        let history = if self.status_history.is_empty() {
+            self.current_status.into_iter().collect()
+        } else {
+            self.status_history
+        };
+        let mut durations: Vec<TimeInStatus> = history
+            .into_iter()
+            .enumerate()
+            .map(|(position, entry)| TimeInStatus {
+                order_index: entry.status.orderindex.unwrap_or(position as u32),
+                duration_ms: entry.total_time.by_minute.unwrap_or(0).max(0) * 60_000,
+                status: entry.status,
+            })
+            .collect();
+        durations.sort_by_key(|d| d.order_index);
+        durations
+    }
+}
+
+/// Format a status duration as e.g. "3d 2h", "5h 10m" or "45m"
+pub fn format_status_duration(ms: i64) -> String {
+    let minutes = ms.max(0) / 60_000;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_durations_from_status_history() {
+        let json = r##"{
+            "current_status": {
+                "status": "in progress",
+                "color": "#4194f6",
+                "total_time": {"by_minute": 300, "since": "1604004423000"}
+            },
+            "status_history": [
+                {
+                    "status": "in progress",
+                    "color": "#4194f6",
+                    "type": "custom",
+                    "orderindex": 1,
+                    "total_time": {"by_minute": "300", "since": "1604004423000"}
+                },
+                {
+                    "status": "open",
+                    "color": "#d3d3d3",
+                    "type": "open",
+                    "orderindex": 0,
+                    "total_time": {"by_minute": 4440, "since": "1604004401000"}
+                }
+            ]
+        }"##;
+
+        let response: TimeInStatusResponse = serde_json::from_str(json).unwrap();
+        let durations = response.into_durations();
+
+        assert_eq!(durations.len(), 2);
+        assert_eq!(durations[0].status.status, "open");
+        assert_eq!(durations[0].duration_ms, 4440 * 60_000);
+        assert_eq!(durations[1].status.status, "in progress");
+        assert_eq!(durations[1].duration_ms, 300 * 60_000);
+    }
+
+    #[test]
+    fn test_durations_fall_back_to_current_status() {
+        let json = r#"{
+            "current_status": {"status": "to do", "total_time": {"by_minute": 2}},
+            "status_history": null
+        }"#;
+
+        let response: TimeInStatusResponse = serde_json::from_str(json).unwrap();
+        let durations = response.into_durations();
+
+        assert_eq!(durations.len(), 1);
+        assert_eq!(durations[0].status.status, "to do");
+        assert_eq!(durations[0].duration_ms, 120_000);
+    }
+
+    #[test]
+    fn test_format_status_duration() {
+        assert_eq!(format_status_duration(0), "0m");
+        assert_eq!(format_status_duration(45 * 60_000), "45m");
+        assert_eq!(format_status_duration((5 * 60 + 10) * 60_000), "5h 10m");
+        assert_eq!(
+            format_status_duration((3 * 1440 + 2 * 60 + 59) * 60_000),
+            "3d 2h"
+        );
+        assert_eq!(format_status_duration(-5), "0m");
+    }
+}
@@ -2,7 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::utils::deserializers::{null_to_default_id, null_to_empty_string, null_to_empty_vec};
+use crate::utils::deserializers::{
+    flexible_i64, flexible_string, null_to_default_id, null_to_empty_string, null_to_empty_vec,
+};
 
 /// User reference (assignee, creator, etc.)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -21,6 +23,50 @@ pub struct User {
     pub initials: Option<String>,
 }
 
+/// User group (team) assigned to a task
+///
+/// Unlike [`User`], groups have a UUID string id and carry their members.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupAssignee {
+    #[serde(default, deserialize_with = "flexible_string")]
+    pub id: String,
+    #[serde(default, deserialize_with = "null_to_empty_string")]
+    pub name: String,
+    /// Mention handle, e.g. "design" for @design
+    #[serde(default)]
+    pub handle: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub initials: Option<String>,
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub members: Vec<User>,
+    /// Member count, when sent without the member list
+    #[serde(default, deserialize_with = "flexible_i64")]
+    pub member_count: Option<i64>,
+}
+
+impl GroupAssignee {
+    /// Number of members, preferring the listed members over the reported count
+    pub fn member_count(&self) -> usize {
+        if self.members.is_empty() {
+            self.member_count.unwrap_or(0).max(0) as usize
+        } else {
+            self.members.len()
+        }
+    }
+
+    /// Mention form of the group, e.g. "@design"
+    pub fn mention(&self) -> String {
+        let handle = self
+            .handle
+            .as_deref()
+            .filter(|h| !h.is_empty())
+            .unwrap_or(&self.name);
+        format!("@{}", handle)
+    }
+}
+
 /// API response for getting list/task members
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MembersResponse {
@@ -91,6 +137,25 @@ mod tests {
         assert_eq!(user.initials, None);
     }
 
+    #[test]
+    fn test_group_assignee_member_count_and_mention() {
+        let json = r#"{
+            "id": "3f1c2a7e-8b1d-4c4e-9a0f-2d6b5e7c9a10",
+            "name": "Design Team",
+            "handle": "design",
+            "members": [{"id": 1, "username": "alice"}, {"id": 2, "username": "bob"}]
+        }"#;
+        let group: GroupAssignee = serde_json::from_str(json).unwrap();
+        assert_eq!(group.member_count(), 2);
+        assert_eq!(group.mention(), "@design");
+
+        let json =
+            r#"{"id": "abc", "name": "Ops", "handle": null, "members": null, "member_count": "7"}"#;
+        let group: GroupAssignee = serde_json::from_str(json).unwrap();
+        assert_eq!(group.member_count(), 7);
+        assert_eq!(group.mention(), "@Ops");
+    }
+
     #[test]
     fn test_members_response_deserialize() {
         let json = "{
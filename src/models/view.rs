@@ -0,0 +1,114 @@
+//! View models
+
+use crate::models::task::Task;
+use crate::models::user::User;
+use crate::utils::deserializers::{flexible_i64, flexible_string, null_to_empty_string, null_to_empty_vec};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A ClickUp View (list, board, sprint, ...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct View {
+    pub id: String,
+    pub name: String,
+    #[serde(default, rename = "type")]
+    pub view_type: Option<String>,
+    /// Location the view is saved on (a sprint view's parent is its sprint list)
+    #[serde(default)]
+    pub parent: Option<ViewParent>,
+}
+
+/// `type` of a [`ViewParent`] that is a list
+pub const VIEW_PARENT_LIST: i64 = 6;
+
+/// Location a view is saved on
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewParent {
+    #[serde(deserialize_with = "flexible_string")]
+    pub id: String,
+    /// 7 workspace, 4 space, 5 folder, 6 list
+    #[serde(default, rename = "type", deserialize_with = "flexible_i64")]
+    pub parent_type: Option<i64>,
+}
+
+/// A view saved at the workspace level ("Everything"), across all spaces
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceView {
+    pub id: String,
+    pub name: String,
+    /// list, board, calendar, gantt, ...
+    #[serde(default, rename = "type", deserialize_with = "null_to_empty_string")]
+    pub view_type: String,
+    /// Sent as a bare user ID; only `id` is filled in then
+    #[serde(default = "unknown_user", deserialize_with = "user_or_id")]
+    pub creator: User,
+    /// public, private or personal
+    #[serde(default, deserialize_with = "null_to_empty_string")]
+    pub visibility: String,
+    /// Section names, in order
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub sections: Vec<String>,
+}
+
+/// Deserialize a user sent either in full or as its ID
+fn user_or_id<'de, D>(deserializer: D) -> Result<User, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum UserOrId {
+        Id(i64),
+        User(User),
+    }
+
+    let id = match Option::<UserOrId>::deserialize(deserializer)? {
+        Some(UserOrId::User(user)) => return Ok(user),
+        Some(UserOrId::Id(id)) => id,
+        None => 0,
+    };
+    Ok(User {
+        id,
+        ..unknown_user()
+    })
+}
+
+/// A user known by ID alone (0 when not even that is)
+fn unknown_user() -> User {
+    User {
+        id: 0,
+        username: String::new(),
+        color: None,
+        email: None,
+        profile_picture: None,
+        initials: None,
+    }
+}
+
+/// API response for getting a single view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewResponse {
+    pub view: View,
+}
+
+/// API response for getting the views saved on a list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListViewsResponse {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub views: Vec<View>,
+}
+
+/// API response for getting the views saved on a workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceViewsResponse {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub views: Vec<WorkspaceView>,
+}
+
+/// API response for getting the tasks shown in a view (paginated)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewTasksResponse {
+    #[serde(default, deserialize_with = "null_to_empty_vec")]
+    pub tasks: Vec<Task>,
+    #[serde(default)]
+    pub last_page: bool,
+}
@@ -1,7 +1,10 @@
 //! Workspace, Space, Folder, and List models
 
+use crate::models::Features;
 use crate::models::Priority;
+use crate::models::TaskStatus;
 use crate::models::User;
+use crate::utils::deserializers::{flexible_count, flexible_timestamp};
 use serde::{Deserialize, Serialize};
 
 /// A ClickUp Workspace (also called Team in the API)
@@ -13,7 +16,8 @@ pub struct Workspace {
     pub color: Option<String>,
     #[serde(default)]
     pub avatar: Option<Avatar>,
-    #[serde(default)]
+    /// Number of members; the API's team object sends the `members` list
+    #[serde(default, alias = "members", deserialize_with = "flexible_count")]
     pub member_count: Option<u32>,
 }
 
@@ -32,6 +36,21 @@ pub struct Space {
     pub folders: Vec<Folder>,
     #[serde(default)]
     pub lists: Vec<List>,
+    /// ClickApps turned on in the space, as far as the plan allows
+    #[serde(default)]
+    pub features: Features,
+}
+
+/// Request body for changing a space's settings; unset fields are left as they are
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UpdateSpaceRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Hex color, e.g. `#7b68ee`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
 }
 
 /// Status of a Space
@@ -57,6 +76,16 @@ pub struct Folder {
     pub space: Option<SpaceReference>,
     #[serde(default)]
     pub lists: Vec<List>,
+    /// Status workflow overriding the space's (empty when not overridden)
+    #[serde(default)]
+    pub statuses: Vec<TaskStatus>,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub archived: bool,
+    /// Number of tasks in the folder's lists; sent as a string
+    #[serde(default, deserialize_with = "flexible_count")]
+    pub task_count: Option<u32>,
 }
 
 /// Reference to a Space
@@ -89,6 +118,13 @@ pub struct List {
     pub status: Option<ListStatus>,
     #[serde(default)]
     pub priority: Option<Priority>,
+    #[serde(default, deserialize_with = "flexible_timestamp")]
+    pub due_date: Option<i64>,
+    #[serde(default, deserialize_with = "flexible_count")]
+    pub task_count: Option<u32>,
+    /// Status workflow, sent when the list is fetched on its own
+    #[serde(default)]
+    pub statuses: Vec<TaskStatus>,
 }
 
 /// Reference to a Folder
@@ -145,3 +181,37 @@ pub struct FoldersResponse {
 pub struct ListsResponse {
     pub lists: Vec<List>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_member_count_from_team_members() {
+        let workspace: Workspace = serde_json::from_str(
+            r##"{"id": "1", "name": "Acme", "color": "#7b68ee", "members": [{"user": {"id": 1}}, {"user": {"id": 2}}]}"##,
+        )
+        .unwrap();
+        assert_eq!(workspace.member_count, Some(2));
+
+        let workspace: Workspace =
+            serde_json::from_str(r#"{"id": "1", "name": "Acme", "members": null}"#).unwrap();
+        assert_eq!(workspace.member_count, None);
+    }
+
+    #[test]
+    fn test_list_statuses_parse_typed() {
+        let list: List = serde_json::from_str(
+            r##"{"id": "1", "name": "Sprint", "task_count": 4, "due_date": "1700000000000",
+                "statuses": [{"id": "p1_abc", "status": "open", "orderindex": 0, "type": "open", "color": "#d3d3d3"}]}"##,
+        )
+        .unwrap();
+        assert_eq!(list.task_count, Some(4));
+        assert_eq!(list.due_date, Some(1_700_000_000_000));
+        let status = &list.statuses[0];
+        assert_eq!(status.id.as_deref(), Some("p1_abc"));
+        assert_eq!(status.type_field.as_deref(), Some("open"));
+        assert_eq!(status.color.as_deref(), Some("#d3d3d3"));
+        assert_eq!(status.orderindex, Some(0));
+    }
+}
@@ -2,33 +2,134 @@
 
 use anyhow::Result;
 use arboard::Clipboard;
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{self, KeyCode, KeyModifiers};
 use ratatui::prelude::Rect;
 use ratatui::Frame;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
-use crate::api::{AuthManager, ClickUpApi, ClickUpClient};
+use crate::api::{AuthManager, CachingClient, ClickUpApi, ClickUpClient};
 use crate::cache::CacheManager;
-use crate::config::ConfigManager;
+use crate::cli::args::OpenTarget;
+use crate::config::{
+    AppConfig, CacheOverrides, CommentOrder, ConfigManager, StartScreen, TaskListDensity, DEFAULT_COMMENT_COLLAPSE_LINES, DEFAULT_STATUS_CLEAR_SECS,
+};
 use crate::models::{
-    ClickUpSpace, Comment, CreateCommentRequest, CreateTaskRequest, Document, Folder, List, SessionState, Task,
-    UpdateCommentRequest, User, Workspace,
+    BulkArchiveResult, BulkDeleteResult, Capabilities, ClickUpSpace, Comment, CommentsCursor, CreateCommentRequest, CreateTaskRequest, Document, Feature, Features, Folder, LinkKind, List, SessionState,
+    SprintOverview, Task, TaskFilters, TaskPages, TaskSortMode, TimeEntry, TimeInStatus, UpdateCommentRequest, User, View, Workspace, WorkspaceView, BULK_DELETE_BATCH_SIZE,
+    COMMENTS_PAGE_SIZE,
 };
+use crate::models::task::is_custom_task_id;
 use crate::tui::widgets::SidebarItem;
-use crate::utils::{ClickUpUrlGenerator, ClipboardService, UrlGenerator};
-
-use super::input::{is_quit, InputEvent};
-use super::layout::{generate_screen_title, split_task_detail, TuiLayout};
+use crate::utils::{format_age, truncate, ClickUpUrlGenerator, ClipboardService, UrlGenerator};
+
+use super::calendar::tasks_on_day;
+use super::export::task_to_text;
+use super::external_editor::{self, EditOutcome};
+use super::input::{is_quit, InputEvent, LineEditor};
+use super::reply_prefetch::ReplyPrefetch;
+use super::layout::{
+    generate_screen_title, split_task_detail, split_task_detail_columns, TuiLayout, SPINNER_FRAMES,
+};
 use super::terminal;
+use super::status::StatusClock;
+use super::type_ahead::{TypeAhead, TYPE_AHEAD_LEADER};
+use super::undo::{InverseCall, UndoAction, UndoStack};
+use super::widgets::auth::{
+    CLICKUP_API_SETTINGS_URL, STEP_READY, STEP_TOKEN, STEP_VERIFYING, STEP_WELCOME,
+};
+use super::widgets::calendar::day_descriptions;
+use super::widgets::comments::{comment_author, filter_comments};
+use super::widgets::error_detail::{self, render_error_detail};
 use super::widgets::{
-    get_dialog_hints, get_help_hints, render_assignee_picker, render_auth, render_comments,
-    render_dialog, render_document, render_help, render_sidebar, render_status_picker,
-    render_task_detail, render_task_list, AuthState, DialogState, DialogType, DocumentState,
-    GroupedTaskList, HelpContext, HelpState, ListRow, SidebarState, TaskDetailState,
+    get_dialog_hints, get_help_hints, get_sprint_hints, render_assignee_picker, render_auth, render_comments,
+    linear, render_dialog, render_document, render_help, render_linear, render_sidebar,
+    render_color_picker, render_name_prompt, render_picker, render_sprint,
+    terminal_supports_nerd_fonts, ColorPickerState,
+    render_status_picker, render_task_description, render_task_detail, render_task_list,
+    render_task_metadata, AuthState, DialogState,
+    render_recent_tasks, render_documents, render_empty_state, Collection, CommentCollapse,
+    DialogType, DocumentState, EmptyState, LoadStatus, render_item_info, ItemInfo,
+    DocumentsState, GroupedTaskList, HelpContext,
+    HelpState, LinearView, ListRow, NamePromptState, NewItem, PickerState, RecentTask, RecentTasksState, SidebarState, SprintState, TaskDetailState,
+    render_workspace_views, WorkspaceViewsState, render_calendar, CalendarState,
 };
 
+/// Tasks of a grouped list as "[status] name" lines, with the index of the selected one
+fn task_list_snapshot(list: &GroupedTaskList) -> (Vec<String>, Option<usize>) {
+    let tasks: Vec<&Task> = list
+        .rows()
+        .iter()
+        .filter_map(|row| match row {
+            ListRow::Task(task) => Some(task.as_ref()),
+            ListRow::Header { .. } => None,
+        })
+        .collect();
+    let selected = list
+        .selected_task()
+        .and_then(|selected| tasks.iter().position(|task| task.id == selected.id));
+    let lines = tasks
+        .iter()
+        .map(|task| {
+            let status = task.status.as_ref().map_or("no status", |s| s.status.as_str());
+            format!("[{}] {}", status, task.name)
+        })
+        .collect();
+    (lines, selected)
+}
+
+/// Number of tasks shown on the Recent Tasks screen
+const RECENT_TASKS_LIMIT: usize = 20;
+
+/// Comment fetches in flight at once while checking for unread comments
+const UNREAD_CHECK_CONCURRENCY: usize = 2;
+
+/// Reply fetches in flight at once while prefetching a comment page's threads
+const REPLY_PREFETCH_CONCURRENCY: usize = 4;
+
+/// Task list rows assumed visible before the first render
+const DEFAULT_TASK_LIST_HEIGHT: usize = 20;
+
+/// Characters of a comment quoted in the delete confirmation
+const COMMENT_DELETE_PREVIEW_CHARS: usize = 60;
+
+/// Build the API client, behind the comment cache when the config enables it
+///
+/// The request queue and comment cache share the app's `cache` connection.
+fn api_client(config: &AppConfig, token: String, cache: &CacheManager) -> Arc<dyn ClickUpApi> {
+    let client = ClickUpClient::new(token)
+        .with_request_queue(cache.clone())
+        .with_markdown(config.prefer_markdown);
+    match config.comment_cache_ttl_secs {
+        Some(ttl_secs) => {
+            CachingClient::new(client, cache.clone(), Duration::from_secs(ttl_secs)).boxed()
+        }
+        None => client.boxed(),
+    }
+}
+
+/// Read every terminal event pending, waiting up to `timeout` for the first
+///
+/// Events that queue up while a frame draws (a burst of keys over a slow
+/// link) are all returned together rather than one per loop iteration.
+fn read_events(timeout: Duration) -> Result<Vec<event::Event>> {
+    let mut events = Vec::new();
+    if event::poll(timeout)? {
+        events.push(event::read()?);
+        while event::poll(Duration::ZERO)? {
+            events.push(event::read()?);
+        }
+    }
+    Ok(events)
+}
+
+/// Full text of an API error including its causes, e.g. the field path of a
+/// parse failure, which `to_string()` would drop
+fn error_chain(e: &anyhow::Error) -> String {
+    format!("{:#}", e)
+}
+
 /// Application screens
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
@@ -41,6 +142,21 @@ pub enum Screen {
     TaskDetail,
     #[allow(dead_code)]
     Document,
+    /// Document browser with search-as-you-type
+    Documents,
+    /// Sprint overview for a sprint view
+    #[allow(dead_code)]
+    Sprint,
+    /// Tasks assigned to the current user across a workspace
+    MyTasks,
+    /// Tasks assigned to the current user, grouped by space and list
+    MyWork,
+    /// Recently opened tasks, newest first
+    RecentTasks,
+    /// Views saved across a workspace, and the tasks of the one opened
+    WorkspaceViews,
+    /// Month calendar of when the tasks loaded on Tasks or My Tasks are due
+    Calendar,
 }
 
 /// Comment view mode for threaded comments
@@ -71,33 +187,229 @@ pub struct CommentCreatedMessageMeta {
 #[derive(Debug, Clone)]
 pub struct CommentsLoadedResponse {
     all_comments: Vec<Comment>,
-    top_level_comments: usize
+    top_level_comments: usize,
+    next_cursor: Option<CommentsCursor>,
+    /// Threads whose replies came with this page
+    prefetched_threads: Vec<String>,
+}
+
+/// Fetch a page of top-level comments in `order`, with the replies to each
+/// when `prefetch_replies` is set; otherwise replies are loaded per thread
+async fn fetch_comment_page(
+    client: Arc<dyn ClickUpApi>,
+    task_id: &str,
+    cursor: Option<CommentsCursor>,
+    prefetch_replies: bool,
+    order: CommentOrder,
+) -> anyhow::Result<CommentsLoadedResponse> {
+    let page = client
+        .get_task_comments(task_id, cursor, Some(COMMENTS_PAGE_SIZE))
+        .await?;
+    let top_level_comments = page.comments.len();
+    let mut all_comments = page.comments;
+    order.arrange(&mut all_comments);
+    let mut prefetched_threads = Vec::new();
+
+    if prefetch_replies {
+        use futures::StreamExt;
+        // Comments known to have no replies are skipped
+        let reply_futures = all_comments
+            .iter()
+            .filter(|comment| comment.reply_count != Some(0))
+            .map(|comment| {
+                let comment_id = comment.id.clone();
+                let client = client.clone();
+                async move {
+                    let result = client.get_comment_replies(&comment_id).await;
+                    (comment_id, result)
+                }
+            })
+            .collect::<Vec<_>>();
+        // A thread whose replies failed is fetched again when it is opened
+        let mut replies = futures::stream::iter(reply_futures)
+            .buffer_unordered(REPLY_PREFETCH_CONCURRENCY);
+        while let Some((parent_id, result)) = replies.next().await {
+            match result {
+                Ok(replies) => {
+                    all_comments.extend(replies.into_iter().map(|mut reply| {
+                        reply.parent_id = Some(parent_id.clone());
+                        reply
+                    }));
+                    prefetched_threads.push(parent_id);
+                }
+                Err(e) => tracing::warn!("Failed to prefetch replies of {}: {:#}", parent_id, e),
+            }
+        }
+    }
+
+    Ok(CommentsLoadedResponse {
+        all_comments,
+        top_level_comments,
+        next_cursor: page.next_cursor,
+        prefetched_threads,
+    })
+}
+
+/// Children of a space: folders, folderless lists and folderless docs
+#[derive(Debug, Clone)]
+pub struct SpaceContents {
+    folders: Vec<Folder>,
+    lists: Vec<List>,
+    documents: Vec<Document>,
+}
+
+/// What a list's members were loaded for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MembersFor {
+    /// The open task's assignee picker
+    TaskAssignees,
+    /// Assigning the comment with this ID
+    Comment(String),
+}
+
+/// A tag named in the name prompt, waiting for its color
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NewTag {
+    space_id: String,
+    task_id: String,
+    name: String,
+}
+
+/// Kind of navigation load; only the latest load of each kind may apply its result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadKind {
+    Spaces,
+    SpaceContents,
+    Lists,
+    Tasks,
+}
+
+/// What a task list load was narrowed down by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskFilter {
+    /// A saved view's tasks
+    View,
+    /// Only tasks assigned to the current user
+    Assigned,
+    /// Archived tasks shown
+    Archived,
+}
+
+/// Tags a navigation load with its request ID and the ID of the item being opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadTicket {
+    pub request_id: u64,
+    pub context_id: String,
+}
+
+impl From<LoadKind> for Collection {
+    fn from(kind: LoadKind) -> Self {
+        match kind {
+            LoadKind::Spaces => Collection::Spaces,
+            LoadKind::SpaceContents => Collection::SpaceContents,
+            LoadKind::Lists => Collection::Lists,
+            LoadKind::Tasks => Collection::Tasks,
+        }
+    }
 }
 
 /// Async messages for API results
 #[derive(Debug, Clone)]
 pub enum AppMessage {
     WorkspacesLoaded(Result<Vec<Workspace>, String>),
-    SpacesLoaded(Result<Vec<ClickUpSpace>, String>),
-    FoldersLoaded(Result<Vec<Folder>, String>),
-    ListsLoaded(Result<Vec<List>, String>),
-    TasksLoaded(Result<Vec<Task>, String>),
-    CommentsLoaded(Result<CommentsLoadedResponse, String>),
+    /// Workspaces fetched in the background after cached ones were shown
+    WorkspacesRefreshed(Result<Vec<Workspace>, String>),
+    SpacesLoaded(Result<Vec<ClickUpSpace>, String>, LoadTicket),
+    SpaceContentsLoaded(Result<SpaceContents, String>, LoadTicket),
+    DocumentLoaded(Result<Document, String>),
+    /// Document browser search results, tagged with the search generation
+    DocumentsSearched(Result<Vec<Document>, String>, u64),
+    SprintOverviewLoaded(Result<SprintOverview, String>),
+    ListsLoaded(Result<Vec<List>, String>, LoadTicket),
+    TasksLoaded(Result<TaskPages, String>, LoadTicket),
+    /// One task of a list load, sent as it arrives; the `TasksLoaded` that
+    /// follows carries no tasks of its own, only whether more were available
+    TaskReceived(Box<Task>, LoadTicket),
+    CommentsLoaded(Result<CommentsLoadedResponse, String>, u64), // result, request generation
+    MoreCommentsLoaded(Result<CommentsLoadedResponse, String>, u64), // next page, request generation
+    /// Replies for one thread: (parent comment ID, result, comments generation)
+    ThreadRepliesLoaded(String, Result<Vec<Comment>, String>, u64),
+    /// Replies fetched ahead for the thread under the selection, as above
+    ThreadRepliesPrefetched(String, Result<Vec<Comment>, String>, u64),
+    MyTasksLoaded(Result<Vec<Task>, String>),
+    /// The current user's tasks across the workspace, with the workspace's spaces
+    MyWorkLoaded(Result<(Vec<Task>, Vec<ClickUpSpace>), String>),
+    /// Current details of the tasks on the Recent Tasks screen
+    RecentTasksLoaded(Result<Vec<Task>, String>),
+    /// Views saved on a list, for the view picker
+    ListViewsLoaded(Result<Vec<View>, String>, String),
+    /// Children of an item expanded in the sidebar tree: (item, result)
+    SidebarChildrenLoaded(SidebarItem, Result<Vec<SidebarItem>, String>),
+    /// Views saved on a workspace: (result, workspace ID)
+    WorkspaceViewsLoaded(Result<Vec<WorkspaceView>, String>, String),
+    /// Tasks shown in a workspace view: (result, view ID)
+    WorkspaceViewTasksLoaded(Result<Vec<Task>, String>, String),
+    /// A space was created: (result, workspace ID)
+    SpaceCreated(Result<ClickUpSpace, String>, String),
+    /// A folder was created: (result, space ID)
+    FolderCreated(Result<Folder, String>, String),
+    /// A task has comments newer than its last view: (task ID, unread check generation)
+    UnreadCommentsFound(String, u64),
     CommentCreated(Result<Comment, String>, CommentCreatedMessageMeta),
     CommentUpdated(Result<Comment, String>),
+    /// A comment fetched again after a change: (result, comments generation)
+    CommentRefreshed(Result<Comment, String>, u64),
+    /// A linked comment that isn't among its task's top-level comments,
+    /// fetched to find the thread it replies to
+    LinkedReplyFetched(Result<Comment, String>),
+    CommentDeleted(Result<String, String>), // Ok(comment_id) or Err(message)
     CurrentUserLoaded(Result<User, String>),
-    MembersLoaded(Result<Vec<User>, String>),
+    MembersLoaded(Result<Vec<User>, String>, MembersFor),
+    /// A comment was assigned: (comment ID, the assignee)
+    CommentAssigned(String, Result<User, String>),
+    /// A new tag was created and added to a task: (task ID, the tag)
+    TaskTagged(String, Result<crate::models::Tag, String>),
+    /// A task was linked to another: (task ID, the link)
+    TaskLinkAdded(String, Result<crate::models::LinkedTask, String>),
+    /// A task's link was removed: (task ID, ID of the task at the other end)
+    TaskLinkRemoved(String, String, Result<(), String>),
+    /// The current list, fetched for its name
+    ListFetched(Result<List, String>),
+    ListStatusesLoaded(String, Result<Vec<crate::models::TaskStatus>, String>), // list_id, result
+    FolderStatusesLoaded(String, Result<Vec<crate::models::TaskStatus>, String>), // folder_id, result
+    TimeEntriesLoaded(String, Result<Vec<TimeEntry>, String>), // task_id, result
+    TimeInStatusLoaded(String, Result<Vec<TimeInStatus>, String>), // task_id, result
     AssigneesUpdated(Result<Task, String>),
     TaskStatusUpdated(Result<Task, String>),
+    /// A staged description saved with update_task
+    TaskDescriptionSaved(Result<Task, String>),
     // URL navigation async messages
     TaskFetchedForNavigation(Result<Task, String>, Screen),
     CommentFetchedForNavigation(Result<Task, String>, String, Screen), // task result, comment_id, prev_screen
     DocumentFetchedForNavigation(Result<Document, String>, Screen),
-    CommentsLoadedForCommentNavigation(Result<Vec<Comment>, String>, String), // comments, comment_id
+    CommentsLoadedForCommentNavigation(Result<Vec<Comment>, String>, String, u64), // comments, comment_id, generation
     // Task creation
     TaskCreated(Result<Task, String>),
+    TaskCreatedFromComment(Result<Task, String>),
     // Task deletion
     TaskDeleted(Result<String, String>), // Ok(task_id) or Err(message)
+    // Bulk deletion: one batch's result, tasks processed so far, total tasks
+    BulkDeleteProgress(BulkDeleteResult, usize, usize),
+    // Bulk deletion finished: results across all batches
+    BulkDeleteFinished(BulkDeleteResult),
+    // Bulk archive or unarchive finished
+    BulkArchiveFinished(BulkArchiveResult),
+    /// A task archived (true) or unarchived: (task_id, archived, result)
+    TaskArchived(String, bool, Result<(), String>),
+    // Undo: result of the inverse call, and the action it reverted
+    UndoApplied(Result<UndoOutcome, String>, UndoAction),
+}
+
+/// What an undo call restored
+#[derive(Debug, Clone)]
+pub enum UndoOutcome {
+    Task(Box<Task>),
+    Comment(Box<Comment>),
 }
 
 /// Main TUI application state
@@ -138,6 +450,40 @@ pub struct TuiApp {
     /// Document state
     document: DocumentState,
 
+    /// Sprint overview state
+    sprint: SprintState,
+
+    /// Screen to return to when closing the sprint overview
+    sprint_return_screen: Option<Screen>,
+    /// Tasks assigned to the current user
+    my_tasks: GroupedTaskList,
+    /// Screen to return to when leaving My Tasks
+    my_tasks_return_screen: Option<Screen>,
+    /// Tasks assigned to the current user, grouped by space and list
+    my_work: GroupedTaskList,
+    /// Screen to return to when leaving My Work
+    my_work_return_screen: Option<Screen>,
+    /// Recently opened tasks from the access log
+    recent_tasks: RecentTasksState,
+    /// Screen to return to when leaving Recent Tasks
+    recent_tasks_return_screen: Option<Screen>,
+    /// Views saved on the workspace chosen on the Workspaces screen
+    workspace_views: WorkspaceViewsState,
+    /// Due-date calendar
+    calendar: CalendarState,
+    /// Screen the calendar was opened from (Tasks or My Tasks)
+    calendar_return_screen: Option<Screen>,
+    /// Day picked in the calendar: the list it was opened from shows only the
+    /// tasks on that day
+    due_day_filter: Option<chrono::NaiveDate>,
+    /// Request ID of the task load whose tasks are being shown as they arrive
+    streamed_load: Option<u64>,
+    /// Tasks of a reload still in flight, by request ID; the tasks it
+    /// replaces are shown until it completes
+    reloaded_tasks: Option<(u64, Vec<Task>)>,
+    /// Screen to return to when leaving the task detail, if not the task list
+    task_detail_return_screen: Option<Screen>,
+
     /// Dialog state
     dialog: DialogState,
 
@@ -153,22 +499,102 @@ pub struct TuiApp {
     /// Data
     workspaces: Vec<Workspace>,
     spaces: Vec<ClickUpSpace>,
+    /// Features of each workspace whose spaces have loaded, by workspace ID
+    workspace_features: std::collections::HashMap<String, Features>,
     folders: Vec<Folder>,
     lists: Vec<List>,
+    /// The current list, fetched on its own when it isn't among `lists`
+    /// (e.g. a task opened from a link)
+    fetched_list: Option<List>,
     tasks: Vec<Task>,
+    /// List `tasks` were loaded from, so a reload of it keeps the selection
+    tasks_list_id: Option<String>,
     documents: Vec<Document>,
+    /// Folderless lists and docs of the current space
+    space_lists: Vec<List>,
+    space_documents: Vec<Document>,
+
+    /// Screen to return to when closing a document opened from the sidebar
+    document_return_screen: Option<Screen>,
+    /// Document browser state
+    documents_browser: DocumentsState,
+    /// Screen to return to when leaving the document browser
+    documents_browser_return_screen: Option<Screen>,
     comments: Vec<Comment>,
 
     /// Comment UI state
     comment_selected_index: usize,
     comment_top_level_count: usize, // stores top level comment length
+    /// Cursor for the page of older top-level comments, None when all are loaded
+    comments_cursor: Option<CommentsCursor>,
+    /// When the shown comments were cached, if the load failed and they came from the cache
+    comments_cached_at: Option<i64>,
+    /// Top-level comments whose replies have been fetched (or are being fetched)
+    loaded_threads: std::collections::HashSet<String>,
+    /// Threads whose replies are fetched ahead while the selection rests on them
+    reply_prefetch: ReplyPrefetch,
+    /// Long comments the user has expanded in the current task view
+    comment_collapse: CommentCollapse,
     comment_editing_index: Option<usize>,
     comment_new_text: String,
     comment_focus: bool, // true = focus on comments, false = focus on task form
+    /// Focus the comments once they load, if there are any (`comment_focus_on_open`)
+    comment_focus_on_load: bool,
+
+    /// The next workspace load is the cached copy shown on startup, which
+    /// must not be cached again
+    workspaces_from_cache: bool,
 
     /// Comment thread navigation state
     comment_view_mode: CommentViewMode,
     comment_previous_selection: Option<usize>, // Store selection when entering thread
+    /// Generation of the latest comment load; responses from older loads are discarded
+    comments_generation: u64,
+    /// Whether `loading` was set by a comment load of the current generation
+    comments_loading: bool,
+
+    /// Background check of visible tasks for unseen comments
+    unread_check: Option<tokio::task::JoinHandle<()>>,
+    /// Generation of the latest unread check; results from older checks are discarded
+    unread_generation: u64,
+    /// Task rows that fit in the task list, updated on render
+    task_list_height: usize,
+
+    /// Latest navigation load of each kind; responses with any other ticket are stale
+    pending_loads: std::collections::HashMap<LoadKind, LoadTicket>,
+    load_request_counter: u64,
+    /// How the latest load of each collection went, to word empty screens
+    load_statuses: std::collections::HashMap<Collection, LoadStatus>,
+
+    /// User settings from config.toml
+    config: AppConfig,
+
+    /// Type-ahead jump buffer for the task list
+    type_ahead: TypeAhead,
+    /// Times the status message so it clears after `status_clear_secs`
+    status_clock: StatusClock,
+
+    /// Reversible actions taken this session, most recent last
+    undo_stack: UndoStack,
+    /// Spinner animation frame, advanced on every render
+    loading_frame: u8,
+    /// Most recent error, kept after the status bar clears it
+    last_error: Option<String>,
+    /// Whether the full error overlay is open
+    error_detail_open: bool,
+    /// Scroll offset of the error overlay
+    error_detail_scroll: u16,
+    /// Start screen to apply once the user has logged in or the workspaces have loaded
+    pending_start_screen: Option<StartScreen>,
+    /// Open the workspace directly if the first load finds only one
+    auto_select_workspace: bool,
+    /// Workspace of the last session, selected on the Workspaces screen
+    /// when the session itself isn't restored
+    last_workspace_id: Option<String>,
+    /// Task text waiting to be shown in the external pager by the run loop
+    pending_pager: Option<String>,
+    /// Description waiting to be edited in the external editor by the run loop
+    pending_editor: Option<String>,
 
     /// Task creation form state
     task_name_input: String,
@@ -178,6 +604,10 @@ pub struct TuiApp {
 
     /// Per-list assigned filter state
     assigned_filter_active: bool,
+    /// Whether the task list includes archived tasks
+    show_archived: bool,
+    /// Task list order; modes the API supports are requested server-side
+    task_sort_mode: TaskSortMode,
 
     /// User identity for assignee filtering
     current_user_id: Option<i32>,
@@ -185,6 +615,16 @@ pub struct TuiApp {
     /// In-memory cache for list members (keyed by list ID)
     cached_list_members: std::collections::HashMap<String, Vec<User>>,
 
+    /// In-memory cache for workspace views (keyed by workspace ID)
+    cached_workspace_views: std::collections::HashMap<String, Vec<WorkspaceView>>,
+
+    /// In-memory cache for list status workflows (keyed by list ID)
+    cached_list_statuses: std::collections::HashMap<String, Vec<crate::models::TaskStatus>>,
+    /// Folder status overrides by folder ID (empty when the folder uses its space's)
+    cached_folder_statuses: std::collections::HashMap<String, Vec<crate::models::TaskStatus>>,
+    /// Time in status by task ID, dropped when the task's status changes
+    cached_time_in_status: std::collections::HashMap<String, Vec<TimeInStatus>>,
+
     /// Assignee picker UI state
     assignee_picker_open: bool,
     assignee_picker_members: Vec<User>,
@@ -240,6 +680,19 @@ pub struct TuiApp {
     url_input_error: Option<String>,
     url_input_cursor: usize,
 
+    /// Name prompt for a new space, folder or tag, when open
+    name_prompt: Option<NamePromptState>,
+    /// Color for the tag named in the name prompt
+    tag_color_picker: Option<(NewTag, ColorPickerState)>,
+    /// Saved views of the current list, picked with `V` on the Tasks screen
+    view_picker: Option<PickerState<View>>,
+    /// Members to assign a comment to, with the ID of that comment
+    comment_assignee_picker: Option<(String, PickerState<User>)>,
+    /// Saved view whose tasks the task list shows instead of the whole list
+    active_view: Option<View>,
+    /// Writes made offline and still waiting in the request queue
+    queued_requests: usize,
+
     /// Navigation loading state for URL-based navigation
     navigating: bool,
     navigating_level: String,
@@ -261,11 +714,22 @@ impl TuiApp {
     #[allow(dead_code)]
     pub fn is_text_input_active(&self) -> bool {
         self.url_input_open
+            || self.name_prompt.is_some()
+            || self.view_picker.is_some()
+            || self.comment_assignee_picker.is_some()
+            || self.tag_color_picker.is_some()
             || self.status_picker_open
             || self.assignee_picker_open
             || self.task_creating
             || self.comment_editing_index.is_some()
             || !self.comment_new_text.is_empty()
+            || self.screen == Screen::Documents
+            || self.type_ahead_active()
+    }
+
+    /// A type-ahead jump is being typed on the task list
+    fn type_ahead_active(&self) -> bool {
+        self.screen == Screen::Tasks && self.type_ahead.is_active()
     }
 
     /// Handle text input when any text input field is active
@@ -273,6 +737,16 @@ impl TuiApp {
     pub fn handle_text_input(&mut self, key: crossterm::event::KeyEvent) {
         if self.url_input_open {
             self.handle_url_input(key);
+        } else if self.name_prompt.is_some() {
+            self.handle_name_prompt_input(key);
+        } else if self.view_picker.is_some() {
+            self.handle_view_picker_input(key);
+        } else if self.comment_assignee_picker.is_some() {
+            self.handle_comment_assignee_picker_input(key);
+        } else if self.tag_color_picker.is_some() {
+            self.handle_tag_color_picker_input(key);
+        } else if self.screen == Screen::Documents {
+            self.handle_documents_input(key);
         } else if self.status_picker_open {
             self.handle_status_picker_input(key);
         } else if self.assignee_picker_open {
@@ -281,6 +755,8 @@ impl TuiApp {
             self.handle_task_creation_input(key);
         } else if self.comment_editing_index.is_some() || !self.comment_new_text.is_empty() {
             self.handle_comment_input(key);
+        } else if self.type_ahead_active() {
+            self.update_tasks(InputEvent::Key(key));
         }
     }
 
@@ -288,30 +764,16 @@ impl TuiApp {
     fn handle_assignee_picker_input(&mut self, key: crossterm::event::KeyEvent) {
         use crossterm::event::{KeyCode, KeyModifiers};
         match key.code {
-            KeyCode::Char('j') => {
+            KeyCode::Char('j')
                 if self.assignee_picker_cursor
-                    < self.assignee_picker_members.len().saturating_sub(1)
-                {
-                    self.assignee_picker_cursor += 1;
-                }
-            }
-            KeyCode::Char('k') => {
-                if self.assignee_picker_cursor > 0 {
-                    self.assignee_picker_cursor -= 1;
-                }
+                    < self.assignee_picker_members.len().saturating_sub(1) =>
+            {
+                self.assignee_picker_cursor += 1;
             }
-            KeyCode::Char(' ') => {
-                if let Some(member) = self
-                    .assignee_picker_members
-                    .get(self.assignee_picker_cursor)
-                {
-                    if self.assignee_picker_selected.contains(&member.id) {
-                        self.assignee_picker_selected.remove(&member.id);
-                    } else {
-                        self.assignee_picker_selected.insert(member.id);
-                    }
-                }
+            KeyCode::Char('k') if self.assignee_picker_cursor > 0 => {
+                self.assignee_picker_cursor -= 1;
             }
+            KeyCode::Char(' ') => self.toggle_picked_assignee(),
             KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.save_assignees();
             }
@@ -390,7 +852,6 @@ impl TuiApp {
                     self.task_description_input.pop();
                 }
             }
-            return;
         }
     }
 
@@ -503,6 +964,12 @@ impl TuiApp {
         self.current_list_id = list_id;
     }
 
+    /// Set the current workspace ID (for testing)
+    #[allow(dead_code)]
+    pub fn set_current_workspace_id(&mut self, workspace_id: Option<String>) {
+        self.current_workspace_id = workspace_id;
+    }
+
     /// Set the current user ID (for testing)
     #[allow(dead_code)]
     pub fn set_current_user_id(&mut self, user_id: Option<i32>) {
@@ -534,6 +1001,25 @@ impl TuiApp {
             .insert(list_id.to_string(), members);
     }
 
+    /// Set the cached status workflow for a list (for testing)
+    #[allow(dead_code)]
+    pub fn set_cached_list_statuses(&mut self, list_id: &str, statuses: Vec<crate::models::TaskStatus>) {
+        self.cached_list_statuses
+            .insert(list_id.to_string(), statuses);
+    }
+
+    /// Get the cached status workflow for a list (for testing)
+    #[allow(dead_code)]
+    pub fn cached_list_statuses(&self, list_id: &str) -> Option<&Vec<crate::models::TaskStatus>> {
+        self.cached_list_statuses.get(list_id)
+    }
+
+    /// Status overrides of a folder, if known (for testing)
+    #[allow(dead_code)]
+    pub fn cached_folder_statuses(&self, folder_id: &str) -> Option<&Vec<crate::models::TaskStatus>> {
+        self.cached_folder_statuses.get(folder_id)
+    }
+
     /// Check if URL input dialog is open (for testing)
     #[allow(dead_code)]
     pub fn is_url_input_open(&self) -> bool {
@@ -613,6 +1099,69 @@ impl TuiApp {
         self.comments = comments;
     }
 
+    /// Get the auth screen state (for testing)
+    #[allow(dead_code)]
+    pub fn auth_state_mut_for_test(&mut self) -> &mut AuthState {
+        &mut self.auth_state
+    }
+
+    /// Get loaded comments (for testing)
+    #[allow(dead_code)]
+    pub fn comments(&self) -> &[crate::models::Comment] {
+        &self.comments
+    }
+
+    /// Comment panel notice when the comments shown came from the cache
+    pub fn comments_cached_notice(&self) -> Option<String> {
+        let fetched_at = self.comments_cached_at?;
+        let age = chrono::Utc::now().timestamp() - fetched_at;
+        Some(format!("⚠ Showing cached comments from {}", format_age(age)))
+    }
+
+    /// Index of the selected comment (for testing)
+    #[allow(dead_code)]
+    pub fn comment_selected_index(&self) -> usize {
+        self.comment_selected_index
+    }
+
+    /// Whether more pages of comments can be loaded (for testing)
+    #[allow(dead_code)]
+    pub fn has_more_comments(&self) -> bool {
+        self.comments_cursor.is_some()
+    }
+
+    /// Text queued for the external pager, if any (for testing)
+    #[allow(dead_code)]
+    pub fn pending_pager_text(&self) -> Option<&str> {
+        self.pending_pager.as_deref()
+    }
+
+    /// Description queued for the external editor, if any (for testing)
+    #[allow(dead_code)]
+    pub fn pending_editor_text(&self) -> Option<&str> {
+        self.pending_editor.as_deref()
+    }
+
+    /// Whether a long comment has been expanded (for testing)
+    #[allow(dead_code)]
+    pub fn is_comment_expanded(&self, comment_id: &str) -> bool {
+        self.comment_collapse.is_expanded(comment_id)
+    }
+
+    /// Whether a task row carries the unread comments marker (for testing)
+    #[allow(dead_code)]
+    pub fn is_task_unread(&self, task_id: &str) -> bool {
+        self.task_list.is_unread(task_id)
+            || self.my_tasks.is_unread(task_id)
+            || self.my_work.is_unread(task_id)
+    }
+
+    /// Whether the comments pane has focus (for testing)
+    #[allow(dead_code)]
+    pub fn comment_focus(&self) -> bool {
+        self.comment_focus
+    }
+
     /// Set comment focus (for testing)
     #[allow(dead_code)]
     pub fn set_comment_focus(&mut self, focus: bool) {
@@ -625,6 +1174,12 @@ impl TuiApp {
         self.help.page
     }
 
+    /// Get the help dialog search query (for testing)
+    #[allow(dead_code)]
+    pub fn help_search_query(&self) -> &str {
+        &self.help.search_query
+    }
+
     /// Get the URL input error message (for testing)
     #[allow(dead_code)]
     pub fn url_input_error(&self) -> Option<&str> {
@@ -649,6 +1204,30 @@ impl TuiApp {
         &mut self.task_list
     }
 
+    /// Get the document browser state (for testing)
+    #[allow(dead_code)]
+    pub fn documents_browser(&self) -> &DocumentsState {
+        &self.documents_browser
+    }
+
+    /// Get the document viewer state (for testing)
+    #[allow(dead_code)]
+    pub fn document_view(&self) -> &DocumentState {
+        &self.document
+    }
+
+    /// Get the key hints shown in the status bar (for testing)
+    #[allow(dead_code)]
+    pub fn key_hints(&self) -> String {
+        self.get_hints()
+    }
+
+    /// Get the My Work task list (for testing)
+    #[allow(dead_code)]
+    pub fn my_work_for_test(&self) -> &crate::tui::widgets::GroupedTaskList {
+        &self.my_work
+    }
+
     /// Get mutable access to dialog for testing
     #[allow(dead_code)]
     pub fn dialog_mut_for_test(&mut self) -> &mut crate::tui::widgets::DialogState {
@@ -684,12 +1263,27 @@ impl TuiApp {
     pub fn task_count(&self) -> usize {
         self.tasks.len()
     }
+
+    /// Get the screen title (for testing)
+    #[allow(dead_code)]
+    pub fn screen_title_for_test(&self) -> &str {
+        &self.screen_title
+    }
 }
 
 impl TuiApp {
     pub fn new() -> Result<Self> {
         let auth = AuthManager::new().unwrap_or_default();
-        let cache = CacheManager::new(ConfigManager::database_path()?)?;
+        let mut config_error = None;
+        let config = ConfigManager::load_config().unwrap_or_else(|e| {
+            tracing::warn!("Using default settings: {:#}", e);
+            config_error = Some(format!("{:#}", e));
+            AppConfig::default()
+        });
+        let cache = CacheManager::new(ConfigManager::database_path_for(
+            &config,
+            &CacheOverrides::from_env(),
+        )?)?;
 
         let state = if auth.load_token().ok().flatten().is_some() {
             AppState::Initializing
@@ -714,36 +1308,96 @@ impl TuiApp {
             auth,
             error: None,
             loading: false,
-            sidebar: SidebarState::new(),
+            sidebar: SidebarState::new().with_nerd_fonts(
+                config.ui.use_nerd_fonts
+                    && terminal_supports_nerd_fonts(std::env::var("TERM").ok().as_deref()),
+            ),
             task_list: GroupedTaskList::new(),
             task_detail: TaskDetailState::new(),
             auth_state: AuthState::new(),
             document: DocumentState::new(),
+            sprint: SprintState::new(),
+            sprint_return_screen: None,
+            my_tasks: GroupedTaskList::new(),
+            my_tasks_return_screen: None,
+            my_work: GroupedTaskList::new(),
+            my_work_return_screen: None,
+            recent_tasks: RecentTasksState::new(),
+            recent_tasks_return_screen: None,
+            workspace_views: WorkspaceViewsState::default(),
+            calendar: CalendarState::default(),
+            calendar_return_screen: None,
+            due_day_filter: None,
+            streamed_load: None,
+            reloaded_tasks: None,
+            task_detail_return_screen: None,
             dialog: DialogState::new(),
             help: HelpState::new(),
             screen_title: generate_screen_title("Authentication"),
             status: String::new(),
             workspaces: Vec::new(),
             spaces: Vec::new(),
+            workspace_features: std::collections::HashMap::new(),
             folders: Vec::new(),
             lists: Vec::new(),
+            fetched_list: None,
             tasks: Vec::new(),
+            tasks_list_id: None,
             documents: Vec::new(),
+            space_lists: Vec::new(),
+            space_documents: Vec::new(),
+            document_return_screen: None,
+            documents_browser: DocumentsState::new(),
+            documents_browser_return_screen: None,
             comments: Vec::new(),
             comment_selected_index: 0,
             comment_editing_index: None,
             comment_new_text: String::new(),
             comment_focus: false,
+            comment_focus_on_load: false,
+            workspaces_from_cache: false,
             comment_top_level_count: 0,
+            comments_cursor: None,
+            comments_cached_at: None,
+            loaded_threads: std::collections::HashSet::new(),
+            reply_prefetch: ReplyPrefetch::new(),
+            comment_collapse: CommentCollapse::new(DEFAULT_COMMENT_COLLAPSE_LINES),
             comment_view_mode: CommentViewMode::TopLevel,
             comment_previous_selection: None,
+            pending_loads: std::collections::HashMap::new(),
+            load_statuses: std::collections::HashMap::new(),
+            load_request_counter: 0,
+            config,
+            type_ahead: TypeAhead::new(),
+            status_clock: StatusClock::new(DEFAULT_STATUS_CLEAR_SECS),
+            undo_stack: UndoStack::new(),
+            loading_frame: 0,
+            last_error: None,
+            error_detail_open: false,
+            error_detail_scroll: 0,
+            pending_start_screen: None,
+            auto_select_workspace: false,
+            last_workspace_id: None,
+            pending_pager: None,
+            pending_editor: None,
+            comments_generation: 0,
+            comments_loading: false,
+            unread_check: None,
+            unread_generation: 0,
+            task_list_height: DEFAULT_TASK_LIST_HEIGHT,
             task_name_input: String::new(),
             task_description_input: String::new(),
             task_creating: false,
             task_creation_focus: TaskCreationField::Name,
             assigned_filter_active: false,
+            show_archived: false,
+            task_sort_mode: TaskSortMode::default(),
             current_user_id: None,
             cached_list_members: std::collections::HashMap::new(),
+            cached_workspace_views: std::collections::HashMap::new(),
+            cached_list_statuses: std::collections::HashMap::new(),
+            cached_time_in_status: std::collections::HashMap::new(),
+            cached_folder_statuses: std::collections::HashMap::new(),
             assignee_picker_open: false,
             assignee_picker_members: Vec::new(),
             assignee_picker_selected: std::collections::HashSet::new(),
@@ -774,19 +1428,29 @@ impl TuiApp {
             url_input_text: String::new(),
             url_input_error: None,
             url_input_cursor: 0,
+            name_prompt: None,
+            view_picker: None,
+            comment_assignee_picker: None,
+            tag_color_picker: None,
+            active_view: None,
+            queued_requests: 0,
             navigating: false,
             navigating_level: String::new(),
         };
-        if matches!(app.state, AppState::Initializing) {
-            let _ = app.restore_session_state();
-        }
+        app.comment_collapse = CommentCollapse::new(app.config.comment_collapse_lines);
+        app.status_clock = StatusClock::new(app.config.status_clear_secs);
+        app.apply_start_screen(app.config.start_screen);
 
         app.update_screen_title();
+        if let Some(e) = config_error {
+            app.last_error = Some(format!("Using default settings: {}", e));
+            app.show_toast("Config error, using defaults (!: details)".to_string());
+        }
 
         if matches!(app.state, AppState::Initializing) {
             // Load token and create client
             if let Ok(Some(token)) = app.auth.load_token() {
-                app.client = Some(Arc::new(ClickUpClient::new(token)));
+                app.client = Some(api_client(&app.config, token, &app.cache));
                 app.load_workspaces();
 
                 // Fetch current user profile in background for assignee filtering
@@ -800,7 +1464,7 @@ impl TuiApp {
                             }
                             Err(e) => {
                                 let _ = tx
-                                    .send(AppMessage::CurrentUserLoaded(Err(e.to_string())))
+                                    .send(AppMessage::CurrentUserLoaded(Err(error_chain(&e))))
                                     .await;
                             }
                         }
@@ -812,9 +1476,34 @@ impl TuiApp {
             }
         }
 
+        app.refresh_queued_requests();
+
+        // First launch: walk the user through getting and verifying a token
+        if app.screen == Screen::Auth {
+            app.auth_state = AuthState::with_wizard();
+        }
+
         Ok(app)
     }
 
+    /// Whether the onboarding wizard is waiting for token verification
+    fn is_verifying_onboarding_token(&self) -> bool {
+        self.screen == Screen::Auth
+            && self.auth_state.wizard
+            && self.auth_state.onboarding_step == STEP_VERIFYING
+    }
+
+    /// Enable or disable the first-launch onboarding wizard
+    ///
+    /// When disabled, the auth screen goes straight to token input.
+    pub fn set_onboarding_wizard(&mut self, enabled: bool) {
+        self.auth_state = if enabled {
+            AuthState::with_wizard()
+        } else {
+            AuthState::new()
+        };
+    }
+
     /// Create a new TUI app with a custom client (for testing)
     #[allow(dead_code)]
     pub fn with_client(client: Arc<dyn ClickUpApi>) -> Result<Self> {
@@ -837,31 +1526,93 @@ impl TuiApp {
             task_detail: TaskDetailState::new(),
             auth_state: AuthState::new(),
             document: DocumentState::new(),
+            sprint: SprintState::new(),
+            sprint_return_screen: None,
+            my_tasks: GroupedTaskList::new(),
+            my_tasks_return_screen: None,
+            my_work: GroupedTaskList::new(),
+            my_work_return_screen: None,
+            recent_tasks: RecentTasksState::new(),
+            recent_tasks_return_screen: None,
+            workspace_views: WorkspaceViewsState::default(),
+            calendar: CalendarState::default(),
+            calendar_return_screen: None,
+            due_day_filter: None,
+            streamed_load: None,
+            reloaded_tasks: None,
+            task_detail_return_screen: None,
             dialog: DialogState::new(),
             help: HelpState::new(),
             screen_title: generate_screen_title("Workspaces"),
             status: String::new(),
             workspaces: Vec::new(),
             spaces: Vec::new(),
+            workspace_features: std::collections::HashMap::new(),
             folders: Vec::new(),
             lists: Vec::new(),
+            fetched_list: None,
             tasks: Vec::new(),
+            tasks_list_id: None,
             documents: Vec::new(),
+            space_lists: Vec::new(),
+            space_documents: Vec::new(),
+            document_return_screen: None,
+            documents_browser: DocumentsState::new(),
+            documents_browser_return_screen: None,
             comments: Vec::new(),
             comment_selected_index: 0,
             comment_editing_index: None,
             comment_new_text: String::new(),
             comment_focus: false,
+            comment_focus_on_load: false,
+            workspaces_from_cache: false,
             comment_view_mode: CommentViewMode::TopLevel,
             comment_previous_selection: None,
+            pending_loads: std::collections::HashMap::new(),
+            load_statuses: std::collections::HashMap::new(),
+            load_request_counter: 0,
+            // Tests share a cache database, so one test's cached workspaces
+            // mustn't stand in for another's mock
+            config: AppConfig {
+                workspace_cache_ttl_secs: 0,
+                ..AppConfig::default()
+            },
+            type_ahead: TypeAhead::new(),
+            status_clock: StatusClock::new(DEFAULT_STATUS_CLEAR_SECS),
+            undo_stack: UndoStack::new(),
+            loading_frame: 0,
+            last_error: None,
+            error_detail_open: false,
+            error_detail_scroll: 0,
+            pending_start_screen: None,
+            auto_select_workspace: false,
+            last_workspace_id: None,
+            pending_pager: None,
+            pending_editor: None,
+            comments_generation: 0,
+            comments_loading: false,
+            unread_check: None,
+            unread_generation: 0,
+            task_list_height: DEFAULT_TASK_LIST_HEIGHT,
             comment_top_level_count: 0,
+            comments_cursor: None,
+            comments_cached_at: None,
+            loaded_threads: std::collections::HashSet::new(),
+            reply_prefetch: ReplyPrefetch::new(),
+            comment_collapse: CommentCollapse::new(DEFAULT_COMMENT_COLLAPSE_LINES),
             task_name_input: String::new(),
             task_description_input: String::new(),
             task_creating: false,
             task_creation_focus: TaskCreationField::Name,
             assigned_filter_active: false,
+            show_archived: false,
+            task_sort_mode: TaskSortMode::default(),
             current_user_id: None,
             cached_list_members: std::collections::HashMap::new(),
+            cached_workspace_views: std::collections::HashMap::new(),
+            cached_list_statuses: std::collections::HashMap::new(),
+            cached_time_in_status: std::collections::HashMap::new(),
+            cached_folder_statuses: std::collections::HashMap::new(),
             assignee_picker_open: false,
             assignee_picker_members: Vec::new(),
             assignee_picker_selected: std::collections::HashSet::new(),
@@ -892,6 +1643,12 @@ impl TuiApp {
             url_input_text: String::new(),
             url_input_error: None,
             url_input_cursor: 0,
+            name_prompt: None,
+            view_picker: None,
+            comment_assignee_picker: None,
+            tag_color_picker: None,
+            active_view: None,
+            queued_requests: 0,
             navigating: false,
             navigating_level: String::new(),
         };
@@ -930,31 +1687,93 @@ impl TuiApp {
             task_detail: TaskDetailState::new(),
             auth_state: AuthState::new(),
             document: DocumentState::new(),
+            sprint: SprintState::new(),
+            sprint_return_screen: None,
+            my_tasks: GroupedTaskList::new(),
+            my_tasks_return_screen: None,
+            my_work: GroupedTaskList::new(),
+            my_work_return_screen: None,
+            recent_tasks: RecentTasksState::new(),
+            recent_tasks_return_screen: None,
+            workspace_views: WorkspaceViewsState::default(),
+            calendar: CalendarState::default(),
+            calendar_return_screen: None,
+            due_day_filter: None,
+            streamed_load: None,
+            reloaded_tasks: None,
+            task_detail_return_screen: None,
             dialog: DialogState::new(),
             help: HelpState::new(),
             screen_title: generate_screen_title("Workspaces"),
             status: String::new(),
             workspaces: Vec::new(),
             spaces: Vec::new(),
+            workspace_features: std::collections::HashMap::new(),
             folders: Vec::new(),
             lists: Vec::new(),
+            fetched_list: None,
             tasks: Vec::new(),
+            tasks_list_id: None,
             documents: Vec::new(),
+            space_lists: Vec::new(),
+            space_documents: Vec::new(),
+            document_return_screen: None,
+            documents_browser: DocumentsState::new(),
+            documents_browser_return_screen: None,
             comments: Vec::new(),
             comment_selected_index: 0,
             comment_editing_index: None,
             comment_top_level_count: 0,
+            comments_cursor: None,
+            comments_cached_at: None,
+            loaded_threads: std::collections::HashSet::new(),
+            reply_prefetch: ReplyPrefetch::new(),
+            comment_collapse: CommentCollapse::new(DEFAULT_COMMENT_COLLAPSE_LINES),
             comment_new_text: String::new(),
             comment_focus: false,
+            comment_focus_on_load: false,
+            workspaces_from_cache: false,
             comment_view_mode: CommentViewMode::TopLevel,
             comment_previous_selection: None,
+            pending_loads: std::collections::HashMap::new(),
+            load_statuses: std::collections::HashMap::new(),
+            load_request_counter: 0,
+            // Tests share a cache database, so one test's cached workspaces
+            // mustn't stand in for another's mock
+            config: AppConfig {
+                workspace_cache_ttl_secs: 0,
+                ..AppConfig::default()
+            },
+            type_ahead: TypeAhead::new(),
+            status_clock: StatusClock::new(DEFAULT_STATUS_CLEAR_SECS),
+            undo_stack: UndoStack::new(),
+            loading_frame: 0,
+            last_error: None,
+            error_detail_open: false,
+            error_detail_scroll: 0,
+            pending_start_screen: None,
+            auto_select_workspace: false,
+            last_workspace_id: None,
+            pending_pager: None,
+            pending_editor: None,
+            comments_generation: 0,
+            comments_loading: false,
+            unread_check: None,
+            unread_generation: 0,
+            task_list_height: DEFAULT_TASK_LIST_HEIGHT,
             task_name_input: String::new(),
             task_description_input: String::new(),
             task_creating: false,
             task_creation_focus: TaskCreationField::Name,
             assigned_filter_active: false,
+            show_archived: false,
+            task_sort_mode: TaskSortMode::default(),
             current_user_id: None,
             cached_list_members: std::collections::HashMap::new(),
+            cached_workspace_views: std::collections::HashMap::new(),
+            cached_list_statuses: std::collections::HashMap::new(),
+            cached_time_in_status: std::collections::HashMap::new(),
+            cached_folder_statuses: std::collections::HashMap::new(),
             assignee_picker_open: false,
             assignee_picker_members: Vec::new(),
             assignee_picker_selected: std::collections::HashSet::new(),
@@ -985,6 +1804,12 @@ impl TuiApp {
             url_input_text: String::new(),
             url_input_error: None,
             url_input_cursor: 0,
+            name_prompt: None,
+            view_picker: None,
+            comment_assignee_picker: None,
+            tag_color_picker: None,
+            active_view: None,
+            queued_requests: 0,
             navigating: false,
             navigating_level: String::new(),
         };
@@ -1014,12 +1839,33 @@ impl TuiApp {
             // Handle async messages first
             self.process_async_messages();
 
-            // Handle input
-            if let Some(event) = self.handle_input()? {
-                match event {
-                    InputEvent::Key(_) | InputEvent::Resize => self.update(event),
-                    InputEvent::None => {}
+            // Handle every event that queued up, in order, before rendering
+            let events = read_events(Duration::from_millis(16))?;
+            let had_input = !events.is_empty();
+            self.handle_events(events);
+
+            self.tick(std::time::Instant::now());
+
+            // The pager needs the terminal itself, so it runs outside update()
+            if let Some(text) = self.pending_pager.take() {
+                let command = self.config.pager_command();
+                if let Err(e) = terminal::run_pager(terminal, &command, &text) {
+                    self.last_error = Some(error_chain(&e));
+                    self.show_toast(format!("Pager failed: {}", e));
                 }
+                self.render(terminal)?;
+                *last_render = std::time::Instant::now();
+            }
+
+            // So does the editor; the temp file is written and read back around it
+            if let Some(text) = self.pending_editor.take() {
+                let command = self.config.editor_command();
+                let result = external_editor::edit_text(&text, |path| {
+                    terminal::while_suspended(terminal, || external_editor::launch(&command, path))
+                });
+                self.finish_description_edit(&text, result);
+                self.render(terminal)?;
+                *last_render = std::time::Instant::now();
             }
 
             // Check if update() signaled to quit (dialog confirmation moved to update())
@@ -1030,8 +1876,8 @@ impl TuiApp {
                 break;
             }
 
-            // Render at target frame rate
-            if last_render.elapsed() >= render_interval {
+            // Render once per batch of input, and otherwise at the target frame rate
+            if had_input || last_render.elapsed() >= render_interval {
                 self.render(terminal)?;
                 *last_render = std::time::Instant::now();
             }
@@ -1050,23 +1896,23 @@ impl TuiApp {
                 messages.push(msg);
             }
 
+            let received = !messages.is_empty();
+            let mut tasks_arrived = false;
+
             // Now process messages without holding the borrow
             for msg in messages {
                 match msg {
                     AppMessage::WorkspacesLoaded(result) => {
                         self.loading = false;
+                        self.finish_load(Collection::Workspaces, &result);
                         match result {
                             Ok(workspaces) => {
+                                // Re-caching the startup copy would keep it fresh forever
+                                if !std::mem::take(&mut self.workspaces_from_cache) {
+                                    self.cache_workspaces(&workspaces);
+                                }
                                 self.workspaces = workspaces.clone();
-                                // Populate sidebar with workspaces
-                                let mut items = Vec::new();
-                                items.extend(self.workspaces.iter().map(|w| {
-                                    SidebarItem::Workspace {
-                                        name: w.name.clone(),
-                                        id: w.id.clone(),
-                                    }
-                                }));
-                                *self.sidebar.items_mut() = items;
+                                let kept = self.show_workspaces_sidebar();
 
                                 // Check if we're restoring a session
                                 if self.restoring_session {
@@ -1098,21 +1944,24 @@ impl TuiApp {
                                         // No workspace ID saved, stay at Workspaces
                                         self.restoring_session = false;
                                         self.sidebar.select_first();
+                                        self.auto_select_workspace =
+                                            self.config.auto_select_single_workspace;
                                         self.status = format!(
                                             "Loaded {} workspace(s)",
                                             self.workspaces.len()
                                         );
                                     }
                                 } else {
-                                    // Normal behavior (not restoring)
-                                    // Check if we have a current_workspace_id from navigation context
-                                    if let Some(ref workspace_id) = self.current_workspace_id {
-                                        if !self.sidebar.select_by_id(workspace_id) {
-                                            // Workspace not found, fallback to first
-                                            self.sidebar.select_first();
-                                        }
-                                    } else {
-                                        self.sidebar.select_first();
+                                    // Normal behavior (not restoring): a reload keeps the
+                                    // selection, otherwise use the navigation context or
+                                    // the last session's workspace
+                                    if let Some(ref workspace_id) = self
+                                        .current_workspace_id
+                                        .clone()
+                                        .or_else(|| self.last_workspace_id.take())
+                                        .filter(|_| !kept)
+                                    {
+                                        self.sidebar.select_by_id(workspace_id);
                                     }
                                     self.status =
                                         format!("Loaded {} workspace(s)", self.workspaces.len());
@@ -1121,6 +1970,39 @@ impl TuiApp {
                                 self.state = AppState::Main;
                                 // Clear any previous error state
                                 self.error = None;
+
+                                if self.pending_start_screen == Some(StartScreen::MyTasks) {
+                                    self.pending_start_screen = None;
+                                    self.open_my_tasks();
+                                }
+
+                                if self.is_verifying_onboarding_token() {
+                                    let token = std::mem::take(&mut self.auth_state.token_input);
+                                    if !token.is_empty() {
+                                        if let Err(e) = self.auth.save_token(&token) {
+                                            self.error =
+                                                Some(format!("Failed to save token: {}", e));
+                                        }
+                                    }
+                                    self.auth_state.cursor_pos = 0;
+                                    self.auth_state.loading = false;
+                                    self.auth_state.workspace_names =
+                                        self.workspaces.iter().map(|w| w.name.clone()).collect();
+                                    self.auth_state.next_step();
+                                } else {
+                                    self.open_pending_auth_link();
+                                    self.auto_select_single_workspace();
+                                }
+                            }
+                            Err(e) if self.is_verifying_onboarding_token() => {
+                                // Token rejected: nothing was saved, so let the user fix it
+                                self.client = None;
+                                self.state = AppState::Unauthenticated;
+                                self.auth_state.loading = false;
+                                self.auth_state.error =
+                                    Some(format!("Could not verify token: {}", e));
+                                self.auth_state.previous_step();
+                                self.status = "Authentication failed".to_string();
                             }
                             Err(e) => {
                                 self.loading = false;
@@ -1132,10 +2014,73 @@ impl TuiApp {
                             }
                         }
                     }
-                    AppMessage::SpacesLoaded(result) => {
+                    AppMessage::WorkspacesRefreshed(result) => match result {
+                        Ok(workspaces) => {
+                            self.cache_workspaces(&workspaces);
+                            if workspaces != self.workspaces {
+                                tracing::debug!("Workspaces changed since they were cached");
+                                self.workspaces = workspaces;
+                                if self.screen == Screen::Workspaces {
+                                    // Keeps the selection when the workspace is still there
+                                    self.show_workspaces_sidebar();
+                                }
+                            }
+                        }
+                        // The cached workspaces stay up; the next load tries again
+                        Err(e) => tracing::warn!("Failed to refresh workspaces: {}", e),
+                    },
+                    AppMessage::SpacesLoaded(_, ticket)
+                        if !self.is_current_load(LoadKind::Spaces, &ticket) =>
+                    {
+                        self.discard_stale_load(LoadKind::Spaces, &ticket);
+                    }
+                    AppMessage::SpaceContentsLoaded(_, ticket)
+                        if !self.is_current_load(LoadKind::SpaceContents, &ticket) =>
+                    {
+                        self.discard_stale_load(LoadKind::SpaceContents, &ticket);
+                    }
+                    AppMessage::ListsLoaded(_, ticket)
+                        if !self.is_current_load(LoadKind::Lists, &ticket) =>
+                    {
+                        self.discard_stale_load(LoadKind::Lists, &ticket);
+                    }
+                    AppMessage::TasksLoaded(_, ticket)
+                        if !self.is_current_load(LoadKind::Tasks, &ticket) =>
+                    {
+                        self.discard_stale_load(LoadKind::Tasks, &ticket);
+                    }
+                    AppMessage::TaskReceived(_, ticket)
+                        if !self.is_current_load(LoadKind::Tasks, &ticket) => {}
+                    AppMessage::TaskReceived(task, ticket) => {
+                        // A reload keeps showing the tasks it refreshes until
+                        // it completes; another list fills in as tasks arrive
+                        if self.tasks_list_id.as_deref() == Some(ticket.context_id.as_str()) {
+                            match &mut self.reloaded_tasks {
+                                Some((id, tasks)) if *id == ticket.request_id => tasks.push(*task),
+                                _ => self.reloaded_tasks = Some((ticket.request_id, vec![*task])),
+                            }
+                        } else {
+                            if self.streamed_load != Some(ticket.request_id) {
+                                self.streamed_load = Some(ticket.request_id);
+                                self.tasks.clear();
+                                if self.due_day_filter_on(&Screen::Tasks) {
+                                    self.due_day_filter = None;
+                                }
+                            }
+                            self.tasks.push(*task);
+                            tasks_arrived = true;
+                        }
+                    }
+                    AppMessage::SpacesLoaded(result, ticket) => {
+                        self.pending_loads.remove(&LoadKind::Spaces);
                         self.loading = false;
+                        self.finish_load(Collection::Spaces, &result);
                         match result {
                             Ok(spaces) => {
+                                self.workspace_features.insert(
+                                    ticket.context_id,
+                                    Features::of_spaces(spaces.iter().map(|s| &s.features)),
+                                );
                                 self.spaces = spaces.clone();
                                 // Populate sidebar with spaces
                                 let mut items = Vec::new();
@@ -1143,7 +2088,7 @@ impl TuiApp {
                                     name: s.name.clone(),
                                     id: s.id.clone(),
                                 }));
-                                *self.sidebar.items_mut() = items;
+                                let kept = self.sidebar.replace_items(items);
 
                                 // Check if we're restoring a session
                                 if self.restoring_session {
@@ -1157,7 +2102,7 @@ impl TuiApp {
                                                 .find(|s| s.id == restored_id)
                                                 .map(|s| s.name.clone())
                                                 .unwrap_or_default();
-                                            self.load_folders(restored_id.clone());
+                                            self.load_space_contents(restored_id.clone());
                                             self.screen = Screen::Folders;
                                             self.screen_title = generate_screen_title(&space_name);
                                         } else {
@@ -1177,15 +2122,12 @@ impl TuiApp {
                                             format!("Loaded {} space(s)", self.spaces.len());
                                     }
                                 } else {
-                                    // Normal behavior (not restoring)
-                                    // Check if we have a current_space_id from navigation context
-                                    if let Some(ref space_id) = self.current_space_id {
-                                        if !self.sidebar.select_by_id(space_id) {
-                                            // Space not found, fallback to first
-                                            self.sidebar.select_first();
-                                        }
-                                    } else {
-                                        self.sidebar.select_first();
+                                    // Normal behavior (not restoring): a reload keeps the
+                                    // selection, otherwise use the navigation context
+                                    if let Some(ref space_id) =
+                                        self.current_space_id.clone().filter(|_| !kept)
+                                    {
+                                        self.sidebar.select_by_id(space_id);
                                     }
                                     self.status = format!("Loaded {} space(s)", self.spaces.len());
                                 }
@@ -1203,18 +2145,16 @@ impl TuiApp {
                             }
                         }
                     }
-                    AppMessage::FoldersLoaded(result) => {
+                    AppMessage::SpaceContentsLoaded(result, _) => {
+                        self.pending_loads.remove(&LoadKind::SpaceContents);
                         self.loading = false;
+                        self.finish_load(Collection::SpaceContents, &result);
                         match result {
-                            Ok(folders) => {
-                                self.folders = folders.clone();
-                                // Populate sidebar with folders
-                                let mut items = Vec::new();
-                                items.extend(self.folders.iter().map(|f| SidebarItem::Folder {
-                                    name: f.name.clone(),
-                                    id: f.id.clone(),
-                                }));
-                                *self.sidebar.items_mut() = items;
+                            Ok(contents) => {
+                                self.folders = contents.folders;
+                                self.space_lists = contents.lists;
+                                self.space_documents = contents.documents;
+                                let kept = self.populate_space_sidebar();
 
                                 // Check if we're restoring a session
                                 if self.restoring_session {
@@ -1240,26 +2180,34 @@ impl TuiApp {
                                                 .to_string();
                                             tracing::warn!("Restored folder {} not found, falling back to Folders", restored_id);
                                         }
+                                    } else if let Some(list) = self
+                                        .restored_list_id
+                                        .as_ref()
+                                        .and_then(|id| self.space_lists.iter().find(|l| &l.id == id))
+                                        .cloned()
+                                    {
+                                        // Folderless list saved, go straight to its tasks
+                                        self.sidebar.select_by_id(&list.id);
+                                        self.current_list_id = Some(list.id.clone());
+                                        self.load_tasks(list.id.clone());
+                                        self.screen = Screen::Tasks;
+                                        self.screen_title =
+                                            generate_screen_title(&format!("Tasks: {}", list.name));
                                     } else {
                                         // No folder ID saved, stay at Folders
                                         self.restoring_session = false;
                                         self.sidebar.select_first();
-                                        self.status =
-                                            format!("Loaded {} folder(s)", self.folders.len());
+                                        self.status = self.space_contents_status();
                                     }
                                 } else {
-                                    // Normal behavior (not restoring)
-                                    // Check if we have a current_folder_id from navigation context
-                                    if let Some(ref folder_id) = self.current_folder_id {
-                                        if !self.sidebar.select_by_id(folder_id) {
-                                            // Folder not found, fallback to first
-                                            self.sidebar.select_first();
-                                        }
-                                    } else {
-                                        self.sidebar.select_first();
+                                    // Normal behavior (not restoring): a reload keeps the
+                                    // selection, otherwise use the navigation context
+                                    if let Some(ref folder_id) =
+                                        self.current_folder_id.clone().filter(|_| !kept)
+                                    {
+                                        self.sidebar.select_by_id(folder_id);
                                     }
-                                    self.status =
-                                        format!("Loaded {} folder(s)", self.folders.len());
+                                    self.status = self.space_contents_status();
                                 }
 
                                 // Clear any previous error state
@@ -1275,8 +2223,53 @@ impl TuiApp {
                             }
                         }
                     }
-                    AppMessage::ListsLoaded(result) => {
+                    AppMessage::DocumentLoaded(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(doc) => self.show_document_content(doc),
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load document: {}", e));
+                                self.status = "Failed to load document".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::DocumentsSearched(_, generation)
+                        if generation != self.documents_browser.generation => {}
+                    AppMessage::DocumentsSearched(result, _) => {
+                        self.documents_browser.loading = false;
+                        match result {
+                            Ok(docs) => {
+                                self.status = format!("{} document(s)", docs.len());
+                                self.documents_browser.set_docs(docs);
+                                self.error = None;
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to search documents: {}", e));
+                                self.status = "Failed to search documents".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::SprintOverviewLoaded(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(overview) => {
+                                self.status = format!(
+                                    "Sprint: {}/{} points completed",
+                                    overview.completed_points, overview.total_points
+                                );
+                                self.sprint.overview = Some(overview);
+                                self.update_screen_title();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load sprint: {}", e));
+                                self.status = "Failed to load sprint".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::ListsLoaded(result, _) => {
+                        self.pending_loads.remove(&LoadKind::Lists);
                         self.loading = false;
+                        self.finish_load(Collection::Lists, &result);
                         match result {
                             Ok(lists) => {
                                 self.lists = lists.clone();
@@ -1286,7 +2279,7 @@ impl TuiApp {
                                     name: l.name.clone(),
                                     id: l.id.clone(),
                                 }));
-                                *self.sidebar.items_mut() = items;
+                                let kept = self.sidebar.replace_items(items);
 
                                 // Check if we're restoring a session
                                 if self.restoring_session {
@@ -1326,15 +2319,12 @@ impl TuiApp {
                                             format!("Loaded {} list(s)", self.lists.len());
                                     }
                                 } else {
-                                    // Normal behavior (not restoring)
-                                    // Check if we have a current_list_id from navigation context
-                                    if let Some(ref list_id) = self.current_list_id {
-                                        if !self.sidebar.select_by_id(list_id) {
-                                            // List not found, fallback to first
-                                            self.sidebar.select_first();
-                                        }
-                                    } else {
-                                        self.sidebar.select_first();
+                                    // Normal behavior (not restoring): a reload keeps the
+                                    // selection, otherwise use the navigation context
+                                    if let Some(ref list_id) =
+                                        self.current_list_id.clone().filter(|_| !kept)
+                                    {
+                                        self.sidebar.select_by_id(list_id);
                                     }
                                     self.status = format!("Loaded {} list(s)", self.lists.len());
                                 }
@@ -1352,14 +2342,71 @@ impl TuiApp {
                             }
                         }
                     }
-                    AppMessage::TasksLoaded(result) => {
+                    AppMessage::TasksLoaded(result, ticket) => {
+                        self.pending_loads.remove(&LoadKind::Tasks);
                         self.loading = false;
+                        // Tasks shown as they arrived keep the selection made meanwhile
+                        let streamed = self.streamed_load.take() == Some(ticket.request_id);
+                        let reloaded_tasks = self
+                            .reloaded_tasks
+                            .take()
+                            .filter(|(id, _)| *id == ticket.request_id)
+                            .map(|(_, tasks)| tasks);
+                        // Streamed loads sent their tasks one by one (TaskReceived)
+                        let result = result.map(|mut pages| {
+                            if streamed {
+                                pages.tasks = std::mem::take(&mut self.tasks);
+                            } else if let Some(tasks) = reloaded_tasks {
+                                pages.tasks = tasks;
+                            }
+                            pages
+                        });
+                        // A failed load shows the list's cached tasks, if there are any
+                        let mut stale = None;
+                        let result = match result {
+                            Ok(pages) => {
+                                // A filtered subset would stand in for the whole list offline
+                                if self.task_filter().is_none() {
+                                    if let Err(e) = self.cache.cache_list_tasks(&ticket.context_id, &pages.tasks) {
+                                        tracing::warn!("Failed to cache tasks of {}: {}", ticket.context_id, e);
+                                    }
+                                }
+                                Ok(pages)
+                            }
+                            Err(e) => match self.cached_list_tasks(&ticket.context_id) {
+                                Some((tasks, fetched_at)) => {
+                                    tracing::warn!("Failed to load tasks, showing cached: {}", e);
+                                    stale = Some((e, fetched_at));
+                                    Ok(TaskPages {
+                                        tasks,
+                                        truncated: false,
+                                    })
+                                }
+                                None => Err(e),
+                            },
+                        };
+                        self.finish_load(Collection::Tasks, &result);
                         match result {
-                            Ok(tasks) => {
+                            Ok(pages) => {
                                 // Store tasks as source of truth
-                                self.tasks = tasks;
-                                // Build grouped task list
-                                self.task_list = GroupedTaskList::from_tasks(self.tasks.clone());
+                                self.tasks = pages.tasks;
+                                // Build grouped task list; a reload of the same
+                                // list keeps the selected task and calendar day
+                                let reloaded = self.tasks_list_id.as_deref()
+                                    == Some(ticket.context_id.as_str());
+                                if !reloaded && self.due_day_filter_on(&Screen::Tasks) {
+                                    self.due_day_filter = None;
+                                }
+                                let rebuilt = GroupedTaskList::from_tasks_sorted_by(
+                                    self.shown_tasks(),
+                                    self.task_sort_mode,
+                                );
+                                if reloaded || streamed {
+                                    self.task_list.refresh_from(rebuilt);
+                                } else {
+                                    self.task_list = rebuilt;
+                                }
+                                self.tasks_list_id = Some(ticket.context_id);
 
                                 // Check if we're restoring a session
                                 if self.restoring_session {
@@ -1401,13 +2448,23 @@ impl TuiApp {
                                         );
                                     }
                                 } else {
-                                    self.task_list.select_first();
+                                    if !reloaded && self.task_list.selected_task().is_none() {
+                                        self.task_list.select_first();
+                                    }
                                     self.status = format!(
                                         "Loaded {} task(s)",
                                         self.task_list.rows().iter().filter(|r| matches!(r, ListRow::Task(_))).count()
                                     );
                                 }
 
+                                if pages.truncated {
+                                    self.status = format!(
+                                        "Showing first {} tasks (more available)",
+                                        self.tasks.len()
+                                    );
+                                }
+                                self.check_unread_comments();
+
                                 // Clear any previous error state
                                 self.error = None;
                             }
@@ -1417,15 +2474,34 @@ impl TuiApp {
                                 self.status = "Failed to load tasks".to_string();
                                 // Clear tasks on error to prevent stale data
                                 self.tasks.clear();
+                                self.tasks_list_id = None;
                                 self.task_list = GroupedTaskList::new();
                                 if self.restoring_session {
                                     self.restoring_session = false;
                                 }
                             }
                         }
+                        if let Some((e, fetched_at)) = stale {
+                            self.status = format!(
+                                "⚠ Showing cached tasks from {}: {}",
+                                format_age(chrono::Utc::now().timestamp() - fetched_at),
+                                e
+                            );
+                        }
+                    }
+                    AppMessage::CommentsLoaded(_, generation)
+                        if generation != self.comments_generation =>
+                    {
+                        // Superseded by a newer load, or the task detail was closed
+                        tracing::debug!(
+                            "Discarding stale comments (generation {}, current {})",
+                            generation,
+                            self.comments_generation
+                        );
                     }
-                    AppMessage::CommentsLoaded(result) => {
+                    AppMessage::CommentsLoaded(result, _) => {
                         self.loading = false;
+                        self.comments_loading = false;
                         match result {
                             Ok(comments) => {
                                 tracing::debug!("Loaded {} comments", comments.all_comments.len());
@@ -1438,24 +2514,342 @@ impl TuiApp {
                                         comment.commenter.as_ref().map(|c| &c.username)
                                     );
                                 }
+                                self.cache_loaded_comments(&comments.all_comments);
+                                self.comments_cached_at = None;
                                 self.comment_top_level_count = comments.top_level_comments;
                                 self.comments = comments.all_comments;
-                                self.comment_selected_index = 0;
+                                self.comments_cursor = comments.next_cursor;
+                                // Start on the newest comment
+                                self.comment_selected_index = self.newest_comment_index();
+                                self.focus_comments_if_opening();
                                 self.error = None;
                                 self.status = format!("Loaded {} comment(s)", self.comments.len());
+                                self.loaded_threads.clear();
+                                self.loaded_threads.extend(comments.prefetched_threads);
+                                self.reply_prefetch.clear();
+                                // A reload (e.g. after replying) drops fetched replies
+                                if let CommentViewMode::InThread { parent_comment_id, .. } =
+                                    &self.comment_view_mode
+                                {
+                                    self.load_thread_replies(parent_comment_id.clone());
+                                }
                             }
                             Err(e) => {
-                                self.error = Some(format!("Failed to load comments: {}", e));
-                                self.status = "Failed to load comments".to_string();
-                                self.comments.clear();
+                                self.comments_cursor = None;
+                                // Stale comments beat an empty panel
+                                if let Some((cached, fetched_at)) = self.cached_task_comments() {
+                                    tracing::warn!("Failed to load comments, showing cached: {}", e);
+                                    self.comment_top_level_count =
+                                        cached.iter().filter(|c| c.parent_id.is_none()).count();
+                                    self.loaded_threads =
+                                        cached.iter().filter_map(|c| c.parent_id.clone()).collect();
+                                    self.reply_prefetch.clear();
+                                    self.comments = cached;
+                                    self.comment_selected_index = self.newest_comment_index();
+                                    self.focus_comments_if_opening();
+                                    self.comments_cached_at = Some(fetched_at);
+                                    self.status = format!("Failed to load comments: {}", e);
+                                } else {
+                                    self.error = Some(format!("Failed to load comments: {}", e));
+                                    self.status = "Failed to load comments".to_string();
+                                    self.comments.clear();
+                                    self.comments_cached_at = None;
+                                }
+                            }
+                        }
+                    }
+                    AppMessage::MoreCommentsLoaded(_, generation)
+                        if generation != self.comments_generation =>
+                    {
+                        tracing::debug!("Discarding stale comment page (generation {})", generation);
+                    }
+                    AppMessage::MoreCommentsLoaded(result, _) => {
+                        self.loading = false;
+                        self.comments_loading = false;
+                        match result {
+                            Ok(page) => {
+                                let (top_level, replies): (Vec<_>, Vec<_>) = page
+                                    .all_comments
+                                    .into_iter()
+                                    .partition(|c| c.parent_id.is_none());
+                                match self.config.comment_order {
+                                    // Older top-level comments go above the loaded ones; the
+                                    // selection moves with them so the view stays put
+                                    CommentOrder::Oldest => {
+                                        self.comments.splice(0..0, top_level);
+                                        self.comment_selected_index += page.top_level_comments;
+                                        if let Some(previous) =
+                                            self.comment_previous_selection.as_mut()
+                                        {
+                                            *previous += page.top_level_comments;
+                                        }
+                                    }
+                                    CommentOrder::Newest => self.comments.extend(top_level),
+                                }
+                                self.comments.extend(replies);
+                                self.comment_top_level_count += page.top_level_comments;
+                                self.comments_cursor = page.next_cursor;
+                                self.loaded_threads.extend(page.prefetched_threads);
+                                self.status = format!("Loaded {} older comment(s)", page.top_level_comments);
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load more comments: {}", e));
+                                self.status = "Failed to load more comments".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::ThreadRepliesLoaded(_, _, generation)
+                    | AppMessage::ThreadRepliesPrefetched(_, _, generation)
+                        if generation != self.comments_generation =>
+                    {
+                        tracing::debug!("Discarding stale thread replies (generation {})", generation);
+                    }
+                    AppMessage::ThreadRepliesLoaded(parent_id, result, _) => {
+                        self.loading = false;
+                        self.comments_loading = false;
+                        match result {
+                            Ok(replies) => {
+                                let count = self.set_thread_replies(&parent_id, replies);
+                                self.status = format!(
+                                    "Loaded {} repl{}",
+                                    count,
+                                    if count == 1 { "y" } else { "ies" }
+                                );
+                            }
+                            Err(e) => {
+                                // Allow another attempt the next time the thread is opened
+                                self.loaded_threads.remove(&parent_id);
+                                self.error = Some(format!("Failed to load replies: {}", e));
+                                self.status = "Failed to load replies".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::ThreadRepliesPrefetched(parent_id, result, _) => match result {
+                        Ok(replies) => {
+                            self.set_thread_replies(&parent_id, replies);
+                            if let Some(dropped) = self.reply_prefetch.insert(parent_id) {
+                                self.drop_thread_replies(&dropped);
+                            }
+                        }
+                        Err(e) => {
+                            tracing::debug!("Failed to prefetch replies of {}: {}", parent_id, e);
+                            self.loaded_threads.remove(&parent_id);
+                        }
+                    },
+                    AppMessage::UnreadCommentsFound(_, generation)
+                        if generation != self.unread_generation => {}
+                    AppMessage::UnreadCommentsFound(task_id, _) => {
+                        self.task_list.set_unread(&task_id);
+                        self.my_tasks.set_unread(&task_id);
+                        self.my_work.set_unread(&task_id);
+                    }
+                    AppMessage::MyTasksLoaded(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(tasks) => {
+                                self.status = format!("{} task(s) assigned to you", tasks.len());
+                                if self.due_day_filter_on(&Screen::MyTasks) {
+                                    self.due_day_filter = None;
+                                }
+                                self.my_tasks = GroupedTaskList::from_tasks(tasks).with_list_names();
+                                self.error = None;
+                                self.check_unread_comments();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load my tasks: {}", e));
+                                self.status = "Failed to load my tasks".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::ListViewsLoaded(result, list_id) => {
+                        self.loading = false;
+                        // Only for the list still on screen
+                        if self.screen != Screen::Tasks
+                            || self.current_list_id.as_deref() != Some(list_id.as_str())
+                        {
+                            continue;
+                        }
+                        match result {
+                            Ok(views) if views.is_empty() => {
+                                self.status.clear();
+                                self.show_toast("No saved views on this list".to_string());
+                            }
+                            Ok(views) => {
+                                self.status.clear();
+                                let mut picker = PickerState::new("Views", views, |view: &View| {
+                                    match &view.view_type {
+                                        Some(kind) => format!("{} ({})", view.name, kind),
+                                        None => view.name.clone(),
+                                    }
+                                });
+                                if let Some(active) = &self.active_view {
+                                    picker.select_where(|view| view.id == active.id);
+                                }
+                                self.view_picker = Some(picker);
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load views: {}", e));
+                                self.status = "Failed to load views".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::SidebarChildrenLoaded(parent, result) => match result {
+                        Ok(children) => {
+                            self.status.clear();
+                            // The tree may have been left while they loaded
+                            if !self.sidebar.insert_children(&parent, children) {
+                                tracing::debug!("Dropped children of {}", parent.name());
+                            }
+                        }
+                        Err(e) => {
+                            self.error = Some(format!("Failed to load {}: {}", parent.name(), e));
+                            self.status = format!("Failed to load {}", parent.name());
+                        }
+                    },
+                    AppMessage::WorkspaceViewsLoaded(result, workspace_id) => match result {
+                        Ok(views) => {
+                            self.cached_workspace_views
+                                .insert(workspace_id.clone(), views.clone());
+                            // Only for the workspace still on screen
+                            if self.workspace_views.workspace_id == workspace_id {
+                                self.workspace_views.loading = false;
+                                self.status = format!("{} view(s)", views.len());
+                                self.workspace_views.set_views(views);
+                            }
+                        }
+                        Err(e) if self.workspace_views.workspace_id == workspace_id => {
+                            self.workspace_views.loading = false;
+                            self.error = Some(format!("Failed to load views: {}", e));
+                            self.status = "Failed to load views".to_string();
+                        }
+                        Err(e) => tracing::debug!("Dropped views of another workspace: {}", e),
+                    },
+                    AppMessage::WorkspaceViewTasksLoaded(result, view_id) => {
+                        // Only for the view still open
+                        if self.workspace_views.open_view.as_ref().map(|v| v.id.as_str())
+                            != Some(view_id.as_str())
+                        {
+                            continue;
+                        }
+                        self.workspace_views.loading = false;
+                        match result {
+                            Ok(tasks) => {
+                                self.status = format!("{} task(s) in view", tasks.len());
+                                self.workspace_views.tasks =
+                                    GroupedTaskList::from_tasks(tasks).with_list_names();
+                                self.error = None;
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load view tasks: {}", e));
+                                self.status = "Failed to load view tasks".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::SpaceCreated(result, workspace_id) => {
+                        self.loading = false;
+                        match result {
+                            Ok(space) => {
+                                self.show_toast(format!("Created space {}", space.name));
+                                let workspace_name = self
+                                    .workspaces
+                                    .iter()
+                                    .find(|w| w.id == workspace_id)
+                                    .map(|w| w.name.clone())
+                                    .unwrap_or_else(|| "Spaces".to_string());
+                                // Reload the workspace's spaces and land on the new one
+                                self.current_workspace_id = Some(workspace_id.clone());
+                                self.current_space_id = Some(space.id);
+                                self.current_folder_id = None;
+                                self.current_list_id = None;
+                                self.load_spaces(workspace_id);
+                                self.screen = Screen::Spaces;
+                                self.screen_title = generate_screen_title(&workspace_name);
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to create space: {}", e));
+                                self.status = "Failed to create space".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::FolderCreated(result, space_id) => {
+                        self.loading = false;
+                        match result {
+                            Ok(folder) => {
+                                self.show_toast(format!("Created folder {}", folder.name));
+                                let space_name = self
+                                    .spaces
+                                    .iter()
+                                    .find(|s| s.id == space_id)
+                                    .map(|s| s.name.clone())
+                                    .unwrap_or_else(|| "Folders".to_string());
+                                // Reload the space's contents and land on the new folder
+                                self.current_space_id = Some(space_id.clone());
+                                self.current_folder_id = Some(folder.id);
+                                self.current_list_id = None;
+                                self.load_space_contents(space_id);
+                                self.screen = Screen::Folders;
+                                self.screen_title = generate_screen_title(&space_name);
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to create folder: {}", e));
+                                self.status = "Failed to create folder".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::RecentTasksLoaded(result) => match result {
+                        Ok(tasks) => {
+                            for task in &tasks {
+                                if let Err(e) = self.cache.cache_task(task) {
+                                    tracing::warn!("Failed to cache task {}: {}", task.id, e);
+                                }
+                            }
+                            self.recent_tasks.apply_details(&tasks);
+                        }
+                        Err(e) => {
+                            // The cached names are still shown
+                            tracing::warn!("Failed to refresh recent tasks: {}", e);
+                        }
+                    },
+                    AppMessage::MyWorkLoaded(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok((tasks, spaces)) => {
+                                let space_names: std::collections::HashMap<String, String> =
+                                    spaces.into_iter().map(|s| (s.id, s.name)).collect();
+                                self.status = format!("{} task(s) assigned to you", tasks.len());
+                                self.my_work =
+                                    GroupedTaskList::from_tasks_by_location(tasks, &space_names);
+                                self.error = None;
+                                self.check_unread_comments();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to load my work: {}", e));
+                                self.status = "Failed to load my work".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::LinkedReplyFetched(result) => {
+                        self.navigating = false;
+                        self.navigating_level.clear();
+                        let parent = result.ok().and_then(|reply| {
+                            let parent_id = reply.parent_id?;
+                            self.comments.iter().position(|c| c.id == parent_id)
+                        });
+                        match parent {
+                            Some(idx) => {
+                                self.comment_selected_index = idx;
+                                self.status =
+                                    "Comment is a reply in this thread (Enter to open)".to_string();
                             }
+                            None => self.status = "Comment not found in task".to_string(),
                         }
                     }
                     AppMessage::CommentCreated(result, comment_meta) => {
                         self.loading = false;
                         match result {
                             Ok(comment) => {
-                                self.comments.insert(0, comment);
+                                let comment_id = comment.id.clone();
+                                let added = self.insert_comment(comment);
                                 self.comment_new_text.clear();
                                 self.comment_editing_index = None;
                                 self.status = if comment_meta.is_reply {
@@ -1464,9 +2858,17 @@ impl TuiApp {
                                     "Comment added".to_string()
                                 };
 
-                                // refetch comments on comment created
-                                self.load_comments(comment_meta.task_id);
-                                if !comment_meta.is_reply {
+                                // The create response leaves fields out; fetch
+                                // the comment rather than reloading them all
+                                let viewing_task = self
+                                    .task_detail
+                                    .task
+                                    .as_ref()
+                                    .is_some_and(|t| t.id == comment_meta.task_id);
+                                if viewing_task {
+                                    self.refresh_comment(comment_id);
+                                }
+                                if added && !comment_meta.is_reply {
                                     self.comment_top_level_count += 1;
                                 }
                             }
@@ -1487,6 +2889,7 @@ impl TuiApp {
                         self.loading = false;
                         match result {
                             Ok(comment) => {
+                                let comment_id = comment.id.clone();
                                 if let Some(idx) =
                                     self.comments.iter().position(|c| c.id == comment.id)
                                 {
@@ -1495,6 +2898,7 @@ impl TuiApp {
                                 self.comment_new_text.clear();
                                 self.comment_editing_index = None;
                                 self.status = "Comment updated".to_string();
+                                self.refresh_comment(comment_id);
                             }
                             Err(e) => {
                                 self.error = Some(format!("Failed to update comment: {}", e));
@@ -1502,6 +2906,172 @@ impl TuiApp {
                             }
                         }
                     }
+                    AppMessage::CommentRefreshed(_, generation)
+                        if generation != self.comments_generation => {}
+                    AppMessage::CommentRefreshed(result, _) => match result {
+                        Ok(comment) => {
+                            if let Some(existing) =
+                                self.comments.iter_mut().find(|c| c.id == comment.id)
+                            {
+                                *existing = comment;
+                            }
+                        }
+                        // The copy from the create or update response stays
+                        Err(e) => tracing::warn!("Failed to refresh comment: {}", e),
+                    },
+                    AppMessage::CommentAssigned(comment_id, result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(assignee) => {
+                                self.status = format!("Comment assigned to {}", assignee.username);
+                                if let Some(comment) =
+                                    self.comments.iter_mut().find(|c| c.id == comment_id)
+                                {
+                                    comment.assigned_commenter = Some(assignee);
+                                }
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to assign comment: {}", e));
+                                self.status = "Failed to assign comment".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::TaskTagged(task_id, result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(tag) => {
+                                self.status = format!("Tagged with {}", tag.name);
+                                let detail = self.task_detail.task.as_mut().filter(|t| t.id == task_id);
+                                for task in self.tasks.iter_mut().filter(|t| t.id == task_id).chain(detail) {
+                                    if !task.tags.iter().any(|t| t.name == tag.name) {
+                                        task.tags.push(tag.clone());
+                                    }
+                                }
+                                self.rebuild_task_list();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to tag task: {}", e));
+                                self.status = "Failed to tag task".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::TaskLinkAdded(task_id, result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(link) => {
+                                let linked = link.other_task_id(&task_id).unwrap_or_default();
+                                self.status = format!("Linked to {}", linked);
+                                let detail = self.task_detail.task.as_mut().filter(|t| t.id == task_id);
+                                for task in self.tasks.iter_mut().filter(|t| t.id == task_id).chain(detail) {
+                                    if !task.linked_tasks.contains(&link) {
+                                        task.linked_tasks.push(link.clone());
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to link task: {}", e));
+                                self.status = "Failed to link task".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::TaskLinkRemoved(task_id, link_id, result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(()) => {
+                                self.status = format!("Unlinked {}", link_id);
+                                let detail = self.task_detail.task.as_mut().filter(|t| t.id == task_id);
+                                for task in self.tasks.iter_mut().filter(|t| t.id == task_id).chain(detail) {
+                                    task.linked_tasks
+                                        .retain(|l| l.other_task_id(&task_id) != Some(link_id.as_str()));
+                                }
+                                self.clamp_link_selection();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to unlink task: {}", e));
+                                self.status = "Failed to unlink task".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::CommentDeleted(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(comment_id) => {
+                                let task_id = self.task_detail.task.as_ref().map(|t| t.id.clone());
+                                let deleted = self.comments.iter().find(|c| c.id == comment_id);
+                                if let (Some(task_id), Some(comment)) = (task_id, deleted) {
+                                    self.undo_stack.push(UndoAction::CommentDeleted {
+                                        task_id,
+                                        parent_id: comment.parent_id.clone(),
+                                        text: comment.text.clone(),
+                                    });
+                                }
+                                if let Err(e) = self.cache.mark_comment_deleted(&comment_id) {
+                                    tracing::warn!("Failed to tombstone cached comment: {}", e);
+                                }
+                                let was_top_level = self
+                                    .comments
+                                    .iter()
+                                    .any(|c| c.id == comment_id && c.parent_id.is_none());
+                                // Replies go with their parent
+                                self.comments.retain(|c| {
+                                    c.id != comment_id
+                                        && c.parent_id.as_deref() != Some(comment_id.as_str())
+                                });
+                                if was_top_level {
+                                    self.comment_top_level_count =
+                                        self.comment_top_level_count.saturating_sub(1);
+                                }
+                                self.comment_selected_index = self
+                                    .comment_selected_index
+                                    .min(self.comments.len().saturating_sub(1));
+                                self.status = "Comment deleted".to_string();
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to delete comment: {}", e));
+                                self.status = "Failed to delete comment".to_string();
+                            }
+                        }
+                    }
+                    AppMessage::UndoApplied(result, action) => {
+                        self.loading = false;
+                        match result {
+                            Ok(UndoOutcome::Task(restored)) => {
+                                for task in &mut self.tasks {
+                                    if task.id == restored.id {
+                                        *task = (*restored).clone();
+                                        break;
+                                    }
+                                }
+                                self.rebuild_task_list();
+                                if self.task_detail.task.as_ref().is_some_and(|t| t.id == restored.id) {
+                                    self.task_detail.task = Some(*restored);
+                                }
+                                self.show_toast(format!("Undid {}", action.description()));
+                            }
+                            Ok(UndoOutcome::Comment(restored)) => {
+                                let viewing_task = match &action {
+                                    UndoAction::CommentDeleted { task_id, .. } => self
+                                        .task_detail
+                                        .task
+                                        .as_ref()
+                                        .is_some_and(|t| &t.id == task_id),
+                                    UndoAction::StatusChanged { .. } => false,
+                                };
+                                if viewing_task {
+                                    let top_level = restored.parent_id.is_none();
+                                    if self.insert_comment(*restored) && top_level {
+                                        self.comment_top_level_count += 1;
+                                    }
+                                }
+                                self.show_toast(format!("Undid {}", action.description()));
+                            }
+                            Err(e) => {
+                                // Keep the action so the undo can be retried
+                                self.error = Some(format!("Failed to undo {}: {}", action.description(), e));
+                                self.undo_stack.push(action);
+                            }
+                        }
+                    }
                     AppMessage::CurrentUserLoaded(result) => {
                         match result {
                             Ok(user) => {
@@ -1525,7 +3095,8 @@ impl TuiApp {
                             }
                         }
                     }
-                    AppMessage::MembersLoaded(result) => {
+                    AppMessage::MembersLoaded(result, purpose) => {
+                        self.loading = false;
                         match result {
                             Ok(members) => {
                                 // Cache the members
@@ -1533,14 +3104,41 @@ impl TuiApp {
                                     self.cached_list_members
                                         .insert(list_id.clone(), members.clone());
                                 }
-                                // Open the picker
-                                self.open_assignee_picker(members);
+                                self.open_members_picker(members, purpose);
                             }
                             Err(e) => {
                                 self.status = format!("Failed to load members: {}", e);
                             }
                         }
                     }
+                    AppMessage::ListFetched(result) => match result {
+                        Ok(list) => {
+                            if !list.statuses.is_empty() {
+                                let mut statuses = list.statuses.clone();
+                                statuses.sort_by_key(|s| s.orderindex.unwrap_or(u32::MAX));
+                                self.store_list_statuses(list.id.clone(), statuses);
+                            }
+                            if self.current_list_id.as_ref() == Some(&list.id) {
+                                self.fetched_list = Some(list);
+                                if self.screen == Screen::Tasks {
+                                    self.update_screen_title();
+                                }
+                            }
+                        }
+                        Err(e) => tracing::warn!("Failed to fetch the current list: {}", e),
+                    },
+                    AppMessage::ListStatusesLoaded(list_id, result) => match result {
+                        Ok(statuses) => self.store_list_statuses(list_id, statuses),
+                        Err(e) => {
+                            tracing::warn!("Failed to load statuses for list {}: {}", list_id, e);
+                        }
+                    },
+                    AppMessage::FolderStatusesLoaded(folder_id, result) => match result {
+                        Ok(statuses) => self.store_folder_statuses(folder_id, statuses),
+                        Err(e) => {
+                            tracing::warn!("Failed to load statuses for folder {}: {}", folder_id, e);
+                        }
+                    },
                     AppMessage::AssigneesUpdated(result) => {
                         match result {
                             Ok(updated_task) => {
@@ -1563,9 +3161,37 @@ impl TuiApp {
                             }
                         }
                     }
+                    AppMessage::TaskDescriptionSaved(result) => match result {
+                        Ok(updated_task) => {
+                            if let Some(task) =
+                                self.tasks.iter_mut().find(|t| t.id == updated_task.id)
+                            {
+                                *task = updated_task.clone();
+                            }
+                            self.rebuild_task_list();
+                            if self.task_detail.task.as_ref().map(|t| &t.id) == Some(&updated_task.id)
+                            {
+                                self.task_detail.open(updated_task);
+                            }
+                            self.status = "Description saved".to_string();
+                        }
+                        Err(e) => {
+                            self.error = Some(format!("Failed to save description: {}", e));
+                            self.status = "Failed to save description".to_string();
+                        }
+                    },
                     AppMessage::TaskStatusUpdated(result) => {
                         match result {
                             Ok(updated_task) => {
+                                if let Some(previous_status) = self.status_picker_original_status.clone() {
+                                    let new_status = updated_task.status.as_ref().map(|s| s.status.as_str());
+                                    if new_status != Some(previous_status.as_str()) {
+                                        self.undo_stack.push(UndoAction::StatusChanged {
+                                            task_id: updated_task.id.clone(),
+                                            previous_status,
+                                        });
+                                    }
+                                }
                                 // Update the task in the tasks list (app cache)
                                 for task in &mut self.tasks {
                                     if task.id == updated_task.id {
@@ -1577,6 +3203,10 @@ impl TuiApp {
                                 self.rebuild_task_list();
                                 // Update task detail view
                                 self.task_detail.task = Some(updated_task.clone());
+                                self.cached_time_in_status.remove(&updated_task.id);
+                                if self.screen == Screen::TaskDetail {
+                                    self.load_time_in_status(updated_task.id.clone());
+                                }
                                 self.status_picker_open = false;
                                 self.status = "Status updated".to_string();
                             }
@@ -1611,20 +3241,38 @@ impl TuiApp {
                             Ok(task) => {
                                 self.navigating = false;
                                 self.navigating_level.clear();
+                                // Derive navigation context from the task's references
+                                if let Some(team_id) = &task.team_id {
+                                    self.current_workspace_id = Some(team_id.clone());
+                                }
+                                if let Some(space) = &task.space {
+                                    self.current_space_id = Some(space.id.clone());
+                                }
+                                if let Some(folder) = &task.folder {
+                                    self.current_folder_id = Some(folder.id.clone());
+                                }
+                                if let Some(list) = &task.list {
+                                    self.current_list_id = Some(list.id.clone());
+                                }
                                 // Open the task in detail view
-                                self.task_detail.task = Some(task.clone());
+                                self.task_detail.open(task.clone());
                                 self.screen = Screen::TaskDetail;
+                                self.reset_task_detail_focus();
                                 self.comment_view_mode = CommentViewMode::TopLevel;
                                 self.comments.clear();
                                 self.comment_selected_index = 0;
                                 self.status = format!("Navigated to task: {}", task.name);
                                 // Load comments for the task
+                                self.record_task_access(&task);
+                                self.load_time_entries(task.id.clone());
+                                self.load_time_in_status(task.id.clone());
                                 self.load_comments(task.id);
                             }
                             Err(e) => {
                                 self.navigating = false;
                                 self.navigating_level.clear();
                                 self.status = format!("Resource not found: {}", e);
+                                self.show_toast(format!("Could not open task: {}", e));
                                 // Restore previous screen
                                 self.screen = prev_screen;
                             }
@@ -1634,13 +3282,18 @@ impl TuiApp {
                         match result {
                             Ok(task) => {
                                 // Navigate to task detail, then find the comment
-                                self.task_detail.task = Some(task.clone());
+                                self.task_detail.open(task.clone());
                                 self.screen = Screen::TaskDetail;
                                 self.comment_view_mode = CommentViewMode::TopLevel;
                                 self.comments.clear();
                                 self.comment_selected_index = 0;
+                                self.record_task_access(&task);
+                                self.load_time_entries(task.id.clone());
+                                self.load_time_in_status(task.id.clone());
 
                                 // Load comments and then find the target one
+                                self.comments_generation += 1;
+                                let generation = self.comments_generation;
                                 let tx = self.message_tx.clone().unwrap();
                                 let task_id = task.id.clone();
                                 let client = match &self.client {
@@ -1654,18 +3307,20 @@ impl TuiApp {
                                     }
                                 };
                                 tokio::spawn(async move {
-                                    let result = client.get_task_comments(&task_id).await;
+                                    let result = client.get_all_task_comments(&task_id).await;
                                     let msg = match result {
                                         Ok(comments) => {
                                             AppMessage::CommentsLoadedForCommentNavigation(
                                                 Ok(comments),
                                                 comment_id,
+                                                generation,
                                             )
                                         }
                                         Err(e) => {
                                             AppMessage::CommentsLoadedForCommentNavigation(
-                                                Err(e.to_string()),
+                                                Err(error_chain(&e)),
                                                 comment_id,
+                                                generation,
                                             )
                                         }
                                     };
@@ -1685,20 +3340,29 @@ impl TuiApp {
                             Ok(doc) => {
                                 self.navigating = false;
                                 self.navigating_level.clear();
-                                self.documents = vec![doc.clone()];
-                                self.screen = Screen::Document;
-                                self.screen_title = generate_screen_title(&doc.name);
-                                self.status = format!("Navigated to document: {}", doc.name);
+                                let name = doc.name.clone();
+                                self.enter_document_screen(&doc);
+                                self.show_document_content(doc);
+                                self.status = format!("Navigated to document: {}", name);
                             }
                             Err(e) => {
                                 self.navigating = false;
                                 self.navigating_level.clear();
                                 self.status = format!("Document not found: {}", e);
+                                self.show_toast(format!("Could not open document: {}", e));
                                 self.screen = prev_screen;
                             }
                         }
                     }
-                    AppMessage::CommentsLoadedForCommentNavigation(result, comment_id) => {
+                    AppMessage::CommentsLoadedForCommentNavigation(_, _, generation)
+                        if generation != self.comments_generation =>
+                    {
+                        // The task was closed or its comments reloaded meanwhile
+                        tracing::debug!("Discarding stale comments for navigation (generation {})", generation);
+                        self.navigating = false;
+                        self.navigating_level.clear();
+                    }
+                    AppMessage::CommentsLoadedForCommentNavigation(result, comment_id, _) => {
                         match result {
                             Ok(comments) => {
                                 self.comments = comments;
@@ -1717,9 +3381,8 @@ impl TuiApp {
                                         &self.comments[idx].text[..self.comments[idx].text.len().min(40)]
                                     );
                                 } else {
-                                    self.navigating = false;
-                                    self.navigating_level.clear();
-                                    self.status = "Comment not found in task".to_string();
+                                    // Maybe a reply: find the thread it is in
+                                    self.fetch_linked_reply(comment_id);
                                 }
                             }
                             Err(e) => {
@@ -1759,6 +3422,19 @@ impl TuiApp {
                             }
                         }
                     }
+                    AppMessage::TaskCreatedFromComment(result) => {
+                        self.loading = false;
+                        match result {
+                            Ok(task) => {
+                                self.status = format!("Created task {} from comment", task.id);
+                            }
+                            Err(e) => {
+                                self.error =
+                                    Some(format!("Failed to create task from comment: {}", e));
+                                self.status = "Task creation failed".to_string();
+                            }
+                        }
+                    }
                     AppMessage::TaskDeleted(result) => {
                         match result {
                             Ok(task_id) => {
@@ -1775,60 +3451,204 @@ impl TuiApp {
                             }
                         }
                     }
+                    AppMessage::TimeEntriesLoaded(task_id, result) => {
+                        // Ignore results for a task that is no longer open
+                        if self.task_detail.task.as_ref().map(|t| t.id.as_str()) != Some(task_id.as_str()) {
+                            continue;
+                        }
+                        match result {
+                            Ok(entries) => self.task_detail.time_entries = entries,
+                            Err(e) => {
+                                // Time tracking may be disabled for the workspace; not worth an error
+                                tracing::warn!("Failed to load time entries for task {}: {}", task_id, e);
+                            }
+                        }
+                    }
+                    AppMessage::TimeInStatusLoaded(task_id, result) => match result {
+                        Ok(durations) => {
+                            if self.task_detail.task.as_ref().map(|t| t.id.as_str()) == Some(task_id.as_str()) {
+                                self.task_detail.time_in_status = durations.clone();
+                            }
+                            self.cached_time_in_status.insert(task_id, durations);
+                        }
+                        Err(e) => {
+                            // The timeline is extra detail; leave it out rather than raise an error
+                            tracing::warn!("Failed to load time in status for task {}: {}", task_id, e);
+                        }
+                    },
+                    AppMessage::BulkDeleteProgress(batch, done, total) => {
+                        self.tasks.retain(|t| !batch.deleted.contains(&t.id));
+                        self.rebuild_task_list();
+                        self.status = format!("Deleting {} of {} tasks...", done, total);
+                    }
+                    AppMessage::BulkDeleteFinished(result) => {
+                        self.loading = false;
+                        let total = result.deleted.len() + result.failed.len();
+                        match result.failed.first() {
+                            None => {
+                                self.status = format!("Deleted {} tasks", result.deleted.len());
+                            }
+                            Some((task_id, e)) => {
+                                // Failed tasks stay marked so the user can retry
+                                self.error = Some(format!(
+                                    "Failed to delete {} of {} tasks ({}: {})",
+                                    result.failed.len(),
+                                    total,
+                                    task_id,
+                                    e
+                                ));
+                                self.status = format!("Deleted {} of {} tasks", result.deleted.len(), total);
+                            }
+                        }
+                    }
+                    AppMessage::TaskArchived(task_id, archived, result) => {
+                        self.loading = false;
+                        let verb = if archived { "archive" } else { "unarchive" };
+                        match result {
+                            Ok(()) => {
+                                let applied = BulkArchiveResult {
+                                    archived,
+                                    updated: vec![task_id.clone()],
+                                    failed: Vec::new(),
+                                };
+                                applied.apply_to(&mut self.tasks, self.show_archived);
+                                self.rebuild_task_list();
+                                if let Some(task) = self
+                                    .task_detail
+                                    .task
+                                    .as_mut()
+                                    .filter(|t| t.id == task_id)
+                                {
+                                    task.archived = Some(archived);
+                                }
+                                self.status = format!("Task {}d", verb);
+                            }
+                            Err(e) => {
+                                self.error = Some(format!("Failed to {} task: {}", verb, e));
+                                self.status = format!("Failed to {} task", verb);
+                            }
+                        }
+                    }
+                    AppMessage::BulkArchiveFinished(result) => {
+                        self.loading = false;
+                        result.apply_to(&mut self.tasks, self.show_archived);
+                        self.rebuild_task_list();
+                        let verb = if result.archived { "Archived" } else { "Unarchived" };
+                        let total = result.updated.len() + result.failed.len();
+                        match result.failed.first() {
+                            None => {
+                                self.task_list.clear_marks();
+                                self.status = format!("{} {} tasks", verb, result.updated.len());
+                            }
+                            Some((task_id, e)) => {
+                                // Failed tasks stay marked so the user can retry
+                                let failed: std::collections::HashSet<String> =
+                                    result.failed.iter().map(|(id, _)| id.clone()).collect();
+                                self.task_list.restore_marks(&failed);
+                                self.error = Some(format!(
+                                    "Failed to update {} of {} tasks ({}: {})",
+                                    result.failed.len(),
+                                    total,
+                                    task_id,
+                                    e
+                                ));
+                                self.status = format!("{} {} of {} tasks", verb, result.updated.len(), total);
+                            }
+                        }
+                    }
                 }
             }
-        }
-    }
-
-    fn handle_input(&mut self) -> Result<Option<InputEvent>> {
-        use crossterm::event;
 
-        if event::poll(Duration::from_millis(16))? {
-            let evt = event::read()?;
-
-            // Check for quit (Ctrl+Q)
-            if let event::Event::Key(key) = &evt {
-                if is_quit(*key) {
-                    self.dialog.show(DialogType::ConfirmQuit);
-                    return Ok(Some(InputEvent::None));
+            // Show the tasks that arrived so far, once per batch
+            if tasks_arrived && self.streamed_load.is_some() {
+                self.rebuild_task_list();
+                if self.task_list.selected_task().is_none() {
+                    self.task_list.select_first();
                 }
+                self.status = format!("Loading tasks... {} so far", self.tasks.len());
             }
 
-            // Handle dialog input — return the key event so update() can process it
-            // This keeps dialog confirmation testable through the public update() method
-            if self.dialog.is_visible() {
-                if let event::Event::Key(key) = evt {
-                    match key.code {
-                        KeyCode::Left | KeyCode::Right => {
-                            self.dialog.toggle();
-                            return Ok(Some(InputEvent::None));
-                        }
-                        KeyCode::Enter | KeyCode::Esc => {
-                            // Pass through to update() for handling
-                            return Ok(Some(InputEvent::Key(key)));
-                        }
-                        _ => return Ok(Some(InputEvent::None)),
-                    }
-                }
+            // A finished write may have been queued offline
+            if received {
+                self.refresh_queued_requests();
             }
+        }
+    }
 
-            // Convert to InputEvent
-            match evt {
-                event::Event::Key(key) => Ok(Some(InputEvent::Key(key))),
-                event::Event::Resize(_, _) => Ok(Some(InputEvent::Resize)),
-                _ => Ok(Some(InputEvent::None)),
+    /// Apply terminal events in the order they were read (public for testing)
+    ///
+    /// Events after one that quits are dropped.
+    pub fn handle_events(&mut self, events: Vec<event::Event>) {
+        for evt in events {
+            if self.state == AppState::Quitting {
+                break;
+            }
+            match self.input_event(evt) {
+                event @ (InputEvent::Key(_) | InputEvent::Resize) => self.update(event),
+                InputEvent::None => {}
             }
-        } else {
-            Ok(None)
+        }
+    }
+
+    /// Convert a terminal event, handling the keys update() doesn't see
+    fn input_event(&mut self, evt: event::Event) -> InputEvent {
+        // Check for quit (Ctrl+Q)
+        if let event::Event::Key(key) = &evt {
+            if is_quit(*key) {
+                self.dialog.show(self.quit_dialog());
+                return InputEvent::None;
+            }
+        }
+
+        // Handle dialog input — return the key event so update() can process it
+        // This keeps dialog confirmation testable through the public update() method
+        if self.dialog.is_visible() {
+            if let event::Event::Key(key) = evt {
+                match key.code {
+                    KeyCode::Left | KeyCode::Right => {
+                        self.dialog.toggle();
+                        return InputEvent::None;
+                    }
+                    KeyCode::Enter | KeyCode::Esc => {
+                        // Pass through to update() for handling
+                        return InputEvent::Key(key);
+                    }
+                    _ => return InputEvent::None,
+                }
+            }
+        }
+
+        // Convert to InputEvent
+        match evt {
+            event::Event::Key(key) => InputEvent::Key(key),
+            event::Event::Resize(_, _) => InputEvent::Resize,
+            _ => InputEvent::None,
         }
     }
 
     /// Process input event and update state (public for testing)
     pub fn update(&mut self, event: InputEvent) {
-        // When help is visible, handle pagination and close
+        // When help is visible, handle search, pagination and close
         if self.help.visible {
             if let InputEvent::Key(key) = event {
+                if self.help.searching {
+                    match key.code {
+                        KeyCode::Esc => self.help.cancel_search(),
+                        KeyCode::Enter => self.help.finish_search(),
+                        _ => {
+                            let help = &mut self.help;
+                            if LineEditor::new(&mut help.search_query, &mut help.search_cursor).handle_key(key) {
+                                help.scroll = 0;
+                            }
+                        }
+                    }
+                    return;
+                }
                 match key.code {
+                    KeyCode::Char('/') => {
+                        self.help.start_search();
+                        return;
+                    }
                     KeyCode::Char('j') | KeyCode::Down | KeyCode::Right => {
                         self.help.next_page();
                         return;
@@ -1837,6 +3657,14 @@ impl TuiApp {
                         self.help.prev_page();
                         return;
                     }
+                    KeyCode::PageDown => {
+                        self.help.scroll_down();
+                        return;
+                    }
+                    KeyCode::PageUp => {
+                        self.help.scroll_up();
+                        return;
+                    }
                     KeyCode::Esc => {
                         self.help.hide();
                         return;
@@ -1851,9 +3679,29 @@ impl TuiApp {
             }
         }
 
+        if self.error_detail_open {
+            if let InputEvent::Key(key) = event {
+                match key.code {
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        self.error_detail_scroll = self.error_detail_scroll.saturating_add(1);
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        self.error_detail_scroll = self.error_detail_scroll.saturating_sub(1);
+                    }
+                    KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('!') => {
+                        self.error_detail_open = false;
+                    }
+                    _ => {}
+                }
+            }
+            return;
+        }
+
         // Handle help toggle with ?
         if let InputEvent::Key(key) = event {
-            if key.code == KeyCode::Char('?') {
+            if key.code == KeyCode::Char('?') && !self.type_ahead_active() {
+                self.help.status_workflow = self.current_list_statuses().to_vec();
+                self.help.features = self.current_features().cloned().unwrap_or_default();
                 self.help.toggle();
                 return;
             }
@@ -1864,6 +3712,20 @@ impl TuiApp {
                     self.open_url_input_dialog();
                     return;
                 }
+                if leader == KeyCode::Char('g')
+                    && key.code == KeyCode::Char('w')
+                    && self.screen != Screen::Auth
+                {
+                    self.open_my_work();
+                    return;
+                }
+                if leader == KeyCode::Char('g')
+                    && key.code == KeyCode::Char('m')
+                    && self.screen != Screen::Auth
+                {
+                    self.open_my_tasks();
+                    return;
+                }
                 // Non-matching second key: pass through to normal handling below
                 // (the key variable still holds the original KeyEvent)
             } else if key.code == KeyCode::Char('g') && !self.is_text_input_active() {
@@ -1886,6 +3748,30 @@ impl TuiApp {
                 return;
             }
 
+            // Handle the new space/folder name prompt (modal overlay)
+            if self.name_prompt.is_some() {
+                self.handle_name_prompt_input(key);
+                return;
+            }
+
+            // Handle the saved view picker (modal overlay)
+            if self.view_picker.is_some() {
+                self.handle_view_picker_input(key);
+                return;
+            }
+
+            // Handle the comment assignee picker (modal overlay)
+            if self.comment_assignee_picker.is_some() {
+                self.handle_comment_assignee_picker_input(key);
+                return;
+            }
+
+            // Handle the new tag's color picker (modal overlay)
+            if self.tag_color_picker.is_some() {
+                self.handle_tag_color_picker_input(key);
+                return;
+            }
+
             // Handle status picker input (modal overlay)
             if self.status_picker_open {
                 self.handle_status_picker_input(key);
@@ -1899,7 +3785,10 @@ impl TuiApp {
                     KeyCode::Enter => {
                         if self.dialog.confirmed() {
                             match &self.dialog.dialog_type {
-                                Some(DialogType::ConfirmQuit) => {
+                                Some(
+                                    DialogType::ConfirmQuit
+                                    | DialogType::ConfirmQuitWithQueued { .. },
+                                ) => {
                                     // Save session state before quitting
                                     if let Err(e) = self.save_session_state() {
                                         tracing::error!("Failed to save session state: {}", e);
@@ -1913,6 +3802,22 @@ impl TuiApp {
                                     // Delete the selected task
                                     self.delete_selected_task();
                                 }
+                                Some(DialogType::ConfirmBulkDelete { .. }) => {
+                                    self.bulk_delete_marked_tasks();
+                                }
+                                Some(DialogType::ConfirmArchive {
+                                    task_id, archive, ..
+                                }) => {
+                                    self.archive_task(task_id.clone(), *archive);
+                                }
+                                Some(DialogType::ConfirmCommentDelete { comment_id, .. }) => {
+                                    self.delete_comment(comment_id.clone());
+                                }
+                                Some(DialogType::ConfirmRemoveLink {
+                                    task_id, link_id, ..
+                                }) => {
+                                    self.remove_task_link(task_id.clone(), link_id.clone());
+                                }
                                 _ => {}
                             }
                         }
@@ -1941,6 +3846,30 @@ impl TuiApp {
                 self.copy_url();
                 return;
             }
+
+            if key.code == KeyCode::Char('!') && self.screen != Screen::Auth {
+                self.open_error_detail();
+                return;
+            }
+
+            if key.code == KeyCode::Char('r')
+                && key.modifiers.contains(KeyModifiers::CONTROL)
+                && self.screen != Screen::Auth
+            {
+                self.open_recent_tasks();
+                return;
+            }
+
+            if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                self.copy_debug_snapshot();
+                return;
+            }
+
+            let ctrl_z = key.code == KeyCode::Char('z') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if (ctrl_z || key.code == KeyCode::Char('U')) && self.screen != Screen::Auth {
+                self.undo_last_action();
+                return;
+            }
         }
 
         match self.screen {
@@ -1951,15 +3880,43 @@ impl TuiApp {
             Screen::Tasks => self.update_tasks(event),
             Screen::TaskDetail => self.update_task_detail(event),
             Screen::Document => self.update_document(event),
+            // Keys reach the browser through handle_text_input
+            Screen::Documents => {}
+            Screen::Sprint => self.update_sprint(event),
+            Screen::MyTasks => self.update_my_tasks(event),
+            Screen::MyWork => self.update_my_work(event),
+            Screen::RecentTasks => self.update_recent_tasks(event),
+            Screen::WorkspaceViews => self.update_workspace_views(event),
+            Screen::Calendar => self.update_calendar(event),
         }
     }
 
     fn update_auth(&mut self, event: InputEvent) {
+        if self.auth_state.wizard && self.auth_state.onboarding_step != STEP_TOKEN {
+            self.update_onboarding(event);
+            return;
+        }
+
         if let InputEvent::Key(key) = event {
             match key.code {
+                KeyCode::Esc
+                    if self.auth_state.wizard && self.auth_state.token_input.is_empty() =>
+                {
+                    self.auth_state.error = None;
+                    self.auth_state.previous_step();
+                }
                 KeyCode::Esc => {
                     self.auth_state.clear();
                 }
+                KeyCode::Char('y')
+                    if self.auth_state.wizard && key.modifiers.contains(KeyModifiers::CONTROL) =>
+                {
+                    let message = match self.clipboard.copy_text(CLICKUP_API_SETTINGS_URL) {
+                        Ok(()) => format!("Copied: {}", CLICKUP_API_SETTINGS_URL),
+                        Err(e) => format!("Failed to copy URL: {}", e),
+                    };
+                    self.show_toast(message);
+                }
                 KeyCode::Enter if !self.auth_state.loading => {
                     self.authenticate();
                 }
@@ -1969,11 +3926,7 @@ impl TuiApp {
                         Ok(mut clipboard) => {
                             match clipboard.get_text() {
                                 Ok(text) => {
-                                    // Insert clipboard content at cursor position
-                                    for c in text.chars() {
-                                        self.auth_state.add_char(c);
-                                    }
-                                    self.status = "Pasted from clipboard".to_string();
+                                    self.paste_on_auth_screen(&text);
                                 }
                                 Err(_) => {
                                     self.status =
@@ -1986,19 +3939,21 @@ impl TuiApp {
                         }
                     }
                 }
-                KeyCode::Char(c) => {
-                    self.auth_state.add_char(c);
-                }
-                KeyCode::Backspace => {
-                    self.auth_state.remove_char();
+                _ => {
+                    self.auth_state.editor().handle_key(key);
                 }
-                _ => {}
             }
         }
     }
 
     fn update_navigation(&mut self, event: InputEvent) {
         if let InputEvent::Key(key) = event {
+            if self.sidebar.is_tree()
+                && self.screen == Screen::Workspaces
+                && self.update_sidebar_tree(key.code)
+            {
+                return;
+            }
             match key.code {
                 KeyCode::Char('j') | KeyCode::Down => {
                     self.sidebar.select_next();
@@ -2015,14 +3970,212 @@ impl TuiApp {
                 KeyCode::Tab => {
                     self.sidebar.visible = !self.sidebar.visible;
                 }
+                KeyCode::Char('d')
+                    if matches!(self.screen, Screen::Workspaces | Screen::Spaces) =>
+                {
+                    self.open_documents();
+                }
+                KeyCode::Char('n')
+                    if matches!(self.screen, Screen::Workspaces | Screen::Spaces) =>
+                {
+                    self.open_name_prompt();
+                }
+                KeyCode::Char('V') if self.screen == Screen::Workspaces => {
+                    self.open_workspace_views();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Handle a key on the sidebar tree; returns false for keys it leaves to
+    /// the normal navigation bindings
+    fn update_sidebar_tree(&mut self, code: KeyCode) -> bool {
+        let on_list = matches!(self.sidebar.selected_item(), Some(SidebarItem::List { .. }));
+        match code {
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if on_list => {
+                self.open_tree_list()
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if let Some(item) = self.sidebar.toggle_selected() {
+                    self.load_tree_children(item);
+                }
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                if let Some(item) = self.sidebar.expand_selected() {
+                    self.load_tree_children(item);
+                }
+            }
+            KeyCode::Char('h') | KeyCode::Left => self.sidebar.collapse_selected(),
+            KeyCode::Char('L') => self.sidebar.expand_all(),
+            KeyCode::Char('H') => self.sidebar.collapse_all(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// Show the workspaces in the sidebar, at the top of the tree in tree
+    /// mode; returns whether the selected item was kept
+    fn show_workspaces_sidebar(&mut self) -> bool {
+        let items = self.workspaces.iter().map(SidebarItem::workspace).collect();
+        if self.config.ui.sidebar_tree {
+            self.sidebar.set_tree_roots(items)
+        } else {
+            self.sidebar.replace_items(items)
+        }
+    }
+
+    /// Fetch the children of a tree item expanded for the first time
+    fn load_tree_children(&mut self, parent: SidebarItem) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.status = format!("Loading {}...", parent.name());
+        tokio::spawn(async move {
+            let list_item = |list: &List| SidebarItem::List {
+                name: list.name.clone(),
+                id: list.id.clone(),
+            };
+            let result = async {
+                let children: Vec<SidebarItem> = match &parent {
+                    SidebarItem::Workspace { id, .. } => client
+                        .get_spaces(id)
+                        .await?
+                        .iter()
+                        .map(|space| SidebarItem::Space {
+                            name: space.name.clone(),
+                            id: space.id.clone(),
+                        })
+                        .collect(),
+                    // Folders first, then the folderless lists, as on the Folders screen
+                    SidebarItem::Space { id, .. } => {
+                        let (folders, lists) = tokio::try_join!(
+                            client.get_folders(id),
+                            client.get_lists_in_space(id, None),
+                        )?;
+                        folders
+                            .iter()
+                            .map(|folder| SidebarItem::Folder {
+                                name: folder.name.clone(),
+                                id: folder.id.clone(),
+                            })
+                            .chain(lists.iter().map(list_item))
+                            .collect()
+                    }
+                    SidebarItem::Folder { id, .. } => client
+                        .get_lists_in_folder(id, None)
+                        .await?
+                        .iter()
+                        .map(list_item)
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                anyhow::Ok(children)
+            }
+            .await;
+            let msg = AppMessage::SidebarChildrenLoaded(parent, result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Open the tasks of the list selected in the tree, taking the
+    /// workspace, space and folder from the items it's nested under
+    fn open_tree_list(&mut self) {
+        let path = self.sidebar.path_to_selected();
+        let Some(SidebarItem::List { id, name }) = path.last().cloned() else {
+            return;
+        };
+        self.current_workspace_id = None;
+        self.current_space_id = None;
+        self.current_folder_id = None;
+        for item in &path {
+            match item {
+                SidebarItem::Workspace { id, .. } => self.current_workspace_id = Some(id.clone()),
+                SidebarItem::Space { id, .. } => self.current_space_id = Some(id.clone()),
+                SidebarItem::Folder { id, .. } => self.current_folder_id = Some(id.clone()),
                 _ => {}
             }
         }
+        self.current_list_id = Some(id.clone());
+        self.load_tasks(id);
+        self.screen = Screen::Tasks;
+        self.screen_title = generate_screen_title(&format!("Tasks: {}", name));
+    }
+
+    /// Handle a key in the document browser: typing edits the search, which
+    /// re-runs once typing pauses
+    fn handle_documents_input(&mut self, key: crossterm::event::KeyEvent) {
+        let now = std::time::Instant::now();
+        match key.code {
+            KeyCode::Down => self.documents_browser.docs.select_next(),
+            KeyCode::Up => self.documents_browser.docs.select_previous(),
+            KeyCode::Enter => {
+                if let Some(doc) = self.documents_browser.docs.selected().cloned() {
+                    self.open_document(doc);
+                }
+            }
+            KeyCode::Esc => self.navigate_back(),
+            _ => {
+                let browser = &mut self.documents_browser;
+                let before = browser.query.clone();
+                LineEditor::new(&mut browser.query, &mut browser.cursor).handle_key(key);
+                if browser.query != before {
+                    browser.search.schedule(now);
+                }
+            }
+        }
+    }
+
+    /// Handle a key while type-ahead is active.
+    ///
+    /// Returns true when the key was consumed; other keys end type-ahead and
+    /// fall through to the normal task list bindings.
+    fn update_type_ahead(&mut self, key: crossterm::event::KeyEvent) -> bool {
+        let now = std::time::Instant::now();
+        if self.type_ahead.is_expired(now) {
+            self.type_ahead.cancel();
+            self.status.clear();
+            return false;
+        }
+
+        if key.code == KeyCode::Esc {
+            self.type_ahead.cancel();
+            self.status.clear();
+            return true;
+        }
+        let before = self.type_ahead.buffer().to_string();
+        if !self.type_ahead.edit(key, now) {
+            self.type_ahead.cancel();
+            self.status.clear();
+            return false;
+        }
+        if self.type_ahead.buffer() != before
+            && !self.type_ahead.buffer().is_empty()
+            && !self.task_list.select_by_name_prefix(self.type_ahead.buffer())
+        {
+            self.status = format!("Jump: {} (no match)", self.type_ahead.buffer());
+            return true;
+        }
+
+        if self.type_ahead.is_active() {
+            self.status = format!("Jump: {}", self.type_ahead.buffer());
+        } else {
+            self.status.clear();
+        }
+        true
     }
 
     fn update_tasks(&mut self, event: InputEvent) {
         if let InputEvent::Key(key) = event {
+            if self.type_ahead.is_active() && self.update_type_ahead(key) {
+                return;
+            }
             match key.code {
+                KeyCode::Char(TYPE_AHEAD_LEADER) => {
+                    self.type_ahead.start(std::time::Instant::now());
+                    self.status = "Jump: ".to_string();
+                }
                 KeyCode::Char('j') | KeyCode::Down => {
                     self.task_list.select_next();
                 }
@@ -2031,10 +4184,15 @@ impl TuiApp {
                 }
                 KeyCode::Enter => {
                     if let Some(task) = self.task_list.selected_task().cloned() {
-                        self.task_detail.task = Some(task.clone());
+                        self.task_detail.open(task.clone());
+                        self.task_detail_return_screen = None;
                         self.screen = Screen::TaskDetail;
+                        self.reset_task_detail_focus();
                         self.update_screen_title();
                         // Load comments for this task
+                        self.record_task_access(&task);
+                        self.load_time_entries(task.id.clone());
+                        self.load_time_in_status(task.id.clone());
                         self.load_comments(task.id.clone());
                     }
                 }
@@ -2051,18 +4209,59 @@ impl TuiApp {
                     self.screen_title = generate_screen_title("New Task");
                     self.status = "Enter task name (Ctrl+S to create, Esc to cancel)".to_string();
                 }
-                KeyCode::Char('e') => {
-                    if self.task_detail.task.is_some() {
-                        self.task_detail.editing = true;
-                        self.screen = Screen::TaskDetail;
-                        self.update_screen_title();
+                KeyCode::Char('e') if self.task_detail.task.is_some() => {
+                    if !self.focused_task_capabilities().edit {
+                        self.deny_read_only("edit this task");
+                        return;
                     }
+                    self.task_detail.editing = true;
+                    self.screen = Screen::TaskDetail;
+                    self.update_screen_title();
                 }
-                KeyCode::Char('d') => {
-                    if self.task_list.selected_task().is_some() {
-                        self.dialog.show(DialogType::ConfirmDelete);
+                KeyCode::Char('d')
+                    if key.modifiers.contains(KeyModifiers::CONTROL) && self.task_list.has_marks() =>
+                {
+                    let task_names = self
+                        .task_list
+                        .marked_tasks()
+                        .iter()
+                        .map(|t| t.name.clone())
+                        .collect();
+                    self.dialog.show(DialogType::ConfirmBulkDelete { task_names });
+                }
+                KeyCode::Char('a')
+                    if key.modifiers.contains(KeyModifiers::CONTROL) && self.task_list.has_marks() =>
+                {
+                    self.bulk_archive_marked_tasks();
+                }
+                KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.toggle_show_archived();
+                }
+                KeyCode::Char('H') => self.toggle_show_archived(),
+                KeyCode::Char('D') => self.toggle_task_list_density(),
+                KeyCode::Char('d') if self.task_list.selected_task().is_some() => {
+                    if !self.focused_task_capabilities().delete {
+                        self.deny_read_only("delete this task");
+                        return;
+                    }
+                    self.dialog.show(DialogType::ConfirmDelete);
+                }
+                KeyCode::Char(' ') if self.task_list.selected_task().is_some() => {
+                    self.task_list.toggle_mark_selected();
+                    let marked = self.task_list.marked_tasks().len();
+                    if marked == 0 {
+                        self.status.clear();
+                    } else {
+                        self.status = format!("{} marked", marked);
                     }
                 }
+                KeyCode::Esc if self.task_list.has_marks() => {
+                    self.task_list.clear_marks();
+                    self.status.clear();
+                }
+                KeyCode::Esc if self.due_day_filter_on(&Screen::Tasks) => {
+                    self.set_due_day_filter(None);
+                }
                 KeyCode::Char('a') => {
                     // Toggle "Assigned to Me" filter
                     self.assigned_filter_active = !self.assigned_filter_active;
@@ -2079,9 +4278,16 @@ impl TuiApp {
                 KeyCode::Char('s') => {
                     // Open status picker if a task is selected
                     if let Some(task) = self.task_list.selected_task().cloned() {
+                        if !task.capabilities().edit {
+                            self.deny_read_only("change this task's status");
+                            return;
+                        }
                         self.open_status_picker(task);
                     }
                 }
+                KeyCode::Char('o') => self.cycle_task_sort(),
+                KeyCode::Char('V') => self.load_list_views(),
+                KeyCode::Char('c') => self.open_calendar(),
                 KeyCode::Esc => {
                     self.navigate_back();
                 }
@@ -2098,31 +4304,16 @@ impl TuiApp {
         if self.assignee_picker_open {
             if let InputEvent::Key(key) = event {
                 match key.code {
-                    KeyCode::Char('j') => {
+                    KeyCode::Char('j')
                         if self.assignee_picker_cursor
-                            < self.assignee_picker_members.len().saturating_sub(1)
-                        {
-                            self.assignee_picker_cursor += 1;
-                        }
-                    }
-                    KeyCode::Char('k') => {
-                        if self.assignee_picker_cursor > 0 {
-                            self.assignee_picker_cursor -= 1;
-                        }
+                            < self.assignee_picker_members.len().saturating_sub(1) =>
+                    {
+                        self.assignee_picker_cursor += 1;
                     }
-                    KeyCode::Char(' ') => {
-                        // Toggle selection of current member
-                        if let Some(member) = self
-                            .assignee_picker_members
-                            .get(self.assignee_picker_cursor)
-                        {
-                            if self.assignee_picker_selected.contains(&member.id) {
-                                self.assignee_picker_selected.remove(&member.id);
-                            } else {
-                                self.assignee_picker_selected.insert(member.id);
-                            }
-                        }
+                    KeyCode::Char('k') if self.assignee_picker_cursor > 0 => {
+                        self.assignee_picker_cursor -= 1;
                     }
+                    KeyCode::Char(' ') => self.toggle_picked_assignee(),
                     KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                         // Save assignees
                         self.save_assignees();
@@ -2161,6 +4352,7 @@ impl TuiApp {
                         self.task_description_input.clear();
                         self.task_creating = false;
                         self.task_detail.creating = false;
+                        self.comment_collapse.expanded.clear();
                         self.screen = Screen::Tasks;
                         self.update_screen_title();
                         self.status = "Task creation cancelled".to_string();
@@ -2300,6 +4492,11 @@ impl TuiApp {
                 }
             }
 
+            if self.task_detail.links_focused {
+                self.handle_links_input(key);
+                return;
+            }
+
             // Handle normal task detail and comment navigation
             match key.code {
                 KeyCode::Esc => {
@@ -2318,39 +4515,106 @@ impl TuiApp {
                         self.comment_editing_index = None;
                         self.status = "Comment editing cancelled".to_string();
                     } else {
-                        // Exit task detail view
-                        self.task_detail.editing = false;
-                        self.screen = Screen::Tasks;
-                        self.update_screen_title();
+                        self.navigate_back();
                     }
                 }
+                KeyCode::Char('e')
+                    if key.modifiers.contains(KeyModifiers::CONTROL) && !self.comment_focus =>
+                {
+                    if !self.task_detail.editing {
+                        self.status = "Press e to edit the task first".to_string();
+                        return;
+                    }
+                    self.edit_description_externally();
+                }
                 KeyCode::Char('e') if !self.comment_focus => {
+                    if !self.focused_task_capabilities().edit {
+                        self.deny_read_only("edit this task");
+                        return;
+                    }
                     self.task_detail.editing = true;
                 }
+                KeyCode::Char('|') => {
+                    self.open_in_pager();
+                }
                 KeyCode::Char('d') => {
+                    if !self.focused_task_capabilities().delete {
+                        self.deny_read_only("delete this task");
+                        return;
+                    }
                     self.dialog.show(DialogType::ConfirmDelete);
                 }
                 KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    // Save task - not yet implemented
-                    self.task_detail.editing = false;
-                    self.status = "Save task - coming soon".to_string();
+                    if self.task_detail.staged_text().is_some() {
+                        self.save_staged_description();
+                    } else {
+                        self.task_detail.editing = false;
+                        self.status = "No changes to save".to_string();
+                    }
                 }
                 KeyCode::Char('s') => {
                     // Open status picker for the current task
                     if let Some(task) = &self.task_detail.task {
+                        if !task.capabilities().edit {
+                            self.deny_read_only("change this task's status");
+                            return;
+                        }
                         self.open_status_picker(task.clone());
                     } else {
                         self.status = "No task selected".to_string();
                     }
                 }
                 KeyCode::Char('A') if !self.comment_focus => {
+                    if !self.focused_task_capabilities().edit {
+                        self.deny_read_only("change this task's assignees");
+                        return;
+                    }
                     // Open assignee picker
                     self.open_assignee_picker_flow();
                 }
+                KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    let Some(task) = &self.task_detail.task else {
+                        return;
+                    };
+                    if !self.focused_task_capabilities().edit {
+                        self.deny_read_only("archive this task");
+                        return;
+                    }
+                    self.dialog.show(DialogType::ConfirmArchive {
+                        task_id: task.id.clone(),
+                        task_name: task.name.clone(),
+                        archive: task.archived != Some(true),
+                    });
+                }
+                KeyCode::Char('T') if !self.comment_focus => {
+                    if !self.focused_task_capabilities().edit {
+                        self.deny_read_only("tag this task");
+                        return;
+                    }
+                    if !self.offers(Feature::Tags) {
+                        self.deny_missing_feature(Feature::Tags);
+                        return;
+                    }
+                    self.open_tag_prompt();
+                }
+                KeyCode::Char('l') if !self.comment_focus && self.task_detail.task.is_some() => {
+                    self.task_detail.links_focused = true;
+                    self.task_detail.link_selected = 0;
+                    self.status = "Related tasks (j/k select, n link, d unlink, Esc done)".to_string();
+                }
+                KeyCode::Char('M') if !self.comment_focus => {
+                    self.task_detail.raw_description = !self.task_detail.raw_description;
+                    self.status = if self.task_detail.raw_description {
+                        "Description: raw source".to_string()
+                    } else {
+                        "Description: rendered markdown".to_string()
+                    };
+                }
                 // Comment navigation
                 KeyCode::Tab => {
                     // Toggle focus between task form and comments
                     self.comment_focus = !self.comment_focus;
+                    self.comment_focus_on_load = false;
                     self.status = if self.comment_focus {
                         "Focus: Comments (j/k navigate, n new, e edit)".to_string()
                     } else {
@@ -2387,7 +4651,20 @@ impl TuiApp {
                         self.comment_selected_index = self.comment_top_level_count - 1;
                     }
                 }
+                KeyCode::Char('L')
+                    if self.comment_focus && self.comment_view_mode == CommentViewMode::TopLevel =>
+                {
+                    if self.comments_cursor.is_some() {
+                        self.load_more_comments();
+                    } else {
+                        self.status = "No older comments".to_string();
+                    }
+                }
                 KeyCode::Char('n') if self.comment_focus => {
+                    if !self.focused_task_capabilities().comment {
+                        self.deny_read_only("comment on this task");
+                        return;
+                    }
                     // Start new comment
                     self.comment_new_text.clear();
                     // usize::MAX is a sentinel value indicating "new comment" mode
@@ -2395,58 +4672,70 @@ impl TuiApp {
                     self.comment_editing_index = Some(usize::MAX);
                     self.status = "Type comment (Ctrl+S save, Esc cancel)".to_string();
                 }
-                KeyCode::Char('e') if self.comment_focus => {
-                    // Edit selected comment (only in top-level view)
-                    if !self.comments.is_empty()
-                        && self.comment_selected_index < self.comments.len()
-                    {
-                        // Check if user owns the comment
-                        let comment = &self.comments[self.comment_selected_index];
-                        // For now, allow editing any comment (will add ownership check later)
-                        self.comment_new_text = comment.text.clone();
-                        self.comment_editing_index = Some(self.comment_selected_index);
-                        self.status = "Editing comment (Ctrl+S save, Esc cancel)".to_string();
+                KeyCode::Char('e')
+                    if self.comment_focus
+                        && self.comment_selected_index < self.comments.len() =>
+                {
+                    // Edit selected comment
+                    let comment = &self.comments[self.comment_selected_index];
+                    if !self.can_change_comment(comment) {
+                        self.deny_read_only("edit this comment");
+                        return;
                     }
+                    self.comment_new_text = comment.text.clone();
+                    self.comment_editing_index = Some(self.comment_selected_index);
+                    self.status = "Editing comment (Ctrl+S save, Esc cancel)".to_string();
+                }
+                KeyCode::Char(' ')
+                    if self.comment_focus && self.comment_selected_index < self.comments.len() =>
+                {
+                    let id = self.comments[self.comment_selected_index].id.clone();
+                    self.comment_collapse.toggle(&id);
                 }
-                KeyCode::Enter if self.comment_focus => {
+                KeyCode::Enter
+                    if self.comment_focus
+                        && matches!(self.comment_view_mode, CommentViewMode::TopLevel)
+                        && self.comment_selected_index < self.comments.len() =>
+                {
                     // Enter thread view when on a top-level comment
-                    if matches!(self.comment_view_mode, CommentViewMode::TopLevel) {
-                        if !self.comments.is_empty()
-                            && self.comment_selected_index < self.comments.len()
-                        {
-                            let comment = &self.comments[self.comment_selected_index];
-                            // Only enter thread if this is a top-level comment
-                            if comment.parent_id.is_none() {
-                                // Store current selection for when we exit
-                                self.comment_previous_selection = Some(self.comment_selected_index);
-
-                                // Get author name for breadcrumb
-                                let author = comment
-                                    .commenter
-                                    .as_ref()
-                                    .map(|c| c.username.clone())
-                                    .unwrap_or_else(|| "Unknown".to_string());
-
-                                // Switch to thread view
-                                self.comment_view_mode = CommentViewMode::InThread {
-                                    parent_comment_id: comment.id.clone(),
-                                    parent_author: author,
-                                };
+                    let comment = &self.comments[self.comment_selected_index];
+                    // Only enter thread if this is a top-level comment
+                    if comment.parent_id.is_none() {
+                        // Store current selection for when we exit
+                        self.comment_previous_selection = Some(self.comment_selected_index);
+
+                        // Get author name for breadcrumb
+                        let author = comment
+                            .commenter
+                            .as_ref()
+                            .map(|c| c.username.clone())
+                            .unwrap_or_else(|| "Unknown".to_string());
+
+                        // Switch to thread view
+                        let comment_id = comment.id.clone();
+                        self.comment_view_mode = CommentViewMode::InThread {
+                            parent_comment_id: comment_id.clone(),
+                            parent_author: author,
+                        };
 
-                                // Set selection to the parent comment's index (not 0)
-                                // This ensures the parent comment is selected when entering thread
-                                self.comment_previous_selection = Some(self.comment_selected_index);
-                                // Keep the same index since we're selecting the parent comment
-                                // The rendering will show the parent comment first in the filtered view
+                        // Set selection to the parent comment's index (not 0)
+                        // This ensures the parent comment is selected when entering thread
+                        self.comment_previous_selection = Some(self.comment_selected_index);
+                        // Keep the same index since we're selecting the parent comment
+                        // The rendering will show the parent comment first in the filtered view
 
-                                self.status = format!("Viewing thread. Press Esc to go back");
-                            }
-                        }
+                        self.status = "Viewing thread. Press Esc to go back".to_string();
+                        self.reply_prefetch.touch(&comment_id);
+                        self.load_thread_replies(comment_id);
                     }
                 }
                 KeyCode::Char('r') if self.comment_focus => {
                     // Reply to thread (only in thread view)
                     if matches!(self.comment_view_mode, CommentViewMode::InThread { .. }) {
+                        if !self.focused_task_capabilities().comment {
+                            self.deny_read_only("reply on this task");
+                            return;
+                        }
                         self.comment_new_text.clear();
                         // usize::MAX is a sentinel value indicating "new reply" mode
                         // (as opposed to Some(index) which means editing existing comment)
@@ -2464,6 +4753,31 @@ impl TuiApp {
                         self.status = "Press Enter to view thread, then 'r' to reply".to_string();
                     }
                 }
+                KeyCode::Char('x') if self.comment_focus => {
+                    if let Some(comment) = self.comments.get(self.comment_selected_index) {
+                        if !self.can_change_comment(comment) {
+                            self.deny_read_only("delete this comment");
+                            return;
+                        }
+                        if self.config.confirm_comment_delete {
+                            let preview = comment.text.split_whitespace().collect::<Vec<_>>();
+                            self.dialog.show(DialogType::ConfirmCommentDelete {
+                                comment_id: comment.id.clone(),
+                                preview: truncate(&preview.join(" "), COMMENT_DELETE_PREVIEW_CHARS),
+                            });
+                        } else {
+                            self.delete_comment(comment.id.clone());
+                        }
+                    } else {
+                        self.status = "No comment selected".to_string();
+                    }
+                }
+                KeyCode::Char('t') if self.comment_focus => {
+                    self.create_task_from_selected_comment();
+                }
+                KeyCode::Char('a') if self.comment_focus => {
+                    self.open_comment_assignee_picker_flow();
+                }
                 _ => {}
             }
         }
@@ -2486,16 +4800,175 @@ impl TuiApp {
         }
     }
 
-    /// Navigate into the selected item (public for testing)
-    pub fn navigate_into(&mut self) {
-        // Navigate based on current screen and selection
-        // Clone the selected item to avoid borrow checker issues
-        let selected_item = self.sidebar.selected_item().cloned();
-
-        // Handle navigation based on current screen
+    fn update_sprint(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            match key.code {
+                KeyCode::Char('r') => {
+                    let view_id = self.sprint.view_id.clone();
+                    self.load_sprint_overview(view_id);
+                }
+                KeyCode::Esc => {
+                    self.navigate_back();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn update_my_tasks(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.my_tasks.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => self.my_tasks.select_previous(),
+                KeyCode::Enter => {
+                    if let Some(task) = self.my_tasks.selected_task().cloned() {
+                        self.task_detail.open(task.clone());
+                        self.task_detail_return_screen = Some(Screen::MyTasks);
+                        self.screen = Screen::TaskDetail;
+                        self.reset_task_detail_focus();
+                        self.update_screen_title();
+                        self.record_task_access(&task);
+                        self.load_time_entries(task.id.clone());
+                        self.load_time_in_status(task.id.clone());
+                        self.load_comments(task.id);
+                    }
+                }
+                KeyCode::Char('r') => self.open_my_tasks(),
+                KeyCode::Char('c') => self.open_calendar(),
+                KeyCode::Esc if self.due_day_filter_on(&Screen::MyTasks) => {
+                    self.set_due_day_filter(None);
+                }
+                KeyCode::Esc => self.navigate_back(),
+                _ => {}
+            }
+        }
+    }
+
+    fn update_calendar(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            match key.code {
+                KeyCode::Char('h') | KeyCode::Left => self.calendar.move_days(-1),
+                KeyCode::Char('l') | KeyCode::Right => self.calendar.move_days(1),
+                KeyCode::Char('k') | KeyCode::Up => self.calendar.move_days(-7),
+                KeyCode::Char('j') | KeyCode::Down => self.calendar.move_days(7),
+                KeyCode::Char('H') => self.calendar.move_months(-1),
+                KeyCode::Char('L') => self.calendar.move_months(1),
+                KeyCode::Enter => {
+                    let day = self.calendar.selected;
+                    self.navigate_back();
+                    self.set_due_day_filter(Some(day));
+                }
+                KeyCode::Esc => self.navigate_back(),
+                _ => {}
+            }
+        }
+    }
+
+    fn update_my_work(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.my_work.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => self.my_work.select_previous(),
+                KeyCode::Enter => {
+                    if let Some(task) = self.my_work.selected_task().cloned() {
+                        self.task_detail.open(task.clone());
+                        self.task_detail_return_screen = Some(Screen::MyWork);
+                        self.screen = Screen::TaskDetail;
+                        self.reset_task_detail_focus();
+                        self.update_screen_title();
+                        self.record_task_access(&task);
+                        self.load_time_entries(task.id.clone());
+                        self.load_time_in_status(task.id.clone());
+                        self.load_comments(task.id);
+                    }
+                }
+                KeyCode::Char('r') => self.open_my_work(),
+                KeyCode::Esc => self.navigate_back(),
+                _ => {}
+            }
+        }
+    }
+
+    fn update_workspace_views(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            if self.workspace_views.open_view.is_none() {
+                match key.code {
+                    KeyCode::Char('j') | KeyCode::Down => self.workspace_views.views.select_next(),
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        self.workspace_views.views.select_previous()
+                    }
+                    KeyCode::Enter => {
+                        if let Some(view) = self.workspace_views.views.selected().cloned() {
+                            // ClickUp sprints are views; they open as an overview
+                            if view.view_type == "sprint" {
+                                self.open_sprint_overview(view.id);
+                                return;
+                            }
+                            self.workspace_views.open(view);
+                            self.update_screen_title();
+                            self.load_workspace_view_tasks();
+                        }
+                    }
+                    KeyCode::Char('r') => {
+                        let workspace_id = self.workspace_views.workspace_id.clone();
+                        self.load_workspace_views(workspace_id);
+                    }
+                    KeyCode::Esc => self.navigate_back(),
+                    _ => {}
+                }
+                return;
+            }
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.workspace_views.tasks.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => self.workspace_views.tasks.select_previous(),
+                KeyCode::Enter => {
+                    if let Some(task) = self.workspace_views.tasks.selected_task().cloned() {
+                        self.task_detail.open(task.clone());
+                        self.task_detail_return_screen = Some(Screen::WorkspaceViews);
+                        self.screen = Screen::TaskDetail;
+                        self.reset_task_detail_focus();
+                        self.update_screen_title();
+                        self.record_task_access(&task);
+                        self.load_time_entries(task.id.clone());
+                        self.load_time_in_status(task.id.clone());
+                        self.load_comments(task.id);
+                    }
+                }
+                KeyCode::Char('r') => self.load_workspace_view_tasks(),
+                KeyCode::Esc => self.navigate_back(),
+                _ => {}
+            }
+        }
+    }
+
+    fn update_recent_tasks(&mut self, event: InputEvent) {
+        if let InputEvent::Key(key) = event {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => self.recent_tasks.tasks.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => self.recent_tasks.tasks.select_previous(),
+                KeyCode::Enter => {
+                    if let Some(task) = self.recent_tasks.tasks.selected().cloned() {
+                        // Fetching the task restores its workspace, space, folder and list
+                        self.task_detail_return_screen = None;
+                        self.navigate_to_task(task.task_id);
+                    }
+                }
+                KeyCode::Esc => self.navigate_back(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Navigate into the selected item (public for testing)
+    pub fn navigate_into(&mut self) {
+        // Navigate based on current screen and selection
+        // Clone the selected item to avoid borrow checker issues
+        let selected_item = self.sidebar.selected_item().cloned();
+
+        // Handle navigation based on current screen
         match &self.screen {
             Screen::Workspaces => {
-                if let Some(SidebarItem::Workspace { id, name }) = selected_item {
+                if let Some(SidebarItem::Workspace { id, name, .. }) = selected_item {
                     self.current_workspace_id = Some(id.clone());
                     self.current_space_id = None;
                     self.current_folder_id = None;
@@ -2510,20 +4983,34 @@ impl TuiApp {
                     self.current_space_id = Some(id.clone());
                     self.current_folder_id = None;
                     self.current_list_id = None;
-                    self.load_folders(id.clone());
+                    self.load_space_contents(id.clone());
                     self.screen = Screen::Folders;
                     self.screen_title = generate_screen_title(&name);
                 }
             }
-            Screen::Folders => {
-                if let Some(SidebarItem::Folder { id, name, .. }) = selected_item {
+            Screen::Folders => match selected_item {
+                Some(SidebarItem::Folder { id, name }) => {
                     self.current_folder_id = Some(id.clone());
                     self.current_list_id = None;
                     self.load_lists(id.clone());
                     self.screen = Screen::Lists;
                     self.screen_title = generate_screen_title(&name);
                 }
-            }
+                Some(SidebarItem::List { id, name }) => {
+                    // Folderless list: go straight to its tasks
+                    self.current_folder_id = None;
+                    self.current_list_id = Some(id.clone());
+                    self.load_tasks(id.clone());
+                    self.screen = Screen::Tasks;
+                    self.screen_title = generate_screen_title(&format!("Tasks: {}", name));
+                }
+                Some(SidebarItem::Document { id, .. }) => {
+                    if let Some(doc) = self.space_documents.iter().find(|d| d.id == id).cloned() {
+                        self.open_document(doc);
+                    }
+                }
+                _ => {}
+            },
             Screen::Lists => {
                 if let Some(SidebarItem::List { id, name, .. }) = selected_item {
                     self.current_list_id = Some(id.clone());
@@ -2538,6 +5025,19 @@ impl TuiApp {
 
     /// Navigate back to previous screen (public for testing)
     pub fn navigate_back(&mut self) {
+        // Results for the screen being left must not land on the one we return to
+        match self.screen {
+            Screen::Spaces => self.cancel_load(LoadKind::Spaces),
+            Screen::Folders => self.cancel_load(LoadKind::SpaceContents),
+            Screen::Lists => self.cancel_load(LoadKind::Lists),
+            Screen::Tasks => {
+                self.cancel_load(LoadKind::Tasks);
+                self.cancel_unread_check();
+            }
+            Screen::MyTasks | Screen::MyWork => self.cancel_unread_check(),
+            _ => {}
+        }
+
         match self.screen {
             Screen::Auth => {}       // Can't go back from auth
             Screen::Workspaces => {} // Can't go back from workspaces
@@ -2548,12 +5048,7 @@ impl TuiApp {
                 self.current_list_id = None;
 
                 // Repopulate sidebar with workspaces
-                let mut items = Vec::new();
-                items.extend(self.workspaces.iter().map(|w| SidebarItem::Workspace {
-                    name: w.name.clone(),
-                    id: w.id.clone(),
-                }));
-                *self.sidebar.items_mut() = items;
+                self.show_workspaces_sidebar();
 
                 // Restore selection using current_workspace_id
                 if let Some(ref workspace_id) = self.current_workspace_id {
@@ -2610,13 +5105,8 @@ impl TuiApp {
                 // Navigate back to Folders
                 self.current_list_id = None;
 
-                // Repopulate sidebar with folders
-                let mut items = Vec::new();
-                items.extend(self.folders.iter().map(|f| SidebarItem::Folder {
-                    name: f.name.clone(),
-                    id: f.id.clone(),
-                }));
-                *self.sidebar.items_mut() = items;
+                // Repopulate sidebar with the space contents
+                self.populate_space_sidebar();
 
                 // Restore selection using current_folder_id
                 if let Some(ref folder_id) = self.current_folder_id {
@@ -2630,16 +5120,24 @@ impl TuiApp {
                 }
 
                 self.screen = Screen::Folders;
-                // Update title to show space name
-                if let Some(ref space_id) = self.current_space_id {
-                    if let Some(space) = self.spaces.iter().find(|s| &s.id == space_id) {
-                        self.screen_title = generate_screen_title(&space.name);
-                    } else {
-                        self.screen_title = generate_screen_title("Spaces");
-                    }
-                } else {
-                    self.screen_title = generate_screen_title("Spaces");
+                self.update_screen_title();
+            }
+            Screen::Tasks if self.sidebar.is_tree() => {
+                // Opened from the tree, which still has the list selected
+                self.current_list_id = None;
+                self.screen = Screen::Workspaces;
+                self.screen_title = generate_screen_title("Workspaces");
+            }
+            Screen::Tasks if self.current_folder_id.is_none() && self.current_space_id.is_some() => {
+                // Folderless list: navigate back to the space contents
+                let list_id = self.current_list_id.take();
+                self.populate_space_sidebar();
+                if !list_id.is_some_and(|id| self.sidebar.select_by_id(&id)) {
+                    self.sidebar.select_first();
                 }
+
+                self.screen = Screen::Folders;
+                self.update_screen_title();
             }
             Screen::Tasks => {
                 // Navigate back to Lists
@@ -2677,14 +5175,84 @@ impl TuiApp {
                 }
             }
             Screen::TaskDetail => {
+                self.cancel_comment_load();
+                self.mark_comments_viewed();
+                // Drop any unsaved description and what was expanded on this task
+                self.task_detail.editing = false;
+                self.task_detail.staged_description = None;
+                self.task_detail.links_focused = false;
+                self.comment_collapse.expanded.clear();
+                if let Some(screen) = self.task_detail_return_screen.take() {
+                    self.screen = screen;
+                    self.update_screen_title();
+                    return;
+                }
                 // Navigate back to Tasks
                 self.screen = Screen::Tasks;
-                if let Some(list) = self.lists.first() {
-                    self.screen_title = generate_screen_title(&format!("Tasks: {}", list.name));
+                // Tasks are not loaded yet when the task was opened directly (deep link)
+                if self.tasks.is_empty() {
+                    if let Some(list_id) = self.current_list_id.clone() {
+                        self.load_tasks(list_id);
+                    }
+                }
+                self.update_screen_title();
+                // The list isn't known when the task was opened directly
+                if self.current_list_name().is_none() {
+                    if let Some(list_id) = self.current_list_id.clone() {
+                        self.fetch_current_list(list_id);
+                    }
                 }
             }
             Screen::Document => {
-                self.screen = Screen::Tasks;
+                self.screen = self.document_return_screen.take().unwrap_or(Screen::Tasks);
+                self.update_screen_title();
+            }
+            Screen::Documents => {
+                self.documents_browser.search.cancel();
+                self.screen = self
+                    .documents_browser_return_screen
+                    .take()
+                    .unwrap_or(Screen::Workspaces);
+                self.update_screen_title();
+            }
+            Screen::Sprint => {
+                self.screen = self.sprint_return_screen.take().unwrap_or(Screen::Tasks);
+                self.update_screen_title();
+            }
+            Screen::MyTasks => {
+                self.screen = self
+                    .my_tasks_return_screen
+                    .take()
+                    .unwrap_or(Screen::Workspaces);
+                self.update_screen_title();
+            }
+            Screen::MyWork => {
+                self.screen = self
+                    .my_work_return_screen
+                    .take()
+                    .unwrap_or(Screen::Workspaces);
+                self.update_screen_title();
+            }
+            Screen::RecentTasks => {
+                self.screen = self
+                    .recent_tasks_return_screen
+                    .take()
+                    .unwrap_or(Screen::Workspaces);
+                self.update_screen_title();
+            }
+            Screen::WorkspaceViews => {
+                if self.workspace_views.open_view.is_some() {
+                    self.workspace_views.close();
+                } else {
+                    self.screen = Screen::Workspaces;
+                }
+                self.update_screen_title();
+            }
+            Screen::Calendar => {
+                self.screen = self
+                    .calendar_return_screen
+                    .clone()
+                    .unwrap_or(Screen::Tasks);
                 self.update_screen_title();
             }
         }
@@ -2703,31 +5271,47 @@ impl TuiApp {
         self.status = "Authenticating...".to_string();
 
         // Create the API client with the token
-        let client = Arc::new(ClickUpClient::new(token.clone()));
+        let client = api_client(&self.config, token.clone(), &self.cache);
 
-        // Save the token
-        if let Err(e) = self.auth.save_token(&token) {
-            self.auth_state.error = Some(format!("Failed to save token: {}", e));
-            self.auth_state.loading = false;
-            self.loading = false;
-            self.status = "Failed to save token".to_string();
-            return;
+        // A new token may belong to another account, whose workspaces differ
+        if let Err(e) = self.cache.clear_workspaces() {
+            tracing::warn!("Failed to clear cached workspaces: {}", e);
+        }
+
+        // Save the token (the wizard saves it only once it has been verified)
+        if !self.auth_state.wizard {
+            if let Err(e) = self.auth.save_token(&token) {
+                self.auth_state.error = Some(format!("Failed to save token: {}", e));
+                self.auth_state.loading = false;
+                self.loading = false;
+                self.status = "Failed to save token".to_string();
+                return;
+            }
         }
 
         // Store the client
         self.client = Some(client.clone());
 
         // Spawn async task to load workspaces
+        self.load_statuses.insert(Collection::Workspaces, LoadStatus::Loading);
         let tx = self.message_tx.clone().unwrap();
         tokio::spawn(async move {
             let result = client.get_workspaces().await;
             let msg = match result {
                 Ok(workspaces) => AppMessage::WorkspacesLoaded(Ok(workspaces)),
-                Err(e) => AppMessage::WorkspacesLoaded(Err(e.to_string())),
+                Err(e) => AppMessage::WorkspacesLoaded(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
 
+        if self.auth_state.wizard {
+            // Stay on the wizard until the workspaces confirm the token works
+            self.auth_state.error = None;
+            self.auth_state.next_step();
+            self.status = "Verifying token...".to_string();
+            return;
+        }
+
         // Clear auth state and navigate to workspaces
         self.auth_state.clear();
         self.auth_state.loading = false;
@@ -2735,33 +5319,150 @@ impl TuiApp {
         self.screen = Screen::Workspaces;
         self.screen_title = generate_screen_title("Workspaces");
         self.status = "Authenticated! Loading workspaces...".to_string();
+        if let Some(start) = self.pending_start_screen.take() {
+            self.apply_start_screen(start);
+        }
+    }
+
+    /// Handle input on the informational onboarding steps
+    fn update_onboarding(&mut self, event: InputEvent) {
+        let InputEvent::Key(key) = event else {
+            return;
+        };
+        match (self.auth_state.onboarding_step, key.code) {
+            (STEP_WELCOME, KeyCode::Enter) => {
+                self.auth_state.next_step();
+            }
+            (STEP_READY, KeyCode::Enter) => {
+                let pending_link = self.auth_state.pending_link.take();
+                self.auth_state = AuthState::new();
+                self.auth_state.pending_link = pending_link;
+                self.screen = Screen::Workspaces;
+                self.screen_title = generate_screen_title("Workspaces");
+                self.status = format!("Loaded {} workspace(s)", self.workspaces.len());
+                self.open_pending_auth_link();
+            }
+            // Verification is in progress; wait for the result
+            _ => {}
+        }
     }
 
     /// Load workspaces from API (public for testing)
+    ///
+    /// Recently cached workspaces are shown straight away instead, and
+    /// refreshed in the background.
     pub fn load_workspaces(&mut self) {
-        self.loading = true;
-        self.status = "Loading workspaces...".to_string();
-
         let client = match &self.client {
             Some(c) => c.clone(),
             None => {
-                self.loading = false;
                 self.error = Some("Not authenticated".to_string());
                 return;
             }
         };
-
+        self.load_statuses.insert(Collection::Workspaces, LoadStatus::Loading);
         let tx = self.message_tx.clone().unwrap();
+
+        if let Some(cached) = self.fresh_cached_workspaces() {
+            tracing::debug!("Showing {} cached workspace(s)", cached.len());
+            self.workspaces_from_cache = true;
+            // Shown without waiting for the runtime when the channel has room;
+            // when it is full, sent ahead of the refresh instead
+            let pending = match tx.try_send(AppMessage::WorkspacesLoaded(Ok(cached))) {
+                Ok(()) => None,
+                Err(mpsc::error::TrySendError::Full(msg)) => Some(msg),
+                Err(mpsc::error::TrySendError::Closed(_)) => return,
+            };
+            tokio::spawn(async move {
+                if let Some(msg) = pending {
+                    let _ = tx.send(msg).await;
+                }
+                let result = client.get_workspaces().await.map_err(|e| error_chain(&e));
+                let _ = tx.send(AppMessage::WorkspacesRefreshed(result)).await;
+            });
+            return;
+        }
+
+        self.loading = true;
+        self.status = "Loading workspaces...".to_string();
         tokio::spawn(async move {
             let result = client.get_workspaces().await;
             let msg = match result {
                 Ok(workspaces) => AppMessage::WorkspacesLoaded(Ok(workspaces)),
-                Err(e) => AppMessage::WorkspacesLoaded(Err(e.to_string())),
+                Err(e) => AppMessage::WorkspacesLoaded(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
     }
 
+    /// Workspaces cached within `workspace_cache_ttl_secs`, if there are any
+    fn fresh_cached_workspaces(&self) -> Option<Vec<Workspace>> {
+        let ttl = self.config.workspace_cache_ttl_secs;
+        if ttl == 0 || !self.cache.is_workspace_cache_valid(ttl).unwrap_or(false) {
+            return None;
+        }
+        self.cache.get_workspaces().ok().filter(|w| !w.is_empty())
+    }
+
+    /// Keep loaded workspaces for the next startup, unless that is turned off
+    fn cache_workspaces(&mut self, workspaces: &[Workspace]) {
+        if self.config.workspace_cache_ttl_secs == 0 {
+            return;
+        }
+        if let Err(e) = self.cache.cache_workspaces(workspaces) {
+            tracing::warn!("Failed to cache workspaces: {}", e);
+        }
+    }
+
+    /// Register a new navigation load, superseding any in-flight load of the same kind
+    fn start_load(&mut self, kind: LoadKind, context_id: &str) -> LoadTicket {
+        self.load_request_counter += 1;
+        let ticket = LoadTicket {
+            request_id: self.load_request_counter,
+            context_id: context_id.to_string(),
+        };
+        self.pending_loads.insert(kind, ticket.clone());
+        self.load_statuses.insert(kind.into(), LoadStatus::Loading);
+        ticket
+    }
+
+    /// Record how a load of `collection` went
+    fn finish_load<T>(&mut self, collection: Collection, result: &Result<T, String>) {
+        let status = match result {
+            Ok(_) => LoadStatus::Loaded,
+            Err(e) => LoadStatus::Failed(e.clone()),
+        };
+        self.load_statuses.insert(collection, status);
+    }
+
+    /// How the latest load of `collection` went (public for testing)
+    pub fn load_status(&self, collection: Collection) -> LoadStatus {
+        self.load_statuses
+            .get(&collection)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Whether a response still belongs to the latest load of its kind
+    fn is_current_load(&self, kind: LoadKind, ticket: &LoadTicket) -> bool {
+        self.pending_loads.get(&kind) == Some(ticket)
+    }
+
+    fn discard_stale_load(&self, kind: LoadKind, ticket: &LoadTicket) {
+        tracing::debug!(
+            "Discarding stale {:?} result for {} (request {})",
+            kind,
+            ticket.context_id,
+            ticket.request_id
+        );
+    }
+
+    /// Forget an in-flight load when the user leaves the screen it was loading for
+    fn cancel_load(&mut self, kind: LoadKind) {
+        if self.pending_loads.remove(&kind).is_some() {
+            self.loading = false;
+        }
+    }
+
     fn load_spaces(&mut self, workspace_id: String) {
         self.loading = true;
         self.status = "Loading spaces...".to_string();
@@ -2775,20 +5476,22 @@ impl TuiApp {
             }
         };
 
+        let ticket = self.start_load(LoadKind::Spaces, &workspace_id);
         let tx = self.message_tx.clone().unwrap();
         tokio::spawn(async move {
             let result = client.get_spaces(&workspace_id).await;
             let msg = match result {
-                Ok(spaces) => AppMessage::SpacesLoaded(Ok(spaces)),
-                Err(e) => AppMessage::SpacesLoaded(Err(e.to_string())),
+                Ok(spaces) => AppMessage::SpacesLoaded(Ok(spaces), ticket),
+                Err(e) => AppMessage::SpacesLoaded(Err(error_chain(&e)), ticket),
             };
             let _ = tx.send(msg).await;
         });
     }
 
-    fn load_folders(&mut self, space_id: String) {
+    /// Load the children of a space: folders, folderless lists and docs
+    fn load_space_contents(&mut self, space_id: String) {
         self.loading = true;
-        self.status = "Loading folders...".to_string();
+        self.status = "Loading space...".to_string();
 
         let client = match &self.client {
             Some(c) => c.clone(),
@@ -2799,78 +5502,611 @@ impl TuiApp {
             }
         };
 
+        let ticket = self.start_load(LoadKind::SpaceContents, &space_id);
         let tx = self.message_tx.clone().unwrap();
         tokio::spawn(async move {
-            let result = client.get_folders(&space_id).await;
-            let msg = match result {
-                Ok(folders) => AppMessage::FoldersLoaded(Ok(folders)),
-                Err(e) => AppMessage::FoldersLoaded(Err(e.to_string())),
+            let doc_filters = crate::models::DocumentFilters {
+                space_id: Some(space_id.clone()),
+                ..Default::default()
+            };
+            let (folders, lists, documents) = tokio::join!(
+                client.get_folders(&space_id),
+                client.get_lists_in_space(&space_id, None),
+                client.search_docs(&doc_filters),
+            );
+
+            // Docs are a best-effort section; a failed search shouldn't hide folders and lists
+            let documents = documents.unwrap_or_else(|e| {
+                tracing::warn!("Failed to load docs for space {}: {}", space_id, e);
+                Vec::new()
+            });
+
+            let msg = match (folders, lists) {
+                (Ok(folders), Ok(lists)) => AppMessage::SpaceContentsLoaded(
+                    Ok(SpaceContents {
+                        folders,
+                        lists,
+                        documents: documents
+                            .into_iter()
+                            .filter(|d| d.folder.is_none())
+                            .collect(),
+                    }),
+                    ticket,
+                ),
+                (Err(e), _) | (_, Err(e)) => {
+                    AppMessage::SpaceContentsLoaded(Err(error_chain(&e)), ticket)
+                }
             };
             let _ = tx.send(msg).await;
         });
     }
 
-    fn load_lists(&mut self, folder_id: String) {
-        self.loading = true;
-        self.status = "Loading lists...".to_string();
+    /// Rebuild the sidebar with the current space's Folders, Lists and Docs sections.
+    /// Empty sections are omitted.
+    /// Returns whether the selected item was kept (see [`SidebarState::replace_items`]).
+    fn populate_space_sidebar(&mut self) -> bool {
+        let mut items = Vec::new();
+
+        if !self.folders.is_empty() {
+            items.push(SidebarItem::Header {
+                label: "Folders".to_string(),
+                count: self.folders.len(),
+            });
+            items.extend(self.folders.iter().map(|f| SidebarItem::Folder {
+                name: f.name.clone(),
+                id: f.id.clone(),
+            }));
+        }
 
-        let client = match &self.client {
-            Some(c) => c.clone(),
-            None => {
-                self.loading = false;
-                self.error = Some("Not authenticated".to_string());
-                return;
-            }
-        };
+        if !self.space_lists.is_empty() {
+            items.push(SidebarItem::Header {
+                label: "Lists".to_string(),
+                count: self.space_lists.len(),
+            });
+            items.extend(self.space_lists.iter().map(|l| SidebarItem::List {
+                name: l.name.clone(),
+                id: l.id.clone(),
+            }));
+        }
 
-        let tx = self.message_tx.clone().unwrap();
-        tokio::spawn(async move {
-            let result = client.get_lists_in_folder(&folder_id, None).await;
-            let msg = match result {
-                Ok(lists) => AppMessage::ListsLoaded(Ok(lists)),
-                Err(e) => AppMessage::ListsLoaded(Err(e.to_string())),
-            };
-            let _ = tx.send(msg).await;
-        });
+        if !self.space_documents.is_empty() {
+            items.push(SidebarItem::Header {
+                label: "Docs".to_string(),
+                count: self.space_documents.len(),
+            });
+            items.extend(self.space_documents.iter().map(|d| SidebarItem::Document {
+                name: d.name.clone(),
+                id: d.id.clone(),
+            }));
+        }
+
+        self.sidebar.replace_items(items)
     }
 
-    /// Load tasks filtered by the current user as assignee
-    fn load_tasks_with_assigned_filter(&mut self, list_id: String) {
-        self.loading = true;
-        self.status = "Loading assigned tasks...".to_string();
+    /// Status line summarising the loaded space contents
+    fn space_contents_status(&self) -> String {
+        format!(
+            "Loaded {} folder(s), {} list(s), {} doc(s)",
+            self.folders.len(),
+            self.space_lists.len(),
+            self.space_documents.len()
+        )
+    }
 
-        let user_id = match self.current_user_id {
-            Some(id) => id,
-            None => {
-                self.loading = false;
-                self.status = "User ID not available for filtering".to_string();
-                return;
-            }
+    /// Open the document browser, listing every doc until a search is typed
+    pub fn open_documents(&mut self) {
+        if self.screen != Screen::Documents {
+            self.documents_browser_return_screen = Some(self.screen.clone());
+        }
+        // Opened from the spaces list, only the highlighted space is searched
+        let space_id = match self.screen {
+            Screen::Spaces => self.sidebar.selected_item().map(|item| item.id().to_string()),
+            _ => None,
         };
+        let generation = self.documents_browser.generation;
+        self.documents_browser = DocumentsState::new();
+        // Keep counting so results for the previous visit are still dropped
+        self.documents_browser.generation = generation;
+        self.documents_browser.space_id = space_id;
+        self.screen = Screen::Documents;
+        self.update_screen_title();
+        self.search_documents();
+    }
+
+    /// Search docs for the browser's current query
+    fn search_documents(&mut self) {
+        self.documents_browser.search.cancel();
+        self.documents_browser.generation += 1;
+        self.status = "Searching documents...".to_string();
 
         let client = match &self.client {
             Some(c) => c.clone(),
             None => {
-                self.loading = false;
                 self.error = Some("Not authenticated".to_string());
                 return;
             }
         };
 
+        self.documents_browser.loading = true;
+        let query = self.documents_browser.query.trim();
+        let filters = crate::models::DocumentFilters {
+            query: (!query.is_empty()).then(|| query.to_string()),
+            space_id: self.documents_browser.space_id.clone(),
+            ..Default::default()
+        };
+        let generation = self.documents_browser.generation;
         let tx = self.message_tx.clone().unwrap();
         tokio::spawn(async move {
-            let result = client
+            let result = client.search_docs(&filters).await;
+            let msg = AppMessage::DocumentsSearched(result.map_err(|e| error_chain(&e)), generation);
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Run work that is due at `now` rather than triggered by input
+    /// (public for testing)
+    pub fn tick(&mut self, now: std::time::Instant) {
+        if self.screen == Screen::Documents && self.documents_browser.search.fire(now) {
+            self.search_documents();
+        }
+        self.prefetch_hovered_replies(now);
+
+        // Transient messages clear after `status_clear_secs`; errors stay
+        if self.status_clock.is_expired(&self.status, now) {
+            self.status.clear();
+        }
+        let toast_expired = self
+            .url_copy_status_time
+            .zip(self.status_clock.timeout())
+            .is_some_and(|(shown, timeout)| now.saturating_duration_since(shown) >= timeout);
+        if toast_expired {
+            self.url_copy_status = None;
+            self.url_copy_status_time = None;
+        }
+    }
+
+    /// Open a document in the document viewer and fetch it with its pages
+    ///
+    /// Search results and sidebar entries only carry the document's metadata,
+    /// so the document itself is always fetched by ID.
+    fn open_document(&mut self, doc: Document) {
+        self.enter_document_screen(&doc);
+        self.load_document(doc.id.clone());
+        self.documents = vec![doc];
+    }
+
+    /// Switch to the document viewer with an empty document titled `doc`'s name
+    fn enter_document_screen(&mut self, doc: &Document) {
+        self.document_return_screen = Some(self.screen.clone());
+        self.document = DocumentState::new();
+        self.document.title = doc.name.clone();
+        self.screen = Screen::Document;
+        self.screen_title = generate_screen_title(&format!("Doc: {}", doc.name));
+    }
+
+    /// Show a fetched document's pages in the document viewer
+    fn show_document_content(&mut self, doc: Document) {
+        self.document.title = doc.name.clone();
+        self.document.content = doc
+            .pages
+            .iter()
+            .map(|p| {
+                p.content_markdown
+                    .clone()
+                    .or_else(|| p.content.clone())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        self.status = format!("Loaded {} page(s)", doc.pages.len());
+        self.documents = vec![doc];
+    }
+
+    /// Queue the open task's description (or the staged edit of it) for
+    /// the external editor
+    fn edit_description_externally(&mut self) {
+        let Some(task) = &self.task_detail.task else {
+            self.status = "No task selected".to_string();
+            return;
+        };
+        let text = self
+            .task_detail
+            .staged_text()
+            .map(str::to_string)
+            .or_else(|| task.description_source())
+            .unwrap_or_default();
+        self.pending_editor = Some(text);
+    }
+
+    /// Take in what the external editor left of `original`: a changed
+    /// description is staged for Ctrl+S, a failed edit is discarded
+    pub fn finish_description_edit(&mut self, original: &str, result: Result<EditOutcome>) {
+        let Some(task_id) = self.task_detail.task.as_ref().map(|t| t.id.clone()) else {
+            return;
+        };
+        match result {
+            Ok(EditOutcome::Changed(text)) => {
+                self.status = format!(
+                    "Description edited: {} (Ctrl+S to save)",
+                    external_editor::change_summary(original, &text)
+                );
+                self.task_detail.staged_description = Some((task_id, text));
+            }
+            Ok(EditOutcome::Unchanged) => {
+                self.status = "Description unchanged".to_string();
+            }
+            Err(e) => {
+                self.last_error = Some(error_chain(&e));
+                self.show_toast(format!("Editor failed, changes discarded: {}", e));
+            }
+        }
+    }
+
+    /// Save the description staged from the external editor
+    fn save_staged_description(&mut self) {
+        let staged = self.task_detail.staged_description.clone();
+        let Some((task_id, description)) = self.task_detail.staged_text().and(staged) else {
+            return;
+        };
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.status = "Not authenticated".to_string();
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        let update = crate::models::UpdateTaskRequest {
+            name: None,
+            description: Some(description),
+            status: None,
+            priority: None,
+            assignees: None,
+            due_date: None,
+            archived: None,
+        };
+        self.status = "Saving description...".to_string();
+        tokio::spawn(async move {
+            let result = client.update_task(&task_id, &update).await;
+            let msg = AppMessage::TaskDescriptionSaved(result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Queue the open task and its comments for the external pager
+    fn open_in_pager(&mut self) {
+        match &self.task_detail.task {
+            Some(task) => {
+                self.pending_pager = Some(task_to_text(task, &self.comments));
+            }
+            None => {
+                self.status = "No task selected".to_string();
+            }
+        }
+    }
+
+    /// Show the most recently opened tasks from the access log
+    pub fn open_recent_tasks(&mut self) {
+        let recent = self.cache.get_recent_tasks(RECENT_TASKS_LIMIT).map(|entries| {
+            entries
+                .into_iter()
+                .map(|(task_id, list_id)| RecentTask {
+                    name: self.cache.get_task_name(&task_id).ok().flatten(),
+                    status: None,
+                    task_id,
+                    list_id,
+                })
+                .collect::<Vec<_>>()
+        });
+        match recent {
+            Ok(tasks) => {
+                self.status = format!("{} recent task(s)", tasks.len());
+                let task_ids = tasks.iter().map(|t| t.task_id.clone()).collect();
+                self.recent_tasks.set_tasks(tasks);
+                self.load_recent_task_details(task_ids);
+            }
+            Err(e) => {
+                self.last_error = Some(error_chain(&e));
+                self.show_toast(format!("Failed to load recent tasks: {}", e));
+                return;
+            }
+        }
+        if self.screen != Screen::RecentTasks {
+            self.recent_tasks_return_screen = Some(self.screen.clone());
+        }
+        self.screen = Screen::RecentTasks;
+        self.update_screen_title();
+    }
+
+    /// Fetch the recent tasks by ID, so names and statuses are current
+    /// without walking the hierarchy to their lists
+    fn load_recent_task_details(&mut self, task_ids: Vec<String>) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            return;
+        };
+        if task_ids.is_empty() {
+            return;
+        }
+        tokio::spawn(async move {
+            let result = client.batch_get_tasks(&task_ids).await;
+            let msg = AppMessage::RecentTasksLoaded(result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Log that a task was opened, for the Recent Tasks screen
+    fn record_task_access(&mut self, task: &Task) {
+        // Opening the task shows its comments
+        self.task_list.clear_unread(&task.id);
+        self.my_tasks.clear_unread(&task.id);
+        self.my_work.clear_unread(&task.id);
+        let Some(list_id) = task
+            .list
+            .as_ref()
+            .map(|l| l.id.clone())
+            .or_else(|| self.current_list_id.clone())
+        else {
+            return;
+        };
+        if let Err(e) = self.cache.cache_task(task) {
+            tracing::warn!("Failed to cache task {}: {:#}", task.id, e);
+        }
+        if let Err(e) = self.cache.record_task_access(&task.id, &list_id) {
+            tracing::warn!("Failed to record access to task {}: {:#}", task.id, e);
+        }
+    }
+
+    /// Show the tasks assigned to the current user across the current workspace
+    pub fn open_my_tasks(&mut self) {
+        let workspace_id = self
+            .current_workspace_id
+            .clone()
+            .or_else(|| self.workspaces.first().map(|w| w.id.clone()));
+        let Some(workspace_id) = workspace_id else {
+            self.show_toast("Load a workspace first".to_string());
+            return;
+        };
+        if self.screen != Screen::MyTasks {
+            self.my_tasks_return_screen = Some(self.screen.clone());
+        }
+        self.screen = Screen::MyTasks;
+        self.update_screen_title();
+        self.load_my_tasks(workspace_id);
+    }
+
+    fn load_my_tasks(&mut self, workspace_id: String) {
+        self.loading = true;
+        self.status = "Loading my tasks...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        // The client looks up the current user when we don't know it yet
+        let filters = TaskFilters {
+            assignees: self.current_user_id.map(i64::from).into_iter().collect(),
+            subtasks: Some(true),
+            ..Default::default()
+        };
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_my_tasks(&workspace_id, &filters).await;
+            let msg = AppMessage::MyTasksLoaded(result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Show the current user's tasks across the workspace, grouped by space and list
+    pub fn open_my_work(&mut self) {
+        let workspace_id = self
+            .current_workspace_id
+            .clone()
+            .or_else(|| self.workspaces.first().map(|w| w.id.clone()));
+        let Some(workspace_id) = workspace_id else {
+            self.show_toast("Load a workspace first".to_string());
+            return;
+        };
+        if self.screen != Screen::MyWork {
+            self.my_work_return_screen = Some(self.screen.clone());
+        }
+        self.screen = Screen::MyWork;
+        self.update_screen_title();
+        self.load_my_work(workspace_id);
+    }
+
+    fn load_my_work(&mut self, workspace_id: String) {
+        self.loading = true;
+        self.status = "Loading my work...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let known_user_id = self.current_user_id;
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = async {
+                let user_id = match known_user_id {
+                    Some(id) => i64::from(id),
+                    None => client.get_current_user().await?.id,
+                };
+                let filters = TaskFilters {
+                    assignees: vec![user_id],
+                    subtasks: Some(true),
+                    ..Default::default()
+                };
+                let tasks = client.get_tasks_for_workspace(&workspace_id, &filters).await?;
+                // Space names label the groups; tasks only carry space IDs
+                let spaces = client.get_spaces(&workspace_id).await.unwrap_or_default();
+                anyhow::Ok((tasks, spaces))
+            }
+            .await;
+            let msg = AppMessage::MyWorkLoaded(result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Open the sprint overview for a sprint view (ClickUp sprints are views)
+    fn open_sprint_overview(&mut self, view_id: String) {
+        if self.screen != Screen::Sprint {
+            self.sprint_return_screen = Some(self.screen.clone());
+        }
+        self.sprint = SprintState {
+            view_id: view_id.clone(),
+            overview: None,
+        };
+        self.screen = Screen::Sprint;
+        self.update_screen_title();
+        self.load_sprint_overview(view_id);
+    }
+
+    fn load_sprint_overview(&mut self, view_id: String) {
+        self.loading = true;
+        self.status = "Loading sprint...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_sprint_overview(&view_id).await;
+            let msg = match result {
+                Ok(overview) => AppMessage::SprintOverviewLoaded(Ok(overview)),
+                Err(e) => AppMessage::SprintOverviewLoaded(Err(error_chain(&e))),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    fn load_document(&mut self, doc_id: String) {
+        self.loading = true;
+        self.status = "Loading document...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_document(&doc_id).await;
+            let msg = match result {
+                Ok(doc) => AppMessage::DocumentLoaded(Ok(doc)),
+                Err(e) => AppMessage::DocumentLoaded(Err(error_chain(&e))),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    fn load_lists(&mut self, folder_id: String) {
+        self.loading = true;
+        self.status = "Loading lists...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        self.load_folder_statuses(&folder_id);
+        let ticket = self.start_load(LoadKind::Lists, &folder_id);
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_lists_in_folder(&folder_id, None).await;
+            let msg = match result {
+                Ok(lists) => AppMessage::ListsLoaded(Ok(lists), ticket),
+                Err(e) => AppMessage::ListsLoaded(Err(error_chain(&e)), ticket),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// What narrows the current list's task load down from all of its
+    /// open tasks, if anything
+    fn task_filter(&self) -> Option<TaskFilter> {
+        if self.active_view.is_some() {
+            Some(TaskFilter::View)
+        } else if self.assigned_filter_active {
+            Some(TaskFilter::Assigned)
+        } else if self.show_archived {
+            Some(TaskFilter::Archived)
+        } else {
+            None
+        }
+    }
+
+    /// Load tasks filtered by the current user as assignee
+    fn load_tasks_with_assigned_filter(&mut self, list_id: String) {
+        self.active_view = None;
+        self.loading = true;
+        self.status = "Loading assigned tasks...".to_string();
+
+        let user_id = match self.current_user_id {
+            Some(id) => id,
+            None => {
+                self.loading = false;
+                self.status = "User ID not available for filtering".to_string();
+                return;
+            }
+        };
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let ticket = self.start_load(LoadKind::Tasks, &list_id);
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client
                 .get_tasks_with_assignee(&list_id, user_id, Some(100))
                 .await;
             let msg = match result {
-                Ok(tasks) => AppMessage::TasksLoaded(Ok(tasks)),
-                Err(e) => AppMessage::TasksLoaded(Err(e.to_string())),
+                Ok(tasks) => AppMessage::TasksLoaded(
+                    Ok(TaskPages {
+                        tasks,
+                        truncated: false,
+                    }),
+                    ticket,
+                ),
+                Err(e) => AppMessage::TasksLoaded(Err(error_chain(&e)), ticket),
             };
             let _ = tx.send(msg).await;
         });
     }
 
     fn load_tasks(&mut self, list_id: String) {
+        self.active_view = None;
+        self.load_list_statuses(&list_id);
+
         // If the assigned filter is active, use the filtered version
         if self.assigned_filter_active {
             self.load_tasks_with_assigned_filter(list_id);
@@ -2890,18 +6126,181 @@ impl TuiApp {
         };
 
         use crate::models::TaskFilters;
+        let ticket = self.start_load(LoadKind::Tasks, &list_id);
         let tx = self.message_tx.clone().unwrap();
-        let filters = TaskFilters::default();
+        let mut filters = TaskFilters::default();
+        self.task_sort_mode.apply(&mut filters);
+        if self.show_archived {
+            filters.archived = Some(true);
+        }
+        filters.slim = self.config.slim_tasks;
+        if filters.slim {
+            filters.include_markdown_description = Some(false);
+        } else if self.config.prefer_markdown {
+            filters.include_markdown_description = Some(true);
+        }
+        let max_pages = self.config.max_task_pages;
         tokio::spawn(async move {
-            let result = client.get_tasks(&list_id, &filters).await;
+            use futures::StreamExt;
+            // Each task is sent on as it is read; the final TasksLoaded carries
+            // only whether the page cap was hit
+            let result = async {
+                let mut stream = client.stream_all_tasks(&list_id, &filters, max_pages);
+                let mut received = 0;
+                while let Some(task) = stream.next().await {
+                    let task = task?;
+                    received += 1;
+                    let _ = tx
+                        .send(AppMessage::TaskReceived(Box::new(task), ticket.clone()))
+                        .await;
+                }
+                Ok::<_, anyhow::Error>(TaskPages {
+                    tasks: Vec::new(),
+                    truncated: TaskPages::cut_short(received, max_pages),
+                })
+            }
+            .await;
             let msg = match result {
-                Ok(tasks) => AppMessage::TasksLoaded(Ok(tasks)),
-                Err(e) => AppMessage::TasksLoaded(Err(e.to_string())),
+                Ok(pages) => AppMessage::TasksLoaded(Ok(pages), ticket),
+                Err(e) => AppMessage::TasksLoaded(Err(error_chain(&e)), ticket),
             };
             let _ = tx.send(msg).await;
         });
     }
 
+    /// Name of the list whose tasks are shown
+    fn current_list_name(&self) -> Option<&str> {
+        match &self.current_list_id {
+            Some(list_id) => self
+                .lists
+                .iter()
+                .chain(&self.fetched_list)
+                .find(|list| &list.id == list_id)
+                .map(|list| list.name.as_str()),
+            None => self.lists.first().map(|list| list.name.as_str()),
+        }
+    }
+
+    /// Fetch the current list, for its name
+    fn fetch_current_list(&mut self, list_id: String) {
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_list(&list_id).await;
+            let _ = tx.send(AppMessage::ListFetched(result.map_err(|e| error_chain(&e)))).await;
+        });
+    }
+
+    /// Find a list's status workflow: from the cache, then the API
+    fn load_list_statuses(&mut self, list_id: &str) {
+        if self.cached_list_statuses.contains_key(list_id) {
+            return;
+        }
+        match self.cache.get_list_statuses(list_id) {
+            Ok(Some(statuses)) => {
+                self.cached_list_statuses.insert(list_id.to_string(), statuses);
+                return;
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to read cached statuses for list {}: {:#}", list_id, e),
+        }
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        let list_id = list_id.to_string();
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_list_statuses(&list_id).await;
+            let msg = AppMessage::ListStatusesLoaded(list_id, result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Find a folder's status overrides: from the folders response, then the
+    /// cache, then the API
+    fn load_folder_statuses(&mut self, folder_id: &str) {
+        if self.cached_folder_statuses.contains_key(folder_id) {
+            return;
+        }
+
+        let from_response = self
+            .folders
+            .iter()
+            .find(|f| f.id == folder_id)
+            .filter(|f| !f.statuses.is_empty())
+            .map(|f| f.statuses.clone());
+        if let Some(statuses) = from_response {
+            self.store_folder_statuses(folder_id.to_string(), statuses);
+            return;
+        }
+        match self.cache.get_folder_statuses(folder_id) {
+            Ok(Some(statuses)) => {
+                self.cached_folder_statuses
+                    .insert(folder_id.to_string(), statuses);
+                return;
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to read cached statuses for folder {}: {:#}", folder_id, e),
+        }
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        let folder_id = folder_id.to_string();
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_folder_statuses(&folder_id).await;
+            let msg = AppMessage::FolderStatusesLoaded(folder_id, result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Remember a list's status workflow in memory and in the cache
+    fn store_list_statuses(&mut self, list_id: String, statuses: Vec<crate::models::TaskStatus>) {
+        if let Err(e) = self.cache.cache_list_statuses(&list_id, &statuses) {
+            tracing::warn!("Failed to cache statuses for list {}: {:#}", list_id, e);
+        }
+        self.cached_list_statuses.insert(list_id, statuses);
+    }
+
+    /// Remember a folder's status overrides in memory and in the cache
+    fn store_folder_statuses(&mut self, folder_id: String, statuses: Vec<crate::models::TaskStatus>) {
+        if let Err(e) = self.cache.cache_folder_statuses(&folder_id, &statuses) {
+            tracing::warn!("Failed to cache statuses for folder {}: {:#}", folder_id, e);
+        }
+        self.cached_folder_statuses.insert(folder_id, statuses);
+    }
+
+    /// Cached status workflow of the current list (empty when unknown)
+    ///
+    /// Falls back to the current folder's status overrides until the list's
+    /// own workflow is known.
+    fn current_list_statuses(&self) -> &[crate::models::TaskStatus] {
+        let list_statuses = self
+            .current_list_id
+            .as_ref()
+            .and_then(|id| self.cached_list_statuses.get(id))
+            .filter(|statuses| !statuses.is_empty());
+        let folder_statuses = || {
+            self.current_folder_id
+                .as_ref()
+                .and_then(|id| self.cached_folder_statuses.get(id))
+        };
+        list_statuses
+            .or_else(folder_statuses)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
     /// Open the assignee picker with the given members list
     fn open_assignee_picker(&mut self, members: Vec<User>) {
         // Build the set of currently assigned user IDs from the selected task
@@ -2919,6 +6318,23 @@ impl TuiApp {
         self.assignee_picker_open = true;
     }
 
+    /// Toggle the member under the cursor; without multiple assignees
+    /// picking one replaces the others
+    fn toggle_picked_assignee(&mut self) {
+        let Some(member) = self.assignee_picker_members.get(self.assignee_picker_cursor) else {
+            return;
+        };
+        let id = member.id;
+        if self.assignee_picker_selected.contains(&id) {
+            self.assignee_picker_selected.remove(&id);
+        } else {
+            if !self.offers(Feature::MultipleAssignees) {
+                self.assignee_picker_selected.clear();
+            }
+            self.assignee_picker_selected.insert(id);
+        }
+    }
+
     /// Open the assignee picker, fetching members if not cached
     fn open_assignee_picker_flow(&mut self) {
         // Guard: need a task
@@ -2926,7 +6342,46 @@ impl TuiApp {
             self.status = "No task selected".to_string();
             return;
         }
+        self.load_list_members(MembersFor::TaskAssignees);
+    }
+
+    /// Pick a member to assign the selected comment to, fetching members if not cached
+    fn open_comment_assignee_picker_flow(&mut self) {
+        let Some(comment) = self.comments.get(self.comment_selected_index) else {
+            self.status = "No comment selected".to_string();
+            return;
+        };
+        if !self.focused_task_capabilities().comment {
+            self.deny_read_only("assign this comment");
+            return;
+        }
+        self.load_list_members(MembersFor::Comment(comment.id.clone()));
+    }
 
+    /// Show the current list's members in the picker for `purpose`
+    fn open_members_picker(&mut self, members: Vec<User>, purpose: MembersFor) {
+        match purpose {
+            MembersFor::TaskAssignees => self.open_assignee_picker(members),
+            MembersFor::Comment(comment_id) => {
+                let mut picker = PickerState::new("Assign comment to", members, |user: &User| {
+                    user.username.clone()
+                });
+                if let Some(current) = self
+                    .comments
+                    .iter()
+                    .find(|c| c.id == comment_id)
+                    .and_then(|c| c.assigned_commenter.as_ref())
+                {
+                    picker.select_where(|user| user.id == current.id);
+                }
+                self.comment_assignee_picker = Some((comment_id, picker));
+            }
+        }
+    }
+
+    /// Open the members picker for `purpose` with the current list's
+    /// members, fetching them if not cached
+    fn load_list_members(&mut self, purpose: MembersFor) {
         // Guard: need list context
         let list_id = match &self.current_list_id {
             Some(id) => id.clone(),
@@ -2938,7 +6393,7 @@ impl TuiApp {
 
         // Check cache first
         if let Some(cached) = self.cached_list_members.get(&list_id) {
-            self.open_assignee_picker(cached.clone());
+            self.open_members_picker(cached.clone(), purpose);
             return;
         }
 
@@ -2958,8 +6413,8 @@ impl TuiApp {
         tokio::spawn(async move {
             let result = client.get_list_members(&list_id).await;
             let msg = match result {
-                Ok(members) => AppMessage::MembersLoaded(Ok(members)),
-                Err(e) => AppMessage::MembersLoaded(Err(e.to_string())),
+                Ok(members) => AppMessage::MembersLoaded(Ok(members), purpose),
+                Err(e) => AppMessage::MembersLoaded(Err(error_chain(&e)), purpose),
             };
             let _ = tx.send(msg).await;
         });
@@ -2997,13 +6452,14 @@ impl TuiApp {
                 self.assignee_picker_selected.clone(),
             )),
             due_date: None,
+            archived: None,
         };
 
         tokio::spawn(async move {
             let result = client.update_task(&task_id, &update).await;
             let msg = match result {
                 Ok(task) => AppMessage::AssigneesUpdated(Ok(task)),
-                Err(e) => AppMessage::AssigneesUpdated(Err(e.to_string())),
+                Err(e) => AppMessage::AssigneesUpdated(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
@@ -3017,40 +6473,39 @@ impl TuiApp {
         // Store original status for potential rollback
         self.status_picker_original_status = task.status.as_ref().map(|s| s.status.clone());
         
-        // Build list of available statuses (from task's space, or default)
-        let statuses = vec![
-            crate::models::TaskStatus {
-                id: None,
-                status: "To Do".to_string(),
-                color: Some("#8794a6".to_string()),
-                type_field: None,
-                orderindex: Some(0),
-                status_group: Some("todo".to_string()),
-            },
-            crate::models::TaskStatus {
-                id: None,
-                status: "In Progress".to_string(),
-                color: Some("#4f46de".to_string()),
-                type_field: None,
-                orderindex: Some(1),
-                status_group: Some("in_progress".to_string()),
-            },
-            crate::models::TaskStatus {
-                id: None,
-                status: "Done".to_string(),
-                color: Some("#0f4a58".to_string()),
-                type_field: None,
-                orderindex: Some(2),
-                status_group: Some("done".to_string()),
-            },
-        ];
-        
-        // If task has custom statuses, add them
-        if let Some(_space) = &task.space {
-            // TODO: Fetch actual space statuses from API
-            // For now, use the space's status list if available
-        }
-        
+        // Use the list's own workflow when known, otherwise the default statuses
+        let list_statuses = self.current_list_statuses();
+        let statuses = if !list_statuses.is_empty() {
+            list_statuses.to_vec()
+        } else {
+            vec![
+                crate::models::TaskStatus {
+                    id: None,
+                    status: "To Do".to_string(),
+                    color: Some("#8794a6".to_string()),
+                    type_field: None,
+                    orderindex: Some(0),
+                    status_group: Some("todo".to_string()),
+                },
+                crate::models::TaskStatus {
+                    id: None,
+                    status: "In Progress".to_string(),
+                    color: Some("#4f46de".to_string()),
+                    type_field: None,
+                    orderindex: Some(1),
+                    status_group: Some("in_progress".to_string()),
+                },
+                crate::models::TaskStatus {
+                    id: None,
+                    status: "Done".to_string(),
+                    color: Some("#0f4a58".to_string()),
+                    type_field: None,
+                    orderindex: Some(2),
+                    status_group: Some("done".to_string()),
+                },
+            ]
+        };
+
         self.status_picker_statuses = statuses;
         self.status_picker_cursor = 0;
         self.status_picker_open = true;
@@ -3060,15 +6515,14 @@ impl TuiApp {
     /// Handle keyboard input for status picker
     fn handle_status_picker_input(&mut self, key: crossterm::event::KeyEvent) {
         match key.code {
-            KeyCode::Char('j') | KeyCode::Down => {
-                if self.status_picker_cursor < self.status_picker_statuses.len().saturating_sub(1) {
-                    self.status_picker_cursor += 1;
-                }
+            KeyCode::Char('j') | KeyCode::Down
+                if self.status_picker_cursor
+                    < self.status_picker_statuses.len().saturating_sub(1) =>
+            {
+                self.status_picker_cursor += 1;
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if self.status_picker_cursor > 0 {
-                    self.status_picker_cursor -= 1;
-                }
+            KeyCode::Char('k') | KeyCode::Up if self.status_picker_cursor > 0 => {
+                self.status_picker_cursor -= 1;
             }
             KeyCode::Enter => {
                 self.save_status();
@@ -3101,6 +6555,18 @@ impl TuiApp {
             }
         };
 
+        // Reject statuses outside the list's workflow before touching the API
+        let list_statuses = self.current_list_statuses();
+        if !list_statuses.is_empty()
+            && !list_statuses
+                .iter()
+                .any(|s| s.status.eq_ignore_ascii_case(&new_status))
+        {
+            self.status_picker_open = false;
+            self.error = Some(format!("Status '{}' is not part of this list's workflow", new_status));
+            return;
+        }
+
         // Optimistic UI update: update the task in self.tasks and rebuild grouped list
         for task in &mut self.tasks {
             if task.id == task_id {
@@ -3142,13 +6608,14 @@ impl TuiApp {
             priority: None,
             assignees: None,
             due_date: None,
+            archived: None,
         };
 
         tokio::spawn(async move {
             let result = client.update_task(&task_id_clone, &update).await;
             let msg = match result {
                 Ok(task) => AppMessage::TaskStatusUpdated(Ok(task)),
-                Err(e) => AppMessage::TaskStatusUpdated(Err(e.to_string())),
+                Err(e) => AppMessage::TaskStatusUpdated(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
@@ -3156,8 +6623,198 @@ impl TuiApp {
         self.status_picker_open = false;
     }
 
-    /// Load comments for a task (top-level + replies)
+    /// Remember when the open task's comments were seen, for unread markers
+    fn mark_comments_viewed(&mut self) {
+        let Some(task_id) = self.task_detail.task.as_ref().map(|t| t.id.clone()) else {
+            return;
+        };
+        let now = chrono::Utc::now().timestamp_millis();
+        if let Err(e) = self.cache.set_last_viewed(&task_id, now) {
+            tracing::warn!("Failed to record view of task {}: {:#}", task_id, e);
+        }
+    }
+
+    /// Stop checking the task list for unread comments
+    fn cancel_unread_check(&mut self) {
+        self.unread_generation += 1;
+        if let Some(handle) = self.unread_check.take() {
+            handle.abort();
+        }
+    }
+
+    /// Check the visible tasks for comments posted since they were last viewed
+    ///
+    /// Only tasks updated after their last view are fetched, at most
+    /// `UNREAD_CHECK_CONCURRENCY` at a time.
+    fn check_unread_comments(&mut self) {
+        self.cancel_unread_check();
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            return;
+        };
+        let list = match self.screen {
+            Screen::MyTasks => &self.my_tasks,
+            Screen::MyWork => &self.my_work,
+            _ => &self.task_list,
+        };
+        let candidates: Vec<(String, i64)> = list
+            .visible_tasks(self.task_list_height)
+            .into_iter()
+            .filter_map(|task| {
+                let viewed_at = self.cache.get_last_viewed(&task.id).ok().flatten()?;
+                (task.updated_at? > viewed_at).then(|| (task.id.clone(), viewed_at))
+            })
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+
+        let generation = self.unread_generation;
+        self.unread_check = Some(tokio::spawn(async move {
+            use futures::StreamExt;
+            let mut checks = futures::stream::iter(candidates)
+                .map(|(task_id, viewed_at)| {
+                    let client = client.clone();
+                    async move {
+                        let result = client
+                            .get_task_comments(&task_id, None, Some(COMMENTS_PAGE_SIZE))
+                            .await;
+                        (task_id, viewed_at, result)
+                    }
+                })
+                .buffer_unordered(UNREAD_CHECK_CONCURRENCY);
+            while let Some((task_id, viewed_at, result)) = checks.next().await {
+                match result {
+                    Ok(page) => {
+                        let unread = page
+                            .comments
+                            .iter()
+                            .any(|c| c.created_at.is_some_and(|at| at > viewed_at));
+                        if unread {
+                            let _ = tx
+                                .send(AppMessage::UnreadCommentsFound(task_id, generation))
+                                .await;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::debug!("Unread check failed for task {}: {:#}", task_id, e);
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Invalidate any in-flight comment load, e.g. when the task detail is closed
+    fn cancel_comment_load(&mut self) {
+        self.comments_generation += 1;
+        // Other loads may have set the flag since
+        if std::mem::take(&mut self.comments_loading) {
+            self.loading = false;
+        }
+    }
+
+    /// Cached tasks of a list, with when they were cached
+    fn cached_list_tasks(&self, list_id: &str) -> Option<(Vec<Task>, i64)> {
+        let fetched_at = self.cache.list_tasks_fetched_at(list_id).ok().flatten()?;
+        let tasks = self.cache.get_list_tasks(list_id).ok()?;
+        (!tasks.is_empty()).then_some((tasks, fetched_at))
+    }
+
+    /// Keep the open task's first page of comments for when loading fails
+    ///
+    /// With the comment cache on, the caching client keeps them instead, and
+    /// writing here would keep its entries from ever expiring.
+    fn cache_loaded_comments(&mut self, comments: &[Comment]) {
+        if self.config.comment_cache_ttl_secs.is_some() {
+            return;
+        }
+        let Some(task_id) = self.task_detail.task.as_ref().map(|t| t.id.clone()) else {
+            return;
+        };
+        if let Err(e) = self.cache.cache_comments(&task_id, comments) {
+            tracing::warn!("Failed to cache comments of {}: {}", task_id, e);
+        }
+    }
+
+    /// Cached comments of the open task, top-level in the configured order
+    /// and then the replies, with when they were cached
+    fn cached_task_comments(&self) -> Option<(Vec<Comment>, i64)> {
+        let task_id = &self.task_detail.task.as_ref()?.id;
+        let fetched_at = self.cache.comments_fetched_at(task_id).ok().flatten()?;
+        // Replies read oldest first whatever the top-level order
+        let cached = self
+            .cache
+            .get_comments_in_order(task_id, CommentOrder::Oldest)
+            .ok()?;
+        let (mut comments, replies): (Vec<_>, Vec<_>) =
+            cached.into_iter().partition(|c| c.parent_id.is_none());
+        if self.config.comment_order == CommentOrder::Newest {
+            comments.reverse();
+        }
+        if comments.is_empty() {
+            return None;
+        }
+        comments.extend(replies);
+        Some((comments, fetched_at))
+    }
+
+    /// Index of the newest top-level comment in the configured order
+    fn newest_comment_index(&self) -> usize {
+        match self.config.comment_order {
+            CommentOrder::Newest => 0,
+            CommentOrder::Oldest => self.comment_top_level_count.saturating_sub(1),
+        }
+    }
+
+    /// Add a comment in display order; one already shown (e.g. from a retried
+    /// create that had gone through) is replaced in place. Returns whether
+    /// the comment is new.
+    fn insert_comment(&mut self, comment: Comment) -> bool {
+        if let Some(existing) = self.comments.iter_mut().find(|c| c.id == comment.id) {
+            *existing = comment;
+            return false;
+        }
+        if let Some(parent_id) = &comment.parent_id {
+            // A reply goes after the last one loaded in its thread
+            let after = self
+                .comments
+                .iter()
+                .rposition(|c| c.parent_id.as_ref() == Some(parent_id))
+                .map_or(self.comments.len(), |i| i + 1);
+            self.comments.insert(after, comment);
+        } else if self.config.comment_order == CommentOrder::Newest {
+            self.comments.insert(0, comment);
+        } else {
+            // Top-level comments come before any loaded replies
+            let end = self.comment_top_level_count.min(self.comments.len());
+            self.comments.insert(end, comment);
+        }
+        true
+    }
+
+    /// Start a task just opened on the task form; with `comment_focus_on_open`,
+    /// focus moves to its comments once they load, if it has any
+    fn reset_task_detail_focus(&mut self) {
+        if self.config.comment_focus_on_open {
+            self.comment_focus = false;
+            self.comment_focus_on_load = true;
+        }
+    }
+
+    /// Focus the comments just loaded for a task just opened (see
+    /// [`reset_task_detail_focus`](Self::reset_task_detail_focus))
+    fn focus_comments_if_opening(&mut self) {
+        if std::mem::take(&mut self.comment_focus_on_load)
+            && !self.comments.is_empty()
+            && self.screen == Screen::TaskDetail
+        {
+            self.comment_focus = true;
+        }
+    }
+
+    /// Load the first page of top-level comments for a task
     fn load_comments(&mut self, task_id: String) {
+        self.comments_generation += 1;
+        let generation = self.comments_generation;
         self.loading = true;
         self.status = "Loading comments...".to_string();
 
@@ -3170,52 +6827,198 @@ impl TuiApp {
             }
         };
 
+        self.comments_loading = true;
         let tx = self.message_tx.clone().unwrap();
+        let prefetch_replies = self.config.prefetch_comment_replies;
+        let order = self.config.comment_order;
         tokio::spawn(async move {
-            // First, fetch top-level comments
-            let top_level_result = client.get_task_comments(&task_id).await;
-
-            match top_level_result {
-                Ok(top_level_comments) => {
-                    // For each top-level comment, fetch its replies
-                    let total_top_level_comments = top_level_comments.len();
-                    let mut all_comments = top_level_comments;
-
-                    // Collect all reply fetches
-                    let mut reply_futures = Vec::new();
-                    for comment in &all_comments {
-                        let comment_id = comment.id.clone();
-                        let client_clone = client.clone();
-                        reply_futures.push(async move {
-                            let result = client_clone.get_comment_replies(&comment_id).await;
-                            (comment_id, result)
-                        });
-                    }
+            let result =
+                fetch_comment_page(client, &task_id, None, prefetch_replies, order).await;
+            let msg = AppMessage::CommentsLoaded(result.map_err(|e| error_chain(&e)), generation);
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Comments for task {} dropped: app is shutting down", task_id);
+            }
+        });
+    }
 
-                    // Wait for all replies to be fetched
-                    let reply_results = futures::future::join_all(reply_futures).await;
+    /// Fetch the page of older comments and add it to the loaded ones
+    fn load_more_comments(&mut self) {
+        let Some(cursor) = self.comments_cursor else {
+            return;
+        };
+        let Some(task_id) = self.task_detail.task.as_ref().map(|t| t.id.clone()) else {
+            return;
+        };
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
 
-                    // Add replies to the comments list with parent_id set
-                    for (parent_id, reply_result) in reply_results {
-                        if let Ok(replies) = reply_result {
-                            for mut reply in replies {
-                                reply.parent_id = Some(parent_id.clone());
-                                all_comments.push(reply);
-                            }
-                        }
-                    }
+        let generation = self.comments_generation;
+        self.loading = true;
+        self.comments_loading = true;
+        self.status = "Loading older comments...".to_string();
 
-                    let msg = AppMessage::CommentsLoaded(Ok(CommentsLoadedResponse { all_comments, top_level_comments: total_top_level_comments }));
-                    let _ = tx.send(msg).await;
-                }
-                Err(e) => {
-                    let msg = AppMessage::CommentsLoaded(Err(e.to_string()));
-                    let _ = tx.send(msg).await;
-                }
+        let tx = self.message_tx.clone().unwrap();
+        let prefetch_replies = self.config.prefetch_comment_replies;
+        let order = self.config.comment_order;
+        tokio::spawn(async move {
+            let result =
+                fetch_comment_page(client, &task_id, Some(cursor), prefetch_replies, order).await;
+            let msg =
+                AppMessage::MoreCommentsLoaded(result.map_err(|e| error_chain(&e)), generation);
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Comments for task {} dropped: app is shutting down", task_id);
+            }
+        });
+    }
+
+    /// Fetch one comment again, to show it as ClickUp has it after a change
+    fn refresh_comment(&mut self, comment_id: String) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            return;
+        };
+        let generation = self.comments_generation;
+        tokio::spawn(async move {
+            let result = client.get_comment(&comment_id).await;
+            let msg = AppMessage::CommentRefreshed(result.map_err(|e| error_chain(&e)), generation);
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Refreshed comment dropped: app is shutting down");
+            }
+        });
+    }
+
+    /// Fetch a linked comment missing from its task's top-level comments
+    fn fetch_linked_reply(&mut self, comment_id: String) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.navigating = false;
+            self.navigating_level.clear();
+            self.status = "Comment not found in task".to_string();
+            return;
+        };
+        tokio::spawn(async move {
+            let result = client.get_comment(&comment_id).await;
+            let msg = AppMessage::LinkedReplyFetched(result.map_err(|e| error_chain(&e)));
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Linked comment dropped: app is shutting down");
+            }
+        });
+    }
+
+    /// Fetch the replies of a thread the first time it is opened
+    fn load_thread_replies(&mut self, comment_id: String) {
+        if self.loaded_threads.contains(&comment_id) {
+            return;
+        }
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        self.loaded_threads.insert(comment_id.clone());
+        let generation = self.comments_generation;
+        self.loading = true;
+        self.comments_loading = true;
+        self.status = "Loading replies...".to_string();
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_comment_replies(&comment_id).await;
+            let msg = AppMessage::ThreadRepliesLoaded(
+                comment_id,
+                result.map_err(|e| error_chain(&e)),
+                generation,
+            );
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Thread replies dropped: app is shutting down");
+            }
+        });
+    }
+
+    /// Fetch the replies of the top-level comment under the selection once it
+    /// has rested there for a moment, so entering the thread is instant
+    fn prefetch_hovered_replies(&mut self, now: std::time::Instant) {
+        let hovered = self
+            .comments
+            .get(self.comment_selected_index)
+            .filter(|_| {
+                self.screen == Screen::TaskDetail
+                    && self.comment_focus
+                    && self.comment_view_mode == CommentViewMode::TopLevel
+            })
+            .filter(|c| c.parent_id.is_none() && c.reply_count.unwrap_or(0) > 0)
+            .filter(|c| !self.loaded_threads.contains(&c.id))
+            .map(|c| c.id.as_str());
+        self.reply_prefetch.hover(hovered, now);
+        let Some(comment_id) = self.reply_prefetch.due(now) else {
+            return;
+        };
+        let Some(client) = self.client.clone() else {
+            return;
+        };
+
+        self.loaded_threads.insert(comment_id.clone());
+        let generation = self.comments_generation;
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_comment_replies(&comment_id).await;
+            let msg = AppMessage::ThreadRepliesPrefetched(
+                comment_id,
+                result.map_err(|e| error_chain(&e)),
+                generation,
+            );
+            if tx.send(msg).await.is_err() {
+                tracing::debug!("Prefetched replies dropped: app is shutting down");
             }
         });
     }
 
+    /// Replace a thread's replies with `replies`, returning how many there are
+    fn set_thread_replies(&mut self, parent_id: &str, replies: Vec<Comment>) -> usize {
+        self.comments
+            .retain(|c| c.parent_id.as_deref() != Some(parent_id));
+        let count = replies.len();
+        self.comments.extend(replies.into_iter().map(|mut reply| {
+            reply.parent_id = Some(parent_id.to_string());
+            reply
+        }));
+        count
+    }
+
+    /// Forget a prefetched thread's replies to bound memory, unless it's open;
+    /// they are fetched again when the thread is entered
+    fn drop_thread_replies(&mut self, parent_id: &str) {
+        let open = matches!(&self.comment_view_mode,
+            CommentViewMode::InThread { parent_comment_id, .. } if parent_comment_id == parent_id);
+        if open {
+            return;
+        }
+        // Selections are indices into the comments, so follow them by ID
+        let id_at = |comments: &[Comment], index: usize| comments.get(index).map(|c| c.id.clone());
+        let selected = id_at(&self.comments, self.comment_selected_index);
+        let previous = self
+            .comment_previous_selection
+            .and_then(|index| id_at(&self.comments, index));
+        self.comments
+            .retain(|c| c.parent_id.as_deref() != Some(parent_id));
+        let index_of = |comments: &[Comment], id: Option<String>| {
+            id.and_then(|id| comments.iter().position(|c| c.id == id))
+        };
+        if let Some(index) = index_of(&self.comments, selected) {
+            self.comment_selected_index = index;
+        }
+        if let Some(index) = index_of(&self.comments, previous) {
+            self.comment_previous_selection = Some(index);
+        }
+        self.loaded_threads.remove(parent_id);
+    }
+
     /// Create a new comment (top-level or reply)
     fn create_comment(&mut self, task_id: String, text: String, parent_id: Option<String>) {
         self.loading = true;
@@ -3245,19 +7048,20 @@ impl TuiApp {
         };
 
         tokio::spawn(async move {
-            // Use different endpoint based on whether this is a reply
-            let result = if let Some(ref parent_comment_id) = parent_id {
-                client
-                    .create_comment_reply(parent_comment_id, &request)
-                    .await
-            } else {
-                client.create_comment(&task_id, &request).await
-            };
+            // A reply goes to its thread; a timed out create is looked for
+            // before it is reported as failed
+            let result = client.create_comment_checked(&task_id, &request).await;
 
             let meta = CommentCreatedMessageMeta { is_reply, task_id };
             let msg = match result {
-                Ok(comment) => AppMessage::CommentCreated(Ok(comment), meta),
-                Err(e) => AppMessage::CommentCreated(Err(e.to_string()), meta),
+                Ok(mut comment) => {
+                    // Replies come back without their parent
+                    if comment.parent_id.is_none() {
+                        comment.parent_id = parent_id;
+                    }
+                    AppMessage::CommentCreated(Ok(comment), meta)
+                }
+                Err(e) => AppMessage::CommentCreated(Err(error_chain(&e)), meta),
             };
             let _ = tx.send(msg).await;
         });
@@ -3298,7 +7102,48 @@ impl TuiApp {
             let result = client.create_task(&list_id, &request).await;
             let msg = match result {
                 Ok(task) => AppMessage::TaskCreated(Ok(task)),
-                Err(e) => AppMessage::TaskCreated(Err(e.to_string())),
+                Err(e) => AppMessage::TaskCreated(Err(error_chain(&e))),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Create a task in the current list from the selected comment
+    ///
+    /// The title is derived from the comment's first line and the description
+    /// links back to the comment the task was created from.
+    fn create_task_from_selected_comment(&mut self) {
+        let Some(comment) = self.comments.get(self.comment_selected_index) else {
+            self.status = "No comment selected".to_string();
+            return;
+        };
+        let Some(list_id) = self.current_list_id.clone() else {
+            self.status = "No list selected".to_string();
+            return;
+        };
+
+        let source_url = self.task_detail.task.as_ref().and_then(|task| {
+            ClickUpUrlGenerator::comment_url("", "", &task.id, &comment.id).ok()
+        });
+        let request = CreateTaskRequest::from_comment(comment, source_url.as_deref());
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        self.loading = true;
+        self.status = format!("Creating task \"{}\"...", request.name);
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.create_task(&list_id, &request).await;
+            let msg = match result {
+                Ok(task) => AppMessage::TaskCreatedFromComment(Ok(task)),
+                Err(e) => AppMessage::TaskCreatedFromComment(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
@@ -3329,12 +7174,188 @@ impl TuiApp {
             let result = client.delete_task(&task_id_clone).await;
             let msg = match result {
                 Ok(()) => AppMessage::TaskDeleted(Ok(task_id_clone)),
-                Err(e) => AppMessage::TaskDeleted(Err(e.to_string())),
+                Err(e) => AppMessage::TaskDeleted(Err(error_chain(&e))),
             };
             let _ = tx.send(msg).await;
         });
     }
 
+    /// Load the time logged on a task for the detail view
+    ///
+    /// Runs alongside the comment load and leaves the loading flag to it.
+    fn load_time_entries(&mut self, task_id: String) {
+        self.task_detail.time_entries.clear();
+        if !self.offers(Feature::TimeTracking) {
+            return;
+        }
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_time_entries(&task_id).await;
+            let msg = AppMessage::TimeEntriesLoaded(task_id, result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Load how long a task spent in each status for the detail view, from
+    /// the per-task cache when it has been fetched before
+    fn load_time_in_status(&mut self, task_id: String) {
+        if let Some(durations) = self.cached_time_in_status.get(&task_id) {
+            self.task_detail.time_in_status = durations.clone();
+            return;
+        }
+        self.task_detail.time_in_status.clear();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.get_task_time_in_status(&task_id).await;
+            let msg = AppMessage::TimeInStatusLoaded(task_id, result.map_err(|e| error_chain(&e)));
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Delete the marked tasks in batches, reporting progress after each batch
+    fn bulk_delete_marked_tasks(&mut self) {
+        let task_ids: Vec<String> = self
+            .task_list
+            .marked_tasks()
+            .iter()
+            .map(|t| t.id.clone())
+            .collect();
+        if task_ids.is_empty() {
+            return;
+        }
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        let total = task_ids.len();
+        self.loading = true;
+        self.status = format!("Deleting 0 of {} tasks...", total);
+        tokio::spawn(async move {
+            let mut overall = BulkDeleteResult::default();
+            let mut done = 0;
+            for batch in task_ids.chunks(BULK_DELETE_BATCH_SIZE) {
+                let result = match client.bulk_delete_tasks(batch).await {
+                    Ok(result) => result,
+                    Err(e) => BulkDeleteResult {
+                        deleted: Vec::new(),
+                        failed: batch.iter().map(|id| (id.clone(), error_chain(&e))).collect(),
+                    },
+                };
+                done += batch.len();
+                overall.deleted.extend(result.deleted.iter().cloned());
+                overall.failed.extend(result.failed.iter().cloned());
+                let _ = tx.send(AppMessage::BulkDeleteProgress(result, done, total)).await;
+            }
+            let _ = tx.send(AppMessage::BulkDeleteFinished(overall)).await;
+        });
+    }
+
+    /// Switch the task lists between comfortable and compact density
+    fn toggle_task_list_density(&mut self) {
+        let density = self.config.ui.task_list_density.toggled();
+        self.config.ui.task_list_density = density;
+        self.status = match density {
+            TaskListDensity::Comfortable => "Comfortable task list".to_string(),
+            TaskListDensity::Compact => "Compact task list".to_string(),
+        };
+    }
+
+    /// Show or hide archived tasks in the task list, reloading it
+    fn toggle_show_archived(&mut self) {
+        self.show_archived = !self.show_archived;
+        if let Some(list_id) = self.current_list_id.clone() {
+            self.load_tasks(list_id);
+        }
+        self.status = if self.show_archived {
+            "Showing archived tasks".to_string()
+        } else {
+            "Hiding archived tasks".to_string()
+        };
+    }
+
+    /// Archive a task, or unarchive it when `archive` is false
+    fn archive_task(&mut self, task_id: String, archive: bool) {
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        self.loading = true;
+        self.status = if archive {
+            "Archiving task...".to_string()
+        } else {
+            "Unarchiving task...".to_string()
+        };
+        tokio::spawn(async move {
+            let result = if archive {
+                client.archive_task(&task_id).await
+            } else {
+                client.unarchive_task(&task_id).await
+            };
+            let result = result.map_err(|e| error_chain(&e));
+            let _ = tx.send(AppMessage::TaskArchived(task_id, archive, result)).await;
+        });
+    }
+
+    /// Archive the marked tasks, or unarchive them when all are archived
+    fn bulk_archive_marked_tasks(&mut self) {
+        let marked = self.task_list.marked_tasks();
+        let archived = !marked.iter().all(|t| t.archived == Some(true));
+        let task_ids: Vec<String> = marked.iter().map(|t| t.id.clone()).collect();
+        if task_ids.is_empty() {
+            return;
+        }
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        self.loading = true;
+        self.status = format!(
+            "{} {} tasks...",
+            if archived { "Archiving" } else { "Unarchiving" },
+            task_ids.len()
+        );
+        tokio::spawn(async move {
+            let result = match client.bulk_archive_tasks(&task_ids, archived).await {
+                Ok(result) => result,
+                Err(e) => BulkArchiveResult {
+                    archived,
+                    updated: Vec::new(),
+                    failed: task_ids.iter().map(|id| (id.clone(), error_chain(&e))).collect(),
+                },
+            };
+            let _ = tx.send(AppMessage::BulkArchiveFinished(result)).await;
+        });
+    }
+
     /// Update an existing comment
     fn update_comment(&mut self, comment_id: String, text: String) {
         self.loading = true;
@@ -3357,11 +7378,106 @@ impl TuiApp {
             assigned_commenter: None,
         };
         tokio::spawn(async move {
-            let result = client.update_comment(&comment_id, &request).await;
+            let result = client.update_comment_checked(&comment_id, &request).await;
             let msg = match result {
                 Ok(comment) => AppMessage::CommentUpdated(Ok(comment)),
-                Err(e) => AppMessage::CommentUpdated(Err(e.to_string())),
+                Err(e) => AppMessage::CommentUpdated(Err(error_chain(&e))),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    fn delete_comment(&mut self, comment_id: String) {
+        self.loading = true;
+        self.status = "Deleting comment...".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.loading = false;
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = client.delete_comment(&comment_id).await;
+            let msg = match result {
+                Ok(()) => AppMessage::CommentDeleted(Ok(comment_id)),
+                Err(e) => AppMessage::CommentDeleted(Err(error_chain(&e))),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Revert the most recent reversible action by issuing its inverse API call
+    fn undo_last_action(&mut self) {
+        let Some(action) = self.undo_stack.pop() else {
+            self.status = "Nothing to undo".to_string();
+            return;
+        };
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.undo_stack.push(action);
+                self.error = Some("Not authenticated".to_string());
+                return;
+            }
+        };
+
+        self.loading = true;
+        self.status = format!("Undoing {}...", action.description());
+
+        let tx = self.message_tx.clone().unwrap();
+        tokio::spawn(async move {
+            let result = match action.inverse() {
+                InverseCall::CreateComment { task_id, text } => {
+                    let request = CreateCommentRequest {
+                        comment_text: text,
+                        assignee: None,
+                        assigned_commenter: None,
+                        parent_id: None,
+                    };
+                    client
+                        .create_comment(&task_id, &request)
+                        .await
+                        .map(|comment| UndoOutcome::Comment(Box::new(comment)))
+                }
+                InverseCall::CreateCommentReply { parent_id, text } => {
+                    let request = CreateCommentRequest {
+                        comment_text: text,
+                        assignee: None,
+                        assigned_commenter: None,
+                        parent_id: Some(parent_id.clone()),
+                    };
+                    client
+                        .create_comment_reply(&parent_id, &request)
+                        .await
+                        .map(|mut comment| {
+                            // Replies come back without their parent
+                            comment.parent_id.get_or_insert(parent_id);
+                            UndoOutcome::Comment(Box::new(comment))
+                        })
+                }
+                InverseCall::SetStatus { task_id, status } => {
+                    let update = crate::models::UpdateTaskRequest {
+                        name: None,
+                        description: None,
+                        status: Some(status),
+                        priority: None,
+                        assignees: None,
+                        due_date: None,
+                        archived: None,
+                    };
+                    client
+                        .update_task(&task_id, &update)
+                        .await
+                        .map(|task| UndoOutcome::Task(Box::new(task)))
+                }
             };
+            let msg = AppMessage::UndoApplied(result.map_err(|e| error_chain(&e)), action);
             let _ = tx.send(msg).await;
         });
     }
@@ -3378,10 +7494,13 @@ impl TuiApp {
                 }
             }
             Screen::Folders => {
-                if let Some(folder) = self.folders.first() {
-                    generate_screen_title(&folder.name)
-                } else {
-                    generate_screen_title("Folders")
+                let space = self
+                    .current_space_id
+                    .as_ref()
+                    .and_then(|id| self.spaces.iter().find(|s| &s.id == id));
+                match space {
+                    Some(space) => generate_screen_title(&space.name),
+                    None => generate_screen_title("Folders"),
                 }
             }
             Screen::Lists => {
@@ -3392,19 +7511,16 @@ impl TuiApp {
                 }
             }
             Screen::Tasks => {
-                if let Some(list) = self.lists.first() {
-                    let base = format!("Tasks: {}", list.name);
-                    if self.assigned_filter_active {
-                        generate_screen_title(&format!("{} (Assigned to Me)", base))
-                    } else {
-                        generate_screen_title(&base)
-                    }
+                let base = match self.current_list_name() {
+                    Some(name) => format!("Tasks: {}", name),
+                    None => "Tasks".to_string(),
+                };
+                if let Some(view) = &self.active_view {
+                    generate_screen_title(&format!("{} - {}", base, view.name))
+                } else if self.assigned_filter_active {
+                    generate_screen_title(&format!("{} (Assigned to Me)", base))
                 } else {
-                    if self.assigned_filter_active {
-                        generate_screen_title("Tasks (Assigned to Me)")
-                    } else {
-                        generate_screen_title("Tasks")
-                    }
+                    generate_screen_title(&base)
                 }
             }
             Screen::TaskDetail => {
@@ -3421,6 +7537,19 @@ impl TuiApp {
                     generate_screen_title("Document")
                 }
             }
+            Screen::Sprint => match &self.sprint.overview {
+                Some(overview) => generate_screen_title(&format!("Sprint: {}", overview.name)),
+                None => generate_screen_title("Sprint"),
+            },
+            Screen::MyTasks => generate_screen_title("My Tasks"),
+            Screen::MyWork => generate_screen_title("My Work"),
+            Screen::Documents => generate_screen_title("Documents"),
+            Screen::RecentTasks => generate_screen_title("Recent Tasks"),
+            Screen::WorkspaceViews => match &self.workspace_views.open_view {
+                Some(view) => generate_screen_title(&format!("View: {}", view.name)),
+                None => generate_screen_title("Views"),
+            },
+            Screen::Calendar => generate_screen_title("Due Dates"),
         };
     }
 
@@ -3428,33 +7557,50 @@ impl TuiApp {
         &mut self,
         terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
     ) -> Result<()> {
-        // Auto-clear URL copy status after 3 seconds
-        if let Some(status_time) = self.url_copy_status_time {
-            if status_time.elapsed() > std::time::Duration::from_secs(3) {
-                self.url_copy_status = None;
-                self.url_copy_status_time = None;
-            }
+        // Drop the type-ahead buffer after a pause in typing
+        if self.type_ahead.is_expired(std::time::Instant::now()) {
+            self.type_ahead.cancel();
+            self.status.clear();
         }
 
+        self.loading_frame = (self.loading_frame + 1) % SPINNER_FRAMES.len() as u8;
+        self.remember_error();
+
+        let linear = self
+            .config
+            .ui
+            .accessibility_mode
+            .then(|| self.linear_view());
+
         terminal.draw(|frame: &mut Frame| {
             let area = frame.area();
             let layout = TuiLayout::new(area);
 
-            // Render title bar
-            layout.render_title(frame, &self.screen_title);
+            if let Some(view) = &linear {
+                // One plain pane with the status on top, for screen readers
+                render_linear(frame, view, &self.status_bar_text(), &self.get_hints(), area);
+            } else {
+                // Render title bar
+                layout.render_title(frame, &self.screen_title);
 
-            // Check if terminal is too small
-            if layout.too_small {
-                layout.render_too_small_warning(frame);
-                return;
-            }
+                // Check if terminal is too small
+                if layout.too_small {
+                    layout.render_too_small_warning(frame);
+                    return;
+                }
 
-            // Render content area
-            if self.sidebar.visible {
-                let (sidebar_area, content_area) = layout.split_content(25);
-                self.render_sidebar_content(frame, sidebar_area, content_area);
-            } else {
-                self.render_main_content(frame, layout.content_area);
+                // Render content area
+                if self.sidebar.visible {
+                    let (sidebar_area, content_area) = layout.split_content(25);
+                    self.render_sidebar_content(frame, sidebar_area, content_area);
+                } else {
+                    self.render_main_content(frame, layout.content_area);
+                }
+
+                // Cover the empty screen while the first workspaces load
+                if self.loading && self.workspaces.is_empty() && self.screen == Screen::Workspaces {
+                    TuiLayout::render_loading_overlay(frame, layout.content_area, &self.status, self.loading_frame);
+                }
             }
 
             // Render dialog if visible
@@ -3496,31 +7642,146 @@ impl TuiApp {
                 self.render_url_input_dialog(frame, area);
             }
 
+            if let Some(prompt) = &self.name_prompt {
+                render_name_prompt(frame, prompt, area);
+            }
+
+            if let Some(picker) = &self.view_picker {
+                render_picker(frame, picker, area);
+            }
+
+            if let Some((_, picker)) = &self.comment_assignee_picker {
+                render_picker(frame, picker, area);
+            }
+
+            if let Some((_, picker)) = &self.tag_color_picker {
+                render_color_picker(frame, picker, area);
+            }
+
+            // Render full error overlay if open
+            if self.error_detail_open {
+                if let Some(error) = &self.last_error {
+                    render_error_detail(frame, area, error, self.error_detail_scroll);
+                }
+            }
+
             // Render help overlay if visible
             let help_context = self.get_help_context();
             render_help(frame, &self.help, &help_context, area);
 
             // Render status bar
-            let hints = self.get_hints();
-            // Priority: error > navigating > url_copy_status > loading > regular status
-            let status = if let Some(ref error) = self.error {
-                error.clone()
-            } else if self.navigating {
-                format!("Loading... {}", self.navigating_level)
-            } else if let Some(ref url_status) = self.url_copy_status {
-                // Show URL copy status (takes priority over regular status)
-                url_status.clone()
-            } else if self.loading {
-                "Loading...".to_string()
-            } else {
-                self.status.clone()
-            };
-            layout.render_status(frame, &status, &hints);
+            if linear.is_none() {
+                let hints = self.get_hints();
+                let status = self.status_bar_text();
+                let spinner = (self.error.is_none() && (self.loading || self.navigating)).then_some(self.loading_frame);
+                layout.render_status(frame, &status, &hints, spinner);
+            }
         })?;
 
         Ok(())
     }
 
+    /// The current screen flattened for accessibility mode
+    pub fn linear_view(&self) -> LinearView {
+        let title = self.screen_title.trim_start_matches("ClickDown - ");
+        match self.screen {
+            Screen::Auth => linear::auth_view(title, &self.auth_state),
+            Screen::Workspaces | Screen::Spaces | Screen::Folders | Screen::Lists => {
+                linear::sidebar_view(title, &self.sidebar)
+            }
+            Screen::Tasks => linear::task_list_view(title, &self.task_list),
+            Screen::MyTasks => linear::task_list_view(title, &self.my_tasks),
+            Screen::MyWork => linear::task_list_view(title, &self.my_work),
+            Screen::RecentTasks => linear::recent_tasks_view(title, &self.recent_tasks),
+            Screen::WorkspaceViews => linear::workspace_views_view(title, &self.workspace_views),
+            Screen::Calendar => linear::calendar_view(title, &self.calendar),
+            Screen::TaskDetail if self.task_detail.creating => linear::task_creation_view(
+                "New Task",
+                &self.task_name_input,
+                &self.task_description_input,
+                &self.task_creation_focus,
+            ),
+            Screen::TaskDetail => linear::task_detail_view(
+                title,
+                &self.task_detail,
+                &self.comments,
+                &self.comment_view_mode,
+                self.comment_selected_index,
+                self.comment_focus,
+            ),
+            Screen::Document => linear::document_view(title, &self.document),
+            Screen::Documents => linear::documents_view(title, &self.documents_browser),
+            Screen::Sprint => linear::sprint_view(title, &self.sprint),
+        }
+    }
+
+    /// Text for the status bar
+    ///
+    /// Priority: error > navigating > url_copy_status > loading > regular status.
+    /// Errors are cut to one line; the full text stays in `self.error`.
+    pub fn status_bar_text(&self) -> String {
+        let text = self.status_bar_message();
+        if self.queued_requests == 0 {
+            return text;
+        }
+        let offline = format!("Offline mode: {} queued", self.queued_requests);
+        if text.is_empty() {
+            offline
+        } else {
+            format!("{} | {}", offline, text)
+        }
+    }
+
+    /// The status bar message, without the offline indicator
+    fn status_bar_message(&self) -> String {
+        if let Some(ref error) = self.error {
+            error_detail::status_line(error)
+        } else if self.navigating {
+            format!("Loading... {}", self.navigating_level)
+        } else if let Some(ref url_status) = self.url_copy_status {
+            // Show URL copy status (takes priority over regular status)
+            url_status.clone()
+        } else if self.loading {
+            "Loading...".to_string()
+        } else {
+            self.status.clone()
+        }
+    }
+
+    /// Re-read how many offline writes are waiting to be sent
+    pub fn refresh_queued_requests(&mut self) {
+        match self.cache.queued_request_count() {
+            Ok(count) => self.queued_requests = count,
+            Err(e) => tracing::warn!("Failed to read request queue: {:#}", e),
+        }
+    }
+
+    /// The quit confirmation, warning about writes still queued
+    pub fn quit_dialog(&self) -> DialogType {
+        match self.queued_requests {
+            0 => DialogType::ConfirmQuit,
+            queued => DialogType::ConfirmQuitWithQueued { queued },
+        }
+    }
+
+    /// Keep the current error for the overlay once the status bar moves on
+    fn remember_error(&mut self) {
+        if self.error.is_some() && self.error != self.last_error {
+            self.last_error = self.error.clone();
+        }
+    }
+
+    /// Open the overlay with the full text of the current or last error
+    fn open_error_detail(&mut self) {
+        self.remember_error();
+        if self.last_error.is_none() {
+            self.show_toast("No error to show".to_string());
+            return;
+        }
+        self.error_detail_open = true;
+        self.error_detail_scroll = 0;
+    }
+
     fn render_sidebar_content(
         &mut self,
         frame: &mut Frame,
@@ -3532,22 +7793,65 @@ impl TuiApp {
     }
 
     fn render_main_content(&mut self, frame: &mut Frame, area: Rect) {
+        // Task lists are rebuilt in many places; they take the density when drawn
+        let density = self.config.ui.task_list_density;
+        for list in [
+            &mut self.task_list,
+            &mut self.my_tasks,
+            &mut self.my_work,
+            &mut self.workspace_views.tasks,
+        ] {
+            list.set_density(density);
+        }
         match self.screen {
-            Screen::Auth => render_auth(frame, &self.auth_state, area),
-            Screen::Tasks => render_task_list(frame, &self.task_list, area, false),
+            Screen::Auth => render_auth(frame, &self.auth_state, area, self.loading_frame),
+            Screen::Tasks => {
+                self.task_list_height = area.height.saturating_sub(2) as usize;
+                match self.empty_state(Collection::Tasks, self.tasks.is_empty()) {
+                    Some(empty) => render_empty_state(frame, &empty, area),
+                    None => render_task_list(frame, &self.task_list, area, false),
+                }
+            }
+            Screen::MyTasks => {
+                self.task_list_height = area.height.saturating_sub(2) as usize;
+                render_task_list(frame, &self.my_tasks, area, false)
+            }
+            Screen::MyWork => {
+                self.task_list_height = area.height.saturating_sub(2) as usize;
+                render_task_list(frame, &self.my_work, area, false)
+            }
+            Screen::RecentTasks => render_recent_tasks(frame, &self.recent_tasks, area),
+            Screen::WorkspaceViews => {
+                self.task_list_height = area.height.saturating_sub(2) as usize;
+                render_workspace_views(frame, &self.workspace_views, area)
+            }
+            Screen::Calendar => render_calendar(frame, &self.calendar, area),
             Screen::TaskDetail => {
-                // Split area between task detail and comments with 3:7 ratio
-                let (task_detail_area, comments_area) = split_task_detail(area);
-
-                // Render task detail in top portion (30%)
-                render_task_detail(
-                    frame,
-                    &self.task_detail,
-                    task_detail_area,
-                    &self.task_name_input,
-                    &self.task_description_input,
-                    &self.task_creation_focus,
-                );
+                let two_column = !self.task_detail.creating
+                    && u32::from(frame.area().width) >= self.config.ui.min_width_for_two_column;
+
+                let comments_area = if two_column {
+                    // Metadata on the left, description over comments on the right
+                    let (metadata_area, description_area, comments_area) =
+                        split_task_detail_columns(area);
+                    render_task_metadata(frame, &self.task_detail, metadata_area);
+                    render_task_description(frame, &self.task_detail, description_area);
+                    comments_area
+                } else {
+                    // Split area between task detail and comments with 3:7 ratio
+                    let (task_detail_area, comments_area) = split_task_detail(area);
+
+                    // Render task detail in top portion (30%)
+                    render_task_detail(
+                        frame,
+                        &self.task_detail,
+                        task_detail_area,
+                        &self.task_name_input,
+                        &self.task_description_input,
+                        &self.task_creation_focus,
+                    );
+                    comments_area
+                };
 
                 // Render comments in bottom portion (70%)
                 render_comments(
@@ -3559,15 +7863,97 @@ impl TuiApp {
                     self.comment_focus,
                     comments_area,
                     &self.comment_view_mode,
+                    self.comments_cursor.is_some(),
+                    &self.comment_collapse,
+                    self.comments_cached_notice().as_deref(),
                 );
             }
             Screen::Document => render_document(frame, &self.document, area),
+            Screen::Documents => render_documents(frame, &self.documents_browser, area),
+            Screen::Sprint => render_sprint(frame, &self.sprint, area),
             _ => {
-                use ratatui::widgets::Paragraph;
-                let placeholder = Paragraph::new(format!("Navigate to see {}", self.screen_title));
-                frame.render_widget(placeholder, area);
+                let collection = match self.screen {
+                    Screen::Workspaces => Some(Collection::Workspaces),
+                    Screen::Spaces => Some(Collection::Spaces),
+                    Screen::Folders => Some(Collection::SpaceContents),
+                    Screen::Lists => Some(Collection::Lists),
+                    _ => None,
+                };
+                let empty = collection.and_then(|collection| {
+                    self.empty_state(collection, self.sidebar.items().is_empty())
+                });
+                if let Some(empty) = empty {
+                    render_empty_state(frame, &empty, area);
+                } else if let Some(info) = self.selected_item_info() {
+                    render_item_info(frame, &info, area);
+                } else {
+                    use ratatui::widgets::Paragraph;
+                    let placeholder =
+                        Paragraph::new(format!("Navigate to see {}", self.screen_title));
+                    frame.render_widget(placeholder, area);
+                }
+            }
+        }
+    }
+
+    /// Details of the item selected in the sidebar, when it's one with any
+    fn selected_item_info(&self) -> Option<ItemInfo> {
+        match self.sidebar.selected_item()? {
+            SidebarItem::Workspace { id, .. } => self
+                .workspaces
+                .iter()
+                .find(|w| &w.id == id)
+                .map(ItemInfo::workspace),
+            SidebarItem::Space { id, .. } => {
+                self.spaces.iter().find(|s| &s.id == id).map(ItemInfo::space)
+            }
+            SidebarItem::Folder { id, .. } => {
+                self.folders.iter().find(|f| &f.id == id).map(ItemInfo::folder)
+            }
+            SidebarItem::List { id, .. } => self
+                .lists
+                .iter()
+                .chain(&self.space_lists)
+                .find(|l| &l.id == id)
+                .map(ItemInfo::list),
+            SidebarItem::Header { .. } | SidebarItem::Document { .. } => None,
+        }
+    }
+
+    /// The panel to show for `collection` when there is nothing in it; an
+    /// empty task list says which filter left it empty
+    fn empty_state(&self, collection: Collection, is_empty: bool) -> Option<EmptyState> {
+        if !is_empty {
+            return None;
+        }
+        let status = self.load_status(collection);
+        let mut empty = EmptyState::new(collection, &status)?;
+        if collection == Collection::Tasks && status == LoadStatus::Loaded {
+            let filtered = match self.task_filter() {
+                Some(TaskFilter::View) => Some((
+                    "No tasks in this view",
+                    "This view matches no tasks in this list",
+                    "Press V to pick another view · Esc to go back",
+                )),
+                Some(TaskFilter::Assigned) => Some((
+                    "No tasks assigned to you",
+                    "No task in this list is assigned to you",
+                    "Press a to show all tasks · Esc to go back",
+                )),
+                Some(TaskFilter::Archived) => Some((
+                    "No archived tasks",
+                    "This list has no archived tasks",
+                    "Press H to show open tasks · Esc to go back",
+                )),
+                None => None,
+            };
+            if let Some((headline, reason, hint)) = filtered {
+                empty.headline = headline.to_string();
+                empty.reason = Some(reason.to_string());
+                empty.hint = hint.to_string();
             }
         }
+        Some(empty)
     }
 
     /// Render the URL input dialog as a centered modal overlay
@@ -3625,7 +8011,7 @@ impl TuiApp {
             .split(dialog_area);
 
         // Prompt
-        let prompt = Paragraph::new("Enter a ClickUp URL:").style(
+        let prompt = Paragraph::new("Enter a ClickUp URL or custom task ID (e.g. DEV-42):").style(
             Style::default()
                 .fg(ratatui::style::Color::Rgb(205, 214, 244))
                 .add_modifier(Modifier::BOLD),
@@ -3633,7 +8019,7 @@ impl TuiApp {
         frame.render_widget(prompt, inner[0]);
 
         // Input field with cursor
-        let cursor_pos = self.url_input_cursor.min(self.url_input_text.len());
+        let cursor_pos = self.url_input_cursor.min(self.url_input_text.chars().count());
         let input_line: Line = if self.url_input_text.is_empty() {
             Line::from(Span::styled(
                 "https://app.clickup.com/...",
@@ -3693,7 +8079,11 @@ impl TuiApp {
             Screen::Workspaces | Screen::Spaces | Screen::Folders | Screen::Lists => {
                 HelpContext::Navigation
             }
-            Screen::Tasks => HelpContext::TaskList,
+            Screen::Tasks
+            | Screen::MyTasks
+            | Screen::MyWork
+            | Screen::RecentTasks
+            | Screen::WorkspaceViews => HelpContext::TaskList,
             Screen::TaskDetail => {
                 if self.comment_focus {
                     HelpContext::Comments
@@ -3701,40 +8091,598 @@ impl TuiApp {
                     HelpContext::TaskDetail
                 }
             }
-            Screen::Document => HelpContext::Document,
+            Screen::Document | Screen::Documents | Screen::Sprint => HelpContext::Document,
+            Screen::Calendar => HelpContext::Calendar,
+        }
+    }
+
+    /// What the token may do with the task in focus: the open task in task
+    /// detail, the selected one in the task list
+    fn focused_task_capabilities(&self) -> Capabilities {
+        let task = match self.screen {
+            Screen::TaskDetail => self.task_detail.task.as_ref(),
+            _ => self.task_list.selected_task(),
+        };
+        task.map_or(Capabilities::FULL, Task::capabilities)
+    }
+
+    /// Whether `comment` may be edited or deleted: one's own comments need
+    /// comment access to the task, others' need edit access
+    fn can_change_comment(&self, comment: &Comment) -> bool {
+        let caps = self.focused_task_capabilities();
+        let own = match (self.current_user_id, &comment.commenter) {
+            (Some(me), Some(author)) => author.id == i64::from(me),
+            // Unknown author or user: leave it to ClickUp
+            _ => true,
+        };
+        caps.edit || (own && caps.comment)
+    }
+
+    /// Refuse an action the token's access to the task doesn't allow
+    fn deny_read_only(&mut self, action: &str) {
+        self.status = format!("Read-only access: can't {}", action);
+    }
+
+    /// Whether actions using `feature` are offered for the open task: by
+    /// its space's features when that space is loaded, otherwise by the
+    /// current workspace's. Unknown features are offered.
+    fn offers(&self, feature: Feature) -> bool {
+        self.current_features()
+            .is_none_or(|features| features.allows(feature))
+    }
+
+    /// Features of the open task's space, else of the current workspace
+    fn current_features(&self) -> Option<&Features> {
+        let space = self
+            .task_detail
+            .task
+            .as_ref()
+            .and_then(|task| task.space.as_ref())
+            .and_then(|space| self.spaces.iter().find(|s| s.id == space.id));
+        if let Some(space) = space {
+            return Some(&space.features);
         }
+        self.current_workspace_id
+            .as_ref()
+            .and_then(|id| self.workspace_features.get(id))
+    }
+
+    /// Refuse an action whose feature the plan or space doesn't have on
+    fn deny_missing_feature(&mut self, feature: Feature) {
+        self.status = format!("Not available on your plan: {}", feature.label());
     }
 
     fn get_hints(&self) -> String {
         if self.dialog.is_visible() {
             get_dialog_hints().to_string()
+        } else if self.error_detail_open {
+            "j/k: Scroll | Esc: Close".to_string()
         } else if self.status_picker_open {
             "j/k: Navigate | Enter: Select | Esc: Cancel".to_string()
+        } else if self.comment_assignee_picker.is_some() {
+            "Type to filter | ↑/↓: Navigate | Enter: Assign | Esc: Cancel".to_string()
+        } else if self.tag_color_picker.is_some() {
+            "Arrows: Move | Enter: Create | Esc: Cancel".to_string()
+        } else if let Some(prompt) = &self.name_prompt {
+            prompt.hints().to_string()
         } else if self.help.visible {
             get_help_hints(&self.help)
         } else {
             match self.screen {
+                Screen::Auth if self.auth_state.wizard => match self.auth_state.onboarding_step {
+                    STEP_WELCOME => "Enter: Continue | Ctrl+Q: Quit".to_string(),
+                    STEP_TOKEN => "Enter: Connect | Ctrl+Y: Copy link | Esc: Back | ? - Help".to_string(),
+                    STEP_VERIFYING => "Verifying token...".to_string(),
+                    _ => "Enter: Start".to_string(),
+                },
                 Screen::Auth => "Enter: Connect | Esc: Cancel | ? - Help".to_string(),
+                Screen::Tasks if self.task_list.has_marks() => {
+                    "Space: Mark | Ctrl+D: Delete marked | Ctrl+A: Archive marked | Esc: Clear marks | ? - Help"
+                        .to_string()
+                }
                 Screen::Tasks => {
-                    "j/k: Navigate | Enter: View | n: New | e: Edit | d: Delete | a: Filter | s: Status | ? - Help".to_string()
+                    // Actions the selected task's access doesn't allow are left out
+                    let caps = self.focused_task_capabilities();
+                    [
+                        Some("j/k: Navigate | Enter: View | n: New"),
+                        caps.edit.then_some("e: Edit"),
+                        caps.delete.then_some("d: Delete"),
+                        Some("a: Filter"),
+                        caps.edit.then_some("s: Status"),
+                        Some("o: Sort | V: Views | H: Archived | Space: Mark | ': Jump | ? - Help"),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" | ")
                 }
                 Screen::TaskDetail => {
+                    let caps = self.focused_task_capabilities();
                     // Show different hints based on comment view mode
-                    if self.comment_focus {
+                    let parts = if self.comment_focus {
                         match self.comment_view_mode {
-                            CommentViewMode::TopLevel => "j/k: Navigate | Enter: View thread | n: New comment | e: Edit | Tab: Task form | ? - Help".to_string(),
-                            CommentViewMode::InThread { .. } => "j/k: Navigate | r: Reply | Esc: Back | Tab: Task form | ? - Help".to_string(),
+                            CommentViewMode::TopLevel => {
+                                let change = self
+                                    .comments
+                                    .get(self.comment_selected_index)
+                                    .is_some_and(|c| self.can_change_comment(c));
+                                vec![
+                                    Some("j/k: Navigate | Enter: View thread | Space: Expand"),
+                                    caps.comment.then_some("n: New comment"),
+                                    change.then_some("e: Edit | x: Delete"),
+                                    caps.comment.then_some("a: Assign"),
+                                    Some("t: To task | Tab: Task form | ? - Help"),
+                                ]
+                            }
+                            CommentViewMode::InThread { .. } => vec![
+                                Some("j/k: Navigate"),
+                                caps.comment.then_some("r: Reply"),
+                                Some("Esc: Back | Tab: Task form | ? - Help"),
+                            ],
                         }
                     } else {
-                        "e: Edit task | Tab: Comments | Esc: Back | ? - Help".to_string()
-                    }
+                        vec![
+                            caps.is_read_only().then_some("Read-only"),
+                            caps.edit.then_some("e: Edit task"),
+                            Some("|: Pager | Tab: Comments | Esc: Back | ? - Help"),
+                        ]
+                    };
+                    parts.into_iter().flatten().collect::<Vec<_>>().join(" | ")
                 }
                 Screen::Document => "j/k: Scroll | Esc: Close | ? - Help".to_string(),
+                Screen::Sprint => format!("{} | ? - Help", get_sprint_hints()),
+                Screen::Documents => "Type to search | ↑/↓: Navigate | Enter: Open | Esc: Back".to_string(),
+                Screen::MyTasks | Screen::MyWork => "j/k: Navigate | Enter: View | r: Refresh | Esc: Back | ? - Help".to_string(),
+                Screen::RecentTasks => "j/k: Navigate | Enter: Open | Esc: Back | ? - Help".to_string(),
+                Screen::WorkspaceViews => "j/k: Navigate | Enter: Open | r: Refresh | Esc: Back | ? - Help".to_string(),
+                Screen::Calendar => "h/l: Day | j/k: Week | H/L: Month | Enter: Show day's tasks | Esc: Back | ? - Help".to_string(),
                 _ => "j/k: Navigate | Enter: Select | Tab: Toggle | Ctrl+Q: Quit | ? - Help".to_string(),
             }
         }
     }
 
+    /// Ask for the name of a new space in the selected workspace, or of a
+    /// new folder in the selected space
+    fn open_name_prompt(&mut self) {
+        let item = match (&self.screen, self.sidebar.selected_item()) {
+            (Screen::Workspaces, Some(SidebarItem::Workspace { id, .. })) => NewItem::Space {
+                workspace_id: id.clone(),
+            },
+            (Screen::Spaces, Some(SidebarItem::Space { id, .. })) => NewItem::Folder {
+                space_id: id.clone(),
+            },
+            // A workspace without spaces needs one before it can have folders
+            (Screen::Spaces, None) if self.current_workspace_id.is_some() => NewItem::Space {
+                workspace_id: self.current_workspace_id.clone().unwrap_or_default(),
+            },
+            (Screen::Workspaces, _) => {
+                self.status = "Select a workspace to add a space to".to_string();
+                return;
+            }
+            _ => {
+                self.status = "Select a space to add a folder to".to_string();
+                return;
+            }
+        };
+        self.name_prompt = Some(NamePromptState::new(item));
+    }
+
+    /// Ask for the name of a new tag for the open task
+    fn open_tag_prompt(&mut self) {
+        let Some(task) = &self.task_detail.task else {
+            self.status = "No task selected".to_string();
+            return;
+        };
+        let Some(space) = &task.space else {
+            self.status = "Can't tag a task whose space is unknown".to_string();
+            return;
+        };
+        self.name_prompt = Some(NamePromptState::new(NewItem::Tag {
+            space_id: space.id.clone(),
+            task_id: task.id.clone(),
+        }));
+    }
+
+    /// Handle keyboard input within the new tag's color picker
+    fn handle_tag_color_picker_input(&mut self, key: crossterm::event::KeyEvent) {
+        let Some((_, picker)) = self.tag_color_picker.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.tag_color_picker = None;
+                self.status = "Tag cancelled".to_string();
+            }
+            KeyCode::Enter => {
+                let (tag, picker) = self.tag_color_picker.take().expect("picker is open");
+                self.create_tag(tag, picker.bg_hex(), picker.fg_hex());
+            }
+            KeyCode::Left => picker.move_left(),
+            KeyCode::Right => picker.move_right(),
+            KeyCode::Up => picker.move_up(),
+            KeyCode::Down => picker.move_down(),
+            _ => {}
+        }
+    }
+
+    /// Create a tag in its space and add it to its task
+    fn create_tag(&mut self, tag: NewTag, bg_color: &str, fg_color: &str) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.loading = true;
+        self.status = format!("Creating tag {}...", tag.name);
+        let (bg_color, fg_color) = (bg_color.to_string(), fg_color.to_string());
+        tokio::spawn(async move {
+            let result = async {
+                let created = client
+                    .create_space_tag(&tag.space_id, &tag.name, &bg_color, &fg_color)
+                    .await?;
+                client.tag_task(&tag.task_id, &created.name).await?;
+                Ok(created)
+            }
+            .await
+            .map_err(|e: anyhow::Error| error_chain(&e));
+            let _ = tx.send(AppMessage::TaskTagged(tag.task_id, result)).await;
+        });
+    }
+
+    /// Handle a key while the Related Tasks section of the task detail has focus
+    fn handle_links_input(&mut self, key: crossterm::event::KeyEvent) {
+        let Some(task) = self.task_detail.task.clone() else {
+            return;
+        };
+        let related = task.related_tasks();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('l') => {
+                self.task_detail.links_focused = false;
+                self.status = "Focus: Task form".to_string();
+            }
+            KeyCode::Char('j') | KeyCode::Down
+                if self.task_detail.link_selected + 1 < related.len() =>
+            {
+                self.task_detail.link_selected += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.task_detail.link_selected = self.task_detail.link_selected.saturating_sub(1);
+            }
+            KeyCode::Char('n') => {
+                if !task.capabilities().edit {
+                    self.deny_read_only("link this task");
+                    return;
+                }
+                self.name_prompt = Some(NamePromptState::new(NewItem::TaskLink {
+                    task_id: task.id.clone(),
+                }));
+            }
+            KeyCode::Char('d') => {
+                let Some(entry) = related.get(self.task_detail.link_selected) else {
+                    self.status = "No link selected".to_string();
+                    return;
+                };
+                if entry.kind != LinkKind::Related {
+                    self.status = format!("{} is a dependency, not a link", entry.task_id);
+                    return;
+                }
+                if !task.capabilities().edit {
+                    self.deny_read_only("unlink this task");
+                    return;
+                }
+                let label = match &entry.name {
+                    Some(name) => format!("{} ({})", name, entry.task_id),
+                    None => entry.task_id.clone(),
+                };
+                self.dialog.show(DialogType::ConfirmRemoveLink {
+                    task_id: task.id.clone(),
+                    link_id: entry.task_id.clone(),
+                    label,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Keep the selected related task in range after one is removed
+    fn clamp_link_selection(&mut self) {
+        let count = self
+            .task_detail
+            .task
+            .as_ref()
+            .map_or(0, |t| t.related_tasks().len());
+        self.task_detail.link_selected = self.task_detail.link_selected.min(count.saturating_sub(1));
+    }
+
+    /// Link a task to the one with ID `links_to`
+    fn add_task_link(&mut self, task_id: String, links_to: String) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        if links_to == task_id {
+            self.status = "A task can't be linked to itself".to_string();
+            return;
+        }
+        self.loading = true;
+        self.status = format!("Linking to {}...", links_to);
+        tokio::spawn(async move {
+            let result = client
+                .add_task_link(&task_id, &links_to)
+                .await
+                .map_err(|e| error_chain(&e));
+            let _ = tx.send(AppMessage::TaskLinkAdded(task_id, result)).await;
+        });
+    }
+
+    /// Remove a task's link to `link_id`
+    fn remove_task_link(&mut self, task_id: String, link_id: String) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.loading = true;
+        self.status = format!("Unlinking {}...", link_id);
+        tokio::spawn(async move {
+            let result = client
+                .remove_task_link(&task_id, &link_id)
+                .await
+                .map_err(|e| error_chain(&e));
+            let _ = tx
+                .send(AppMessage::TaskLinkRemoved(task_id, link_id, result))
+                .await;
+        });
+    }
+
+    /// Handle keyboard input within the name prompt
+    fn handle_name_prompt_input(&mut self, key: crossterm::event::KeyEvent) {
+        let Some(prompt) = self.name_prompt.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.name_prompt = None,
+            KeyCode::Enter => {
+                if let Some(name) = prompt.submit() {
+                    let prompt = self.name_prompt.take().expect("prompt is open");
+                    match prompt.item {
+                        NewItem::Tag { space_id, task_id } => {
+                            let picker = ColorPickerState::new(format!("Color for {}", name));
+                            let tag = NewTag {
+                                space_id,
+                                task_id,
+                                name,
+                            };
+                            self.tag_color_picker = Some((tag, picker));
+                        }
+                        NewItem::TaskLink { task_id } => self.add_task_link(task_id, name),
+                        item => self.create_hierarchy_item(item, name, prompt.private),
+                    }
+                }
+            }
+            KeyCode::Tab => prompt.toggle_private(),
+            _ => {
+                prompt.edit(key);
+            }
+        }
+    }
+
+    /// Fetch the saved views of the current list for the view picker
+    fn load_list_views(&mut self) {
+        let Some(list_id) = self.current_list_id.clone() else {
+            return;
+        };
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.loading = true;
+        self.status = "Loading views...".to_string();
+        tokio::spawn(async move {
+            let result = client
+                .get_list_views(&list_id)
+                .await
+                .map_err(|e| error_chain(&e));
+            let _ = tx.send(AppMessage::ListViewsLoaded(result, list_id)).await;
+        });
+    }
+
+    /// Handle input in the saved view picker: typing filters, Enter opens
+    fn handle_view_picker_input(&mut self, key: crossterm::event::KeyEvent) {
+        let Some(picker) = self.view_picker.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.view_picker = None,
+            KeyCode::Enter => {
+                if let Some(view) = picker.selected_item().cloned() {
+                    self.view_picker = None;
+                    self.load_view_tasks(view);
+                }
+            }
+            KeyCode::Down => picker.select_next(),
+            KeyCode::Up => picker.select_previous(),
+            _ => {
+                picker.edit(key);
+            }
+        }
+    }
+
+    /// Handle input in the comment assignee picker: typing filters, Enter assigns
+    fn handle_comment_assignee_picker_input(&mut self, key: crossterm::event::KeyEvent) {
+        let Some((_, picker)) = self.comment_assignee_picker.as_mut() else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.comment_assignee_picker = None;
+                self.status = "Assignment cancelled".to_string();
+            }
+            KeyCode::Enter => {
+                if let Some(user) = picker.selected_item().cloned() {
+                    let (comment_id, _) = self.comment_assignee_picker.take().expect("picker is open");
+                    self.assign_comment(comment_id, user);
+                }
+            }
+            KeyCode::Down => picker.select_next(),
+            KeyCode::Up => picker.select_previous(),
+            _ => {
+                picker.edit(key);
+            }
+        }
+    }
+
+    /// Assign a comment to `assignee`
+    fn assign_comment(&mut self, comment_id: String, assignee: User) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        // ClickUp expects the text along with the assignee
+        let text = self
+            .comments
+            .iter()
+            .find(|c| c.id == comment_id)
+            .map(|c| c.text.clone());
+        let request = UpdateCommentRequest {
+            comment_text: text,
+            assigned: None,
+            assignee: Some(assignee.id),
+            assigned_commenter: None,
+        };
+        self.loading = true;
+        self.status = format!("Assigning comment to {}...", assignee.username);
+
+        tokio::spawn(async move {
+            let result = client.update_comment_checked(&comment_id, &request).await;
+            let msg = AppMessage::CommentAssigned(
+                comment_id,
+                result.map(|_| assignee).map_err(|e| error_chain(&e)),
+            );
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Show the tasks of a saved view in place of the current list's tasks
+    ///
+    /// The result arrives as a task load of the current list, so leaving the
+    /// list or opening another one discards it; reloading the list (or
+    /// toggling the assignee filter) goes back to all of its tasks.
+    fn load_view_tasks(&mut self, view: View) {
+        let Some(list_id) = self.current_list_id.clone() else {
+            return;
+        };
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.loading = true;
+        self.status = format!("Loading view {}...", view.name);
+        let ticket = self.start_load(LoadKind::Tasks, &list_id);
+        let view_id = view.id.clone();
+        self.active_view = Some(view);
+        self.update_screen_title();
+        tokio::spawn(async move {
+            let msg = match client.get_view_tasks(&view_id).await {
+                Ok(tasks) => AppMessage::TasksLoaded(
+                    Ok(TaskPages {
+                        tasks,
+                        truncated: false,
+                    }),
+                    ticket,
+                ),
+                Err(e) => AppMessage::TasksLoaded(Err(error_chain(&e)), ticket),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Open the views saved on the workspace selected on the Workspaces
+    /// screen, from the cache when they were fetched before
+    pub fn open_workspace_views(&mut self) {
+        let Some(SidebarItem::Workspace { id, .. }) = self.sidebar.selected_item().cloned() else {
+            return;
+        };
+        self.workspace_views = WorkspaceViewsState::new(id.clone());
+        self.screen = Screen::WorkspaceViews;
+        self.update_screen_title();
+        match self.cached_workspace_views.get(&id) {
+            Some(views) => self.workspace_views.set_views(views.clone()),
+            None => self.load_workspace_views(id),
+        }
+    }
+
+    /// Fetch the views saved on a workspace
+    fn load_workspace_views(&mut self, workspace_id: String) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.workspace_views.loading = true;
+        self.status = "Loading views...".to_string();
+        tokio::spawn(async move {
+            let result = client
+                .get_workspace_views(&workspace_id)
+                .await
+                .map_err(|e| error_chain(&e));
+            let _ = tx
+                .send(AppMessage::WorkspaceViewsLoaded(result, workspace_id))
+                .await;
+        });
+    }
+
+    /// Fetch the tasks shown in the open workspace view
+    fn load_workspace_view_tasks(&mut self) {
+        let Some(view_id) = self.workspace_views.open_view.as_ref().map(|v| v.id.clone()) else {
+            return;
+        };
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.workspace_views.loading = true;
+        self.status = "Loading view tasks...".to_string();
+        tokio::spawn(async move {
+            let result = client
+                .get_view_tasks(&view_id)
+                .await
+                .map_err(|e| error_chain(&e));
+            let _ = tx
+                .send(AppMessage::WorkspaceViewTasksLoaded(result, view_id))
+                .await;
+        });
+    }
+
+    /// Create a space or folder in the background
+    fn create_hierarchy_item(&mut self, item: NewItem, name: String, private: bool) {
+        let (Some(client), Some(tx)) = (self.client.clone(), self.message_tx.clone()) else {
+            self.error = Some("Not authenticated".to_string());
+            return;
+        };
+        self.loading = true;
+        self.status = format!("Creating {}...", name);
+        tokio::spawn(async move {
+            let msg = match item {
+                NewItem::Space { workspace_id } => AppMessage::SpaceCreated(
+                    client
+                        .create_space(&workspace_id, &name, private)
+                        .await
+                        .map_err(|e| error_chain(&e)),
+                    workspace_id,
+                ),
+                NewItem::Folder { space_id } => AppMessage::FolderCreated(
+                    client
+                        .create_folder(&space_id, &name)
+                        .await
+                        .map_err(|e| error_chain(&e)),
+                    space_id,
+                ),
+                // Tags are created once their color is picked, by `create_tag`,
+                // and links by `add_task_link`
+                NewItem::Tag { .. } | NewItem::TaskLink { .. } => return,
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
+
     /// Open the URL input dialog
     fn open_url_input_dialog(&mut self) {
         // Guard: must be authenticated
@@ -3756,6 +8704,16 @@ impl TuiApp {
         }
 
         let url = self.url_input_text.clone();
+        if is_custom_task_id(url.trim()) {
+            let Some(workspace_id) = self.current_workspace_id.clone() else {
+                self.url_input_error =
+                    Some("Open a workspace to look up custom task IDs".to_string());
+                return;
+            };
+            self.close_url_input_dialog();
+            self.navigate_to_custom_task_id(url.trim().to_string(), workspace_id);
+            return;
+        }
         self.close_url_input_dialog();
 
         // Parse the URL
@@ -3770,7 +8728,7 @@ impl TuiApp {
                 self.url_input_text = url.clone();
                 let error_msg = format!("Unrecognized ClickUp URL format: {}", e);
                 self.url_input_error = Some(error_msg);
-                self.url_input_cursor = url.len();
+                self.url_input_cursor = url.chars().count();
             }
         }
     }
@@ -3797,38 +8755,45 @@ impl TuiApp {
                 // Paste from clipboard
                 use arboard::Clipboard;
                 if let Ok(mut clipboard) = Clipboard::new() {
-                    if let Ok(text) = clipboard.get_text() {
-                        for c in text.chars() {
-                            self.url_input_text.insert(self.url_input_cursor, c);
-                            self.url_input_cursor += 1;
-                        }
-                        self.url_input_error = None;
-                    }
-                }
-            }
-            KeyCode::Char(c) => {
-                self.url_input_text.insert(self.url_input_cursor, c);
-                self.url_input_cursor += 1;
-                self.url_input_error = None;
-            }
-            KeyCode::Backspace => {
-                if self.url_input_cursor > 0 {
-                    self.url_input_text.remove(self.url_input_cursor - 1);
-                    self.url_input_cursor -= 1;
-                    self.url_input_error = None;
-                }
-            }
-            KeyCode::Left => {
-                if self.url_input_cursor > 0 {
-                    self.url_input_cursor -= 1;
+                    if let Ok(text) = clipboard.get_text() {
+                        LineEditor::new(&mut self.url_input_text, &mut self.url_input_cursor)
+                            .insert_str(&text);
+                        self.url_input_error = None;
+                    }
                 }
             }
-            KeyCode::Right => {
-                if self.url_input_cursor < self.url_input_text.len() {
-                    self.url_input_cursor += 1;
+            _ => {
+                let mut editor = LineEditor::new(&mut self.url_input_text, &mut self.url_input_cursor);
+                if editor.handle_key(key) {
+                    self.url_input_error = None;
                 }
             }
-            _ => {}
+        }
+    }
+
+    /// Handle text pasted on the token screen
+    ///
+    /// A ClickUp task link is kept aside and opened once the user has
+    /// connected; anything else is typed into the token input.
+    pub fn paste_on_auth_screen(&mut self, text: &str) {
+        if ClickUpUrlGenerator::extract_task_id(text).is_some() {
+            self.auth_state.pending_link = Some(text.trim().to_string());
+            self.status = "ClickUp link found: it opens after you connect".to_string();
+            return;
+        }
+        self.auth_state.editor().insert_str(text);
+        self.status = "Pasted from clipboard".to_string();
+    }
+
+    /// Open the task link pasted on the token screen, if any
+    fn open_pending_auth_link(&mut self) {
+        let Some(link) = self.auth_state.pending_link.take() else {
+            return;
+        };
+        if let Some((workspace_id, task_id)) = ClickUpUrlGenerator::extract_custom_task_id(&link) {
+            self.open_target(OpenTarget::WorkspaceTask { workspace_id, task_id });
+        } else if let Some(task_id) = ClickUpUrlGenerator::extract_task_id(&link) {
+            self.open_target(OpenTarget::Task { task_id });
         }
     }
 
@@ -3851,6 +8816,9 @@ impl TuiApp {
             ParsedUrl::Task { task_id } => {
                 self.navigate_to_task(task_id);
             }
+            ParsedUrl::WorkspaceTask { workspace_id, task_id } => {
+                self.navigate_to_workspace_task(task_id, workspace_id);
+            }
             ParsedUrl::Comment { task_id, comment_id } => {
                 self.navigate_to_comment(task_id, comment_id);
             }
@@ -3860,6 +8828,123 @@ impl TuiApp {
         }
     }
 
+    /// Open a resource given on the command line (deep link)
+    ///
+    /// Session restore is cancelled so the replayed navigation does not override
+    /// the target. Failures show a toast and leave the normal start screen.
+    pub fn open_target(&mut self, target: OpenTarget) {
+        if self.client.is_none() {
+            self.show_toast("Log in before opening links".to_string());
+            return;
+        }
+        self.restoring_session = false;
+
+        match target {
+            OpenTarget::Task { task_id } if is_custom_task_id(&task_id) => {
+                match self.current_workspace_id.clone() {
+                    Some(workspace_id) => self.navigate_to_custom_task_id(task_id, workspace_id),
+                    None => self.show_toast("Open a workspace to look up custom task IDs".to_string()),
+                }
+            }
+            OpenTarget::Task { task_id } => self.navigate_to_task(task_id),
+            OpenTarget::WorkspaceTask { workspace_id, task_id } => {
+                self.navigate_to_workspace_task(task_id, workspace_id)
+            }
+            OpenTarget::List { list_id } => {
+                self.current_folder_id = None;
+                self.current_list_id = Some(list_id.clone());
+                self.screen = Screen::Tasks;
+                self.update_screen_title();
+                self.load_tasks(list_id);
+            }
+            OpenTarget::Document { doc_id } => self.navigate_to_document(doc_id),
+        }
+    }
+
+    /// Choose the screen the app starts on
+    ///
+    /// Before login the choice is kept and applied once authentication succeeds.
+    /// Targets that can't be opened fall back to Workspaces with a toast.
+    pub fn apply_start_screen(&mut self, start: StartScreen) {
+        if matches!(self.state, AppState::Unauthenticated) {
+            self.pending_start_screen = Some(start);
+            return;
+        }
+
+        match start {
+            StartScreen::LastLocation => {
+                if !self.restoring_session {
+                    if let Err(e) = self.restore_session_state() {
+                        tracing::warn!("Failed to restore session: {:#}", e);
+                    }
+                }
+                // Nothing to restore: start as if on Workspaces
+                if !self.restoring_session {
+                    self.auto_select_workspace = self.config.auto_select_single_workspace;
+                }
+            }
+            StartScreen::Workspaces => {
+                self.cancel_session_restore();
+                self.auto_select_workspace = self.config.auto_select_single_workspace;
+            }
+            StartScreen::MyTasks => {
+                self.cancel_session_restore();
+                if self.workspaces.is_empty() {
+                    // Opened once the workspaces have loaded
+                    self.pending_start_screen = Some(start);
+                } else {
+                    self.open_my_tasks();
+                }
+            }
+        }
+    }
+
+    /// Open the only workspace once the first load after startup finds one
+    ///
+    /// Going back from its spaces still shows the Workspaces screen, with
+    /// the workspace selected.
+    fn auto_select_single_workspace(&mut self) {
+        if !std::mem::take(&mut self.auto_select_workspace)
+            || self.workspaces.len() != 1
+            || self.screen != Screen::Workspaces
+        {
+            return;
+        }
+        self.sidebar.select_first();
+        self.navigate_into();
+    }
+
+    /// Drop a session restore in progress and start from Workspaces
+    ///
+    /// The last session's workspace is remembered so it starts out selected.
+    fn cancel_session_restore(&mut self) {
+        self.last_workspace_id = match self.cache.load_session_state() {
+            Ok(state) => state.and_then(|state| state.workspace_id),
+            Err(e) => {
+                tracing::warn!("Failed to read last session: {:#}", e);
+                None
+            }
+        };
+        self.restoring_session = false;
+        self.restored_workspace_id = None;
+        self.restored_space_id = None;
+        self.restored_folder_id = None;
+        self.restored_list_id = None;
+        self.restored_task_id = None;
+        self.current_workspace_id = None;
+        self.current_space_id = None;
+        self.current_folder_id = None;
+        self.current_list_id = None;
+        self.screen = Screen::Workspaces;
+        self.update_screen_title();
+    }
+
+    /// Show a transient message in the status bar
+    fn show_toast(&mut self, message: String) {
+        self.url_copy_status = Some(message);
+        self.url_copy_status_time = Some(std::time::Instant::now());
+    }
+
     // --- URL-based navigation implementations ---
 
     /// Navigate to a workspace by ID
@@ -4019,12 +9104,48 @@ impl TuiApp {
             let result = client.get_task(&target_task_id).await;
             let msg = match result {
                 Ok(task) => AppMessage::TaskFetchedForNavigation(Ok(task), prev_screen),
-                Err(e) => AppMessage::TaskFetchedForNavigation(Err(e.to_string()), prev_screen),
+                Err(e) => AppMessage::TaskFetchedForNavigation(Err(error_chain(&e)), prev_screen),
             };
             let _ = tx.send(msg).await;
         });
     }
 
+    /// Navigate to a task by its custom ID (e.g. `DEV-42`) in a workspace
+    fn navigate_to_custom_task_id(&mut self, custom_id: String, workspace_id: String) {
+        self.navigating = true;
+        self.navigating_level = "task".to_string();
+
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => {
+                self.navigating = false;
+                self.navigating_level.clear();
+                self.status = "Not authenticated".to_string();
+                return;
+            }
+        };
+
+        let tx = self.message_tx.clone().unwrap();
+        let prev_screen = self.screen.clone();
+        tokio::spawn(async move {
+            let result = client.get_task_by_custom_id(&custom_id, &workspace_id).await;
+            let msg = AppMessage::TaskFetchedForNavigation(
+                result.map_err(|e| error_chain(&e)),
+                prev_screen,
+            );
+            let _ = tx.send(msg).await;
+        });
+    }
+
+    /// Navigate to a task linked under a workspace, looking up custom IDs there
+    fn navigate_to_workspace_task(&mut self, task_id: String, workspace_id: String) {
+        if is_custom_task_id(&task_id) {
+            self.navigate_to_custom_task_id(task_id, workspace_id);
+        } else {
+            self.navigate_to_task(task_id);
+        }
+    }
+
     /// Navigate to a comment by task ID + comment ID
     fn navigate_to_comment(&mut self, task_id: String, comment_id: String) {
         // First navigate to the task, then find the comment
@@ -4054,7 +9175,7 @@ impl TuiApp {
                     prev_screen,
                 ),
                 Err(e) => AppMessage::CommentFetchedForNavigation(
-                    Err(e.to_string()),
+                    Err(error_chain(&e)),
                     target_comment_id,
                     prev_screen,
                 ),
@@ -4082,63 +9203,37 @@ impl TuiApp {
         let target_doc_id = doc_id.clone();
         let prev_screen = self.screen.clone();
         tokio::spawn(async move {
-            // Search for the document by ID using filters
-            use crate::models::DocumentFilters;
-            let filters = DocumentFilters::default();
-            let result = client.search_docs(&filters).await;
-            match result {
-                Ok(docs) => {
-                    // Find the matching document
-                    let doc = docs.iter().find(|d| d.id == target_doc_id);
-                    if let Some(doc) = doc.cloned() {
-                        let _ = tx
-                            .send(AppMessage::DocumentFetchedForNavigation(Ok(doc), prev_screen))
-                            .await;
-                    } else {
-                        let _ = tx
-                            .send(AppMessage::DocumentFetchedForNavigation(
-                                Err("Document not found in search results".to_string()),
-                                prev_screen,
-                            ))
-                            .await;
-                    }
-                }
-                Err(e) => {
-                    let _ = tx
-                        .send(AppMessage::DocumentFetchedForNavigation(
-                            Err(e.to_string()),
-                            prev_screen,
-                        ))
-                        .await;
-                }
-            }
+            let result = client.get_document(&target_doc_id).await;
+            let msg =
+                AppMessage::DocumentFetchedForNavigation(result.map_err(|e| error_chain(&e)), prev_screen);
+            let _ = tx.send(msg).await;
         });
     }
 
+    /// URL of `task`: the long form under its own list when the workspace
+    /// is known, the short form otherwise
+    fn task_url(&self, task: &Task) -> crate::utils::url_generator::UrlResult<String> {
+        ClickUpUrlGenerator::task_url(
+            self.current_workspace_id.as_deref().unwrap_or(""),
+            task.list.as_ref().map_or("", |list| list.id.as_str()),
+            &task.id,
+        )
+    }
+
     /// Copy URL for the current context to clipboard
     fn copy_url(&mut self) {
         tracing::debug!("copy_url called, screen: {:?}", self.screen);
 
-        // Helper to get ID from sidebar item
-        fn get_sidebar_id(item: &SidebarItem) -> &str {
-            match item {
-                SidebarItem::Workspace { id, .. } => id,
-                SidebarItem::Space { id, .. } => id,
-                SidebarItem::Folder { id, .. } => id,
-                SidebarItem::List { id, .. } => id,
-            }
-        }
-
         let url_result = match self.screen {
             Screen::Auth => {
-                self.url_copy_status = Some("URL copy not available on auth screen".to_string());
+                self.show_toast("URL copy not available on auth screen".to_string());
                 return;
             }
             Screen::Workspaces => {
                 if let Some(ws) = self.sidebar.selected_item() {
-                    ClickUpUrlGenerator::workspace_url(get_sidebar_id(ws))
+                    ClickUpUrlGenerator::workspace_url(ws.id())
                 } else {
-                    self.url_copy_status = Some("No workspace selected".to_string());
+                    self.show_toast("No workspace selected".to_string());
                     return;
                 }
             }
@@ -4146,27 +9241,33 @@ impl TuiApp {
                 if let Some(space) = self.sidebar.selected_item() {
                     // Use tracked workspace context
                     if let Some(ref ws_id) = self.current_workspace_id {
-                        ClickUpUrlGenerator::space_url(ws_id, get_sidebar_id(space))
+                        ClickUpUrlGenerator::space_url(ws_id, space.id())
                     } else {
-                        self.url_copy_status = Some("Missing workspace context".to_string());
+                        self.show_toast("Missing workspace context".to_string());
                         return;
                     }
                 } else {
-                    self.url_copy_status = Some("No space selected".to_string());
+                    self.show_toast("No space selected".to_string());
                     return;
                 }
             }
             Screen::Folders => {
-                if let Some(folder) = self.sidebar.selected_item() {
+                if let Some(item) = self.sidebar.selected_item() {
                     // Use tracked workspace context
                     if let Some(ref ws_id) = self.current_workspace_id {
-                        ClickUpUrlGenerator::folder_url(ws_id, get_sidebar_id(folder))
+                        match item {
+                            SidebarItem::List { id, .. } => ClickUpUrlGenerator::list_url(ws_id, id),
+                            SidebarItem::Document { id, .. } => {
+                                ClickUpUrlGenerator::document_url(ws_id, id)
+                            }
+                            _ => ClickUpUrlGenerator::folder_url(ws_id, item.id()),
+                        }
                     } else {
-                        self.url_copy_status = Some("Missing workspace context".to_string());
+                        self.show_toast("Missing workspace context".to_string());
                         return;
                     }
                 } else {
-                    self.url_copy_status = Some("No folder selected".to_string());
+                    self.show_toast("No folder selected".to_string());
                     return;
                 }
             }
@@ -4174,22 +9275,21 @@ impl TuiApp {
                 if let Some(list) = self.sidebar.selected_item() {
                     // Use tracked workspace context
                     if let Some(ref ws_id) = self.current_workspace_id {
-                        ClickUpUrlGenerator::list_url(ws_id, get_sidebar_id(list))
+                        ClickUpUrlGenerator::list_url(ws_id, list.id())
                     } else {
-                        self.url_copy_status = Some("Missing workspace context".to_string());
+                        self.show_toast("Missing workspace context".to_string());
                         return;
                     }
                 } else {
-                    self.url_copy_status = Some("No list selected".to_string());
+                    self.show_toast("No list selected".to_string());
                     return;
                 }
             }
             Screen::Tasks => {
                 if let Some(task) = self.task_list.selected_task() {
-                    // Short-form task URL: only need task ID
-                    ClickUpUrlGenerator::task_url("", "", &task.id)
+                    self.task_url(task)
                 } else {
-                    self.url_copy_status = Some("No task selected".to_string());
+                    self.show_toast("No task selected".to_string());
                     return;
                 }
             }
@@ -4204,15 +9304,15 @@ impl TuiApp {
                     if let Some(task) = &self.task_detail.task {
                         ClickUpUrlGenerator::comment_url("", "", &task.id, &comment.id)
                     } else {
-                        self.url_copy_status = Some("No task selected".to_string());
+                        self.show_toast("No task selected".to_string());
                         return;
                     }
                 } else {
                     // Copy task URL
                     if let Some(task) = &self.task_detail.task {
-                        ClickUpUrlGenerator::task_url("", "", &task.id)
+                        self.task_url(task)
                     } else {
-                        self.url_copy_status = Some("No task selected".to_string());
+                        self.show_toast("No task selected".to_string());
                         return;
                     }
                 }
@@ -4222,7 +9322,55 @@ impl TuiApp {
                     // Short-form document URL: only need doc ID
                     ClickUpUrlGenerator::document_url("", &doc.id)
                 } else {
-                    self.url_copy_status = Some("No document selected".to_string());
+                    self.show_toast("No document selected".to_string());
+                    return;
+                }
+            }
+            Screen::Sprint => {
+                self.show_toast("URL copy not available for sprints".to_string());
+                return;
+            }
+            Screen::Calendar => {
+                self.show_toast("URL copy not available for the calendar".to_string());
+                return;
+            }
+            Screen::Documents => {
+                if let Some(doc) = self.documents_browser.docs.selected() {
+                    ClickUpUrlGenerator::document_url("", &doc.id)
+                } else {
+                    self.show_toast("No document selected".to_string());
+                    return;
+                }
+            }
+            Screen::MyTasks => {
+                if let Some(task) = self.my_tasks.selected_task() {
+                    self.task_url(task)
+                } else {
+                    self.show_toast("No task selected".to_string());
+                    return;
+                }
+            }
+            Screen::MyWork => {
+                if let Some(task) = self.my_work.selected_task() {
+                    self.task_url(task)
+                } else {
+                    self.show_toast("No task selected".to_string());
+                    return;
+                }
+            }
+            Screen::RecentTasks => {
+                if let Some(task) = self.recent_tasks.tasks.selected() {
+                    ClickUpUrlGenerator::task_url("", &task.list_id, &task.task_id)
+                } else {
+                    self.show_toast("No task selected".to_string());
+                    return;
+                }
+            }
+            Screen::WorkspaceViews => {
+                if let Some(task) = self.workspace_views.tasks.selected_task() {
+                    self.task_url(task)
+                } else {
+                    self.show_toast("No task selected".to_string());
                     return;
                 }
             }
@@ -4235,7 +9383,7 @@ impl TuiApp {
                 url
             }
             Err(e) => {
-                self.url_copy_status = Some(format!("URL error: {}", e));
+                self.show_toast(format!("URL error: {}", e));
                 return;
             }
         };
@@ -4244,19 +9392,105 @@ impl TuiApp {
         match self.clipboard.copy_text(&url) {
             Ok(()) => {
                 // Show success with truncated URL
-                let truncated = if url.len() > 60 {
-                    format!("{}...", &url[..57])
-                } else {
-                    url.clone()
-                };
-                self.url_copy_status = Some(format!("Copied: {}", truncated));
-                self.url_copy_status_time = Some(std::time::Instant::now());
+                self.show_toast(format!("Copied: {}", truncate(&url, 60)));
             }
             Err(e) => {
-                self.url_copy_status = Some(format!("Failed to copy URL: {}", e));
-                self.url_copy_status_time = Some(std::time::Instant::now());
+                self.show_toast(format!("Failed to copy URL: {}", e));
+            }
+        }
+    }
+
+    /// Copy the plain-text dump of the current screen to the clipboard
+    fn copy_debug_snapshot(&mut self) {
+        let snapshot = self.debug_snapshot();
+        match self.clipboard.copy_text(&snapshot) {
+            Ok(()) => self.show_toast("Copied screen as text".to_string()),
+            Err(e) => self.show_toast(format!("Failed to copy screen: {}", e)),
+        }
+    }
+
+    /// Plain-text dump of the current screen's content, for bug reports
+    ///
+    /// Lists the screen, its title and status, then its items with the
+    /// selected one marked `>`. It doesn't depend on the terminal size, so the
+    /// same state always gives the same text.
+    pub fn debug_snapshot(&self) -> String {
+        let mut lines = vec![
+            format!("screen: {:?}", self.screen),
+            format!("title: {}", self.screen_title),
+            format!("status: {}", self.status_bar_text()),
+        ];
+        if let Some(error) = &self.error {
+            lines.push(format!("error: {}", error));
+        }
+
+        let (items, selected) = match self.screen {
+            Screen::Workspaces | Screen::Spaces | Screen::Folders | Screen::Lists => (
+                self.sidebar
+                    .items()
+                    .iter()
+                    .map(|item| format!("{} {}", item.kind_name(), item.name()))
+                    .collect(),
+                self.sidebar.state().selected(),
+            ),
+            Screen::Tasks => task_list_snapshot(&self.task_list),
+            Screen::MyTasks => task_list_snapshot(&self.my_tasks),
+            Screen::MyWork => task_list_snapshot(&self.my_work),
+            Screen::RecentTasks => (
+                self.recent_tasks
+                    .tasks
+                    .items()
+                    .iter()
+                    .map(|task| task.label().to_string())
+                    .collect(),
+                self.recent_tasks.tasks.state().selected(),
+            ),
+            Screen::TaskDetail => {
+                if let Some(task) = &self.task_detail.task {
+                    lines.push(format!("task: {} ({})", task.name, task.id));
+                    if let Some(status) = &task.status {
+                        lines.push(format!("task status: {}", status.status));
+                    }
+                }
+                let order = filter_comments(&self.comment_view_mode, &self.comments);
+                let selected = order
+                    .iter()
+                    .position(|&i| i == self.comment_selected_index)
+                    .filter(|_| self.comment_focus);
+                let comments = order
+                    .iter()
+                    .map(|&i| {
+                        let comment = &self.comments[i];
+                        let first_line = comment.text.lines().next().unwrap_or_default();
+                        format!("{}: {}", comment_author(comment), first_line)
+                    })
+                    .collect();
+                (comments, selected)
+            }
+            Screen::WorkspaceViews if self.workspace_views.open_view.is_some() => {
+                task_list_snapshot(&self.workspace_views.tasks)
+            }
+            Screen::WorkspaceViews => (
+                self.workspace_views
+                    .views
+                    .items()
+                    .iter()
+                    .map(|view| format!("{} {}", view.view_type, view.name))
+                    .collect(),
+                self.workspace_views.views.state().selected(),
+            ),
+            Screen::Calendar => day_descriptions(&self.calendar),
+            Screen::Auth | Screen::Document | Screen::Documents | Screen::Sprint => {
+                (Vec::new(), None)
             }
+        };
+
+        lines.push(format!("items: {}", items.len()));
+        for (i, item) in items.iter().enumerate() {
+            let marker = if selected == Some(i) { '>' } else { ' ' };
+            lines.push(format!("{} {}", marker, item));
         }
+        lines.join("\n")
     }
 
     /// Save the current session state to the cache
@@ -4361,6 +9595,31 @@ impl TuiApp {
                     Some("Saved document not found, showing tasks".to_string()),
                 )
             }
+            Screen::Documents => {
+                // The browser's search isn't saved
+                (
+                    Screen::Workspaces,
+                    Some("Document browser not restored, showing workspaces".to_string()),
+                )
+            }
+            Screen::Sprint => {
+                // Sprint views are not part of the saved navigation chain
+                (
+                    Screen::Tasks,
+                    Some("Sprint view not restored, showing tasks".to_string()),
+                )
+            }
+            Screen::MyTasks => (Screen::MyTasks, None),
+            Screen::MyWork => (Screen::MyWork, None),
+            Screen::RecentTasks => (Screen::RecentTasks, None),
+            Screen::WorkspaceViews => (
+                Screen::Workspaces,
+                Some("Workspace views not restored, showing workspaces".to_string()),
+            ),
+            Screen::Calendar => (
+                Screen::Tasks,
+                Some("Calendar not restored, showing tasks".to_string()),
+            ),
             Screen::TaskDetail => {
                 if saved_state.task_id.is_some() && saved_state.list_id.is_some() {
                     return (Screen::TaskDetail, None);
@@ -4442,22 +9701,93 @@ impl TuiApp {
         &mut self.task_list
     }
 
+    /// Switch to the next sort mode, refetching when the API does the ordering
+    fn cycle_task_sort(&mut self) {
+        self.task_sort_mode = self.task_sort_mode.next();
+        match (self.task_sort_mode.server_order(), self.current_list_id.clone()) {
+            // Only a server-side order brings the right tasks into a truncated list
+            (Some(_), Some(list_id)) if !self.assigned_filter_active => self.load_tasks(list_id),
+            _ => self.rebuild_task_list(),
+        }
+        self.status = format!("Sorted by {}", self.task_sort_mode.label());
+    }
+
     /// Rebuild the grouped task list from `self.tasks`.
-    /// Preserves the currently selected task by ID if it still exists.
+    /// Preserves the currently selected task by ID, or the nearest one if it is gone.
     fn rebuild_task_list(&mut self) {
-        let selected_id = self.task_list.selected_task().map(|t| t.id.clone());
-        self.task_list = GroupedTaskList::from_tasks(self.tasks.clone());
-        if let Some(ref id) = selected_id {
-            if !self
-                .task_list
+        let rebuilt = GroupedTaskList::from_tasks_sorted_by(self.shown_tasks(), self.task_sort_mode);
+        self.task_list.refresh_from(rebuilt);
+    }
+
+    /// `self.tasks`, narrowed to the day picked in the calendar if there is one
+    fn shown_tasks(&self) -> Vec<Task> {
+        match self.due_day_filter {
+            Some(day) if self.due_day_filter_on(&Screen::Tasks) => {
+                tasks_on_day(&self.tasks, day, self.calendar.today, &chrono::Local)
+            }
+            _ => self.tasks.clone(),
+        }
+    }
+
+    /// Whether `screen`'s list is narrowed to a day picked in the calendar
+    fn due_day_filter_on(&self, screen: &Screen) -> bool {
+        self.due_day_filter.is_some() && self.calendar_return_screen.as_ref() == Some(screen)
+    }
+
+    /// Open the due-date calendar over the tasks loaded on this screen
+    fn open_calendar(&mut self) {
+        let tasks = match self.screen {
+            Screen::Tasks => self.tasks.clone(),
+            // A list already narrowed to a day still counts all its tasks
+            Screen::MyTasks if self.due_day_filter_on(&Screen::MyTasks) => {
+                self.calendar.tasks.clone()
+            }
+            Screen::MyTasks => self
+                .my_tasks
                 .rows()
                 .iter()
-                .any(|r| matches!(r, crate::tui::widgets::ListRow::Task(t) if &t.id == id))
-            {
-                // Selected task no longer exists, select first
-                self.task_list.select_first();
-            }
+                .filter_map(|row| match row {
+                    ListRow::Task(task) => Some(task.as_ref().clone()),
+                    ListRow::Header { .. } => None,
+                })
+                .collect(),
+            _ => return,
+        };
+        let picked = self.due_day_filter.filter(|_| self.due_day_filter_on(&self.screen));
+        let today = chrono::Local::now().date_naive();
+        self.calendar = CalendarState::new(tasks, today, self.config.ui.week_start.weekday());
+        if let Some(day) = picked {
+            self.calendar.selected = day;
+        }
+        self.calendar_return_screen = Some(self.screen.clone());
+        self.screen = Screen::Calendar;
+        self.update_screen_title();
+    }
+
+    /// Narrow the list the calendar was opened from to the tasks on `day`,
+    /// or show all of them again
+    fn set_due_day_filter(&mut self, day: Option<chrono::NaiveDate>) {
+        self.due_day_filter = day;
+        if self.calendar_return_screen == Some(Screen::MyTasks) {
+            let tasks = match day {
+                Some(day) => {
+                    tasks_on_day(&self.calendar.tasks, day, self.calendar.today, &chrono::Local)
+                }
+                None => self.calendar.tasks.clone(),
+            };
+            self.my_tasks = GroupedTaskList::from_tasks(tasks).with_list_names();
+        } else {
+            self.rebuild_task_list();
+            self.task_list.select_first();
         }
+        self.status = match day {
+            Some(day) => format!(
+                "Due {}: {} task(s) (Esc: show all)",
+                day.format("%a %-d %b"),
+                self.calendar.count(day).total()
+            ),
+            None => "Showing all tasks".to_string(),
+        };
     }
 
     /// Public wrapper for testing
@@ -4478,6 +9808,12 @@ impl TuiApp {
         &self.status
     }
 
+    /// Get sprint overview state (public for testing)
+    #[allow(dead_code)]
+    pub fn sprint(&self) -> &SprintState {
+        &self.sprint
+    }
+
     /// Get cache manager (public for testing)
     #[allow(dead_code)]
     pub fn cache(&mut self) -> &mut crate::cache::CacheManager {
@@ -4490,12 +9826,60 @@ impl TuiApp {
         self.current_workspace_id.as_ref()
     }
 
+    /// Get current list ID (public for testing)
+    #[allow(dead_code)]
+    pub fn current_list_id(&self) -> Option<&String> {
+        self.current_list_id.as_ref()
+    }
+
+    /// Get the transient status-bar message, if any (public for testing)
+    #[allow(dead_code)]
+    pub fn toast(&self) -> Option<&str> {
+        self.url_copy_status.as_deref()
+    }
+
+    /// Get the Recent Tasks screen state (for testing)
+    #[allow(dead_code)]
+    pub fn recent_tasks(&self) -> &RecentTasksState {
+        &self.recent_tasks
+    }
+
+    /// Get the Workspace Views screen state (for testing)
+    #[allow(dead_code)]
+    pub fn workspace_views(&self) -> &WorkspaceViewsState {
+        &self.workspace_views
+    }
+
+    /// Get mutable settings (for testing)
+    #[allow(dead_code)]
+    pub fn config_mut_for_test(&mut self) -> &mut AppConfig {
+        &mut self.config
+    }
+
     /// Get status picker state (public for testing)
     #[allow(dead_code)]
     pub fn is_status_picker_open(&self) -> bool {
         self.status_picker_open
     }
 
+    /// Check if the error detail overlay is open (for testing)
+    #[allow(dead_code)]
+    pub fn is_error_detail_open(&self) -> bool {
+        self.error_detail_open
+    }
+
+    /// Get the statuses offered by the status picker (public for testing)
+    #[allow(dead_code)]
+    pub fn status_picker_statuses(&self) -> &[crate::models::TaskStatus] {
+        &self.status_picker_statuses
+    }
+
+    /// Get the current error message, if any (public for testing)
+    #[allow(dead_code)]
+    pub fn error_message(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
     /// Set screen directly (public for testing)
     #[allow(dead_code)]
     pub fn set_screen(&mut self, screen: Screen) {
@@ -4766,4 +10150,198 @@ mod tests {
             app.screen_title
         );
     }
+
+    /// Test that inserting a comment whose id is already shown updates it
+    /// instead of showing it twice
+    #[test]
+    fn test_insert_comment_with_existing_id_updates_in_place() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.config.comment_order = CommentOrder::Newest;
+        let comment = |id: &str, text: &str| -> Comment {
+            serde_json::from_value(serde_json::json!({ "id": id, "comment_text": text }))
+                .unwrap()
+        };
+
+        assert!(app.insert_comment(comment("1", "First")));
+        assert!(app.insert_comment(comment("2", "Second")));
+        assert!(!app.insert_comment(comment("1", "First, retried")));
+
+        let texts: Vec<&str> = app.comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(texts, ["Second", "First, retried"]);
+    }
+
+    /// Test that a new top-level comment in oldest-first order goes after
+    /// the top-level comments, not after the replies loaded behind them
+    #[test]
+    fn test_insert_comment_oldest_first_keeps_replies_last() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.config.comment_order = CommentOrder::Oldest;
+        let comment = |id: &str, parent: Option<&str>| -> Comment {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "comment_text": id,
+                "parent_id": parent,
+            }))
+            .unwrap()
+        };
+        // Two top-level comments, followed by a prefetched reply
+        app.comments = vec![comment("1", None), comment("2", None), comment("r1", Some("1"))];
+        app.comment_top_level_count = 2;
+
+        assert!(app.insert_comment(comment("3", None)));
+        app.comment_top_level_count += 1;
+        assert!(app.insert_comment(comment("r2", Some("2"))));
+
+        let ids: Vec<&str> = app.comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "3", "r1", "r2"]);
+    }
+
+    /// Test that undoing a deleted reply puts it back in its thread rather
+    /// than at the top of the comments
+    #[tokio::test]
+    async fn test_undo_reply_delete_restores_it_in_its_thread() {
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let comment = |id: &str, parent: Option<&str>| -> Comment {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "comment_text": id,
+                "parent_id": parent,
+            }))
+            .unwrap()
+        };
+        // The create response leaves the parent out, like the API's
+        let mock = MockClickUpClient::new()
+            .with_delete_comment_success()
+            .with_create_comment_reply_response(comment("r1-restored", None));
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.config.confirm_comment_delete = false;
+        app.screen = Screen::TaskDetail;
+        app.task_detail.task = Some(Task {
+            id: "t1".to_string(),
+            ..Default::default()
+        });
+        app.comments = vec![
+            comment("1", None),
+            comment("2", None),
+            comment("r1", Some("1")),
+            comment("r2", Some("1")),
+            comment("r3", Some("2")),
+        ];
+        app.comment_top_level_count = 2;
+        app.comment_focus = true;
+        app.comment_selected_index = 2;
+
+        for key in [
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL),
+        ] {
+            app.update(InputEvent::Key(key));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        let ids: Vec<&str> = app.comments.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2", "r2", "r1-restored", "r3"]);
+        assert_eq!(app.comments[3].parent_id.as_deref(), Some("1"));
+        assert_eq!(app.comment_top_level_count, 2);
+    }
+
+    /// Test that without multiple assignees picking a member replaces the
+    /// one picked before
+    #[test]
+    fn test_single_assignee_plan_picks_one_member() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.assignee_picker_members = serde_json::from_value(serde_json::json!([
+            {"id": 1, "username": "ana"},
+            {"id": 2, "username": "bo"},
+        ]))
+        .unwrap();
+
+        app.toggle_picked_assignee();
+        app.assignee_picker_cursor = 1;
+        app.toggle_picked_assignee();
+        assert_eq!(app.assignee_picker_selected.len(), 2);
+
+        app.current_workspace_id = Some("w".to_string());
+        app.workspace_features.insert(
+            "w".to_string(),
+            serde_json::from_str(r#"{"multiple_assignees": {"enabled": false}}"#).unwrap(),
+        );
+        app.assignee_picker_selected.clear();
+        app.assignee_picker_cursor = 0;
+        app.toggle_picked_assignee();
+        app.assignee_picker_cursor = 1;
+        app.toggle_picked_assignee();
+        assert_eq!(app.assignee_picker_selected, [2].into());
+    }
+
+    /// Test that a task's URL names its own list, not the one open
+    #[test]
+    fn test_task_url_uses_the_tasks_list() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        let task: Task = serde_json::from_value(serde_json::json!({
+            "id": "t1",
+            "name": "Task",
+            "list": {"id": "l9"}
+        }))
+        .unwrap();
+
+        assert_eq!(app.task_url(&task).unwrap(), "https://app.clickup.com/t/t1");
+        app.current_workspace_id = Some("w1".to_string());
+        app.current_list_id = Some("l1".to_string());
+        assert_eq!(
+            app.task_url(&task).unwrap(),
+            "https://app.clickup.com/w1/l/l9/t/t1"
+        );
+    }
+
+    /// Test that an empty task list names the filter that emptied it
+    #[test]
+    fn test_empty_task_list_names_its_filter() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.load_statuses.insert(Collection::Tasks, LoadStatus::Loaded);
+        let reason = |app: &TuiApp| app.empty_state(Collection::Tasks, true).unwrap().reason;
+
+        assert_eq!(reason(&app).as_deref(), Some("This list has no tasks"));
+        app.show_archived = true;
+        assert_eq!(reason(&app).as_deref(), Some("This list has no archived tasks"));
+        app.assigned_filter_active = true;
+        assert_eq!(
+            reason(&app).as_deref(),
+            Some("No task in this list is assigned to you")
+        );
+
+        app.load_statuses.insert(Collection::Tasks, LoadStatus::Loading);
+        let loading = app.empty_state(Collection::Tasks, true).unwrap();
+        assert_eq!(loading.headline, "Loading tasks...");
+    }
+
+    /// Test that comments fetched for a comment link are dropped once the
+    /// task is closed, and closing it leaves other loads' loading flag alone
+    #[tokio::test]
+    async fn test_stale_comment_navigation_is_discarded() {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        let comment: Comment = serde_json::from_value(serde_json::json!({
+            "id": "c1",
+            "comment_text": "hi",
+        }))
+        .unwrap();
+
+        app.navigating = true;
+        app.comments_generation += 1;
+        let generation = app.comments_generation;
+        // A task list load is in flight when the task detail is closed
+        app.loading = true;
+        app.cancel_comment_load();
+        assert!(app.loading);
+
+        let tx = app.message_tx.clone().unwrap();
+        let msg = AppMessage::CommentsLoadedForCommentNavigation(Ok(vec![comment]), "c1".to_string(), generation);
+        tx.send(msg).await.unwrap();
+        app.process_async_messages();
+
+        assert!(app.comments.is_empty());
+        assert!(!app.navigating);
+    }
 }
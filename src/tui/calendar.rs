@@ -0,0 +1,323 @@
+//! Month layout and due-date counts for the due-date calendar
+//!
+//! Everything here is date arithmetic over values passed in (today, the
+//! timezone, the first day of the week), so any month can be checked without
+//! a clock or a terminal.
+
+use crate::models::Task;
+use chrono::{Datelike, Months, NaiveDate, TimeZone, Weekday};
+use std::collections::HashMap;
+
+/// One month as rows of seven days, the first row starting on `week_start`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthLayout {
+    /// First day of the month
+    pub first: NaiveDate,
+    pub week_start: Weekday,
+    /// Weeks of the month; days of the neighbouring months are `None`
+    pub weeks: Vec<[Option<NaiveDate>; 7]>,
+}
+
+impl MonthLayout {
+    /// The month containing `date`
+    pub fn new(date: NaiveDate, week_start: Weekday) -> Self {
+        let first = date.with_day(1).expect("every month has a first day");
+        let leading = days_after(first.weekday(), week_start);
+        let days = days_in_month(first);
+
+        let cells = (leading + days).div_ceil(7) * 7;
+        let weeks = (0..cells)
+            .map(|cell| {
+                let day = cell.checked_sub(leading).filter(|day| *day < days);
+                day.map(|day| first + chrono::Days::new(day as u64))
+            })
+            .collect::<Vec<_>>()
+            .chunks(7)
+            .map(|week| week.try_into().expect("chunks of seven"))
+            .collect();
+        Self {
+            first,
+            week_start,
+            weeks,
+        }
+    }
+
+    /// Blank cells before the first day
+    #[allow(dead_code)]
+    pub fn leading_blanks(&self) -> usize {
+        days_after(self.first.weekday(), self.week_start)
+    }
+
+    /// Blank cells after the last day
+    #[allow(dead_code)]
+    pub fn trailing_blanks(&self) -> usize {
+        self.weeks.len() * 7 - self.leading_blanks() - days_in_month(self.first)
+    }
+
+    /// Row and column of `date`, when it is in this month
+    #[allow(dead_code)]
+    pub fn position(&self, date: NaiveDate) -> Option<(usize, usize)> {
+        if (date.year(), date.month()) != (self.first.year(), self.first.month()) {
+            return None;
+        }
+        let cell = self.leading_blanks() + date.day0() as usize;
+        Some((cell / 7, cell % 7))
+    }
+
+    /// Column headings, starting on `week_start`
+    pub fn weekday_labels(&self) -> [&'static str; 7] {
+        const LABELS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+        let offset = self.week_start.num_days_from_monday() as usize;
+        std::array::from_fn(|i| LABELS[(offset + i) % 7])
+    }
+
+    /// e.g. "February 2024"
+    pub fn title(&self) -> String {
+        self.first.format("%B %Y").to_string()
+    }
+}
+
+/// Days from `week_start` to `day`, going forward
+fn days_after(day: Weekday, week_start: Weekday) -> usize {
+    (day.num_days_from_monday() + 7 - week_start.num_days_from_monday()) as usize % 7
+}
+
+fn days_in_month(first: NaiveDate) -> usize {
+    let next = first + Months::new(1);
+    (next - first).num_days() as usize
+}
+
+/// `date` moved by whole months, clamped to the end of shorter months
+/// (Jan 31 + 1 month is the last day of February)
+pub fn shift_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let shifted = if months >= 0 {
+        date.checked_add_months(Months::new(months.unsigned_abs()))
+    } else {
+        date.checked_sub_months(Months::new(months.unsigned_abs()))
+    };
+    shifted.unwrap_or(date)
+}
+
+/// Tasks a day cell counts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayCount {
+    /// Tasks due that day
+    pub due: usize,
+    /// Unfinished tasks due before today (only ever on today)
+    pub overdue: usize,
+}
+
+impl DayCount {
+    pub fn total(&self) -> usize {
+        self.due + self.overdue
+    }
+}
+
+/// The day a task shows on, and whether it's there for being overdue
+///
+/// Unfinished tasks due before `today` pile up on today; everything else
+/// stays on its due date in `tz`. Tasks without a due date show nowhere.
+pub fn calendar_day<Tz: TimeZone>(task: &Task, today: NaiveDate, tz: &Tz) -> Option<(NaiveDate, bool)> {
+    let due = tz
+        .timestamp_millis_opt(task.due_date?)
+        .single()?
+        .date_naive();
+    if due < today && !task.is_finished() {
+        Some((today, true))
+    } else {
+        Some((due, false))
+    }
+}
+
+/// Per-day counts of `tasks`, see [`calendar_day`]
+pub fn count_by_day<Tz: TimeZone>(
+    tasks: &[Task],
+    today: NaiveDate,
+    tz: &Tz,
+) -> HashMap<NaiveDate, DayCount> {
+    let mut counts: HashMap<NaiveDate, DayCount> = HashMap::new();
+    for (day, overdue) in tasks.iter().filter_map(|t| calendar_day(t, today, tz)) {
+        let count = counts.entry(day).or_default();
+        if overdue {
+            count.overdue += 1;
+        } else {
+            count.due += 1;
+        }
+    }
+    counts
+}
+
+/// The tasks that show on `day` (today includes the overdue ones)
+pub fn tasks_on_day<Tz: TimeZone>(
+    tasks: &[Task],
+    day: NaiveDate,
+    today: NaiveDate,
+    tz: &Tz,
+) -> Vec<Task> {
+    tasks
+        .iter()
+        .filter(|t| calendar_day(t, today, tz).is_some_and(|(shown_on, _)| shown_on == day))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaskStatus;
+    use chrono::Utc;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// Day numbers of a week, 0 for blanks
+    fn days(week: &[Option<NaiveDate>; 7]) -> [u32; 7] {
+        week.map(|day| day.map_or(0, |d| d.day()))
+    }
+
+    #[test]
+    fn test_month_starting_monday_weeks() {
+        // April 2024 starts on a Monday and has 30 days
+        let layout = MonthLayout::new(date(2024, 4, 17), Weekday::Mon);
+        assert_eq!(layout.first, date(2024, 4, 1));
+        assert_eq!(layout.leading_blanks(), 0);
+        assert_eq!(layout.trailing_blanks(), 5);
+        assert_eq!(layout.weeks.len(), 5);
+        assert_eq!(days(&layout.weeks[0]), [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(days(&layout.weeks[4]), [29, 30, 0, 0, 0, 0, 0]);
+
+        // With Sunday first, the Monday the 1st is one cell in
+        let layout = MonthLayout::new(date(2024, 4, 17), Weekday::Sun);
+        assert_eq!(layout.leading_blanks(), 1);
+        assert_eq!(layout.trailing_blanks(), 4);
+        assert_eq!(days(&layout.weeks[0]), [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(days(&layout.weeks[4]), [28, 29, 30, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_month_ending_on_last_column_has_no_trailing_week() {
+        // June 2024: Saturday the 1st, Sunday the 30th
+        let layout = MonthLayout::new(date(2024, 6, 1), Weekday::Mon);
+        assert_eq!(layout.leading_blanks(), 5);
+        assert_eq!(layout.trailing_blanks(), 0);
+        assert_eq!(layout.weeks.len(), 5);
+        assert_eq!(days(&layout.weeks[4]), [24, 25, 26, 27, 28, 29, 30]);
+
+        // Starting on Sunday it spills into a sixth week
+        let layout = MonthLayout::new(date(2024, 6, 1), Weekday::Sun);
+        assert_eq!(layout.leading_blanks(), 6);
+        assert_eq!(layout.weeks.len(), 6);
+        assert_eq!(days(&layout.weeks[5]), [30, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_february_in_leap_and_common_years() {
+        let leap = MonthLayout::new(date(2024, 2, 10), Weekday::Mon);
+        assert_eq!(leap.weeks.iter().flatten().flatten().count(), 29);
+        assert_eq!(leap.position(date(2024, 2, 29)), Some((4, 3)));
+
+        // February 2021 starts on a Monday: exactly four full weeks
+        let common = MonthLayout::new(date(2021, 2, 1), Weekday::Mon);
+        assert_eq!(common.weeks.len(), 4);
+        assert_eq!(common.leading_blanks() + common.trailing_blanks(), 0);
+
+        let common = MonthLayout::new(date(2021, 2, 1), Weekday::Sun);
+        assert_eq!(common.weeks.len(), 5);
+        assert_eq!(common.leading_blanks(), 1);
+        assert_eq!(common.trailing_blanks(), 6);
+    }
+
+    #[test]
+    fn test_every_layout_holds_each_day_once_in_order() {
+        for week_start in [Weekday::Mon, Weekday::Sun] {
+            for month in 1..=12 {
+                let layout = MonthLayout::new(date(2023, month, 1), week_start);
+                let listed: Vec<NaiveDate> = layout.weeks.iter().flatten().flatten().copied().collect();
+                let expected: Vec<NaiveDate> = layout
+                    .first
+                    .iter_days()
+                    .take_while(|d| d.month() == month)
+                    .collect();
+                assert_eq!(listed, expected, "{} {:?}", month, week_start);
+                assert_eq!(
+                    layout.leading_blanks() + listed.len() + layout.trailing_blanks(),
+                    layout.weeks.len() * 7
+                );
+                for day in &listed {
+                    let (row, column) = layout.position(*day).unwrap();
+                    assert_eq!(layout.weeks[row][column], Some(*day));
+                    assert_eq!(
+                        layout.weekday_labels()[column],
+                        &day.weekday().to_string()[..2]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_position_outside_month_and_labels() {
+        let layout = MonthLayout::new(date(2024, 3, 5), Weekday::Sun);
+        assert_eq!(layout.position(date(2024, 2, 29)), None);
+        assert_eq!(layout.position(date(2025, 3, 5)), None);
+        assert_eq!(layout.weekday_labels(), ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"]);
+        assert_eq!(layout.title(), "March 2024");
+    }
+
+    #[test]
+    fn test_shift_months_across_years_and_short_months() {
+        assert_eq!(shift_months(date(2024, 1, 31), 1), date(2024, 2, 29));
+        assert_eq!(shift_months(date(2023, 1, 31), 1), date(2023, 2, 28));
+        assert_eq!(shift_months(date(2024, 3, 31), -1), date(2024, 2, 29));
+        assert_eq!(shift_months(date(2024, 12, 15), 1), date(2025, 1, 15));
+        assert_eq!(shift_months(date(2025, 1, 15), -1), date(2024, 12, 15));
+        assert_eq!(shift_months(date(2024, 2, 29), 12), date(2025, 2, 28));
+    }
+
+    fn task(id: &str, due: Option<NaiveDate>, status: &str) -> Task {
+        Task {
+            id: id.to_string(),
+            due_date: due.map(|d| d.and_hms_opt(12, 0, 0).unwrap().and_utc().timestamp_millis()),
+            status: Some(TaskStatus {
+                id: None,
+                status: status.to_string(),
+                color: None,
+                type_field: None,
+                orderindex: None,
+                status_group: Some(status.to_string()),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_overdue_tasks_pile_up_on_today() {
+        let today = date(2024, 3, 1);
+        let tasks = vec![
+            task("late", Some(date(2024, 2, 27)), "todo"),
+            task("later", Some(date(2024, 1, 5)), "in_progress"),
+            task("finished", Some(date(2024, 2, 27)), "done"),
+            task("today", Some(today), "todo"),
+            task("soon", Some(date(2024, 3, 4)), "todo"),
+            task("undated", None, "todo"),
+        ];
+
+        let counts = count_by_day(&tasks, today, &Utc);
+        assert_eq!(counts[&today], DayCount { due: 1, overdue: 2 });
+        assert_eq!(counts[&today].total(), 3);
+        assert_eq!(counts[&date(2024, 2, 27)], DayCount { due: 1, overdue: 0 });
+        assert_eq!(counts[&date(2024, 3, 4)].due, 1);
+        assert_eq!(counts.len(), 3);
+
+        let ids = |day| -> Vec<String> {
+            tasks_on_day(&tasks, day, today, &Utc)
+                .into_iter()
+                .map(|t| t.id)
+                .collect()
+        };
+        assert_eq!(ids(today), ["late", "later", "today"]);
+        assert_eq!(ids(date(2024, 2, 27)), ["finished"]);
+        assert!(ids(date(2024, 3, 2)).is_empty());
+    }
+}
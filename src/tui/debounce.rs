@@ -0,0 +1,90 @@
+//! Deadline-based debouncing for the run loop
+//!
+//! Each input pushes the deadline back; the run loop asks [`Debounce::fire`]
+//! on every tick and acts once the deadline has passed without new input.
+
+use std::time::{Duration, Instant};
+
+/// Quiet period after the last keystroke before searching
+pub const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A pending action that runs once input has been quiet for `delay`
+#[derive(Debug, Clone)]
+pub struct Debounce {
+    delay: Duration,
+    deadline: Option<Instant>,
+}
+
+impl Debounce {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            deadline: None,
+        }
+    }
+
+    /// Record input at `now`, pushing the deadline back
+    pub fn schedule(&mut self, now: Instant) {
+        self.deadline = Some(now + self.delay);
+    }
+
+    /// Drop the pending action
+    pub fn cancel(&mut self) {
+        self.deadline = None;
+    }
+
+    #[allow(dead_code)]
+    pub fn is_pending(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Whether the action is due at `now`; a due action is consumed
+    pub fn fire(&mut self, now: Instant) -> bool {
+        match self.deadline {
+            Some(deadline) if now >= deadline => {
+                self.deadline = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Default for Debounce {
+    fn default() -> Self {
+        Self::new(SEARCH_DEBOUNCE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fires_once_after_quiet_period() {
+        let start = Instant::now();
+        let mut debounce = Debounce::default();
+        assert!(!debounce.fire(start + Duration::from_secs(1)));
+
+        debounce.schedule(start);
+        assert!(!debounce.fire(start + Duration::from_millis(299)));
+        assert!(debounce.fire(start + SEARCH_DEBOUNCE));
+        assert!(!debounce.fire(start + Duration::from_secs(1)));
+        assert!(!debounce.is_pending());
+    }
+
+    #[test]
+    fn test_new_input_pushes_deadline_back() {
+        let start = Instant::now();
+        let mut debounce = Debounce::default();
+        debounce.schedule(start);
+        debounce.schedule(start + Duration::from_millis(200));
+
+        assert!(!debounce.fire(start + Duration::from_millis(400)));
+        assert!(debounce.fire(start + Duration::from_millis(500)));
+
+        debounce.schedule(start);
+        debounce.cancel();
+        assert!(!debounce.fire(start + Duration::from_secs(1)));
+    }
+}
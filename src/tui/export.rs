@@ -0,0 +1,171 @@
+//! Plain-text export of a task and its comments
+//!
+//! Used to hand the task detail view to an external pager.
+
+use crate::models::{Comment, Task};
+use crate::utils::format_timestamp;
+
+/// Render a task, its description and its comment threads as plain text
+///
+/// Replies are indented under their parent; replies whose parent isn't in
+/// `comments` are listed after the top-level threads.
+pub fn task_to_text(task: &Task, comments: &[Comment]) -> String {
+    let mut out = String::new();
+
+    out.push_str(&task.name);
+    out.push('\n');
+    out.push_str(&"=".repeat(task.name.chars().count().max(1)));
+    out.push_str("\n\n");
+
+    let status = task.status.as_ref().map(|s| s.status.as_str());
+    let priority = task.priority.as_ref().map(|p| p.priority.as_str());
+    let assignees = task.assignee_labels().join(", ");
+    let tags = task
+        .tags
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let list = task.list.as_ref().and_then(|l| l.name.as_deref());
+    let due = task.due_date.map(format_timestamp);
+
+    push_field(&mut out, "Status", status);
+    push_field(&mut out, "Priority", priority);
+    push_field(&mut out, "Assignees", Some(assignees.as_str()));
+    push_field(&mut out, "Tags", Some(tags.as_str()));
+    push_field(&mut out, "List", list);
+    push_field(&mut out, "Due", due.as_deref());
+    push_field(&mut out, "URL", task.url.as_deref());
+
+    out.push_str("\nDescription\n-----------\n");
+    let description = task
+        .description
+        .as_ref()
+        .map(|d| d.as_text())
+        .filter(|d| !d.trim().is_empty())
+        .unwrap_or_else(|| "No description".to_string());
+    out.push_str(description.trim_end());
+    out.push('\n');
+
+    out.push_str(&format!("\nComments ({})\n", comments.len()));
+    out.push_str("------------\n");
+    if comments.is_empty() {
+        out.push_str("No comments\n");
+        return out;
+    }
+
+    let is_top_level = |c: &Comment| c.parent_id.is_none();
+    let has_parent = |c: &Comment| {
+        c.parent_id
+            .as_ref()
+            .is_some_and(|pid| comments.iter().any(|p| &p.id == pid))
+    };
+
+    for comment in comments.iter().filter(|c| is_top_level(c)) {
+        push_comment(&mut out, comment, "");
+        for reply in comments
+            .iter()
+            .filter(|r| r.parent_id.as_deref() == Some(comment.id.as_str()))
+        {
+            push_comment(&mut out, reply, "    ");
+        }
+    }
+    for orphan in comments
+        .iter()
+        .filter(|c| !is_top_level(c) && !has_parent(c))
+    {
+        push_comment(&mut out, orphan, "    ");
+    }
+
+    out
+}
+
+fn push_field(out: &mut String, label: &str, value: Option<&str>) {
+    if let Some(value) = value.filter(|v| !v.is_empty()) {
+        out.push_str(&format!("{}: {}\n", label, value));
+    }
+}
+
+fn push_comment(out: &mut String, comment: &Comment, indent: &str) {
+    let author = comment
+        .commenter
+        .as_ref()
+        .map(|u| u.username.as_str())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("Unknown");
+    let date = comment.created_at.map(format_timestamp).unwrap_or_default();
+
+    let header = format!("{}{}  {}", indent, author, date);
+    out.push('\n');
+    out.push_str(header.trim_end());
+    out.push('\n');
+    for line in comment.text.lines() {
+        out.push_str(indent);
+        out.push_str(line);
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task() -> Task {
+        serde_json::from_str(
+            r##"{
+                "id": "t1",
+                "name": "Fix login",
+                "status": {"status": "in progress", "color": "#fff", "type": "custom", "orderindex": 1},
+                "description": "Line one\nLine two",
+                "tags": [{"name": "bug"}],
+                "url": "https://app.clickup.com/t/t1"
+            }"##,
+        )
+        .unwrap()
+    }
+
+    fn comment(id: &str, parent: Option<&str>, author: &str, text: &str) -> Comment {
+        let mut comment: Comment = serde_json::from_str(&format!(
+            r#"{{"id": "{}", "comment_text": "{}", "user": {{"id": 1, "username": "{}"}}}}"#,
+            id, text, author
+        ))
+        .unwrap();
+        comment.parent_id = parent.map(str::to_string);
+        comment
+    }
+
+    #[test]
+    fn test_task_to_text_includes_fields_and_description() {
+        let text = task_to_text(&task(), &[]);
+
+        assert!(text.starts_with("Fix login\n=========\n"), "{}", text);
+        assert!(text.contains("Status: in progress\n"), "{}", text);
+        assert!(text.contains("Tags: bug\n"), "{}", text);
+        assert!(
+            text.contains("URL: https://app.clickup.com/t/t1\n"),
+            "{}",
+            text
+        );
+        assert!(text.contains("Line one\nLine two\n"), "{}", text);
+        assert!(!text.contains("Priority:"), "{}", text);
+        assert!(text.ends_with("No comments\n"), "{}", text);
+    }
+
+    #[test]
+    fn test_task_to_text_indents_replies_under_parent() {
+        let comments = vec![
+            comment("1", None, "alice", "First"),
+            comment("2", None, "bob", "Second"),
+            comment("3", Some("1"), "carol", "Reply to first"),
+        ];
+
+        let text = task_to_text(&task(), &comments);
+
+        assert!(text.contains("Comments (3)"), "{}", text);
+        let first = text.find("alice").unwrap();
+        let reply = text.find("    carol").unwrap();
+        let second = text.find("bob").unwrap();
+        assert!(first < reply && reply < second, "{}", text);
+        assert!(text.contains("    Reply to first\n"), "{}", text);
+    }
+}
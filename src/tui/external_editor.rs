@@ -0,0 +1,146 @@
+//! Editing text in an external editor (`$EDITOR`)
+//!
+//! The text goes to a temporary file the editor is started on, and is read
+//! back once it exits. Suspending the TUI around the editor is left to the
+//! caller, see [`crate::tui::terminal::while_suspended`].
+
+use anyhow::{Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// What came back from the editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditOutcome {
+    /// The text was saved as it was (or not saved at all)
+    Unchanged,
+    /// The edited text, without the trailing newlines editors add
+    Changed(String),
+}
+
+/// Write `text` to a temporary file, let `edit` change it, and read it back
+///
+/// The file is removed afterwards, also when `edit` fails.
+pub fn edit_text(text: &str, edit: impl FnOnce(&Path) -> Result<()>) -> Result<EditOutcome> {
+    let file = TempFile::create(text)?;
+    edit(&file.path)?;
+    let edited = fs::read_to_string(&file.path)
+        .with_context(|| format!("Failed to read back {}", file.path.display()))?;
+    let edited = edited.trim_end_matches('\n');
+    if edited == text.trim_end_matches('\n') {
+        Ok(EditOutcome::Unchanged)
+    } else {
+        Ok(EditOutcome::Changed(edited.to_string()))
+    }
+}
+
+/// Start `command` on `path` and wait for it; exiting non-zero is an error
+pub fn launch(command: &str, path: &Path) -> Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().context("Editor command is empty")?;
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", command))?;
+    if !status.success() {
+        anyhow::bail!("Editor '{}' exited with {}", command, status);
+    }
+    Ok(())
+}
+
+/// "12 → 15 lines, +240 chars": how much an edit changed the text
+pub fn change_summary(before: &str, after: &str) -> String {
+    let chars = after.chars().count() as i64 - before.chars().count() as i64;
+    format!(
+        "{} → {} lines, {:+} chars",
+        before.lines().count(),
+        after.lines().count(),
+        chars
+    )
+}
+
+/// A file in the temp directory, removed when dropped
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn create(text: &str) -> Result<Self> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let name = format!(
+            "clickdown-{}-{}.md",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = std::env::temp_dir().join(name);
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        let temp = Self { path };
+        file.write_all(text.as_bytes())
+            .with_context(|| format!("Failed to write {}", temp.path.display()))?;
+        Ok(temp)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_text_reads_back_changes_and_removes_the_file() {
+        let mut edited_path = None;
+        let outcome = edit_text("# Plan\n", |path| {
+            assert_eq!(fs::read_to_string(path).unwrap(), "# Plan\n");
+            edited_path = Some(path.to_path_buf());
+            fs::write(path, "# Plan\n\nShip it\n")?;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(outcome, EditOutcome::Changed("# Plan\n\nShip it".to_string()));
+        assert!(!edited_path.unwrap().exists());
+    }
+
+    #[test]
+    fn test_edit_text_unchanged_and_failed_edits() {
+        // An editor adding a final newline hasn't changed anything
+        let outcome = edit_text("Plan", |path| Ok(fs::write(path, "Plan\n")?)).unwrap();
+        assert_eq!(outcome, EditOutcome::Unchanged);
+
+        let mut edited_path = None;
+        let result = edit_text("Plan", |path| {
+            edited_path = Some(path.to_path_buf());
+            fs::write(path, "Half done")?;
+            anyhow::bail!("editor crashed")
+        });
+        assert!(result.is_err());
+        assert!(!edited_path.unwrap().exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_launch_fails_on_non_zero_exit() {
+        let path = Path::new("/dev/null");
+        launch("true", path).unwrap();
+        let err = launch("false", path).unwrap_err();
+        assert!(err.to_string().starts_with("Editor 'false' exited with"), "{}", err);
+        assert!(launch("  ", path).is_err());
+    }
+
+    #[test]
+    fn test_change_summary() {
+        assert_eq!(change_summary("a\nb", "a\nb\nc"), "2 → 3 lines, +2 chars");
+        assert_eq!(change_summary("long line", ""), "1 → 0 lines, -9 chars");
+    }
+}
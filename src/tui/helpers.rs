@@ -41,6 +41,7 @@ impl<T> SelectableList<T> {
     }
 
     /// Select the first item
+    #[allow(dead_code)]
     pub fn select_first(&mut self) {
         if !self.items.is_empty() {
             self.state.select(Some(0));
@@ -48,6 +49,7 @@ impl<T> SelectableList<T> {
     }
 
     /// Move selection down (wraps to start)
+    #[allow(dead_code)]
     pub fn select_next(&mut self) {
         if self.items.is_empty() {
             return;
@@ -66,6 +68,7 @@ impl<T> SelectableList<T> {
     }
 
     /// Move selection up (wraps to end)
+    #[allow(dead_code)]
     pub fn select_previous(&mut self) {
         if self.items.is_empty() {
             return;
@@ -122,6 +125,37 @@ impl<T> SelectableList<T> {
     pub fn state(&self) -> &ListState {
         &self.state
     }
+
+    /// Get the internal ListState (mutable)
+    pub fn state_mut(&mut self) -> &mut ListState {
+        &mut self.state
+    }
+}
+
+/// Index to select after a list's items were replaced or reordered
+///
+/// `key_of` gives an item's ID, or `None` for rows that can't be selected
+/// (headers). The item with `selected_key` is preferred; when it is gone, the
+/// selectable row at or after `previous_index` takes its place, then the one
+/// before it. Returns `None` when nothing is selectable.
+pub fn reselect_index<'a, T>(
+    items: &'a [T],
+    key_of: impl Fn(&'a T) -> Option<&'a str>,
+    selected_key: Option<&str>,
+    previous_index: Option<usize>,
+) -> Option<usize> {
+    if let Some(key) = selected_key {
+        if let Some(index) = items.iter().position(|item| key_of(item) == Some(key)) {
+            return Some(index);
+        }
+    }
+    let selectable = |index: &usize| key_of(&items[*index]).is_some();
+    let start = previous_index
+        .unwrap_or(0)
+        .min(items.len().saturating_sub(1));
+    (start..items.len())
+        .find(selectable)
+        .or_else(|| (0..start).rev().find(selectable))
 }
 
 impl<T> Default for SelectableList<T> {
@@ -178,6 +212,49 @@ mod tests {
         assert!(!list.select_by(|&x| x == "z"));
     }
 
+    /// Rows are task IDs; `None` stands for a header
+    fn reselect(
+        items: &[Option<&'static str>],
+        selected: Option<&str>,
+        previous: Option<usize>,
+    ) -> Option<usize> {
+        reselect_index(items, |item| *item, selected, previous)
+    }
+
+    #[test]
+    fn test_reselect_moved_item() {
+        let items = [None, Some("c"), Some("a"), None, Some("b")];
+        assert_eq!(reselect(&items, Some("b"), Some(2)), Some(4));
+        assert_eq!(reselect(&items, Some("a"), Some(4)), Some(2));
+    }
+
+    #[test]
+    fn test_reselect_removed_item_keeps_position() {
+        // "b" was at index 2 and is gone: the row now at 2 takes its place
+        let items = [None, Some("a"), Some("c"), Some("d")];
+        assert_eq!(reselect(&items, Some("b"), Some(2)), Some(2));
+
+        // A header in that position is skipped forwards
+        let items = [Some("a"), None, Some("c")];
+        assert_eq!(reselect(&items, Some("b"), Some(1)), Some(2));
+
+        // Past the end, the last selectable row is used
+        let items = [None, Some("a"), Some("c"), None];
+        assert_eq!(reselect(&items, Some("z"), Some(9)), Some(2));
+    }
+
+    #[test]
+    fn test_reselect_without_previous_selection() {
+        let items = [None, Some("a"), Some("b")];
+        assert_eq!(reselect(&items, None, None), Some(1));
+    }
+
+    #[test]
+    fn test_reselect_empty_results() {
+        assert_eq!(reselect(&[], Some("a"), Some(3)), None);
+        assert_eq!(reselect(&[None, None], Some("a"), Some(1)), None);
+    }
+
     #[test]
     fn test_select_none() {
         let mut list = SelectableList::empty();
@@ -1,6 +1,8 @@
 //! Input event handling for TUI
 
+use crate::utils::byte_offset;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Represents a TUI input event
 #[derive(Debug, Clone)]
@@ -21,3 +23,318 @@ pub fn is_quit(key: KeyEvent) -> bool {
         _ => false,
     }
 }
+
+/// Readline-style editing of a single-line text input
+///
+/// Borrows the input's text and cursor. The cursor is a char offset that
+/// always sits on a grapheme boundary, so characters built from several
+/// code points (an accent written as a combining mark, a flag emoji) move
+/// and delete as one.
+///
+/// Alt+B/Alt+F move over words of letters and digits, stopping at
+/// punctuation; Ctrl+W deletes back to the previous whitespace, so it
+/// removes a whole URL or path segment at once, as in a shell.
+pub struct LineEditor<'a> {
+    text: &'a mut String,
+    cursor: &'a mut usize,
+}
+
+impl<'a> LineEditor<'a> {
+    pub fn new(text: &'a mut String, cursor: &'a mut usize) -> Self {
+        let mut editor = Self { text, cursor };
+        let byte = editor.byte_cursor();
+        editor.set_byte_cursor(byte);
+        editor
+    }
+
+    /// Apply an editing key, returning whether it was one
+    ///
+    /// Keys that aren't editing keys (Enter, Esc, Tab, other Ctrl shortcuts)
+    /// are left for the caller.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Char('a') if ctrl => self.home(),
+            KeyCode::Char('e') if ctrl => self.end(),
+            KeyCode::Char('w') if ctrl => self.delete_word_before(),
+            KeyCode::Char('u') if ctrl => self.clear_to_start(),
+            KeyCode::Char('k') if ctrl => self.clear_to_end(),
+            KeyCode::Char('b') if alt => self.word_left(),
+            KeyCode::Char('f') if alt => self.word_right(),
+            KeyCode::Char(_) if ctrl || alt => return false,
+            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Backspace => self.backspace(),
+            KeyCode::Delete => self.delete(),
+            KeyCode::Left => self.move_left(),
+            KeyCode::Right => self.move_right(),
+            KeyCode::Home => self.home(),
+            KeyCode::End => self.end(),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        let byte = self.byte_cursor();
+        self.text.insert(byte, c);
+        self.set_byte_cursor(byte + c.len_utf8());
+    }
+
+    pub fn insert_str(&mut self, s: &str) {
+        let byte = self.byte_cursor();
+        self.text.insert_str(byte, s);
+        self.set_byte_cursor(byte + s.len());
+    }
+
+    /// Delete the grapheme before the cursor
+    pub fn backspace(&mut self) {
+        let end = self.byte_cursor();
+        let start = self.prev_boundary(end);
+        self.remove(start, end);
+    }
+
+    /// Delete the grapheme under the cursor
+    pub fn delete(&mut self) {
+        let start = self.byte_cursor();
+        let end = self.next_boundary(start);
+        self.remove(start, end);
+    }
+
+    pub fn move_left(&mut self) {
+        let byte = self.prev_boundary(self.byte_cursor());
+        self.set_byte_cursor(byte);
+    }
+
+    pub fn move_right(&mut self) {
+        let byte = self.next_boundary(self.byte_cursor());
+        self.set_byte_cursor(byte);
+    }
+
+    pub fn home(&mut self) {
+        *self.cursor = 0;
+    }
+
+    pub fn end(&mut self) {
+        *self.cursor = self.text.chars().count();
+    }
+
+    /// Move to the start of the word before the cursor
+    pub fn word_left(&mut self) {
+        let byte = self.word_start_before(self.byte_cursor(), is_word);
+        self.set_byte_cursor(byte);
+    }
+
+    /// Move to the end of the word after the cursor
+    pub fn word_right(&mut self) {
+        let mut byte = self.byte_cursor();
+        while let Some(g) = self.grapheme_after(byte).filter(|g| !is_word(g)) {
+            byte += g.len();
+        }
+        while let Some(g) = self.grapheme_after(byte).filter(|g| is_word(g)) {
+            byte += g.len();
+        }
+        self.set_byte_cursor(byte);
+    }
+
+    /// Delete back to the previous whitespace
+    pub fn delete_word_before(&mut self) {
+        let end = self.byte_cursor();
+        let start = self.word_start_before(end, |g| !g.chars().all(char::is_whitespace));
+        self.remove(start, end);
+    }
+
+    /// Delete everything before the cursor
+    pub fn clear_to_start(&mut self) {
+        let end = self.byte_cursor();
+        self.remove(0, end);
+    }
+
+    /// Delete everything from the cursor on
+    pub fn clear_to_end(&mut self) {
+        let start = self.byte_cursor();
+        self.text.truncate(start);
+    }
+
+    /// Start of the run of `in_word` graphemes before `byte`, skipping
+    /// the graphemes outside it that come first
+    fn word_start_before(&self, mut byte: usize, in_word: fn(&str) -> bool) -> usize {
+        while let Some(g) = self.grapheme_before(byte).filter(|g| !in_word(g)) {
+            byte -= g.len();
+        }
+        while let Some(g) = self.grapheme_before(byte).filter(|g| in_word(g)) {
+            byte -= g.len();
+        }
+        byte
+    }
+
+    fn grapheme_before(&self, byte: usize) -> Option<&str> {
+        self.text[..byte].graphemes(true).next_back()
+    }
+
+    fn grapheme_after(&self, byte: usize) -> Option<&str> {
+        self.text[byte..].graphemes(true).next()
+    }
+
+    fn prev_boundary(&self, byte: usize) -> usize {
+        self.grapheme_before(byte).map_or(byte, |g| byte - g.len())
+    }
+
+    fn next_boundary(&self, byte: usize) -> usize {
+        self.grapheme_after(byte).map_or(byte, |g| byte + g.len())
+    }
+
+    fn remove(&mut self, start: usize, end: usize) {
+        self.text.replace_range(start..end, "");
+        self.set_byte_cursor(start);
+    }
+
+    /// The cursor as a byte offset, moved back onto a grapheme boundary
+    fn byte_cursor(&self) -> usize {
+        let byte = byte_offset(self.text, *self.cursor);
+        if byte >= self.text.len() {
+            return self.text.len();
+        }
+        self.text
+            .grapheme_indices(true)
+            .map(|(i, _)| i)
+            .take_while(|&i| i <= byte)
+            .last()
+            .unwrap_or(0)
+    }
+
+    fn set_byte_cursor(&mut self, byte: usize) {
+        *self.cursor = self.text[..byte].chars().count();
+    }
+}
+
+/// Whether a grapheme is part of a word for Alt+B/Alt+F
+fn is_word(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Apply `keys` to `text` with the cursor at `cursor`, returning the
+    /// text with `|` marking the cursor
+    fn edit(text: &str, cursor: usize, keys: &[KeyEvent]) -> String {
+        let mut text = text.to_string();
+        let mut cursor = cursor;
+        let mut editor = LineEditor::new(&mut text, &mut cursor);
+        for key in keys {
+            assert!(editor.handle_key(*key), "{:?} should be handled", key);
+        }
+        let at = byte_offset(&text, cursor);
+        format!("{}|{}", &text[..at], &text[at..])
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    fn alt(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT)
+    }
+
+    #[test]
+    fn test_typing_inserts_at_cursor() {
+        assert_eq!(edit("hllo", 1, &[key(KeyCode::Char('e'))]), "he|llo");
+        let shifted = KeyEvent::new(KeyCode::Char('H'), KeyModifiers::SHIFT);
+        assert_eq!(edit("ello", 0, &[shifted]), "H|ello");
+    }
+
+    #[test]
+    fn test_ctrl_a_and_ctrl_e_jump_to_home_and_end() {
+        assert_eq!(edit("hello", 3, &[ctrl('a')]), "|hello");
+        assert_eq!(edit("hello", 3, &[ctrl('e')]), "hello|");
+        assert_eq!(edit("hello", 3, &[key(KeyCode::Home)]), "|hello");
+        assert_eq!(edit("hello", 3, &[key(KeyCode::End)]), "hello|");
+    }
+
+    #[test]
+    fn test_ctrl_w_deletes_back_to_whitespace() {
+        assert_eq!(edit("open the door", 13, &[ctrl('w')]), "open the |");
+        // Trailing whitespace goes with the word before it
+        assert_eq!(edit("open the  ", 10, &[ctrl('w')]), "open |");
+        // Punctuation doesn't end the word, so a URL goes at once
+        let url = "go https://app.clickup.com/t/abc";
+        assert_eq!(edit(url, 32, &[ctrl('w')]), "go |");
+        // Only what is before the cursor
+        assert_eq!(edit("one two", 5, &[ctrl('w')]), "one |wo");
+        assert_eq!(edit("", 0, &[ctrl('w')]), "|");
+    }
+
+    #[test]
+    fn test_ctrl_u_and_ctrl_k_clear_to_start_and_end() {
+        assert_eq!(edit("token-123", 6, &[ctrl('u')]), "|123");
+        assert_eq!(edit("token-123", 6, &[ctrl('k')]), "token-|");
+    }
+
+    #[test]
+    fn test_alt_b_and_alt_f_stop_at_punctuation() {
+        let path = "app.clickup.com/t/abc";
+        assert_eq!(edit(path, 21, &[alt('b')]), "app.clickup.com/t/|abc");
+        assert_eq!(
+            edit(path, 21, &[alt('b'), alt('b')]),
+            "app.clickup.com/|t/abc"
+        );
+        assert_eq!(edit(path, 0, &[alt('f')]), "app|.clickup.com/t/abc");
+        assert_eq!(edit(path, 3, &[alt('f')]), "app.clickup|.com/t/abc");
+        // Whitespace and punctuation before a word are skipped together
+        assert_eq!(edit("a -- b", 6, &[alt('b'), alt('b')]), "|a -- b");
+        assert_eq!(edit("a -- b", 1, &[alt('f')]), "a -- b|");
+    }
+
+    #[test]
+    fn test_arrows_delete_and_backspace() {
+        assert_eq!(edit("abc", 1, &[key(KeyCode::Right)]), "ab|c");
+        assert_eq!(edit("abc", 1, &[key(KeyCode::Left)]), "|abc");
+        assert_eq!(edit("abc", 0, &[key(KeyCode::Left)]), "|abc");
+        assert_eq!(edit("abc", 3, &[key(KeyCode::Right)]), "abc|");
+        assert_eq!(edit("abc", 1, &[key(KeyCode::Delete)]), "a|c");
+        assert_eq!(edit("abc", 3, &[key(KeyCode::Delete)]), "abc|");
+        assert_eq!(edit("abc", 1, &[key(KeyCode::Backspace)]), "|bc");
+        assert_eq!(edit("abc", 0, &[key(KeyCode::Backspace)]), "|abc");
+    }
+
+    #[test]
+    fn test_multibyte_text_edits_whole_graphemes() {
+        // "é" as e + combining acute accent, and a two-code-point flag
+        let text = "cafe\u{301} 🇲🇾 ok";
+        assert_eq!(edit(text, 5, &[key(KeyCode::Backspace)]), "caf| 🇲🇾 ok");
+        assert_eq!(edit(text, 3, &[key(KeyCode::Right)]), "cafe\u{301}| 🇲🇾 ok");
+        assert_eq!(edit(text, 6, &[key(KeyCode::Delete)]), "cafe\u{301} | ok");
+        assert_eq!(edit(text, 8, &[key(KeyCode::Left)]), "cafe\u{301} |🇲🇾 ok");
+        assert_eq!(edit(text, 0, &[alt('f')]), "cafe\u{301}| 🇲🇾 ok");
+        assert_eq!(edit(text, 11, &[alt('b'), alt('b')]), "|cafe\u{301} 🇲🇾 ok");
+        assert_eq!(edit(text, 8, &[ctrl('w')]), "cafe\u{301} | ok");
+        assert_eq!(edit("日本語", 2, &[ctrl('k')]), "日本|");
+    }
+
+    #[test]
+    fn test_cursor_inside_a_grapheme_moves_to_its_start() {
+        // Char offset 4 is between "e" and its combining accent
+        assert_eq!(edit("cafe\u{301}", 4, &[]), "caf|e\u{301}");
+        assert_eq!(edit("ab", 10, &[]), "ab|");
+    }
+
+    #[test]
+    fn test_other_keys_are_left_to_the_caller() {
+        let mut text = "abc".to_string();
+        let mut cursor = 1;
+        let mut editor = LineEditor::new(&mut text, &mut cursor);
+        assert!(!editor.handle_key(key(KeyCode::Enter)));
+        assert!(!editor.handle_key(key(KeyCode::Esc)));
+        assert!(!editor.handle_key(key(KeyCode::Tab)));
+        assert!(!editor.handle_key(ctrl('s')));
+        assert!(!editor.handle_key(ctrl('v')));
+        assert!(!editor.handle_key(alt('x')));
+        assert_eq!((text.as_str(), cursor), ("abc", 1));
+    }
+}
@@ -5,9 +5,10 @@ use ratatui::{
     layout::{Constraint, Direction, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Minimum terminal dimensions
 pub const MIN_WIDTH: u16 = 80;
@@ -17,6 +18,36 @@ pub const MIN_HEIGHT: u16 = 24;
 pub const TASK_DETAIL_DESCRIPTION_RATIO: u16 = 30;
 pub const TASK_DETAIL_COMMENTS_RATIO: u16 = 70;
 
+/// Width ratio for the two-column task detail view: metadata gets 40%,
+/// description and comments share the remaining 60%
+pub const TASK_DETAIL_METADATA_RATIO: u16 = 40;
+pub const TASK_DETAIL_CONTENT_RATIO: u16 = 60;
+
+/// Spinner animation frames shown while loading
+pub const SPINNER_FRAMES: [char; 8] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧'];
+
+/// Spinner character for an animation frame, wrapping past the last one
+pub fn spinner_char(loading_frame: u8) -> char {
+    SPINNER_FRAMES[loading_frame as usize % SPINNER_FRAMES.len()]
+}
+
+/// A one-line input's text with the cursor (a char offset on a grapheme
+/// boundary) highlighting the character under it, or a space at the end
+pub fn input_spans(text: &str, cursor: usize) -> Vec<Span<'static>> {
+    let byte = crate::utils::byte_offset(text, cursor);
+    let (before, rest) = text.split_at(byte);
+    let under = rest.graphemes(true).next().unwrap_or("");
+    let after = &rest[under.len()..];
+    vec![
+        Span::raw(before.to_string()),
+        Span::styled(
+            if under.is_empty() { " " } else { under }.to_string(),
+            Style::default().bg(Theme::SECONDARY),
+        ),
+        Span::raw(after.to_string()),
+    ]
+}
+
 /// Create a standard titled block with the given title
 pub fn titled_block(title: impl Into<Line<'static>>) -> Block<'static> {
     Block::default()
@@ -89,7 +120,19 @@ impl TuiLayout {
     }
 
     /// Render status bar with message and help hints
-    pub fn render_status(&self, frame: &mut Frame, status: &str, hints: &str) {
+    ///
+    /// `loading_frame` is the spinner frame to prepend while loading, `None` otherwise.
+    pub fn render_status(
+        &self,
+        frame: &mut Frame,
+        status: &str,
+        hints: &str,
+        loading_frame: Option<u8>,
+    ) {
+        let status = match loading_frame {
+            Some(loading_frame) => format!("{} {}", spinner_char(loading_frame), status),
+            None => status.to_string(),
+        };
         let status_text = Line::from(vec![
             Span::styled(status, Style::default().fg(Theme::WARNING)),
             Span::raw(" | "),
@@ -105,6 +148,25 @@ impl TuiLayout {
         frame.render_widget(status_widget, self.status_area);
     }
 
+    /// Blank out `area` and show a centered spinner with `message`
+    pub fn render_loading_overlay(frame: &mut Frame, area: Rect, message: &str, loading_frame: u8) {
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Block::default().style(Style::default().bg(Theme::BACKGROUND)),
+            area,
+        );
+
+        let line_area = Rect {
+            y: area.y + area.height / 2,
+            height: area.height.min(1),
+            ..area
+        };
+        let text = Paragraph::new(format!("{} {}", spinner_char(loading_frame), message))
+            .style(Style::default().fg(Theme::TEXT_DIM))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(text, line_area);
+    }
+
     /// Render "terminal too small" warning
     pub fn render_too_small_warning(&self, frame: &mut Frame) {
         let warning = Paragraph::new(vec![
@@ -161,6 +223,22 @@ pub fn split_task_detail(area: Rect) -> (Rect, Rect) {
     (chunks[0], chunks[1])
 }
 
+/// Split task detail area into a metadata column beside a column holding
+/// description and comments (stacked 3:7 as in [`split_task_detail`])
+/// Returns (metadata_area, description_area, comments_area)
+pub fn split_task_detail_columns(area: Rect) -> (Rect, Rect, Rect) {
+    let columns = ratatui::layout::Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(TASK_DETAIL_METADATA_RATIO),
+            Constraint::Percentage(TASK_DETAIL_CONTENT_RATIO),
+        ])
+        .split(area);
+    let (description_area, comments_area) = split_task_detail(columns[1]);
+
+    (columns[0], description_area, comments_area)
+}
+
 /// Scroll state for tracking independent panel scrolling
 #[derive(Debug, Clone, Default)]
 pub struct ScrollState {
@@ -280,6 +358,13 @@ pub fn render_scroll_indicator(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_spinner_char_wraps() {
+        assert_eq!(spinner_char(0), '⠋');
+        assert_eq!(spinner_char(7), '⠧');
+        assert_eq!(spinner_char(8), '⠋');
+    }
+
     #[test]
     fn test_scroll_state_new() {
         let state = ScrollState::new();
@@ -402,9 +487,24 @@ mod tests {
 
         // Allow some tolerance for rounding
         assert!(
-            desc_ratio >= 0.25 && desc_ratio <= 0.35,
+            (0.25..=0.35).contains(&desc_ratio),
             "Description ratio should be ~30%, got {}",
             desc_ratio
         );
     }
+
+    #[test]
+    fn test_split_task_detail_columns() {
+        let area = Rect::new(0, 0, 200, 50);
+        let (metadata, description, comments) = split_task_detail_columns(area);
+
+        assert_eq!(metadata.x, 0);
+        assert_eq!(metadata.width, 80);
+        assert_eq!(metadata.height, 50);
+        assert_eq!(description.x, 80);
+        assert_eq!(description.width, 120);
+        assert_eq!(comments.x, 80);
+        assert_eq!(description.y + description.height, comments.y);
+        assert_eq!(description.height + comments.height, 50);
+    }
 }
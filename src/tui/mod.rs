@@ -1,9 +1,17 @@
 //! TUI module for terminal-based user interface
 
 pub mod app;
+pub mod calendar;
+pub mod debounce;
+pub mod export;
+pub mod external_editor;
 pub mod helpers;
 pub mod input;
 pub mod layout;
+pub mod reply_prefetch;
+pub mod status;
 pub mod terminal;
 pub mod theme;
+pub mod type_ahead;
+pub mod undo;
 pub mod widgets;
@@ -0,0 +1,143 @@
+//! Hover-intent prefetching of comment replies
+//!
+//! When the selection rests on a top-level comment for [`HOVER_DELAY`], its
+//! replies are fetched in the background so entering the thread is instant.
+//! Like [`Debounce`](super::debounce::Debounce), the run loop passes the time
+//! in on every tick. At most [`MAX_PREFETCHED_THREADS`] prefetched threads are
+//! kept; the least recently used one is dropped to make room.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// How long the selection must rest on a comment before its replies are fetched
+pub const HOVER_DELAY: Duration = Duration::from_millis(500);
+
+/// Prefetched threads kept before the least recently used is dropped
+pub const MAX_PREFETCHED_THREADS: usize = 8;
+
+#[derive(Debug, Clone)]
+pub struct ReplyPrefetch {
+    /// Comment the selection rests on, and since when
+    hover: Option<(String, Instant)>,
+    /// Prefetched threads by parent comment ID, most recently used last
+    threads: VecDeque<String>,
+}
+
+impl ReplyPrefetch {
+    pub fn new() -> Self {
+        Self {
+            hover: None,
+            threads: VecDeque::new(),
+        }
+    }
+
+    /// Note the comment the selection is on at `now` (None when it's on
+    /// nothing worth prefetching); moving elsewhere restarts the timer
+    pub fn hover(&mut self, comment_id: Option<&str>, now: Instant) {
+        match comment_id {
+            Some(id) if self.hover.as_ref().is_some_and(|(hovered, _)| hovered == id) => {}
+            Some(id) => self.hover = Some((id.to_string(), now)),
+            None => self.hover = None,
+        }
+    }
+
+    /// The comment whose replies are due for fetching at `now`, once per hover
+    pub fn due(&mut self, now: Instant) -> Option<String> {
+        let due = self
+            .hover
+            .as_ref()
+            .is_some_and(|(_, since)| now.saturating_duration_since(*since) >= HOVER_DELAY);
+        if due {
+            self.hover.take().map(|(id, _)| id)
+        } else {
+            None
+        }
+    }
+
+    /// Remember a prefetched thread, returning the thread dropped to make room
+    pub fn insert(&mut self, parent_id: String) -> Option<String> {
+        self.threads.retain(|id| *id != parent_id);
+        self.threads.push_back(parent_id);
+        if self.threads.len() > MAX_PREFETCHED_THREADS {
+            self.threads.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Mark a prefetched thread as used, so it's dropped last
+    pub fn touch(&mut self, parent_id: &str) {
+        if let Some(position) = self.threads.iter().position(|id| id == parent_id) {
+            if let Some(id) = self.threads.remove(position) {
+                self.threads.push_back(id);
+            }
+        }
+    }
+
+    /// Forget the timer and the prefetched threads, e.g. for another task
+    pub fn clear(&mut self) {
+        self.hover = None;
+        self.threads.clear();
+    }
+}
+
+impl Default for ReplyPrefetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_due_once_after_resting_on_a_comment() {
+        let start = Instant::now();
+        let mut prefetch = ReplyPrefetch::new();
+        prefetch.hover(Some("c1"), start);
+
+        // Hovering the same comment again keeps the original start
+        prefetch.hover(Some("c1"), start + Duration::from_millis(300));
+        assert_eq!(prefetch.due(start + Duration::from_millis(499)), None);
+        assert_eq!(prefetch.due(start + HOVER_DELAY), Some("c1".to_string()));
+        assert_eq!(prefetch.due(start + Duration::from_secs(5)), None);
+    }
+
+    #[test]
+    fn test_moving_the_selection_restarts_or_cancels() {
+        let start = Instant::now();
+        let mut prefetch = ReplyPrefetch::new();
+        prefetch.hover(Some("c1"), start);
+        prefetch.hover(Some("c2"), start + Duration::from_millis(400));
+        assert_eq!(prefetch.due(start + Duration::from_millis(600)), None);
+        assert_eq!(
+            prefetch.due(start + Duration::from_millis(900)),
+            Some("c2".to_string())
+        );
+
+        prefetch.hover(Some("c3"), start);
+        prefetch.hover(None, start + Duration::from_millis(100));
+        assert_eq!(prefetch.due(start + Duration::from_secs(1)), None);
+    }
+
+    #[test]
+    fn test_least_recently_used_thread_is_dropped() {
+        let mut prefetch = ReplyPrefetch::new();
+        for i in 0..MAX_PREFETCHED_THREADS {
+            assert_eq!(prefetch.insert(format!("t{}", i)), None);
+        }
+
+        // Using the oldest makes the next oldest go first
+        prefetch.touch("t0");
+        assert_eq!(prefetch.insert("new".to_string()), Some("t1".to_string()));
+        assert_eq!(prefetch.insert("newer".to_string()), Some("t2".to_string()));
+
+        // Inserting a known thread only refreshes it
+        assert_eq!(prefetch.insert("t3".to_string()), None);
+        assert_eq!(prefetch.insert("newest".to_string()), Some("t4".to_string()));
+
+        prefetch.clear();
+        assert_eq!(prefetch.insert("t0".to_string()), None);
+    }
+}
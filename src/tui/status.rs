@@ -0,0 +1,94 @@
+//! Auto-clearing of transient status bar messages
+//!
+//! The status line is assigned from many places, so rather than stamping each
+//! assignment the clock notices when the text changes and times it from there.
+
+use std::time::{Duration, Instant};
+
+/// Tracks how long the current status message has been shown
+#[derive(Debug, Clone, Default)]
+pub struct StatusClock {
+    /// How long a message stays up; `None` keeps messages until replaced
+    timeout: Option<Duration>,
+    /// The message being timed
+    shown: String,
+    since: Option<Instant>,
+}
+
+impl StatusClock {
+    /// A clock clearing messages after `secs` seconds (0 never clears)
+    pub fn new(secs: u64) -> Self {
+        Self {
+            timeout: (secs > 0).then(|| Duration::from_secs(secs)),
+            ..Self::default()
+        }
+    }
+
+    /// The configured timeout
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Whether `status`, seen at `now`, has been up for the whole timeout
+    ///
+    /// A message different from the last one seen starts a new timer.
+    pub fn is_expired(&mut self, status: &str, now: Instant) -> bool {
+        if status != self.shown || self.since.is_none() {
+            self.shown = status.to_string();
+            self.since = Some(now);
+            return false;
+        }
+        match (self.timeout, self.since) {
+            (Some(timeout), Some(since)) => {
+                !status.is_empty() && now.saturating_duration_since(since) >= timeout
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_expires_after_timeout() {
+        let start = Instant::now();
+        let mut clock = StatusClock::new(3);
+
+        assert!(!clock.is_expired("Loaded 3 task(s)", start));
+        assert!(!clock.is_expired("Loaded 3 task(s)", start + Duration::from_secs(2)));
+        assert!(clock.is_expired("Loaded 3 task(s)", start + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_new_message_restarts_timer() {
+        let start = Instant::now();
+        let mut clock = StatusClock::new(3);
+
+        clock.is_expired("Loading tasks...", start);
+        let later = start + Duration::from_secs(2);
+        assert!(!clock.is_expired("Loaded 3 task(s)", later));
+        assert!(!clock.is_expired("Loaded 3 task(s)", start + Duration::from_secs(4)));
+        assert!(clock.is_expired("Loaded 3 task(s)", later + Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_zero_never_expires() {
+        let start = Instant::now();
+        let mut clock = StatusClock::new(0);
+
+        clock.is_expired("Saved", start);
+        assert!(!clock.is_expired("Saved", start + Duration::from_secs(3600)));
+        assert!(clock.timeout().is_none());
+    }
+
+    #[test]
+    fn test_empty_status_never_expires() {
+        let start = Instant::now();
+        let mut clock = StatusClock::new(1);
+
+        clock.is_expired("", start);
+        assert!(!clock.is_expired("", start + Duration::from_secs(5)));
+    }
+}
@@ -1,12 +1,13 @@
 //! Terminal initialization and management
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::{
     terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use ratatui::{backend::CrosstermBackend, Frame, Terminal};
-use std::io;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
 
 /// Initialize the terminal for TUI rendering
 pub fn init() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
@@ -40,6 +41,124 @@ pub fn restore() -> Result<()> {
     Ok(())
 }
 
+/// Switching the terminal between the TUI and the shell
+pub trait ScreenModes {
+    /// Hand the terminal back to the shell
+    fn leave(&mut self) -> Result<()>;
+    /// Take the terminal back for the TUI
+    fn enter(&mut self) -> Result<()>;
+}
+
+/// The real terminal: alternate screen and raw mode
+struct TerminalModes<'a> {
+    terminal: &'a mut Terminal<CrosstermBackend<io::Stdout>>,
+}
+
+impl ScreenModes for TerminalModes<'_> {
+    fn leave(&mut self) -> Result<()> {
+        io::stdout().execute(LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+        Ok(())
+    }
+
+    /// Also forces a full redraw, as the other program drew over the screen
+    fn enter(&mut self) -> Result<()> {
+        io::stdout().execute(EnterAlternateScreen)?;
+        terminal::enable_raw_mode()?;
+        self.terminal.clear()?;
+        Ok(())
+    }
+}
+
+/// The TUI suspended while another program runs
+///
+/// [`Suspended::restore`] takes the terminal back; restoring again does
+/// nothing, and a suspension dropped without being restored (e.g. on a
+/// panic) is restored then.
+pub struct Suspended<M: ScreenModes> {
+    modes: M,
+    restored: bool,
+}
+
+impl<M: ScreenModes> Suspended<M> {
+    pub fn new(mut modes: M) -> Result<Self> {
+        modes.leave()?;
+        Ok(Self {
+            modes,
+            restored: false,
+        })
+    }
+
+    pub fn restore(&mut self) -> Result<()> {
+        if self.restored {
+            return Ok(());
+        }
+        self.restored = true;
+        self.modes.enter()
+    }
+}
+
+impl<M: ScreenModes> Drop for Suspended<M> {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
+
+/// Run `program` (a pager, an editor) with the TUI suspended
+///
+/// The terminal is restored however `program` ends, so a broken program
+/// never leaves the terminal in cooked mode.
+pub fn while_suspended<T>(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    program: impl FnOnce() -> Result<T>,
+) -> Result<T> {
+    let mut suspended = Suspended::new(TerminalModes { terminal })?;
+    let result = program();
+    suspended.restore()?;
+    result
+}
+
+/// Show `content` in an external pager, suspending the TUI until it exits
+pub fn run_pager(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    command: &str,
+    content: &str,
+) -> Result<()> {
+    while_suspended(terminal, || pipe_to_pager(command, content))
+}
+
+/// Spawn `command` and write `content` to its stdin, waiting for it to exit
+fn pipe_to_pager(command: &str, content: &str) -> Result<()> {
+    let mut parts = command.split_whitespace();
+    let program = parts.next().context("Pager command is empty")?;
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start pager '{}'", command))?;
+
+    // Dropping stdin closes the pipe so the pager sees end of input
+    let written = match child.stdin.take() {
+        Some(mut stdin) => stdin.write_all(content.as_bytes()),
+        None => Ok(()),
+    };
+
+    // Always wait, so the TUI never resumes underneath a running pager
+    let status = child.wait().context("Failed to wait for pager")?;
+    match written {
+        // The pager may quit before reading everything; that's not an error
+        Err(e) if e.kind() != io::ErrorKind::BrokenPipe => {
+            return Err(e).context("Failed to write to pager")
+        }
+        _ => {}
+    }
+    if !status.success() {
+        anyhow::bail!("Pager '{}' exited with {}", command, status);
+    }
+    Ok(())
+}
+
 /// Draw a frame on the terminal
 #[allow(dead_code)]
 pub fn draw<F>(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, f: F) -> Result<()>
@@ -49,3 +168,42 @@ where
     terminal.draw(f)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Records each switch instead of touching the terminal
+    struct RecordedModes(Rc<RefCell<Vec<&'static str>>>);
+
+    impl ScreenModes for RecordedModes {
+        fn leave(&mut self) -> Result<()> {
+            self.0.borrow_mut().push("leave");
+            Ok(())
+        }
+
+        fn enter(&mut self) -> Result<()> {
+            self.0.borrow_mut().push("enter");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_restore_is_idempotent() {
+        let switches = Rc::new(RefCell::new(Vec::new()));
+        let mut suspended = Suspended::new(RecordedModes(switches.clone())).unwrap();
+        suspended.restore().unwrap();
+        suspended.restore().unwrap();
+        drop(suspended);
+        assert_eq!(*switches.borrow(), ["leave", "enter"]);
+    }
+
+    #[test]
+    fn test_dropped_suspension_is_restored() {
+        let switches = Rc::new(RefCell::new(Vec::new()));
+        drop(Suspended::new(RecordedModes(switches.clone())).unwrap());
+        assert_eq!(*switches.borrow(), ["leave", "enter"]);
+    }
+}
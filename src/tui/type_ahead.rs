@@ -0,0 +1,156 @@
+//! Type-ahead selection for lists
+//!
+//! Typing a prefix after the `'` leader jumps the selection to the first item
+//! whose name starts with it, like file managers do. The buffer is dropped
+//! after a short period without input.
+
+use crate::tui::input::LineEditor;
+use crossterm::event::{KeyCode, KeyEvent};
+use std::time::{Duration, Instant};
+
+/// Key that starts type-ahead (keeps plain letters free for action bindings)
+pub const TYPE_AHEAD_LEADER: char = '\'';
+
+/// How long the buffer survives without input
+pub const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// Type-ahead buffer state
+#[derive(Debug, Clone, Default)]
+pub struct TypeAhead {
+    buffer: String,
+    /// Cursor in `buffer`, in characters
+    cursor: usize,
+    active: bool,
+    last_input: Option<Instant>,
+}
+
+impl TypeAhead {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start collecting a prefix
+    pub fn start(&mut self, now: Instant) {
+        self.buffer.clear();
+        self.cursor = 0;
+        self.active = true;
+        self.last_input = Some(now);
+    }
+
+    /// Stop collecting and drop the buffer
+    pub fn cancel(&mut self) {
+        self.buffer.clear();
+        self.cursor = 0;
+        self.active = false;
+        self.last_input = None;
+    }
+
+    /// Apply an editing key to the buffer, returning whether it was one
+    ///
+    /// Backspace on an empty buffer cancels.
+    pub fn edit(&mut self, key: KeyEvent, now: Instant) -> bool {
+        if key.code == KeyCode::Backspace && self.buffer.is_empty() {
+            self.cancel();
+            return true;
+        }
+        let edited = LineEditor::new(&mut self.buffer, &mut self.cursor).handle_key(key);
+        if edited {
+            self.last_input = Some(now);
+        }
+        edited
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Whether the buffer has timed out at `now`
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.active
+            && self
+                .last_input
+                .is_some_and(|last| now.duration_since(last) > TYPE_AHEAD_TIMEOUT)
+    }
+
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+}
+
+/// Index of the first name starting with `prefix`, ignoring case
+///
+/// An empty prefix matches nothing, so the selection stays put.
+pub fn find_prefix_match<'a>(
+    names: impl IntoIterator<Item = (usize, &'a str)>,
+    prefix: &str,
+) -> Option<usize> {
+    if prefix.is_empty() {
+        return None;
+    }
+    let prefix = prefix.to_lowercase();
+    names
+        .into_iter()
+        .find(|(_, name)| name.to_lowercase().starts_with(&prefix))
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_prefix_match_is_case_insensitive() {
+        let names = [(0, "Fix login"), (1, "feature flags"), (2, "Write docs")];
+
+        assert_eq!(find_prefix_match(names, "F"), Some(0));
+        assert_eq!(find_prefix_match(names, "fe"), Some(1));
+        assert_eq!(find_prefix_match(names, "WRI"), Some(2));
+        assert_eq!(find_prefix_match(names, "zzz"), None);
+        assert_eq!(find_prefix_match(names, ""), None);
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, crossterm::event::KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_buffer_edit() {
+        let now = Instant::now();
+        let mut type_ahead = TypeAhead::new();
+        type_ahead.start(now);
+        assert!(type_ahead.edit(key(KeyCode::Char('a')), now));
+        assert!(type_ahead.edit(key(KeyCode::Char('b')), now));
+        assert_eq!(type_ahead.buffer(), "ab");
+
+        assert!(type_ahead.edit(key(KeyCode::Home), now));
+        assert!(type_ahead.edit(key(KeyCode::Char('x')), now));
+        assert_eq!(type_ahead.buffer(), "xab");
+        assert!(!type_ahead.edit(key(KeyCode::Enter), now));
+
+        assert!(type_ahead.edit(key(KeyCode::Backspace), now));
+        assert_eq!(type_ahead.buffer(), "ab");
+        type_ahead.edit(key(KeyCode::End), now);
+        type_ahead.edit(key(KeyCode::Backspace), now);
+        type_ahead.edit(key(KeyCode::Backspace), now);
+        assert!(type_ahead.is_active());
+
+        // Backspace on an empty buffer leaves type-ahead
+        type_ahead.edit(key(KeyCode::Backspace), now);
+        assert!(!type_ahead.is_active());
+    }
+
+    #[test]
+    fn test_timeout_resets_on_input() {
+        let start = Instant::now();
+        let mut type_ahead = TypeAhead::new();
+        type_ahead.start(start);
+
+        assert!(!type_ahead.is_expired(start + Duration::from_millis(1000)));
+        type_ahead.edit(key(KeyCode::Char('x')), start + Duration::from_millis(1000));
+        assert!(!type_ahead.is_expired(start + Duration::from_millis(2000)));
+        assert!(type_ahead.is_expired(start + Duration::from_millis(2600)));
+
+        type_ahead.cancel();
+        assert!(!type_ahead.is_expired(start + Duration::from_secs(10)));
+    }
+}
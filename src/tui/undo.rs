@@ -0,0 +1,195 @@
+//! Undo stack for reversible actions
+//!
+//! Each entry keeps enough data to issue the inverse API call. The stack lives
+//! only in memory, so entries never outlive the session that created them.
+//! Task deletion is not recorded: ClickUp offers no API to restore a task.
+
+/// Maximum number of actions kept; the oldest is dropped first
+pub const UNDO_STACK_CAP: usize = 20;
+
+/// An action that can be reverted
+#[derive(Debug, Clone, PartialEq)]
+pub enum UndoAction {
+    /// A comment was deleted; it is recreated with the same text and parent
+    CommentDeleted {
+        task_id: String,
+        parent_id: Option<String>,
+        text: String,
+    },
+    /// A task's status was changed from `previous_status`
+    StatusChanged {
+        task_id: String,
+        previous_status: String,
+    },
+}
+
+/// API call that reverts an [`UndoAction`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum InverseCall {
+    CreateComment { task_id: String, text: String },
+    CreateCommentReply { parent_id: String, text: String },
+    SetStatus { task_id: String, status: String },
+}
+
+impl UndoAction {
+    /// The call that reverts this action
+    pub fn inverse(&self) -> InverseCall {
+        match self {
+            UndoAction::CommentDeleted {
+                task_id,
+                parent_id: None,
+                text,
+            } => InverseCall::CreateComment {
+                task_id: task_id.clone(),
+                text: text.clone(),
+            },
+            UndoAction::CommentDeleted {
+                parent_id: Some(parent_id),
+                text,
+                ..
+            } => InverseCall::CreateCommentReply {
+                parent_id: parent_id.clone(),
+                text: text.clone(),
+            },
+            UndoAction::StatusChanged {
+                task_id,
+                previous_status,
+            } => InverseCall::SetStatus {
+                task_id: task_id.clone(),
+                status: previous_status.clone(),
+            },
+        }
+    }
+
+    /// Short description for the status bar, e.g. "comment deletion"
+    pub fn description(&self) -> String {
+        match self {
+            UndoAction::CommentDeleted {
+                parent_id: None, ..
+            } => "comment deletion".to_string(),
+            UndoAction::CommentDeleted { .. } => "reply deletion".to_string(),
+            UndoAction::StatusChanged {
+                previous_status, ..
+            } => format!("status change (back to {})", previous_status),
+        }
+    }
+}
+
+/// Bounded stack of reversible actions, most recent last
+#[derive(Debug, Clone, Default)]
+pub struct UndoStack {
+    entries: Vec<UndoAction>,
+}
+
+impl UndoStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an action, dropping the oldest one when the stack is full
+    pub fn push(&mut self, action: UndoAction) {
+        if self.entries.len() == UNDO_STACK_CAP {
+            self.entries.remove(0);
+        }
+        self.entries.push(action);
+    }
+
+    /// Take the most recent action
+    pub fn pop(&mut self) -> Option<UndoAction> {
+        self.entries.pop()
+    }
+
+    #[allow(dead_code)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_change(previous: &str) -> UndoAction {
+        UndoAction::StatusChanged {
+            task_id: "task-1".to_string(),
+            previous_status: previous.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_inverse_of_top_level_comment_deletion_recreates_comment() {
+        let action = UndoAction::CommentDeleted {
+            task_id: "task-1".to_string(),
+            parent_id: None,
+            text: "Hello".to_string(),
+        };
+        assert_eq!(
+            action.inverse(),
+            InverseCall::CreateComment {
+                task_id: "task-1".to_string(),
+                text: "Hello".to_string(),
+            }
+        );
+        assert_eq!(action.description(), "comment deletion");
+    }
+
+    #[test]
+    fn test_inverse_of_reply_deletion_recreates_reply() {
+        let action = UndoAction::CommentDeleted {
+            task_id: "task-1".to_string(),
+            parent_id: Some("comment-1".to_string()),
+            text: "Reply".to_string(),
+        };
+        assert_eq!(
+            action.inverse(),
+            InverseCall::CreateCommentReply {
+                parent_id: "comment-1".to_string(),
+                text: "Reply".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_inverse_of_status_change_restores_previous_status() {
+        assert_eq!(
+            status_change("to do").inverse(),
+            InverseCall::SetStatus {
+                task_id: "task-1".to_string(),
+                status: "to do".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stack_is_lifo_and_capped() {
+        let mut stack = UndoStack::new();
+        for i in 0..UNDO_STACK_CAP + 5 {
+            stack.push(status_change(&i.to_string()));
+        }
+        assert_eq!(stack.len(), UNDO_STACK_CAP);
+
+        assert_eq!(
+            stack.pop(),
+            Some(status_change(&(UNDO_STACK_CAP + 4).to_string()))
+        );
+
+        let mut remaining = 0;
+        let mut oldest = None;
+        while let Some(action) = stack.pop() {
+            remaining += 1;
+            oldest = Some(action);
+        }
+        assert_eq!(remaining, UNDO_STACK_CAP - 1);
+        assert_eq!(
+            oldest,
+            Some(status_change("5")),
+            "Oldest entries are dropped first"
+        );
+        assert!(stack.is_empty());
+    }
+}
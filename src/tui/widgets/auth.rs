@@ -1,14 +1,28 @@
 //! Authentication widget
 
-use crate::tui::layout::centered_rect;
+use crate::tui::input::LineEditor;
+use crate::tui::layout::{centered_rect, spinner_char};
 use crate::tui::theme::Theme;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
+    text::Line,
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
+/// ClickUp settings page where personal API tokens are generated
+pub const CLICKUP_API_SETTINGS_URL: &str = "https://app.clickup.com/settings/apps";
+
+/// Number of steps in the onboarding wizard
+pub const ONBOARDING_STEPS: u8 = 4;
+
+/// Onboarding wizard steps
+pub const STEP_WELCOME: u8 = 1;
+pub const STEP_TOKEN: u8 = 2;
+pub const STEP_VERIFYING: u8 = 3;
+pub const STEP_READY: u8 = 4;
+
 /// Authentication state
 #[derive(Debug, Clone)]
 pub struct AuthState {
@@ -16,28 +30,58 @@ pub struct AuthState {
     pub cursor_pos: usize,
     pub error: Option<String>,
     pub loading: bool,
+    /// Whether the first-launch onboarding wizard is shown
+    pub wizard: bool,
+    /// Current wizard step (1-based, see `STEP_*`)
+    pub onboarding_step: u8,
+    /// Workspace names found when the token was verified
+    pub workspace_names: Vec<String>,
+    /// ClickUp task link pasted before logging in, opened once connected
+    pub pending_link: Option<String>,
 }
 
 impl AuthState {
+    /// Plain token input, without the onboarding wizard
     pub fn new() -> Self {
         Self {
             token_input: String::new(),
             cursor_pos: 0,
             error: None,
             loading: false,
+            wizard: false,
+            onboarding_step: STEP_TOKEN,
+            workspace_names: Vec::new(),
+            pending_link: None,
+        }
+    }
+
+    /// Onboarding wizard for first-time users, starting at the welcome step
+    pub fn with_wizard() -> Self {
+        Self {
+            wizard: true,
+            onboarding_step: STEP_WELCOME,
+            ..Self::new()
         }
     }
 
-    pub fn add_char(&mut self, c: char) {
-        self.token_input.insert(self.cursor_pos, c);
-        self.cursor_pos += 1;
+    /// Move to the next wizard step
+    pub fn next_step(&mut self) {
+        self.onboarding_step = (self.onboarding_step + 1).min(ONBOARDING_STEPS);
     }
 
-    pub fn remove_char(&mut self) {
-        if self.cursor_pos > 0 {
-            self.cursor_pos -= 1;
-            self.token_input.remove(self.cursor_pos);
-        }
+    /// Move to the previous wizard step
+    pub fn previous_step(&mut self) {
+        self.onboarding_step = self.onboarding_step.saturating_sub(1).max(STEP_WELCOME);
+    }
+
+    /// Progress label for the current wizard step, e.g. "Step 2 of 4"
+    pub fn progress_label(&self) -> String {
+        format!("Step {} of {}", self.onboarding_step, ONBOARDING_STEPS)
+    }
+
+    /// Editor over the token input and its cursor
+    pub fn editor(&mut self) -> LineEditor<'_> {
+        LineEditor::new(&mut self.token_input, &mut self.cursor_pos)
     }
 
     pub fn clear(&mut self) {
@@ -53,14 +97,143 @@ impl Default for AuthState {
     }
 }
 
-pub fn render_auth(frame: &mut Frame, state: &AuthState, area: Rect) {
+/// Heading, body and footer of the wizard step shown, or None while the
+/// token form is shown; `loading_frame` animates the verifying spinner
+pub fn wizard_step_text(
+    state: &AuthState,
+    loading_frame: u8,
+) -> Option<(&'static str, Vec<String>, &'static str)> {
+    if !state.wizard {
+        return None;
+    }
+
+    match state.onboarding_step {
+        STEP_WELCOME => Some((
+            "Welcome to ClickDown",
+            vec![
+                "ClickDown is a keyboard-driven terminal client for ClickUp.".to_string(),
+                String::new(),
+                "To get started you need a personal ClickUp API token.".to_string(),
+                "The next step shows where to find it.".to_string(),
+            ],
+            "Press Enter to continue",
+        )),
+        STEP_TOKEN => None,
+        STEP_VERIFYING => Some((
+            "Verifying...",
+            vec![format!(
+                "{} Checking your token with ClickUp",
+                spinner_char(loading_frame)
+            )],
+            "Please wait",
+        )),
+        _ => {
+            let mut lines = vec![format!(
+                "Connected to {} workspace(s):",
+                state.workspace_names.len()
+            )];
+            lines.extend(
+                state
+                    .workspace_names
+                    .iter()
+                    .take(5)
+                    .map(|name| format!("  • {}", name)),
+            );
+            if state.workspace_names.len() > 5 {
+                lines.push(format!("  + {} more", state.workspace_names.len() - 5));
+            }
+            Some(("You're ready!", lines, "Press Enter to start"))
+        }
+    }
+}
+
+/// Where to get a token, shown under the token form's title
+pub fn token_help_text(state: &AuthState) -> String {
+    if state.wizard {
+        format!("Get your token at {}", CLICKUP_API_SETTINGS_URL)
+    } else {
+        "Get your token from ClickUp Settings → Apps → ClickUp API".to_string()
+    }
+}
+
+/// Keys that act on the token form
+pub fn token_instructions(state: &AuthState) -> &'static str {
+    if state.wizard {
+        "Enter to connect, Ctrl+Y to copy the link, Esc to go back"
+    } else {
+        "Press Enter to connect, Esc to cancel"
+    }
+}
+
+/// Render the auth screen; `loading_frame` animates the spinner while the
+/// token is being verified
+pub fn render_auth(frame: &mut Frame, state: &AuthState, area: Rect, loading_frame: u8) {
+    match wizard_step_text(state, loading_frame) {
+        Some((title, body, footer)) => render_wizard_step(
+            frame,
+            state,
+            area,
+            title,
+            body.into_iter().map(Line::from).collect(),
+            footer,
+        ),
+        None => render_token_form(frame, state, area),
+    }
+}
+
+/// Render an informational wizard step with a progress indicator
+fn render_wizard_step(
+    frame: &mut Frame,
+    state: &AuthState,
+    area: Rect,
+    title: &str,
+    body: Vec<Line>,
+    footer: &str,
+) {
+    let auth_area = centered_rect(60, 40, area);
+
+    frame.render_widget(Clear, auth_area);
+
+    let block = Block::default()
+        .title(format!(" {} ", state.progress_label()))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Theme::PRIMARY));
+
+    frame.render_widget(block, auth_area);
+
+    let inner = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(auth_area);
+
+    let heading = Paragraph::new(title).style(Style::default().add_modifier(Modifier::BOLD));
+    frame.render_widget(heading, inner[0]);
+
+    frame.render_widget(Paragraph::new(body), inner[1]);
+
+    let footer = Paragraph::new(footer).style(Style::default().fg(Theme::SECONDARY));
+    frame.render_widget(footer, inner[2]);
+}
+
+/// Render the token input form (wizard step 2, or the whole screen without the wizard)
+fn render_token_form(frame: &mut Frame, state: &AuthState, area: Rect) {
     // Center the auth box
     let auth_area = centered_rect(60, 40, area);
 
     frame.render_widget(Clear, auth_area);
 
+    let block_title = if state.wizard {
+        format!(" {} ", state.progress_label())
+    } else {
+        " Authentication ".to_string()
+    };
     let block = Block::default()
-        .title(" Authentication ")
+        .title(block_title)
         .borders(Borders::ALL)
         .style(Style::default().bg(Theme::PRIMARY));
 
@@ -85,8 +258,7 @@ pub fn render_auth(frame: &mut Frame, state: &AuthState, area: Rect) {
     frame.render_widget(title, inner[0]);
 
     // Help text
-    let help = Paragraph::new("Get your token from ClickUp Settings → Apps → ClickUp API")
-        .style(Style::default().fg(Theme::SECONDARY));
+    let help = Paragraph::new(token_help_text(state)).style(Style::default().fg(Theme::SECONDARY));
     frame.render_widget(help, inner[1]);
 
     // Token input (partially masked: first 4 chars visible, rest masked)
@@ -137,15 +309,19 @@ pub fn render_auth(frame: &mut Frame, state: &AuthState, area: Rect) {
         .style(Style::default().fg(Theme::TEXT_DIM));
     frame.render_widget(token_len_info, inner[3]);
 
-    // Error message
+    // Error message, or the link waiting to be opened
     if let Some(ref error) = state.error {
         let error_para = Paragraph::new(error.as_str()).style(Style::default().fg(Theme::ERROR));
         frame.render_widget(error_para, inner[4]);
+    } else if let Some(ref link) = state.pending_link {
+        let link_para = Paragraph::new(format!("Opens after you connect: {}", link))
+            .style(Style::default().fg(Theme::SECONDARY));
+        frame.render_widget(link_para, inner[4]);
     }
 
     // Instructions
-    let instructions = Paragraph::new("Press Enter to connect, Esc to cancel")
-        .style(Style::default().fg(Theme::SECONDARY));
+    let instructions =
+        Paragraph::new(token_instructions(state)).style(Style::default().fg(Theme::SECONDARY));
     frame.render_widget(instructions, inner[5]);
 }
 
@@ -153,3 +329,81 @@ pub fn render_auth(frame: &mut Frame, state: &AuthState, area: Rect) {
 pub fn get_auth_hints() -> &'static str {
     "Enter: Connect | Esc: Cancel"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn rendered(state: &AuthState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|frame| render_auth(frame, state, frame.area(), 0))
+            .unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn test_token_input_edits_multibyte_characters() {
+        let mut state = AuthState::new();
+        for c in "pké🎉".chars() {
+            state.editor().insert_char(c);
+        }
+        state.editor().backspace();
+        state.editor().insert_char('x');
+        assert_eq!(state.token_input, "pkéx");
+        assert_eq!(state.cursor_pos, 4);
+
+        // Editing in the middle, after a two-byte character
+        state.cursor_pos = 3;
+        state.editor().backspace();
+        state.editor().insert_char('e');
+        assert_eq!(state.token_input, "pkex");
+    }
+
+    #[test]
+    fn test_wizard_steps_are_bounded() {
+        let mut state = AuthState::with_wizard();
+        assert_eq!(state.onboarding_step, STEP_WELCOME);
+
+        state.previous_step();
+        assert_eq!(state.onboarding_step, STEP_WELCOME);
+
+        for _ in 0..10 {
+            state.next_step();
+        }
+        assert_eq!(state.onboarding_step, STEP_READY);
+        assert_eq!(state.progress_label(), "Step 4 of 4");
+    }
+
+    #[test]
+    fn test_render_wizard_welcome_and_token_steps() {
+        let mut state = AuthState::with_wizard();
+        let welcome = rendered(&state);
+        assert!(welcome.contains("Step 1 of 4"));
+        assert!(welcome.contains("Welcome to ClickDown"));
+
+        state.next_step();
+        let token = rendered(&state);
+        assert!(token.contains("Step 2 of 4"));
+        assert!(token.contains(CLICKUP_API_SETTINGS_URL));
+    }
+
+    #[test]
+    fn test_render_wizard_ready_lists_workspaces() {
+        let mut state = AuthState::with_wizard();
+        state.onboarding_step = STEP_READY;
+        state.workspace_names = vec!["Acme".to_string(), "Side project".to_string()];
+
+        let ready = rendered(&state);
+        assert!(ready.contains("You're ready!"));
+        assert!(ready.contains("Connected to 2 workspace(s)"));
+        assert!(ready.contains("Side project"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Initials badges for workspaces and users
+//!
+//! A badge is up to two initials on a colored background, like the avatars in
+//! the ClickUp web app. The color comes from the item's own hex color when it
+//! has a valid one, otherwise from a palette keyed on the name, so an item
+//! keeps the same color across sessions.
+
+use crate::tui::widgets::status_picker::parse_hex_color;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// Fallback colors for items without a usable color of their own
+const PALETTE: [Color; 8] = [
+    Color::Rgb(0x7b, 0x68, 0xee),
+    Color::Rgb(0x00, 0x9d, 0xf5),
+    Color::Rgb(0x1b, 0xbc, 0x9c),
+    Color::Rgb(0x2e, 0xcd, 0x6f),
+    Color::Rgb(0xf9, 0xd9, 0x00),
+    Color::Rgb(0xff, 0x7f, 0x00),
+    Color::Rgb(0xe5, 0x48, 0x4d),
+    Color::Rgb(0xee, 0x5e, 0x99),
+];
+
+/// Initials on a background color
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Badge {
+    pub initials: String,
+    pub color: Color,
+}
+
+impl Badge {
+    /// Badge for `name`, colored by `hex` when it parses
+    pub fn new(name: &str, hex: Option<&str>) -> Self {
+        Self {
+            initials: initials(name),
+            color: hex
+                .and_then(parse_hex_color)
+                .unwrap_or_else(|| fallback_color(name)),
+        }
+    }
+
+    /// The badge as a span, padded and with text readable on its color
+    pub fn span(&self) -> Span<'static> {
+        Span::styled(
+            format!(" {:<2} ", self.initials),
+            Style::default()
+                .bg(self.color)
+                .fg(text_color(self.color))
+                .add_modifier(Modifier::BOLD),
+        )
+    }
+}
+
+/// First letters of the first two words of `name`, uppercased
+///
+/// Words are runs of letters or digits, so "the-ops team" gives "TO". A name
+/// without any gives "?".
+pub fn initials(name: &str) -> String {
+    let initials: String = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect();
+    if initials.is_empty() {
+        "?".to_string()
+    } else {
+        initials
+    }
+}
+
+/// A palette color picked by `name`, the same every time
+pub fn fallback_color(name: &str) -> Color {
+    let hash = name.bytes().fold(0usize, |hash, b| {
+        hash.wrapping_mul(31).wrapping_add(b as usize)
+    });
+    PALETTE[hash % PALETTE.len()]
+}
+
+/// Black or white, whichever reads better on `background`
+fn text_color(background: Color) -> Color {
+    match background {
+        Color::Rgb(r, g, b) => {
+            let luminance = 299 * r as u32 + 587 * g as u32 + 114 * b as u32;
+            if luminance > 150_000 {
+                Color::Black
+            } else {
+                Color::White
+            }
+        }
+        _ => Color::White,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initials_from_up_to_two_words() {
+        assert_eq!(initials("Acme"), "A");
+        assert_eq!(initials("acme corp"), "AC");
+        assert_eq!(initials("Acme Corp Europe"), "AC");
+        assert_eq!(initials("  the-ops   team "), "TO");
+        assert_eq!(initials("2024 roadmap"), "2R");
+        assert_eq!(initials("éclair bakery"), "ÉB");
+        assert_eq!(initials(""), "?");
+        assert_eq!(initials("--"), "?");
+    }
+
+    #[test]
+    fn test_color_from_hex_or_fallback() {
+        assert_eq!(
+            Badge::new("Acme", Some("#7B68EE")).color,
+            Color::Rgb(0x7b, 0x68, 0xee)
+        );
+
+        // Missing or invalid hex: the same palette color for the same name
+        let fallback = fallback_color("Acme");
+        assert!(PALETTE.contains(&fallback));
+        assert_eq!(Badge::new("Acme", None).color, fallback);
+        assert_eq!(Badge::new("Acme", Some("not a color")).color, fallback);
+        assert_eq!(Badge::new("Acme", Some("#12")).color, fallback);
+        assert_eq!(Badge::new("Acme", Some("#1é345")).color, fallback);
+    }
+
+    #[test]
+    fn test_text_readable_on_badge() {
+        assert_eq!(text_color(Color::Rgb(0xf9, 0xd9, 0x00)), Color::Black);
+        assert_eq!(text_color(Color::Rgb(0x7b, 0x68, 0xee)), Color::White);
+    }
+}
@@ -0,0 +1,258 @@
+//! Due-date calendar widget: one month of day cells, each counting the
+//! loaded tasks due that day
+//!
+//! Layout and counting live in [`crate::tui::calendar`]; this is the state
+//! the calendar screen moves around in and how it is drawn.
+
+use crate::models::Task;
+use crate::tui::calendar::{count_by_day, shift_months, DayCount, MonthLayout};
+use crate::tui::layout::titled_block;
+use crate::tui::theme::Theme;
+use chrono::{Days, NaiveDate, Weekday};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+use std::collections::HashMap;
+
+/// Calendar state
+#[derive(Debug, Clone)]
+pub struct CalendarState {
+    /// Day the cursor is on
+    pub selected: NaiveDate,
+    pub today: NaiveDate,
+    pub week_start: Weekday,
+    /// The tasks counted: those loaded where the calendar was opened
+    pub tasks: Vec<Task>,
+    counts: HashMap<NaiveDate, DayCount>,
+}
+
+impl CalendarState {
+    /// A calendar of `tasks` on today's month, with today selected
+    pub fn new(tasks: Vec<Task>, today: NaiveDate, week_start: Weekday) -> Self {
+        let counts = count_by_day(&tasks, today, &chrono::Local);
+        Self {
+            selected: today,
+            today,
+            week_start,
+            tasks,
+            counts,
+        }
+    }
+
+    /// The month the selected day is in
+    pub fn layout(&self) -> MonthLayout {
+        MonthLayout::new(self.selected, self.week_start)
+    }
+
+    pub fn count(&self, day: NaiveDate) -> DayCount {
+        self.counts.get(&day).copied().unwrap_or_default()
+    }
+
+    /// Move the selection by `days`, into the next or previous month as needed
+    pub fn move_days(&mut self, days: i64) {
+        let moved = if days >= 0 {
+            self.selected.checked_add_days(Days::new(days.unsigned_abs()))
+        } else {
+            self.selected.checked_sub_days(Days::new(days.unsigned_abs()))
+        };
+        if let Some(moved) = moved {
+            self.selected = moved;
+        }
+    }
+
+    pub fn move_months(&mut self, months: i32) {
+        self.selected = shift_months(self.selected, months);
+    }
+}
+
+impl Default for CalendarState {
+    fn default() -> Self {
+        let today = chrono::Local::now().date_naive();
+        Self::new(Vec::new(), today, Weekday::Mon)
+    }
+}
+
+/// "3 due · 2 overdue", empty for a day without tasks
+pub fn count_label(count: DayCount) -> String {
+    match (count.due, count.overdue) {
+        (0, 0) => String::new(),
+        (due, 0) => format!("{} due", due),
+        (0, overdue) => format!("{} overdue", overdue),
+        (due, overdue) => format!("{} due · {} overdue", due, overdue),
+    }
+}
+
+/// Each day of the shown month as "Tue 12: 3 due", today marked, with the
+/// index of the selected day
+pub fn day_descriptions(state: &CalendarState) -> (Vec<String>, Option<usize>) {
+    let layout = state.layout();
+    let days: Vec<NaiveDate> = layout.weeks.iter().flatten().flatten().copied().collect();
+    let lines = days
+        .iter()
+        .map(|day| {
+            let mut text = day.format("%a %-d").to_string();
+            let count = count_label(state.count(*day));
+            if !count.is_empty() {
+                text = format!("{}: {}", text, count);
+            }
+            if *day == state.today {
+                text.push_str(" (today)");
+            }
+            text
+        })
+        .collect();
+    let selected = days.iter().position(|day| *day == state.selected);
+    (lines, selected)
+}
+
+pub fn render_calendar(frame: &mut Frame, state: &CalendarState, area: Rect) {
+    let layout = state.layout();
+    let block = titled_block(format!(" 📅 {} ", layout.title()));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let columns = |row: Rect| {
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, 7); 7])
+            .split(row)
+    };
+
+    let label_style = Style::default()
+        .fg(Theme::TEXT_DIM)
+        .add_modifier(Modifier::BOLD);
+    for (label, cell) in layout.weekday_labels().iter().zip(columns(rows[0]).iter()) {
+        frame.render_widget(Paragraph::new(*label).style(label_style), *cell);
+    }
+
+    let weeks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Ratio(1, layout.weeks.len() as u32); layout.weeks.len()])
+        .split(rows[1]);
+    for (week, row) in layout.weeks.iter().zip(weeks.iter()) {
+        for (day, cell) in week.iter().zip(columns(*row).iter()) {
+            if let Some(day) = day {
+                render_day(frame, state, *day, *cell);
+            }
+        }
+    }
+
+    let summary = match count_label(state.count(state.selected)) {
+        label if label.is_empty() => "Nothing due".to_string(),
+        label => label,
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(
+                state.selected.format("%a %-d %B: ").to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(summary, Style::default().fg(Theme::TEXT_DIM)),
+        ])),
+        rows[2],
+    );
+}
+
+/// One day cell: the day number, then the counts (on the same line when
+/// the cell is a single line high)
+fn render_day(frame: &mut Frame, state: &CalendarState, day: NaiveDate, area: Rect) {
+    let count = state.count(day);
+    let mut number_style = Style::default().fg(Theme::TEXT);
+    if day == state.today {
+        number_style = number_style
+            .fg(Theme::PRIMARY)
+            .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    }
+    let number = Span::styled(day.format("%e").to_string(), number_style);
+
+    let mut counts = Vec::new();
+    if count.due > 0 {
+        counts.push(Span::styled(
+            format!(" {}", count.due),
+            Style::default().fg(Theme::WARNING),
+        ));
+    }
+    if count.overdue > 0 {
+        counts.push(Span::styled(
+            format!(" !{}", count.overdue),
+            Style::default().fg(Theme::ERROR).add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let lines = if area.height >= 2 {
+        vec![Line::from(number), Line::from(counts)]
+    } else {
+        let mut spans = vec![number];
+        spans.extend(counts);
+        vec![Line::from(spans)]
+    };
+    let mut paragraph = Paragraph::new(lines);
+    if day == state.selected {
+        paragraph = paragraph.style(
+            Style::default()
+                .bg(Theme::SECONDARY)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    frame.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_moving_crosses_month_boundaries() {
+        let mut state = CalendarState::new(Vec::new(), date(2024, 1, 31), Weekday::Mon);
+        state.move_days(1);
+        assert_eq!(state.selected, date(2024, 2, 1));
+        assert_eq!(state.layout().first, date(2024, 2, 1));
+        state.move_days(-7);
+        assert_eq!(state.selected, date(2024, 1, 25));
+
+        state.move_days(6);
+        state.move_months(1);
+        assert_eq!(state.selected, date(2024, 2, 29));
+        state.move_months(-2);
+        assert_eq!(state.selected, date(2023, 12, 29));
+    }
+
+    #[test]
+    fn test_day_descriptions_mark_today_and_selection() {
+        let mut state = CalendarState::new(Vec::new(), date(2024, 2, 14), Weekday::Sun);
+        state.move_days(1);
+        let (lines, selected) = day_descriptions(&state);
+        assert_eq!(lines.len(), 29);
+        assert_eq!(lines[0], "Thu 1");
+        assert_eq!(lines[13], "Wed 14 (today)");
+        assert_eq!(selected, Some(14));
+    }
+
+    #[test]
+    fn test_count_label() {
+        assert_eq!(count_label(DayCount::default()), "");
+        assert_eq!(count_label(DayCount { due: 2, overdue: 0 }), "2 due");
+        assert_eq!(count_label(DayCount { due: 0, overdue: 1 }), "1 overdue");
+        assert_eq!(
+            count_label(DayCount { due: 1, overdue: 3 }),
+            "1 due · 3 overdue"
+        );
+    }
+}
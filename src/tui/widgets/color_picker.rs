@@ -0,0 +1,219 @@
+//! Color picker for new tags
+//!
+//! A grid of the 16 terminal colors, two rows of eight, moved through with
+//! the arrow keys. Each cell is drawn in its `Color::Indexed` color; the
+//! picked color is sent to ClickUp as the hex value of the standard palette.
+
+use crate::tui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Hex values of the 16 standard terminal colors, by index
+const PALETTE: [&str; 16] = [
+    "#000000", "#800000", "#008000", "#808000", "#000080", "#800080", "#008080", "#c0c0c0",
+    "#808080", "#ff0000", "#00ff00", "#ffff00", "#0000ff", "#ff00ff", "#00ffff", "#ffffff",
+];
+
+/// Cells per row of the grid
+const COLUMNS: usize = 8;
+
+/// Width of a cell, in characters
+const CELL_WIDTH: u16 = 4;
+
+/// Which color is selected, and what it is for
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorPickerState {
+    /// Shown in the border, e.g. the name of the tag being created
+    pub title: String,
+    /// Index into the 16-color palette
+    pub selected: usize,
+}
+
+impl ColorPickerState {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            // Blue: readable on both light and dark themes
+            selected: 12,
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if !self.selected.is_multiple_of(COLUMNS) {
+            self.selected -= 1;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.selected % COLUMNS < COLUMNS - 1 {
+            self.selected += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected >= COLUMNS {
+            self.selected -= COLUMNS;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if self.selected + COLUMNS < PALETTE.len() {
+            self.selected += COLUMNS;
+        }
+    }
+
+    /// The selected color as a terminal color
+    pub fn color(&self) -> Color {
+        Color::Indexed(self.selected as u8)
+    }
+
+    /// The selected color as hex, for the tag background
+    pub fn bg_hex(&self) -> &'static str {
+        PALETTE[self.selected]
+    }
+
+    /// Black or white hex, whichever reads better on the selected color
+    pub fn fg_hex(&self) -> &'static str {
+        if is_light(self.bg_hex()) {
+            "#000000"
+        } else {
+            "#ffffff"
+        }
+    }
+}
+
+/// Whether a `#rrggbb` color is light enough to need dark text
+fn is_light(hex: &str) -> bool {
+    let channel = |i: usize| {
+        u8::from_str_radix(hex.get(i..i + 2).unwrap_or("00"), 16).unwrap_or(0) as u32
+    };
+    // Perceived brightness (ITU-R BT.601 weights), out of 255
+    (299 * channel(1) + 587 * channel(3) + 114 * channel(5)) / 1000 > 140
+}
+
+/// Render the picker as a centered overlay
+pub fn render_color_picker(frame: &mut Frame, state: &ColorPickerState, area: Rect) {
+    let width = (CELL_WIDTH * COLUMNS as u16 + 4).max(36).min(area.width);
+    let height = 8.min(area.height);
+    let picker_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, picker_area);
+    let block = Block::default()
+        .title(format!(" {} ", state.title))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Theme::BACKGROUND));
+    let inner = block.inner(picker_area);
+    frame.render_widget(block, picker_area);
+
+    let mut lines: Vec<Line> = PALETTE
+        .chunks(COLUMNS)
+        .enumerate()
+        .map(|(row, colors)| {
+            let cells = (0..colors.len()).map(|column| {
+                let index = row * COLUMNS + column;
+                // The selected cell is marked with brackets in a contrasting color
+                let (text, fg) = if index == state.selected {
+                    let fg = if is_light(PALETTE[index]) {
+                        Color::Black
+                    } else {
+                        Color::White
+                    };
+                    ("[  ]", fg)
+                } else {
+                    ("    ", Color::Reset)
+                };
+                Span::styled(
+                    text,
+                    Style::default()
+                        .bg(Color::Indexed(index as u8))
+                        .fg(fg)
+                        .add_modifier(Modifier::BOLD),
+                )
+            });
+            Line::from(cells.collect::<Vec<_>>())
+        })
+        .collect();
+    lines.push(Line::raw(""));
+    lines.push(Line::from(vec![
+        Span::styled("Color ", Style::default().fg(Theme::TEXT_DIM)),
+        Span::styled("  ", Style::default().bg(state.color())),
+        Span::styled(
+            format!(" {}", state.bg_hex()),
+            Style::default().fg(Theme::TEXT_DIM),
+        ),
+    ]));
+    lines.push(Line::from(Span::styled(
+        "Arrows: Move | Enter: Create | Esc: Cancel",
+        Style::default().fg(Theme::TEXT_DIM),
+    )));
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrows_move_within_the_grid() {
+        let mut picker = ColorPickerState::new("Tag");
+        picker.selected = 0;
+
+        picker.move_left();
+        picker.move_up();
+        assert_eq!(picker.selected, 0, "Edges don't wrap");
+
+        picker.move_right();
+        picker.move_down();
+        assert_eq!(picker.selected, 9);
+        assert_eq!(picker.color(), Color::Indexed(9));
+
+        picker.move_down();
+        assert_eq!(picker.selected, 9, "Bottom row stays put");
+
+        picker.selected = 7;
+        picker.move_right();
+        assert_eq!(picker.selected, 7, "Rows don't wrap into each other");
+    }
+
+    #[test]
+    fn test_foreground_contrasts_with_background() {
+        let mut picker = ColorPickerState::new("Tag");
+        picker.selected = 15;
+        assert_eq!((picker.bg_hex(), picker.fg_hex()), ("#ffffff", "#000000"));
+        picker.selected = 4;
+        assert_eq!((picker.bg_hex(), picker.fg_hex()), ("#000080", "#ffffff"));
+        picker.selected = 11;
+        assert_eq!(picker.fg_hex(), "#000000");
+    }
+
+    #[test]
+    fn test_render_draws_every_color() {
+        use ratatui::{backend::TestBackend, Terminal};
+        let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+        let picker = ColorPickerState::new("Color for urgent");
+
+        terminal
+            .draw(|frame| render_color_picker(frame, &picker, frame.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let backgrounds: std::collections::HashSet<Color> =
+            buffer.content().iter().map(|cell| cell.bg).collect();
+        for index in 0..16 {
+            assert!(backgrounds.contains(&Color::Indexed(index)), "color {}", index);
+        }
+        let text: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Color for urgent"));
+        assert!(text.contains("#0000ff"));
+    }
+}
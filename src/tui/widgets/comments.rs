@@ -3,7 +3,7 @@
 use crate::models::Comment;
 use crate::tui::app::CommentViewMode;
 use crate::tui::theme::Theme;
-use crate::utils::{format_timestamp, wrap_text};
+use crate::utils::{format_timestamp, truncate, wrap_text};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -11,8 +11,10 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use std::collections::HashSet;
 
 /// Render comments section with list of comments and optional form
+#[allow(clippy::too_many_arguments)]
 pub fn render_comments(
     frame: &mut Frame,
     comments: &[Comment],
@@ -22,20 +24,18 @@ pub fn render_comments(
     comment_focus: bool,
     area: Rect,
     view_mode: &CommentViewMode,
+    has_more: bool,
+    collapse: &CommentCollapse,
+    notice: Option<&str>,
 ) {
-    // Build title based on view mode
-    let title = match view_mode {
-        CommentViewMode::TopLevel => " Comments ".to_string(),
-        CommentViewMode::InThread { parent_author, .. } => {
-            // Truncate long names
-            let truncated = if parent_author.len() > 20 {
-                format!("{}...", &parent_author[..17])
-            } else {
-                parent_author.clone()
-            };
-            format!(" Comments > {} ", truncated)
-        }
-    };
+    let mut title = vec![Span::raw(comments_title(view_mode))];
+    if let Some(notice) = notice {
+        title.push(Span::styled(
+            format!("{} ", notice),
+            Style::default().fg(Theme::WARNING),
+        ));
+    }
+    let title = Line::from(title);
 
     // Check if area is too small to render
     if area.height < 5 || area.width < 20 {
@@ -91,6 +91,8 @@ pub fn render_comments(
         comment_focus,
         chunks[0],
         view_mode,
+        has_more,
+        collapse,
     );
 
     // Render input form if editing or creating
@@ -128,7 +130,51 @@ pub fn render_comments(
     }
 }
 
+/// Marker above the oldest loaded top-level comment when older pages exist
+pub const OLDER_COMMENTS_LABEL: &str = "· older comments — press L to load ·";
+
+/// Which long comments are shown in full
+///
+/// Comments that wrap to more than `max_lines` lines are cut to `max_lines`
+/// with a trailer, unless their id is in `expanded`. A `max_lines` of 0
+/// never collapses.
+#[derive(Debug, Clone, Default)]
+pub struct CommentCollapse {
+    pub max_lines: usize,
+    pub expanded: HashSet<String>,
+}
+
+impl CommentCollapse {
+    pub fn new(max_lines: usize) -> Self {
+        Self {
+            max_lines,
+            expanded: HashSet::new(),
+        }
+    }
+
+    /// Expand a collapsed comment, or collapse an expanded one
+    pub fn toggle(&mut self, comment_id: &str) {
+        if !self.expanded.remove(comment_id) {
+            self.expanded.insert(comment_id.to_string());
+        }
+    }
+
+    pub fn is_expanded(&self, comment_id: &str) -> bool {
+        self.expanded.contains(comment_id)
+    }
+
+    /// Wrapped lines to show for a comment, and how many were hidden
+    fn visible<'a>(&self, comment_id: &str, wrapped: &'a [String]) -> (&'a [String], usize) {
+        if self.max_lines == 0 || wrapped.len() <= self.max_lines || self.is_expanded(comment_id) {
+            (wrapped, 0)
+        } else {
+            (&wrapped[..self.max_lines], wrapped.len() - self.max_lines)
+        }
+    }
+}
+
 /// Render the list of comments with scrolling support
+#[allow(clippy::too_many_arguments)]
 fn render_comment_list(
     frame: &mut Frame,
     comments: &[Comment],
@@ -137,32 +183,92 @@ fn render_comment_list(
     comment_focus: bool,
     area: Rect,
     view_mode: &CommentViewMode,
+    has_more: bool,
+    collapse: &CommentCollapse,
 ) {
     // Check if area is too small
     if area.height < 3 || area.width < 15 {
         return;
     }
 
-    // Filter comments based on view mode
-    let filtered_comments: Vec<(usize, &Comment)> = match view_mode {
+    let order = filter_comments(view_mode, comments);
+
+    if order.is_empty() {
+        let empty_msg = match view_mode {
+            CommentViewMode::TopLevel => "No comments yet. Press 'n' to add one.",
+            CommentViewMode::InThread { .. } => "No replies yet. Press 'r' to reply.",
+        };
+        let paragraph = Paragraph::new(empty_msg).style(Style::default().fg(Theme::SECONDARY));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Calculate available width (accounting for borders)
+    let available_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for padding
+
+    let mut all_comment_lines = build_comment_lines(
+        comments,
+        &order,
+        selected_index,
+        editing_index,
+        comment_focus,
+        view_mode,
+        available_width,
+        collapse,
+    );
+
+    // Comments run oldest to newest, so older pages load in above the list
+    if has_more && matches!(view_mode, CommentViewMode::TopLevel) {
+        all_comment_lines.insert(
+            0,
+            (
+                usize::MAX,
+                Line::from(Span::styled(
+                    OLDER_COMMENTS_LABEL,
+                    Style::default()
+                        .fg(Theme::SECONDARY)
+                        .add_modifier(Modifier::ITALIC),
+                )),
+            ),
+        );
+    }
+
+    let total_lines = all_comment_lines.len();
+    let available_height = area.height as usize;
+    let scroll_offset =
+        compute_scroll(selected_index, &order, &all_comment_lines, available_height);
+
+    // Get visible lines
+    let visible_lines: Vec<Line> = all_comment_lines
+        .into_iter()
+        .skip(scroll_offset)
+        .take(available_height)
+        .map(|(_, line)| line)
+        .collect();
+
+    let comments_paragraph = Paragraph::new(visible_lines);
+    frame.render_widget(comments_paragraph, area);
+
+    // Render scroll indicator if content exceeds visible area
+    if total_lines > available_height {
+        crate::tui::layout::render_scroll_indicator(frame, area, total_lines, scroll_offset);
+    }
+}
+
+/// Indices of the comments shown in the given view mode, in display order
+///
+/// Top-level view shows comments without a parent. Thread view shows the
+/// parent comment first, followed by all of its replies.
+pub fn filter_comments(view_mode: &CommentViewMode, comments: &[Comment]) -> Vec<usize> {
+    match view_mode {
         CommentViewMode::TopLevel => {
-            // Show only top-level comments (no parent_id)
-            let result: Vec<(usize, &Comment)> = comments
+            let result: Vec<usize> = comments
                 .iter()
                 .enumerate()
-                .filter(|(_, c)| {
-                    let is_top_level = c.parent_id.is_none();
-                    tracing::debug!(
-                        "Comment {} ({}): parent_id={:?}, is_top_level={}",
-                        c.id,
-                        c.text.chars().take(20).collect::<String>(),
-                        c.parent_id,
-                        is_top_level
-                    );
-                    is_top_level
-                })
+                .filter(|(_, c)| c.parent_id.is_none())
+                .map(|(i, _)| i)
                 .collect();
-            tracing::info!(
+            tracing::debug!(
                 "TopLevel view: showing {} of {} comments",
                 result.len(),
                 comments.len()
@@ -172,100 +278,66 @@ fn render_comment_list(
         CommentViewMode::InThread {
             parent_comment_id, ..
         } => {
-            // Show parent comment first, then all replies
-            let mut result: Vec<(usize, &Comment)> = Vec::new();
-
-            // Find and add parent comment first
-            for (i, comment) in comments.iter().enumerate() {
-                if comment.id == *parent_comment_id {
-                    tracing::debug!("Found parent comment: {}", comment.id);
-                    result.push((i, comment));
-                    break;
-                }
-            }
-
-            // Add all replies (comments with this parent_id)
-            for (i, comment) in comments.iter().enumerate() {
-                if comment.parent_id.as_ref() == Some(parent_comment_id) {
-                    tracing::debug!(
-                        "Found reply: {} -> parent={}",
-                        comment.id,
-                        parent_comment_id
-                    );
-                    result.push((i, comment));
-                }
-            }
-
-            tracing::info!(
+            let parent = comments.iter().position(|c| c.id == *parent_comment_id);
+            let replies = comments
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.parent_id.as_ref() == Some(parent_comment_id))
+                .map(|(i, _)| i);
+            let result: Vec<usize> = parent.into_iter().chain(replies).collect();
+            tracing::debug!(
                 "InThread view: showing {} comments (1 parent + {} replies)",
                 result.len(),
                 result.len().saturating_sub(1)
             );
             result
         }
-    };
-
-    if filtered_comments.is_empty() {
-        let empty_msg = match view_mode {
-            CommentViewMode::TopLevel => "No comments yet. Press 'n' to add one.",
-            CommentViewMode::InThread { .. } => "No replies yet. Press 'r' to reply.",
-        };
-        let paragraph = Paragraph::new(empty_msg).style(Style::default().fg(Theme::SECONDARY));
-        frame.render_widget(paragraph, area);
-        return;
     }
+}
 
-    // Calculate available width (accounting for borders)
-    let available_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for padding
-
-    // Build all comment lines first to calculate total height
-    let mut all_comment_lines: Vec<(usize, Line)> = Vec::new(); // (original_index, line)
-    let mut display_indices: Vec<usize> = Vec::new(); // Maps display position to original index
-
-    // Pre-calculate reply counts for top-level comments (for task 3.3)
-    let reply_counts: std::collections::HashMap<&str, usize> =
-        if matches!(view_mode, CommentViewMode::TopLevel) {
-            let mut counts = std::collections::HashMap::new();
-            for comment in comments.iter() {
-                if let Some(parent_id) = &comment.parent_id {
-                    *counts.entry(parent_id.as_str()).or_insert(0) += 1;
-                }
+/// Build the styled, wrapped lines for the comments in `order`
+///
+/// Each line is tagged with the original index of the comment it belongs to.
+/// The comment being edited is left out, since it is shown in the input form.
+/// Collapsing is decided on the wrapped lines, so it follows the pane width.
+#[allow(clippy::too_many_arguments)]
+pub fn build_comment_lines(
+    comments: &[Comment],
+    order: &[usize],
+    selected_index: usize,
+    editing_index: Option<usize>,
+    comment_focus: bool,
+    view_mode: &CommentViewMode,
+    available_width: usize,
+    collapse: &CommentCollapse,
+) -> Vec<(usize, Line<'static>)> {
+    let in_thread = matches!(view_mode, CommentViewMode::InThread { .. });
+
+    // Reply counts for top-level comments
+    let mut reply_counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    if !in_thread {
+        for comment in comments {
+            if let Some(parent_id) = &comment.parent_id {
+                *reply_counts.entry(parent_id.as_str()).or_insert(0) += 1;
             }
-            counts
-        } else {
-            std::collections::HashMap::new()
-        };
-
-    // Track if we're rendering the parent comment in thread view (for task 3.4)
-    let mut is_first_comment_in_thread = true;
+        }
+    }
 
-    for (orig_idx, comment) in filtered_comments.iter() {
-        let is_editing = editing_index == Some(*orig_idx);
+    let mut lines = Vec::new();
 
+    for (position, &orig_idx) in order.iter().enumerate() {
         // Skip rendering if this comment is being edited
-        if is_editing {
+        if editing_index == Some(orig_idx) {
             continue;
         }
+        let comment = &comments[orig_idx];
 
-        // Track this comment's display position
-        display_indices.push(*orig_idx);
+        // In thread view, the parent comment is the first in display order
+        let is_parent_in_thread = in_thread && position == 0;
+        let is_selected = orig_idx == selected_index;
 
-        // In thread view, identify the parent comment (first comment in the filtered list)
-        let is_parent_in_thread =
-            matches!(view_mode, CommentViewMode::InThread { .. }) && is_first_comment_in_thread;
-        is_first_comment_in_thread = false;
-
-        // Format author and date
-        let author = comment
-            .commenter
-            .as_ref()
-            .map(|c| c.username.as_str())
-            .unwrap_or("Anonymous");
-
-        let date_str = comment
-            .created_at
-            .map(|ts| format_timestamp(ts))
-            .unwrap_or_else(|| "Unknown date".to_string());
+        let author = comment_author(comment);
+        let date_str = comment_date(comment);
 
         let edited = if comment.updated_at.is_some() && comment.updated_at != comment.created_at {
             " (edited)"
@@ -273,12 +345,8 @@ fn render_comment_list(
             ""
         };
 
-        // Check if this comment is selected by comparing with the stored selected_index
-        let is_selected = *orig_idx == selected_index;
-
-        // Task 3.4: Parent comment in thread view gets distinct styling
+        // Parent comment in thread view gets distinct styling
         let header_style = if is_parent_in_thread {
-            // Parent comment: bold white with underline
             Style::default()
                 .fg(Theme::TEXT)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
@@ -290,29 +358,36 @@ fn render_comment_list(
             Style::default().fg(Theme::PRIMARY)
         };
 
-        // Build header line with optional reply count (task 3.3)
         let mut header_spans = vec![
             Span::styled(format!("{} - {}", author, date_str), header_style),
             Span::styled(edited, Style::default().fg(Theme::SECONDARY)),
         ];
 
-        // Add reply count indicator for top-level comments with replies
-        if matches!(view_mode, CommentViewMode::TopLevel) {
-            if let Some(reply_count) = reply_counts.get(comment.id.as_str()) {
-                if *reply_count > 0 {
-                    header_spans.push(Span::styled(
-                        format!(
-                            " • {} repl{}",
-                            reply_count,
-                            if *reply_count == 1 { "y" } else { "ies" }
-                        ),
-                        Style::default().fg(Theme::SECONDARY),
-                    ));
-                }
+        // Reply count indicator for top-level comments with replies; replies are
+        // fetched when the thread is opened, so fall back to the API's count
+        if !in_thread {
+            let loaded = reply_counts.get(comment.id.as_str()).copied().unwrap_or(0);
+            let reported = comment.reply_count.unwrap_or(0).max(0) as usize;
+            let reply_count = loaded.max(reported);
+            if reply_count > 0 {
+                header_spans.push(Span::styled(
+                    format!(
+                        " • {} repl{}",
+                        reply_count,
+                        if reply_count == 1 { "y" } else { "ies" }
+                    ),
+                    Style::default().fg(Theme::SECONDARY),
+                ));
             }
         }
 
-        // Task 3.4: Add "Parent comment" label for parent in thread view
+        if let Some(assignee) = &comment.assigned_commenter {
+            header_spans.push(Span::styled(
+                format!(" • assigned to {}", assignee.username),
+                Style::default().fg(Theme::SUCCESS),
+            ));
+        }
+
         if is_parent_in_thread {
             header_spans.push(Span::styled(
                 " • Parent comment",
@@ -322,11 +397,8 @@ fn render_comment_list(
             ));
         }
 
-        let header = Line::from(header_spans);
-        all_comment_lines.push((*orig_idx, header));
+        lines.push((orig_idx, Line::from(header_spans)));
 
-        // Content lines with wrapping
-        // Task 3.4: Parent comment gets distinct background
         let content_style = if is_parent_in_thread {
             Style::default().add_modifier(Modifier::BOLD)
         } else if is_selected && comment_focus {
@@ -335,74 +407,368 @@ fn render_comment_list(
             Style::default()
         };
 
-        // Task 3.6: Add visual thread indicator for replies (not parent)
-        let is_reply_in_thread =
-            matches!(view_mode, CommentViewMode::InThread { .. }) && !is_parent_in_thread;
-
-        // Wrap text to fit available width
-        let wrapped_content = wrap_text(&comment.text, available_width);
-        for line in wrapped_content {
-            if is_reply_in_thread {
-                // Add vertical line indicator for replies (thread line)
-                let indented_line = format!("│ {}", line);
-                all_comment_lines.push((
-                    *orig_idx,
-                    Line::from(Span::styled(indented_line, content_style)),
-                ));
-            } else {
-                all_comment_lines.push((*orig_idx, Line::from(Span::styled(line, content_style))));
-            }
+        // Replies in thread view get a vertical thread indicator
+        let is_reply_in_thread = in_thread && !is_parent_in_thread;
+
+        let prefix = if is_reply_in_thread { "│ " } else { "" };
+        let wrapped = wrap_text(&comment.text, available_width);
+        let (shown, hidden) = collapse.visible(&comment.id, &wrapped);
+        for line in shown {
+            lines.push((
+                orig_idx,
+                Line::from(Span::styled(format!("{}{}", prefix, line), content_style)),
+            ));
+        }
+        if hidden > 0 {
+            lines.push((
+                orig_idx,
+                Line::from(Span::styled(
+                    format!(
+                        "{}… ({} more line{} — press space to expand)",
+                        prefix,
+                        hidden,
+                        if hidden == 1 { "" } else { "s" }
+                    ),
+                    Style::default().fg(Theme::SECONDARY),
+                )),
+            ));
         }
 
         // Add spacing between comments
-        all_comment_lines.push((*orig_idx, Line::from("")));
+        lines.push((orig_idx, Line::from("")));
     }
 
-    let total_lines = all_comment_lines.len();
-    let available_height = area.height as usize;
+    lines
+}
 
-    // Calculate visible range based on scroll position
-    // For simplicity, we'll scroll by comment index, not line index
-    let mut scroll_offset = 0;
+/// Pane title, naming the thread's author (cut to 20 characters) in a thread
+fn comments_title(view_mode: &CommentViewMode) -> String {
+    match view_mode {
+        CommentViewMode::TopLevel => " Comments ".to_string(),
+        CommentViewMode::InThread { parent_author, .. } => {
+            format!(" Comments > {} ", truncate(parent_author, 20))
+        }
+    }
+}
 
-    // Find the line index where the selected comment starts
-    let selected_line_start = all_comment_lines
-        .iter()
-        .position(|(idx, _)| *idx == selected_index)
-        .unwrap_or(0);
+/// The comment's author as shown, "Anonymous" when unknown
+pub(crate) fn comment_author(comment: &Comment) -> String {
+    comment
+        .commenter
+        .as_ref()
+        .map(|c| c.username.clone())
+        .unwrap_or_else(|| "Anonymous".to_string())
+}
 
-    // Calculate visible range
-    let visible_start = scroll_offset;
-    let visible_end = (scroll_offset + available_height).min(total_lines);
+/// When the comment was posted, as shown: relative for the past week
+pub(crate) fn comment_date(comment: &Comment) -> String {
+    let now = chrono::Utc::now().timestamp_millis();
+    comment
+        .created_at
+        .map(|ts| format_relative_time(ts, now))
+        .unwrap_or_else(|| "Unknown date".to_string())
+}
 
-    // Auto-scroll: adjust scroll_offset if selected comment is outside visible range
-    if selected_line_start < visible_start {
-        scroll_offset = selected_line_start;
-    } else if selected_line_start >= visible_end {
-        scroll_offset = (selected_line_start + 1).saturating_sub(available_height);
+/// Describe the time `ts` relative to `now` (both in ms), e.g. "2 hours ago"
+///
+/// Times more than a week ago, or more than a minute ahead (a skewed clock),
+/// are shown as absolute dates instead.
+pub fn format_relative_time(ts: i64, now: i64) -> String {
+    const MINUTE: i64 = 60 * 1000;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let elapsed = now - ts;
+    let plural = |count: i64, unit: &str| {
+        format!("{} {}{} ago", count, unit, if count == 1 { "" } else { "s" })
+    };
+    match elapsed {
+        e if !(-MINUTE..7 * DAY).contains(&e) => format_timestamp(ts),
+        e if e < MINUTE => "just now".to_string(),
+        e if e < HOUR => plural(e / MINUTE, "minute"),
+        e if e < DAY => plural(e / HOUR, "hour"),
+        e if e < 2 * DAY => "yesterday".to_string(),
+        e => plural(e / DAY, "day"),
     }
+}
 
-    // Get visible lines
-    let visible_lines: Vec<Line> = all_comment_lines
+/// Scroll offset that keeps the selected comment visible
+///
+/// When the selected comment has no lines (it is being edited), the view is
+/// anchored to the next comment in display order, which is where the edited
+/// comment would have been.
+pub fn compute_scroll(
+    selected_index: usize,
+    order: &[usize],
+    lines: &[(usize, Line)],
+    height: usize,
+) -> usize {
+    let start = order
         .iter()
-        .skip(scroll_offset)
-        .take(available_height)
-        .map(|(_, line)| line.clone())
-        .collect();
+        .position(|&idx| idx == selected_index)
+        .unwrap_or(0);
 
-    let comments_paragraph = Paragraph::new(visible_lines);
-    frame.render_widget(comments_paragraph, area);
+    let anchor = order[start.min(order.len())..]
+        .iter()
+        .find_map(|&idx| lines.iter().position(|(line_idx, _)| *line_idx == idx))
+        .unwrap_or(lines.len().saturating_sub(1));
 
-    // Render scroll indicator if content exceeds visible area
-    if total_lines > available_height {
-        crate::tui::layout::render_scroll_indicator(frame, area, total_lines, scroll_offset);
-    }
+    // Scroll just enough for the anchor line to be the last visible line
+    (anchor + 1).saturating_sub(height)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn comment(id: &str, text: &str, parent_id: Option<&str>) -> Comment {
+        Comment {
+            id: id.to_string(),
+            text: text.to_string(),
+            text_preview: String::new(),
+            commenter: None,
+            created_at: None,
+            updated_at: None,
+            assigned_commenter: None,
+            assigned_by: None,
+            assigned: false,
+            reaction: String::new(),
+            parent_id: parent_id.map(str::to_string),
+            reply_count: None,
+        }
+    }
+
+    fn thread_mode(parent_id: &str) -> CommentViewMode {
+        CommentViewMode::InThread {
+            parent_comment_id: parent_id.to_string(),
+            parent_author: "Alice".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_thread_title_truncates_multibyte_author() {
+        let view_mode = CommentViewMode::InThread {
+            parent_comment_id: "c1".to_string(),
+            parent_author: "Zoë Ångström-Þórsdóttir".to_string(),
+        };
+        assert_eq!(comments_title(&view_mode), " Comments > Zoë Ångström-Þórs... ");
+        assert_eq!(comments_title(&thread_mode("c1")), " Comments > Alice ");
+
+        // The cut lands right after a four-byte emoji
+        let view_mode = CommentViewMode::InThread {
+            parent_comment_id: "c1".to_string(),
+            parent_author: "Jean-François 🎉🎉🎉 Dupont".to_string(),
+        };
+        assert_eq!(comments_title(&view_mode), " Comments > Jean-François 🎉🎉🎉... ");
+    }
+
+    #[test]
+    fn test_filter_comments_top_level() {
+        let comments = vec![
+            comment("a", "one", None),
+            comment("b", "reply", Some("a")),
+            comment("c", "two", None),
+        ];
+        assert_eq!(
+            filter_comments(&CommentViewMode::TopLevel, &comments),
+            vec![0, 2]
+        );
+    }
+
+    #[test]
+    fn test_filter_comments_thread_puts_parent_first() {
+        let comments = vec![
+            comment("r1", "reply", Some("p")),
+            comment("x", "other", None),
+            comment("p", "parent", None),
+            comment("r2", "reply", Some("p")),
+        ];
+        assert_eq!(filter_comments(&thread_mode("p"), &comments), vec![2, 0, 3]);
+    }
+
+    #[test]
+    fn test_build_comment_lines_counts_replies_and_skips_edited() {
+        let comments = vec![
+            comment("a", "one", None),
+            comment("b", "reply", Some("a")),
+            comment("c", "two", None),
+        ];
+        let order = vec![0, 2];
+
+        let lines = build_comment_lines(
+            &comments,
+            &order,
+            0,
+            Some(2),
+            true,
+            &CommentViewMode::TopLevel,
+            40,
+            &CommentCollapse::default(),
+        );
+
+        // Header, one content line and a spacer for comment "a" only
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|(idx, _)| *idx == 0));
+        assert!(lines[0].1.to_string().contains("1 reply"));
+    }
+
+    #[test]
+    fn test_build_comment_lines_marks_assigned_comments() {
+        let mut assigned = comment("a", "one", None);
+        assigned.assigned_commenter = Some(crate::models::User {
+            id: 7,
+            username: "Bob".to_string(),
+            email: None,
+            color: None,
+            profile_picture: None,
+            initials: None,
+        });
+        let comments = vec![assigned, comment("b", "two", None)];
+
+        let lines = build_comment_lines(
+            &comments,
+            &[0, 1],
+            0,
+            None,
+            false,
+            &CommentViewMode::TopLevel,
+            40,
+            &CommentCollapse::default(),
+        );
+
+        let headers: Vec<String> = lines
+            .iter()
+            .filter(|(_, line)| line.to_string().contains(" - "))
+            .map(|(_, line)| line.to_string())
+            .collect();
+        assert!(headers[0].ends_with(" • assigned to Bob"), "{}", headers[0]);
+        assert!(!headers[1].contains("assigned"), "{}", headers[1]);
+    }
+
+    #[test]
+    fn test_build_comment_lines_marks_thread_replies() {
+        let comments = vec![
+            comment("p", "parent", None),
+            comment("r", "reply", Some("p")),
+        ];
+
+        let lines = build_comment_lines(
+            &comments,
+            &[0, 1],
+            0,
+            None,
+            true,
+            &thread_mode("p"),
+            40,
+            &CommentCollapse::default(),
+        );
+
+        assert!(lines[0].1.to_string().contains("Parent comment"));
+        assert_eq!(lines[4].1.to_string(), "│ reply");
+    }
+
+    fn content_lines(text: &str, collapse: &CommentCollapse) -> Vec<String> {
+        let comments = vec![comment("a", text, None)];
+        let lines = build_comment_lines(
+            &comments,
+            &[0],
+            0,
+            None,
+            false,
+            &CommentViewMode::TopLevel,
+            40,
+            collapse,
+        );
+        // Drop the header and the trailing spacer
+        lines[1..lines.len() - 1]
+            .iter()
+            .map(|(_, line)| line.to_string())
+            .collect()
+    }
+
+    /// Text that wraps to exactly `count` lines at width 40
+    fn numbered_lines(count: usize) -> String {
+        (1..=count)
+            .map(|i| format!("line-{:0>25}", i))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_comment_of_exactly_max_lines_is_not_collapsed() {
+        let lines = content_lines(&numbered_lines(8), &CommentCollapse::new(8));
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[7], format!("line-{:0>25}", 8));
+    }
+
+    #[test]
+    fn test_comment_over_max_lines_is_collapsed_with_trailer() {
+        let lines = content_lines(&numbered_lines(9), &CommentCollapse::new(8));
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[7], format!("line-{:0>25}", 8));
+        assert_eq!(lines[8], "… (1 more line — press space to expand)");
+
+        let lines = content_lines(&numbered_lines(40), &CommentCollapse::new(8));
+        assert_eq!(lines[8], "… (32 more lines — press space to expand)");
+    }
+
+    #[test]
+    fn test_collapse_counts_wrapped_lines() {
+        // One long paragraph that wraps past the limit at width 40
+        let text = "word ".repeat(60);
+        let lines = content_lines(&text, &CommentCollapse::new(3));
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].starts_with("… ("), "{:?}", lines);
+    }
+
+    #[test]
+    fn test_expanded_comment_shows_all_lines() {
+        let mut collapse = CommentCollapse::new(8);
+        collapse.toggle("a");
+        assert_eq!(content_lines(&numbered_lines(20), &collapse).len(), 20);
+
+        collapse.toggle("a");
+        assert!(!collapse.is_expanded("a"));
+        assert_eq!(content_lines(&numbered_lines(20), &collapse).len(), 9);
+    }
+
+    #[test]
+    fn test_compute_scroll_keeps_selection_visible() {
+        let lines: Vec<(usize, Line)> = (0..5)
+            .flat_map(|idx| (0..3).map(move |_| (idx, Line::from(""))))
+            .collect();
+        let order = vec![0, 1, 2, 3, 4];
+
+        assert_eq!(compute_scroll(0, &order, &lines, 6), 0);
+        // Comment 4 starts at line 12; it must end up as the last visible line
+        assert_eq!(compute_scroll(4, &order, &lines, 6), 7);
+    }
+
+    #[test]
+    fn test_compute_scroll_anchors_edited_comment_in_place() {
+        let comments: Vec<Comment> = (0..6)
+            .map(|i| comment(&format!("c{}", i), "text", None))
+            .collect();
+        let order = filter_comments(&CommentViewMode::TopLevel, &comments);
+
+        // Comment 4 is selected and being edited, so it produces no lines
+        let lines = build_comment_lines(
+            &comments,
+            &order,
+            4,
+            Some(4),
+            true,
+            &CommentViewMode::TopLevel,
+            40,
+            &CommentCollapse::default(),
+        );
+        assert!(lines.iter().all(|(idx, _)| *idx != 4));
+
+        // The view should stay where comment 4 was (comment 5 starts at line 12),
+        // not jump back to the top of the list
+        assert_eq!(compute_scroll(4, &order, &lines, 6), 7);
+    }
+
     #[test]
     fn test_wrap_text() {
         let text = "This is a test comment with multiple words";
@@ -421,6 +787,50 @@ mod tests {
         assert!(wrapped[0].is_empty());
     }
 
+    const NOW: i64 = 1_700_000_000_000;
+    const MINUTE: i64 = 60 * 1000;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    #[test]
+    fn test_format_relative_time_same_day() {
+        assert_eq!(format_relative_time(NOW, NOW), "just now");
+        assert_eq!(format_relative_time(NOW - 59 * 1000, NOW), "just now");
+        assert_eq!(format_relative_time(NOW - MINUTE, NOW), "1 minute ago");
+        assert_eq!(format_relative_time(NOW - 59 * MINUTE, NOW), "59 minutes ago");
+        assert_eq!(format_relative_time(NOW - HOUR, NOW), "1 hour ago");
+        assert_eq!(format_relative_time(NOW - 2 * HOUR - 5 * MINUTE, NOW), "2 hours ago");
+        assert_eq!(format_relative_time(NOW - DAY + 1, NOW), "23 hours ago");
+    }
+
+    #[test]
+    fn test_format_relative_time_yesterday() {
+        assert_eq!(format_relative_time(NOW - DAY, NOW), "yesterday");
+        assert_eq!(format_relative_time(NOW - 2 * DAY + 1, NOW), "yesterday");
+        assert_eq!(format_relative_time(NOW - 2 * DAY, NOW), "2 days ago");
+    }
+
+    #[test]
+    fn test_format_relative_time_last_week_turns_absolute() {
+        assert_eq!(format_relative_time(NOW - 6 * DAY, NOW), "6 days ago");
+        let week_ago = NOW - 7 * DAY;
+        assert_eq!(format_relative_time(week_ago, NOW), format_timestamp(week_ago));
+    }
+
+    #[test]
+    fn test_format_relative_time_over_a_year_ago() {
+        let year_ago = NOW - 400 * DAY;
+        assert_eq!(format_relative_time(year_ago, NOW), format_timestamp(year_ago));
+    }
+
+    #[test]
+    fn test_format_relative_time_future() {
+        // A little clock skew still reads as just posted
+        assert_eq!(format_relative_time(NOW + 30 * 1000, NOW), "just now");
+        let later = NOW + HOUR;
+        assert_eq!(format_relative_time(later, NOW), format_timestamp(later));
+    }
+
     #[test]
     fn test_format_timestamp() {
         // Test with a known timestamp: 1234567890000 ms = Feb 13, 2009
@@ -11,17 +11,86 @@ use ratatui::{
 };
 
 /// Dialog types
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum DialogType {
     ConfirmDelete,
     ConfirmQuit,
+    /// Quit while writes made offline are still queued
+    ConfirmQuitWithQueued {
+        queued: usize,
+    },
+    /// Delete all marked tasks; holds their names for the prompt
+    ConfirmBulkDelete {
+        task_names: Vec<String>,
+    },
+    /// Archive the task `task_id`, or unarchive it when `archive` is false
+    ConfirmArchive {
+        task_id: String,
+        task_name: String,
+        archive: bool,
+    },
+    /// Delete the comment `comment_id`; `preview` is its text, cut short
+    ConfirmCommentDelete {
+        comment_id: String,
+        preview: String,
+    },
+    /// Remove the open task's link to `link_id`, described by `label`
+    ConfirmRemoveLink {
+        task_id: String,
+        link_id: String,
+        label: String,
+    },
 }
 
+/// Task names listed in the bulk delete prompt before "…and N more"
+const BULK_DELETE_NAMES_SHOWN: usize = 5;
+
 impl DialogType {
-    pub fn message(&self) -> &str {
+    pub fn message(&self) -> String {
         match self {
-            DialogType::ConfirmDelete => "Are you sure you want to delete this task?",
-            DialogType::ConfirmQuit => "Are you sure you want to quit?",
+            DialogType::ConfirmDelete => "Delete this task? This cannot be undone.".to_string(),
+            DialogType::ConfirmQuit => "Are you sure you want to quit?".to_string(),
+            DialogType::ConfirmQuitWithQueued { queued } => format!(
+                "{} change(s) made offline are still queued.\n\
+                 Run `clickdown debug drain-queue` once back online.\n\n\
+                 Quit anyway?",
+                queued
+            ),
+            DialogType::ConfirmArchive {
+                task_name, archive, ..
+            } => {
+                if *archive {
+                    format!(
+                        "Archive \"{}\"?\n\nIt is hidden from lists until unarchived.",
+                        task_name
+                    )
+                } else {
+                    format!("Unarchive \"{}\"?", task_name)
+                }
+            }
+            DialogType::ConfirmCommentDelete { preview, .. } => {
+                format!("Delete this comment?\n\n\"{}\"", preview)
+            }
+            DialogType::ConfirmRemoveLink { label, .. } => {
+                format!("Remove the link to {}?", label)
+            }
+            DialogType::ConfirmBulkDelete { task_names } => {
+                let mut message = format!(
+                    "Delete {} tasks? This cannot be undone.\n",
+                    task_names.len()
+                );
+                for name in task_names.iter().take(BULK_DELETE_NAMES_SHOWN) {
+                    message.push_str(&format!("\n• {}", name));
+                }
+                if task_names.len() > BULK_DELETE_NAMES_SHOWN {
+                    message.push_str(&format!(
+                        "\n…and {} more",
+                        task_names.len() - BULK_DELETE_NAMES_SHOWN
+                    ));
+                }
+                message
+            }
         }
     }
 }
@@ -80,8 +149,12 @@ pub fn render_dialog(frame: &mut Frame, state: &DialogState, area: Rect) {
         None => return,
     };
 
-    // Center the dialog
-    let dialog_area = centered_rect(50, 30, area);
+    // Center the dialog, leaving room for the task names of a bulk delete
+    let height = match dialog_type {
+        DialogType::ConfirmBulkDelete { .. } => 50,
+        _ => 30,
+    };
+    let dialog_area = centered_rect(50, height, area);
 
     frame.render_widget(Clear, dialog_area);
 
@@ -132,3 +205,19 @@ pub fn render_dialog(frame: &mut Frame, state: &DialogState, area: Rect) {
 pub fn get_dialog_hints() -> &'static str {
     "←/→: Select | Enter: Confirm | Esc: Cancel"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_delete_message_lists_count_and_names() {
+        let task_names: Vec<String> = (1..=7).map(|i| format!("Task {}", i)).collect();
+        let message = DialogType::ConfirmBulkDelete { task_names }.message();
+
+        assert!(message.starts_with("Delete 7 tasks? This cannot be undone."));
+        assert!(message.contains("• Task 5"));
+        assert!(!message.contains("• Task 6"));
+        assert!(message.ends_with("…and 2 more"));
+    }
+}
@@ -45,7 +45,7 @@ impl Default for DocumentState {
 }
 
 /// Simple Markdown to plain text conversion for terminal
-fn markdown_to_text(md: &str) -> String {
+pub(crate) fn markdown_to_text(md: &str) -> String {
     let parser = Parser::new(md);
     let mut result = String::new();
 
@@ -0,0 +1,123 @@
+//! Document browser widget: a search input above the matching docs
+
+use crate::models::Document;
+use crate::tui::debounce::Debounce;
+use crate::tui::helpers::SelectableList;
+use crate::tui::theme::Theme;
+use crate::utils::format_timestamp;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Document browser state
+#[derive(Debug, Clone)]
+pub struct DocumentsState {
+    pub docs: SelectableList<Document>,
+    /// Text typed into the search input
+    pub query: String,
+    /// Cursor in `query`, in characters
+    pub cursor: usize,
+    /// Re-runs the search once typing pauses
+    pub search: Debounce,
+    /// Bumped for every search so results of superseded ones are dropped
+    pub generation: u64,
+    pub loading: bool,
+    /// Space the search is limited to; None searches the whole workspace
+    pub space_id: Option<String>,
+}
+
+impl DocumentsState {
+    pub fn new() -> Self {
+        Self {
+            docs: SelectableList::empty(),
+            query: String::new(),
+            cursor: 0,
+            search: Debounce::default(),
+            generation: 0,
+            loading: false,
+            space_id: None,
+        }
+    }
+
+    /// What the results panel says while it has no docs
+    pub fn empty_message(&self) -> &'static str {
+        if self.loading {
+            "Searching..."
+        } else {
+            "No documents found"
+        }
+    }
+
+    /// Replace the results and select the first doc
+    pub fn set_docs(&mut self, docs: Vec<Document>) {
+        *self.docs.items_mut() = docs;
+        self.docs.select_first();
+    }
+}
+
+impl Default for DocumentsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// When a doc was last updated, for the results list
+pub fn updated_text(doc: &Document) -> String {
+    doc.updated_at
+        .map(format_timestamp)
+        .unwrap_or_else(|| "never updated".to_string())
+}
+
+pub fn render_documents(frame: &mut Frame, state: &DocumentsState, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+    let (input_area, list_area) = (chunks[0], chunks[1]);
+
+    let input = Paragraph::new(Line::from(crate::tui::layout::input_spans(
+        &state.query,
+        state.cursor,
+    )))
+    .block(crate::tui::layout::titled_block(" Search "));
+    frame.render_widget(input, input_area);
+
+    let block = crate::tui::layout::titled_block(" Documents ");
+    if state.docs.items().is_empty() {
+        let inner = block.inner(list_area);
+        frame.render_widget(block, list_area);
+        let empty = Paragraph::new(state.empty_message()).style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(empty, inner);
+        return;
+    }
+
+    let items: Vec<ListItem> = state
+        .docs
+        .items()
+        .iter()
+        .map(|doc| {
+            ListItem::new(Line::from(vec![
+                Span::raw(doc.name.as_str()),
+                Span::styled(
+                    format!("  · {}", updated_text(doc)),
+                    Style::default().fg(Theme::TEXT_DIM),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .bg(Theme::SECONDARY)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ");
+
+    frame.render_stateful_widget(list, list_area, &mut state.docs.state().clone());
+}
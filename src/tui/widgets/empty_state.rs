@@ -0,0 +1,197 @@
+//! Panels shown in place of an empty collection
+//!
+//! Whether a collection is still loading, failed to load or is really empty
+//! can't be told from an empty vec, so the app records a [`LoadStatus`] for
+//! each one and [`EmptyState::new`] words the panel from it.
+
+use crate::tui::layout::titled_block;
+use crate::tui::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+
+/// A collection the app loads and shows as a list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Collection {
+    Workspaces,
+    Spaces,
+    /// The folders and folderless lists of a space
+    SpaceContents,
+    /// The lists of a folder
+    Lists,
+    Tasks,
+}
+
+impl Collection {
+    /// Plural noun for messages, e.g. "lists"
+    fn noun(self) -> &'static str {
+        match self {
+            Self::Workspaces => "workspaces",
+            Self::Spaces => "spaces",
+            Self::SpaceContents => "folders",
+            Self::Lists => "lists",
+            Self::Tasks => "tasks",
+        }
+    }
+
+    /// Title of the panel's border
+    fn title(self) -> &'static str {
+        match self {
+            Self::Workspaces => " Workspaces ",
+            Self::Spaces => " Spaces ",
+            Self::SpaceContents => " Folders ",
+            Self::Lists => " Lists ",
+            Self::Tasks => " Tasks ",
+        }
+    }
+
+    /// How to leave the screen: the workspaces are the top level
+    fn leave_hint(self) -> &'static str {
+        match self {
+            Self::Workspaces => "Ctrl+Q to quit",
+            _ => "Esc to go back",
+        }
+    }
+}
+
+/// Where the latest load of a collection got to
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LoadStatus {
+    /// Never requested
+    #[default]
+    NotStarted,
+    Loading,
+    Loaded,
+    /// The load failed with this error
+    Failed(String),
+}
+
+/// What an empty panel says: what's empty, why, and what to press next
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmptyState {
+    pub collection: Collection,
+    pub headline: String,
+    pub reason: Option<String>,
+    pub hint: String,
+    /// Drawn as an error rather than as an ordinary notice
+    pub failed: bool,
+}
+
+impl EmptyState {
+    /// The panel for an empty `collection`; None before its first load
+    pub fn new(collection: Collection, status: &LoadStatus) -> Option<Self> {
+        let noun = collection.noun();
+        let leave = collection.leave_hint();
+        let (headline, reason, hint) = match status {
+            LoadStatus::NotStarted => return None,
+            LoadStatus::Loading => (format!("Loading {}...", noun), None, format!("Press {}", leave)),
+            LoadStatus::Failed(error) => (
+                format!("Couldn't load {}", noun),
+                Some(error.lines().next().unwrap_or_default().to_string()),
+                format!("Press ! for details · {}", leave),
+            ),
+            LoadStatus::Loaded => {
+                let (headline, reason, hint) = match collection {
+                    Collection::Workspaces => (
+                        "No workspaces",
+                        "This account isn't a member of any workspace",
+                        "Join one in ClickUp, then restart · Ctrl+Q to quit",
+                    ),
+                    Collection::Spaces => (
+                        "No spaces",
+                        "This workspace has no spaces",
+                        "Press n to create one · Esc to go back",
+                    ),
+                    Collection::SpaceContents => (
+                        "No folders or lists",
+                        "This space has no folders or lists",
+                        "Press Esc to go back",
+                    ),
+                    Collection::Lists => (
+                        "No lists",
+                        "This folder has no lists",
+                        "Press Esc to go back",
+                    ),
+                    Collection::Tasks => (
+                        "No tasks",
+                        "This list has no tasks",
+                        "Press n to create one · Esc to go back",
+                    ),
+                };
+                (headline.to_string(), Some(reason.to_string()), hint.to_string())
+            }
+        };
+        Some(Self {
+            collection,
+            headline,
+            reason,
+            hint,
+            failed: matches!(status, LoadStatus::Failed(_)),
+        })
+    }
+}
+
+/// Render the panel, its text centered in `area`
+pub fn render_empty_state(frame: &mut Frame, state: &EmptyState, area: Rect) {
+    let block = titled_block(state.collection.title());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let headline_color = if state.failed { Theme::ERROR } else { Theme::TEXT };
+    let mut lines = vec![Line::styled(
+        state.headline.clone(),
+        Style::default().fg(headline_color).add_modifier(Modifier::BOLD),
+    )];
+    if let Some(reason) = &state.reason {
+        lines.push(Line::styled(reason.clone(), Style::default().fg(Theme::TEXT_DIM)));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::styled(state.hint.clone(), Style::default().fg(Theme::PRIMARY)));
+
+    // Vertically centered, as far as the wrapped text allows
+    let height = (lines.len() as u16).min(inner.height);
+    let text_area = Rect {
+        y: inner.y + inner.height.saturating_sub(height) / 2,
+        height: inner.height.saturating_sub(inner.height.saturating_sub(height) / 2),
+        ..inner
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        text_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_panel_before_the_first_load() {
+        assert_eq!(EmptyState::new(Collection::Lists, &LoadStatus::NotStarted), None);
+    }
+
+    #[test]
+    fn test_loaded_failed_and_loading_are_told_apart() {
+        let loaded = EmptyState::new(Collection::Lists, &LoadStatus::Loaded).unwrap();
+        assert_eq!(loaded.reason.as_deref(), Some("This folder has no lists"));
+        assert_eq!(loaded.hint, "Press Esc to go back");
+
+        let failed = EmptyState::new(
+            Collection::Lists,
+            &LoadStatus::Failed("API error (500): boom\ncaused by".to_string()),
+        )
+        .unwrap();
+        assert_eq!(failed.headline, "Couldn't load lists");
+        assert_eq!(failed.reason.as_deref(), Some("API error (500): boom"));
+
+        let loading = EmptyState::new(Collection::Workspaces, &LoadStatus::Loading).unwrap();
+        assert_eq!(loading.headline, "Loading workspaces...");
+        assert_eq!(loading.hint, "Press Ctrl+Q to quit");
+    }
+}
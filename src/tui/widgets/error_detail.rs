@@ -0,0 +1,193 @@
+//! Error detail overlay
+//!
+//! The status bar only has room for one line of an error. This overlay shows
+//! the whole text and, for API errors, pulls out the HTTP status and response body.
+
+use crate::tui::layout::centered_rect;
+use crate::tui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Longest error shown in the status bar before it is cut short
+pub const STATUS_ERROR_MAX_CHARS: usize = 100;
+
+/// Kind of failure, recognised from the error text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The API answered with a non-success status
+    Api,
+    /// The response body did not match the expected shape
+    Parse,
+    /// The request never got a response
+    Network,
+}
+
+impl ErrorKind {
+    fn label(self) -> &'static str {
+        match self {
+            ErrorKind::Api => "API error",
+            ErrorKind::Parse => "Parse error",
+            ErrorKind::Network => "Network error",
+        }
+    }
+}
+
+/// An error message split into the parts worth showing separately
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorDetail {
+    pub kind: Option<ErrorKind>,
+    /// HTTP status of an API error, e.g. "404 Not Found"
+    pub http_status: Option<String>,
+    /// Response body of an API error, pretty-printed when it is JSON
+    pub response_body: Option<String>,
+    pub message: String,
+}
+
+impl ErrorDetail {
+    /// Recognise the error formats produced by the API client
+    pub fn parse(error: &str) -> Self {
+        let mut detail = Self {
+            kind: None,
+            http_status: None,
+            response_body: None,
+            message: error.to_string(),
+        };
+
+        if let Some(start) = error.find("API error (") {
+            let rest = &error[start + "API error (".len()..];
+            if let Some((status, body)) = rest.split_once("): ") {
+                detail.kind = Some(ErrorKind::Api);
+                detail.http_status = Some(status.to_string());
+                detail.response_body = Some(pretty_body(body));
+            }
+        } else if error.contains("Failed to parse response") {
+            detail.kind = Some(ErrorKind::Parse);
+        } else if error.contains("Request failed") {
+            detail.kind = Some(ErrorKind::Network);
+        }
+        detail
+    }
+}
+
+/// Pretty-print a JSON body, or return it unchanged
+fn pretty_body(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// One-line form of an error for the status bar
+///
+/// Keeps the first line, cuts it at [`STATUS_ERROR_MAX_CHARS`], and points at
+/// the overlay when anything was left out.
+pub fn status_line(error: &str) -> String {
+    let first_line = error.lines().next().unwrap_or("");
+    let truncated = first_line.chars().count() > STATUS_ERROR_MAX_CHARS;
+    if !truncated && first_line.len() == error.trim_end().len() {
+        return first_line.to_string();
+    }
+    let shown: String = first_line.chars().take(STATUS_ERROR_MAX_CHARS).collect();
+    format!("{}… (!: details)", shown.trim_end())
+}
+
+/// Render the full error in a centered overlay
+pub fn render_error_detail(frame: &mut Frame, area: Rect, error: &str, scroll: u16) {
+    let detail = ErrorDetail::parse(error);
+    let dialog_area = centered_rect(80, 70, area);
+    frame.render_widget(Clear, dialog_area);
+
+    let label_style = Style::default()
+        .fg(Theme::PRIMARY)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    if let Some(kind) = detail.kind {
+        lines.push(Line::from(vec![
+            Span::styled("Kind: ", label_style),
+            Span::raw(kind.label()),
+        ]));
+    }
+    if let Some(status) = &detail.http_status {
+        lines.push(Line::from(vec![
+            Span::styled("Status: ", label_style),
+            Span::raw(status.clone()),
+        ]));
+    }
+    if !lines.is_empty() {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled("Message:", label_style));
+    lines.extend(detail.message.lines().map(|l| Line::from(l.to_string())));
+    if let Some(body) = &detail.response_body {
+        lines.push(Line::from(""));
+        lines.push(Line::styled("Response body:", label_style));
+        lines.extend(body.lines().map(|l| Line::from(l.to_string())));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Error Details ")
+                .title_bottom(" j/k: Scroll | Esc: Close ")
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Theme::ERROR).bg(Theme::BACKGROUND)),
+        )
+        .style(Style::default().fg(Theme::TEXT))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, dialog_area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_api_error_extracts_status_and_body() {
+        let detail = ErrorDetail::parse(
+            r#"Failed to load tasks: API error (401 Unauthorized): {"err":"Token invalid","ECODE":"OAUTH_025"}"#,
+        );
+        assert_eq!(detail.kind, Some(ErrorKind::Api));
+        assert_eq!(detail.http_status.as_deref(), Some("401 Unauthorized"));
+        assert!(detail
+            .response_body
+            .unwrap()
+            .contains("\"ECODE\": \"OAUTH_025\""));
+    }
+
+    #[test]
+    fn test_parse_recognises_parse_and_network_errors() {
+        assert_eq!(
+            ErrorDetail::parse("Failed to parse response: {}: tasks[0].id: invalid type").kind,
+            Some(ErrorKind::Parse)
+        );
+        assert_eq!(
+            ErrorDetail::parse("Request failed: connection refused").kind,
+            Some(ErrorKind::Network)
+        );
+        assert_eq!(ErrorDetail::parse("Not authenticated").kind, None);
+    }
+
+    #[test]
+    fn test_status_line_truncates_long_and_multiline_errors() {
+        assert_eq!(status_line("Not authenticated"), "Not authenticated");
+
+        let long = "x".repeat(STATUS_ERROR_MAX_CHARS + 20);
+        let line = status_line(&long);
+        assert!(line.ends_with("… (!: details)"));
+        assert_eq!(
+            line.chars().filter(|c| *c == 'x').count(),
+            STATUS_ERROR_MAX_CHARS
+        );
+
+        assert_eq!(
+            status_line("First line\nsecond line"),
+            "First line… (!: details)"
+        );
+    }
+}
@@ -1,18 +1,25 @@
-//! Help overlay widget with paginated pages
+//! Help overlay widget with paginated pages and a searchable command index
 
-use crate::tui::layout::centered_rect;
+use crate::models::{Feature, Features, TaskStatus};
+use crate::tui::layout::{centered_rect, input_spans};
 use crate::tui::theme::Theme;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
     Frame,
 };
 
 /// Number of pages in the help dialog
 const TOTAL_PAGES: u8 = 3;
 
+/// Width of the key column, including its indent
+const KEY_COLUMN_WIDTH: u16 = 16;
+
+/// Rows moved per PgDn/PgUp
+const SCROLL_STEP: u16 = 5;
+
 /// Context for determining what to show on page 1
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HelpContext {
@@ -28,6 +35,8 @@ pub enum HelpContext {
     Comments,
     /// Document viewer
     Document,
+    /// Due-date calendar
+    Calendar,
 }
 
 impl HelpContext {
@@ -40,16 +49,30 @@ impl HelpContext {
             HelpContext::TaskDetail => "Task Detail",
             HelpContext::Comments => "Comments",
             HelpContext::Document => "Document",
+            HelpContext::Calendar => "Calendar",
         }
     }
 }
 
-/// Help state with pagination
+/// Help state with pagination and search
 #[derive(Debug, Clone)]
 pub struct HelpState {
     pub visible: bool,
     /// Current page (0-indexed: 0, 1, 2)
     pub page: u8,
+    /// Status workflow of the current list, shown on the task list page
+    pub status_workflow: Vec<TaskStatus>,
+    /// Filter over every key binding; pages are replaced by the matches
+    pub search_query: String,
+    /// Cursor in `search_query`, in characters
+    pub search_cursor: usize,
+    /// Whether keys are typed into the search input
+    pub searching: bool,
+    /// Rows scrolled past on a page too long for the overlay
+    pub scroll: u16,
+    /// Features where help was opened; bindings needing one that is off are
+    /// left out
+    pub features: Features,
 }
 
 impl HelpState {
@@ -57,6 +80,12 @@ impl HelpState {
         Self {
             visible: false,
             page: 0,
+            status_workflow: Vec::new(),
+            search_query: String::new(),
+            search_cursor: 0,
+            searching: false,
+            scroll: 0,
+            features: Features::default(),
         }
     }
 
@@ -64,17 +93,20 @@ impl HelpState {
         self.visible = !self.visible;
         if self.visible {
             self.page = 0; // Reset to page 1 when opening
+            self.cancel_search();
         }
     }
 
     pub fn hide(&mut self) {
         self.visible = false;
         self.page = 0;
+        self.cancel_search();
     }
 
     /// Advance to next page (wraps from last to first)
     pub fn next_page(&mut self) {
         self.page = (self.page + 1) % TOTAL_PAGES;
+        self.scroll = 0;
     }
 
     /// Go to previous page (wraps from first to last)
@@ -84,6 +116,17 @@ impl HelpState {
         } else {
             self.page -= 1;
         }
+        self.scroll = 0;
+    }
+
+    /// Show later rows of a page longer than the overlay
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(SCROLL_STEP);
+    }
+
+    /// Show earlier rows of a page longer than the overlay
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(SCROLL_STEP);
     }
 
     /// Reset to page 1 (without changing visibility)
@@ -91,6 +134,24 @@ impl HelpState {
     pub fn reset(&mut self) {
         self.page = 0;
     }
+
+    /// Start typing into the search input
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    /// Stop typing, keeping the bindings filtered
+    pub fn finish_search(&mut self) {
+        self.searching = false;
+    }
+
+    /// Stop typing and show the pages again
+    pub fn cancel_search(&mut self) {
+        self.searching = false;
+        self.search_query.clear();
+        self.search_cursor = 0;
+        self.scroll = 0;
+    }
 }
 
 impl Default for HelpState {
@@ -99,175 +160,338 @@ impl Default for HelpState {
     }
 }
 
-/// Section builder helper
-fn section(title: &str, items: &[(&str, &str)]) -> Vec<Line<'static>> {
-    let mut lines = Vec::new();
-    lines.push(Line::from(Span::styled(
-        title.to_string(),
-        Style::default().add_modifier(Modifier::BOLD),
-    )));
-    for (key, desc) in items {
-        lines.push(Line::from(format!("  {:<14}- {}", key, desc)));
-    }
-    lines
+/// Key bindings shown together under one heading
+#[derive(Debug, PartialEq, Eq)]
+struct HelpSection {
+    title: &'static str,
+    bindings: &'static [(&'static str, &'static str)],
 }
 
-/// Get lines for a given page and context
-fn page_lines(context: &HelpContext, page: u8) -> Vec<Line<'static>> {
-    let nav = section("Navigation", &[
+const AUTH: HelpSection = HelpSection {
+    title: "Auth",
+    bindings: &[
+        ("Enter", "Connect to ClickUp"),
+        ("Ctrl+V", "Paste token"),
+        ("Ctrl+Y", "Copy API settings URL (setup)"),
+        ("Esc", "Cancel"),
+    ],
+};
+
+const NAVIGATION: HelpSection = HelpSection {
+    title: "Navigation",
+    bindings: &[
         ("j/k or ↑/↓", "Move selection"),
         ("Enter", "Select/Open item"),
         ("Esc", "Go back/Close"),
         ("g u", "Navigate to URL"),
-    ]);
+        ("d", "Browse documents (workspaces/spaces)"),
+        ("n", "New space (workspaces) / folder (spaces)"),
+        ("V", "Views saved on the workspace (workspaces)"),
+        ("h/l or Space", "Collapse/expand (sidebar tree)"),
+        ("H/L", "Collapse/expand all (sidebar tree)"),
+    ],
+};
 
-    let global = section("Global", &[
+const GLOBAL: HelpSection = HelpSection {
+    title: "Global",
+    bindings: &[
         ("Ctrl+Q", "Quit (saves session)"),
         ("Tab", "Toggle sidebar"),
         ("?", "Show this help"),
         ("u", "Copy element URL"),
-    ]);
+        ("U / Ctrl+Z", "Undo comment delete / status change"),
+        ("!", "Show full error details"),
+        ("g m", "My Tasks (assigned to you)"),
+        ("g w", "My Work (your tasks by list)"),
+        ("Ctrl+R", "Recently opened tasks"),
+        ("Ctrl+P", "Copy screen as text (for bug reports)"),
+    ],
+};
 
-    let actions = section("Actions", &[
+const ACTIONS: HelpSection = HelpSection {
+    title: "Actions",
+    bindings: &[
         ("n", "Create new item"),
         ("e", "Edit selected item"),
         ("d", "Delete selected item"),
-    ]);
+    ],
+};
 
-    let task_list = section("Task List", &[
+const TASK_LIST: HelpSection = HelpSection {
+    title: "Task List",
+    bindings: &[
         ("a", "Toggle Assigned to Me filter"),
         ("n", "Create new task"),
+        ("e", "Edit selected task"),
         ("s", "Open status picker"),
+        ("o", "Cycle sort order"),
+        ("V", "Show the tasks of a saved view"),
         ("d", "Delete selected task"),
-    ]);
+        ("Space", "Mark task for bulk actions"),
+        ("Ctrl+D", "Delete marked tasks"),
+        ("Ctrl+A", "Archive/unarchive marked tasks, or show/hide archived tasks"),
+        ("H", "Show/hide archived tasks"),
+        ("D", "Toggle compact task list"),
+        ("c", "Due-date calendar"),
+        ("'", "Jump to task by typing its name"),
+    ],
+};
 
-    let task_detail = section("Task Detail", &[
+const TASK_DETAIL: HelpSection = HelpSection {
+    title: "Task Detail",
+    bindings: &[
         ("s", "Open status picker"),
         ("A", "Open assignee picker"),
+        ("T", "Create a tag and add it to the task"),
+        ("l", "Manage related tasks (n: link, d: unlink)"),
         ("e", "Edit task"),
+        ("Ctrl+E", "Edit description in $EDITOR (while editing)"),
+        ("Ctrl+S", "Save staged description"),
+        ("d", "Delete task"),
+        ("Ctrl+W", "Archive/unarchive task"),
+        ("|", "Read task and comments in pager"),
+        ("M", "Toggle raw/rendered description"),
         ("Tab", "Toggle comments focus"),
         ("Esc", "Back to task list"),
-    ]);
+    ],
+};
 
-    let task_creation = section("Task Creation", &[
+const TASK_CREATION: HelpSection = HelpSection {
+    title: "Task Creation",
+    bindings: &[
         ("Tab", "Switch between name/description"),
         ("Ctrl+S", "Create task"),
         ("Esc", "Cancel creation"),
-    ]);
+    ],
+};
 
-    let comments = section("Comments", &[
+const COMMENTS: HelpSection = HelpSection {
+    title: "Comments",
+    bindings: &[
         ("Tab", "Toggle focus (task/comments)"),
         ("j/k", "Navigate comments"),
+        ("L", "Load older comments"),
+        ("Space", "Expand/collapse long comment"),
         ("n", "New comment"),
         ("e", "Edit selected comment"),
+        ("x", "Delete selected comment"),
         ("r", "Reply to thread (in thread view)"),
+        ("t", "Create task from selected comment"),
+        ("a", "Assign selected comment to a member"),
         ("Enter", "View thread"),
         ("Ctrl+S", "Save comment"),
         ("Esc", "Cancel editing / Exit thread"),
-    ]);
+    ],
+};
 
-    let forms = section("Forms", &[
-        ("Ctrl+S", "Save"),
-        ("Esc", "Cancel"),
-    ]);
+const MY_WORK: HelpSection = HelpSection {
+    title: "My Work & Tasks",
+    bindings: &[
+        ("Enter", "Open selected task"),
+        ("r", "Refresh (also sprint overview)"),
+        ("c", "Due-date calendar (My Tasks)"),
+        ("Esc", "Go back"),
+    ],
+};
+
+const CALENDAR: HelpSection = HelpSection {
+    title: "Calendar",
+    bindings: &[
+        ("h/l", "Previous/next day"),
+        ("j/k", "Next/previous week"),
+        ("H/L", "Previous/next month"),
+        ("Enter", "Show the day's tasks (Esc in the list: all tasks)"),
+        ("Esc", "Back to tasks"),
+    ],
+};
+
+const DOCUMENT: HelpSection = HelpSection {
+    title: "Document",
+    bindings: &[("j/k or ↑/↓", "Scroll"), ("Esc", "Close document")],
+};
+
+const FORMS: HelpSection = HelpSection {
+    title: "Forms",
+    bindings: &[("Ctrl+S", "Save"), ("Esc", "Cancel")],
+};
 
-    let session = section("Session", &[
+const HELP: HelpSection = HelpSection {
+    title: "Help",
+    bindings: &[
+        ("j/k or ◄ ►", "Change page"),
+        ("PgDn/PgUp", "Scroll a long page"),
+        ("/", "Search shortcuts"),
+        ("Esc", "Close help"),
+    ],
+};
+
+const SESSION: HelpSection = HelpSection {
+    title: "Session",
+    bindings: &[
         ("", "Session auto-saves on exit"),
         ("", "Restores last view on startup"),
-    ]);
+    ],
+};
 
+/// Every section, in the order search results list them
+const ALL_SECTIONS: [&HelpSection; 14] = [
+    &AUTH,
+    &NAVIGATION,
+    &GLOBAL,
+    &ACTIONS,
+    &TASK_LIST,
+    &TASK_DETAIL,
+    &TASK_CREATION,
+    &COMMENTS,
+    &MY_WORK,
+    &CALENDAR,
+    &DOCUMENT,
+    &FORMS,
+    &HELP,
+    &SESSION,
+];
+
+/// Sections shown on a given page and context
+fn page_sections(context: &HelpContext, page: u8) -> Vec<&'static HelpSection> {
     match page {
-        0 => {
-            // Page 1: Contextual
-            match context {
-                HelpContext::Auth => {
-                    let mut lines = Vec::new();
-                    lines.push(Line::from(Span::styled(
-                        "Auth",
-                        Style::default().add_modifier(Modifier::BOLD),
-                    )));
-                    lines.push(Line::from("  Enter         - Connect to ClickUp"));
-                    lines.push(Line::from("  Esc           - Cancel"));
-                    lines.push(Line::from(""));
-                    lines
-                }
-                HelpContext::Navigation => nav,
-                HelpContext::TaskList => task_list.clone(),
-                HelpContext::TaskDetail => task_detail.clone(),
-                HelpContext::Comments => comments.clone(),
-                HelpContext::Document => {
-                    let mut lines = Vec::new();
-                    lines.push(Line::from(Span::styled(
-                        "Document",
-                        Style::default().add_modifier(Modifier::BOLD),
-                    )));
-                    lines.push(Line::from("  j/k or ↑/↓  - Scroll"));
-                    lines.push(Line::from("  Esc         - Close document"));
-                    lines.push(Line::from(""));
-                    lines
-                }
-            }
-        }
-        1 => {
-            // Page 2: Always Global (Navigation + Global + Actions + Forms)
-            let mut lines = Vec::new();
-            lines.extend(nav);
-            lines.push(Line::from(""));
-            lines.extend(global);
-            lines.push(Line::from(""));
-            lines.extend(actions);
-            lines.push(Line::from(""));
-            lines.extend(forms);
-            lines
-        }
+        // Page 1: Contextual
+        0 => vec![match context {
+            HelpContext::Auth => &AUTH,
+            HelpContext::Navigation => &NAVIGATION,
+            HelpContext::TaskList => &TASK_LIST,
+            HelpContext::TaskDetail => &TASK_DETAIL,
+            HelpContext::Comments => &COMMENTS,
+            HelpContext::Document => &DOCUMENT,
+            HelpContext::Calendar => &CALENDAR,
+        }],
+        // Page 2: Always Global (Navigation + Global + Actions + Forms)
+        1 => vec![&NAVIGATION, &GLOBAL, &ACTIONS, &FORMS],
+        // Page 3: Reference (everything not on page 1 or 2)
         _ => {
-            // Page 3: Reference (everything not on page 1 or 2)
-            let mut lines = Vec::new();
-            match context {
-                HelpContext::TaskList => {
-                    lines.extend(task_detail);
-                    lines.push(Line::from(""));
-                    lines.extend(task_creation);
-                    lines.push(Line::from(""));
-                    lines.extend(comments);
-                    lines.push(Line::from(""));
-                    lines.extend(session);
-                }
-                HelpContext::TaskDetail => {
-                    lines.extend(task_list);
-                    lines.push(Line::from(""));
-                    lines.extend(task_creation);
-                    lines.push(Line::from(""));
-                    lines.extend(comments);
-                    lines.push(Line::from(""));
-                    lines.extend(session);
-                }
-                HelpContext::Comments => {
-                    lines.extend(task_detail);
-                    lines.push(Line::from(""));
-                    lines.extend(task_creation);
-                    lines.push(Line::from(""));
-                    lines.extend(task_list);
-                    lines.push(Line::from(""));
-                    lines.extend(session);
-                }
+            let mut sections = match context {
+                HelpContext::TaskList => vec![&TASK_DETAIL, &TASK_CREATION, &COMMENTS, &CALENDAR],
+                HelpContext::TaskDetail => vec![&TASK_LIST, &TASK_CREATION, &COMMENTS],
+                HelpContext::Comments => vec![&TASK_DETAIL, &TASK_CREATION, &TASK_LIST],
                 HelpContext::Navigation | HelpContext::Auth | HelpContext::Document => {
-                    lines.extend(task_list);
-                    lines.push(Line::from(""));
-                    lines.extend(task_detail);
-                    lines.push(Line::from(""));
-                    lines.extend(task_creation);
-                    lines.push(Line::from(""));
-                    lines.extend(comments);
-                    lines.push(Line::from(""));
-                    lines.extend(session);
+                    vec![&TASK_LIST, &TASK_DETAIL, &TASK_CREATION, &COMMENTS]
                 }
-            }
-            lines
+                HelpContext::Calendar => vec![&TASK_LIST, &TASK_DETAIL],
+            };
+            sections.extend([&MY_WORK, &HELP, &SESSION]);
+            sections
         }
     }
 }
 
+/// Bindings whose key or description contains the query, grouped by section
+fn search_sections(query: &str) -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
+    let query = query.to_lowercase();
+    ALL_SECTIONS
+        .iter()
+        .filter_map(|section| {
+            let matches: Vec<_> = section
+                .bindings
+                .iter()
+                .filter(|(key, desc)| {
+                    key.to_lowercase().contains(&query) || desc.to_lowercase().contains(&query)
+                })
+                .copied()
+                .collect();
+            (!matches.is_empty()).then_some((section.title, matches))
+        })
+        .collect()
+}
+
+/// Table rows for one section: a bold heading, then a row per binding
+fn section_rows(title: &str, bindings: &[(&str, &str)]) -> Vec<Row<'static>> {
+    let mut rows = vec![Row::new(vec![Cell::from(Span::styled(
+        title.to_string(),
+        Style::default().add_modifier(Modifier::BOLD),
+    ))])];
+    for (key, desc) in bindings {
+        rows.push(Row::new(vec![
+            Cell::from(format!("  {}", key)),
+            Cell::from(format!("- {}", desc)),
+        ]));
+    }
+    rows
+}
+
+/// Bindings offered only when a feature is on, by description
+const GATED_BINDINGS: &[(&str, Feature)] = &[("Create a tag and add it to the task", Feature::Tags)];
+
+/// Bindings of the current page, or the search matches when filtering,
+/// without those whose feature is off
+fn shown_sections(
+    state: &HelpState,
+    context: &HelpContext,
+) -> Vec<(&'static str, Vec<(&'static str, &'static str)>)> {
+    let groups: Vec<(&str, Vec<(&str, &str)>)> = if state.search_query.is_empty() {
+        page_sections(context, state.page)
+            .into_iter()
+            .map(|section| (section.title, section.bindings.to_vec()))
+            .collect()
+    } else {
+        search_sections(&state.search_query)
+    };
+    groups
+        .into_iter()
+        .filter_map(|(title, mut bindings)| {
+            bindings.retain(|(_, desc)| {
+                GATED_BINDINGS
+                    .iter()
+                    .all(|(gated, feature)| gated != desc || state.features.allows(*feature))
+            });
+            (!bindings.is_empty()).then_some((title, bindings))
+        })
+        .collect()
+}
+
+/// Table rows for the current page, or the search matches when filtering
+fn help_rows(state: &HelpState, context: &HelpContext) -> Vec<Row<'static>> {
+    let groups = shown_sections(state, context);
+
+    if groups.is_empty() {
+        return vec![Row::new(vec![Cell::from(Span::styled(
+            "  No matching shortcuts",
+            Style::default().fg(Theme::TEXT_DIM),
+        ))])];
+    }
+
+    let mut rows = Vec::new();
+    for (i, (title, bindings)) in groups.iter().enumerate() {
+        if i > 0 {
+            rows.push(Row::new(vec![Cell::from("")]));
+        }
+        rows.extend(section_rows(title, bindings));
+    }
+    rows
+}
+
+/// Status workflow rendered as colored status names joined by arrows
+pub fn status_workflow_lines(statuses: &[TaskStatus]) -> Vec<Line<'static>> {
+    let mut spans = vec![Span::raw("  ")];
+    for (i, status) in statuses.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" → ", Style::default().fg(Theme::TEXT_DIM)));
+        }
+        let color = status
+            .color
+            .as_deref()
+            .and_then(super::status_picker::parse_hex_color)
+            .unwrap_or(Theme::TEXT);
+        spans.push(Span::styled(status.status.clone(), Style::default().fg(color)));
+    }
+
+    vec![
+        Line::from(Span::styled(
+            "Status Workflow",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(spans),
+    ]
+}
+
 /// Get title text for current page
 fn page_title(context: &HelpContext, page: u8) -> String {
     let section_name = match page {
@@ -283,6 +507,25 @@ fn page_title(context: &HelpContext, page: u8) -> String {
     )
 }
 
+/// The search input line at the top of the overlay
+fn search_line(state: &HelpState) -> Line<'static> {
+    if state.searching {
+        let mut spans = vec![Span::styled("/ ", Style::default().fg(Theme::SECONDARY))];
+        spans.extend(input_spans(&state.search_query, state.search_cursor));
+        Line::from(spans).style(Style::default().fg(Theme::TEXT))
+    } else if !state.search_query.is_empty() {
+        Line::from(vec![
+            Span::styled("/ ", Style::default().fg(Theme::SECONDARY)),
+            Span::styled(state.search_query.clone(), Style::default().fg(Theme::TEXT)),
+        ])
+    } else {
+        Line::from(Span::styled(
+            "/ Search shortcuts",
+            Style::default().fg(Theme::TEXT_DIM),
+        ))
+    }
+}
+
 pub fn render_help(frame: &mut Frame, state: &HelpState, context: &HelpContext, area: Rect) {
     if !state.visible {
         return;
@@ -292,8 +535,12 @@ pub fn render_help(frame: &mut Frame, state: &HelpState, context: &HelpContext,
 
     frame.render_widget(Clear, help_area);
 
-    // Build title with page indicator
-    let title = page_title(context, state.page);
+    // Build title with page indicator, or the search mode when filtering
+    let title = if state.search_query.is_empty() {
+        page_title(context, state.page)
+    } else {
+        " Keyboard Shortcuts — Search ".to_string()
+    };
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -301,36 +548,78 @@ pub fn render_help(frame: &mut Frame, state: &HelpState, context: &HelpContext,
 
     frame.render_widget(block, help_area);
 
-    // Build content lines for current page
-    let content_lines = page_lines(context, state.page);
+    let workflow = if state.search_query.is_empty()
+        && state.page == 0
+        && *context == HelpContext::TaskList
+        && !state.status_workflow.is_empty()
+    {
+        let mut lines = vec![Line::from("")];
+        lines.extend(status_workflow_lines(&state.status_workflow));
+        lines
+    } else {
+        Vec::new()
+    };
 
-    // Layout: content area + footer
+    // Layout: search input + content area + status workflow + footer
     let inner = Layout::default()
         .direction(Direction::Vertical)
         .margin(2)
         .constraints([
-            Constraint::Min(1), // Content
-            Constraint::Length(1), // Footer
+            Constraint::Length(1),                     // Search input
+            Constraint::Min(1),                        // Content
+            Constraint::Length(workflow.len() as u16), // Status workflow
+            Constraint::Length(1),                     // Footer
         ])
         .split(help_area);
 
-    // Render content
-    let content = Paragraph::new(content_lines).style(Style::default().fg(Theme::TEXT));
-    frame.render_widget(content, inner[0]);
+    frame.render_widget(Paragraph::new(search_line(state)), inner[0]);
+
+    // Render content, scrolled no further than its last row
+    let rows = help_rows(state, context);
+    let overflows = rows.len() > inner[1].height as usize;
+    let scroll = (state.scroll as usize).min(rows.len().saturating_sub(inner[1].height as usize));
+    let table = Table::new(
+        rows.into_iter().skip(scroll),
+        [Constraint::Length(KEY_COLUMN_WIDTH), Constraint::Min(1)],
+    )
+    .column_spacing(0)
+    .style(Style::default().fg(Theme::TEXT));
+    frame.render_widget(table, inner[1]);
+
+    if !workflow.is_empty() {
+        let workflow = Paragraph::new(workflow).style(Style::default().fg(Theme::TEXT));
+        frame.render_widget(workflow, inner[2]);
+    }
 
     // Render pagination footer
-    let footer_text = format!(
-        "◄ ►  {}/{}  │  j/k: Pages  │  Esc: Close",
-        state.page + 1,
-        TOTAL_PAGES
-    );
+    let footer_text = if state.searching {
+        "Type to filter  │  Enter: Done  │  Esc: Clear".to_string()
+    } else if !state.search_query.is_empty() {
+        "/: Edit search  │  Esc: Close".to_string()
+    } else if overflows {
+        format!(
+            "◄ ► {}/{} │ j/k: Pages │ PgDn: Scroll │ Esc: Close",
+            state.page + 1,
+            TOTAL_PAGES
+        )
+    } else {
+        format!(
+            "◄ ►  {}/{}  │  j/k: Pages  │  Esc: Close",
+            state.page + 1,
+            TOTAL_PAGES
+        )
+    };
     let footer = Paragraph::new(footer_text).style(Style::default().fg(Theme::SECONDARY));
-    frame.render_widget(footer, inner[1]);
+    frame.render_widget(footer, inner[3]);
 }
 
 /// Get pagination hint for status bar
 pub fn get_help_hints(state: &HelpState) -> String {
-    format!("j/k: Pages | Esc: Close | {}/{}", state.page + 1, TOTAL_PAGES)
+    if state.searching {
+        "Type to filter | Enter: Done | Esc: Clear search".to_string()
+    } else {
+        format!("j/k: Pages | Esc: Close | {}/{}", state.page + 1, TOTAL_PAGES)
+    }
 }
 
 #[cfg(test)]
@@ -404,7 +693,7 @@ mod tests {
     }
 
     #[test]
-    fn test_page_lines_page2_is_always_global() {
+    fn test_page_sections_page2_is_always_global() {
         // Page 2 should always include Navigation, Global, Actions, Forms
         let contexts = [
             HelpContext::Auth,
@@ -413,10 +702,11 @@ mod tests {
             HelpContext::TaskDetail,
             HelpContext::Comments,
             HelpContext::Document,
+            HelpContext::Calendar,
         ];
         for ctx in contexts {
-            let lines = page_lines(&ctx, 1);
-            assert!(!lines.is_empty(), "Page 2 should not be empty for {:?}", ctx);
+            let sections = page_sections(&ctx, 1);
+            assert_eq!(sections, vec![&NAVIGATION, &GLOBAL, &ACTIONS, &FORMS], "{:?}", ctx);
         }
     }
 
@@ -435,31 +725,99 @@ mod tests {
 
     #[test]
     fn test_section_format() {
-        let lines = section("Test", &[("a", "Do something"), ("b", "Do other")]);
-        assert_eq!(lines.len(), 3); // title + 2 items
-        assert!(matches!(&lines[0], Line { .. })); // title is bold
+        let rows = section_rows("Test", &[("a", "Do something"), ("b", "Do other")]);
+        assert_eq!(rows.len(), 3); // title + 2 items
     }
 
     #[test]
     fn test_contextual_page1_content() {
         // TaskList page 1 should contain task list shortcuts
-        let lines = page_lines(&HelpContext::TaskList, 0);
-        assert!(!lines.is_empty());
+        assert_eq!(page_sections(&HelpContext::TaskList, 0), vec![&TASK_LIST]);
 
         // TaskDetail page 1 should contain task detail shortcuts
-        let lines = page_lines(&HelpContext::TaskDetail, 0);
-        assert!(!lines.is_empty());
+        assert_eq!(page_sections(&HelpContext::TaskDetail, 0), vec![&TASK_DETAIL]);
 
         // Comments page 1 should contain comments shortcuts
-        let lines = page_lines(&HelpContext::Comments, 0);
-        assert!(!lines.is_empty());
+        assert_eq!(page_sections(&HelpContext::Comments, 0), vec![&COMMENTS]);
+    }
+
+    #[test]
+    fn test_status_workflow_lines_join_statuses_with_arrows() {
+        let status = |name: &str, color: Option<&str>| TaskStatus {
+            id: None,
+            status: name.to_string(),
+            color: color.map(str::to_string),
+            type_field: None,
+            orderindex: None,
+            status_group: None,
+        };
+        let lines = status_workflow_lines(&[
+            status("to do", Some("#ff0000")),
+            status("review", None),
+            status("done", Some("#00ff00")),
+        ]);
+
+        let text: String = lines[1].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, "  to do → review → done");
+        assert_eq!(
+            lines[1].spans[1].style.fg,
+            Some(ratatui::style::Color::Rgb(255, 0, 0))
+        );
+        assert_eq!(lines[1].spans[3].style.fg, Some(Theme::TEXT));
     }
 
     #[test]
     fn test_page3_complement() {
         // Page 3 should be different from page 1 for TaskList
-        let page1 = page_lines(&HelpContext::TaskList, 0);
-        let page3 = page_lines(&HelpContext::TaskList, 2);
-        assert_ne!(page1.len(), page3.len(), "Page 3 should differ from page 1");
+        let page1 = page_sections(&HelpContext::TaskList, 0);
+        let page3 = page_sections(&HelpContext::TaskList, 2);
+        assert!(
+            page1.iter().all(|section| !page3.contains(section)),
+            "Page 3 should differ from page 1"
+        );
+    }
+
+    #[test]
+    fn test_search_matches_key_or_description_grouped_by_section() {
+        let results = search_sections("URL");
+        let titles: Vec<_> = results.iter().map(|(title, _)| *title).collect();
+        assert_eq!(titles, vec!["Auth", "Navigation", "Global"]);
+        assert!(results[2].1.contains(&("u", "Copy element URL")));
+
+        // Keys match too, case-insensitively
+        let results = search_sections("ctrl+s");
+        assert!(results.iter().all(|(_, bindings)| bindings
+            .iter()
+            .all(|(key, _)| *key == "Ctrl+S")));
+        assert!(results.iter().any(|(title, _)| *title == "Comments"));
+
+        assert!(search_sections("no such shortcut").is_empty());
+    }
+
+    #[test]
+    fn test_bindings_of_features_turned_off_are_hidden() {
+        let tag = ("T", "Create a tag and add it to the task");
+        let mut state = HelpState::new();
+        let shown = |state: &HelpState| shown_sections(state, &HelpContext::TaskDetail);
+        assert!(shown(&state)[0].1.contains(&tag));
+
+        state.features = serde_json::from_str(r#"{"tags": {"enabled": false}}"#).unwrap();
+        assert!(!shown(&state)[0].1.contains(&tag));
+        state.search_query.push_str("tag");
+        assert!(shown(&state).iter().all(|(_, bindings)| !bindings.contains(&tag)));
+    }
+
+    #[test]
+    fn test_closing_help_clears_search() {
+        let mut state = HelpState::new();
+        state.toggle();
+        state.start_search();
+        state.search_query.push_str("url");
+        state.finish_search();
+        assert!(!state.searching);
+        assert_eq!(state.search_query, "url");
+
+        state.hide();
+        assert!(state.search_query.is_empty());
     }
 }
@@ -0,0 +1,218 @@
+//! Details of the item selected in the sidebar
+//!
+//! The navigation screens show the selected workspace, space, folder or list
+//! in the main area. [`ItemInfo`] is built from the model alone, so whatever
+//! the API left out is simply not shown.
+
+use crate::models::{ClickUpSpace, Folder, List, Workspace};
+use crate::tui::layout::titled_block;
+use crate::tui::theme::Theme;
+use crate::utils::format_timestamp;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
+
+/// What the info panel shows for one item
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemInfo {
+    /// Kind of item, the panel's title, e.g. "List"
+    pub kind: &'static str,
+    pub name: String,
+    pub description: Option<String>,
+    /// Labelled values, e.g. ("Tasks", "12"), only those known
+    pub fields: Vec<(&'static str, String)>,
+    /// Flags that are set, e.g. "Archived"
+    pub flags: Vec<&'static str>,
+}
+
+impl ItemInfo {
+    pub fn workspace(workspace: &Workspace) -> Self {
+        let mut fields = Vec::new();
+        if let Some(count) = workspace.member_count {
+            fields.push(("Members", count.to_string()));
+        }
+        Self {
+            kind: "Workspace",
+            name: workspace.name.clone(),
+            description: None,
+            fields,
+            flags: Vec::new(),
+        }
+    }
+
+    pub fn space(space: &ClickUpSpace) -> Self {
+        let mut fields = Vec::new();
+        if !space.folders.is_empty() {
+            fields.push(("Folders", space.folders.len().to_string()));
+        }
+        if !space.lists.is_empty() {
+            fields.push(("Lists", space.lists.len().to_string()));
+        }
+        Self {
+            kind: "Space",
+            name: space.name.clone(),
+            description: None,
+            fields,
+            flags: flags(&[(space.private, "Private")]),
+        }
+    }
+
+    pub fn folder(folder: &Folder) -> Self {
+        let mut fields = vec![("Lists", folder.lists.len().to_string())];
+        if let Some(count) = folder.task_count {
+            fields.push(("Tasks", count.to_string()));
+        }
+        if !folder.statuses.is_empty() {
+            fields.push(("Statuses", format!("{} (own workflow)", folder.statuses.len())));
+        }
+        Self {
+            kind: "Folder",
+            name: folder.name.clone(),
+            description: None,
+            fields,
+            flags: flags(&[
+                (folder.private, "Private"),
+                (folder.hidden, "Hidden"),
+                (folder.archived, "Archived"),
+            ]),
+        }
+    }
+
+    pub fn list(list: &List) -> Self {
+        let mut fields = Vec::new();
+        if let Some(status) = &list.status {
+            fields.push(("Status", status.status.clone()));
+        }
+        if let Some(priority) = &list.priority {
+            fields.push(("Priority", priority.priority.clone()));
+        }
+        if let Some(due) = list.due_date {
+            fields.push(("Due", format_timestamp(due)));
+        }
+        if let Some(count) = list.task_count {
+            fields.push(("Tasks", count.to_string()));
+        }
+        // `content` is the description as plain text; `description` is only
+        // sent by some endpoints
+        let description = [&list.content, &list.description]
+            .into_iter()
+            .flatten()
+            .map(|text| text.trim())
+            .find(|text| !text.is_empty())
+            .map(str::to_string);
+        Self {
+            kind: "List",
+            name: list.name.clone(),
+            description,
+            fields,
+            flags: flags(&[(list.hidden, "Hidden"), (list.archived, "Archived")]),
+        }
+    }
+}
+
+/// Names of the flags that are set
+fn flags(flags: &[(bool, &'static str)]) -> Vec<&'static str> {
+    flags.iter().filter(|(set, _)| *set).map(|(_, name)| *name).collect()
+}
+
+/// Render the panel in `area`
+pub fn render_item_info(frame: &mut Frame, info: &ItemInfo, area: Rect) {
+    let block = titled_block(format!(" {} ", info.kind));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines = vec![Line::styled(
+        info.name.clone(),
+        Style::default().fg(Theme::TEXT).add_modifier(Modifier::BOLD),
+    )];
+    if !info.flags.is_empty() {
+        lines.push(Line::styled(
+            info.flags.join(" · "),
+            Style::default().fg(Theme::WARNING),
+        ));
+    }
+    lines.push(Line::raw(""));
+    for (label, value) in &info.fields {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}: ", label), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(value.clone()),
+        ]));
+    }
+    if let Some(description) = &info.description {
+        if !info.fields.is_empty() {
+            lines.push(Line::raw(""));
+        }
+        lines.extend(
+            description
+                .lines()
+                .map(|line| Line::styled(line.to_string(), Style::default().fg(Theme::TEXT_DIM))),
+        );
+    }
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(json: &str) -> List {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_list_without_optional_fields() {
+        let info = ItemInfo::list(&list(r#"{"id": "1", "name": "Backlog"}"#));
+        assert_eq!(info.kind, "List");
+        assert_eq!(info.name, "Backlog");
+        assert_eq!(info.description, None);
+        assert!(info.fields.is_empty());
+        assert!(info.flags.is_empty());
+    }
+
+    #[test]
+    fn test_list_with_all_fields() {
+        let info = ItemInfo::list(&list(
+            r#"{"id": "1", "name": "Backlog", "content": "  Ideas to triage ",
+                "status": {"status": "on track"},
+                "priority": {"priority": "high"},
+                "due_date": "1700000000000", "task_count": "12",
+                "archived": true}"#,
+        ));
+        assert_eq!(info.description.as_deref(), Some("Ideas to triage"));
+        let labels: Vec<_> = info.fields.iter().map(|(label, _)| *label).collect();
+        assert_eq!(labels, ["Status", "Priority", "Due", "Tasks"]);
+        assert_eq!(info.fields[3].1, "12");
+        assert_eq!(info.flags, ["Archived"]);
+    }
+
+    #[test]
+    fn test_blank_content_falls_back_to_description() {
+        let info = ItemInfo::list(&list(
+            r#"{"id": "1", "name": "L", "content": " ", "description": "Notes"}"#,
+        ));
+        assert_eq!(info.description.as_deref(), Some("Notes"));
+    }
+
+    #[test]
+    fn test_folder_counts_and_flags() {
+        let folder: Folder = serde_json::from_str(
+            r#"{"id": "f", "name": "Q1", "hidden": true, "task_count": "7",
+                "lists": [{"id": "1", "name": "A"}, {"id": "2", "name": "B"}]}"#,
+        )
+        .unwrap();
+        let info = ItemInfo::folder(&folder);
+        assert_eq!(
+            info.fields,
+            [("Lists", "2".to_string()), ("Tasks", "7".to_string())]
+        );
+        assert_eq!(info.flags, ["Hidden"]);
+
+        let bare: Folder = serde_json::from_str(r#"{"id": "f", "name": "Q1"}"#).unwrap();
+        assert_eq!(ItemInfo::folder(&bare).fields, [("Lists", "0".to_string())]);
+    }
+}
@@ -0,0 +1,689 @@
+//! Linearized rendering for screen readers
+//!
+//! In accessibility mode a screen is flattened into plain lines: no borders,
+//! no colors, one item per line with a spoken-style prefix ("Task 3 of 40:
+//! ..."). The status line comes first and the selected item's full text is
+//! repeated on a fixed row, so a screen reader finds both in the same place
+//! after every keypress. The text comes from the same helpers the normal
+//! widgets use, so both layouts show the same content.
+
+use crate::models::{Comment, Task};
+use crate::tui::app::{CommentViewMode, TaskCreationField};
+use crate::tui::widgets::auth::{token_help_text, token_instructions, wizard_step_text, AuthState};
+use crate::tui::widgets::calendar::{day_descriptions, CalendarState};
+use crate::tui::widgets::comments::{comment_author, comment_date, filter_comments};
+use crate::tui::widgets::document::{markdown_to_text, DocumentState};
+use crate::tui::widgets::documents::{updated_text, DocumentsState};
+use crate::tui::widgets::recent_tasks::RecentTasksState;
+use crate::tui::widgets::sidebar::{SidebarItem, SidebarState};
+use crate::tui::widgets::sprint::{stats_lines, SprintState};
+use crate::tui::widgets::task_detail::{
+    due_text, metadata_lines, priority_text, status_text, TaskDetailState,
+};
+use crate::tui::widgets::task_list::{GroupedTaskList, ListRow};
+use crate::tui::widgets::workspace_views::WorkspaceViewsState;
+use crate::utils::text::wrap_text;
+use ratatui::{layout::Rect, text::Line, widgets::Paragraph, Frame};
+
+/// Width of the "> " marker in front of each item
+const MARKER_WIDTH: usize = 2;
+
+/// A screen flattened to one line per item
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LinearView {
+    /// What the screen shows, read when nothing is selected
+    pub title: String,
+    /// One line per item, already prefixed
+    pub items: Vec<String>,
+    /// Index into `items` of the selected item
+    pub selected: Option<usize>,
+}
+
+impl LinearView {
+    /// The selected item's text, or the title when nothing is selected
+    pub fn focus_line(&self) -> &str {
+        self.selected
+            .and_then(|i| self.items.get(i))
+            .unwrap_or(&self.title)
+    }
+
+    /// Exactly `height` lines of at most `width` characters: status, focus
+    /// line, blank, the items scrolled to keep the selection visible, and
+    /// the status again with key hints on the last row
+    ///
+    /// Long lines wrap rather than run off the edge; an item's continuation
+    /// lines are indented under its text.
+    pub fn lines(&self, status: &str, hints: &str, width: usize, height: usize) -> Vec<String> {
+        let status = if status.is_empty() { "Ready" } else { status };
+        let mut lines = wrap(&format!("Status: {}", status), width);
+        lines.extend(wrap(self.focus_line(), width));
+        lines.push(String::new());
+
+        let items: Vec<Vec<String>> = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = if Some(i) == self.selected { "> " } else { "  " };
+                wrap(item, width.saturating_sub(MARKER_WIDTH))
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, text)| {
+                        let prefix = if row == 0 { marker } else { "  " };
+                        format!("{}{}", prefix, text)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Start as far back as still leaves the whole selected item in view
+        let rows = height.saturating_sub(lines.len() + 1);
+        let selected = self.selected.unwrap_or(0).min(items.len().saturating_sub(1));
+        let mut start = selected;
+        let mut used = items.get(selected).map_or(0, Vec::len);
+        while start > 0 && used + items[start - 1].len() <= rows {
+            start -= 1;
+            used += items[start].len();
+        }
+        lines.extend(items.iter().skip(start).flatten().take(rows).cloned());
+
+        lines.resize(height.saturating_sub(1), String::new());
+        lines.push(format!("{} | {}", status, hints));
+        lines.truncate(height);
+        lines
+    }
+}
+
+/// Word-wrap `text` to `width` characters, breaking words too long for a line
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    wrap_text(text, width)
+        .into_iter()
+        .flat_map(|line| {
+            let chars: Vec<char> = line.chars().collect();
+            if chars.len() <= width {
+                return vec![line];
+            }
+            chars.chunks(width).map(|chunk| chunk.iter().collect()).collect()
+        })
+        .collect()
+}
+
+/// The text of a styled line, without its styling
+fn plain(line: &Line) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
+/// Render `view` as plain lines filling `area`
+pub fn render_linear(frame: &mut Frame, view: &LinearView, status: &str, hints: &str, area: Rect) {
+    let text = view
+        .lines(status, hints, area.width as usize, area.height as usize)
+        .join("\n");
+    frame.render_widget(Paragraph::new(text), area);
+}
+
+/// "{Kind} n of m: {name}", numbering items of the same kind
+fn numbered(kind: &str, position: usize, total: usize, text: &str) -> String {
+    format!("{} {} of {}: {}", kind, position + 1, total, text)
+}
+
+/// The sidebar's items, e.g. "Space 2 of 5: Engineering"
+pub fn sidebar_view(title: &str, state: &SidebarState) -> LinearView {
+    let items = state.items();
+    let items_text = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| match item {
+            SidebarItem::Header { label, count } => {
+                format!("{}: {}, {} items", item.kind_name(), label, count)
+            }
+            _ => {
+                let same_kind = |other: &SidebarItem| {
+                    std::mem::discriminant(other) == std::mem::discriminant(item)
+                };
+                let position = items[..i].iter().filter(|o| same_kind(o)).count();
+                let total = items.iter().filter(|o| same_kind(o)).count();
+                let text = match item.member_label() {
+                    Some(members) => format!("{}, {}", item.name(), members),
+                    None => item.name().to_string(),
+                };
+                numbered(item.kind_name(), position, total, &text)
+            }
+        })
+        .collect();
+
+    LinearView {
+        title: title.to_string(),
+        items: items_text,
+        selected: state.state().selected(),
+    }
+}
+
+/// One task as a sentence: name, then status, priority and due date if set
+pub fn describe_task(task: &Task) -> String {
+    let mut parts = vec![task.name.clone(), format!("status {}", status_text(task))];
+    if task.priority.is_some() {
+        parts.push(format!("priority {}", priority_text(task)));
+    }
+    if task.due_date.is_some() {
+        parts.push(format!("due {}", due_text(task)));
+    }
+    parts.join(", ")
+}
+
+/// The task list, e.g. "Task 3 of 40: Fix login bug, status in progress"
+///
+/// Group headers are left out since every line names its status.
+pub fn task_list_view(title: &str, state: &GroupedTaskList) -> LinearView {
+    let selected_row = state.state().selected();
+    let total = state
+        .rows()
+        .iter()
+        .filter(|row| matches!(row, ListRow::Task(_)))
+        .count();
+
+    let mut items = Vec::with_capacity(total);
+    let mut selected = None;
+    for (row_index, row) in state.rows().iter().enumerate() {
+        let ListRow::Task(task) = row else {
+            continue;
+        };
+        if selected_row == Some(row_index) {
+            selected = Some(items.len());
+        }
+        let mut text = numbered("Task", items.len(), total, &describe_task(task));
+        if state.is_marked(&task.id) {
+            text.push_str(", marked");
+        }
+        if state.is_unread(&task.id) {
+            text.push_str(", new comments");
+        }
+        items.push(text);
+    }
+
+    LinearView {
+        title: title.to_string(),
+        items,
+        selected,
+    }
+}
+
+/// The calendar's month, one day per line, e.g. "Day 12 of 31: Tue 12: 3 due"
+pub fn calendar_view(title: &str, state: &CalendarState) -> LinearView {
+    let (days, selected) = day_descriptions(state);
+    let total = days.len();
+    LinearView {
+        title: format!("{}, {}", title, state.layout().title()),
+        items: days
+            .iter()
+            .enumerate()
+            .map(|(i, day)| numbered("Day", i, total, day))
+            .collect(),
+        selected,
+    }
+}
+
+/// The open task: metadata, description lines, then comments as
+/// "Comment 2 of 5 by alice, {date}: {text}"
+///
+/// The selection follows the comment cursor while comments have focus.
+pub fn task_detail_view(
+    title: &str,
+    detail: &TaskDetailState,
+    comments: &[Comment],
+    view_mode: &CommentViewMode,
+    selected_comment: usize,
+    comment_focus: bool,
+) -> LinearView {
+    let Some(task) = &detail.task else {
+        return LinearView {
+            title: title.to_string(),
+            items: vec!["No task selected".to_string()],
+            selected: None,
+        };
+    };
+
+    let mut items: Vec<String> = metadata_lines(task)
+        .iter()
+        .map(plain)
+        .filter(|text| !text.is_empty())
+        .collect();
+
+    let description = detail.description_text();
+    items.extend(
+        description
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(i, line)| {
+                if i == 0 {
+                    format!("Description: {}", line)
+                } else {
+                    line.to_string()
+                }
+            }),
+    );
+
+    let order = filter_comments(view_mode, comments);
+    if order.is_empty() {
+        items.push("No comments".to_string());
+    }
+    let mut selected = None;
+    for (position, &index) in order.iter().enumerate() {
+        if comment_focus && index == selected_comment {
+            selected = Some(items.len());
+        }
+        let comment = &comments[index];
+        let text = comment
+            .text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let header = format!("by {}, {}", comment_author(comment), comment_date(comment));
+        items.push(numbered(
+            "Comment",
+            position,
+            order.len(),
+            &format!("{}: {}", header, text),
+        ));
+    }
+
+    LinearView {
+        title: title.to_string(),
+        items,
+        selected,
+    }
+}
+
+/// The new task form, one line per field, selecting the focused one
+pub fn task_creation_view(
+    title: &str,
+    name: &str,
+    description: &str,
+    focus: &TaskCreationField,
+) -> LinearView {
+    let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    LinearView {
+        title: title.to_string(),
+        items: vec![
+            format!("Name, required: {}", name),
+            format!("Description, optional: {}", description),
+        ],
+        selected: Some(match focus {
+            TaskCreationField::Name => 0,
+            TaskCreationField::Description => 1,
+        }),
+    }
+}
+
+/// The auth screen: the wizard step's text, or the token form with the
+/// token's length rather than its characters
+pub fn auth_view(title: &str, state: &AuthState) -> LinearView {
+    let title = if state.wizard {
+        format!("{}, {}", title, state.progress_label())
+    } else {
+        title.to_string()
+    };
+
+    // A still spinner, so the line doesn't change on every frame
+    if let Some((heading, body, footer)) = wizard_step_text(state, 0) {
+        let mut items = vec![heading.to_string()];
+        items.extend(body.into_iter().filter(|line| !line.trim().is_empty()));
+        items.push(footer.to_string());
+        return LinearView {
+            title,
+            items,
+            selected: None,
+        };
+    }
+
+    let token = if state.loading {
+        "Token: checking".to_string()
+    } else if state.token_input.is_empty() {
+        "Token: empty".to_string()
+    } else {
+        format!("Token: {} characters entered", state.token_input.chars().count())
+    };
+    let mut items = vec![
+        "Enter your ClickUp API Token".to_string(),
+        token_help_text(state),
+        token,
+    ];
+    if let Some(error) = &state.error {
+        items.push(format!("Error: {}", error));
+    } else if let Some(link) = &state.pending_link {
+        items.push(format!("Opens after you connect: {}", link));
+    }
+    items.push(token_instructions(state).to_string());
+
+    LinearView {
+        title,
+        items,
+        // Keep the token line in focus while typing
+        selected: Some(2),
+    }
+}
+
+/// An open doc, e.g. "Line 3 of 40: ...", selecting the line scrolled to
+pub fn document_view(title: &str, state: &DocumentState) -> LinearView {
+    let text = markdown_to_text(&state.content);
+    let lines: Vec<&str> = text.lines().filter(|line| !line.trim().is_empty()).collect();
+    let total = lines.len();
+    LinearView {
+        title: title.to_string(),
+        items: lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| numbered("Line", i, total, line))
+            .collect(),
+        selected: (total > 0).then(|| state.scroll_offset.min(total - 1)),
+    }
+}
+
+/// The doc search: the query first, then "Doc 2 of 5: Roadmap, {updated}"
+pub fn documents_view(title: &str, state: &DocumentsState) -> LinearView {
+    let docs = state.docs.items();
+    let mut items = vec![format!("Search: {}", state.query)];
+    if docs.is_empty() {
+        items.push(state.empty_message().to_string());
+    }
+    items.extend(docs.iter().enumerate().map(|(i, doc)| {
+        let text = format!("{}, {}", doc.name, updated_text(doc));
+        numbered("Doc", i, docs.len(), &text)
+    }));
+
+    LinearView {
+        title: title.to_string(),
+        items,
+        selected: state.docs.state().selected().map(|i| i + 1),
+    }
+}
+
+/// The sprint's stats, one per line
+pub fn sprint_view(title: &str, state: &SprintState) -> LinearView {
+    let items = match &state.overview {
+        Some(overview) => stats_lines(overview).iter().map(plain).collect(),
+        None => vec!["Loading sprint...".to_string()],
+    };
+    LinearView {
+        title: title.to_string(),
+        items,
+        selected: None,
+    }
+}
+
+/// Recently opened tasks, e.g. "Task 1 of 8: Fix login bug, status done"
+pub fn recent_tasks_view(title: &str, state: &RecentTasksState) -> LinearView {
+    let tasks = state.tasks.items();
+    let mut items: Vec<String> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| {
+            let text = match &task.status {
+                Some(status) => format!("{}, status {}", task.label(), status),
+                None => task.label().to_string(),
+            };
+            numbered("Task", i, tasks.len(), &text)
+        })
+        .collect();
+    if items.is_empty() {
+        items.push("No recently opened tasks".to_string());
+    }
+
+    LinearView {
+        title: title.to_string(),
+        items,
+        selected: state.tasks.state().selected(),
+    }
+}
+
+/// The workspace's views, e.g. "View 2 of 4: Sprint board, board", or the
+/// tasks of the one opened
+pub fn workspace_views_view(title: &str, state: &WorkspaceViewsState) -> LinearView {
+    if let Some(message) = state.empty_message() {
+        return LinearView {
+            title: title.to_string(),
+            items: vec![message.to_string()],
+            selected: None,
+        };
+    }
+    if state.open_view.is_some() {
+        return task_list_view(title, &state.tasks);
+    }
+
+    let views = state.views.items();
+    LinearView {
+        title: title.to_string(),
+        items: views
+            .iter()
+            .enumerate()
+            .map(|(i, view)| {
+                let mut text = format!("{}, {}", view.name, view.view_type);
+                if !view.visibility.is_empty() {
+                    text.push_str(&format!(", {}", view.visibility));
+                }
+                numbered("View", i, views.len(), &text)
+            })
+            .collect(),
+        selected: state.views.state().selected(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, name: &str, status: &str) -> Task {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "status": {"status": status},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_task_list_lines_number_tasks_and_skip_headers() {
+        let mut list = GroupedTaskList::from_tasks(vec![
+            task("1", "Fix login bug", "in progress"),
+            task("2", "Write docs", "todo"),
+        ]);
+        list.select_first();
+        list.select_next();
+
+        let view = task_list_view("Tasks", &list);
+        assert_eq!(view.items.len(), 2);
+        assert!(view.items.iter().all(|item| item.starts_with("Task ")));
+        let selected = &view.items[view.selected.unwrap()];
+        assert!(selected.starts_with("Task 2 of 2: "), "{}", selected);
+        assert_eq!(view.focus_line(), selected);
+    }
+
+    #[test]
+    fn test_describe_task_reads_as_a_sentence() {
+        let mut fix = task("1", "Fix login bug", "in progress");
+        fix.priority = Some(crate::models::Priority {
+            priority: "high".to_string(),
+            color: None,
+        });
+        assert_eq!(
+            describe_task(&fix),
+            "Fix login bug, status in progress, priority high"
+        );
+    }
+
+    #[test]
+    fn test_sidebar_items_numbered_by_kind() {
+        let mut sidebar = SidebarState::new();
+        sidebar.replace_items(vec![
+            SidebarItem::Header {
+                label: "Folders".to_string(),
+                count: 1,
+            },
+            SidebarItem::Folder {
+                name: "Backend".to_string(),
+                id: "f1".to_string(),
+            },
+            SidebarItem::List {
+                name: "Inbox".to_string(),
+                id: "l1".to_string(),
+            },
+            SidebarItem::List {
+                name: "Bugs".to_string(),
+                id: "l2".to_string(),
+            },
+        ]);
+        sidebar.select_by_id("l2");
+
+        let view = sidebar_view("Spaces", &sidebar);
+        assert_eq!(
+            view.items,
+            [
+                "Section: Folders, 1 items",
+                "Folder 1 of 1: Backend",
+                "List 1 of 2: Inbox",
+                "List 2 of 2: Bugs",
+            ]
+        );
+        assert_eq!(view.focus_line(), "List 2 of 2: Bugs");
+    }
+
+    #[test]
+    fn test_lines_keep_status_and_focus_on_fixed_rows() {
+        let view = LinearView {
+            title: "Tasks".to_string(),
+            items: (1..=20).map(|i| format!("Task {} of 20", i)).collect(),
+            selected: Some(14),
+        };
+
+        let lines = view.lines("Loaded 20 task(s)", "j/k: Navigate", 80, 10);
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "Status: Loaded 20 task(s)");
+        assert_eq!(lines[1], "Task 15 of 20");
+        assert_eq!(lines[2], "");
+        // The selection stays in view, marked, as the list scrolls
+        assert_eq!(lines[8], "> Task 15 of 20");
+        assert_eq!(lines[9], "Loaded 20 task(s) | j/k: Navigate");
+
+        let lines = view.lines("", "", 80, 10);
+        assert_eq!(lines[0], "Status: Ready");
+    }
+
+    #[test]
+    fn test_lines_wrap_to_the_width() {
+        let view = LinearView {
+            title: "Tasks".to_string(),
+            items: vec![
+                "Task 1 of 2: Fix the login bug on the settings page".to_string(),
+                "Task 2 of 2: Write docs".to_string(),
+            ],
+            selected: Some(0),
+        };
+
+        let lines = view.lines("Ready", "q: Quit", 20, 12);
+        assert_eq!(lines.len(), 12);
+        assert!(lines.iter().take(11).all(|line| line.chars().count() <= 20), "{:?}", lines);
+        // The focus line wraps in full under the status
+        assert_eq!(
+            lines[1..5],
+            ["Task 1 of 2: Fix the", "login bug on the", "settings page", ""]
+        );
+        // Continuation lines are indented under the item's text
+        assert_eq!(
+            lines[5..9],
+            [
+                "> Task 1 of 2: Fix",
+                "  the login bug on",
+                "  the settings page",
+                "  Task 2 of 2: Write",
+            ]
+        );
+        assert_eq!(lines[9], "  docs");
+    }
+
+    #[test]
+    fn test_wrap_breaks_words_longer_than_the_width() {
+        assert_eq!(wrap("see https://example.com/a", 10), ["see", "https://ex", "ample.com/", "a"]);
+    }
+
+    #[test]
+    fn test_auth_view_reads_the_token_length_not_the_token() {
+        let mut state = AuthState::new();
+        state.token_input = "pk_12345".to_string();
+        state.error = Some("Invalid token".to_string());
+
+        let view = auth_view("Authentication", &state);
+        assert_eq!(view.focus_line(), "Token: 8 characters entered");
+        assert!(view.items.contains(&"Error: Invalid token".to_string()));
+        assert!(view.items.iter().all(|item| !item.contains("pk_1")));
+
+        let wizard = auth_view("Authentication", &AuthState::with_wizard());
+        assert_eq!(wizard.title, "Authentication, Step 1 of 4");
+        assert_eq!(wizard.items[0], "Welcome to ClickDown");
+    }
+
+    #[test]
+    fn test_documents_view_puts_the_query_first() {
+        let mut state = DocumentsState::new();
+        state.query = "road".to_string();
+        state.set_docs(
+            serde_json::from_value(serde_json::json!([
+                {"id": "d1", "name": "Roadmap"},
+                {"id": "d2", "name": "Road trip"},
+            ]))
+            .unwrap(),
+        );
+
+        let view = documents_view("Documents", &state);
+        assert_eq!(
+            view.items,
+            [
+                "Search: road",
+                "Doc 1 of 2: Roadmap, never updated",
+                "Doc 2 of 2: Road trip, never updated",
+            ]
+        );
+        assert_eq!(view.focus_line(), "Doc 1 of 2: Roadmap, never updated");
+    }
+
+    #[test]
+    fn test_task_detail_selects_focused_comment() {
+        let mut detail = TaskDetailState::new();
+        detail.task = Some(task("1", "Fix login bug", "todo"));
+        let comments: Vec<Comment> = serde_json::from_value(serde_json::json!([
+            {"id": "c1", "comment_text": "First\nline", "user": {"id": 1, "username": "alice"}},
+            {"id": "c2", "comment_text": "Second", "user": {"id": 2, "username": "bob"}},
+        ]))
+        .unwrap();
+
+        let view = task_detail_view(
+            "Task",
+            &detail,
+            &comments,
+            &CommentViewMode::TopLevel,
+            1,
+            true,
+        );
+        assert!(view.items.contains(&"Status: todo".to_string()));
+        assert!(view
+            .items
+            .contains(&"Description: No description".to_string()));
+        assert_eq!(
+            view.focus_line(),
+            "Comment 2 of 2: by bob, Unknown date: Second"
+        );
+        assert!(view
+            .items
+            .contains(&"Comment 1 of 2: by alice, Unknown date: First line".to_string()));
+
+        let unfocused = task_detail_view(
+            "Task",
+            &detail,
+            &comments,
+            &CommentViewMode::TopLevel,
+            1,
+            false,
+        );
+        assert_eq!(unfocused.focus_line(), "Task");
+    }
+}
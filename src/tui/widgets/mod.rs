@@ -1,23 +1,49 @@
 //! TUI widgets
 
 pub mod assignee_picker;
+pub mod avatar;
 pub mod auth;
+pub mod calendar;
+pub mod color_picker;
 pub mod comments;
 pub mod dialog;
 pub mod document;
+pub mod documents;
+pub mod empty_state;
+pub mod error_detail;
 pub mod help;
+pub mod item_info;
+pub mod linear;
+pub mod name_prompt;
+pub mod picker;
+pub mod recent_tasks;
 pub mod sidebar;
+pub mod sprint;
 pub mod status_picker;
 pub mod task_detail;
 pub mod task_list;
+pub mod workspace_views;
 
 pub use assignee_picker::render_assignee_picker;
 pub use auth::{render_auth, AuthState};
-pub use comments::render_comments;
+pub use calendar::{render_calendar, CalendarState};
+pub use color_picker::{render_color_picker, ColorPickerState};
+pub use comments::{render_comments, CommentCollapse};
 pub use dialog::{get_dialog_hints, render_dialog, DialogState, DialogType};
 pub use document::{render_document, DocumentState};
+pub use documents::{render_documents, DocumentsState};
+pub use empty_state::{render_empty_state, Collection, EmptyState, LoadStatus};
 pub use help::{get_help_hints, render_help, HelpContext, HelpState};
-pub use sidebar::{render_sidebar, SidebarItem, SidebarState};
+pub use item_info::{render_item_info, ItemInfo};
+pub use linear::{render_linear, LinearView};
+pub use name_prompt::{render_name_prompt, NamePromptState, NewItem};
+pub use recent_tasks::{render_recent_tasks, RecentTask, RecentTasksState};
+pub use sidebar::{render_sidebar, terminal_supports_nerd_fonts, SidebarItem, SidebarState};
+pub use sprint::{get_sprint_hints, render_sprint, SprintState};
+pub use picker::{render_picker, PickerState};
 pub use status_picker::render_status_picker;
-pub use task_detail::{render_task_detail, TaskDetailState};
+pub use task_detail::{
+    render_task_description, render_task_detail, render_task_metadata, TaskDetailState,
+};
 pub use task_list::{render_task_list, GroupedTaskList, ListRow};
+pub use workspace_views::{render_workspace_views, WorkspaceViewsState};
@@ -0,0 +1,245 @@
+//! Prompt for the name of a new space, folder or tag, or the task to link to
+//!
+//! A one-line input in a centered overlay. For a space, Tab also toggles
+//! whether it is private; a tag goes on to pick its color.
+
+use crate::tui::input::LineEditor;
+use crate::tui::layout::input_spans;
+use crate::tui::theme::Theme;
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// What the prompt creates, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewItem {
+    Space { workspace_id: String },
+    Folder { space_id: String },
+    /// A tag in the task's space, added to the task once created
+    Tag { space_id: String, task_id: String },
+    /// A link from the task to another, whose ID is typed
+    TaskLink { task_id: String },
+}
+
+/// Name typed so far and how to create the item
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamePromptState {
+    pub item: NewItem,
+    pub name: String,
+    /// Cursor in `name`, in characters
+    pub cursor: usize,
+    /// Create the space as private (ignored for folders)
+    pub private: bool,
+    /// Why the last submit was refused
+    pub error: Option<String>,
+}
+
+impl NamePromptState {
+    pub fn new(item: NewItem) -> Self {
+        Self {
+            item,
+            name: String::new(),
+            cursor: 0,
+            private: false,
+            error: None,
+        }
+    }
+
+    /// "space", "folder", "tag" or "link"
+    pub fn kind(&self) -> &'static str {
+        match self.item {
+            NewItem::Space { .. } => "space",
+            NewItem::Folder { .. } => "folder",
+            NewItem::Tag { .. } => "tag",
+            NewItem::TaskLink { .. } => "link",
+        }
+    }
+
+    /// Label of the input
+    pub fn label(&self) -> &'static str {
+        match self.item {
+            NewItem::TaskLink { .. } => "Task ID: ",
+            _ => "Name: ",
+        }
+    }
+
+    /// Key hints for the prompt
+    pub fn hints(&self) -> &'static str {
+        match self.item {
+            NewItem::Space { .. } => "Enter: Create | Tab: Private | Esc: Cancel",
+            NewItem::Folder { .. } => "Enter: Create | Esc: Cancel",
+            NewItem::Tag { .. } => "Enter: Pick color | Esc: Cancel",
+            NewItem::TaskLink { .. } => "Enter: Link | Esc: Cancel",
+        }
+    }
+
+    /// Apply an editing key to the name, returning whether it was one
+    pub fn edit(&mut self, key: KeyEvent) -> bool {
+        let edited = LineEditor::new(&mut self.name, &mut self.cursor).handle_key(key);
+        if edited {
+            self.error = None;
+        }
+        edited
+    }
+
+    /// Flip the private flag of a new space
+    pub fn toggle_private(&mut self) {
+        if matches!(self.item, NewItem::Space { .. }) {
+            self.private = !self.private;
+        }
+    }
+
+    /// The trimmed name, or `None` with an error shown when it is blank
+    pub fn submit(&mut self) -> Option<String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            self.error = Some(match self.item {
+                NewItem::TaskLink { .. } => "Please enter the ID of a task".to_string(),
+                _ => format!("Please enter a {} name", self.kind()),
+            });
+            return None;
+        }
+        Some(name.to_string())
+    }
+}
+
+/// Render the prompt as a centered overlay
+pub fn render_name_prompt(frame: &mut Frame, state: &NamePromptState, area: Rect) {
+    let width = 50.min(area.width.saturating_sub(4));
+    let height = 9.min(area.height);
+    let prompt_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, prompt_area);
+    let title = match state.item {
+        NewItem::Space { .. } => " New Space ",
+        NewItem::Folder { .. } => " New Folder ",
+        NewItem::Tag { .. } => " New Tag ",
+        NewItem::TaskLink { .. } => " Link Task ",
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Theme::BACKGROUND));
+    let inner = block.inner(prompt_area);
+    frame.render_widget(block, prompt_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(1), // Name
+            Constraint::Length(1), // Private toggle
+            Constraint::Length(1), // Error
+            Constraint::Min(1),    // Hint
+        ])
+        .split(inner);
+
+    let mut name = vec![Span::styled(
+        state.label(),
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    name.extend(input_spans(&state.name, state.cursor));
+    let name = Line::from(name);
+    frame.render_widget(Paragraph::new(name), layout[0]);
+
+    if matches!(state.item, NewItem::Space { .. }) {
+        let checkbox = if state.private { "[x]" } else { "[ ]" };
+        let private = Paragraph::new(format!("{} Private", checkbox))
+            .style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(private, layout[1]);
+    }
+
+    if let Some(error) = &state.error {
+        let error = Paragraph::new(error.as_str()).style(Style::default().fg(Theme::ERROR));
+        frame.render_widget(error, layout[2]);
+    }
+
+    let hint = Paragraph::new(state.hints()).style(Style::default().fg(Theme::TEXT_DIM));
+    frame.render_widget(hint, layout[3]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn type_char(prompt: &mut NamePromptState, c: char) {
+        prompt.edit(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_submit_trims_and_rejects_blank_names() {
+        let mut prompt = NamePromptState::new(NewItem::Folder {
+            space_id: "s1".to_string(),
+        });
+        type_char(&mut prompt, ' ');
+        assert_eq!(prompt.submit(), None);
+        assert_eq!(prompt.error.as_deref(), Some("Please enter a folder name"));
+
+        // Typing clears the error
+        for c in "Q3 ".chars() {
+            type_char(&mut prompt, c);
+        }
+        assert!(prompt.error.is_none());
+        assert_eq!(prompt.submit().as_deref(), Some("Q3"));
+    }
+
+    #[test]
+    fn test_edit_keys_move_the_cursor_and_clear_the_error() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        let mut prompt = NamePromptState::new(NewItem::Folder {
+            space_id: "s1".to_string(),
+        });
+        for c in "Q3 plans".chars() {
+            type_char(&mut prompt, c);
+        }
+        prompt.error = Some("Please enter a folder name".to_string());
+
+        assert!(prompt.edit(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::ALT)));
+        assert!(prompt.edit(key(KeyCode::Char('Q'))));
+        assert!(prompt.edit(key(KeyCode::Char('4'))));
+        assert!(prompt.edit(key(KeyCode::Char(' '))));
+        assert_eq!(prompt.name, "Q3 Q4 plans");
+        assert!(prompt.error.is_none());
+        assert!(!prompt.edit(key(KeyCode::Enter)));
+    }
+
+    #[test]
+    fn test_only_spaces_can_be_private() {
+        let mut space = NamePromptState::new(NewItem::Space {
+            workspace_id: "w1".to_string(),
+        });
+        space.toggle_private();
+        assert!(space.private);
+
+        let mut folder = NamePromptState::new(NewItem::Folder {
+            space_id: "s1".to_string(),
+        });
+        folder.toggle_private();
+        assert!(!folder.private);
+    }
+
+    #[test]
+    fn test_tag_prompt_asks_for_a_tag_name() {
+        let mut tag = NamePromptState::new(NewItem::Tag {
+            space_id: "s1".to_string(),
+            task_id: "t1".to_string(),
+        });
+        tag.toggle_private();
+        assert!(!tag.private);
+        assert_eq!(tag.submit(), None);
+        assert_eq!(tag.error.as_deref(), Some("Please enter a tag name"));
+        assert_eq!(tag.hints(), "Enter: Pick color | Esc: Cancel");
+    }
+}
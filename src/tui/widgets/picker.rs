@@ -0,0 +1,357 @@
+//! Filterable selection overlay shared by pickers
+//!
+//! A [`PickerState`] holds the items, the typed query and which items match
+//! it; [`render_picker`] draws it as a centered overlay. Items are matched on
+//! the text the label closure gives them, so any item type can be picked.
+
+use crate::tui::input::LineEditor;
+use crate::tui::layout::input_spans;
+use crate::tui::theme::Theme;
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::fmt;
+use std::sync::Arc;
+
+/// Text shown for an item, also what the query matches against
+pub type PickerLabel<T> = Arc<dyn Fn(&T) -> String + Send + Sync>;
+
+/// Items, query and selection of a filterable picker
+#[derive(Clone)]
+pub struct PickerState<T> {
+    title: String,
+    items: Vec<T>,
+    label: PickerLabel<T>,
+    query: String,
+    /// Cursor in `query`, in characters
+    cursor: usize,
+    /// Indices into `items` matching the query, in item order
+    filtered: Vec<usize>,
+    /// Position in `filtered` of the selection
+    selected: usize,
+}
+
+#[allow(dead_code)]
+impl<T> PickerState<T> {
+    /// A picker over `items`, labelled by `label`, with an empty query
+    pub fn new(
+        title: impl Into<String>,
+        items: Vec<T>,
+        label: impl Fn(&T) -> String + Send + Sync + 'static,
+    ) -> Self {
+        let mut picker = Self {
+            title: title.into(),
+            items,
+            label: Arc::new(label),
+            query: String::new(),
+            cursor: 0,
+            filtered: Vec::new(),
+            selected: 0,
+        };
+        picker.refilter();
+        picker
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Replace the items, keeping the query
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        self.refilter();
+    }
+
+    /// The label of `item`
+    pub fn label_of(&self, item: &T) -> String {
+        (self.label)(item)
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Replace the query, with the cursor at its end, and filter again
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.cursor = self.query.chars().count();
+        self.refilter();
+    }
+
+    /// Apply an editing key to the query, returning whether it was one
+    pub fn edit(&mut self, key: KeyEvent) -> bool {
+        let before = self.query.clone();
+        if !LineEditor::new(&mut self.query, &mut self.cursor).handle_key(key) {
+            return false;
+        }
+        if self.query != before {
+            self.refilter();
+        }
+        true
+    }
+
+    /// Items matching the query, in item order
+    pub fn filtered(&self) -> impl Iterator<Item = &T> {
+        self.filtered.iter().map(|&i| &self.items[i])
+    }
+
+    /// Number of items matching the query
+    pub fn match_count(&self) -> usize {
+        self.filtered.len()
+    }
+
+    /// Whether the query matches nothing
+    pub fn is_empty(&self) -> bool {
+        self.filtered.is_empty()
+    }
+
+    /// The selected item, `None` when nothing matches
+    pub fn selected_item(&self) -> Option<&T> {
+        self.selected_index().map(|i| &self.items[i])
+    }
+
+    /// Index into `items` of the selected item
+    pub fn selected_index(&self) -> Option<usize> {
+        self.filtered.get(self.selected).copied()
+    }
+
+    /// Position of the selection among the matches
+    pub fn selected_position(&self) -> Option<usize> {
+        (!self.filtered.is_empty()).then_some(self.selected)
+    }
+
+    /// Move down, wrapping from the last match to the first
+    pub fn select_next(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = (self.selected + 1) % self.filtered.len();
+        }
+    }
+
+    /// Move up, wrapping from the first match to the last
+    pub fn select_previous(&mut self) {
+        if !self.filtered.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.filtered.len() - 1);
+        }
+    }
+
+    /// Select the first item `predicate` accepts, if it matches the query
+    pub fn select_where(&mut self, predicate: impl Fn(&T) -> bool) -> bool {
+        match self
+            .filtered
+            .iter()
+            .position(|&i| predicate(&self.items[i]))
+        {
+            Some(position) => {
+                self.selected = position;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Recompute the matches, keeping the selected item when it still matches
+    /// and clamping the selection otherwise
+    fn refilter(&mut self) {
+        let previous = self.selected_index();
+        let terms: Vec<String> = self
+            .query
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect();
+        self.filtered = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                let label = (self.label)(item).to_lowercase();
+                terms.iter().all(|term| label.contains(term.as_str()))
+            })
+            .map(|(i, _)| i)
+            .collect();
+        self.selected = previous
+            .and_then(|p| self.filtered.iter().position(|&i| i == p))
+            .unwrap_or(0)
+            .min(self.filtered.len().saturating_sub(1));
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PickerState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PickerState")
+            .field("title", &self.title)
+            .field("items", &self.items)
+            .field("query", &self.query)
+            .field("cursor", &self.cursor)
+            .field("filtered", &self.filtered)
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+/// Render the picker as a centered overlay: query on top, matches below
+pub fn render_picker<T>(frame: &mut Frame, state: &PickerState<T>, area: Rect) {
+    let width = 50.min(area.width.saturating_sub(4));
+    let height = (state.items.len() as u16 + 5)
+        .clamp(8, area.height.saturating_sub(4).max(8))
+        .min(area.height);
+    let picker_area = Rect {
+        x: area.x + area.width.saturating_sub(width) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, picker_area);
+    let block = Block::default()
+        .title(format!(" {} ", state.title))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Theme::BACKGROUND));
+    let inner = block.inner(picker_area);
+    frame.render_widget(block, picker_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Query
+            Constraint::Min(1),    // Matches
+            Constraint::Length(1), // Hint
+        ])
+        .split(inner);
+
+    let mut query = vec![Span::styled("> ", Style::default().fg(Theme::PRIMARY))];
+    query.extend(input_spans(&state.query, state.cursor));
+    query.push(Span::styled(
+        format!("  {}/{}", state.match_count(), state.items.len()),
+        Style::default().fg(Theme::TEXT_DIM),
+    ));
+    let query = Line::from(query);
+    frame.render_widget(Paragraph::new(query), layout[0]);
+
+    if state.is_empty() {
+        let empty = Paragraph::new("No matches").style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(empty, layout[1]);
+    } else {
+        let items: Vec<ListItem> = state
+            .filtered()
+            .map(|item| ListItem::new(state.label_of(item)))
+            .collect();
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::SECONDARY)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▸ ");
+        let mut list_state = ListState::default().with_selected(state.selected_position());
+        frame.render_stateful_widget(list, layout[1], &mut list_state);
+    }
+
+    let hint = Paragraph::new("Type to filter | ↑/↓: Move | Enter: Pick | Esc: Close")
+        .style(Style::default().fg(Theme::TEXT_DIM));
+    frame.render_widget(hint, layout[2]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn picker() -> PickerState<&'static str> {
+        PickerState::new(
+            "Pick",
+            vec!["In Progress", "Todo", "Done", "In Review"],
+            |s: &&str| s.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_query_filters_case_insensitively() {
+        let mut picker = picker();
+        assert_eq!(picker.match_count(), 4);
+
+        picker.set_query("in");
+        assert_eq!(
+            picker.filtered().copied().collect::<Vec<_>>(),
+            ["In Progress", "In Review"]
+        );
+
+        // Every term must match
+        picker.set_query("in rev");
+        assert_eq!(
+            picker.filtered().copied().collect::<Vec<_>>(),
+            ["In Review"]
+        );
+
+        for _ in 0..3 {
+            assert!(picker.edit(key(KeyCode::Backspace)));
+        }
+        assert_eq!(picker.query(), "in ");
+        assert_eq!(picker.match_count(), 2);
+
+        // Editing keys work anywhere in the query
+        assert!(picker.edit(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL)));
+        assert!(picker.edit(key(KeyCode::Char('x'))));
+        assert_eq!(picker.query(), "xin ");
+        assert!(picker.is_empty());
+        assert!(!picker.edit(key(KeyCode::Enter)));
+    }
+
+    #[test]
+    fn test_selection_wraps() {
+        let mut picker = picker();
+        assert_eq!(picker.selected_item(), Some(&"In Progress"));
+
+        picker.select_previous();
+        assert_eq!(picker.selected_item(), Some(&"In Review"));
+        picker.select_next();
+        assert_eq!(picker.selected_item(), Some(&"In Progress"));
+    }
+
+    #[test]
+    fn test_selection_follows_item_or_clamps_when_filtering() {
+        let mut picker = picker();
+        picker.select_where(|s| *s == "In Review");
+
+        // Still matching: stays on the same item
+        picker.set_query("in");
+        assert_eq!(picker.selected_item(), Some(&"In Review"));
+        assert_eq!(picker.selected_position(), Some(1));
+
+        // Filtered out: falls back to the first match
+        picker.set_query("do");
+        assert_eq!(picker.selected_item(), Some(&"Todo"));
+        assert_eq!(picker.selected_index(), Some(1));
+    }
+
+    #[test]
+    fn test_no_matches() {
+        let mut picker = picker();
+        picker.edit(key(KeyCode::Char('x')));
+
+        assert!(picker.is_empty());
+        assert_eq!(picker.selected_item(), None);
+        assert_eq!(picker.selected_position(), None);
+        picker.select_next();
+        picker.select_previous();
+        assert_eq!(picker.selected_index(), None);
+
+        picker.edit(key(KeyCode::Backspace));
+        assert_eq!(picker.selected_item(), Some(&"In Progress"));
+    }
+}
@@ -0,0 +1,111 @@
+//! Recently opened tasks widget
+
+use crate::models::Task;
+use crate::tui::helpers::SelectableList;
+use crate::tui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph},
+    Frame,
+};
+
+/// A task from the access log
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentTask {
+    pub task_id: String,
+    pub list_id: String,
+    /// Cached task name (None if the task was never cached)
+    pub name: Option<String>,
+    /// Status from the latest fetch (None until the task is loaded)
+    pub status: Option<String>,
+}
+
+impl RecentTask {
+    /// Name to show, falling back to the task ID
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.task_id)
+    }
+}
+
+/// Recent tasks state, newest first
+#[derive(Debug, Clone)]
+pub struct RecentTasksState {
+    pub tasks: SelectableList<RecentTask>,
+}
+
+impl RecentTasksState {
+    pub fn new() -> Self {
+        Self {
+            tasks: SelectableList::empty(),
+        }
+    }
+
+    /// Replace the list and select the newest task
+    pub fn set_tasks(&mut self, tasks: Vec<RecentTask>) {
+        *self.tasks.items_mut() = tasks;
+        self.tasks.select_first();
+    }
+
+    /// Fill in names and statuses from freshly loaded tasks
+    pub fn apply_details(&mut self, tasks: &[Task]) {
+        for recent in self.tasks.items_mut() {
+            if let Some(task) = tasks.iter().find(|t| t.id == recent.task_id) {
+                recent.name = Some(task.name.clone());
+                recent.status = task.status.as_ref().map(|s| s.status.clone());
+            }
+        }
+    }
+}
+
+impl Default for RecentTasksState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn render_recent_tasks(frame: &mut Frame, state: &RecentTasksState, area: Rect) {
+    let block = crate::tui::layout::titled_block(" Recent Tasks ");
+
+    if state.tasks.items().is_empty() {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let empty =
+            Paragraph::new("No recently opened tasks").style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(empty, inner);
+        return;
+    }
+
+    let items: Vec<ListItem> = state
+        .tasks
+        .items()
+        .iter()
+        .map(|task| {
+            let name_style = if task.name.is_some() {
+                Style::default()
+            } else {
+                Style::default().fg(Theme::TEXT_DIM)
+            };
+            let mut spans = vec![Span::styled(task.label().to_string(), name_style)];
+            if let Some(status) = &task.status {
+                spans.push(Span::styled(
+                    format!("  · {}", status),
+                    Style::default().fg(Theme::TEXT_DIM),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .bg(Theme::SECONDARY)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ");
+
+    frame.render_stateful_widget(list, area, &mut state.tasks.state().clone());
+}
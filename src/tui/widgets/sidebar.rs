@@ -1,6 +1,8 @@
 //! Sidebar widget for workspace hierarchy navigation
 
-use crate::tui::helpers::SelectableList;
+use crate::models::Workspace;
+use crate::tui::helpers::{reselect_index, SelectableList};
+use crate::tui::widgets::avatar::Badge;
 use crate::tui::theme::Theme;
 use ratatui::{
     layout::Rect,
@@ -13,53 +15,256 @@ use ratatui::{
 /// Sidebar item types
 #[derive(Debug, Clone)]
 pub enum SidebarItem {
-    Workspace { name: String, id: String },
+    /// Non-selectable section header (e.g. "Folders (3)")
+    Header { label: String, count: usize },
+    Workspace {
+        name: String,
+        id: String,
+        /// Hex color for the workspace's badge
+        color: Option<String>,
+        member_count: Option<u32>,
+    },
     Space { name: String, id: String },
     Folder { name: String, id: String },
     List { name: String, id: String },
+    Document { name: String, id: String },
 }
 
 impl SidebarItem {
-    /// Get the ID of this sidebar item
+    /// Sidebar entry for a workspace
+    pub fn workspace(workspace: &Workspace) -> Self {
+        SidebarItem::Workspace {
+            name: workspace.name.clone(),
+            id: workspace.id.clone(),
+            color: workspace.color.clone(),
+            member_count: workspace.member_count,
+        }
+    }
+
+    /// Get the ID of this sidebar item (empty for headers)
     pub fn id(&self) -> &str {
         match self {
+            SidebarItem::Header { .. } => "",
             SidebarItem::Workspace { id, .. } => id,
             SidebarItem::Space { id, .. } => id,
             SidebarItem::Folder { id, .. } => id,
             SidebarItem::List { id, .. } => id,
+            SidebarItem::Document { id, .. } => id,
+        }
+    }
+
+    /// The item's display name (the label for headers)
+    pub fn name(&self) -> &str {
+        match self {
+            SidebarItem::Header { label, .. } => label,
+            SidebarItem::Workspace { name, .. }
+            | SidebarItem::Space { name, .. }
+            | SidebarItem::Folder { name, .. }
+            | SidebarItem::List { name, .. }
+            | SidebarItem::Document { name, .. } => name,
+        }
+    }
+
+    /// What kind of item this is, spelled out
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            SidebarItem::Header { .. } => "Section",
+            SidebarItem::Workspace { .. } => "Workspace",
+            SidebarItem::Space { .. } => "Space",
+            SidebarItem::Folder { .. } => "Folder",
+            SidebarItem::List { .. } => "List",
+            SidebarItem::Document { .. } => "Document",
+        }
+    }
+
+    /// Prefix marking the item's kind: a Nerd Font icon, or a letter when
+    /// `nerd_fonts` is off (empty for headers)
+    pub fn icon(&self, nerd_fonts: bool) -> &'static str {
+        match (self, nerd_fonts) {
+            (SidebarItem::Header { .. }, _) => "",
+            (SidebarItem::Workspace { .. }, true) => "\u{f1ad}",
+            (SidebarItem::Workspace { .. }, false) => "W",
+            (SidebarItem::Space { .. }, true) => "\u{f009}",
+            (SidebarItem::Space { .. }, false) => "S",
+            (SidebarItem::Folder { .. }, true) => "\u{f07b}",
+            (SidebarItem::Folder { .. }, false) => "F",
+            (SidebarItem::List { .. }, true) => "\u{f0ca}",
+            (SidebarItem::List { .. }, false) => "L",
+            (SidebarItem::Document { .. }, true) => "\u{f15c}",
+            (SidebarItem::Document { .. }, false) => "D",
+        }
+    }
+
+    /// Member count as shown, e.g. "5 members", when known
+    pub fn member_label(&self) -> Option<String> {
+        match self {
+            SidebarItem::Workspace {
+                member_count: Some(count),
+                ..
+            } => Some(format!(
+                "{} member{}",
+                count,
+                if *count == 1 { "" } else { "s" }
+            )),
+            _ => None,
+        }
+    }
+
+    /// Whether this item can receive the selection cursor
+    pub fn is_selectable(&self) -> bool {
+        !matches!(self, SidebarItem::Header { .. })
+    }
+
+    /// Whether this item holds others in the tree (workspaces, spaces, folders)
+    pub fn is_branch(&self) -> bool {
+        matches!(
+            self,
+            SidebarItem::Workspace { .. } | SidebarItem::Space { .. } | SidebarItem::Folder { .. }
+        )
+    }
+
+    /// Whether `other` is the same item: same kind and ID
+    fn is_same(&self, other: &SidebarItem) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self.id() == other.id()
+    }
+}
+
+/// An item of the sidebar tree
+#[derive(Debug, Clone)]
+struct TreeNode {
+    item: SidebarItem,
+    expanded: bool,
+    /// None until the children are first loaded
+    children: Option<Vec<TreeNode>>,
+}
+
+impl TreeNode {
+    fn new(item: SidebarItem) -> Self {
+        Self {
+            item,
+            expanded: false,
+            children: None,
+        }
+    }
+
+    /// The node for `item`, searching this node and everything under it
+    fn find(&self, item: &SidebarItem) -> Option<&TreeNode> {
+        if self.item.is_same(item) {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .flatten()
+            .find_map(|child| child.find(item))
+    }
+
+    /// Mutable [`find`](Self::find)
+    fn find_mut(&mut self, item: &SidebarItem) -> Option<&mut TreeNode> {
+        if self.item.is_same(item) {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .flatten()
+            .find_map(|child| child.find_mut(item))
+    }
+
+    /// Expand or collapse this node and everything under it; only nodes
+    /// with loaded children are expanded
+    fn set_expanded_all(&mut self, expanded: bool) {
+        self.expanded = expanded && self.children.is_some();
+        for child in self.children.iter_mut().flatten() {
+            child.set_expanded_all(expanded);
+        }
+    }
+
+    /// Append the visible items under and including this node, with depths
+    fn flatten_into(&self, depth: usize, rows: &mut Vec<(SidebarItem, usize)>) {
+        rows.push((self.item.clone(), depth));
+        if self.expanded {
+            for child in self.children.iter().flatten() {
+                child.flatten_into(depth + 1, rows);
+            }
         }
     }
 }
 
 /// Sidebar state
+///
+/// The sidebar shows either one level of the hierarchy, replaced as the user
+/// navigates, or (in tree mode) the workspaces with their spaces, folders and
+/// lists nested under them. In tree mode the list holds the visible rows of
+/// the tree, so selection works the same in both.
 #[derive(Debug, Clone)]
 pub struct SidebarState {
     list: SelectableList<SidebarItem>,
+    /// The tree, while the sidebar shows it
+    tree: Option<Vec<TreeNode>>,
+    /// Nesting depth of each row while the sidebar shows the tree
+    depths: Vec<usize>,
     /// Whether sidebar is visible
     pub visible: bool,
+    /// Prefix items with Nerd Font icons rather than letters
+    pub nerd_fonts: bool,
 }
 
 impl SidebarState {
     pub fn new() -> Self {
         Self {
             list: SelectableList::empty(),
+            tree: None,
+            depths: Vec::new(),
             visible: true,
+            nerd_fonts: false,
         }
     }
 
-    /// Select the first item
+    /// Use Nerd Font icons as item prefixes
+    pub fn with_nerd_fonts(mut self, nerd_fonts: bool) -> Self {
+        self.nerd_fonts = nerd_fonts;
+        self
+    }
+
+    /// Select the first selectable item, skipping headers
     pub fn select_first(&mut self) {
-        self.list.select_first();
+        let first = self.list.items().iter().position(|item| item.is_selectable());
+        if first.is_some() {
+            self.list.select(first);
+        }
     }
 
-    /// Move selection down
+    /// Move selection down, skipping headers (wraps to start)
     pub fn select_next(&mut self) {
-        self.list.select_next();
+        let len = self.list.items().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.list.state().selected().unwrap_or(len - 1);
+
+        for offset in 1..=len {
+            let next = (current + offset) % len;
+            if self.list.items()[next].is_selectable() {
+                self.list.select(Some(next));
+                return;
+            }
+        }
     }
 
-    /// Move selection up
+    /// Move selection up, skipping headers (wraps to end)
     pub fn select_previous(&mut self) {
-        self.list.select_previous();
+        let len = self.list.items().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.list.state().selected().unwrap_or(0);
+
+        for offset in 1..=len {
+            let prev = (current + len - offset) % len;
+            if self.list.items()[prev].is_selectable() {
+                self.list.select(Some(prev));
+                return;
+            }
+        }
     }
 
     /// Get currently selected item
@@ -69,7 +274,8 @@ impl SidebarState {
 
     /// Select item by ID, returns true if found
     pub fn select_by_id(&mut self, id: &str) -> bool {
-        self.list.select_by(|item| item.id() == id)
+        self.list
+            .select_by(|item| item.is_selectable() && item.id() == id)
     }
 
     /// Select item by index (public for testing)
@@ -84,14 +290,236 @@ impl SidebarState {
     }
 
     /// Get mutable sidebar items
+    ///
+    /// The items replace the tree, if it was shown.
     pub fn items_mut(&mut self) -> &mut Vec<SidebarItem> {
+        self.leave_tree();
         self.list.items_mut()
     }
 
+    /// Replace the items after a reload, keeping the selected item by ID
+    ///
+    /// When the same level is reloaded (the new items include the selected
+    /// item's kind), a selected item that is gone gives way to its nearest
+    /// neighbour and the scroll offset is kept. Items of another level start
+    /// at the first item. Returns whether the selected item was found again.
+    pub fn replace_items(&mut self, items: Vec<SidebarItem>) -> bool {
+        self.leave_tree();
+        let previous = self.list.selected().cloned();
+        let previous_index = self.list.state().selected();
+        let offset = self.list.state().offset();
+        let same_level = previous.as_ref().is_some_and(|selected| {
+            items
+                .iter()
+                .any(|item| std::mem::discriminant(item) == std::mem::discriminant(selected))
+        });
+
+        *self.list.items_mut() = items;
+        if !same_level {
+            self.list.select(None);
+            self.select_first();
+            return false;
+        }
+
+        let selected_id = previous.as_ref().map(SidebarItem::id);
+        let index = reselect_index(
+            self.list.items(),
+            |item| item.is_selectable().then(|| item.id()),
+            selected_id,
+            previous_index,
+        );
+        let kept = index
+            .and_then(|i| self.list.items().get(i))
+            .is_some_and(|item| Some(item.id()) == selected_id);
+        self.list.select(index);
+        *self.list.state_mut().offset_mut() = offset;
+        kept
+    }
+
     /// Get the internal list state for rendering
     pub fn state(&self) -> &ratatui::widgets::ListState {
         self.list.state()
     }
+
+    /// Whether the sidebar shows the tree
+    pub fn is_tree(&self) -> bool {
+        self.tree.is_some()
+    }
+
+    /// Nesting depth of the item at `index` (0 outside tree mode)
+    pub fn depth(&self, index: usize) -> usize {
+        self.depths.get(index).copied().unwrap_or(0)
+    }
+
+    /// Whether `item` is expanded in the tree
+    pub fn is_expanded(&self, item: &SidebarItem) -> bool {
+        self.tree
+            .as_ref()
+            .and_then(|roots| roots.iter().find_map(|root| root.find(item)))
+            .is_some_and(|node| node.expanded)
+    }
+
+    /// Show the tree with `roots` at the top, keeping what was loaded under
+    /// roots it already had and the selection. Returns whether the selected
+    /// item was found again.
+    pub fn set_tree_roots(&mut self, roots: Vec<SidebarItem>) -> bool {
+        let mut previous = self.tree.take().unwrap_or_default();
+        let nodes = roots
+            .into_iter()
+            .map(|item| {
+                match previous.iter().position(|node| node.item.is_same(&item)) {
+                    // Keep the children, but show the latest name
+                    Some(i) => TreeNode {
+                        item,
+                        ..previous.swap_remove(i)
+                    },
+                    None => TreeNode::new(item),
+                }
+            })
+            .collect();
+        self.tree = Some(nodes);
+        self.refresh_tree(None)
+    }
+
+    /// Put the loaded children of `parent` in the tree; returns false when
+    /// `parent` isn't in it
+    pub fn insert_children(&mut self, parent: &SidebarItem, children: Vec<SidebarItem>) -> bool {
+        let Some(node) = self.find_node_mut(parent) else {
+            return false;
+        };
+        node.children = Some(children.into_iter().map(TreeNode::new).collect());
+        self.refresh_tree(None);
+        true
+    }
+
+    /// Expand the selected item; returns it when its children still need
+    /// loading
+    pub fn expand_selected(&mut self) -> Option<SidebarItem> {
+        let selected = self.selected_item().filter(|item| item.is_branch())?.clone();
+        let node = self.find_node_mut(&selected)?;
+        node.expanded = true;
+        let needs_children = node.children.is_none();
+        self.refresh_tree(None);
+        needs_children.then_some(selected)
+    }
+
+    /// Collapse the selected item, or when it's a leaf or already collapsed,
+    /// select its parent
+    pub fn collapse_selected(&mut self) {
+        let Some(selected) = self.selected_item().cloned() else {
+            return;
+        };
+        if self.is_expanded(&selected) {
+            if let Some(node) = self.find_node_mut(&selected) {
+                node.expanded = false;
+            }
+            self.refresh_tree(None);
+        } else if let Some(index) = self.parent_index(self.list.state().selected()) {
+            self.list.select(Some(index));
+        }
+    }
+
+    /// Expand the selected item if collapsed, collapse it otherwise; returns
+    /// it when its children still need loading
+    pub fn toggle_selected(&mut self) -> Option<SidebarItem> {
+        let selected = self.selected_item()?.clone();
+        if self.is_expanded(&selected) {
+            self.collapse_selected();
+            None
+        } else {
+            self.expand_selected()
+        }
+    }
+
+    /// Expand every item whose children are loaded
+    pub fn expand_all(&mut self) {
+        for root in self.tree.iter_mut().flatten() {
+            root.set_expanded_all(true);
+        }
+        self.refresh_tree(None);
+    }
+
+    /// Collapse the whole tree, selecting the top-level item the selection
+    /// was under
+    pub fn collapse_all(&mut self) {
+        let root = self.path_to_selected().into_iter().next();
+        for node in self.tree.iter_mut().flatten() {
+            node.set_expanded_all(false);
+        }
+        self.refresh_tree(root);
+    }
+
+    /// The items from the top of the tree down to the selected one
+    pub fn path_to_selected(&self) -> Vec<SidebarItem> {
+        let mut path = Vec::new();
+        let mut index = self.list.state().selected();
+        while let Some(i) = index {
+            path.push(self.list.items()[i].clone());
+            index = self.parent_index(Some(i));
+        }
+        path.reverse();
+        path
+    }
+
+    /// Index of the row the item at `index` is nested under
+    fn parent_index(&self, index: Option<usize>) -> Option<usize> {
+        let index = index?;
+        let depth = self.depth(index);
+        if !self.is_tree() || depth == 0 {
+            return None;
+        }
+        (0..index).rev().find(|&i| self.depth(i) < depth)
+    }
+
+    fn find_node_mut(&mut self, item: &SidebarItem) -> Option<&mut TreeNode> {
+        self.tree
+            .as_mut()?
+            .iter_mut()
+            .find_map(|root| root.find_mut(item))
+    }
+
+    /// Rebuild the rows from the tree, selecting `select` or else the item
+    /// selected before (or its nearest visible parent). Returns whether that
+    /// item itself was selected.
+    fn refresh_tree(&mut self, select: Option<SidebarItem>) -> bool {
+        let Some(roots) = &self.tree else {
+            return false;
+        };
+        // The selection or, when it was collapsed away, its closest parent
+        let wanted: Vec<SidebarItem> = select
+            .into_iter()
+            .chain(self.path_to_selected().into_iter().rev())
+            .collect();
+        let mut rows = Vec::new();
+        for root in roots {
+            root.flatten_into(0, &mut rows);
+        }
+        let (items, depths) = rows.into_iter().unzip();
+        *self.list.items_mut() = items;
+        self.depths = depths;
+
+        let found = wanted.iter().enumerate().find_map(|(rank, wanted)| {
+            let index = self.list.items().iter().position(|item| item.is_same(wanted))?;
+            Some((rank, index))
+        });
+        match found {
+            Some((rank, index)) => {
+                self.list.select(Some(index));
+                rank == 0
+            }
+            None => {
+                self.list.select(None);
+                self.select_first();
+                false
+            }
+        }
+    }
+
+    /// Back to showing one level
+    fn leave_tree(&mut self) {
+        self.tree = None;
+        self.depths.clear();
+    }
 }
 
 impl Default for SidebarState {
@@ -105,29 +533,53 @@ pub fn render_sidebar(frame: &mut Frame, state: &SidebarState, area: Rect) {
     let items: Vec<ListItem> = state
         .items()
         .iter()
-        .map(|item| {
-            let (type_label, name, name_style) = match item {
-                SidebarItem::Workspace { name, .. } => (
-                    "WS",
-                    format!("{}", name),
-                    Style::default().add_modifier(Modifier::BOLD),
-                ),
-                SidebarItem::Space { name, .. } => ("SP", format!("{}", name), Style::default()),
-                SidebarItem::Folder { name, .. } => ("FL", format!("{}", name), Style::default()),
-                SidebarItem::List { name, .. } => (
-                    "LI",
-                    format!("{}", name),
-                    Style::default().fg(Theme::PRIMARY),
-                ),
+        .enumerate()
+        .map(|(index, item)| {
+            let mut spans = tree_prefix(state, index, item);
+            let name_style = match item {
+                SidebarItem::Header { label, count } => {
+                    let header = Span::styled(
+                        format!("{} ({})", label, count),
+                        Style::default()
+                            .fg(Theme::TEXT_DIM)
+                            .add_modifier(Modifier::BOLD),
+                    );
+                    return ListItem::new(Line::from(header));
+                }
+                SidebarItem::Workspace { name, color, .. } => {
+                    // Workspaces get a colored initials badge after the icon
+                    spans.extend([
+                        Span::styled(
+                            item.icon(state.nerd_fonts),
+                            Style::default().fg(Theme::SECONDARY),
+                        ),
+                        Span::raw(" "),
+                        Badge::new(name, color.as_deref()).span(),
+                        Span::raw(" "),
+                        Span::styled(name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                    ]);
+                    if let Some(members) = item.member_label() {
+                        spans.push(Span::styled(
+                            format!(" · {}", members),
+                            Style::default().fg(Theme::TEXT_DIM),
+                        ));
+                    }
+                    return ListItem::new(Line::from(spans));
+                }
+                SidebarItem::List { .. } => Style::default().fg(Theme::PRIMARY),
+                _ => Style::default(),
             };
 
-            let line = Line::from(vec![
-                Span::styled(type_label, Style::default().fg(Theme::SECONDARY)),
+            spans.extend([
+                Span::styled(
+                    item.icon(state.nerd_fonts),
+                    Style::default().fg(Theme::SECONDARY),
+                ),
                 Span::raw(" "),
-                Span::styled(name, name_style),
+                Span::styled(item.name().to_string(), name_style),
             ]);
 
-            ListItem::new(line)
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -143,6 +595,33 @@ pub fn render_sidebar(frame: &mut Frame, state: &SidebarState, area: Rect) {
     frame.render_stateful_widget(sidebar, area, &mut state.state().clone());
 }
 
+/// Indentation and expand marker of a tree row (nothing outside tree mode)
+fn tree_prefix(state: &SidebarState, index: usize, item: &SidebarItem) -> Vec<Span<'static>> {
+    if !state.is_tree() {
+        return Vec::new();
+    }
+    let marker = match (item.is_branch(), state.is_expanded(item)) {
+        (false, _) => "  ",
+        (true, true) => "▾ ",
+        (true, false) => "▹ ",
+    };
+    vec![
+        Span::raw("  ".repeat(state.depth(index))),
+        Span::styled(marker, Style::default().fg(Theme::TEXT_DIM)),
+    ]
+}
+
+/// Whether the terminal named by `TERM` can draw Nerd Font icons
+///
+/// The Linux console and dumb or legacy terminals only have their built-in
+/// font; anything else might use a patched one, which the config decides.
+pub fn terminal_supports_nerd_fonts(term: Option<&str>) -> bool {
+    !matches!(
+        term,
+        Some("dumb" | "linux" | "vt100" | "vt102" | "vt220" | "ansi")
+    )
+}
+
 /// Get help hints for sidebar
 #[allow(dead_code)]
 pub fn get_sidebar_hints() -> &'static str {
@@ -153,6 +632,71 @@ pub fn get_sidebar_hints() -> &'static str {
 mod tests {
     use super::*;
 
+    fn list(id: &str) -> SidebarItem {
+        SidebarItem::List {
+            name: id.to_uppercase(),
+            id: id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_icons_or_letters_by_kind() {
+        let folder = SidebarItem::Folder {
+            name: "Backend".to_string(),
+            id: "f1".to_string(),
+        };
+        assert_eq!(folder.icon(false), "F");
+        assert_eq!(list("a").icon(false), "L");
+        assert_eq!(list("a").icon(true), "\u{f0ca}");
+        let header = SidebarItem::Header {
+            label: "Lists".to_string(),
+            count: 1,
+        };
+        assert_eq!(header.icon(true), "");
+    }
+
+    #[test]
+    fn test_terminal_supports_nerd_fonts() {
+        assert!(terminal_supports_nerd_fonts(Some("xterm-256color")));
+        assert!(terminal_supports_nerd_fonts(None));
+        assert!(!terminal_supports_nerd_fonts(Some("linux")));
+        assert!(!terminal_supports_nerd_fonts(Some("dumb")));
+    }
+
+    #[test]
+    fn test_replace_items_keeps_selection_on_reload() {
+        let mut state = SidebarState::new();
+        *state.items_mut() = vec![list("a"), list("b"), list("c")];
+        state.select_by_id("c");
+
+        assert!(state.replace_items(vec![list("a"), list("c"), list("b")]));
+        assert_eq!(state.selected_item().map(SidebarItem::id), Some("c"));
+
+        // The selected list was deleted: its neighbour takes its place
+        assert!(!state.replace_items(vec![list("a"), list("b")]));
+        assert_eq!(state.selected_item().map(SidebarItem::id), Some("b"));
+    }
+
+    #[test]
+    fn test_replace_items_with_another_level_selects_first() {
+        let mut state = SidebarState::new();
+        *state.items_mut() = vec![list("a"), list("b")];
+        state.select_by_id("b");
+
+        let spaces = vec![
+            SidebarItem::Header {
+                label: "Spaces".to_string(),
+                count: 1,
+            },
+            SidebarItem::Space {
+                name: "Space".to_string(),
+                id: "b".to_string(),
+            },
+        ];
+        assert!(!state.replace_items(spaces));
+        assert_eq!(state.state().selected(), Some(1));
+    }
+
     #[test]
     fn test_select_by_id_finds_matching_item() {
         let mut state = SidebarState::new();
@@ -160,14 +704,20 @@ mod tests {
             SidebarItem::Workspace {
                 name: "WS1".to_string(),
                 id: "ws-1".to_string(),
+                color: None,
+                member_count: None,
             },
             SidebarItem::Workspace {
                 name: "WS2".to_string(),
                 id: "ws-2".to_string(),
+                color: None,
+                member_count: None,
             },
             SidebarItem::Workspace {
                 name: "WS3".to_string(),
                 id: "ws-3".to_string(),
+                color: None,
+                member_count: None,
             },
         ];
 
@@ -184,10 +734,14 @@ mod tests {
             SidebarItem::Workspace {
                 name: "WS1".to_string(),
                 id: "ws-1".to_string(),
+                color: None,
+                member_count: None,
             },
             SidebarItem::Workspace {
                 name: "WS2".to_string(),
                 id: "ws-2".to_string(),
+                color: None,
+                member_count: None,
             },
         ];
 
@@ -215,6 +769,8 @@ mod tests {
         let workspace = SidebarItem::Workspace {
             name: "Test".to_string(),
             id: "ws-123".to_string(),
+            color: None,
+            member_count: None,
         };
         assert_eq!(workspace.id(), "ws-123");
 
@@ -236,4 +792,146 @@ mod tests {
         };
         assert_eq!(list.id(), "lt-abc");
     }
+
+    fn sectioned_items() -> Vec<SidebarItem> {
+        vec![
+            SidebarItem::Header {
+                label: "Folders".to_string(),
+                count: 1,
+            },
+            SidebarItem::Folder {
+                name: "Folder".to_string(),
+                id: "fd-1".to_string(),
+            },
+            SidebarItem::Header {
+                label: "Lists".to_string(),
+                count: 1,
+            },
+            SidebarItem::List {
+                name: "List".to_string(),
+                id: "lt-1".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_select_first_skips_header() {
+        let mut state = SidebarState::new();
+        *state.items_mut() = sectioned_items();
+
+        state.select_first();
+
+        assert_eq!(state.state().selected(), Some(1));
+    }
+
+    #[test]
+    fn test_select_next_and_previous_skip_headers() {
+        let mut state = SidebarState::new();
+        *state.items_mut() = sectioned_items();
+        state.select_first();
+
+        state.select_next();
+        assert_eq!(state.state().selected(), Some(3), "Should skip Lists header");
+
+        state.select_next();
+        assert_eq!(state.state().selected(), Some(1), "Should wrap past Folders header");
+
+        state.select_previous();
+        assert_eq!(state.state().selected(), Some(3), "Should wrap backwards to last item");
+    }
+
+    #[test]
+    fn test_header_is_not_selectable() {
+        let mut state = SidebarState::new();
+        *state.items_mut() = vec![SidebarItem::Header {
+            label: "Docs".to_string(),
+            count: 0,
+        }];
+
+        state.select_first();
+        state.select_next();
+
+        assert_eq!(state.state().selected(), None);
+        assert!(!state.select_by_id(""));
+    }
+
+    fn workspace(id: &str) -> SidebarItem {
+        SidebarItem::Workspace {
+            name: id.to_uppercase(),
+            id: id.to_string(),
+            color: None,
+            member_count: None,
+        }
+    }
+
+    fn space(id: &str) -> SidebarItem {
+        SidebarItem::Space {
+            name: id.to_uppercase(),
+            id: id.to_string(),
+        }
+    }
+
+    fn rows(state: &SidebarState) -> Vec<(usize, &str)> {
+        (0..state.items().len())
+            .map(|i| (state.depth(i), state.items()[i].id()))
+            .collect()
+    }
+
+    #[test]
+    fn test_tree_loads_children_on_first_expand_only() {
+        let mut state = SidebarState::new();
+        state.set_tree_roots(vec![workspace("w1"), workspace("w2")]);
+        assert!(state.is_tree());
+        assert_eq!(rows(&state), [(0, "w1"), (0, "w2")]);
+
+        // Children are asked for once, and show when they arrive
+        let needs = state.expand_selected();
+        assert_eq!(needs.as_ref().map(SidebarItem::id), Some("w1"));
+        assert!(state.insert_children(&workspace("w1"), vec![space("s1"), space("s2")]));
+        assert_eq!(rows(&state), [(0, "w1"), (1, "s1"), (1, "s2"), (0, "w2")]);
+        assert_eq!(state.selected_item().map(SidebarItem::id), Some("w1"));
+
+        assert!(state.toggle_selected().is_none());
+        assert_eq!(rows(&state), [(0, "w1"), (0, "w2")]);
+        assert!(state.toggle_selected().is_none(), "Already loaded");
+        assert_eq!(state.items().len(), 4);
+
+        // A reload of the workspaces keeps what was loaded under them
+        state.set_tree_roots(vec![workspace("w1"), workspace("w2")]);
+        assert_eq!(state.items().len(), 4);
+        assert!(!state.insert_children(&space("gone"), Vec::new()));
+    }
+
+    #[test]
+    fn test_tree_collapse_selects_parent_and_all() {
+        let mut state = SidebarState::new();
+        state.set_tree_roots(vec![workspace("w1")]);
+        state.expand_selected();
+        state.insert_children(&workspace("w1"), vec![space("s1")]);
+        state.select_next();
+        state.expand_selected();
+        state.insert_children(&space("s1"), vec![list("a"), list("b")]);
+        state.select_by_id("b");
+        assert_eq!(rows(&state), [(0, "w1"), (1, "s1"), (2, "a"), (2, "b")]);
+        let path: Vec<_> = state.path_to_selected().iter().map(|i| i.id().to_string()).collect();
+        assert_eq!(path, ["w1", "s1", "b"]);
+
+        // h on a leaf goes to its parent, then collapses it
+        state.collapse_selected();
+        assert_eq!(state.selected_item().map(SidebarItem::id), Some("s1"));
+        state.collapse_selected();
+        assert_eq!(rows(&state), [(0, "w1"), (1, "s1")]);
+
+        state.expand_all();
+        assert_eq!(state.items().len(), 4);
+        state.select_by_id("a");
+        state.collapse_all();
+        assert_eq!(rows(&state), [(0, "w1")]);
+        assert_eq!(state.selected_item().map(SidebarItem::id), Some("w1"));
+
+        // Flat items leave the tree
+        state.replace_items(vec![list("a")]);
+        assert!(!state.is_tree());
+        assert_eq!(state.depth(0), 0);
+    }
 }
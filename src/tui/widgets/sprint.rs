@@ -0,0 +1,241 @@
+//! Sprint overview widget: burndown chart, sprint stats and points completed per day
+
+use crate::models::SprintOverview;
+use crate::tui::theme::Theme;
+use crate::utils::format_timestamp;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{Axis, Chart, Dataset, GraphType, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Sprint overview state
+#[derive(Debug, Clone, Default)]
+pub struct SprintState {
+    /// ID of the sprint view being shown
+    pub view_id: String,
+    /// Loaded overview (None while loading)
+    pub overview: Option<SprintOverview>,
+}
+
+impl SprintState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Ideal burndown line from total points at day 0 to zero at the last sprint day
+pub fn ideal_burndown(overview: &SprintOverview) -> Vec<(f64, f64)> {
+    vec![
+        (0.0, overview.total_points as f64),
+        (overview.sprint_days() as f64, 0.0),
+    ]
+}
+
+/// Actual burndown points, one per elapsed day
+pub fn actual_burndown(overview: &SprintOverview) -> Vec<(f64, f64)> {
+    overview
+        .burndown
+        .iter()
+        .enumerate()
+        .map(|(day, remaining)| (day as f64, *remaining as f64))
+        .collect()
+}
+
+pub fn render_sprint(frame: &mut Frame, state: &SprintState, area: Rect) {
+    let title = match &state.overview {
+        Some(overview) => format!(" Sprint: {} ", overview.name),
+        None => " Sprint ".to_string(),
+    };
+    let block = crate::tui::layout::titled_block(title);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(overview) = &state.overview else {
+        let loading =
+            Paragraph::new("Loading sprint...").style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(loading, inner_area);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Stats
+            Constraint::Min(6),    // Burndown
+            Constraint::Length(4), // Points completed per day
+        ])
+        .split(inner_area);
+
+    render_stats(frame, overview, chunks[0]);
+    render_burndown(frame, overview, chunks[1]);
+    render_daily_completed(frame, overview, chunks[2]);
+}
+
+/// Dates, points, tasks and velocity of the sprint, one line each
+pub fn stats_lines(overview: &SprintOverview) -> Vec<Line<'static>> {
+    let label = Style::default().fg(Theme::TEXT_DIM);
+    vec![
+        Line::from(vec![
+            Span::styled("Dates: ", label),
+            Span::raw(format!(
+                "{} → {} ({} days)",
+                format_timestamp(overview.start_date),
+                format_timestamp(overview.end_date),
+                overview.sprint_days()
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Points: ", label),
+            Span::styled(
+                format!(
+                    "{}/{} ({}%)",
+                    overview.completed_points,
+                    overview.total_points,
+                    overview.completion_percent()
+                ),
+                Style::default()
+                    .fg(Theme::SUCCESS)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Tasks: ", label),
+            Span::raw(format!(
+                "{}/{}",
+                overview.tasks_completed, overview.tasks_total
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Velocity: ", label),
+            Span::raw(format!("{:.1} pts/day", overview.velocity)),
+        ]),
+    ]
+}
+
+fn render_stats(frame: &mut Frame, overview: &SprintOverview, area: Rect) {
+    frame.render_widget(Paragraph::new(stats_lines(overview)), area);
+}
+
+fn render_burndown(frame: &mut Frame, overview: &SprintOverview, area: Rect) {
+    let ideal = ideal_burndown(overview);
+    let actual = actual_burndown(overview);
+    let max_days = overview.sprint_days() as f64;
+    let max_points = (overview.total_points as f64).max(1.0);
+
+    let datasets = vec![
+        Dataset::default()
+            .name("Ideal")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Theme::SECONDARY))
+            .data(&ideal),
+        Dataset::default()
+            .name("Remaining")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Theme::PRIMARY))
+            .data(&actual),
+    ];
+
+    let chart = Chart::new(datasets)
+        .block(crate::tui::layout::titled_block(" Burndown "))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(Theme::TEXT_DIM))
+                .bounds([0.0, max_days])
+                .labels(["Day 0".to_string(), format!("Day {}", max_days)]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(Theme::TEXT_DIM))
+                .bounds([0.0, max_points])
+                .labels(["0".to_string(), format!("{}", max_points)]),
+        );
+
+    frame.render_widget(chart, area);
+}
+
+fn render_daily_completed(frame: &mut Frame, overview: &SprintOverview, area: Rect) {
+    let data: Vec<u64> = overview
+        .daily_completed
+        .iter()
+        .map(|points| points.round() as u64)
+        .collect();
+
+    let sparkline = Sparkline::default()
+        .block(crate::tui::layout::titled_block(" Points completed per day "))
+        .style(Style::default().fg(Theme::SUCCESS))
+        .data(&data);
+
+    frame.render_widget(sparkline, area);
+}
+
+pub fn get_sprint_hints() -> &'static str {
+    "r: Refresh | Esc: Back"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn overview() -> SprintOverview {
+        SprintOverview {
+            name: "Sprint 7".to_string(),
+            start_date: 1_700_000_000_000,
+            end_date: 1_700_000_000_000 + 4 * 24 * 60 * 60 * 1000,
+            total_points: 10.0,
+            completed_points: 6.0,
+            tasks_total: 5,
+            tasks_completed: 3,
+            velocity: 3.0,
+            burndown: vec![10.0, 7.0, 4.0],
+            daily_completed: vec![3.0, 3.0],
+        }
+    }
+
+    #[test]
+    fn test_ideal_burndown_spans_sprint() {
+        assert_eq!(ideal_burndown(&overview()), vec![(0.0, 10.0), (4.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_actual_burndown_one_point_per_day() {
+        assert_eq!(
+            actual_burndown(&overview()),
+            vec![(0.0, 10.0), (1.0, 7.0), (2.0, 4.0)]
+        );
+    }
+
+    #[test]
+    fn test_render_sprint_uses_braille_and_shows_stats() {
+        let state = SprintState {
+            view_id: "view-1".to_string(),
+            overview: Some(overview()),
+        };
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal
+            .draw(|frame| render_sprint(frame, &state, frame.area()))
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(content.contains("Sprint: Sprint 7"));
+        assert!(content.contains("6/10 (60%)"));
+        assert!(content.contains("3/5"));
+        assert!(
+            content
+                .chars()
+                .any(|c| ('\u{2801}'..='\u{28FF}').contains(&c)),
+            "Burndown should be drawn with braille characters"
+        );
+    }
+}
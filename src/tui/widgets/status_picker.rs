@@ -130,9 +130,9 @@ pub fn render_status_picker(
 }
 
 /// Parse a hex color string to a ratatui Color
-fn parse_hex_color(hex: &str) -> Option<Color> {
+pub(crate) fn parse_hex_color(hex: &str) -> Option<Color> {
     let hex = hex.trim_start_matches('#');
-    if hex.len() == 6 {
+    if hex.len() == 6 && hex.is_ascii() {
         let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
         let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
         let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
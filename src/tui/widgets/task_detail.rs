@@ -1,9 +1,14 @@
 //! Task detail widget
 
-use crate::models::Task;
+use crate::models::time_entry::format_duration;
+use crate::models::time_in_status::format_status_duration;
+use crate::models::task::CustomField;
+use crate::models::{Task, TimeEntry, TimeInStatus};
 use crate::tui::app::TaskCreationField;
 use crate::tui::layout::ScrollState;
 use crate::tui::theme::Theme;
+use crate::tui::widgets::document::markdown_to_text;
+use crate::tui::widgets::status_picker::parse_hex_color;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -12,6 +17,12 @@ use ratatui::{
     Frame,
 };
 
+/// Rows of the Related Tasks section in the narrow layout, header included
+const RELATED_ROWS: usize = 5;
+
+/// Shown while the task is being edited
+const EDIT_HINT: &str = "Ctrl+E: edit description in $EDITOR, Ctrl+S to save, Esc to cancel";
+
 /// Task detail state
 #[derive(Debug, Clone)]
 pub struct TaskDetailState {
@@ -20,6 +31,19 @@ pub struct TaskDetailState {
     pub creating: bool,
     /// Scroll state for the description panel
     pub description_scroll: ScrollState,
+    /// Time logged on the task, oldest first
+    pub time_entries: Vec<TimeEntry>,
+    /// Time spent in each status, in workflow order
+    pub time_in_status: Vec<TimeInStatus>,
+    /// Show the description source verbatim instead of rendering its markdown
+    pub raw_description: bool,
+    /// The Related Tasks section has focus, for adding and removing links
+    pub links_focused: bool,
+    /// Selected row of the Related Tasks section
+    pub link_selected: usize,
+    /// Description edited in the external editor, not yet saved:
+    /// (task ID, text)
+    pub staged_description: Option<(String, String)>,
 }
 
 impl TaskDetailState {
@@ -29,6 +53,47 @@ impl TaskDetailState {
             editing: false,
             creating: false,
             description_scroll: ScrollState::new(),
+            time_entries: Vec::new(),
+            time_in_status: Vec::new(),
+            raw_description: false,
+            links_focused: false,
+            link_selected: 0,
+            staged_description: None,
+        }
+    }
+
+    /// Show `task`, dropping the edit and Related Tasks state of the task
+    /// shown before
+    pub fn open(&mut self, task: Task) {
+        self.task = Some(task);
+        self.editing = false;
+        self.staged_description = None;
+        self.links_focused = false;
+        self.link_selected = 0;
+    }
+
+    /// The staged description edit, if it belongs to the task shown
+    pub fn staged_text(&self) -> Option<&str> {
+        match (&self.task, &self.staged_description) {
+            (Some(task), Some((task_id, text))) if &task.id == task_id => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The description as shown: rendered markdown, or the source when
+    /// `raw_description` is set. A staged edit is shown in its place.
+    pub fn description_text(&self) -> String {
+        let source = self
+            .staged_text()
+            .map(str::to_string)
+            .or_else(|| self.task.as_ref().and_then(|t| t.description_source()));
+        let Some(source) = source else {
+            return "No description".to_string();
+        };
+        if self.raw_description {
+            source
+        } else {
+            markdown_to_text(&source).trim_end().to_string()
         }
     }
 }
@@ -68,6 +133,26 @@ pub fn render_task_detail(
 
     let inner_area = block.inner(area);
 
+    let group_rows = match &state.task {
+        Some(task) if !task.group_assignees.is_empty() => 1,
+        _ => 0,
+    };
+    let due_rows = match &state.task {
+        Some(task) if task.due_date.is_some() || task.recurrence.is_some() => 1,
+        _ => 0,
+    };
+    let timeline_rows = if state.time_in_status.is_empty() { 0 } else { 2 };
+    let mut related = related_task_lines(state);
+    if related.len() > RELATED_ROWS {
+        // Keep the header, and scroll the rows to the selected one
+        let skip = state
+            .link_selected
+            .saturating_sub(RELATED_ROWS - 2)
+            .min(related.len() - RELATED_ROWS);
+        related.drain(1..1 + skip);
+        related.truncate(RELATED_ROWS);
+    }
+
     // Split into task info and description with better ratio
     let inner = Layout::default()
         .direction(Direction::Vertical)
@@ -77,6 +162,10 @@ pub fn render_task_detail(
             Constraint::Length(1), // Status
             Constraint::Length(1), // Priority
             Constraint::Length(1), // Assignees
+            Constraint::Length(group_rows), // Group assignees
+            Constraint::Length(due_rows), // Due date and recurrence
+            Constraint::Length(timeline_rows), // Time in status
+            Constraint::Length(related.len() as u16), // Related tasks
             Constraint::Min(2),    // Description (flexible space)
         ])
         .split(inner_area);
@@ -84,85 +173,444 @@ pub fn render_task_detail(
     if let Some(task) = &state.task {
         frame.render_widget(Paragraph::new(format!("Name: {}", task.name)), inner[0]);
 
-        let status = task
-            .status
-            .as_ref()
-            .map(|s| s.status.as_str())
-            .unwrap_or("None");
-        frame.render_widget(Paragraph::new(format!("Status: {}", status)), inner[1]);
-
-        let priority = task
-            .priority
-            .as_ref()
-            .map(|p| p.priority.as_str())
-            .unwrap_or("None");
-        frame.render_widget(Paragraph::new(format!("Priority: {}", priority)), inner[2]);
-
-        // Render assignees
-        let assignees_str = if task.assignees.is_empty() {
+        frame.render_widget(
+            Paragraph::new(format!("Status: {}", status_text(task))),
+            inner[1],
+        );
+        frame.render_widget(
+            Paragraph::new(format!("Priority: {}", priority_text(task))),
+            inner[2],
+        );
+
+        // Render assignees, with groups as @mentions
+        let labels = task.assignee_labels();
+        let assignees_str = if labels.is_empty() {
             "None".to_string()
         } else {
-            task.assignees
-                .iter()
-                .map(|u| u.username.clone())
-                .collect::<Vec<_>>()
-                .join(", ")
+            labels.join(", ")
         };
         frame.render_widget(
             Paragraph::new(format!("Assignees: {}", assignees_str)),
             inner[3],
         );
 
-        let desc = task
-            .description
-            .as_ref()
-            .map(|d| d.as_text())
-            .unwrap_or_else(|| "No description".to_string());
-
-        // Calculate description content height for scroll state
-        let available_height = inner[4].height as usize;
-        let available_width = inner[4].width.saturating_sub(4) as usize; // Account for borders
-
-        // Estimate content height by counting wrapped lines
-        let content_height = estimate_wrapped_lines(&desc, available_width);
-
-        // Update scroll state
-        let mut scroll_state = state.description_scroll.clone();
-        scroll_state.update(content_height, available_height);
-
-        // Render description with text wrapping and scrolling
-        let desc_paragraph = Paragraph::new(desc)
-            .block(
-                Block::default()
-                    .title(" Description ")
-                    .borders(Borders::ALL)
-                    .style(Style::default().fg(Theme::PRIMARY)),
-            )
-            .wrap(Wrap { trim: true });
-
-        // Render with scroll offset
-        frame.render_widget(desc_paragraph, inner[4]);
-
-        // Render scroll indicator if needed
-        if scroll_state.scrollable {
-            crate::tui::layout::render_scroll_indicator(
-                frame,
+        if !task.group_assignees.is_empty() {
+            let groups = task
+                .group_assignees
+                .iter()
+                .map(|g| {
+                    let count = g.member_count();
+                    let noun = if count == 1 { "member" } else { "members" };
+                    format!("👥 {} ({} {})", g.name, count, noun)
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            frame.render_widget(
+                Paragraph::new(format!("Groups: {}", groups)),
                 inner[4],
-                content_height,
-                scroll_state.offset,
             );
         }
+
+        if due_rows > 0 {
+            let mut spans = vec![Span::raw(format!("Due: {}", due_text(task)))];
+            if let Some(recurrence) = &task.recurrence {
+                spans.push(Span::styled(
+                    format!("  🔁 Recurs: {}", recurrence.label()),
+                    Style::default().fg(Theme::SECONDARY),
+                ));
+            }
+            frame.render_widget(Paragraph::new(Line::from(spans)), inner[5]);
+        }
+
+        if timeline_rows > 0 {
+            let lines = time_in_status_lines(&state.time_in_status, inner[6].width as usize);
+            frame.render_widget(Paragraph::new(lines), inner[6]);
+        }
+
+        if !related.is_empty() {
+            frame.render_widget(Paragraph::new(related), inner[7]);
+        }
+
+        render_description(frame, state, inner[8]);
     } else {
         frame.render_widget(Paragraph::new("No task selected"), inner[0]);
     }
 
     if state.editing {
-        let edit_hint = Paragraph::new("Press Ctrl+S to save, Esc to cancel")
+        let edit_hint = Paragraph::new(EDIT_HINT)
+            .style(Style::default().fg(Theme::WARNING));
+        frame.render_widget(edit_hint, inner[8]);
+    }
+}
+
+/// Render the task metadata on its own, as the left column of the wide
+/// task detail layout
+pub fn render_task_metadata(frame: &mut Frame, state: &TaskDetailState, area: Rect) {
+    let block = Block::default()
+        .title(" Task Detail ")
+        .borders(Borders::ALL)
+        .style(Style::default().bg(Theme::BACKGROUND));
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let mut lines = match &state.task {
+        Some(task) => metadata_lines(task),
+        None => vec![Line::from("No task selected")],
+    };
+    if state.task.is_some() && !state.time_in_status.is_empty() {
+        // Less the padding on either side
+        let width = inner_area.width.saturating_sub(2) as usize;
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Time in status",
+            Style::default().fg(Theme::SECONDARY),
+        )));
+        lines.extend(time_in_status_lines(&state.time_in_status, width));
+    }
+    let related = related_task_lines(state);
+    if !related.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(related);
+    }
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(Block::default().padding(ratatui::widgets::Padding::uniform(1)))
+            .wrap(Wrap { trim: true }),
+        inner_area,
+    );
+
+    if state.editing {
+        let hint_area = Rect {
+            y: inner_area.bottom().saturating_sub(1),
+            height: inner_area.height.min(1),
+            ..inner_area
+        };
+        let edit_hint = Paragraph::new(EDIT_HINT)
             .style(Style::default().fg(Theme::WARNING));
-        frame.render_widget(edit_hint, inner[4]);
+        frame.render_widget(edit_hint, hint_area);
     }
 }
 
+/// Render the description pane on its own, as the top of the right column
+/// of the wide task detail layout
+pub fn render_task_description(frame: &mut Frame, state: &TaskDetailState, area: Rect) {
+    if state.task.is_some() {
+        render_description(frame, state, area);
+    }
+}
+
+/// The task's status as shown, "None" when unset
+pub(crate) fn status_text(task: &Task) -> String {
+    task.status
+        .as_ref()
+        .map(|s| s.status.clone())
+        .unwrap_or_else(|| "None".to_string())
+}
+
+/// The task's priority as shown, "None" when unset
+pub(crate) fn priority_text(task: &Task) -> String {
+    task.priority
+        .as_ref()
+        .map(|p| p.priority.clone())
+        .unwrap_or_else(|| "None".to_string())
+}
+
+/// The task's due date as shown, "None" when unset
+pub(crate) fn due_text(task: &Task) -> String {
+    task.due_date
+        .map(crate::utils::format_timestamp)
+        .unwrap_or_else(|| "None".to_string())
+}
+
+/// Metadata rows for the wide layout, which has room for tags and custom fields
+pub(crate) fn metadata_lines(task: &Task) -> Vec<Line<'static>> {
+    let row = |name: &str, value: String| {
+        Line::from(vec![
+            Span::styled(
+                format!("{}: ", name),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(value),
+        ])
+    };
+    let or_none = |values: Vec<String>| {
+        if values.is_empty() {
+            "None".to_string()
+        } else {
+            values.join(", ")
+        }
+    };
+
+    let mut lines = vec![
+        row("Name", task.name.clone()),
+        row("Status", status_text(task)),
+        row("Priority", priority_text(task)),
+        row("Assignees", or_none(task.assignee_labels())),
+    ];
+
+    if !task.group_assignees.is_empty() {
+        let groups = task
+            .group_assignees
+            .iter()
+            .map(|g| {
+                let count = g.member_count();
+                let noun = if count == 1 { "member" } else { "members" };
+                format!("👥 {} ({} {})", g.name, count, noun)
+            })
+            .collect();
+        lines.push(row("Groups", or_none(groups)));
+    }
+
+    lines.push(row("Due", due_text(task)));
+    if let Some(recurrence) = &task.recurrence {
+        lines.push(Line::from(Span::styled(
+            format!("🔁 Recurs: {}", recurrence.label()),
+            Style::default().fg(Theme::SECONDARY),
+        )));
+    }
+
+    let tags = task.tags.iter().map(|t| t.name.clone()).collect();
+    lines.push(row("Tags", or_none(tags)));
+
+    let fields: Vec<(String, String)> = task
+        .custom_fields
+        .iter()
+        .filter_map(|f| custom_field_value(f).map(|v| (f.name.clone(), v)))
+        .collect();
+    if !fields.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Custom fields",
+            Style::default().fg(Theme::SECONDARY),
+        )));
+        lines.extend(fields.into_iter().map(|(name, value)| row(&name, value)));
+    }
+
+    lines
+}
+
+/// The Related Tasks section: a header, then one row per dependency or link
+/// with an icon for how it relates. Left out when there is nothing to show,
+/// unless the section has focus.
+pub(crate) fn related_task_lines(state: &TaskDetailState) -> Vec<Line<'static>> {
+    let Some(task) = &state.task else {
+        return Vec::new();
+    };
+    let related = task.related_tasks();
+    if related.is_empty() && !state.links_focused {
+        return Vec::new();
+    }
+
+    let dim = Style::default().fg(Theme::TEXT_DIM);
+    let mut header = vec![Span::styled(
+        "Related Tasks",
+        Style::default().fg(Theme::SECONDARY),
+    )];
+    if state.links_focused {
+        header.push(Span::styled("  n: Link | d: Unlink | Esc: Done", dim));
+    }
+    let mut lines = vec![Line::from(header)];
+    if related.is_empty() {
+        lines.push(Line::styled("  None", dim));
+    }
+    for (i, entry) in related.into_iter().enumerate() {
+        let name = match entry.name {
+            Some(name) => format!("{} ({})", name, entry.task_id),
+            None => entry.task_id,
+        };
+        let style = if state.links_focused && i == state.link_selected {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!("{} {:<10} {}", entry.kind.icon(), entry.kind.label(), name),
+                style,
+            ),
+        ]));
+    }
+    lines
+}
+
+/// Time in status as a bar of `width` cells, each status colored and sized by
+/// its share of the time, over a legend of statuses and durations
+pub(crate) fn time_in_status_lines(
+    durations: &[TimeInStatus],
+    width: usize,
+) -> Vec<Line<'static>> {
+    let color = |entry: &TimeInStatus| {
+        entry
+            .status
+            .color
+            .as_deref()
+            .and_then(parse_hex_color)
+            .unwrap_or(Theme::SECONDARY)
+    };
+    let ms: Vec<i64> = durations.iter().map(|d| d.duration_ms.max(0)).collect();
+    let bar = durations
+        .iter()
+        .zip(bar_widths(&ms, width))
+        .filter(|(_, cells)| *cells > 0)
+        .map(|(entry, cells)| {
+            Span::styled("█".repeat(cells), Style::default().fg(color(entry)))
+        })
+        .collect::<Vec<_>>();
+    let legend = durations
+        .iter()
+        .flat_map(|entry| {
+            [
+                Span::styled("■ ", Style::default().fg(color(entry))),
+                Span::raw(format!(
+                    "{} {}  ",
+                    entry.status.status,
+                    format_status_duration(entry.duration_ms)
+                )),
+            ]
+        })
+        .collect::<Vec<_>>();
+    vec![Line::from(bar), Line::from(legend)]
+}
+
+/// Split `width` cells in proportion to `durations`, giving the cells lost to
+/// rounding to the largest remainders
+fn bar_widths(durations: &[i64], width: usize) -> Vec<usize> {
+    let total: i128 = durations.iter().map(|&d| d as i128).sum();
+    if total <= 0 {
+        return vec![0; durations.len()];
+    }
+    let share = |d: i64| d as i128 * width as i128;
+    let mut widths: Vec<usize> = durations
+        .iter()
+        .map(|&d| (share(d) / total) as usize)
+        .collect();
+    let mut by_remainder: Vec<usize> = (0..durations.len()).collect();
+    by_remainder.sort_by_key(|&i| std::cmp::Reverse(share(durations[i]) % total));
+    let unassigned = width - widths.iter().sum::<usize>();
+    for &i in by_remainder.iter().take(unassigned) {
+        widths[i] += 1;
+    }
+    widths
+}
+
+/// A custom field's value as text, or `None` when unset or not displayable
+///
+/// Drop-down values are option indexes, so they are looked up in the field's
+/// `type_config` options.
+fn custom_field_value(field: &CustomField) -> Option<String> {
+    use serde_json::Value;
+
+    let value = field.value.as_ref()?;
+    if field.type_field.as_deref() == Some("drop_down") {
+        let options = field.type_config.as_ref()?.get("options")?.as_array()?;
+        return options
+            .iter()
+            .find(|o| o.get("orderindex") == Some(value) || o.get("id") == Some(value))
+            .and_then(|o| o.get("name")?.as_str())
+            .map(str::to_string);
+    }
+    match value {
+        Value::String(s) if !s.trim().is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(if *b { "Yes" } else { "No" }.to_string()),
+        _ => None,
+    }
+}
+
+/// Render the description, with logged time in its own pane beside it
+fn render_description(frame: &mut Frame, state: &TaskDetailState, area: Rect) {
+    let desc_area = if state.time_entries.is_empty() {
+        area
+    } else {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+        render_time_entries(frame, &state.time_entries, columns[1]);
+        columns[0]
+    };
+
+    let desc = state.description_text();
+
+    // Calculate description content height for scroll state
+    let available_height = desc_area.height as usize;
+    let available_width = desc_area.width.saturating_sub(4) as usize; // Account for borders
+
+    // Estimate content height by counting wrapped lines
+    let content_height = estimate_wrapped_lines(&desc, available_width);
+
+    // Update scroll state
+    let mut scroll_state = state.description_scroll.clone();
+    scroll_state.update(content_height, available_height);
+
+    // Render description with text wrapping and scrolling
+    let desc_title = if state.raw_description {
+        " Description (raw) "
+    } else {
+        " Description "
+    };
+    let desc_paragraph = Paragraph::new(desc)
+        .block(
+            Block::default()
+                .title(desc_title)
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Theme::PRIMARY)),
+        )
+        .wrap(Wrap {
+            trim: !state.raw_description,
+        });
+
+    // Render with scroll offset
+    frame.render_widget(desc_paragraph, desc_area);
+
+    // Render scroll indicator if needed
+    if scroll_state.scrollable {
+        crate::tui::layout::render_scroll_indicator(
+            frame,
+            desc_area,
+            content_height,
+            scroll_state.offset,
+        );
+    }
+}
+
+/// Render who logged time on the task, newest first, under the total
+fn render_time_entries(frame: &mut Frame, entries: &[TimeEntry], area: Rect) {
+    let total: i64 = entries.iter().filter_map(|e| e.logged_ms()).sum();
+
+    let lines: Vec<Line> = entries
+        .iter()
+        .rev()
+        .map(|entry| {
+            let logged = match entry.logged_ms() {
+                Some(ms) => format_duration(ms),
+                None => "running".to_string(),
+            };
+            let date = entry
+                .start
+                .map(crate::utils::format_timestamp)
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>7} ", logged),
+                    Style::default().fg(Theme::WARNING),
+                ),
+                Span::raw(entry.user_name().to_string()),
+                Span::styled(format!("  {}", date), Style::default().fg(Theme::TEXT_DIM)),
+            ])
+        })
+        .collect();
+
+    let pane = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Time Tracked: {} ", format_duration(total)))
+            .borders(Borders::ALL)
+            .style(Style::default().fg(Theme::PRIMARY)),
+    );
+    frame.render_widget(pane, area);
+}
+
 /// Render the task creation form with name and description input fields
 fn render_task_creation_form(
     frame: &mut Frame,
@@ -208,7 +656,7 @@ fn render_task_creation_form(
     let cursor_indicator = if *focus == TaskCreationField::Name { "█" } else { "" };
     let name_content = if name_input.is_empty() {
         Line::from(Span::styled(
-            format!("{}{}", cursor_indicator, if *focus == TaskCreationField::Name { "" } else { "" }),
+            cursor_indicator.to_string(),
             Style::default().fg(Theme::SECONDARY),
         ))
     } else {
@@ -235,7 +683,7 @@ fn render_task_creation_form(
     let cursor_indicator = if *focus == TaskCreationField::Description { "█" } else { "" };
     let desc_content = if description_input.is_empty() {
         Line::from(Span::styled(
-            format!("{}{}", cursor_indicator, if *focus == TaskCreationField::Description { "" } else { "" }),
+            cursor_indicator.to_string(),
             Style::default().fg(Theme::SECONDARY),
         ))
     } else {
@@ -275,7 +723,7 @@ fn estimate_wrapped_lines(text: &str, available_width: usize) -> usize {
             lines += 1;
         } else {
             // Estimate wrapped lines for this line
-            let wrapped = (line.len() + available_width - 1) / available_width;
+            let wrapped = line.len().div_ceil(available_width);
             lines += wrapped.max(1);
         }
     }
@@ -286,3 +734,117 @@ fn estimate_wrapped_lines(text: &str, available_width: usize) -> usize {
 pub fn get_task_detail_hints() -> &'static str {
     "e: Edit | d: Delete | Ctrl+S: Save | Esc: Close"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::task::{TaskDescription, TaskStatus};
+
+    fn time_in(status: &str, color: &str, minutes: i64, order_index: u32) -> TimeInStatus {
+        TimeInStatus {
+            status: TaskStatus {
+                id: None,
+                status: status.to_string(),
+                color: Some(color.to_string()),
+                type_field: None,
+                orderindex: Some(order_index),
+                status_group: None,
+            },
+            duration_ms: minutes * 60_000,
+            order_index,
+        }
+    }
+
+    #[test]
+    fn test_bar_widths_fill_width_in_proportion() {
+        assert_eq!(bar_widths(&[3, 1], 8), vec![6, 2]);
+        assert_eq!(bar_widths(&[1, 1, 1], 10), vec![4, 3, 3]);
+        assert_eq!(bar_widths(&[0, 5], 4), vec![0, 4]);
+        assert_eq!(bar_widths(&[0, 0], 4), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_time_in_status_lines() {
+        let durations = vec![
+            time_in("open", "#d3d3d3", 3 * 1440 + 120, 0),
+            time_in("in progress", "#4194f6", 1440 + 60, 1),
+        ];
+
+        let lines = time_in_status_lines(&durations, 20);
+
+        let bar = &lines[0].spans;
+        assert_eq!(bar.len(), 2);
+        assert_eq!(bar[0].content.chars().count(), 15);
+        assert_eq!(bar[1].content.chars().count(), 5);
+        assert_eq!(bar[0].style.fg, Some(ratatui::style::Color::Rgb(0xd3, 0xd3, 0xd3)));
+        let legend: String = lines[1].spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(legend, "■ open 3d 2h  ■ in progress 1d 1h  ");
+    }
+
+    #[test]
+    fn test_raw_description_toggles_render_path() {
+        let mut task: Task = serde_json::from_str(r#"{"id": "t1", "name": "Task"}"#).unwrap();
+        task.description = Some(TaskDescription::Plain(
+            "# Plan\n\nShip **this** week".to_string(),
+        ));
+        let mut state = TaskDetailState::new();
+        state.task = Some(task);
+
+        assert_eq!(state.description_text(), "Plan\n\nShip this week");
+
+        state.raw_description = true;
+        assert_eq!(state.description_text(), "# Plan\n\nShip **this** week");
+
+        // An edit waiting to be saved replaces the task's description
+        let task_id = state.task.as_ref().unwrap().id.clone();
+        state.staged_description = Some((task_id, "# Plan\n\nShip next week".to_string()));
+        assert_eq!(state.description_text(), "# Plan\n\nShip next week");
+
+        // ...but only on the task it was made for
+        state.staged_description = Some(("other".to_string(), "Not this".to_string()));
+        assert_eq!(state.description_text(), "# Plan\n\nShip **this** week");
+    }
+
+    #[test]
+    fn test_missing_description() {
+        let mut state = TaskDetailState::new();
+        state.raw_description = true;
+        assert_eq!(state.description_text(), "No description");
+    }
+
+    #[test]
+    fn test_metadata_lines_include_tags_and_custom_fields() {
+        let task: Task = serde_json::from_str(
+            r#"{
+                "id": "t1",
+                "name": "Task",
+                "tags": [{"name": "backend"}, {"name": "urgent"}],
+                "custom_fields": [
+                    {"id": "f1", "name": "Estimate", "type": "number", "value": 5},
+                    {"id": "f2", "name": "Empty", "type": "short_text"},
+                    {
+                        "id": "f3",
+                        "name": "Team",
+                        "type": "drop_down",
+                        "value": 1,
+                        "type_config": {"options": [
+                            {"id": "o0", "name": "Web", "orderindex": 0},
+                            {"id": "o1", "name": "Mobile", "orderindex": 1}
+                        ]}
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let text: Vec<String> = metadata_lines(&task)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+
+        assert!(text.contains(&"Tags: backend, urgent".to_string()));
+        assert!(text.contains(&"Estimate: 5".to_string()));
+        assert!(text.contains(&"Team: Mobile".to_string()));
+        assert!(!text.iter().any(|line| line.starts_with("Empty")));
+    }
+}
@@ -1,8 +1,13 @@
 //! Task list widget
 
-use crate::models::task::{get_status_group_priority, resolve_status_group, sort_tasks, StatusGroupPriority};
+use crate::config::TaskListDensity;
+use crate::models::task::{get_status_group_priority, resolve_status_group, sort_tasks_by, StatusGroupPriority, TaskSortMode};
 use crate::models::Task;
+use crate::tui::helpers::reselect_index;
 use crate::tui::theme::Theme;
+use crate::utils::truncate;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
@@ -11,11 +16,15 @@ use ratatui::{
     Frame,
 };
 
+/// Longest task name shown in the compact density, so the status after it
+/// stays in view
+const COMPACT_NAME_CHARS: usize = 48;
+
 /// A row in the task list — either a status group header or a task
 #[derive(Debug, Clone)]
 pub enum ListRow {
     Header { label: String, #[allow(dead_code)] count: usize },
-    Task(Task),
+    Task(Box<Task>),
 }
 
 /// Grouped task list state, replacing the flat `TaskListState`
@@ -26,6 +35,34 @@ pub enum ListRow {
 pub struct GroupedTaskList {
     rows: Vec<ListRow>,
     list: ListState,
+    /// IDs of tasks marked for bulk actions
+    marked: HashSet<String>,
+    /// Show each task's list name (for views spanning several lists)
+    show_list_names: bool,
+    /// IDs of tasks with comments posted since they were last viewed
+    unread: HashSet<String>,
+    /// Compact lists leave out the header rows and show tasks on one short line
+    density: TaskListDensity,
+    /// Bumped on every change to what the rows display
+    version: u64,
+    /// Version at which each row last changed; rows past the end changed at `version`
+    row_versions: Vec<u64>,
+    /// Lines built by earlier renders, reused until their row changes
+    render_cache: RefCell<RenderCache>,
+}
+
+/// A row's line as last rendered, and the list version it was built at
+#[derive(Debug, Clone)]
+pub struct PrerenderedTaskRow {
+    line: Line<'static>,
+    version: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RenderCache {
+    rows: Vec<Option<PrerenderedTaskRow>>,
+    /// List version the cache was last checked against
+    last_rendered_version: u64,
 }
 
 impl GroupedTaskList {
@@ -33,6 +70,13 @@ impl GroupedTaskList {
         Self {
             rows: Vec::new(),
             list: ListState::default(),
+            marked: HashSet::new(),
+            show_list_names: false,
+            unread: HashSet::new(),
+            version: 0,
+            row_versions: Vec::new(),
+            density: TaskListDensity::default(),
+            render_cache: RefCell::default(),
         }
     }
 
@@ -42,9 +86,13 @@ impl GroupedTaskList {
     /// `status.status` when `status_group` is `None`), sorted within groups by
     /// `updated_at` descending. Known status groups (in_progress, todo, done)
     /// appear first in priority order; unknown groups appear after.
-    pub fn from_tasks(mut tasks: Vec<Task>) -> Self {
-        // Ensure tasks are sorted by status priority and recency
-        tasks = sort_tasks(tasks);
+    pub fn from_tasks(tasks: Vec<Task>) -> Self {
+        Self::from_tasks_sorted_by(tasks, TaskSortMode::Status)
+    }
+
+    /// Build a grouped task list ordered by `mode` within each status group
+    pub fn from_tasks_sorted_by(mut tasks: Vec<Task>, mode: TaskSortMode) -> Self {
+        tasks = sort_tasks_by(tasks, mode);
 
         // Group tasks by resolved status group name (String key)
         // Each group tracks its priority (for ordering) and the task list
@@ -74,7 +122,7 @@ impl GroupedTaskList {
                 count: group_tasks.len(),
             });
             for task in group_tasks {
-                rows.push(ListRow::Task(task.clone()));
+                rows.push(ListRow::Task(Box::new(task.clone())));
             }
         }
 
@@ -86,7 +134,220 @@ impl GroupedTaskList {
         let mut list = ListState::default();
         list.select(first_task_index);
 
-        Self { rows, list }
+        Self {
+            rows,
+            list,
+            marked: HashSet::new(),
+            show_list_names: false,
+            unread: HashSet::new(),
+            version: 0,
+            row_versions: Vec::new(),
+            density: TaskListDensity::default(),
+            render_cache: RefCell::default(),
+        }
+    }
+
+    /// Build a task list grouped by where each task lives, one header per
+    /// space and list.
+    ///
+    /// Groups are ordered by space name, then list name; tasks within a group
+    /// keep the status ordering of [`Self::from_tasks`]. Space names missing
+    /// from the tasks are looked up in `space_names` (space ID → name).
+    pub fn from_tasks_by_location(tasks: Vec<Task>, space_names: &HashMap<String, String>) -> Self {
+        let tasks = sort_tasks_by(tasks, TaskSortMode::Status);
+
+        let mut groups: BTreeMap<(String, String), Vec<Task>> = BTreeMap::new();
+        for task in tasks {
+            let space = task
+                .space
+                .as_ref()
+                .and_then(|s| s.name.clone().or_else(|| space_names.get(&s.id).cloned()))
+                .unwrap_or_else(|| "Unknown space".to_string());
+            let list = task
+                .list
+                .as_ref()
+                .and_then(|l| l.name.clone())
+                .unwrap_or_else(|| "Unknown list".to_string());
+            groups.entry((space, list)).or_default().push(task);
+        }
+
+        let mut rows: Vec<ListRow> = Vec::new();
+        for ((space, list), group_tasks) in groups {
+            rows.push(ListRow::Header {
+                label: format!("▸ {} / {} ({})", space, list, group_tasks.len()),
+                count: group_tasks.len(),
+            });
+            rows.extend(group_tasks.into_iter().map(|t| ListRow::Task(Box::new(t))));
+        }
+
+        let mut list = ListState::default();
+        list.select(rows.iter().position(|r| matches!(r, ListRow::Task(_))));
+
+        Self {
+            rows,
+            list,
+            marked: HashSet::new(),
+            show_list_names: false,
+            unread: HashSet::new(),
+            version: 0,
+            row_versions: Vec::new(),
+            density: TaskListDensity::default(),
+            render_cache: RefCell::default(),
+        }
+    }
+
+    /// Show the list name next to each task
+    pub fn with_list_names(mut self) -> Self {
+        self.show_list_names = true;
+        self.touch_all();
+        self
+    }
+
+    /// Lay the rows out as `density` asks, rebuilding them if it changed
+    pub fn set_density(&mut self, density: TaskListDensity) {
+        if self.density != density {
+            self.density = density;
+            self.touch_all();
+        }
+    }
+
+    /// Record a change to every row
+    fn touch_all(&mut self) {
+        self.version += 1;
+        self.row_versions = vec![self.version; self.rows.len()];
+    }
+
+    /// Record a change to the row showing the task with `id`
+    fn touch_task(&mut self, id: &str) {
+        let Some(index) = self
+            .rows
+            .iter()
+            .position(|row| matches!(row, ListRow::Task(task) if task.id == id))
+        else {
+            return;
+        };
+        if self.row_versions.len() < self.rows.len() {
+            self.row_versions.resize(self.rows.len(), self.version);
+        }
+        self.version += 1;
+        self.row_versions[index] = self.version;
+    }
+
+    /// Version at which row `index` last changed
+    fn row_version(&self, index: usize) -> u64 {
+        self.row_versions.get(index).copied().unwrap_or(self.version)
+    }
+
+    /// Lines for rows `indices`, building only those changed since they were last rendered
+    fn prerendered_lines(&self, indices: &[usize]) -> Vec<Line<'static>> {
+        let mut cache = self.render_cache.borrow_mut();
+        cache.rows.resize(self.rows.len(), None);
+        if cache.last_rendered_version != self.version {
+            for (index, slot) in cache.rows.iter_mut().enumerate() {
+                if slot.as_ref().is_some_and(|row| row.version < self.row_version(index)) {
+                    *slot = None;
+                }
+            }
+            cache.last_rendered_version = self.version;
+        }
+        indices
+            .iter()
+            .map(|&index| {
+                cache.rows[index]
+                    .get_or_insert_with(|| PrerenderedTaskRow {
+                        line: self.render_row(&self.rows[index]),
+                        version: self.version,
+                    })
+                    .line
+                    .clone()
+            })
+            .collect()
+    }
+
+    /// Number of rows with a line built by an earlier render
+    #[cfg(test)]
+    fn prerendered_count(&self) -> usize {
+        self.render_cache.borrow().rows.iter().flatten().count()
+    }
+
+    /// Build the line for a single row
+    fn render_row(&self, row: &ListRow) -> Line<'static> {
+        match row {
+            ListRow::Header { label, .. } => {
+                // Header rows: dimmed, bold text, no highlight symbol
+                Line::from(vec![Span::styled(
+                    label.clone(),
+                    Style::default()
+                        .fg(Theme::TEXT_DIM)
+                        .add_modifier(Modifier::BOLD),
+                )])
+            }
+            ListRow::Task(task) if self.density == TaskListDensity::Compact => {
+                let mark = if self.is_marked(&task.id) { "● " } else { "" };
+                let id = task.custom_id.as_deref().unwrap_or(&task.id);
+                let mut name = archived_name(task);
+                name.content = truncate(&name.content, COMPACT_NAME_CHARS).into();
+                let status = task.status.as_ref().map(|s| s.status.as_str()).unwrap_or("");
+                Line::from(vec![
+                    Span::styled(mark, Style::default().fg(Theme::PRIMARY)),
+                    Span::styled(format!("{} ", id), Style::default().fg(Theme::TEXT_DIM)),
+                    name,
+                    Span::styled(
+                        format!("  {}", status),
+                        Style::default().fg(get_status_color(&task.status)),
+                    ),
+                ])
+            }
+            ListRow::Task(task) => {
+                let priority = get_priority_indicator(&task.priority);
+                let mark = if self.is_marked(&task.id) { "● " } else { "" };
+
+                let mut spans = vec![
+                    Span::styled(mark, Style::default().fg(Theme::PRIMARY)),
+                    Span::styled(
+                        format!("[{}] ", priority),
+                        Style::default().fg(Theme::WARNING),
+                    ),
+                    archived_name(task),
+                ];
+                if self.is_unread(&task.id) {
+                    spans.push(Span::styled(" 💬*", Style::default().fg(Theme::PRIMARY)));
+                }
+                if task.archived == Some(true) {
+                    spans.push(Span::styled(" (archived)", Style::default().fg(Theme::TEXT_DIM)));
+                }
+                let list_name = task.list.as_ref().and_then(|l| l.name.as_deref());
+                if let (true, Some(list_name)) = (self.show_list_names, list_name) {
+                    spans.push(Span::styled(
+                        format!("  · {}", list_name),
+                        Style::default().fg(Theme::TEXT_DIM),
+                    ));
+                }
+                Line::from(spans)
+            }
+        }
+    }
+
+    /// Indices of the rows shown in `height` lines: starting at the scroll
+    /// offset, moved just enough to keep the selection in view. Compact
+    /// lists skip the header rows.
+    fn visible_rows(&self, height: usize) -> Vec<usize> {
+        let shown: Vec<usize> = match self.density {
+            TaskListDensity::Comfortable => (0..self.rows.len()).collect(),
+            TaskListDensity::Compact => self
+                .rows
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| matches!(row, ListRow::Task(_)))
+                .map(|(index, _)| index)
+                .collect(),
+        };
+        let offset = shown.partition_point(|&index| index < self.list.offset());
+        let selected = self
+            .list
+            .selected()
+            .and_then(|index| shown.binary_search(&index).ok());
+        shown[visible_range(shown.len(), offset, selected, height)].to_vec()
     }
 
     /// Move selection to the next task row, skipping header rows.
@@ -145,11 +406,36 @@ impl GroupedTaskList {
             .selected()
             .and_then(|i| self.rows.get(i))
             .and_then(|row| match row {
-                ListRow::Task(task) => Some(task),
+                ListRow::Task(task) => Some(task.as_ref()),
                 ListRow::Header { .. } => None,
             })
     }
 
+    /// Take over the rows of `rebuilt` (a reload or re-sort of this list),
+    /// keeping the selected task, marks, unread flags and scroll offset.
+    ///
+    /// When the selected task is gone, the task now nearest its old row is
+    /// selected instead.
+    pub fn refresh_from(&mut self, mut rebuilt: GroupedTaskList) {
+        let selected_id = self.selected_task().map(|t| t.id.clone());
+        let index = reselect_index(
+            &rebuilt.rows,
+            |row| match row {
+                ListRow::Task(task) => Some(task.id.as_str()),
+                ListRow::Header { .. } => None,
+            },
+            selected_id.as_deref(),
+            self.list.selected(),
+        );
+        rebuilt.list.select(index);
+        *rebuilt.list.offset_mut() = self.list.offset();
+        rebuilt.restore_marks(&self.marked);
+        for id in &self.unread {
+            rebuilt.set_unread(id);
+        }
+        *self = rebuilt;
+    }
+
     /// Select item by index (raw index into rows, may select a header — callers should prefer navigation methods)
     pub fn select(&mut self, index: Option<usize>) {
         if let Some(i) = index {
@@ -161,6 +447,119 @@ impl GroupedTaskList {
         }
     }
 
+    /// Select the first task whose name starts with `prefix` (case-insensitive).
+    ///
+    /// Returns false and keeps the current selection when nothing matches.
+    pub fn select_by_name_prefix(&mut self, prefix: &str) -> bool {
+        let names = self.rows.iter().enumerate().filter_map(|(i, row)| match row {
+            ListRow::Task(task) => Some((i, task.name.as_str())),
+            ListRow::Header { .. } => None,
+        });
+        match crate::tui::type_ahead::find_prefix_match(names, prefix) {
+            Some(index) => {
+                self.list.select(Some(index));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Toggle the bulk-action mark on the selected task.
+    ///
+    /// Returns false when the selection is on a header or the list is empty.
+    pub fn toggle_mark_selected(&mut self) -> bool {
+        let Some(id) = self.selected_task().map(|t| t.id.clone()) else {
+            return false;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id.clone());
+        }
+        self.touch_task(&id);
+        true
+    }
+
+    /// Whether the task with `id` is marked
+    pub fn is_marked(&self, id: &str) -> bool {
+        self.marked.contains(id)
+    }
+
+    /// Marked tasks in display order
+    pub fn marked_tasks(&self) -> Vec<&Task> {
+        self.rows
+            .iter()
+            .filter_map(|row| match row {
+                ListRow::Task(task) if self.marked.contains(&task.id) => Some(task.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn has_marks(&self) -> bool {
+        !self.marked.is_empty()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.touch_all();
+    }
+
+    /// Carry marks over from a previous list, dropping tasks that are gone
+    pub fn restore_marks(&mut self, marked: &HashSet<String>) {
+        self.marked = self
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                ListRow::Task(task) if marked.contains(&task.id) => Some(task.id.clone()),
+                _ => None,
+            })
+            .collect();
+        self.touch_all();
+    }
+
+    /// IDs of marked tasks
+    #[allow(dead_code)]
+    pub fn marked_ids(&self) -> &HashSet<String> {
+        &self.marked
+    }
+
+    /// Flag a task as having unseen comments.
+    ///
+    /// Returns false when the task is not in this list.
+    pub fn set_unread(&mut self, id: &str) -> bool {
+        let present = self
+            .rows
+            .iter()
+            .any(|row| matches!(row, ListRow::Task(task) if task.id == id));
+        if present && self.unread.insert(id.to_string()) {
+            self.touch_task(id);
+        }
+        present
+    }
+
+    pub fn clear_unread(&mut self, id: &str) {
+        if self.unread.remove(id) {
+            self.touch_task(id);
+        }
+    }
+
+    /// Whether the task with `id` has unseen comments
+    pub fn is_unread(&self, id: &str) -> bool {
+        self.unread.contains(id)
+    }
+
+    /// Tasks in the `height` rows starting at the scroll offset
+    pub fn visible_tasks(&self, height: usize) -> Vec<&Task> {
+        self.rows
+            .iter()
+            .skip(self.list.offset())
+            .take(height)
+            .filter_map(|row| match row {
+                ListRow::Task(task) => Some(task.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Get all rows (for rendering)
     pub fn rows(&self) -> &[ListRow] {
         &self.rows
@@ -169,6 +568,7 @@ impl GroupedTaskList {
     /// Get all rows (mutable, for rendering)
     #[allow(dead_code)]
     pub fn rows_mut(&mut self) -> &mut Vec<ListRow> {
+        self.touch_all();
         &mut self.rows
     }
 
@@ -190,6 +590,42 @@ impl Default for GroupedTaskList {
     }
 }
 
+/// A task's name, dimmed and struck through when archived
+fn archived_name(task: &Task) -> Span<'static> {
+    if task.archived == Some(true) {
+        Span::styled(
+            task.name.clone(),
+            Style::default()
+                .fg(Theme::TEXT_DIM)
+                .add_modifier(Modifier::CROSSED_OUT),
+        )
+    } else {
+        Span::raw(task.name.clone())
+    }
+}
+
+/// Which of `len` rows fit in `height` lines: starting at `offset`, moved
+/// just enough to keep `selected` in view
+fn visible_range(
+    len: usize,
+    offset: usize,
+    selected: Option<usize>,
+    height: usize,
+) -> std::ops::Range<usize> {
+    let mut start = offset.min(len.saturating_sub(1));
+    let mut end = (start + height).min(len);
+    if let Some(selected) = selected.filter(|&i| i < len) {
+        if selected >= end {
+            end = selected + 1;
+            start = end.saturating_sub(height);
+        } else if selected < start {
+            start = selected;
+            end = (start + height).min(len);
+        }
+    }
+    start..end
+}
+
 /// Format a group label for display from the resolved status group name.
 /// e.g., `"in progress"` with count 3 → "▸ IN PROGRESS (3)"
 ///       `"review"` with count 2 → "▸ REVIEW (2)"
@@ -240,35 +676,25 @@ pub fn render_task_list(frame: &mut Frame, state: &GroupedTaskList, area: Rect,
         return;
     }
 
+    // Only the rows in view are handed to the widget, each built once and
+    // reused until the row changes
+    let block = crate::tui::layout::titled_block(" Tasks ");
+    let shown = state.visible_rows(block.inner(area).height as usize);
+    let mut window = ListState::default();
+    window.select(
+        state
+            .state()
+            .selected()
+            .and_then(|selected| shown.iter().position(|&index| index == selected)),
+    );
     let items: Vec<ListItem> = state
-        .rows()
-        .iter()
-        .map(|row| match row {
-            ListRow::Header { label, .. } => {
-                // Header rows: dimmed, bold text, no highlight symbol
-                ListItem::new(Line::from(vec![Span::styled(
-                    label.as_str(),
-                    Style::default()
-                        .fg(Theme::TEXT_DIM)
-                        .add_modifier(Modifier::BOLD),
-                )]))
-            }
-            ListRow::Task(task) => {
-                let priority = get_priority_indicator(&task.priority);
-
-                ListItem::new(Line::from(vec![
-                    Span::styled(
-                        format!("[{}] ", priority),
-                        Style::default().fg(Theme::WARNING),
-                    ),
-                    Span::raw(task.name.as_str()),
-                ]))
-            }
-        })
+        .prerendered_lines(&shown)
+        .into_iter()
+        .map(ListItem::new)
         .collect();
 
     let list = List::new(items)
-        .block(crate::tui::layout::titled_block(" Tasks "))
+        .block(block)
         .highlight_style(
             Style::default()
                 .bg(Theme::SECONDARY)
@@ -276,7 +702,7 @@ pub fn render_task_list(frame: &mut Frame, state: &GroupedTaskList, area: Rect,
         )
         .highlight_symbol("▸ ");
 
-    frame.render_stateful_widget(list, area, &mut state.state().clone());
+    frame.render_stateful_widget(list, area, &mut window);
 }
 
 #[allow(dead_code)]
@@ -579,4 +1005,274 @@ mod tests {
             "BLOCKED should appear after known status groups"
         );
     }
+
+    #[test]
+    fn test_marks_toggle_and_survive_rebuild() {
+        let mut list = GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(2000)),
+            make_task("t2", Some("todo"), Some(1000)),
+        ]);
+        assert!(list.toggle_mark_selected());
+        list.select_next();
+        assert!(list.toggle_mark_selected());
+        assert!(list.toggle_mark_selected(), "Second toggle unmarks");
+        let names: Vec<&str> = list.marked_tasks().iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Task t1"]);
+
+        let marked = list.marked_ids().clone();
+        let mut rebuilt = GroupedTaskList::from_tasks(vec![make_task("t2", Some("todo"), None)]);
+        rebuilt.restore_marks(&marked);
+        assert!(!rebuilt.has_marks(), "Marks of removed tasks are dropped");
+    }
+
+    #[test]
+    fn test_unread_only_flags_tasks_in_the_list() {
+        let mut grouped = GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(1000)),
+            make_task("t2", Some("done"), Some(2000)),
+        ]);
+
+        assert!(grouped.set_unread("t1"));
+        assert!(!grouped.set_unread("missing"));
+        assert!(grouped.is_unread("t1"));
+        assert!(!grouped.is_unread("missing"));
+
+        grouped.clear_unread("t1");
+        assert!(!grouped.is_unread("t1"));
+    }
+
+    fn render(list: &GroupedTaskList, height: u16) -> ratatui::buffer::Buffer {
+        use ratatui::{backend::TestBackend, Terminal};
+        let mut terminal = Terminal::new(TestBackend::new(40, height)).unwrap();
+        terminal
+            .draw(|frame| render_task_list(frame, list, frame.area(), false))
+            .unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
+        buffer.content().iter().map(|cell| cell.symbol()).collect()
+    }
+
+    fn many_tasks(count: usize) -> GroupedTaskList {
+        GroupedTaskList::from_tasks(
+            (0..count)
+                .map(|i| make_task(&format!("t{:02}", i), Some("todo"), Some(10_000 - i as i64)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_render_builds_only_visible_rows() {
+        let list = many_tasks(50);
+
+        render(&list, 12);
+
+        // 10 rows inside the borders: the header and the first nine tasks
+        assert_eq!(list.prerendered_count(), 10);
+    }
+
+    #[test]
+    fn test_render_keeps_selection_in_view() {
+        let mut list = many_tasks(50);
+        for _ in 0..20 {
+            list.select_next();
+        }
+
+        let text = buffer_text(&render(&list, 12));
+
+        assert!(text.contains("▸ [•] Task t20"), "Selected row is drawn highlighted");
+        assert!(!text.contains("Task t00"), "Rows above the window are skipped");
+        assert!(list.prerendered_count() <= 10);
+    }
+
+    #[test]
+    fn test_compact_density_fits_more_tasks() {
+        let mut list = GroupedTaskList::from_tasks(
+            ["todo", "done", "in_progress", "blocked", "review"]
+                .iter()
+                .enumerate()
+                .flat_map(|(g, group)| {
+                    (0..3).map(move |i| {
+                        make_task(&format!("t{}{}", g, i), Some(group), Some(100 - i))
+                    })
+                })
+                .collect(),
+        );
+        let shown_tasks = |list: &GroupedTaskList| {
+            let text = buffer_text(&render(list, 12));
+            list.rows()
+                .iter()
+                .filter_map(|row| match row {
+                    ListRow::Task(task) => Some(task.name.clone()),
+                    ListRow::Header { .. } => None,
+                })
+                .filter(|name| text.contains(name.as_str()))
+                .count()
+        };
+
+        let comfortable = shown_tasks(&list);
+        list.set_density(TaskListDensity::Compact);
+        let compact = shown_tasks(&list);
+        assert_eq!(compact, 10, "One task per line inside the borders");
+        assert!(compact > comfortable, "{} vs {}", compact, comfortable);
+    }
+
+    #[test]
+    fn test_compact_density_highlights_and_truncates() {
+        let mut tasks = vec![make_task("t1", Some("todo"), Some(2000))];
+        let mut long = make_task("t2", Some("todo"), Some(1000));
+        long.custom_id = Some("DEV-42".to_string());
+        long.name = "A very long task name that goes on and on past the width".to_string();
+        tasks.push(long);
+        let mut list = GroupedTaskList::from_tasks(tasks);
+        list.set_density(TaskListDensity::Compact);
+        list.select_next();
+
+        let line: String = list
+            .render_row(&list.rows[2])
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(line, "DEV-42 A very long task name that goes on and on pas...  test");
+
+        let text = buffer_text(&render(&list, 6));
+        assert!(text.contains("▸ DEV-42"), "Selected row is drawn highlighted");
+        assert!(!text.contains("TODO"), "No group headers");
+
+        // Back to comfortable, the headers return and the line is rebuilt
+        list.set_density(TaskListDensity::Comfortable);
+        let text = buffer_text(&render(&list, 6));
+        assert!(text.contains("TODO (2)"));
+        assert!(text.contains("▸ [•] A very long"));
+    }
+
+    #[test]
+    fn test_mutation_rebuilds_only_the_changed_row() {
+        let mut list = many_tasks(5);
+        render(&list, 10);
+        let versions = |list: &GroupedTaskList| -> Vec<u64> {
+            list.render_cache
+                .borrow()
+                .rows
+                .iter()
+                .map(|row| row.as_ref().unwrap().version)
+                .collect()
+        };
+        let before = versions(&list);
+
+        list.select_next();
+        assert!(list.toggle_mark_selected());
+        let text = buffer_text(&render(&list, 10));
+
+        let after = versions(&list);
+        let changed: Vec<usize> = (0..after.len()).filter(|&i| after[i] != before[i]).collect();
+        assert_eq!(changed, vec![2], "Only the marked task's row is rebuilt");
+        assert!(text.contains("● [•] Task t01"));
+    }
+
+    #[test]
+    fn test_whole_list_changes_rebuild_every_row() {
+        let mut list = many_tasks(3);
+        assert!(list.toggle_mark_selected());
+        assert!(buffer_text(&render(&list, 10)).contains("●"));
+
+        list.clear_marks();
+
+        assert!(!buffer_text(&render(&list, 10)).contains("●"));
+    }
+
+    #[test]
+    fn test_visible_tasks_skip_headers() {
+        let grouped = GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(1000)),
+            make_task("t2", Some("done"), Some(2000)),
+        ]);
+
+        // Rows: TODO header, t1, DONE header, t2
+        let visible: Vec<&str> = grouped.visible_tasks(3).iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(visible, vec!["t1"]);
+        assert_eq!(grouped.visible_tasks(10).len(), 2);
+    }
+
+    #[test]
+    fn test_from_tasks_by_location_groups_by_space_and_list() {
+        use crate::models::task::{ListReference, SpaceReference};
+
+        let located = |id: &str, space_id: &str, list: &str| Task {
+            list: Some(ListReference {
+                id: format!("list-{}", list),
+                name: Some(list.to_string()),
+                access: None,
+            }),
+            space: Some(SpaceReference {
+                id: space_id.to_string(),
+                name: None,
+                access: None,
+            }),
+            ..make_task(id, Some("todo"), Some(1000))
+        };
+        let space_names = HashMap::from([
+            ("s1".to_string(), "Engineering".to_string()),
+            ("s2".to_string(), "Design".to_string()),
+        ]);
+
+        let grouped = GroupedTaskList::from_tasks_by_location(
+            vec![
+                located("t1", "s1", "Backlog"),
+                located("t2", "s2", "Mockups"),
+                located("t3", "s1", "Backlog"),
+            ],
+            &space_names,
+        );
+
+        let headers: Vec<&str> = grouped
+            .rows()
+            .iter()
+            .filter_map(|r| match r {
+                ListRow::Header { label, .. } => Some(label.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            headers,
+            vec!["▸ Design / Mockups (1)", "▸ Engineering / Backlog (2)"]
+        );
+        assert_eq!(grouped.selected_task().unwrap().id, "t2");
+    }
+
+    #[test]
+    fn test_refresh_from_keeps_selected_task() {
+        let mut list = GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(3000)),
+            make_task("t2", Some("todo"), Some(2000)),
+            make_task("t3", Some("todo"), Some(1000)),
+        ]);
+        list.select_next();
+        list.select_next();
+        assert_eq!(list.selected_task().map(|t| t.id.as_str()), Some("t3"));
+        list.toggle_mark_selected();
+
+        // A status change moves t3 into another group
+        list.refresh_from(GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(3000)),
+            make_task("t2", Some("todo"), Some(2000)),
+            make_task("t3", Some("in_progress"), Some(1000)),
+        ]));
+        assert_eq!(list.selected_task().map(|t| t.id.as_str()), Some("t3"));
+        assert!(list.is_marked("t3"));
+
+        // t3 deleted: the nearest task takes its place, skipping headers
+        list.refresh_from(GroupedTaskList::from_tasks(vec![
+            make_task("t1", Some("todo"), Some(3000)),
+            make_task("t2", Some("todo"), Some(2000)),
+        ]));
+        assert_eq!(list.selected_task().map(|t| t.id.as_str()), Some("t1"));
+        assert!(!list.has_marks());
+
+        list.refresh_from(GroupedTaskList::from_tasks(Vec::new()));
+        assert!(list.selected_task().is_none());
+        assert_eq!(list.state().selected(), None);
+    }
 }
@@ -0,0 +1,161 @@
+//! Workspace views widget: the views saved across a workspace, then the
+//! tasks of the one opened
+
+use crate::models::WorkspaceView;
+use crate::tui::helpers::SelectableList;
+use crate::tui::layout::titled_block;
+use crate::tui::theme::Theme;
+use crate::tui::widgets::task_list::{render_task_list, GroupedTaskList};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Icon for a view type
+pub fn view_icon(view_type: &str) -> &'static str {
+    match view_type {
+        "list" => "📋",
+        "board" => "📊",
+        "calendar" => "📅",
+        "chart" | "gantt" => "📈",
+        _ => "•",
+    }
+}
+
+/// Workspace views state
+#[derive(Debug, Clone)]
+pub struct WorkspaceViewsState {
+    pub workspace_id: String,
+    pub views: SelectableList<WorkspaceView>,
+    /// The view whose tasks are shown, once one is opened
+    pub open_view: Option<WorkspaceView>,
+    pub tasks: GroupedTaskList,
+    pub loading: bool,
+}
+
+impl WorkspaceViewsState {
+    pub fn new(workspace_id: String) -> Self {
+        Self {
+            workspace_id,
+            views: SelectableList::empty(),
+            open_view: None,
+            tasks: GroupedTaskList::new(),
+            loading: false,
+        }
+    }
+
+    /// Replace the views and select the first
+    pub fn set_views(&mut self, views: Vec<WorkspaceView>) {
+        *self.views.items_mut() = views;
+        self.views.select_first();
+    }
+
+    /// Show `view`, with its tasks still to load
+    pub fn open(&mut self, view: WorkspaceView) {
+        self.open_view = Some(view);
+        self.tasks = GroupedTaskList::new();
+    }
+
+    /// What to show instead of the views, or the open view's tasks, while
+    /// there are none
+    pub fn empty_message(&self) -> Option<&'static str> {
+        match &self.open_view {
+            Some(_) if self.loading => Some("Loading tasks..."),
+            Some(_) if self.tasks.rows().is_empty() => Some("No tasks in this view"),
+            Some(_) => None,
+            None if !self.views.items().is_empty() => None,
+            None if self.loading => Some("Loading views..."),
+            None => Some("No views saved on this workspace"),
+        }
+    }
+
+    /// Go back to the views, keeping the selection
+    pub fn close(&mut self) {
+        self.open_view = None;
+        self.tasks = GroupedTaskList::new();
+    }
+}
+
+impl Default for WorkspaceViewsState {
+    fn default() -> Self {
+        Self::new(String::new())
+    }
+}
+
+pub fn render_workspace_views(frame: &mut Frame, state: &WorkspaceViewsState, area: Rect) {
+    if let Some(view) = &state.open_view {
+        render_view_tasks(frame, state, view, area);
+        return;
+    }
+
+    let block = titled_block(" Views ");
+    if let Some(message) = state.empty_message() {
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let empty = Paragraph::new(message).style(Style::default().fg(Theme::TEXT_DIM));
+        frame.render_widget(empty, inner);
+        return;
+    }
+
+    let items: Vec<ListItem> = state
+        .views
+        .items()
+        .iter()
+        .map(|view| {
+            let mut spans = vec![Span::raw(format!(
+                "{} {}",
+                view_icon(&view.view_type),
+                view.name
+            ))];
+            if !view.visibility.is_empty() {
+                spans.push(Span::styled(
+                    format!("  · {}", view.visibility),
+                    Style::default().fg(Theme::TEXT_DIM),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .bg(Theme::SECONDARY)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▸ ");
+
+    frame.render_stateful_widget(list, area, &mut state.views.state().clone());
+}
+
+fn render_view_tasks(frame: &mut Frame, state: &WorkspaceViewsState, view: &WorkspaceView, area: Rect) {
+    let Some(message) = state.empty_message() else {
+        render_task_list(frame, &state.tasks, area, false);
+        return;
+    };
+    let block = titled_block(format!(" {} {} ", view_icon(&view.view_type), view.name));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    frame.render_widget(
+        Paragraph::new(message).style(Style::default().fg(Theme::TEXT_DIM)),
+        inner,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_icons() {
+        assert_eq!(view_icon("list"), "📋");
+        assert_eq!(view_icon("board"), "📊");
+        assert_eq!(view_icon("calendar"), "📅");
+        assert_eq!(view_icon("chart"), "📈");
+        assert_eq!(view_icon("mind_map"), "•");
+    }
+}
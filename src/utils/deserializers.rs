@@ -2,9 +2,58 @@
 //!
 //! This module provides reusable deserializer functions for handling common
 //! API response variations like null values, type flexibility, and missing fields.
+//! Every model uses these rather than its own copies, so a new input format
+//! (e.g. ISO-8601 timestamps) only needs adding here.
+//!
+//! The numeric helpers (`flexible_timestamp`, `flexible_int`, `flexible_i64`,
+//! `flexible_count`) all accept a JSON number or a numeric string, map null or
+//! a missing field to `None`, and reject anything else (floats, booleans,
+//! non-numeric or out-of-range strings) with an error naming the expected
+//! type and the value received. None of them panic.
 
-use serde::de::{self, Deserializer};
+use serde::de::{self, DeserializeOwned, Deserializer};
 use serde::Deserialize;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A number as the API may send it
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberValue<T> {
+    Int(T),
+    String(String),
+    Float(f64),
+    Bool(bool),
+}
+
+/// Deserialize a number sent as a JSON number or a numeric string
+///
+/// `expected` names the type in errors, e.g. "an i32".
+fn flexible_number<'de, D, T>(deserializer: D, expected: &str) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Option::<NumberValue<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(NumberValue::Int(v)) => Ok(Some(v)),
+        Some(NumberValue::String(s)) => s.parse::<T>().map(Some).map_err(|e| {
+            de::Error::custom(format!(
+                "invalid value: expected {} or numeric string, got {:?} ({})",
+                expected, s, e
+            ))
+        }),
+        Some(NumberValue::Float(f)) => Err(de::Error::custom(format!(
+            "invalid value: expected {} or numeric string, got {}",
+            expected, f
+        ))),
+        Some(NumberValue::Bool(b)) => Err(de::Error::custom(format!(
+            "invalid value: expected {} or numeric string, got {}",
+            expected, b
+        ))),
+    }
+}
 
 /// Helper function to deserialize null as empty string
 ///
@@ -47,19 +96,7 @@ pub fn flexible_timestamp<'de, D>(deserializer: D) -> Result<Option<i64>, D::Err
 where
     D: Deserializer<'de>,
 {
-    #[derive(Deserialize)]
-    #[serde(untagged)]
-    enum TimestampValue {
-        Int(i64),
-        String(String),
-    }
-
-    let opt = Option::<TimestampValue>::deserialize(deserializer)?;
-    match opt {
-        None => Ok(None),
-        Some(TimestampValue::Int(v)) => Ok(Some(v)),
-        Some(TimestampValue::String(s)) => s.parse::<i64>().map(Some).map_err(de::Error::custom),
-    }
+    flexible_number(deserializer, "a timestamp in milliseconds")
 }
 
 /// Flexible deserializer for integer fields that can be either i32 or string
@@ -67,38 +104,37 @@ pub fn flexible_int<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    #[derive(Deserialize)]
-    #[serde(untagged)]
-    enum IntValue {
-        Int(i32),
-        String(String),
-    }
-
-    let opt = Option::<IntValue>::deserialize(deserializer)?;
-    match opt {
-        None => Ok(None),
-        Some(IntValue::Int(v)) => Ok(Some(v)),
-        Some(IntValue::String(s)) => s.parse::<i32>().map(Some).map_err(de::Error::custom),
-    }
+    flexible_number(deserializer, "an i32")
 }
 
 /// Flexible deserializer for i64 fields that can be either i64 or string
 pub fn flexible_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    flexible_number(deserializer, "an i64")
+}
+
+/// Deserializer for counts sent either as a number (or numeric string) or as
+/// the list of things counted, in which case its length is used
+pub fn flexible_count<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
 where
     D: Deserializer<'de>,
 {
     #[derive(Deserialize)]
     #[serde(untagged)]
-    enum I64Value {
-        Int(i64),
-        String(String),
+    enum CountValue {
+        List(Vec<serde::de::IgnoredAny>),
+        Number(serde_json::Value),
     }
 
-    let opt = Option::<I64Value>::deserialize(deserializer)?;
+    let opt = Option::<CountValue>::deserialize(deserializer)?;
     match opt {
         None => Ok(None),
-        Some(I64Value::Int(v)) => Ok(Some(v)),
-        Some(I64Value::String(s)) => s.parse::<i64>().map(Some).map_err(de::Error::custom),
+        Some(CountValue::List(items)) => Ok(Some(items.len() as u32)),
+        Some(CountValue::Number(value)) => {
+            flexible_number(value, "a count").map_err(de::Error::custom)
+        }
     }
 }
 
@@ -115,6 +151,7 @@ where
     enum StringOrInt {
         String(String),
         Int(i64),
+        Other(serde_json::Value),
     }
 
     let opt = Option::<StringOrInt>::deserialize(deserializer)?;
@@ -122,6 +159,10 @@ where
         None => Ok(String::new()),
         Some(StringOrInt::String(s)) => Ok(s),
         Some(StringOrInt::Int(i)) => Ok(i.to_string()),
+        Some(StringOrInt::Other(v)) => Err(de::Error::custom(format!(
+            "expected a string or integer ID, got {}",
+            v
+        ))),
     }
 }
 
@@ -147,6 +188,7 @@ where
     enum ResolvedValue {
         Bool(bool),
         Int(i64),
+        Other(serde_json::Value),
     }
 
     let opt = Option::<ResolvedValue>::deserialize(deserializer)?;
@@ -154,9 +196,24 @@ where
         None => Ok(None),
         Some(ResolvedValue::Bool(b)) => Ok(Some(if b { 1 } else { 0 })),
         Some(ResolvedValue::Int(v)) => Ok(Some(v)),
+        Some(ResolvedValue::Other(v)) => Err(de::Error::custom(format!(
+            "expected a bool or integer, got {}",
+            v
+        ))),
     }
 }
 
+/// Deserialize a JSON string, recording the path to the field that failed
+///
+/// The error's `Display` is prefixed with the JSON path (e.g. `tasks[3].status`),
+/// so a change in the API's response shape can be traced to the exact field.
+pub fn from_json_str<T: DeserializeOwned>(
+    json: &str,
+) -> Result<T, serde_path_to_error::Error<serde_json::Error>> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize(&mut deserializer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,6 +240,22 @@ mod tests {
         assert_eq!(test.value, "");
     }
 
+    #[test]
+    fn test_flexible_count() {
+        #[derive(Deserialize)]
+        struct Test {
+            #[serde(default, deserialize_with = "flexible_count")]
+            count: Option<u32>,
+        }
+
+        let count = |json: &str| serde_json::from_str::<Test>(json).unwrap().count;
+        assert_eq!(count(r#"{"count": 3}"#), Some(3));
+        assert_eq!(count(r#"{"count": "4"}"#), Some(4));
+        assert_eq!(count(r#"{"count": [{"user": {}}, {"user": {}}]}"#), Some(2));
+        assert_eq!(count(r#"{"count": null}"#), None);
+        assert_eq!(count(r#"{}"#), None);
+    }
+
     #[test]
     fn test_null_to_empty_vec() {
         #[derive(Deserialize)]
@@ -358,4 +431,163 @@ mod tests {
         let test: Test = serde_json::from_str(json).unwrap();
         assert_eq!(test.resolved, None);
     }
+
+    /// Deterministic xorshift generator so failures reproduce
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+            items[(self.next() % items.len() as u64) as usize]
+        }
+    }
+
+    /// A random value of the kinds the API has been seen to send
+    enum Sample {
+        Int(i64),
+        NumericString(i64),
+        Null,
+        Bool(bool),
+        Float(f64),
+        Garbage(String),
+    }
+
+    impl Sample {
+        fn random(rng: &mut Rng) -> Self {
+            let int = match rng.next() % 3 {
+                0 => (rng.next() % 1000) as i64,
+                1 => rng.next() as i64,
+                _ => -((rng.next() % 100_000) as i64),
+            };
+            match rng.next() % 6 {
+                0 => Sample::Int(int),
+                1 => Sample::NumericString(int),
+                2 => Sample::Null,
+                3 => Sample::Bool(rng.next().is_multiple_of(2)),
+                4 => Sample::Float(int as f64 + 0.5),
+                _ => {
+                    let parts = [
+                        "", " ", "abc", "12a", "0x1F", "1e3", "--1", "NaN", "✓", "1 2",
+                    ];
+                    Sample::Garbage(rng.pick(&parts).to_string() + rng.pick(&parts))
+                }
+            }
+        }
+
+        fn json(&self) -> String {
+            match self {
+                Sample::Int(v) => v.to_string(),
+                Sample::NumericString(v) => format!("\"{}\"", v),
+                Sample::Null => "null".to_string(),
+                Sample::Bool(b) => b.to_string(),
+                Sample::Float(f) => format!("{:?}", f),
+                Sample::Garbage(s) => serde_json::to_string(s).unwrap(),
+            }
+        }
+    }
+
+    /// Feed random samples to a helper, checking each against `expected`
+    ///
+    /// `expected` returns the value the helper should produce, or `None` when
+    /// it should fail; failures must carry a message naming what was expected.
+    fn check_samples<T, E>(seed: u64, expected: E)
+    where
+        T: serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+        E: Fn(&Sample) -> Option<T>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper<T> {
+            value: T,
+        }
+
+        let mut rng = Rng(seed);
+        for _ in 0..500 {
+            let sample = Sample::random(&mut rng);
+            let json = format!(r#"{{"value": {}}}"#, sample.json());
+            let result = serde_json::from_str::<Wrapper<T>>(&json).map(|w| w.value);
+            match (expected(&sample), result) {
+                (Some(want), Ok(got)) => assert_eq!(got, want, "input {}", json),
+                (None, Err(e)) => assert!(
+                    e.to_string().contains("expected") || e.to_string().contains("invalid type"),
+                    "undescriptive error for {}: {}",
+                    json,
+                    e
+                ),
+                (want, got) => panic!("input {}: wanted {:?}, got {:?}", json, want, got),
+            }
+        }
+    }
+
+    /// Expected result for the numeric helpers: numbers and numeric strings
+    /// within range of `T`, `None` for null, an error for anything else
+    fn numeric<T: TryFrom<i64>>(sample: &Sample) -> Option<Option<T>> {
+        match sample {
+            Sample::Int(v) | Sample::NumericString(v) => T::try_from(*v).ok().map(Some),
+            Sample::Null => Some(None),
+            Sample::Garbage(s) => s
+                .parse::<i64>()
+                .ok()
+                .and_then(|v| T::try_from(v).ok())
+                .map(Some),
+            Sample::Bool(_) | Sample::Float(_) => None,
+        }
+    }
+
+    #[test]
+    fn test_numeric_helpers_with_random_input() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Timestamp(#[serde(deserialize_with = "flexible_timestamp")] Option<i64>);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Int(#[serde(deserialize_with = "flexible_int")] Option<i32>);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct I64(#[serde(deserialize_with = "flexible_i64")] Option<i64>);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Count(#[serde(deserialize_with = "flexible_count")] Option<u32>);
+
+        check_samples(0x5eed_0001, |s| numeric::<i64>(s).map(Timestamp));
+        check_samples(0x5eed_0002, |s| numeric::<i32>(s).map(Int));
+        check_samples(0x5eed_0003, |s| numeric::<i64>(s).map(I64));
+        check_samples(0x5eed_0004, |s| numeric::<u32>(s).map(Count));
+    }
+
+    #[test]
+    fn test_non_numeric_helpers_with_random_input() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Id(#[serde(deserialize_with = "flexible_string")] String);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Resolved(#[serde(deserialize_with = "flexible_resolved")] Option<i64>);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct UserId(#[serde(deserialize_with = "null_to_default_id")] i64);
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Flag(#[serde(deserialize_with = "null_to_false")] bool);
+
+        check_samples(0x5eed_0005, |s| match s {
+            Sample::Int(v) | Sample::NumericString(v) => Some(Id(v.to_string())),
+            Sample::Garbage(g) => Some(Id(g.clone())),
+            Sample::Null => Some(Id(String::new())),
+            Sample::Bool(_) | Sample::Float(_) => None,
+        });
+        check_samples(0x5eed_0006, |s| match s {
+            Sample::Int(v) => Some(Resolved(Some(*v))),
+            Sample::Bool(b) => Some(Resolved(Some(*b as i64))),
+            Sample::Null => Some(Resolved(None)),
+            _ => None,
+        });
+        check_samples(0x5eed_0007, |s| match s {
+            Sample::Int(v) => Some(UserId(*v)),
+            Sample::Null => Some(UserId(0)),
+            _ => None,
+        });
+        check_samples(0x5eed_0008, |s| match s {
+            Sample::Bool(b) => Some(Flag(*b)),
+            Sample::Null => Some(Flag(false)),
+            _ => None,
+        });
+    }
 }
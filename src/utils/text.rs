@@ -18,6 +18,40 @@ pub fn format_timestamp(ts: i64) -> String {
     }
 }
 
+/// Describe an age in seconds, e.g. "5 minutes ago"
+pub fn format_age(secs: i64) -> String {
+    match secs.max(0) / 60 {
+        0 => "less than a minute ago".to_string(),
+        1 => "1 minute ago".to_string(),
+        minutes if minutes < 120 => format!("{} minutes ago", minutes),
+        minutes if minutes < 48 * 60 => format!("{} hours ago", minutes / 60),
+        minutes => format!("{} days ago", minutes / (24 * 60)),
+    }
+}
+
+/// Cut `text` to at most `max_chars` characters, ending in "..." when cut
+///
+/// Counts characters rather than bytes, so it never splits a multibyte
+/// character.
+pub fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+    format!("{}...", kept)
+}
+
+/// Byte offset of the `char_index`-th character of `text`, or the length of
+/// `text` when the index is past the end
+///
+/// Turns a cursor counted in characters into an index that `String::insert`
+/// and `String::remove` accept.
+pub fn byte_offset(text: &str, char_index: usize) -> usize {
+    text.char_indices()
+        .nth(char_index)
+        .map_or(text.len(), |(offset, _)| offset)
+}
+
 /// Wrap text to fit within the given width
 pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
     let mut lines = Vec::new();
@@ -45,3 +79,68 @@ pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
 
     lines
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(30), "less than a minute ago");
+        assert_eq!(format_age(60), "1 minute ago");
+        assert_eq!(format_age(5 * 60 + 10), "5 minutes ago");
+        assert_eq!(format_age(3 * 3600), "3 hours ago");
+        assert_eq!(format_age(3 * 86400), "3 days ago");
+        assert_eq!(format_age(-5), "less than a minute ago");
+    }
+
+    #[test]
+    fn test_truncate_short_text_unchanged() {
+        assert_eq!(truncate("Alice", 20), "Alice");
+        assert_eq!(truncate("", 5), "");
+        assert_eq!(truncate("exactly", 7), "exactly");
+    }
+
+    #[test]
+    fn test_truncate_counts_characters() {
+        assert_eq!(truncate("abcdefghij", 8), "abcde...");
+        // 18 two-byte characters: a byte slice at 17 would split one
+        let author = "é".repeat(18);
+        assert_eq!(truncate(&author, 17), format!("{}...", "é".repeat(14)));
+        assert_eq!(truncate("日本語のユーザー名です", 8), "日本語のユ...");
+    }
+
+    #[test]
+    fn test_truncate_at_four_byte_character() {
+        // Emoji take four bytes in UTF-8 and a surrogate pair in UTF-16; the
+        // cut falls right next to one
+        let author = "Zoë 🎉 Müller-Świątek 🚀";
+        assert_eq!(truncate(author, 20), "Zoë 🎉 Müller-Świą...");
+        assert_eq!(truncate("🎉🎉🎉🎉🎉", 4), "🎉...");
+    }
+
+    #[test]
+    fn test_byte_offset_counts_characters() {
+        let text = "aé🎉b";
+        assert_eq!(byte_offset(text, 0), 0);
+        assert_eq!(byte_offset(text, 1), 1);
+        assert_eq!(byte_offset(text, 2), 3);
+        assert_eq!(byte_offset(text, 3), 7);
+        assert_eq!(byte_offset(text, 4), text.len());
+        assert_eq!(byte_offset(text, 10), text.len());
+    }
+
+    #[test]
+    fn test_truncate_url_with_multibyte_path() {
+        // Byte 57 falls inside a multibyte character here
+        let url = format!(
+            "https://app.clickup.com/t/{}{}",
+            "a".repeat(30),
+            "ü".repeat(20)
+        );
+        let shown = truncate(&url, 60);
+        assert_eq!(shown.chars().count(), 60);
+        assert!(shown.ends_with("..."));
+        assert!(url.starts_with(shown.trim_end_matches("...")));
+    }
+}
@@ -15,6 +15,9 @@
 //! - Comment: `https://app.clickup.com/{workspace_id}/l/{list_id}/t/{task_id}/comment/{comment_id}`
 //! - Document: `https://app.clickup.com/{workspace_id}/d/{doc_id}`
 
+use crate::models::task::is_custom_task_id;
+use crate::utils::url_parser::{ParsedUrl, UrlParser};
+
 /// Base URL for ClickUp web app
 const CLICKUP_BASE_URL: &str = "https://app.clickup.com";
 
@@ -23,6 +26,7 @@ pub type UrlResult<T> = Result<T, UrlError>;
 
 /// Error type for URL generation failures
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 pub enum UrlError {
     /// Missing required workspace ID
     MissingWorkspace,
@@ -115,14 +119,19 @@ pub trait UrlGenerator {
     /// The list URL or error if missing required IDs
     fn list_url(workspace_id: &str, list_id: &str) -> UrlResult<String>;
 
-    /// Generate URL for a task (short-form: no workspace/list needed)
+    /// Generate URL for a task
+    ///
+    /// Produces the long-form URL when both workspace and list IDs are known,
+    /// falling back to the short-form URL otherwise.
     ///
     /// # Arguments
+    /// * `workspace_id` - The workspace ID (may be empty)
+    /// * `list_id` - The list ID (may be empty)
     /// * `task_id` - The task ID
     ///
     /// # Returns
     /// The task URL
-    fn task_url(_workspace_id: &str, _list_id: &str, task_id: &str) -> UrlResult<String>;
+    fn task_url(workspace_id: &str, list_id: &str, task_id: &str) -> UrlResult<String>;
 
     /// Generate URL for a comment (includes task context)
     ///
@@ -207,11 +216,19 @@ impl UrlGenerator for ClickUpUrlGenerator {
         ))
     }
 
-    fn task_url(_workspace_id: &str, _list_id: &str, task_id: &str) -> UrlResult<String> {
-        // Use short-form URL: https://app.clickup.com/t/{task_id}
+    fn task_url(workspace_id: &str, list_id: &str, task_id: &str) -> UrlResult<String> {
         if task_id.is_empty() {
             return Err(UrlError::MissingTask);
         }
+        // Long-form URL when context is known:
+        // https://app.clickup.com/{workspace_id}/l/{list_id}/t/{task_id}
+        if !workspace_id.is_empty() && !list_id.is_empty() {
+            return Ok(format!(
+                "{}/{}/l/{}/t/{}",
+                CLICKUP_BASE_URL, workspace_id, list_id, task_id
+            ));
+        }
+        // Short-form fallback: https://app.clickup.com/t/{task_id}
         Ok(format!("{}/t/{}", CLICKUP_BASE_URL, task_id))
     }
 
@@ -243,6 +260,63 @@ impl UrlGenerator for ClickUpUrlGenerator {
     }
 }
 
+impl ClickUpUrlGenerator {
+    /// Extract the task ID from a ClickUp task (or task comment) URL
+    ///
+    /// Accepts the short `/t/{task_id}` form, the workspace-prefixed forms and
+    /// task links nested under a space or list path. The scheme may be
+    /// omitted. Returns `None` for anything that isn't a task link.
+    pub fn extract_task_id(url: &str) -> Option<String> {
+        let rest = Self::strip_link_scheme(url)?;
+        let url = format!("https://{}", rest);
+
+        if let Ok(
+            ParsedUrl::Task { task_id }
+            | ParsedUrl::WorkspaceTask { task_id, .. }
+            | ParsedUrl::Comment { task_id, .. },
+        ) = UrlParser::parse(&url)
+        {
+            return Some(task_id);
+        }
+
+        // Task opened from a space or folder view: .../t/{task_id}
+        let path = rest.split('?').next().unwrap_or(rest).trim_end_matches('/');
+        let segments: Vec<&str> = path.split('/').collect();
+        segments
+            .windows(2)
+            .rev()
+            .find(|pair| pair[0] == "t" && !pair[1].is_empty())
+            .map(|pair| pair[1].to_string())
+    }
+
+    /// Extract the workspace ID and custom task ID from a task link
+    ///
+    /// Only workspace-prefixed links (`/t/{workspace_id}/{task_id}`) whose ID
+    /// looks like a custom ID (e.g. `DEV-42`) match; ClickUp looks those up
+    /// per workspace. Returns `(workspace_id, custom_id)`.
+    pub fn extract_custom_task_id(url: &str) -> Option<(String, String)> {
+        let url = format!("https://{}", Self::strip_link_scheme(url)?);
+        match UrlParser::parse(&url) {
+            Ok(ParsedUrl::WorkspaceTask {
+                workspace_id,
+                task_id,
+            }) if is_custom_task_id(&task_id) => Some((workspace_id, task_id)),
+            _ => None,
+        }
+    }
+
+    /// Trim a ClickUp link down to `app.clickup.com/...`, without scheme or fragment
+    fn strip_link_scheme(url: &str) -> Option<&str> {
+        let url = url.trim();
+        let rest = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .unwrap_or(url);
+        let rest = rest.split('#').next().unwrap_or(rest);
+        rest.starts_with("app.clickup.com/").then_some(rest)
+    }
+}
+
 /// Helper function to truncate URL for display
 #[cfg(test)]
 mod tests {
@@ -304,8 +378,23 @@ mod tests {
 
     #[test]
     fn test_task_url_generation() {
-        // Short-form URL: workspace and list params are ignored
+        // Long-form URL when workspace and list context are known
         let url = ClickUpUrlGenerator::task_url("ws123", "list012", "task345").unwrap();
+        assert_eq!(url, "https://app.clickup.com/ws123/l/list012/t/task345");
+    }
+
+    #[test]
+    fn test_task_url_without_context_is_short_form() {
+        let url = ClickUpUrlGenerator::task_url("", "", "task345").unwrap();
+        assert_eq!(url, "https://app.clickup.com/t/task345");
+    }
+
+    #[test]
+    fn test_task_url_partial_context_is_short_form() {
+        let url = ClickUpUrlGenerator::task_url("ws123", "", "task345").unwrap();
+        assert_eq!(url, "https://app.clickup.com/t/task345");
+
+        let url = ClickUpUrlGenerator::task_url("", "list012", "task345").unwrap();
         assert_eq!(url, "https://app.clickup.com/t/task345");
     }
 
@@ -342,6 +431,63 @@ mod tests {
         assert_eq!(result, Err(UrlError::MissingDocument));
     }
 
+    #[test]
+    fn test_extract_task_id() {
+        let cases = [
+            ("https://app.clickup.com/t/abc123def", "abc123def"),
+            ("app.clickup.com/t/abc123def/", "abc123def"),
+            ("https://app.clickup.com/t/9012/PROJ-42", "PROJ-42"),
+            ("https://app.clickup.com/t/abc123?comment=c9", "abc123"),
+            ("https://app.clickup.com/9012/l/list1/t/abc123", "abc123"),
+            (
+                "https://app.clickup.com/9012/v/o/s/space1/t/abc123",
+                "abc123",
+            ),
+            ("  http://app.clickup.com/t/abc123#activity  ", "abc123"),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(
+                ClickUpUrlGenerator::extract_task_id(url).as_deref(),
+                Some(expected),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_task_id_rejects_other_links() {
+        assert_eq!(
+            ClickUpUrlGenerator::extract_task_id("https://app.clickup.com/d/doc1"),
+            None
+        );
+        assert_eq!(
+            ClickUpUrlGenerator::extract_task_id("https://app.clickup.com/9012/v/li/list1"),
+            None
+        );
+        assert_eq!(
+            ClickUpUrlGenerator::extract_task_id("https://example.com/t/abc123"),
+            None
+        );
+        assert_eq!(ClickUpUrlGenerator::extract_task_id("abc123"), None);
+    }
+
+    #[test]
+    fn test_extract_custom_task_id() {
+        assert_eq!(
+            ClickUpUrlGenerator::extract_custom_task_id("app.clickup.com/t/9012/PROJ-42"),
+            Some(("9012".to_string(), "PROJ-42".to_string()))
+        );
+        assert_eq!(
+            ClickUpUrlGenerator::extract_custom_task_id("https://app.clickup.com/t/9012/abc123"),
+            None
+        );
+        assert_eq!(
+            ClickUpUrlGenerator::extract_custom_task_id("https://app.clickup.com/t/PROJ-42"),
+            None
+        );
+    }
+
     #[test]
     fn test_url_error_display() {
         assert_eq!(
@@ -12,6 +12,12 @@
 //! - Space: `https://app.clickup.com/{workspace_id}/v/o/s/{space_id}`
 //! - Folder: `https://app.clickup.com/{workspace_id}/v/o/f/{folder_id}`
 //! - List: `https://app.clickup.com/{workspace_id}/v/l/{view}-{list_id}-{suffix}`
+//! - Task: `https://app.clickup.com/{workspace_id}/l/{list_id}/t/{task_id}`
+//!
+//! ## Web app URLs (as copied from the ClickUp browser address bar):
+//! - List: `https://app.clickup.com/{workspace_id}/v/li/{list_id}`
+//! - Document: `https://app.clickup.com/{workspace_id}/v/dc/{doc_id}/{page_id}`
+//! - Task (custom ID): `https://app.clickup.com/t/{workspace_id}/{task_id}`
 //!
 //! ## Short-form URLs:
 //! - Task: `https://app.clickup.com/t/{task_id}`
@@ -73,10 +79,19 @@ pub enum ParsedUrl {
         workspace_id: String,
         list_id: String,
     },
-    /// Task URL (short-form): `https://app.clickup.com/t/{task_id}`
+    /// Task URL: `https://app.clickup.com/t/{task_id}` or
+    /// `https://app.clickup.com/{workspace_id}/l/{list_id}/t/{task_id}`
     Task {
         task_id: String,
     },
+    /// Workspace-prefixed task URL: `https://app.clickup.com/t/{workspace_id}/{task_id}`
+    ///
+    /// ClickUp uses this form for custom task IDs, which only resolve
+    /// within their workspace.
+    WorkspaceTask {
+        workspace_id: String,
+        task_id: String,
+    },
     /// Comment URL (short-form): `https://app.clickup.com/t/{task_id}?comment={comment_id}`
     Comment {
         task_id: String,
@@ -139,7 +154,14 @@ impl UrlParser {
     /// Try to match short-form URL patterns: /t/{id}, /d/{id}
     fn try_short_form(path: &str, query: Option<&str>) -> ParseResult<Option<ParsedUrl>> {
         // Task or comment: /t/{task_id} or /t/{task_id}?comment={comment_id}
+        // Workspace-prefixed custom IDs use /t/{workspace_id}/{task_id}
         if let Some(rest) = path.strip_prefix("t/") {
+            if let Some((workspace_id, task_id)) = rest.split_once('/') {
+                return Ok(Some(ParsedUrl::WorkspaceTask {
+                    workspace_id: Self::extract_id(workspace_id)?.to_string(),
+                    task_id: Self::extract_id(task_id)?.to_string(),
+                }));
+            }
             let task_id = Self::extract_id(rest)?;
 
             // Check for comment query parameter
@@ -214,6 +236,33 @@ impl UrlParser {
             });
         }
 
+        // Task: {workspace_id}/l/{list_id}/t/{task_id}
+        // Path has 5 segments: [workspace_id, l, list_id, t, task_id]
+        if segments.len() >= 5 && segments[1] == "l" && segments[3] == "t" {
+            Self::extract_id(segments[2])?;
+            let task_id = Self::extract_id(segments[4])?;
+            return Ok(ParsedUrl::Task {
+                task_id: task_id.to_string(),
+            });
+        }
+
+        // List (web app): {workspace_id}/v/li/{list_id}
+        if segments.len() >= 4 && segments[1] == "v" && segments[2] == "li" {
+            let list_id = Self::extract_id(segments[3])?;
+            return Ok(ParsedUrl::List {
+                workspace_id: workspace_id.to_string(),
+                list_id: list_id.to_string(),
+            });
+        }
+
+        // Document (web app): {workspace_id}/v/dc/{doc_id}[/{page_id}]
+        if segments.len() >= 4 && segments[1] == "v" && segments[2] == "dc" {
+            let doc_id = Self::extract_id(segments[3])?;
+            return Ok(ParsedUrl::Document {
+                doc_id: doc_id.to_string(),
+            });
+        }
+
         // List: {workspace_id}/v/l/{view}-{list_id}-{suffix}
         // Path has 4 segments: [workspace_id, v, l, pattern]
         if segments.len() >= 4
@@ -428,6 +477,44 @@ mod tests {
         }
     }
 
+    // ========== Web app URL shapes ==========
+
+    #[test]
+    fn test_parse_workspace_prefixed_task_url() {
+        let result = UrlParser::parse("https://app.clickup.com/t/9012345/PROJ-42").unwrap();
+        assert_eq!(
+            result,
+            ParsedUrl::WorkspaceTask {
+                workspace_id: "9012345".to_string(),
+                task_id: "PROJ-42".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_web_app_list_url() {
+        let result = UrlParser::parse("https://app.clickup.com/9012345/v/li/901234567").unwrap();
+        assert_eq!(
+            result,
+            ParsedUrl::List {
+                workspace_id: "9012345".to_string(),
+                list_id: "901234567".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_web_app_doc_url_with_page() {
+        let result =
+            UrlParser::parse("https://app.clickup.com/9012345/v/dc/8cdu0-1234/8cdu0-5678").unwrap();
+        assert_eq!(
+            result,
+            ParsedUrl::Document {
+                doc_id: "8cdu0-1234".to_string()
+            }
+        );
+    }
+
     // ========== Error cases ==========
 
     #[test]
@@ -524,6 +611,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_roundtrip_long_form_task_url() {
+        let generated = ClickUpUrlGenerator::task_url("ws123", "list012", "task345").unwrap();
+        let parsed = UrlParser::parse(&generated).unwrap();
+        match parsed {
+            ParsedUrl::Task { task_id } => {
+                assert_eq!(task_id, "task345");
+            }
+            _ => panic!("Expected Task, got {:?}", parsed),
+        }
+    }
+
     #[test]
     fn test_roundtrip_comment_url() {
         let generated =
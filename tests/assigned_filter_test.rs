@@ -104,8 +104,10 @@ fn test_mock_client_with_assigned_tasks() {
 fn test_task_filters_with_assignees() {
     use clickdown::models::task::TaskFilters;
 
-    let mut filters = TaskFilters::default();
-    filters.assignees = vec![123, 456];
+    let filters = TaskFilters {
+        assignees: vec![123, 456],
+        ..Default::default()
+    };
 
     let query_string = filters.to_query_string();
 
@@ -122,6 +122,7 @@ fn test_mock_update_task_merges_assignees() {
                 vec![1, 2, 3].into_iter().collect(),
             )),
             due_date: None,
+            archived: None,
         };
         mock_client.update_task("test-task-1", &update).await
     });
@@ -157,6 +158,7 @@ fn test_mock_update_task_merges_all_fields() {
                 vec![42].into_iter().collect(),
             )),
             due_date: Some(1700000000000),
+            archived: None,
         };
         mock_client.update_task("test-task-1", &update).await
     });
@@ -196,6 +198,7 @@ fn test_mock_update_task_clears_assignees() {
                 vec![].into_iter().collect(),
             )),
             due_date: None,
+            archived: None,
         };
         mock_client.update_task("test-task-1", &update).await
     });
@@ -222,6 +225,7 @@ fn test_mock_update_task_preserves_assignees_when_not_updated() {
             priority: None,
             assignees: None,
             due_date: None,
+            archived: None,
         };
         mock_client.update_task("test-task-1", &update).await
     });
@@ -6,7 +6,7 @@ use clickdown::commands::DebugOperations;
 use std::sync::Arc;
 
 mod fixtures;
-use fixtures::{test_document, test_task, test_workspace};
+use fixtures::{test_document, test_page, test_task, test_workspace};
 
 #[tokio::test]
 async fn test_debug_list_workspaces() {
@@ -99,3 +99,302 @@ async fn test_debug_auth_status_not_authenticated() {
     assert!(result.is_ok());
     assert!(!result.unwrap());
 }
+
+#[tokio::test]
+async fn test_debug_drain_queue() {
+    use clickdown::cache::CacheManager;
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut cache = CacheManager::new(dir.path().join("cache.db")).unwrap();
+    cache
+        .queue_request("DELETE", "https://api.clickup.com/api/v2/task/t1", None)
+        .unwrap();
+    let mock_client = MockClickUpClient::new().with_send_request_success();
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.drain_queue(&mut cache).await.is_ok());
+    assert_eq!(cache.queued_request_count().unwrap(), 0);
+    assert!(debug_ops.drain_queue_json(&mut cache).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_views_and_view_tasks() {
+    let view = clickdown::models::View {
+        id: "view-1".to_string(),
+        name: "Bugs - P1 only".to_string(),
+        view_type: Some("list".to_string()),
+        parent: None,
+    };
+    let mock_client = MockClickUpClient::new()
+        .with_list_views(vec![view])
+        .with_view_task_pages(vec![vec![test_task()], vec![test_task()]]);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.list_views("list-1").await.is_ok());
+    assert!(debug_ops.list_views_json("list-1").await.is_ok());
+    assert!(debug_ops.view_tasks("view-1").await.is_ok());
+    assert!(debug_ops.view_tasks_json("view-1").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_sprint_overview() {
+    let overview = clickdown::models::SprintOverview::from_tasks(
+        "Sprint 1",
+        clickdown::models::SprintDates::default(),
+        &[test_task()],
+        0,
+    );
+    let mock_client = MockClickUpClient::new().with_sprint_overview(overview);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.sprint_overview("view-1").await.is_ok());
+    assert!(debug_ops.sprint_overview_json("view-1").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_sprint_overview_error() {
+    let mock_client =
+        MockClickUpClient::new().with_sprint_overview_error("View not found".to_string());
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    let result = debug_ops.sprint_overview("missing").await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_debug_timesheet() {
+    use clickdown::cli::args::parse_date_range;
+
+    let mock_client =
+        MockClickUpClient::new().with_workspace_time_entries(vec![fixtures::test_time_entry()]);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+    let range = parse_date_range("2024-03-01", "2024-03-08").unwrap();
+
+    assert!(debug_ops.timesheet("ws-1", range, None).await.is_ok());
+    assert!(debug_ops
+        .timesheet_json("ws-1", range, Some("123"))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_timesheet_without_time_tracking_names_the_plan() {
+    use clickdown::cli::args::parse_date_range;
+
+    let free_plan: clickdown::models::ClickUpSpace =
+        serde_json::from_str(include_str!("fixtures/space_free_plan.json")).unwrap();
+    let mock_client = MockClickUpClient::new()
+        .with_spaces(vec![free_plan])
+        .with_workspace_time_entries_error(
+            r#"API error (401 Unauthorized): {"err":"Team not authorized","ECODE":"OAUTH_027"}"#,
+        );
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), AuthManager::default(), None);
+    let range = parse_date_range("2024-03-01", "2024-03-08").unwrap();
+
+    let error = debug_ops.timesheet("ws-1", range, None).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to load time entries: time tracking is not available on your plan"
+    );
+    assert!(debug_ops.workspace_features("ws-1").await.is_ok());
+    assert!(debug_ops.workspace_features_json("ws-1").await.is_ok());
+
+    // With time tracking on, ClickUp's own reason is shown
+    let mock_client = MockClickUpClient::new()
+        .with_spaces(vec![fixtures::test_space()])
+        .with_workspace_time_entries_error(
+            r#"API error (401 Unauthorized): {"err":"Team not authorized","ECODE":"OAUTH_027"}"#,
+        );
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), AuthManager::default(), None);
+    let error = debug_ops.timesheet_json("ws-1", range, None).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to load time entries: Team not authorized (OAUTH_027)"
+    );
+}
+
+#[tokio::test]
+async fn test_debug_my_tasks() {
+    let mock_client = MockClickUpClient::new().with_my_tasks(vec![fixtures::test_task()]);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.my_tasks("ws-1").await.is_ok());
+    assert!(debug_ops.my_tasks_json("ws-1").await.is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_standup() {
+    use clickdown::cli::args::Since;
+
+    let mock_client = MockClickUpClient::new().with_my_tasks(vec![fixtures::test_task()]);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.standup("ws-1", Since::default()).await.is_ok());
+    assert!(debug_ops.standup_json("ws-1", Since::default()).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_debug_standup_error() {
+    use clickdown::cli::args::Since;
+
+    let mock_client = MockClickUpClient::new().with_my_tasks_error("Network error".to_string());
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.standup("ws-1", Since::default()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_debug_open_url() {
+    let mock_client = MockClickUpClient::new().with_task(test_task());
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops
+        .open_url("https://app.clickup.com/t/abc123def")
+        .await
+        .is_ok());
+    assert!(debug_ops
+        .open_url_json("https://app.clickup.com/9012/l/list1/t/abc123def")
+        .await
+        .is_ok());
+    assert!(debug_ops
+        .open_url("https://app.clickup.com/d/doc1")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_debug_verify_env_reports_failures() {
+    use clickdown::commands::verify_env::EnvPaths;
+
+    let dir = tempfile::tempdir().unwrap();
+    let paths = EnvPaths {
+        config_dir: dir.path().to_path_buf(),
+        cache_dir: dir.path().to_path_buf(),
+        database: dir.path().join("cache.db"),
+    };
+    let mock_client = MockClickUpClient::new();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), AuthManager::default(), None);
+
+    assert!(
+        debug_ops
+            .print_env_checks(Some("pk_test"), &paths, Some("xterm-256color"))
+            .await
+    );
+    assert!(
+        !debug_ops
+            .print_env_checks(None, &paths, Some("xterm-256color"))
+            .await
+    );
+    assert!(
+        !debug_ops
+            .print_env_checks(Some("pk_test"), &paths, Some("dumb"))
+            .await
+    );
+}
+
+#[tokio::test]
+async fn test_debug_delete_comment() {
+    let mock_client = MockClickUpClient::new().with_delete_comment_success();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), AuthManager::default(), None);
+
+    assert!(debug_ops.delete_comment("c1").await.is_ok());
+    assert!(debug_ops.delete_comment_json("c1").await.is_ok());
+
+    let failing = MockClickUpClient::new().with_delete_comment_error("not found");
+    let debug_ops = DebugOperations::new(Arc::new(failing), AuthManager::default(), None);
+    assert!(debug_ops.delete_comment("c1").await.is_err());
+}
+
+#[tokio::test]
+async fn test_debug_create_and_update_space() {
+    use clickdown::models::UpdateSpaceRequest;
+
+    let mut updated = fixtures::test_space();
+    updated.name = "Ops".to_string();
+    updated.private = true;
+    let mock = Arc::new(
+        MockClickUpClient::new()
+            .with_create_space_response(fixtures::test_space())
+            .with_update_space_response(updated),
+    );
+    let debug_ops = DebugOperations::new(mock.clone(), AuthManager::default(), None);
+    let update = UpdateSpaceRequest {
+        name: Some("Ops".to_string()),
+        color: None,
+        private: Some(true),
+    };
+
+    assert!(debug_ops.create_space("ws-1", "Test Space", false).await.is_ok());
+    assert!(debug_ops.create_space_json("ws-1", "Test Space", false).await.is_ok());
+    assert!(debug_ops.update_space("sp-1", &update).await.is_ok());
+    assert!(debug_ops.update_space_json("sp-1", &update).await.is_ok());
+    assert_eq!(
+        mock.recorded_calls()[2],
+        r#"update_space sp-1 {"name":"Ops","private":true}"#
+    );
+}
+
+#[tokio::test]
+async fn test_debug_space_errors_show_clickup_reason() {
+    use clickdown::models::UpdateSpaceRequest;
+
+    let mock_client = MockClickUpClient::new().with_space_error(
+        r#"API error (400 Bad Request): {"err":"Space name taken","ECODE":"PROJ_017"}"#,
+    );
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), AuthManager::default(), None);
+
+    let error = debug_ops
+        .create_space("ws-1", "Marketing", false)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to create space: Space name taken (PROJ_017)"
+    );
+
+    let error = debug_ops
+        .update_space_json("sp-1", &UpdateSpaceRequest::default())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Failed to update space: Space name taken (PROJ_017)"
+    );
+}
+
+#[tokio::test]
+async fn test_debug_get_document_and_page() {
+    let mock_client = MockClickUpClient::new()
+        .with_documents(vec![test_document()])
+        .with_pages(vec![test_page("p1", "# One"), test_page("p2", "# Two")]);
+
+    let auth = AuthManager::default();
+    let debug_ops = DebugOperations::new(Arc::new(mock_client), auth, None);
+
+    assert!(debug_ops.get_document("test-doc-1", None).await.is_ok());
+    assert!(debug_ops.get_document_json("test-doc-1", None).await.is_ok());
+    assert!(debug_ops.get_document("test-doc-1", Some("p2")).await.is_ok());
+    assert!(debug_ops.get_document("missing-doc", None).await.is_err());
+    assert!(debug_ops
+        .get_document_json("test-doc-1", Some("missing-page"))
+        .await
+        .is_err());
+}
@@ -1,7 +1,7 @@
 //! Test fixtures for ClickDown tests
 
 use clickdown::models::comment::Comment;
-use clickdown::models::document::Document;
+use clickdown::models::document::{Document, Page};
 use clickdown::models::task::Task;
 use clickdown::models::workspace::{Folder, List, Space, Workspace};
 
@@ -28,6 +28,7 @@ pub fn test_space() -> Space {
         status: None,
         folders: vec![],
         lists: vec![],
+        features: Default::default(),
     }
 }
 
@@ -41,6 +42,10 @@ pub fn test_folder() -> Folder {
         private: false,
         space: None,
         lists: vec![],
+        statuses: vec![],
+        hidden: false,
+        archived: false,
+        task_count: None,
     }
 }
 
@@ -59,9 +64,13 @@ pub fn test_list() -> List {
         folder: None,
         status: None,
         priority: None,
+        due_date: None,
+        task_count: None,
+        statuses: vec![],
     }
 }
 
+#[allow(dead_code)]
 /// Create a test task
 pub fn test_task() -> Task {
     Task {
@@ -91,6 +100,7 @@ pub fn test_task() -> Task {
         priority: None,
         due_date: None,
         start_date: None,
+        recurrence: None,
         points: None,
         custom_fields: vec![],
         attachments: vec![],
@@ -127,6 +137,21 @@ pub fn test_document() -> Document {
     }
 }
 
+/// Create a test document page with `markdown` as its content
+#[allow(dead_code)]
+pub fn test_page(id: &str, markdown: &str) -> Page {
+    Page {
+        id: id.to_string(),
+        name: format!("Page {}", id),
+        content: None,
+        content_markdown: Some(markdown.to_string()),
+        order: None,
+        created_at: None,
+        updated_at: None,
+        children: vec![],
+    }
+}
+
 #[allow(dead_code)]
 /// Create a test comment user
 pub fn test_comment_user() -> clickdown::models::User {
@@ -155,6 +180,7 @@ pub fn test_comment() -> Comment {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     }
 }
 
@@ -173,6 +199,7 @@ pub fn test_comment_edited() -> Comment {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     }
 }
 
@@ -182,6 +209,7 @@ pub fn test_comments() -> Vec<Comment> {
     vec![test_comment(), test_comment_edited()]
 }
 
+#[allow(dead_code)]
 /// Create a test user (for task assignees)
 pub fn test_user() -> clickdown::models::User {
     clickdown::models::User {
@@ -194,6 +222,7 @@ pub fn test_user() -> clickdown::models::User {
     }
 }
 
+#[allow(dead_code)]
 /// Create a test task with assignees
 pub fn test_task_with_assignee() -> Task {
     let mut task = test_task();
@@ -203,6 +232,7 @@ pub fn test_task_with_assignee() -> Task {
     task
 }
 
+#[allow(dead_code)]
 /// Create multiple test tasks with assignees
 pub fn test_tasks_with_assignees() -> Vec<Task> {
     vec![
@@ -229,3 +259,21 @@ pub fn test_tasks_with_assignees() -> Vec<Task> {
         },
     ]
 }
+
+#[allow(dead_code)]
+/// Create a test time entry: one hour logged by `test_user()` on `test_task()`
+pub fn test_time_entry() -> clickdown::models::TimeEntry {
+    let start = 1_709_542_800_000; // 2024-03-04 09:00 UTC
+    clickdown::models::TimeEntry {
+        id: "time-entry-1".to_string(),
+        task: Some(clickdown::models::time_entry::TimeEntryTask {
+            id: test_task().id,
+            name: Some(test_task().name),
+        }),
+        user: Some(test_user()),
+        start: Some(start),
+        end: Some(start + 3_600_000),
+        duration: Some(3_600_000),
+        description: None,
+    }
+}
@@ -0,0 +1,68 @@
+//! Budget test for slimming tasks with huge custom field payloads
+//!
+//! `tests/fixtures/large_custom_fields.json` holds a page of tasks whose
+//! rich-text custom fields and drop-down configs dwarf the data the UI shows.
+//! The page is repeated into a response of several MB and read both in full
+//! and slim; the slim tasks must retain a fraction of the data.
+
+use clickdown::api::task_stream::read_tasks;
+use clickdown::models::{Task, TasksResponse};
+
+const FIXTURE: &str = include_str!("fixtures/large_custom_fields.json");
+
+/// Copies of the fixture page in the generated response
+const REPEAT: usize = 100;
+
+/// A response of `REPEAT` copies of the fixture's tasks
+fn large_response() -> String {
+    let page: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+    let tasks = page["tasks"].as_array().unwrap();
+    let repeated: Vec<serde_json::Value> = (0..REPEAT)
+        .flat_map(|i| {
+            tasks.iter().map(move |task| {
+                let mut task = task.clone();
+                task["id"] = format!("{}-{}", task["id"].as_str().unwrap(), i).into();
+                task
+            })
+        })
+        .collect();
+    serde_json::json!({ "tasks": repeated }).to_string()
+}
+
+/// Size of the tasks once serialized, as a stand-in for what they hold in memory
+fn retained_bytes(tasks: &[Task]) -> usize {
+    serde_json::to_string(tasks).unwrap().len()
+}
+
+#[test]
+fn test_slim_tasks_stay_within_budget() {
+    let body = large_response();
+
+    let mut full_tasks = serde_json::from_str::<TasksResponse>(&body).unwrap().tasks;
+    let full = retained_bytes(&full_tasks);
+
+    let mut tasks = Vec::new();
+    read_tasks(body.as_bytes(), true, |task| {
+        tasks.push(task);
+        true
+    })
+    .unwrap();
+    let slim = retained_bytes(&tasks);
+
+    assert_eq!(tasks.len(), REPEAT * 5);
+    assert!(
+        slim * 5 < full,
+        "slimmed tasks should keep under a fifth of the data ({} of {} bytes)",
+        slim,
+        full
+    );
+    full_tasks.iter_mut().for_each(Task::slim);
+    assert_eq!(tasks, full_tasks);
+
+    let stage = &tasks[0].custom_fields[1];
+    let options = stage.type_config.as_ref().unwrap()["options"]
+        .as_array()
+        .unwrap();
+    assert_eq!(options.len(), 60);
+    assert_eq!(options[1]["name"], "Stage 1");
+}
@@ -9,20 +9,32 @@
 
 mod fixtures;
 
-use clickdown::models::Task;
-use clickdown::tui::app::TaskCreationField;
+use clickdown::models::{Comment, RecurrenceConfig, Task};
+use clickdown::tui::app::{CommentViewMode, TaskCreationField};
 use clickdown::tui::layout::{generate_screen_title, TuiLayout};
+use clickdown::tui::theme::Theme;
 use clickdown::tui::widgets::{
     auth::{render_auth, AuthState},
+    comments::{render_comments, CommentCollapse},
     dialog::{render_dialog, DialogState, DialogType},
     document::{render_document, DocumentState},
+    empty_state::{render_empty_state, Collection, EmptyState, LoadStatus},
     help::{render_help, HelpContext, HelpState},
+    item_info::{render_item_info, ItemInfo},
     sidebar::{render_sidebar, SidebarItem, SidebarState},
-    task_detail::{render_task_detail, TaskDetailState},
+    task_detail::{
+        render_task_description, render_task_detail, render_task_metadata, TaskDetailState,
+    },
     task_list::{render_task_list, GroupedTaskList},
 };
 use insta::assert_snapshot;
-use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    layout::Rect,
+    style::Modifier,
+    Terminal,
+};
 
 // ============================================================================
 // Test Helpers
@@ -59,6 +71,39 @@ where
     assert_snapshot!(name, snapshot);
 }
 
+/// Render into a fresh terminal and return its buffer, for checking styles
+fn render_buffer<F>(width: u16, height: u16, mut render_fn: F) -> Buffer
+where
+    F: FnMut(&mut ratatui::Frame),
+{
+    let mut terminal = create_test_terminal(width, height);
+    terminal.draw(|frame| render_fn(frame)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Column where `needle` starts in row `y`
+///
+/// Counts cells rather than bytes or chars, so glyphs that take several
+/// bytes, or a cell holding more than one char, don't shift the column.
+fn find_in_row(buffer: &Buffer, y: u16, needle: &str) -> Option<u16> {
+    let mut row = String::new();
+    let mut cell_starts = Vec::new();
+    for x in 0..buffer.area.width {
+        cell_starts.push(row.len());
+        row.push_str(buffer[(x, y)].symbol());
+    }
+    let byte = row.find(needle)?;
+    cell_starts
+        .iter()
+        .position(|&start| start == byte)
+        .map(|x| x as u16)
+}
+
+/// First row containing `needle`, with its position in the row
+fn find_text(buffer: &Buffer, needle: &str) -> Option<(u16, u16)> {
+    (0..buffer.area.height).find_map(|y| Some((find_in_row(buffer, y, needle)?, y)))
+}
+
 // ============================================================================
 // Fixture Data Generators (Task 2.4)
 // ============================================================================
@@ -124,6 +169,8 @@ fn create_sidebar_items() -> Vec<SidebarItem> {
         SidebarItem::Workspace {
             name: "Engineering".to_string(),
             id: "ws-1".to_string(),
+            color: None,
+            member_count: None,
         },
         SidebarItem::Space {
             name: "Backend".to_string(),
@@ -188,6 +235,17 @@ fn test_sidebar_with_items() {
     });
 }
 
+#[test]
+fn test_sidebar_with_nerd_font_icons() {
+    let mut sidebar = SidebarState::new().with_nerd_fonts(true);
+    *sidebar.items_mut() = create_sidebar_items();
+
+    assert_widget_snapshot("sidebar_with_nerd_font_icons", 40, 15, |frame| {
+        let area = Rect::new(0, 0, 40, 15);
+        render_sidebar(frame, &sidebar, area);
+    });
+}
+
 #[test]
 fn test_sidebar_with_selection() {
     let mut sidebar = SidebarState::new();
@@ -238,6 +296,72 @@ fn test_task_list_empty() {
     });
 }
 
+/// Render the empty-state panel of `collection` in a 60x15 area
+fn assert_empty_state_snapshot(name: &str, collection: Collection, status: LoadStatus) {
+    let empty = EmptyState::new(collection, &status).unwrap();
+
+    assert_widget_snapshot(name, 60, 15, |frame| {
+        let area = Rect::new(0, 0, 60, 15);
+        render_empty_state(frame, &empty, area);
+    });
+}
+
+#[test]
+fn test_empty_state_tasks_loaded() {
+    assert_empty_state_snapshot("empty_state_tasks_loaded", Collection::Tasks, LoadStatus::Loaded);
+}
+
+#[test]
+fn test_empty_state_lists_loaded() {
+    assert_empty_state_snapshot("empty_state_lists_loaded", Collection::Lists, LoadStatus::Loaded);
+}
+
+#[test]
+fn test_empty_state_workspaces_loaded() {
+    assert_empty_state_snapshot(
+        "empty_state_workspaces_loaded",
+        Collection::Workspaces,
+        LoadStatus::Loaded,
+    );
+}
+
+#[test]
+fn test_empty_state_spaces_loading() {
+    assert_empty_state_snapshot(
+        "empty_state_spaces_loading",
+        Collection::Spaces,
+        LoadStatus::Loading,
+    );
+}
+
+#[test]
+fn test_empty_state_folders_failed() {
+    assert_empty_state_snapshot(
+        "empty_state_folders_failed",
+        Collection::SpaceContents,
+        LoadStatus::Failed("API error (401): {\"err\":\"Token invalid\"}".to_string()),
+    );
+}
+
+#[test]
+fn test_item_info_list() {
+    let mut list = fixtures::test_list();
+    list.content = Some("Bugs reported by customers\nTriaged every Monday".to_string());
+    list.status = Some(clickdown::models::workspace::ListStatus {
+        status: "on track".to_string(),
+        color: None,
+        type_field: None,
+    });
+    list.task_count = Some(12);
+    list.archived = true;
+    let info = ItemInfo::list(&list);
+
+    assert_widget_snapshot("item_info_list", 60, 12, |frame| {
+        let area = Rect::new(0, 0, 60, 12);
+        render_item_info(frame, &info, area);
+    });
+}
+
 #[test]
 fn test_task_list_with_tasks() {
     let tasks = create_test_tasks();
@@ -262,11 +386,121 @@ fn test_task_list_with_selection() {
     });
 }
 
+#[test]
+fn test_task_list_highlights_selected_row() {
+    let mut task_list = GroupedTaskList::from_tasks(create_test_tasks());
+    task_list.select_first();
+    let selected = task_list.selected_task().unwrap().name.clone();
+    let unselected = create_test_tasks()
+        .into_iter()
+        .map(|t| t.name)
+        .find(|name| *name != selected)
+        .unwrap();
+
+    let buffer = render_buffer(60, 15, |frame| {
+        render_task_list(frame, &task_list, Rect::new(0, 0, 60, 15), false);
+    });
+
+    let (x, y) = find_text(&buffer, &selected).expect("selected task rendered");
+    assert_eq!(buffer[(x, y)].bg, Theme::SECONDARY);
+    let (x, y) = find_text(&buffer, &unselected).expect("other task rendered");
+    assert_ne!(buffer[(x, y)].bg, Theme::SECONDARY);
+}
+
+#[test]
+fn test_sidebar_highlights_selected_row() {
+    let mut sidebar = SidebarState::new();
+    *sidebar.items_mut() = create_sidebar_items();
+    sidebar.select_first();
+
+    let buffer = render_buffer(40, 15, |frame| {
+        render_sidebar(frame, &sidebar, Rect::new(0, 0, 40, 15));
+    });
+
+    let highlighted: Vec<u16> = (0..15)
+        .filter(|&y| buffer[(2, y)].bg == Theme::SECONDARY)
+        .collect();
+    assert_eq!(highlighted.len(), 1, "exactly one row is highlighted");
+}
+
+// ============================================================================
+// Comment List Widget Tests
+// ============================================================================
+
+/// Comment without a date, so snapshots don't depend on the local timezone
+fn undated_comment(id: &str, text: &str, parent_id: Option<&str>) -> Comment {
+    let mut comment = fixtures::test_comment();
+    comment.id = id.to_string();
+    comment.text = text.to_string();
+    comment.created_at = None;
+    comment.parent_id = parent_id.map(str::to_string);
+    comment
+}
+
+fn create_test_comments() -> Vec<Comment> {
+    vec![
+        undated_comment("c1", "Can we ship this on Friday?", None),
+        undated_comment("r1", "Yes, after QA signs off", Some("c1")),
+        undated_comment("r2", "QA is done", Some("c1")),
+        undated_comment("c2", "Release notes are drafted", None),
+    ]
+}
+
+fn render_test_comments(frame: &mut ratatui::Frame, comments: &[Comment], selected: usize) {
+    render_comments(
+        frame,
+        comments,
+        selected,
+        None,
+        "",
+        true,
+        Rect::new(0, 0, 60, 12),
+        &CommentViewMode::TopLevel,
+        false,
+        &CommentCollapse::default(),
+        None,
+    );
+}
+
+#[test]
+fn test_comments_empty() {
+    assert_widget_snapshot("comments_empty", 60, 12, |frame| {
+        render_test_comments(frame, &[], 0);
+    });
+
+    let buffer = render_buffer(60, 12, |frame| render_test_comments(frame, &[], 0));
+    assert!(find_text(&buffer, "No comments yet. Press 'n' to add one.").is_some());
+}
+
+#[test]
+fn test_comments_with_replies() {
+    let comments = create_test_comments();
+    assert_widget_snapshot("comments_with_replies", 60, 12, |frame| {
+        render_test_comments(frame, &comments, 3);
+    });
+
+    let buffer = render_buffer(60, 12, |frame| render_test_comments(frame, &comments, 3));
+    // Replies are counted on their thread's top-level comment, not listed
+    assert!(find_text(&buffer, "• 2 replies").is_some());
+    assert!(find_text(&buffer, "QA is done").is_none());
+
+    // The selected comment's header is highlighted; the other's is not
+    let headers: Vec<(u16, u16)> = (0..12)
+        .filter_map(|y| Some((find_in_row(&buffer, y, "testuser")?, y)))
+        .collect();
+    assert_eq!(headers.len(), 2);
+    let (first, selected) = (buffer[headers[0]].clone(), buffer[headers[1]].clone());
+    assert_eq!(selected.fg, Theme::WARNING);
+    assert!(selected.modifier.contains(Modifier::BOLD));
+    assert_eq!(first.fg, Theme::PRIMARY);
+}
+
 // ============================================================================
 // Assigned Tasks View Snapshot Tests
 // ============================================================================
 
 /// Create test tasks with assignees for "Assigned to Me" view
+#[allow(dead_code)]
 fn create_assigned_test_tasks() -> Vec<Task> {
     use clickdown::models::task::User;
 
@@ -362,6 +596,94 @@ fn test_task_detail_view_mode() {
     });
 }
 
+#[test]
+fn test_task_detail_recurring_task() {
+    let mut detail = TaskDetailState::new();
+    // No due date: it would render in the local timezone
+    let task = Task {
+        recurrence: Some(RecurrenceConfig {
+            type_field: "weekly".to_string(),
+            period: 1,
+            end_count: None,
+        }),
+        ..create_test_tasks().remove(0)
+    };
+    detail.task = Some(task);
+
+    assert_widget_snapshot("task_detail_recurring", 60, 20, |frame| {
+        let area = Rect::new(0, 0, 60, 20);
+        render_task_detail(frame, &detail, area, "", "", &TaskCreationField::Name);
+    });
+}
+
+#[test]
+fn test_task_detail_related_tasks() {
+    use clickdown::models::task::{LinkedTask, TaskDependencyRef};
+
+    let dependency = |task_id: &str, depends_on: &str| TaskDependencyRef {
+        id: None,
+        task_id: Some(task_id.to_string()),
+        depends_on: Some(depends_on.to_string()),
+        name: None,
+        dependency_status: None,
+        type_field: None,
+        date_created: None,
+        userid: None,
+        workspace_id: None,
+        chain_id: None,
+    };
+    let mut detail = TaskDetailState::new();
+    let task = create_test_tasks().remove(0);
+    let task = Task {
+        dependencies: vec![dependency("task-1", "api-1"), dependency("ui-2", "task-1")],
+        linked_tasks: vec![LinkedTask {
+            id: None,
+            task_id: Some("task-1".to_string()),
+            link_id: Some("docs-3".to_string()),
+            name: Some("Update docs".to_string()),
+            status: None,
+            date_created: None,
+            userid: None,
+            workspace_id: None,
+        }],
+        ..task
+    };
+    detail.task = Some(task);
+    detail.links_focused = true;
+    detail.link_selected = 2;
+
+    assert_widget_snapshot("task_detail_related_tasks", 60, 20, |frame| {
+        let area = Rect::new(0, 0, 60, 20);
+        render_task_detail(frame, &detail, area, "", "", &TaskCreationField::Name);
+    });
+}
+
+#[test]
+fn test_task_detail_two_column() {
+    use clickdown::models::Tag;
+    use clickdown::tui::layout::split_task_detail_columns;
+
+    let mut detail = TaskDetailState::new();
+    let task = Task {
+        tags: vec![Tag {
+            id: None,
+            name: "backend".to_string(),
+            color: None,
+            tag_fg: None,
+            tag_bg: None,
+            creator: None,
+        }],
+        ..create_test_tasks().remove(0)
+    };
+    detail.task = Some(task);
+
+    assert_widget_snapshot("task_detail_two_column", 120, 20, |frame| {
+        let (metadata, description, _) = split_task_detail_columns(Rect::new(0, 0, 120, 20));
+        render_task_metadata(frame, &detail, metadata);
+        render_task_description(frame, &detail, description);
+    });
+}
+
 #[test]
 fn test_task_detail_empty() {
     let detail = TaskDetailState::new();
@@ -435,7 +757,7 @@ fn test_s_key_in_task_detail_no_response_snapshot() {
 
         // Render status bar (showing status picker instructions)
         let hints = "j/k: Navigate | Enter: Select | Esc: Cancel";
-        layout.render_status(frame, status_after_s, hints);
+        layout.render_status(frame, status_after_s, hints, None);
 
         // Render status picker overlay (the fix!)
         render_status_picker(frame, area, &statuses, 0, Some("in progress"));
@@ -464,7 +786,7 @@ fn test_task_detail_before_s_key_baseline() {
 
         // Render status bar
         let hints = "e: Edit task | Tab: Comments | Esc: Back | ? - Help";
-        layout.render_status(frame, "", hints);
+        layout.render_status(frame, "", hints, None);
     });
 }
 
@@ -478,7 +800,7 @@ fn test_auth_view_empty() {
 
     assert_widget_snapshot("auth_view_empty", 60, 20, |frame| {
         let area = Rect::new(0, 0, 60, 20);
-        render_auth(frame, &auth, area);
+        render_auth(frame, &auth, area, 0);
     });
 }
 
@@ -490,8 +812,20 @@ fn test_auth_view_partial_token() {
 
     assert_widget_snapshot("auth_view_partial_token", 60, 20, |frame| {
         let area = Rect::new(0, 0, 60, 20);
-        render_auth(frame, &auth, area);
+        render_auth(frame, &auth, area, 0);
+    });
+}
+
+#[test]
+fn test_auth_verifying_uses_the_shared_spinner() {
+    let mut auth = AuthState::new();
+    auth.wizard = true;
+    auth.onboarding_step = clickdown::tui::widgets::auth::STEP_VERIFYING;
+
+    let buffer = render_buffer(60, 20, |frame| {
+        render_auth(frame, &auth, Rect::new(0, 0, 60, 20), 1);
     });
+    assert!(find_text(&buffer, "⠙ Checking your token").is_some());
 }
 
 #[test]
@@ -501,7 +835,7 @@ fn test_auth_view_error() {
 
     assert_widget_snapshot("auth_view_error", 60, 20, |frame| {
         let area = Rect::new(0, 0, 60, 20);
-        render_auth(frame, &auth, area);
+        render_auth(frame, &auth, area, 0);
     });
 }
 
@@ -547,6 +881,44 @@ fn test_help_dialog_visible() {
     });
 }
 
+/// Test that at 80x24 the task list page scrolls to its last bindings
+#[test]
+fn test_help_dialog_scrolls_at_80x24() {
+    let mut help = HelpState::new();
+    help.visible = true;
+    let render = |help: &HelpState| {
+        render_buffer(80, 24, |frame| {
+            render_help(frame, help, &HelpContext::TaskList, Rect::new(0, 0, 80, 24));
+        })
+    };
+
+    let buffer = render(&help);
+    assert!(find_text(&buffer, "/ Search shortcuts").is_some());
+    assert!(find_text(&buffer, "PgDn: Scroll").is_some());
+    assert!(find_text(&buffer, "Jump to task by typing its name").is_none());
+
+    help.scroll_down();
+    help.scroll_down();
+    let buffer = render(&help);
+    assert!(find_text(&buffer, "Ctrl+D").is_some());
+    assert!(find_text(&buffer, "Jump to task by typing its name").is_some());
+    assert!(find_text(&buffer, "/ Search shortcuts").is_some(), "Search stays in place");
+}
+
+#[test]
+fn test_help_dialog_search() {
+    let mut help = HelpState::new();
+    help.visible = true;
+    help.start_search();
+    help.search_query.push_str("url");
+    help.search_cursor = 3;
+
+    assert_widget_snapshot("help_dialog_search", 80, 24, |frame| {
+        let area = Rect::new(0, 0, 80, 24);
+        render_help(frame, &help, &HelpContext::TaskList, area);
+    });
+}
+
 #[test]
 fn test_dialog_quit_confirmation() {
     let mut dialog = DialogState::new();
@@ -629,7 +1001,7 @@ fn test_auth_screen_80x24() {
 
     assert_widget_snapshot("auth_screen_80x24", 80, 24, |frame| {
         let area = Rect::new(0, 0, 80, 24);
-        render_auth(frame, &auth, area);
+        render_auth(frame, &auth, area, 0);
     });
 }
 
@@ -639,7 +1011,7 @@ fn test_auth_screen_120x30() {
 
     assert_widget_snapshot("auth_screen_120x30", 120, 30, |frame| {
         let area = Rect::new(0, 0, 120, 30);
-        render_auth(frame, &auth, area);
+        render_auth(frame, &auth, area, 0);
     });
 }
 
@@ -743,7 +1115,7 @@ fn test_navigation_hierarchy() {
     use clickdown::api::mock_client::MockClickUpClient;
     use clickdown::models::workspace::{Folder, List, Space, Workspace};
     use clickdown::tui::app::{Screen, TuiApp};
-    use clickdown::tui::widgets::{render_sidebar, SidebarItem};
+    use clickdown::tui::widgets::render_sidebar;
     use insta::assert_snapshot;
     use ratatui::{backend::TestBackend, layout::Rect, Terminal};
     use std::sync::Arc;
@@ -788,6 +1160,7 @@ fn test_navigation_hierarchy() {
                 status: None,
                 folders: vec![],
                 lists: vec![],
+                features: Default::default(),
             },
             Space {
                 id: "sp-2".to_string(),
@@ -797,6 +1170,7 @@ fn test_navigation_hierarchy() {
                 status: None,
                 folders: vec![],
                 lists: vec![],
+                features: Default::default(),
             },
             Space {
                 id: "sp-3".to_string(),
@@ -806,6 +1180,7 @@ fn test_navigation_hierarchy() {
                 status: None,
                 folders: vec![],
                 lists: vec![],
+                features: Default::default(),
             },
         ];
 
@@ -818,6 +1193,10 @@ fn test_navigation_hierarchy() {
                 private: false,
                 space: None,
                 lists: vec![],
+                statuses: vec![],
+                hidden: false,
+                archived: false,
+                task_count: None,
             },
             Folder {
                 id: "fd-2".to_string(),
@@ -826,6 +1205,10 @@ fn test_navigation_hierarchy() {
                 private: false,
                 space: None,
                 lists: vec![],
+                statuses: vec![],
+                hidden: false,
+                archived: false,
+                task_count: None,
             },
             Folder {
                 id: "fd-3".to_string(),
@@ -834,6 +1217,10 @@ fn test_navigation_hierarchy() {
                 private: false,
                 space: None,
                 lists: vec![],
+                statuses: vec![],
+                hidden: false,
+                archived: false,
+                task_count: None,
             },
         ];
 
@@ -851,6 +1238,9 @@ fn test_navigation_hierarchy() {
                 folder: None,
                 status: None,
                 priority: None,
+                due_date: None,
+                task_count: None,
+                statuses: vec![],
             },
             List {
                 id: "lst-2".to_string(),
@@ -864,6 +1254,9 @@ fn test_navigation_hierarchy() {
                 folder: None,
                 status: None,
                 priority: None,
+                due_date: None,
+                task_count: None,
+                statuses: vec![],
             },
             List {
                 id: "lst-3".to_string(),
@@ -877,6 +1270,9 @@ fn test_navigation_hierarchy() {
                 folder: None,
                 status: None,
                 priority: None,
+                due_date: None,
+                task_count: None,
+                statuses: vec![],
             },
         ];
 
@@ -1473,3 +1869,16 @@ fn test_quit_dialog_enter_in_task_creation() {
         "BUG: Dialog should close on Enter but it didn't!"
     );
 }
+
+/// Snapshot of the initial-load overlay with the spinner in the status bar
+#[test]
+fn test_loading_overlay() {
+    assert_widget_snapshot("loading_overlay", 80, 24, |frame| {
+        let area = Rect::new(0, 0, 80, 24);
+        let layout = TuiLayout::new(area);
+
+        layout.render_title(frame, "ClickDown - Workspaces");
+        TuiLayout::render_loading_overlay(frame, layout.content_area, "Loading workspaces...", 2);
+        layout.render_status(frame, "Loading...", "? - Help", Some(2));
+    });
+}
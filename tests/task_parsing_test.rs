@@ -4,7 +4,7 @@
 //! that the ClickUp API might return.
 
 use clickdown::models::{Task, TasksResponse};
-use serde_json;
+use clickdown::utils::deserializers::from_json_str;
 
 // ============================================================================
 // Description Tests
@@ -583,6 +583,83 @@ fn test_task_attachments_as_null() {
     );
 }
 
+// ============================================================================
+// Group Assignee Tests
+// ============================================================================
+
+#[test]
+fn test_task_group_assignees_real_payload() {
+    // Shape returned for tasks assigned to a user group (team)
+    let json = r##"
+    {
+        "id": "86d20qjkb",
+        "name": "Review onboarding flow",
+        "assignees": [
+            {"id": 94803855, "username": "Alice", "color": "#827718", "initials": "A", "email": "alice@example.com", "profilePicture": null}
+        ],
+        "group_assignees": [
+            {
+                "id": "4bfdd5b6-3a6e-4f2b-8f1e-6d0c1b2a9e31",
+                "team_id": "9016123456",
+                "userid": 94803855,
+                "name": "Design Team",
+                "handle": "design",
+                "date_created": "1700000000000",
+                "initials": "DT",
+                "color": "#ff7800",
+                "avatar": null,
+                "members": [
+                    {"id": 94803855, "username": "Alice", "email": "alice@example.com", "color": "#827718", "initials": "A", "profilePicture": null},
+                    {"id": 94803856, "username": "Bob", "email": "bob@example.com", "color": null, "initials": "B", "profilePicture": null},
+                    {"id": 94803857, "username": "Carol", "email": "carol@example.com", "color": null, "initials": "C", "profilePicture": null}
+                ]
+            }
+        ]
+    }
+    "##;
+
+    let task: Task = serde_json::from_str(json).expect("group assignees should parse");
+    assert_eq!(task.group_assignees.len(), 1);
+    let group = &task.group_assignees[0];
+    assert_eq!(group.id, "4bfdd5b6-3a6e-4f2b-8f1e-6d0c1b2a9e31");
+    assert_eq!(group.name, "Design Team");
+    assert_eq!(group.member_count(), 3);
+    assert_eq!(group.members[1].username, "Bob");
+    assert_eq!(task.assignee_labels(), vec!["Alice", "@design"]);
+}
+
+#[test]
+fn test_task_group_assignees_null_and_empty() {
+    for value in ["null", "[]"] {
+        let json = format!(
+            r#"{{"id": "task-1", "name": "Test", "group_assignees": {}}}"#,
+            value
+        );
+        let task: Task = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("group_assignees {} should parse: {}", value, e));
+        assert!(task.group_assignees.is_empty());
+    }
+
+    let task: Task = serde_json::from_str(r#"{"id": "task-1", "name": "Test"}"#).unwrap();
+    assert!(task.group_assignees.is_empty());
+}
+
+#[test]
+fn test_task_group_assignee_without_members() {
+    // Some endpoints send only the count
+    let json = r#"
+    {
+        "id": "task-1",
+        "name": "Test",
+        "group_assignees": [{"id": "b1c2", "name": "Ops", "members": null, "member_count": 5}]
+    }
+    "#;
+
+    let task: Task = serde_json::from_str(json).unwrap();
+    assert_eq!(task.group_assignees[0].member_count(), 5);
+    assert_eq!(task.assignee_labels(), vec!["@Ops"]);
+}
+
 // ============================================================================
 // Complex Real-World Task Tests
 // ============================================================================
@@ -849,3 +926,69 @@ fn test_task_assignees_as_single_object_still_fails() {
         "Single object assignees still fails (expected - should be array)"
     );
 }
+
+// ============================================================================
+// Field Path Diagnostics Tests
+// ============================================================================
+
+#[test]
+fn test_bad_nested_field_error_names_json_path() {
+    let json = r#"
+    {
+        "tasks": [
+            {"id": "task-1", "name": "First"},
+            {"id": "task-2", "name": "Second", "status": 42}
+        ]
+    }
+    "#;
+
+    let err = from_json_str::<TasksResponse>(json).unwrap_err();
+
+    assert_eq!(err.path().to_string(), "tasks[1].status");
+    assert!(
+        err.to_string().contains("tasks[1].status"),
+        "Error should name the failing field: {}",
+        err
+    );
+}
+
+// ============================================================================
+// Recurrence Tests
+// ============================================================================
+
+#[test]
+fn test_task_recurrence_parses() {
+    let json = r#"
+    {
+        "id": "task-1",
+        "name": "Weekly review",
+        "recurrence": {"type": "weekly", "period": 2, "end_count": 10}
+    }
+    "#;
+
+    let task: Task = serde_json::from_str(json).unwrap();
+    let recurrence = task.recurrence.expect("recurrence should parse");
+    assert_eq!(recurrence.type_field, "weekly");
+    assert_eq!(recurrence.period, 2);
+    assert_eq!(recurrence.end_count, Some(10));
+}
+
+#[test]
+fn test_task_recurrence_null_or_missing() {
+    let null: Task =
+        serde_json::from_str(r#"{"id": "task-1", "name": "Once", "recurrence": null}"#).unwrap();
+    assert!(null.recurrence.is_none());
+
+    let missing: Task = serde_json::from_str(r#"{"id": "task-1", "name": "Once"}"#).unwrap();
+    assert!(missing.recurrence.is_none());
+}
+
+#[test]
+fn test_task_recurrence_period_defaults_to_one() {
+    let json = r#"{"id": "task-1", "name": "Daily", "recurrence": {"type": "daily"}}"#;
+
+    let task: Task = serde_json::from_str(json).unwrap();
+    let recurrence = task.recurrence.unwrap();
+    assert_eq!(recurrence.period, 1);
+    assert_eq!(recurrence.label(), "daily");
+}
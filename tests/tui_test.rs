@@ -306,16 +306,16 @@ fn test_auth_state() {
     assert_eq!(auth.cursor_pos, 0, "Cursor should start at 0");
 
     // Add characters
-    auth.add_char('t');
-    auth.add_char('e');
-    auth.add_char('s');
-    auth.add_char('t');
+    auth.editor().insert_char('t');
+    auth.editor().insert_char('e');
+    auth.editor().insert_char('s');
+    auth.editor().insert_char('t');
 
     assert_eq!(auth.token_input, "test", "Token input should be 'test'");
     assert_eq!(auth.cursor_pos, 4, "Cursor should be at 4");
 
     // Remove character
-    auth.remove_char();
+    auth.editor().backspace();
     assert_eq!(auth.token_input, "tes", "Token input should be 'tes'");
     assert_eq!(auth.cursor_pos, 3, "Cursor should be at 3");
 
@@ -342,6 +342,8 @@ fn test_sidebar_state() {
     sidebar.items_mut().push(SidebarItem::Workspace {
         name: "Test Workspace".to_string(),
         id: "ws-1".to_string(),
+        color: None,
+        member_count: None,
     });
     sidebar.items_mut().push(SidebarItem::Space {
         name: "Test Space".to_string(),
@@ -423,6 +425,7 @@ fn test_task_list_state() {
             priority: None,
             due_date: None,
             start_date: None,
+            recurrence: None,
             points: None,
             custom_fields: vec![],
             attachments: vec![],
@@ -442,7 +445,7 @@ fn test_task_list_state() {
         },
     ];
 
-    let mut task_list = GroupedTaskList::from_tasks(tasks);
+    let task_list = GroupedTaskList::from_tasks(tasks);
 
     // from_tasks selects first task automatically
     assert!(task_list.selected_task().is_some(), "Should have a task selected");
@@ -505,9 +508,9 @@ fn test_partial_token_masking() {
     assert!(auth.token_input.is_empty(), "Token should start empty");
 
     // Test short token (< 4 chars) - all visible
-    auth.add_char('a');
-    auth.add_char('b');
-    auth.add_char('c');
+    auth.editor().insert_char('a');
+    auth.editor().insert_char('b');
+    auth.editor().insert_char('c');
 
     // Build display string (mimicking render_auth logic)
     let visible_chars = 4;
@@ -522,7 +525,7 @@ fn test_partial_token_masking() {
     assert_eq!(display, "abc", "Short token should show all chars unmasked");
 
     // Test exactly 4 chars - all visible
-    auth.add_char('d');
+    auth.editor().insert_char('d');
     display.clear();
     for (i, c) in auth.token_input.chars().enumerate() {
         if i < visible_chars {
@@ -537,9 +540,9 @@ fn test_partial_token_masking() {
     );
 
     // Test long token (> 4 chars) - first 4 visible, rest masked
-    auth.add_char('e');
-    auth.add_char('f');
-    auth.add_char('g');
+    auth.editor().insert_char('e');
+    auth.editor().insert_char('f');
+    auth.editor().insert_char('g');
     display.clear();
     for (i, c) in auth.token_input.chars().enumerate() {
         if i < visible_chars {
@@ -557,12 +560,12 @@ fn test_cursor_indicator() {
     use clickdown::tui::widgets::AuthState;
 
     let mut auth = AuthState::new();
-    auth.add_char('t');
-    auth.add_char('e');
-    auth.add_char('s');
-    auth.add_char('t');
-    auth.add_char('1');
-    auth.add_char('2');
+    auth.editor().insert_char('t');
+    auth.editor().insert_char('e');
+    auth.editor().insert_char('s');
+    auth.editor().insert_char('t');
+    auth.editor().insert_char('1');
+    auth.editor().insert_char('2');
 
     // Cursor at position 0 (beginning)
     auth.cursor_pos = 0;
@@ -645,7 +648,7 @@ fn test_auth_display_after_paste() {
     // Simulate paste: add multiple characters at once
     let pasted_text = "test_api_token_12345";
     for c in pasted_text.chars() {
-        auth.add_char(c);
+        auth.editor().insert_char(c);
     }
 
     // Verify token was added
@@ -684,19 +687,19 @@ fn test_auth_display_after_typing() {
     let mut auth = AuthState::new();
 
     // Simulate typing character by character
-    auth.add_char('a');
+    auth.editor().insert_char('a');
     assert_eq!(auth.token_input, "a");
 
-    auth.add_char('b');
+    auth.editor().insert_char('b');
     assert_eq!(auth.token_input, "ab");
 
-    auth.add_char('c');
+    auth.editor().insert_char('c');
     assert_eq!(auth.token_input, "abc");
 
-    auth.add_char('d');
+    auth.editor().insert_char('d');
     assert_eq!(auth.token_input, "abcd");
 
-    auth.add_char('e');
+    auth.editor().insert_char('e');
     assert_eq!(auth.token_input, "abcde");
 
     // Build display string
@@ -729,7 +732,6 @@ fn test_auth_display_after_typing() {
 #[test]
 fn test_mock_client_with_comments() {
     use clickdown::api::mock_client::MockClickUpClient;
-    use std::sync::Arc;
     use clickdown::api::ClickUpApi;
     use clickdown::models::comment::Comment;
     use tokio::runtime::Runtime;
@@ -750,6 +752,7 @@ fn test_mock_client_with_comments() {
                 assigned: false,
                 reaction: String::new(),
                 parent_id: None,
+                reply_count: None,
             },
             Comment {
                 id: "test-comment-2".to_string(),
@@ -763,12 +766,13 @@ fn test_mock_client_with_comments() {
                 assigned: false,
                 reaction: String::new(),
                 parent_id: None,
+                reply_count: None,
             },
         ];
 
         let mock_client = MockClickUpClient::new().with_task_comments(comments);
 
-        let comments = mock_client.get_task_comments("task-123").await.unwrap();
+        let comments = mock_client.get_all_task_comments("task-123").await.unwrap();
         assert_eq!(comments.len(), 2);
         assert_eq!(comments[0].id, "test-comment-1");
     });
@@ -778,7 +782,6 @@ fn test_mock_client_with_comments() {
 #[test]
 fn test_mock_client_create_comment() {
     use clickdown::api::mock_client::MockClickUpClient;
-    use std::sync::Arc;
     use clickdown::api::ClickUpApi;
     use clickdown::models::comment::Comment;
     use clickdown::models::CreateCommentRequest;
@@ -799,6 +802,7 @@ fn test_mock_client_create_comment() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         };
 
         let mock_client =
@@ -820,11 +824,57 @@ fn test_mock_client_create_comment() {
     });
 }
 
+/// Test that the mock records the assignee sent when creating and updating comments
+#[test]
+fn test_mock_client_comment_assignee_requests() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::api::ClickUpApi;
+    use clickdown::models::{CreateCommentRequest, UpdateCommentRequest};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut assigned = fixtures::test_comment();
+        assigned.assigned_commenter = Some(fixtures::test_user());
+        let mock = MockClickUpClient::new()
+            .with_create_comment_response(assigned.clone())
+            .with_update_comment_response(assigned);
+
+        let create = CreateCommentRequest {
+            comment_text: "Please review".to_string(),
+            assignee: Some(42),
+            assigned_commenter: None,
+            parent_id: None,
+        };
+        let created = mock.create_comment("task-123", &create).await.unwrap();
+        assert!(created.assigned_commenter.is_some());
+
+        let update = UpdateCommentRequest {
+            comment_text: Some("Please review".to_string()),
+            assigned: None,
+            assignee: Some(7),
+            assigned_commenter: None,
+        };
+        mock.update_comment("comment-1", &update).await.unwrap();
+
+        let creates = mock.requested_comment_creates();
+        assert_eq!(creates.len(), 1);
+        assert_eq!(creates[0].assignee, Some(42));
+        let updates = mock.requested_comment_updates();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].0, "comment-1");
+        assert_eq!(updates[0].1.assignee, Some(7));
+        assert_eq!(
+            serde_json::to_value(&updates[0].1).unwrap()["assignee"],
+            serde_json::json!(7)
+        );
+    });
+}
+
 /// Test that mock client update comment works
 #[test]
 fn test_mock_client_update_comment() {
     use clickdown::api::mock_client::MockClickUpClient;
-    use std::sync::Arc;
     use clickdown::api::ClickUpApi;
     use clickdown::models::comment::Comment;
     use clickdown::models::UpdateCommentRequest;
@@ -845,6 +895,7 @@ fn test_mock_client_update_comment() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         };
 
         let mock_client =
@@ -895,6 +946,7 @@ fn test_cache_comments() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         },
         Comment {
             id: "test-comment-2".to_string(),
@@ -908,6 +960,7 @@ fn test_cache_comments() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         },
     ];
 
@@ -921,6 +974,40 @@ fn test_cache_comments() {
     assert_eq!(cached[1].id, "test-comment-1");
 }
 
+/// Test that cached comments come back newest or oldest first by created_at
+#[test]
+fn test_cache_comments_in_order() {
+    use clickdown::cache::CacheManager;
+    use clickdown::config::CommentOrder;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let mut cache = CacheManager::new(temp_dir.path().join("test.db")).unwrap();
+
+    // Cached out of order, with known timestamps
+    let comments: Vec<_> = [("b", 2000), ("c", 3000), ("a", 1000)]
+        .into_iter()
+        .map(|(id, created_at)| {
+            let mut comment = fixtures::test_comment();
+            comment.id = id.to_string();
+            comment.created_at = Some(created_at);
+            comment
+        })
+        .collect();
+    cache.cache_comments("task-1", &comments).unwrap();
+
+    let ids = |order| -> Vec<String> {
+        cache
+            .get_comments_in_order("task-1", order)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.id)
+            .collect()
+    };
+    assert_eq!(ids(CommentOrder::Newest), vec!["c", "b", "a"]);
+    assert_eq!(ids(CommentOrder::Oldest), vec!["a", "b", "c"]);
+}
+
 /// Test that cache validity check works
 #[test]
 fn test_cache_validity() {
@@ -949,6 +1036,7 @@ fn test_cache_validity() {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     }];
 
     // Cache comments
@@ -992,6 +1080,7 @@ fn test_clear_comments() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         },
         Comment {
             id: "test-comment-2".to_string(),
@@ -1005,6 +1094,7 @@ fn test_clear_comments() {
             assigned: false,
             reaction: String::new(),
             parent_id: None,
+            reply_count: None,
         },
     ];
 
@@ -1047,6 +1137,7 @@ fn test_comments_isolated_by_task() {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     };
 
     let comment2 = Comment {
@@ -1061,6 +1152,7 @@ fn test_comments_isolated_by_task() {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     };
 
     // Cache different comments for different tasks
@@ -1078,13 +1170,67 @@ fn test_comments_isolated_by_task() {
     assert_eq!(task2_comments[0].id, "test-comment-2");
 }
 
+/// Test that deleted comments stay suppressed across a stale re-cache
+#[test]
+fn test_cache_tombstones_deleted_comments() {
+    use clickdown::cache::CacheManager;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = PathBuf::from(temp_dir.path()).join("cache.db");
+    let mut cache = CacheManager::new(db_path).unwrap();
+
+    let comment = fixtures::test_comment();
+    cache
+        .cache_comments("task-1", std::slice::from_ref(&comment))
+        .unwrap();
+
+    cache.mark_comment_deleted(&comment.id).unwrap();
+    assert!(cache.get_comments("task-1").unwrap().is_empty());
+
+    // A refresh that still contains the deleted comment must not resurrect it
+    cache.cache_comments("task-1", &[comment]).unwrap();
+    assert!(cache.get_comments("task-1").unwrap().is_empty());
+}
+
+/// Test that purging removes tombstones only once they are old enough
+#[test]
+fn test_purge_deleted_comments() {
+    use clickdown::cache::CacheManager;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = PathBuf::from(temp_dir.path()).join("cache.db");
+    let mut cache = CacheManager::new(db_path).unwrap();
+
+    let comment = fixtures::test_comment();
+    cache
+        .cache_comments("task-1", std::slice::from_ref(&comment))
+        .unwrap();
+    cache.mark_comment_deleted(&comment.id).unwrap();
+
+    assert_eq!(
+        cache
+            .purge_deleted_comments(Duration::from_secs(3600))
+            .unwrap(),
+        0
+    );
+    assert_eq!(cache.purge_deleted_comments(Duration::ZERO).unwrap(), 1);
+
+    // Once purged, a fresh fetch caches the comment again
+    cache.cache_comments("task-1", &[comment]).unwrap();
+    assert_eq!(cache.get_comments("task-1").unwrap().len(), 1);
+}
+
 // ==================== Reply Creation Tests ====================
 
 /// Test that mock client can create comment replies
 #[test]
 fn test_mock_client_create_comment_reply() {
     use clickdown::api::mock_client::MockClickUpClient;
-    use std::sync::Arc;
     use clickdown::api::ClickUpApi;
     use clickdown::models::comment::Comment;
     use clickdown::models::CreateCommentRequest;
@@ -1105,6 +1251,7 @@ fn test_mock_client_create_comment_reply() {
             assigned: false,
             reaction: String::new(),
             parent_id: Some("parent-comment-123".to_string()),
+            reply_count: None,
         };
 
         let mock_client =
@@ -1197,6 +1344,7 @@ fn test_reply_comment_parent_id() {
         assigned: false,
         reaction: String::new(),
         parent_id: Some("parent-123".to_string()),
+        reply_count: None,
     };
 
     assert_eq!(reply.parent_id, Some("parent-123".to_string()));
@@ -1219,6 +1367,7 @@ fn test_top_level_comment_no_parent_id() {
         assigned: false,
         reaction: String::new(),
         parent_id: None,
+        reply_count: None,
     };
 
     assert_eq!(top_level.parent_id, None);
@@ -1272,6 +1421,7 @@ fn test_s_key_opens_status_picker() {
         priority: None,
         due_date: None,
         start_date: None,
+        recurrence: None,
         points: None,
         custom_fields: vec![],
         attachments: vec![],
@@ -1511,6 +1661,59 @@ fn test_invalid_url_shows_error() {
     assert!(app.url_input_error().is_some(), "Error should be shown for invalid URL");
 }
 
+/// Test that a custom task ID typed into the URL input is looked up in
+/// the current workspace
+#[test]
+fn test_url_input_opens_task_by_custom_id() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::{Screen, TuiApp};
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.custom_id = Some("DEV-42".to_string());
+        let mock = Arc::new(MockClickUpClient::new().with_task_by_id(task.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        let submit = |app: &mut TuiApp| {
+            app.update(InputEvent::Key(key('g')));
+            app.update(InputEvent::Key(key('u')));
+            for c in "DEV-42".chars() {
+                app.handle_url_input(key(c));
+            }
+            app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        };
+
+        // Custom IDs only resolve within a workspace
+        submit(&mut app);
+        assert_eq!(
+            app.url_input_error(),
+            Some("Open a workspace to look up custom task IDs")
+        );
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+
+        app.set_current_workspace_id(Some("ws-1".to_string()));
+        submit(&mut app);
+        assert!(!app.is_url_input_open());
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(mock
+            .recorded_calls()
+            .contains(&"get_task_by_custom_id DEV-42 ws-1".to_string()));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(
+            app.task_detail().task.as_ref().map(|t| t.id.as_str()),
+            Some(task.id.as_str())
+        );
+    });
+}
+
 /// Test that Esc cancels the g leader key
 #[test]
 fn test_esc_cancels_g_leader() {
@@ -1922,3 +2125,3997 @@ fn test_delete_task_empty_response_fails_to_parse() {
     });
 }
 
+
+/// Helper: navigate a mock-backed app from Workspaces into the first space
+async fn open_first_space(app: &mut TuiApp) {
+    app.load_workspaces();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    app.process_async_messages();
+
+    app.sidebar().select_first();
+    app.navigate_into();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    app.process_async_messages();
+
+    app.sidebar().select_first();
+    app.navigate_into();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    app.process_async_messages();
+}
+
+/// Test that the space view renders Folders, Lists and Docs sections with counts
+#[test]
+fn test_space_view_shows_folders_lists_and_docs_sections() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::widgets::{render_sidebar, SidebarItem};
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut folderless_list = fixtures::test_list();
+        folderless_list.id = "list-2".to_string();
+        folderless_list.name = "Backlog".to_string();
+
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_folders(vec![fixtures::test_folder()])
+            .with_lists_in_space(vec![fixtures::test_list(), folderless_list])
+            .with_documents(vec![fixtures::test_document()]);
+
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        open_first_space(&mut app).await;
+
+        assert_eq!(app.screen(), Screen::Folders);
+
+        let items = app.sidebar().items().to_vec();
+        let headers: Vec<(String, usize)> = items
+            .iter()
+            .filter_map(|item| match item {
+                SidebarItem::Header { label, count } => Some((label.clone(), *count)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            headers,
+            vec![
+                ("Folders".to_string(), 1),
+                ("Lists".to_string(), 2),
+                ("Docs".to_string(), 1)
+            ]
+        );
+
+        // Selection lands on the first folder, not the header
+        assert!(matches!(
+            app.sidebar().selected_item(),
+            Some(SidebarItem::Folder { .. })
+        ));
+
+        let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+        terminal
+            .draw(|frame| render_sidebar(frame, app.sidebar(), Rect::new(0, 0, 40, 12)))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rendered: String = (0..12)
+            .map(|y| {
+                (0..40)
+                    .map(|x| buffer[(x, y)].symbol().to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(rendered.contains("Folders (1)"), "{}", rendered);
+        assert!(rendered.contains("Lists (2)"), "{}", rendered);
+        assert!(rendered.contains("Docs (1)"), "{}", rendered);
+        assert!(rendered.contains("Test Document"), "{}", rendered);
+    });
+}
+
+/// Test that empty sections are hidden and Enter on a folderless list opens its tasks
+#[test]
+fn test_space_view_hides_empty_sections_and_opens_folderless_list() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::widgets::SidebarItem;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()])
+            .with_documents_error("docs unavailable".to_string())
+            .with_tasks(vec![fixtures::test_task()]);
+
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        open_first_space(&mut app).await;
+
+        let items = app.sidebar().items().to_vec();
+        assert_eq!(items.len(), 2, "Only the Lists section should be shown");
+        assert!(matches!(&items[0], SidebarItem::Header { label, count: 1 } if label == "Lists"));
+
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Tasks);
+
+        app.navigate_back();
+        assert_eq!(app.screen(), Screen::Folders, "Back from a folderless list returns to the space");
+        assert_eq!(app.sidebar().selected_item().map(|i| i.id()), Some("test-list-1"));
+    });
+}
+
+/// Test that a navigation result arriving after a newer load of the same kind is ignored
+#[test]
+fn test_out_of_order_task_load_is_ignored() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::{Task, TaskPages};
+    use clickdown::tui::app::{AppMessage, LoadTicket, Screen};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()])
+            .with_tasks(vec![fixtures::test_task()]);
+
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        open_first_space(&mut app).await;
+
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Tasks);
+        let current_id = app.task_list().selected_task().unwrap().id.clone();
+
+        // A slow response from a list the user opened earlier
+        let stale_task = Task {
+            id: "stale-task".to_string(),
+            name: "From another list".to_string(),
+            ..Default::default()
+        };
+        let stale_ticket = LoadTicket {
+            request_id: 0,
+            context_id: "earlier-list".to_string(),
+        };
+        app.message_tx_for_testing()
+            .send(AppMessage::TasksLoaded(
+                Ok(TaskPages {
+                    tasks: vec![stale_task],
+                    truncated: false,
+                }),
+                stale_ticket,
+            ))
+            .await
+            .unwrap();
+        app.process_async_messages();
+
+        assert_eq!(app.task_list().selected_task().unwrap().id, current_id);
+    });
+}
+
+/// Test that recently cached workspaces show at once and are swapped for fresh ones
+#[test]
+fn test_cached_workspaces_show_at_once_then_refresh() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::Workspace;
+    use clickdown::tui::widgets::SidebarItem;
+    use std::sync::Arc;
+
+    // Spawned tasks only run while the test awaits
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let workspace = |id: &str, name: &str| Workspace {
+        id: id.to_string(),
+        name: name.to_string(),
+        color: None,
+        avatar: None,
+        member_count: None,
+    };
+    let names = |app: &mut TuiApp| -> Vec<String> {
+        app.sidebar()
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                SidebarItem::Workspace { name, .. } => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    };
+
+    rt.block_on(async {
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![workspace("w1", "Renamed"), workspace("w2", "New")]);
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock_client)).unwrap();
+        app.config_mut_for_test().workspace_cache_ttl_secs = 3600;
+        app.cache()
+            .cache_workspaces(&[workspace("w1", "Cached")])
+            .unwrap();
+
+        app.load_workspaces();
+        assert!(!app.is_loading(), "No spinner on a warm start");
+        app.process_async_messages();
+        assert_eq!(names(&mut app), vec!["Cached"]);
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(names(&mut app), vec!["Renamed", "New"]);
+        assert_eq!(app.cache().get_workspaces().unwrap().len(), 2);
+    });
+}
+
+/// Test that loaders record whether a collection loaded empty or failed
+#[test]
+fn test_load_status_tells_empty_from_failed() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::widgets::{Collection, LoadStatus};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()])
+            .with_tasks(vec![]);
+
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        assert_eq!(app.load_status(Collection::Tasks), LoadStatus::NotStarted);
+        open_first_space(&mut app).await;
+
+        app.navigate_into();
+        assert_eq!(app.load_status(Collection::Tasks), LoadStatus::Loading);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(app.load_status(Collection::Tasks), LoadStatus::Loaded);
+        assert_eq!(app.load_status(Collection::SpaceContents), LoadStatus::Loaded);
+
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces_error("API error (401): {\"err\":\"Token invalid\"}".to_string());
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(matches!(
+            app.load_status(Collection::Workspaces),
+            LoadStatus::Failed(e) if e.contains("Token invalid")
+        ));
+    });
+}
+
+/// Build a full page of tasks for pagination tests
+fn full_task_page(page: usize) -> Vec<clickdown::models::Task> {
+    (0..clickdown::models::TASKS_PAGE_SIZE)
+        .map(|i| clickdown::models::Task {
+            id: format!("p{}-t{}", page, i),
+            name: format!("Task {} on page {}", i, page),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Process messages until a task load that streams in more tasks than the
+/// message queue holds has finished
+async fn finish_task_load(app: &mut TuiApp) {
+    for _ in 0..50 {
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        if !app.status_message().starts_with("Loading tasks") {
+            return;
+        }
+    }
+}
+
+/// Test that max_task_pages bounds the page requests and reports the truncation
+#[test]
+fn test_max_task_pages_caps_page_requests() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_lists_in_space(vec![fixtures::test_list()])
+                .with_task_pages(vec![full_task_page(0), full_task_page(1), full_task_page(2)]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.config_mut_for_test().max_task_pages = 2;
+        open_first_space(&mut app).await;
+
+        app.navigate_into();
+        finish_task_load(&mut app).await;
+
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(mock.requested_task_pages(), vec![0, 1]);
+        assert_eq!(app.status_message(), "Showing first 200 tasks (more available)");
+    });
+}
+
+/// Test that paging stops at the first short page without reporting truncation
+#[test]
+fn test_stream_all_tasks_stops_at_short_page() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::api::ClickUpApi;
+    use clickdown::models::{TaskFilters, TaskPages};
+    use futures::TryStreamExt;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new()
+            .with_task_pages(vec![full_task_page(0), vec![fixtures::test_task()]]);
+
+        let tasks: Vec<_> = mock
+            .stream_all_tasks("list-1", &TaskFilters::default(), 10)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(tasks.len(), clickdown::models::TASKS_PAGE_SIZE + 1);
+        assert!(!TaskPages::cut_short(tasks.len(), 10));
+        assert_eq!(mock.requested_task_pages(), vec![0, 1]);
+    });
+}
+
+/// Test that opening a list caches its status workflow and the status picker offers it
+#[test]
+fn test_list_statuses_are_cached_and_used_by_status_picker() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::TaskStatus;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let status = |name: &str| TaskStatus {
+        id: None,
+        status: name.to_string(),
+        color: Some("#123456".to_string()),
+        type_field: None,
+        orderindex: None,
+        status_group: None,
+    };
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock_client = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()])
+            .with_tasks(vec![fixtures::test_task()])
+            .with_list_statuses(vec![status("backlog"), status("shipped")]);
+
+        let mut app = TuiApp::with_client(Arc::new(mock_client)).unwrap();
+        open_first_space(&mut app).await;
+
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Tasks);
+
+        let cached = app
+            .cached_list_statuses("test-list-1")
+            .expect("Statuses should be cached for the opened list");
+        assert_eq!(cached.len(), 2);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)));
+        let offered: Vec<&str> = app
+            .status_picker_statuses()
+            .iter()
+            .map(|s| s.status.as_str())
+            .collect();
+        assert_eq!(offered, vec!["backlog", "shipped"]);
+
+        // The workflow changed while the picker was open: the stale choice is rejected locally
+        app.set_cached_list_statuses("test-list-1", vec![status("open"), status("closed")]);
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!app.is_status_picker_open());
+        assert!(!app.is_loading(), "No update should be sent to the API");
+        assert_eq!(
+            app.error_message(),
+            Some("Status 'backlog' is not part of this list's workflow")
+        );
+    });
+}
+
+/// Test that a folder's status overrides are fetched once and used for its lists
+#[test]
+fn test_folder_statuses_used_when_list_has_none() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::TaskStatus;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let status = |name: &str| TaskStatus {
+        id: None,
+        status: name.to_string(),
+        color: Some("#123456".to_string()),
+        type_field: None,
+        orderindex: None,
+        status_group: None,
+    };
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_folders(vec![fixtures::test_folder()])
+                .with_lists_in_folder(vec![fixtures::test_list()])
+                .with_tasks(vec![fixtures::test_task()])
+                .with_folder_statuses(vec![status("intake"), status("released")]),
+        );
+
+        let mut app = TuiApp::with_client_and_test_cache(mock.clone()).unwrap();
+        open_first_space(&mut app).await;
+        assert_eq!(app.screen(), Screen::Folders);
+
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Lists);
+        assert_eq!(app.cached_folder_statuses("test-folder-1").map(Vec::len), Some(2));
+
+        // Re-entering the folder reuses the statuses already fetched
+        app.navigate_back();
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Lists);
+        let folder_fetches = mock
+            .recorded_calls()
+            .iter()
+            .filter(|call| call.starts_with("get_folder_statuses"))
+            .count();
+        assert_eq!(folder_fetches, 1);
+
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Tasks);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE)));
+        let offered: Vec<&str> = app
+            .status_picker_statuses()
+            .iter()
+            .map(|s| s.status.as_str())
+            .collect();
+        assert_eq!(offered, vec!["intake", "released"]);
+    });
+}
+
+/// Test that Enter on a sprint view opens its overview in Screen::Sprint
+/// and Esc returns to the views
+#[test]
+fn test_sprint_overview_screen_loads_and_returns() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::{SprintDates, SprintOverview, WorkspaceView};
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.points = Some(5);
+        let overview = SprintOverview::from_tasks("Sprint 12", SprintDates::default(), &[task], 0);
+        let views: Vec<WorkspaceView> = serde_json::from_str(
+            r#"[{"id": "view-1", "name": "Sprint 12", "type": "sprint", "creator": 183}]"#,
+        )
+        .unwrap();
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_workspace_views(views)
+            .with_sprint_overview(overview);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::Sprint);
+        assert!(app.sprint().overview.is_none(), "Overview loads asynchronously");
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let loaded = app.sprint().overview.as_ref().expect("Overview should be loaded");
+        assert_eq!(loaded.name, "Sprint 12");
+        assert_eq!(loaded.total_points, 5.0);
+        assert_eq!(app.sprint().view_id, "view-1");
+
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::WorkspaceViews);
+    });
+}
+
+/// Test that a deep-linked task opens TaskDetail and derives list context from the task
+#[test]
+fn test_open_target_task_lands_on_task_detail() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::cli::args::OpenTarget;
+    use clickdown::models::task::ListReference;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.list = Some(ListReference {
+            id: "list-42".to_string(),
+            name: Some("Inbox".to_string()),
+            access: None,
+        });
+        task.team_id = Some("ws-7".to_string());
+        let mock = MockClickUpClient::new().with_task(task);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+
+        app.open_target(OpenTarget::Task {
+            task_id: "test-task-1".to_string(),
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(app.current_list_id().map(String::as_str), Some("list-42"));
+        assert_eq!(app.current_workspace_id().map(String::as_str), Some("ws-7"));
+    });
+}
+
+/// Test that an inaccessible deep-link target shows a toast and keeps the start screen
+#[test]
+fn test_open_target_invalid_task_falls_back_with_toast() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::cli::args::OpenTarget;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        let start_screen = app.screen();
+
+        app.open_target(OpenTarget::Task {
+            task_id: "missing".to_string(),
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), start_screen);
+        assert_ne!(app.screen(), Screen::TaskDetail);
+        assert!(
+            app.toast().is_some_and(|t| t.contains("Could not open task")),
+            "Expected a toast, got {:?}",
+            app.toast()
+        );
+    });
+}
+
+/// Test that a workspace-prefixed custom-ID link looks the task up in that workspace
+#[test]
+fn test_open_target_workspace_custom_id_looks_up_in_workspace() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::cli::args::parse_open_target;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.custom_id = Some("PROJ-42".to_string());
+        let mock = Arc::new(MockClickUpClient::new().with_task_by_id(task.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        let target = parse_open_target("https://app.clickup.com/t/9012345/PROJ-42").unwrap();
+        app.open_target(target);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(mock
+            .recorded_calls()
+            .contains(&"get_task_by_custom_id PROJ-42 9012345".to_string()));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(
+            app.task_detail().task.as_ref().map(|t| t.id.as_str()),
+            Some(task.id.as_str())
+        );
+    });
+}
+
+/// Test that 't' in comment focus creates a task from the selected comment
+#[test]
+fn test_t_in_comment_focus_creates_task_from_comment() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::Task;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let created = Task {
+            id: "new-task-9".to_string(),
+            name: "This is a test comment".to_string(),
+            ..Default::default()
+        };
+        let mock = MockClickUpClient::new().with_create_task_response(created);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_current_list_id(Some("list-1".to_string()));
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        app.update(InputEvent::Key(KeyEvent::new(
+            KeyCode::Char('t'),
+            KeyModifiers::NONE,
+        )));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(app.status_message(), "Created task new-task-9 from comment");
+    });
+}
+
+/// Test that typing after the type-ahead leader jumps to a matching task
+#[test]
+fn test_type_ahead_jumps_to_matching_task() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::Task;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut app = rt
+        .block_on(async { TuiApp::with_client(Arc::new(MockClickUpClient::new())) })
+        .unwrap();
+
+    app.set_screen(Screen::Tasks);
+    for (id, name) in [("t1", "Alpha"), ("t2", "Beta"), ("t3", "Bravo")] {
+        app.tasks_mut_for_test().push(Task {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        });
+    }
+    app.rebuild_task_list_for_test();
+
+    let press = |app: &mut TuiApp, code: KeyCode| {
+        app.update(InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+    };
+
+    // The leader keeps plain letters bound to their actions
+    press(&mut app, KeyCode::Char('\''));
+    press(&mut app, KeyCode::Char('B'));
+    press(&mut app, KeyCode::Char('r'));
+    assert_eq!(app.task_list().selected_task().unwrap().id, "t3");
+    assert_eq!(app.status_message(), "Jump: Br");
+
+    press(&mut app, KeyCode::Backspace);
+    assert_eq!(app.task_list().selected_task().unwrap().id, "t2");
+    assert_eq!(app.status_message(), "Jump: B");
+
+    // Esc leaves type-ahead without leaving the screen
+    press(&mut app, KeyCode::Esc);
+    assert_eq!(app.screen(), Screen::Tasks);
+    assert_eq!(app.status_message(), "");
+}
+
+/// Test that keys with global bindings (u, U, !, ?) extend a type-ahead
+/// query instead of firing their shortcut
+#[test]
+fn test_type_ahead_takes_globally_bound_keys() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::Task;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut app = rt
+        .block_on(async { TuiApp::with_client(Arc::new(MockClickUpClient::new())) })
+        .unwrap();
+
+    app.set_screen(Screen::Tasks);
+    for (id, name) in [("t1", "Alpha"), ("t2", "Update docs?")] {
+        app.tasks_mut_for_test().push(Task {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        });
+    }
+    app.rebuild_task_list_for_test();
+
+    let press = |app: &mut TuiApp, c: char| {
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+    };
+
+    press(&mut app, '\'');
+    press(&mut app, 'u');
+    assert_eq!(app.task_list().selected_task().unwrap().id, "t2");
+    assert_eq!(app.status_message(), "Jump: u");
+
+    for c in "pdate docs?".chars() {
+        press(&mut app, c);
+    }
+    assert_eq!(app.status_message(), "Jump: update docs?");
+    assert!(!app.is_help_visible());
+    assert_eq!(app.screen(), Screen::Tasks);
+}
+
+/// Test that 'x' in comment focus deletes the selected comment once the
+/// confirmation naming it is accepted
+#[test]
+fn test_x_in_comment_focus_deletes_comment() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::DialogType;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(MockClickUpClient::new().with_delete_comment_success());
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        app.update(InputEvent::Key(KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::NONE,
+        )));
+        match app.dialog_type_for_test() {
+            Some(DialogType::ConfirmCommentDelete { comment_id, preview }) => {
+                assert_eq!(comment_id, "test-comment-1");
+                assert_eq!(preview, "This is a test comment");
+            }
+            other => panic!("Expected comment delete dialog, got {:?}", other),
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(mock.recorded_calls().is_empty(), "Nothing is deleted before confirming");
+
+        app.dialog_mut_for_test().toggle();
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(mock.recorded_calls(), vec!["delete_comment test-comment-1"]);
+        assert_eq!(app.status_message(), "Comment deleted");
+        assert!(app.comments().is_empty());
+    });
+}
+
+/// Test that a declined comment delete confirmation keeps the comment, and
+/// that `confirm_comment_delete = false` deletes without asking
+#[test]
+fn test_comment_delete_confirmation_declined_or_turned_off() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(MockClickUpClient::new().with_delete_comment_success());
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        // Enter on the default "No", then Esc, both keep the comment
+        for answer in [KeyCode::Enter, KeyCode::Esc] {
+            app.update(key(KeyCode::Char('x')));
+            assert!(app.dialog_type_for_test().is_some());
+            app.update(key(answer));
+            assert!(app.dialog_type_for_test().is_none());
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(mock.recorded_calls().is_empty());
+        assert_eq!(app.comments().len(), 1);
+
+        app.config_mut_for_test().confirm_comment_delete = false;
+        app.update(key(KeyCode::Char('x')));
+        assert!(app.dialog_type_for_test().is_none());
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(mock.recorded_calls(), vec!["delete_comment test-comment-1"]);
+        assert!(app.comments().is_empty());
+    });
+}
+
+/// Test that undo after deleting a comment recreates it with the same text
+#[test]
+fn test_undo_comment_delete_recreates_comment() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut recreated = fixtures::test_comment();
+        recreated.id = "recreated-comment".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_delete_comment_success()
+                .with_create_comment_response(recreated),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.config_mut_for_test().confirm_comment_delete = false;
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        for key in [
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL),
+        ] {
+            app.update(InputEvent::Key(key));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                "delete_comment test-comment-1".to_string(),
+                format!("create_comment {} This is a test comment", fixtures::test_task().id),
+            ]
+        );
+        assert_eq!(app.toast(), Some("Undid comment deletion"));
+        assert_eq!(app.comments().len(), 1);
+        assert_eq!(app.comments()[0].id, "recreated-comment");
+
+        // The stack is empty again
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('U'), KeyModifiers::NONE)));
+        assert_eq!(app.status_message(), "Nothing to undo");
+        assert_eq!(mock.recorded_calls().len(), 2);
+    });
+}
+
+/// Test that undo after a status change restores the previous status
+#[test]
+fn test_undo_status_change_restores_previous_status() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::TaskStatus;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.status = Some(TaskStatus {
+            id: None,
+            status: "To Do".to_string(),
+            color: None,
+            type_field: None,
+            orderindex: None,
+            status_group: None,
+        });
+        let mock = Arc::new(MockClickUpClient::new().with_update_task_response(task.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task.clone());
+        app.rebuild_task_list_for_test();
+
+        // Pick the second default status ("In Progress"), then undo
+        for code in [KeyCode::Char('s'), KeyCode::Char('j'), KeyCode::Enter, KeyCode::Char('U')] {
+            app.update(InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                format!("update_task {} status=In Progress", task.id),
+                format!("update_task {} status=To Do", task.id),
+            ]
+        );
+        assert_eq!(app.toast(), Some("Undid status change (back to To Do)"));
+        let restored = app.task_list().selected_task().unwrap();
+        assert_eq!(restored.status.as_ref().unwrap().status, "To Do");
+    });
+}
+
+/// Test that a burst of queued terminal events is applied in full and in order
+#[test]
+fn test_handle_events_applies_burst_in_order() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut app = rt
+        .block_on(async { TuiApp::with_client(Arc::new(MockClickUpClient::new())) })
+        .unwrap();
+    let key = |c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+
+    // g u opens the URL input, which then takes the remaining 98 keys; a
+    // resize in the middle of the burst doesn't cost one
+    let typed: String = (0..98)
+        .map(|i| char::from(b"abcdefghijklmnopqrstuvwxyz0123456789"[i % 36]))
+        .collect();
+    let mut events: Vec<Event> = "gu".chars().chain(typed.chars()).map(key).collect();
+    events.insert(50, Event::Resize(80, 24));
+    assert_eq!(events.len(), 101);
+    app.handle_events(events);
+
+    assert!(app.is_url_input_open());
+    assert_eq!(app.url_input_text(), typed);
+}
+
+/// Test that going back from a task opened by link titles the task list with
+/// its list's name, fetched as it isn't among the loaded lists
+#[test]
+fn test_navigate_back_titles_tasks_with_fetched_list() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::ListReference;
+    use clickdown::tui::app::{AppMessage, Screen};
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut list = fixtures::test_list();
+        list.id = "list-bugs".to_string();
+        list.name = "Bugs".to_string();
+        let mock = Arc::new(MockClickUpClient::new().with_lists_in_space(vec![list]));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        let mut task = fixtures::test_task();
+        task.list = Some(ListReference {
+            id: "list-bugs".to_string(),
+            name: None,
+            access: None,
+        });
+        app.message_tx_for_testing()
+            .send(AppMessage::TaskFetchedForNavigation(Ok(task), Screen::Workspaces))
+            .await
+            .unwrap();
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::TaskDetail);
+
+        app.update(key(KeyCode::Esc));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert!(mock.recorded_calls().contains(&"get_list list-bugs".to_string()));
+        assert!(
+            app.screen_title_for_test().contains("Tasks: Bugs"),
+            "{}",
+            app.screen_title_for_test()
+        );
+    });
+}
+
+/// Test the onboarding wizard steps from welcome to the workspace summary
+#[test]
+fn test_onboarding_wizard_flow() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::{AppMessage, Screen};
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::auth::{STEP_READY, STEP_TOKEN, STEP_VERIFYING, STEP_WELCOME};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.set_screen(Screen::Auth);
+        app.set_onboarding_wizard(true);
+        assert_eq!(app.auth_state_mut_for_test().onboarding_step, STEP_WELCOME);
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.auth_state_mut_for_test().onboarding_step, STEP_TOKEN);
+
+        // Esc with an empty token goes back to the welcome step
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.auth_state_mut_for_test().onboarding_step, STEP_WELCOME);
+
+        // Simulate a token being verified
+        app.auth_state_mut_for_test().onboarding_step = STEP_VERIFYING;
+        app.message_tx_for_testing()
+            .send(AppMessage::WorkspacesLoaded(Ok(vec![fixtures::test_workspace()])))
+            .await
+            .unwrap();
+        app.process_async_messages();
+
+        let auth = app.auth_state_mut_for_test();
+        assert_eq!(auth.onboarding_step, STEP_READY);
+        assert_eq!(auth.workspace_names, vec![fixtures::test_workspace().name]);
+        assert_eq!(app.screen(), Screen::Auth);
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+/// Test that a task link pasted on the token screen opens once connected
+#[test]
+fn test_pasted_task_link_opens_after_onboarding() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::{AppMessage, Screen};
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::auth::{STEP_TOKEN, STEP_VERIFYING};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let link = "https://app.clickup.com/t/test-task-1";
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new().with_task(fixtures::test_task());
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+        app.set_screen(Screen::Auth);
+        app.set_onboarding_wizard(true);
+        app.auth_state_mut_for_test().onboarding_step = STEP_TOKEN;
+
+        // A link is kept aside instead of being typed into the token input
+        app.paste_on_auth_screen(link);
+        let auth = app.auth_state_mut_for_test();
+        assert_eq!(auth.pending_link.as_deref(), Some(link));
+        assert!(auth.token_input.is_empty());
+
+        app.paste_on_auth_screen("pk_123");
+        assert_eq!(app.auth_state_mut_for_test().token_input, "pk_123");
+
+        // Clearing the input keeps the link; an empty token is never saved
+        app.auth_state_mut_for_test().clear();
+        assert!(app.auth_state_mut_for_test().pending_link.is_some());
+
+        app.auth_state_mut_for_test().onboarding_step = STEP_VERIFYING;
+        app.message_tx_for_testing()
+            .send(AppMessage::WorkspacesLoaded(Ok(vec![fixtures::test_workspace()])))
+            .await
+            .unwrap();
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Auth);
+
+        app.update(InputEvent::Key(KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert!(app.auth_state_mut_for_test().pending_link.is_none());
+    });
+}
+
+/// Test that a rejected token returns the wizard to token input with an error
+#[test]
+fn test_onboarding_wizard_verification_failure() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::{AppMessage, Screen};
+    use clickdown::tui::widgets::auth::{STEP_TOKEN, STEP_VERIFYING};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.set_screen(Screen::Auth);
+        app.set_onboarding_wizard(true);
+        app.auth_state_mut_for_test().onboarding_step = STEP_VERIFYING;
+
+        app.message_tx_for_testing()
+            .send(AppMessage::WorkspacesLoaded(Err("401 Unauthorized".to_string())))
+            .await
+            .unwrap();
+        app.process_async_messages();
+
+        let auth = app.auth_state_mut_for_test();
+        assert_eq!(auth.onboarding_step, STEP_TOKEN);
+        assert!(auth
+            .error
+            .as_deref()
+            .is_some_and(|e| e.contains("401 Unauthorized")));
+        assert_eq!(app.screen(), Screen::Auth);
+    });
+}
+
+/// Test that comments arriving after the task detail was closed are discarded
+#[test]
+fn test_superseded_comment_load_is_discarded() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let task = fixtures::test_task();
+        let mock = MockClickUpClient::new().with_task_comments(fixtures::test_comments());
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task);
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        // Open the task (starts a comment load) and close it before the load lands
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert!(!app.is_loading(), "Closing the task should clear loading");
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(app.comments().is_empty(), "Stale comments should be ignored");
+        assert!(!app.is_loading());
+
+        // Reopening starts a fresh load whose result is applied
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.comments().len(), fixtures::test_comments().len());
+    });
+}
+
+/// Test that bulk deletion reports per-task failures without stopping the rest
+#[test]
+fn test_bulk_delete_tasks_collects_failures() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::api::ClickUpApi;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new()
+            .with_delete_task_success()
+            .with_delete_task_failure_for("t2");
+        let ids: Vec<String> = (1..=3).map(|i| format!("t{}", i)).collect();
+
+        let result = mock.bulk_delete_tasks(&ids).await.unwrap();
+
+        assert_eq!(result.deleted, vec!["t1".to_string(), "t3".to_string()]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, "t2");
+    });
+}
+
+/// Test that Ctrl+D deletes marked tasks in batches and keeps failed ones marked
+#[test]
+fn test_ctrl_d_bulk_deletes_marked_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::DialogType;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let tasks: Vec<clickdown::models::Task> = (1..=12)
+            .map(|i| clickdown::models::Task {
+                id: format!("bulk-{}", i),
+                name: format!("Bulk task {}", i),
+                ..Default::default()
+            })
+            .collect();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_delete_task_success()
+                .with_delete_task_failure_for("bulk-7"),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().extend(tasks);
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        for _ in 0..12 {
+            app.update(key(KeyCode::Char(' ')));
+            app.update(key(KeyCode::Char('j')));
+        }
+        assert_eq!(app.status_message(), "12 marked");
+
+        app.update(InputEvent::Key(KeyEvent::new(
+            KeyCode::Char('d'),
+            KeyModifiers::CONTROL,
+        )));
+        match app.dialog_type_for_test() {
+            Some(DialogType::ConfirmBulkDelete { task_names }) => {
+                assert_eq!(task_names.len(), 12)
+            }
+            other => panic!("Expected bulk delete dialog, got {:?}", other),
+        }
+        app.dialog_mut_for_test().toggle();
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.status_message(), "Deleting 0 of 12 tasks...");
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        app.process_async_messages();
+
+        let deletes = mock
+            .recorded_calls()
+            .iter()
+            .filter(|c| c.starts_with("delete_task "))
+            .count();
+        assert_eq!(deletes, 12);
+        assert_eq!(app.task_count(), 1, "Only the failed task remains");
+        assert!(app.task_list_for_test().is_marked("bulk-7"));
+        assert!(app
+            .error_message()
+            .is_some_and(|e| e.starts_with("Failed to delete 1 of 12 tasks")));
+    });
+}
+
+/// Test that opening a task loads its time entries into the detail view
+#[test]
+fn test_opening_task_loads_time_entries() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new().with_task_time_entries(vec![fixtures::test_time_entry()]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let entries = &app.task_detail().time_entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].user_name(), "testuser");
+    });
+}
+
+/// Test that a space with time tracking and tags off doesn't fetch an
+/// opened task's time entries and refuses to tag it
+#[test]
+fn test_features_off_in_space_skip_time_entries_and_tagging() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::SpaceReference;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut space = fixtures::test_space();
+        space.features = serde_json::from_str(
+            r#"{"time_tracking": {"enabled": false}, "tags": {"enabled": false}}"#,
+        )
+        .unwrap();
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![space.clone()])
+            .with_task_time_entries(vec![fixtures::test_time_entry()]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.sidebar().select_first();
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let mut task = fixtures::test_task();
+        task.space = Some(SpaceReference {
+            id: space.id.clone(),
+            name: Some(space.name.clone()),
+            access: None,
+        });
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task);
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert!(app.task_detail().time_entries.is_empty());
+
+        app.update(key(KeyCode::Char('T')));
+        assert_eq!(app.status_message(), "Not available on your plan: tags");
+    });
+}
+
+/// Test that a long error keeps its full text while the status bar shows one short line
+#[test]
+fn test_full_error_retained_when_status_bar_truncates() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let body = format!(r#"{{"err":"{}","ECODE":"ITEM_015"}}"#, "x".repeat(150));
+        let mock = MockClickUpClient::new()
+            .with_workspaces_error(format!("API error (500 Internal Server Error): {}", body));
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let error = app.error_message().expect("Load should fail").to_string();
+        assert!(error.ends_with(&body), "Full error is kept: {}", error);
+
+        let status = app.status_bar_text();
+        assert!(status.ends_with("… (!: details)"), "Status bar is cut: {}", status);
+        assert!(status.chars().count() < error.chars().count());
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('!'), KeyModifiers::NONE)));
+        assert!(app.is_error_detail_open());
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+        assert!(!app.is_error_detail_open());
+    });
+}
+
+/// Test that comments load newest page first and L puts the next (older) page
+/// above it, keeping the same comment selected
+#[test]
+fn test_load_older_comments_prepends_page_and_keeps_selection() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::COMMENTS_PAGE_SIZE;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        // Like the API, the mock serves comments newest first
+        let page_size = COMMENTS_PAGE_SIZE as usize;
+        let comments: Vec<_> = (1..=page_size + 5)
+            .map(|i| {
+                let mut comment = fixtures::test_comments()[0].clone();
+                comment.id = i.to_string();
+                comment
+            })
+            .collect();
+        let mock = Arc::new(MockClickUpClient::new().with_task_comments(comments));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // The newest page is shown oldest first, with the newest selected
+        assert_eq!(app.comments().len(), page_size);
+        assert!(app.has_more_comments());
+        assert_eq!(app.comments()[0].id, page_size.to_string());
+        assert_eq!(app.comments()[page_size - 1].id, "1");
+        assert_eq!(app.comment_selected_index(), page_size - 1);
+
+        // Look at the oldest loaded comment, then load the older page
+        app.set_comment_focus(true);
+        for _ in 0..page_size - 1 {
+            app.update(key(KeyCode::Char('k')));
+        }
+        assert_eq!(app.comment_selected_index(), 0);
+        app.update(key(KeyCode::Char('L')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.comments().len(), page_size + 5);
+        assert_eq!(app.comments()[0].id, (page_size + 5).to_string());
+        assert_eq!(app.comments()[5].id, page_size.to_string());
+        assert_eq!(app.comment_selected_index(), 5);
+        assert!(!app.has_more_comments());
+        assert_eq!(
+            mock.requested_comment_pages(),
+            vec![None, Some(page_size as i64)]
+        );
+
+        // Nothing older is left
+        app.update(key(KeyCode::Char('L')));
+        assert_eq!(app.status_message(), "No older comments");
+        assert_eq!(mock.requested_comment_pages().len(), 2);
+    });
+}
+
+/// Test that with reply prefetching off, replies are fetched once, when their
+/// thread is first entered
+#[test]
+fn test_entering_thread_fetches_replies_once() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut parent = fixtures::test_comment();
+        parent.reply_count = Some(1);
+        let mut reply = fixtures::test_comment();
+        reply.id = "reply-1".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_task_comments(vec![parent.clone()])
+                .with_comment_replies(&parent.id, vec![reply]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.config_mut_for_test().prefetch_comment_replies = false;
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // Opening the task loads only the top-level comments
+        assert_eq!(app.comments().len(), 1);
+        let reply_fetches = |mock: &MockClickUpClient| {
+            mock.recorded_calls()
+                .iter()
+                .filter(|c| c.starts_with("get_comment_replies "))
+                .count()
+        };
+        assert_eq!(reply_fetches(&mock), 0);
+
+        // Enter the thread, leave it, and enter it again
+        app.set_comment_focus(true);
+        for code in [KeyCode::Enter, KeyCode::Esc, KeyCode::Enter] {
+            app.update(key(code));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(reply_fetches(&mock), 1);
+        assert_eq!(app.comments().len(), 2);
+        assert_eq!(app.comments()[1].parent_id.as_deref(), Some(parent.id.as_str()));
+    });
+}
+
+/// Test that resting the selection on a comment with replies prefetches them,
+/// so entering its thread needs no further fetch
+#[test]
+fn test_resting_on_comment_prefetches_replies() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::reply_prefetch::HOVER_DELAY;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut parent = fixtures::test_comment();
+        parent.reply_count = Some(1);
+        let mut reply = fixtures::test_comment();
+        reply.id = "reply-1".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_task_comments(vec![parent.clone()])
+                .with_comment_replies(&parent.id, vec![reply]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.config_mut_for_test().prefetch_comment_replies = false;
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.set_comment_focus(true);
+
+        let reply_fetches = |mock: &MockClickUpClient| {
+            mock.recorded_calls()
+                .iter()
+                .filter(|c| c.starts_with("get_comment_replies "))
+                .count()
+        };
+        let start = Instant::now();
+        app.tick(start);
+        app.tick(start + HOVER_DELAY / 2);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(reply_fetches(&mock), 0);
+
+        app.tick(start + HOVER_DELAY);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(reply_fetches(&mock), 1);
+        assert_eq!(app.comments().len(), 2);
+
+        app.update(key(KeyCode::Enter));
+        app.tick(start + HOVER_DELAY * 4);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(reply_fetches(&mock), 1);
+        assert_eq!(app.comments().len(), 2);
+    });
+}
+
+/// Test that with `comment_focus_on_open`, selecting a task focuses its
+/// comments once they load, and leaves the form focused when it has none
+#[test]
+fn test_comment_focus_on_open() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        for comments in [vec![fixtures::test_comment()], vec![]] {
+            let has_comments = !comments.is_empty();
+            let mock = MockClickUpClient::new().with_task_comments(comments);
+            let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+            app.config_mut_for_test().comment_focus_on_open = true;
+
+            app.set_screen(Screen::Tasks);
+            app.tasks_mut_for_test().push(fixtures::test_task());
+            app.rebuild_task_list_for_test();
+            app.task_list_mut_for_test().select_first();
+
+            app.update(key(KeyCode::Enter));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+
+            assert_eq!(app.screen(), Screen::TaskDetail);
+            assert_eq!(app.comment_focus(), has_comments);
+            assert_eq!(
+                app.key_hints().contains("n: New comment"),
+                has_comments,
+                "{}",
+                app.key_hints()
+            );
+
+            // Tab still switches to the form
+            if has_comments {
+                app.update(key(KeyCode::Tab));
+                assert!(!app.comment_focus());
+            }
+        }
+    });
+}
+
+/// Test that Space expands and collapses the selected comment
+#[test]
+fn test_space_toggles_comment_expansion() {
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut app = TuiApp::new().unwrap();
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+        let id = fixtures::test_comment().id;
+
+        assert!(!app.is_comment_expanded(&id));
+        app.update(key(KeyCode::Char(' ')));
+        assert!(app.is_comment_expanded(&id));
+        app.update(key(KeyCode::Char(' ')));
+        assert!(!app.is_comment_expanded(&id));
+
+        // Expansion only lasts for the task view, however it is left
+        app.update(key(KeyCode::Char(' ')));
+        app.update(key(KeyCode::Esc));
+        assert!(!app.is_comment_expanded(&id));
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_comment_focus(true);
+        app.update(key(KeyCode::Char(' ')));
+        assert!(app.is_comment_expanded(&id));
+        app.navigate_back();
+        assert!(!app.is_comment_expanded(&id));
+    });
+}
+
+/// Test that opened tasks show up on the Recent Tasks screen and can be reopened
+#[test]
+fn test_ctrl_r_lists_recently_opened_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::ListReference;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let ctrl_r = InputEvent::Key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.list = Some(ListReference {
+            id: "list-recent".to_string(),
+            name: Some("Backlog".to_string()),
+            access: None,
+        });
+        let mock = MockClickUpClient::new().with_task(task.clone());
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task.clone());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+        app.update(key(KeyCode::Enter));
+        app.update(key(KeyCode::Esc));
+
+        app.update(ctrl_r.clone());
+        assert_eq!(app.screen(), Screen::RecentTasks);
+        assert_eq!(app.status_message(), "1 recent task(s)");
+
+        // Reopening fetches the task to restore its hierarchy
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(
+            app.task_detail().task.as_ref().map(|t| t.id.as_str()),
+            Some(task.id.as_str())
+        );
+    });
+}
+
+/// Test that the Recent Tasks screen refreshes names and statuses by task ID
+#[test]
+fn test_recent_tasks_refreshed_by_id() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::ListReference;
+    use clickdown::models::TaskStatus;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let ctrl_r = InputEvent::Key(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.list = Some(ListReference {
+            id: "list-recent".to_string(),
+            name: Some("Backlog".to_string()),
+            access: None,
+        });
+        let mut renamed = task.clone();
+        renamed.name = "Renamed elsewhere".to_string();
+        renamed.status = Some(TaskStatus {
+            id: None,
+            status: "review".to_string(),
+            color: None,
+            type_field: None,
+            orderindex: None,
+            status_group: None,
+        });
+        let mock = Arc::new(MockClickUpClient::new().with_task_by_id(renamed));
+        let mut app = TuiApp::with_client_and_test_cache(mock.clone()).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task.clone());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+        app.update(key(KeyCode::Enter));
+        app.update(key(KeyCode::Esc));
+
+        app.update(ctrl_r);
+        assert_eq!(
+            app.recent_tasks().tasks.items()[0].label(),
+            task.name.as_str()
+        );
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let recent = &app.recent_tasks().tasks.items()[0];
+        assert_eq!(recent.label(), "Renamed elsewhere");
+        assert_eq!(recent.status.as_deref(), Some("review"));
+        assert_eq!(mock.requested_task_ids(), [task.id.clone()]);
+    });
+}
+
+/// Test that tasks with comments newer than their last view get an unread marker
+#[test]
+fn test_unread_comments_marker_set_and_cleared() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let settle = || tokio::time::sleep(std::time::Duration::from_millis(10));
+
+    rt.block_on(async {
+        // Updated and commented on well after any view made by this test
+        let future = chrono::Utc::now().timestamp_millis() + 3_600_000;
+        let mut task = fixtures::test_task();
+        task.updated_at = Some(future);
+        let mut comment = fixtures::test_comment();
+        comment.created_at = Some(future);
+
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_my_tasks(vec![task.clone()])
+            .with_task(task.clone())
+            .with_task_comments(vec![comment]);
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        settle().await;
+        app.process_async_messages();
+
+        // Never viewed: nothing to compare against
+        app.open_my_tasks();
+        settle().await;
+        app.process_async_messages();
+        settle().await;
+        app.process_async_messages();
+        assert!(!app.is_task_unread(&task.id));
+
+        // Viewing the task records when its comments were seen
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::MyTasks);
+
+        app.open_my_tasks();
+        settle().await;
+        app.process_async_messages();
+        settle().await;
+        app.process_async_messages();
+        assert!(app.is_task_unread(&task.id));
+
+        // Opening the task clears the marker
+        app.update(key(KeyCode::Enter));
+        assert!(!app.is_task_unread(&task.id));
+    });
+}
+
+/// Test that cycling the sort order requests server-side ordering when the API supports it
+#[test]
+fn test_sort_key_requests_server_order() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_lists_in_space(vec![fixtures::test_list()])
+                .with_tasks(vec![fixtures::test_task()]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        open_first_space(&mut app).await;
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert!(!mock.requested_task_queries()[0].contains("order_by"));
+
+        // Status → due date: the API sorts, soonest first
+        app.update(key(KeyCode::Char('o')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let query = mock.requested_task_queries().last().cloned().unwrap();
+        assert!(query.contains("order_by=due_date"), "{}", query);
+        assert!(query.contains("reverse=true"), "{}", query);
+
+        // Created and updated are server-side too
+        app.update(key(KeyCode::Char('o')));
+        app.update(key(KeyCode::Char('o')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let queries = mock.requested_task_queries();
+        assert!(queries.last().unwrap().contains("order_by=updated"));
+
+        // Priority is sorted locally without another request
+        app.update(key(KeyCode::Char('o')));
+        assert_eq!(app.status_message(), "Sorted by priority");
+        assert_eq!(mock.requested_task_queries().len(), queries.len());
+    });
+}
+
+/// Test that `g w` aggregates the user's tasks across lists, grouped by space and list
+#[test]
+fn test_my_work_groups_assigned_tasks_by_space_and_list() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::{ListReference, SpaceReference};
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::ListRow;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let located = |id: &str, list: &str, assigned: bool| {
+        let mut task = fixtures::test_task();
+        task.id = id.to_string();
+        task.list = Some(ListReference {
+            id: format!("list-{}", list),
+            name: Some(list.to_string()),
+            access: None,
+        });
+        task.space = Some(SpaceReference {
+            id: fixtures::test_space().id,
+            name: None,
+            access: None,
+        });
+        if assigned {
+            task.assignees = vec![fixtures::test_user()];
+        }
+        task
+    };
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_workspace_tasks(vec![
+                    located("t1", "Backlog", true),
+                    located("t2", "Sprint", true),
+                    located("t3", "Backlog", true),
+                    located("t4", "Backlog", false),
+                ]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_current_user_id(Some(fixtures::test_user().id as i32));
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(key(KeyCode::Char('g')));
+        app.update(key(KeyCode::Char('w')));
+        assert_eq!(app.screen(), Screen::MyWork);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.status_message(), "3 task(s) assigned to you");
+        assert!(mock.requested_task_queries()[0].contains("assignees[]=123"));
+        let headers: Vec<&str> = app
+            .my_work_for_test()
+            .rows()
+            .iter()
+            .filter_map(|r| match r {
+                ListRow::Header { label, .. } => Some(label.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            headers,
+            vec!["▸ Test Space / Backlog (2)", "▸ Test Space / Sprint (1)"]
+        );
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::MyWork);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+/// Test that the document browser re-searches only once typing pauses and
+/// drops results of superseded searches
+#[test]
+fn test_documents_browser_debounces_search() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::{AppMessage, Screen};
+    use clickdown::tui::debounce::SEARCH_DEBOUNCE;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_documents(vec![fixtures::test_document()]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(key(KeyCode::Char('d')));
+        assert_eq!(app.screen(), Screen::Documents);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.documents_browser().docs.items().len(), 1);
+        let opened_generation = app.documents_browser().generation;
+
+        // Typing (including keys that are global shortcuts elsewhere) only edits the query
+        for c in "ug".chars() {
+            app.update(key(KeyCode::Char(c)));
+        }
+        assert_eq!(app.screen(), Screen::Documents);
+        assert_eq!(app.documents_browser().query, "ug");
+        app.tick(Instant::now());
+        assert_eq!(app.documents_browser().generation, opened_generation);
+
+        app.tick(Instant::now() + SEARCH_DEBOUNCE);
+        assert_eq!(app.documents_browser().generation, opened_generation + 1);
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.status_message(), "1 document(s)");
+
+        // A late reply to the first search is ignored
+        app.message_tx_for_testing()
+            .send(AppMessage::DocumentsSearched(Ok(vec![]), opened_generation))
+            .await
+            .unwrap();
+        app.process_async_messages();
+        assert_eq!(app.documents_browser().docs.items().len(), 1);
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::Document);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Documents);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+/// Test that docs opened from the spaces list are limited to the highlighted space
+#[test]
+fn test_documents_browser_searches_the_highlighted_space() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut in_space = fixtures::test_document();
+        in_space.space = Some(fixtures::test_space());
+        let mut elsewhere = fixtures::test_document();
+        elsewhere.id = "test-doc-2".to_string();
+        elsewhere.space = Some(clickdown::models::ClickUpSpace {
+            id: "other-space".to_string(),
+            ..fixtures::test_space()
+        });
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_documents(vec![in_space, elsewhere]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.sidebar().select_first();
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Spaces);
+        app.sidebar().select_first();
+
+        app.update(key(KeyCode::Char('d')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let ids: Vec<_> = app.documents_browser().docs.items().iter().map(|d| d.id.as_str()).collect();
+        assert_eq!(ids, ["test-doc-1"]);
+
+        // From the workspace list every space is searched
+        app.update(key(KeyCode::Esc));
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+        app.update(key(KeyCode::Char('d')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.documents_browser().docs.items().len(), 2);
+    });
+}
+
+/// Test that status messages clear after the timeout while errors stay
+#[test]
+fn test_status_message_clears_but_error_stays() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::AppMessage;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut app =
+            TuiApp::with_client_and_test_cache(Arc::new(MockClickUpClient::new())).unwrap();
+        app.message_tx_for_testing()
+            .send(AppMessage::WorkspacesLoaded(Ok(vec![fixtures::test_workspace()])))
+            .await
+            .unwrap();
+        app.process_async_messages();
+        assert_eq!(app.status_message(), "Loaded 1 workspace(s)");
+
+        let start = Instant::now();
+        app.tick(start);
+        app.tick(start + Duration::from_secs(2));
+        assert_eq!(app.status_message(), "Loaded 1 workspace(s)");
+        app.tick(start + Duration::from_secs(3));
+        assert_eq!(app.status_message(), "");
+
+        app.message_tx_for_testing()
+            .send(AppMessage::WorkspacesLoaded(Err("timed out".to_string())))
+            .await
+            .unwrap();
+        app.process_async_messages();
+        let start = Instant::now();
+        app.tick(start);
+        app.tick(start + Duration::from_secs(60));
+        assert!(
+            app.status_bar_text().contains("timed out"),
+            "{}",
+            app.status_bar_text()
+        );
+    });
+}
+
+/// Test that `g m` opens My Tasks and Esc returns through the task detail
+#[test]
+fn test_g_m_opens_my_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_my_tasks(vec![fixtures::test_task()]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(key(KeyCode::Char('g')));
+        app.update(key(KeyCode::Char('m')));
+        assert_eq!(app.screen(), Screen::MyTasks);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.status_message(), "1 task(s) assigned to you");
+
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert_eq!(
+            app.task_detail().task.as_ref().map(|t| t.id.as_str()),
+            Some(fixtures::test_task().id.as_str())
+        );
+
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::MyTasks);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+#[test]
+fn test_pipe_key_queues_task_for_pager() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_my_tasks(vec![fixtures::test_task()]);
+        let mut app = TuiApp::with_client(Arc::new(mock)).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.open_my_tasks();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        assert!(app.pending_pager_text().is_none());
+
+        app.update(key(KeyCode::Char('|')));
+
+        // The run loop hands this to the pager; the screen itself is unchanged
+        let text = app.pending_pager_text().expect("task should be queued");
+        assert!(text.starts_with(&fixtures::test_task().name), "{}", text);
+        assert_eq!(app.screen(), Screen::TaskDetail);
+    });
+}
+
+/// Type `text` into the app one key at a time
+fn type_text(app: &mut TuiApp, text: &str) {
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    for c in text.chars() {
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)));
+    }
+}
+
+/// Test that `n` on the workspaces screen creates a space and selects it
+#[test]
+fn test_new_space_from_workspaces_screen() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut marketing = fixtures::test_space();
+        marketing.id = "space-new".to_string();
+        marketing.name = "Marketing".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space(), marketing.clone()])
+                .with_create_space_response(marketing),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.sidebar().select_first();
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)));
+        assert!(app.is_text_input_active());
+
+        // A blank name is refused and the prompt stays open
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(app.is_text_input_active());
+
+        type_text(&mut app, "Marketing");
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE)));
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!app.is_text_input_active());
+
+        // Creation, then the reload of the workspace's spaces
+        for _ in 0..2 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec!["create_space test-ws-1 Marketing private=true"]
+        );
+        assert_eq!(app.screen(), Screen::Spaces);
+        assert_eq!(
+            app.sidebar().selected_item().map(|item| item.id().to_string()),
+            Some("space-new".to_string())
+        );
+    });
+}
+
+/// Test that `n` on the spaces screen creates a folder and selects it
+#[test]
+fn test_new_folder_from_spaces_screen() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut q3 = fixtures::test_folder();
+        q3.id = "folder-new".to_string();
+        q3.name = "Q3".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_folders(vec![fixtures::test_folder(), q3.clone()])
+                .with_create_folder_response(q3),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.sidebar().select_first();
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Spaces);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE)));
+        type_text(&mut app, "Q3");
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        for _ in 0..2 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(mock.recorded_calls(), vec!["create_folder test-space-1 Q3"]);
+        assert_eq!(app.screen(), Screen::Folders);
+        assert_eq!(
+            app.sidebar().selected_item().map(|item| item.id().to_string()),
+            Some("folder-new".to_string())
+        );
+    });
+}
+
+/// Test that the only workspace is opened on startup, and Esc still shows it
+#[test]
+fn test_single_workspace_auto_selected_on_start() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::config::StartScreen;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()]),
+        );
+        let mut app = TuiApp::with_client(mock).unwrap();
+        app.apply_start_screen(StartScreen::Workspaces);
+        app.load_workspaces();
+
+        // Workspaces, then the spaces of the only one
+        for _ in 0..2 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(app.screen(), Screen::Spaces);
+        assert_eq!(
+            app.current_workspace_id().map(String::as_str),
+            Some("test-ws-1")
+        );
+        assert_eq!(
+            app.sidebar().selected_item().map(|item| item.id().to_string()),
+            Some(fixtures::test_space().id)
+        );
+
+        // Back shows the workspace selected, and a reload doesn't descend again
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+        assert_eq!(app.screen(), Screen::Workspaces);
+        assert_eq!(
+            app.sidebar().selected_item().map(|item| item.id().to_string()),
+            Some("test-ws-1".to_string())
+        );
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+/// Test that the option can be turned off
+#[test]
+fn test_single_workspace_auto_select_disabled() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::config::StartScreen;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new().with_workspaces(vec![fixtures::test_workspace()]),
+        );
+        let mut app = TuiApp::with_client(mock).unwrap();
+        app.config_mut_for_test().auto_select_single_workspace = false;
+        app.apply_start_screen(StartScreen::Workspaces);
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Workspaces);
+    });
+}
+
+/// Test that with several workspaces the last session's one starts selected
+#[test]
+fn test_last_workspace_preselected_among_several() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::config::StartScreen;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut other = fixtures::test_workspace();
+        other.id = "test-ws-2".to_string();
+        other.name = "Other Workspace".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace(), other])
+                .with_spaces(vec![fixtures::test_space()]),
+        );
+        let mut app = TuiApp::with_client_and_test_cache(mock).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // Last session ended in the second workspace
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)));
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Spaces);
+        app.save_session_state().unwrap();
+
+        // Next start, on Workspaces rather than the restored location
+        app.apply_start_screen(StartScreen::Workspaces);
+        assert_eq!(app.current_workspace_id(), None);
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Workspaces);
+        assert_eq!(
+            app.sidebar().selected_item().map(|item| item.id().to_string()),
+            Some("test-ws-2".to_string())
+        );
+    });
+}
+
+/// Test that V lists the list's saved views and picking one shows its tasks
+#[test]
+fn test_saved_view_tasks_replace_list_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::View;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let view = |id: &str, name: &str| View {
+            id: id.to_string(),
+            name: name.to_string(),
+            view_type: Some("list".to_string()),
+            parent: None,
+        };
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_list_views(vec![view("v1", "Board"), view("v2", "Bugs - P1 only")])
+                .with_view_task_pages(vec![full_task_page(0), full_task_page(1)]),
+        );
+        let mut app = TuiApp::with_client(mock).unwrap();
+        app.set_current_list_id(Some("list-1".to_string()));
+        app.set_screen(Screen::Tasks);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(app.is_text_input_active(), "Picker opens once the views load");
+
+        type_text(&mut app, "bugs");
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!app.is_text_input_active());
+        assert!(app.screen_title_for_test().contains("Bugs - P1 only"));
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // Both pages of the view
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(app.task_count(), 2 * clickdown::models::TASKS_PAGE_SIZE);
+        assert!(app.screen_title_for_test().contains("Bugs - P1 only"));
+    });
+}
+
+/// Test that a list without saved views says so instead of opening an empty picker
+#[test]
+fn test_list_without_saved_views() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.set_current_list_id(Some("list-1".to_string()));
+        app.set_screen(Screen::Tasks);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(!app.is_text_input_active());
+        assert!(app.status_bar_text().contains("No saved views"));
+    });
+}
+
+/// Test that V on Workspaces lists the workspace's views, Enter shows a
+/// view's tasks, and the views are fetched once per workspace
+#[test]
+fn test_workspace_views_open_view_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::WorkspaceView;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let views: Vec<WorkspaceView> = serde_json::from_str(
+            r#"[{"id": "v1", "name": "Roadmap", "type": "calendar", "creator": 183,
+                 "visibility": "public"},
+                {"id": "v2", "name": "All bugs", "type": "list",
+                 "creator": {"id": 7, "username": "Ana"}, "sections": ["Open"]}]"#,
+        )
+        .unwrap();
+        assert_eq!(views[0].creator.id, 183);
+        assert_eq!(views[1].creator.username, "Ana");
+
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_workspace_views(views)
+                .with_view_task_pages(vec![vec![fixtures::test_task()]]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)));
+        assert_eq!(app.screen(), Screen::WorkspaceViews);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.workspace_views().views.items().len(), 2);
+
+        app.update(key(KeyCode::Char('j')));
+        app.update(key(KeyCode::Enter));
+        assert!(app.screen_title_for_test().contains("All bugs"));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(mock.recorded_calls().contains(&"get_view_tasks_page v2 0".to_string()));
+        assert!(app.workspace_views().tasks.selected_task().is_some());
+
+        // Enter opens the task and Esc returns to the view's tasks
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::WorkspaceViews);
+        assert!(app.workspace_views().open_view.is_some());
+
+        // Then back to the views, and to the workspaces
+        app.update(key(KeyCode::Esc));
+        assert!(app.workspace_views().open_view.is_none());
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+
+        // Reopening uses the cached views
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('V'), KeyModifiers::SHIFT)));
+        assert_eq!(app.workspace_views().views.items().len(), 2);
+        let fetches = mock
+            .recorded_calls()
+            .iter()
+            .filter(|call| call.starts_with("get_workspace_views"))
+            .count();
+        assert_eq!(fetches, 1);
+    });
+}
+
+/// Test that the sidebar tree loads children on first expand and opens a
+/// list's tasks in place
+#[test]
+fn test_sidebar_tree_expands_lazily_and_opens_list() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_folders(vec![fixtures::test_folder()])
+                .with_lists_in_space(vec![fixtures::test_list()])
+                .with_tasks(vec![fixtures::test_task()]),
+        );
+        let mut app = TuiApp::with_client(mock).unwrap();
+        app.config_mut_for_test().ui.sidebar_tree = true;
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(app.sidebar().is_tree());
+
+        // Expanding the workspace, then its space, loads each level once
+        app.update(key(KeyCode::Char('l')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.update(key(KeyCode::Char('j')));
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.screen(), Screen::Workspaces);
+        let kinds: Vec<_> = app.sidebar().items().iter().map(|i| i.kind_name()).collect();
+        assert_eq!(kinds, ["Workspace", "Space", "Folder", "List"]);
+
+        // Collapsing and expanding again needs no reload
+        app.update(key(KeyCode::Char('h')));
+        assert_eq!(app.sidebar().items().len(), 2);
+        app.update(key(KeyCode::Char('l')));
+        assert_eq!(app.sidebar().items().len(), 4);
+
+        // Enter on the list opens its tasks; Esc comes back to the tree
+        app.update(key(KeyCode::Char('j')));
+        app.update(key(KeyCode::Char('j')));
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(
+            app.current_list_id().map(String::as_str),
+            Some(fixtures::test_list().id.as_str())
+        );
+        app.update(key(KeyCode::Esc));
+        assert_eq!(app.screen(), Screen::Workspaces);
+        assert!(app.sidebar().is_tree());
+        assert_eq!(
+            app.sidebar().selected_item().map(|i| i.kind_name()),
+            Some("List")
+        );
+    });
+}
+
+/// Test that queued offline writes show in the status bar and warn on quit
+#[test]
+fn test_queued_offline_writes_indicator_and_quit_warning() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::widgets::DialogType;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = MockClickUpClient::new();
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+        app.set_screen(Screen::Workspaces);
+        assert!(!app.status_bar_text().contains("Offline mode"));
+        assert!(matches!(app.quit_dialog(), DialogType::ConfirmQuit));
+
+        app.cache()
+            .queue_request("DELETE", "https://api.clickup.com/api/v2/task/t1", None)
+            .unwrap();
+        app.refresh_queued_requests();
+        assert!(app.status_bar_text().starts_with("Offline mode: 1 queued"));
+
+        assert!(matches!(
+            app.quit_dialog(),
+            DialogType::ConfirmQuitWithQueued { queued: 1 }
+        ));
+    });
+}
+
+/// Test that / searches the help shortcuts and typed keys don't page or close it
+#[test]
+fn test_help_search_filters_by_typed_query() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut app =
+        rt.block_on(async { TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap() });
+    app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('?'), KeyModifiers::SHIFT)));
+    app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE)));
+    type_text(&mut app, "jk?");
+    app.update(InputEvent::Key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE)));
+
+    assert!(app.is_help_visible());
+    assert_eq!(app.help_page(), 0, "j/k are typed, not paging");
+    assert_eq!(app.help_search_query(), "jk");
+
+    // Enter keeps the filter, Esc then closes help and clears it
+    app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+    assert_eq!(app.help_search_query(), "jk");
+    app.update(InputEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+    assert!(!app.is_help_visible());
+    assert_eq!(app.help_search_query(), "");
+}
+
+/// Test that Ctrl+A archives marked tasks, hides them, and keeps failed ones marked
+#[test]
+fn test_ctrl_a_bulk_archives_marked_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let tasks: Vec<clickdown::models::Task> = (1..=3)
+            .map(|i| clickdown::models::Task {
+                id: format!("arch-{}", i),
+                name: format!("Archive task {}", i),
+                ..Default::default()
+            })
+            .collect();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_update_task_response(tasks[0].clone())
+                .with_update_task_failure_for("arch-2"),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().extend(tasks);
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        for _ in 0..2 {
+            app.update(key(KeyCode::Char(' ')));
+            app.update(key(KeyCode::Char('j')));
+        }
+        app.update(InputEvent::Key(KeyEvent::new(
+            KeyCode::Char('a'),
+            KeyModifiers::CONTROL,
+        )));
+        assert_eq!(app.status_message(), "Archiving 2 tasks...");
+
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        app.process_async_messages();
+
+        let calls = mock.recorded_calls();
+        assert!(calls.contains(&"update_task arch-1 archived=true".to_string()));
+        assert!(calls.contains(&"update_task arch-2 archived=true".to_string()));
+        assert_eq!(app.task_count(), 2, "The archived task is hidden");
+        assert!(app.task_list_for_test().is_marked("arch-2"));
+        assert!(!app.task_list_for_test().is_marked("arch-3"));
+        assert!(app
+            .error_message()
+            .is_some_and(|e| e.starts_with("Failed to update 1 of 2 tasks")));
+    });
+}
+
+/// Test that Ctrl+W in task detail archives the task after confirmation
+#[test]
+fn test_ctrl_w_archives_open_task_after_confirmation() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::DialogType;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let task = fixtures::test_task();
+        let mock = Arc::new(MockClickUpClient::new().with_update_task_response(task.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_screen(Screen::TaskDetail);
+        app.tasks_mut_for_test().push(task.clone());
+        app.rebuild_task_list_for_test();
+        app.task_detail().task = Some(task.clone());
+
+        let ctrl_w = || InputEvent::Key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        app.update(ctrl_w());
+        assert!(matches!(
+            app.dialog_type_for_test(),
+            Some(DialogType::ConfirmArchive { archive: true, .. })
+        ));
+
+        // Declining leaves the task alone
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert!(!mock.recorded_calls().iter().any(|c| c.starts_with("update_task")));
+
+        app.update(ctrl_w());
+        app.dialog_mut_for_test().toggle();
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let archive_call = format!("update_task {} archived=true", task.id);
+        assert!(mock.recorded_calls().contains(&archive_call));
+        assert_eq!(app.status_message(), "Task archived");
+        assert_eq!(app.task_detail().task.as_ref().unwrap().archived, Some(true));
+        assert_eq!(app.task_count(), 0, "The archived task is hidden");
+
+        // Once archived, Ctrl+W offers to unarchive it
+        app.update(ctrl_w());
+        assert!(matches!(
+            app.dialog_type_for_test(),
+            Some(DialogType::ConfirmArchive { archive: false, .. })
+        ));
+    });
+}
+
+/// Test that Ctrl+E while editing queues the description for the external
+/// editor, and an edit is staged until Ctrl+S saves it
+#[test]
+fn test_external_editor_description_is_staged_then_saved() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::TaskDescription;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::external_editor::EditOutcome;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.description = Some(TaskDescription::Plain("Plan".to_string()));
+        let mock = Arc::new(MockClickUpClient::new().with_update_task_response(task.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_screen(Screen::TaskDetail);
+        app.task_detail().task = Some(task.clone());
+
+        let ctrl = |c| InputEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+        app.update(ctrl('e'));
+        assert_eq!(app.pending_editor_text(), None, "Only while editing the task");
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE)));
+        app.update(ctrl('e'));
+        assert_eq!(app.pending_editor_text(), Some("Plan"));
+
+        // A failed or untouched edit stages nothing
+        app.finish_description_edit("Plan", Err(anyhow::anyhow!("exited with 1")));
+        assert!(app.toast().is_some_and(|t| t.starts_with("Editor failed, changes discarded")));
+        app.finish_description_edit("Plan", Ok(EditOutcome::Unchanged));
+        assert_eq!(app.status_message(), "Description unchanged");
+        assert_eq!(app.task_detail().staged_description, None);
+
+        let edited = "Plan\n\nShip it".to_string();
+        app.finish_description_edit("Plan", Ok(EditOutcome::Changed(edited.clone())));
+        assert_eq!(
+            app.status_message(),
+            "Description edited: 1 → 3 lines, +9 chars (Ctrl+S to save)"
+        );
+        assert_eq!(app.task_detail().description_text(), "Plan\n\nShip it");
+
+        app.update(ctrl('s'));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(mock.recorded_calls().contains(&format!("update_task {}", task.id)));
+        assert_eq!(app.status_message(), "Description saved");
+        assert_eq!(app.task_detail().staged_description, None);
+        assert_eq!(
+            app.task_detail().task.as_ref().unwrap().description_source().as_deref(),
+            Some(edited.as_str())
+        );
+    });
+}
+
+/// Test that a staged description stays with its task: opening another task
+/// neither shows nor saves it there
+#[test]
+fn test_staged_description_does_not_follow_to_another_task() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::TaskDescription;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::external_editor::EditOutcome;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut first = fixtures::test_task();
+        first.description = Some(TaskDescription::Plain("Plan".to_string()));
+        let mut second = fixtures::test_task();
+        second.id = "task-2".to_string();
+        second.description = Some(TaskDescription::Plain("Other plan".to_string()));
+        let mock = Arc::new(MockClickUpClient::new().with_update_task_response(second.clone()));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.tasks_mut_for_test().push(second.clone());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.set_screen(Screen::TaskDetail);
+        app.task_detail().task = Some(first.clone());
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE)));
+        app.finish_description_edit("Plan", Ok(EditOutcome::Changed("Plan B".to_string())));
+        assert_eq!(app.task_detail().description_text(), "Plan B");
+
+        // Open the second task from the list without leaving through Esc
+        app.set_screen(Screen::Tasks);
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert_eq!(app.task_detail().task.as_ref().unwrap().id, "task-2");
+        assert!(!app.task_detail().editing);
+        assert_eq!(app.task_detail().description_text(), "Other plan");
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(!mock.recorded_calls().iter().any(|c| c.starts_with("update_task")));
+    });
+}
+
+/// Test that Related Tasks focus doesn't carry over to the next task opened
+#[test]
+fn test_related_tasks_focus_resets_on_task_open() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let first = fixtures::test_task();
+        let mut second = fixtures::test_task();
+        second.id = "task-2".to_string();
+        let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+        app.tasks_mut_for_test().push(second.clone());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.set_screen(Screen::TaskDetail);
+        app.task_detail().task = Some(first);
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::NONE)));
+        assert!(app.task_detail().links_focused);
+
+        app.set_screen(Screen::Tasks);
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        assert_eq!(app.task_detail().task.as_ref().unwrap().id, "task-2");
+        assert!(!app.task_detail().links_focused);
+        assert_eq!(app.task_detail().link_selected, 0);
+    });
+}
+
+/// Test that D switches the task list density, starting from the config
+#[test]
+fn test_d_toggles_task_list_density() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::config::TaskListDensity;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut app = rt
+        .block_on(async { TuiApp::with_client(Arc::new(MockClickUpClient::new())) })
+        .unwrap();
+    app.set_screen(Screen::Tasks);
+    app.config_mut_for_test().ui.task_list_density = TaskListDensity::Compact;
+
+    let d = || InputEvent::Key(KeyEvent::new(KeyCode::Char('D'), KeyModifiers::NONE));
+    app.update(d());
+    assert_eq!(app.status_message(), "Comfortable task list");
+    assert_eq!(app.config_mut_for_test().ui.task_list_density, TaskListDensity::Comfortable);
+    app.update(d());
+    assert_eq!(app.status_message(), "Compact task list");
+}
+
+/// Test that Ctrl+A with no tasks marked toggles showing archived tasks
+#[test]
+fn test_ctrl_a_without_marks_toggles_archived_tasks() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mock = Arc::new(MockClickUpClient::new().with_tasks(vec![]));
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_screen(Screen::Tasks);
+        app.set_current_list_id(Some("list-1".to_string()));
+
+        let ctrl_a = || InputEvent::Key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+        app.update(ctrl_a());
+        assert_eq!(app.status_message(), "Showing archived tasks");
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let query = mock.requested_task_queries().last().cloned().unwrap();
+        assert!(query.contains("archived=true"), "{}", query);
+
+        app.update(ctrl_a());
+        assert_eq!(app.status_message(), "Hiding archived tasks");
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        let query = mock.requested_task_queries().last().cloned().unwrap();
+        assert!(!query.contains("archived"), "{}", query);
+    });
+}
+
+/// Test that replies are prefetched with the comments by default, and
+/// entering a thread doesn't fetch them again
+#[test]
+fn test_replies_prefetched_on_open_by_default() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mut parent = fixtures::test_comment();
+        parent.reply_count = Some(1);
+        let mut quiet = fixtures::test_comment();
+        quiet.id = "no-replies".to_string();
+        quiet.reply_count = Some(0);
+        let mut reply = fixtures::test_comment();
+        reply.id = "reply-1".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_task_comments(vec![parent.clone(), quiet])
+                .with_comment_replies(&parent.id, vec![reply]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // Replies arrive with the comments, skipping the comment without any
+        let reply_fetches = |mock: &MockClickUpClient| {
+            mock.recorded_calls()
+                .into_iter()
+                .filter(|c| c.starts_with("get_comment_replies "))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(reply_fetches(&mock), vec![format!("get_comment_replies {}", parent.id)]);
+        assert_eq!(app.comments().len(), 3);
+        assert_eq!(app.comments()[2].parent_id.as_deref(), Some(parent.id.as_str()));
+
+        app.set_comment_focus(true);
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(reply_fetches(&mock).len(), 1);
+    });
+}
+
+/// Test that a failed comment load shows the cached comments with a notice
+#[test]
+fn test_failed_comment_load_falls_back_to_cache() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut mock = MockClickUpClient::new();
+        mock.task_comments_response = Some(Err(anyhow::anyhow!("connection refused")));
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+
+        let mut task = fixtures::test_task();
+        task.id = "cached-comments-task".to_string();
+        let mut older = fixtures::test_comment();
+        older.id = "c-older".to_string();
+        older.created_at = Some(1_700_000_000_000);
+        let mut newer = fixtures::test_comment();
+        newer.id = "c-newer".to_string();
+        newer.created_at = Some(1_700_000_100_000);
+        app.cache().cache_comments(&task.id, &[newer, older]).unwrap();
+
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(task);
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // Oldest first, with the newest selected
+        let ids: Vec<_> = app.comments().iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["c-older", "c-newer"]);
+        assert_eq!(app.comment_selected_index(), 1);
+        assert!(app.error_message().is_none());
+        assert_eq!(
+            app.comments_cached_notice().as_deref(),
+            Some("⚠ Showing cached comments from less than a minute ago")
+        );
+    });
+}
+
+/// Test that a failed task load shows the list's cached tasks
+#[test]
+fn test_failed_task_load_falls_back_to_cache() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::ListReference;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()]);
+        mock.tasks_response = Some(Err(anyhow::anyhow!("connection refused")));
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+
+        let mut task = fixtures::test_task();
+        task.list = Some(ListReference {
+            id: fixtures::test_list().id,
+            name: Some("Test List".to_string()),
+            access: None,
+        });
+        app.cache()
+            .cache_list_tasks(&fixtures::test_list().id, &[task.clone()])
+            .unwrap();
+
+        open_first_space(&mut app).await;
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(app.task_count(), 1);
+        assert_eq!(app.task_list_for_test().selected_task().unwrap().id, task.id);
+        assert!(app
+            .status_message()
+            .starts_with("⚠ Showing cached tasks from less than a minute ago"));
+    });
+}
+
+/// Test that a filtered task load doesn't replace the list's cached tasks
+#[test]
+fn test_filtered_task_load_is_not_cached() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::ListReference;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let list = ListReference {
+            id: fixtures::test_list().id,
+            name: Some("Test List".to_string()),
+            access: None,
+        };
+        let mut all = fixtures::test_task();
+        all.list = Some(list.clone());
+        let mut mine = fixtures::test_task();
+        mine.id = "mine".to_string();
+        mine.list = Some(list);
+
+        let mut mock = MockClickUpClient::new()
+            .with_workspaces(vec![fixtures::test_workspace()])
+            .with_spaces(vec![fixtures::test_space()])
+            .with_lists_in_space(vec![fixtures::test_list()]);
+        mock.tasks_with_assignee_response = Some(Ok(vec![mine]));
+        let mut app = TuiApp::with_client_and_test_cache(Arc::new(mock)).unwrap();
+        app.cache()
+            .cache_list_tasks(&fixtures::test_list().id, &[all.clone()])
+            .unwrap();
+        app.set_current_user_id(Some(1));
+        app.set_assigned_filter_active(true);
+
+        open_first_space(&mut app).await;
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert_eq!(app.task_list_for_test().selected_task().unwrap().id, "mine");
+        let cached = app.cache().get_list_tasks(&fixtures::test_list().id).unwrap();
+        assert_eq!(cached.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), [all.id.as_str()]);
+    });
+}
+
+/// Test that task loads ask for markdown descriptions unless prefer_markdown is off
+#[test]
+fn test_load_tasks_requests_markdown_descriptions() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        for prefer_markdown in [true, false] {
+            let mock = Arc::new(
+                MockClickUpClient::new()
+                    .with_workspaces(vec![fixtures::test_workspace()])
+                    .with_spaces(vec![fixtures::test_space()])
+                    .with_lists_in_space(vec![fixtures::test_list()])
+                    .with_tasks(vec![fixtures::test_task()]),
+            );
+            let mut app = TuiApp::with_client(mock.clone()).unwrap();
+            app.config_mut_for_test().prefer_markdown = prefer_markdown;
+            open_first_space(&mut app).await;
+            app.navigate_into();
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+
+            assert_eq!(app.screen(), Screen::Tasks);
+            assert_eq!(
+                mock.requested_task_queries()[0].contains("include_markdown_description=true"),
+                prefer_markdown
+            );
+        }
+    });
+}
+
+/// Test that the debug snapshot names the screen and lists its items
+#[test]
+fn test_debug_snapshot_lists_screen_and_items() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use std::sync::Arc;
+
+    let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+    app.set_screen(Screen::Tasks);
+    let mut second = fixtures::test_task();
+    second.id = "test-task-2".to_string();
+    second.name = "Second Task".to_string();
+    app.tasks_mut_for_test().push(fixtures::test_task());
+    app.tasks_mut_for_test().push(second);
+    app.rebuild_task_list_for_test();
+    app.task_list_mut_for_test().select_first();
+
+    let snapshot = app.debug_snapshot();
+    let lines: Vec<&str> = snapshot.lines().collect();
+    assert_eq!(lines[0], "screen: Tasks");
+    assert!(lines.contains(&"items: 2"), "{}", snapshot);
+    let selected: Vec<_> = lines.iter().filter(|l| l.starts_with('>')).collect();
+    assert_eq!(selected.len(), 1, "{}", snapshot);
+
+    // Same state, same text
+    assert_eq!(app.debug_snapshot(), snapshot);
+}
+
+/// Test that slim_tasks skips markdown descriptions and trims custom fields
+#[test]
+fn test_slim_tasks_trims_loaded_custom_fields() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::CustomField;
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut task = fixtures::test_task();
+        task.custom_fields = vec![CustomField {
+            id: "cf1".to_string(),
+            name: "Notes".to_string(),
+            type_field: Some("text".to_string()),
+            value: Some(serde_json::json!("short")),
+            value_richtext: Some(serde_json::json!({"ops": [{"insert": "huge"}]})),
+            type_config: Some(serde_json::json!({"default": "blob"})),
+            date_created: None,
+            hide_from_guests: None,
+            required: None,
+        }];
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_lists_in_space(vec![fixtures::test_list()])
+                .with_tasks(vec![task]),
+        );
+        let mut app = TuiApp::with_client_and_test_cache(mock.clone()).unwrap();
+        app.config_mut_for_test().slim_tasks = true;
+        open_first_space(&mut app).await;
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert!(mock.requested_task_queries()[0].contains("include_markdown_description=false"));
+        let loaded = app.task_list().selected_task().expect("task loaded");
+        assert_eq!(loaded.custom_fields[0].value, Some(serde_json::json!("short")));
+        assert!(loaded.custom_fields[0].value_richtext.is_none());
+        assert!(loaded.custom_fields[0].type_config.is_none());
+    });
+}
+
+/// Test that time in status loads with the task detail and is cached per task
+#[test]
+fn test_time_in_status_loaded_once_per_task() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::{TaskStatus, TimeInStatus};
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let open = TimeInStatus {
+            status: TaskStatus {
+                id: None,
+                status: "open".to_string(),
+                color: Some("#d3d3d3".to_string()),
+                type_field: None,
+                orderindex: Some(0),
+                status_group: None,
+            },
+            duration_ms: 3 * 86_400_000,
+            order_index: 0,
+        };
+        let mock = Arc::new(MockClickUpClient::new().with_time_in_status(vec![open.clone()]));
+        let mut app = TuiApp::with_client_and_test_cache(mock.clone()).unwrap();
+        app.set_screen(Screen::Tasks);
+        app.tasks_mut_for_test().push(fixtures::test_task());
+        app.rebuild_task_list_for_test();
+        app.task_list_mut_for_test().select_first();
+
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        app.update(InputEvent::Key(enter));
+        assert_eq!(app.screen(), Screen::TaskDetail);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.task_detail().time_in_status, vec![open.clone()]);
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)));
+        assert_eq!(app.screen(), Screen::Tasks);
+        app.update(InputEvent::Key(enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.task_detail().time_in_status, vec![open]);
+        assert_eq!(mock.requested_time_in_status(), vec!["test-task-1".to_string()]);
+    });
+}
+
+/// Test that opening a searched document fetches it by ID with its pages
+#[test]
+fn test_open_searched_document_fetches_it_by_id() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_documents(vec![fixtures::test_document()])
+                .with_pages(vec![
+                    fixtures::test_page("p1", "First page"),
+                    fixtures::test_page("p2", "Second page"),
+                ]),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.load_workspaces();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        app.update(key(KeyCode::Char('d')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        app.update(key(KeyCode::Enter));
+        assert_eq!(app.screen(), Screen::Document);
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(mock.requested_documents(), vec!["test-doc-1".to_string()]);
+        assert_eq!(app.document_view().title, "Test Document");
+        assert_eq!(app.document_view().content, "First page\n\nSecond page");
+        assert_eq!(app.status_message(), "Loaded 2 page(s)");
+    });
+}
+
+/// Test that comment_order arranges loaded comments, starts on the newest,
+/// and puts a new comment where the newest belongs
+#[test]
+fn test_comment_order_newest_and_oldest() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::config::CommentOrder;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    // Single-threaded, so the reload after creating a comment only runs
+    // when the test awaits
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let key = |code, modifiers| InputEvent::Key(KeyEvent::new(code, modifiers));
+    let comment = |id: &str, created_at| {
+        let mut comment = fixtures::test_comment();
+        comment.id = id.to_string();
+        comment.created_at = Some(created_at);
+        comment
+    };
+    let ids = |app: &TuiApp| -> Vec<String> { app.comments().iter().map(|c| c.id.clone()).collect() };
+
+    for (order, loaded, selected, created) in [
+        (CommentOrder::Oldest, ["1", "2", "3"], 2, ["1", "2", "3", "4"]),
+        (CommentOrder::Newest, ["3", "2", "1"], 0, ["4", "3", "2", "1"]),
+    ] {
+        rt.block_on(async {
+            // Like the API, the mock serves comments newest first
+            let mock = Arc::new(
+                MockClickUpClient::new()
+                    .with_task_comments(vec![
+                        comment("3", 3000),
+                        comment("2", 2000),
+                        comment("1", 1000),
+                    ])
+                    .with_create_comment_response(comment("4", 4000)),
+            );
+            let mut app = TuiApp::with_client(mock).unwrap();
+            app.config_mut_for_test().comment_order = order;
+
+            app.set_screen(Screen::Tasks);
+            app.tasks_mut_for_test().push(fixtures::test_task());
+            app.rebuild_task_list_for_test();
+            app.task_list_mut_for_test().select_first();
+            app.update(key(KeyCode::Enter, KeyModifiers::NONE));
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+
+            assert_eq!(ids(&app), loaded, "{:?}", order);
+            assert_eq!(app.comment_selected_index(), selected, "{:?}", order);
+
+            app.set_comment_focus(true);
+            app.update(key(KeyCode::Char('n'), KeyModifiers::NONE));
+            app.update(key(KeyCode::Char('x'), KeyModifiers::NONE));
+            app.update(key(KeyCode::Char('s'), KeyModifiers::CONTROL));
+            tokio::task::yield_now().await;
+            app.process_async_messages();
+
+            assert_eq!(ids(&app), created, "{:?}", order);
+        });
+    }
+}
+
+/// Test that a new comment is fetched again on its own instead of
+/// reloading every comment on the task
+#[test]
+fn test_created_comment_is_refreshed_alone() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code, modifiers| InputEvent::Key(KeyEvent::new(code, modifiers));
+
+    rt.block_on(async {
+        let mut created = fixtures::test_comment();
+        created.id = "new-comment".to_string();
+        created.text = "x".to_string();
+        // ClickUp's copy has what the create response leaves out
+        let mut stored = created.clone();
+        stored.text = "x (stored)".to_string();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_task_comments(vec![stored, fixtures::test_comment()])
+                .with_create_comment_response(created),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        app.update(key(KeyCode::Char('n'), KeyModifiers::NONE));
+        app.update(key(KeyCode::Char('x'), KeyModifiers::NONE));
+        app.update(key(KeyCode::Char('s'), KeyModifiers::CONTROL));
+        for _ in 0..2 {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            app.process_async_messages();
+        }
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                format!("create_comment {} x", fixtures::test_task().id),
+                "get_comment new-comment".to_string(),
+            ]
+        );
+        assert_eq!(app.comments().len(), 2);
+        assert!(app.comments().iter().any(|c| c.text == "x (stored)"));
+    });
+}
+
+/// Test that a comment create that times out but reached ClickUp is found
+/// among the task's comments and shown as added, and one that didn't is
+/// reported as failed
+#[test]
+fn test_timed_out_comment_create_that_landed_is_added() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::User;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code, modifiers| InputEvent::Key(KeyEvent::new(code, modifiers));
+    let timeout = "API error (504 Gateway Timeout): upstream request timeout";
+
+    for landed in [true, false] {
+        rt.block_on(async {
+            // ClickUp made the comment, by the current user, after all
+            let mut created = fixtures::test_comment();
+            created.id = "landed".to_string();
+            created.text = "x".to_string();
+            created.created_at = Some(chrono::Utc::now().timestamp_millis());
+            created.commenter = Some(User {
+                id: 1,
+                username: "test_user".to_string(),
+                color: None,
+                email: None,
+                profile_picture: None,
+                initials: None,
+            });
+            let mut comments = vec![fixtures::test_comment()];
+            if landed {
+                comments.insert(0, created);
+            }
+            let mock = Arc::new(
+                MockClickUpClient::new()
+                    .with_task_comments(comments)
+                    .with_create_comment_error(timeout),
+            );
+            let mut app = TuiApp::with_client(mock.clone()).unwrap();
+
+            app.set_screen(Screen::TaskDetail);
+            app.set_task_detail_task(fixtures::test_task());
+            app.set_comments(vec![fixtures::test_comment()]);
+            app.set_comment_focus(true);
+
+            app.update(key(KeyCode::Char('n'), KeyModifiers::NONE));
+            app.update(key(KeyCode::Char('x'), KeyModifiers::NONE));
+            app.update(key(KeyCode::Char('s'), KeyModifiers::CONTROL));
+            for _ in 0..2 {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                app.process_async_messages();
+            }
+
+            let creates = mock
+                .recorded_calls()
+                .iter()
+                .filter(|c| c.starts_with("create_comment"))
+                .count();
+            assert_eq!(creates, 1, "The create is not sent again");
+            if landed {
+                assert_eq!(app.status_message(), "Comment added");
+                assert!(app.error_message().is_none());
+                assert_eq!(app.comments().len(), 2);
+                assert!(app.comments().iter().any(|c| c.id == "landed"));
+            } else {
+                assert_eq!(app.status_message(), "Failed to create comment");
+                assert!(app.error_message().is_some_and(|e| e.contains("504")));
+                assert_eq!(app.comments().len(), 1);
+            }
+        });
+    }
+}
+
+/// Test that actions a task's permission level doesn't allow are hidden
+/// from the hints and refused with a read-only message
+#[test]
+fn test_read_only_task_hides_and_refuses_actions() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::User;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let key = |c| InputEvent::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+    let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+    let mut task = fixtures::test_task();
+    task.permission_level = Some("comment".to_string());
+    let author = |id| User {
+        id,
+        username: format!("user-{}", id),
+        email: None,
+        color: None,
+        profile_picture: None,
+        initials: None,
+    };
+    let mut own = fixtures::test_comment();
+    own.commenter = Some(author(1));
+    let mut others = fixtures::test_comment();
+    others.id = "test-comment-2".to_string();
+    others.commenter = Some(author(2));
+
+    app.set_screen(Screen::TaskDetail);
+    app.set_task_detail_task(task);
+    app.set_current_user_id(Some(1));
+
+    // Comment access: no task edits
+    assert!(!app.key_hints().contains("e: Edit task"), "{}", app.key_hints());
+    app.update(key('e'));
+    assert_eq!(app.status_message(), "Read-only access: can't edit this task");
+    app.update(key('s'));
+    assert_eq!(
+        app.status_message(),
+        "Read-only access: can't change this task's status"
+    );
+
+    // Others' comments can't be changed, own ones can
+    app.set_comments(vec![others]);
+    app.set_comment_focus(true);
+    assert!(app.key_hints().contains("n: New comment"), "{}", app.key_hints());
+    assert!(!app.key_hints().contains("x: Delete"), "{}", app.key_hints());
+    app.update(key('x'));
+    assert_eq!(app.status_message(), "Read-only access: can't delete this comment");
+    assert_eq!(app.comments().len(), 1);
+
+    app.set_comments(vec![own]);
+    assert!(app.key_hints().contains("e: Edit | x: Delete"), "{}", app.key_hints());
+}
+
+/// Test that `a` on a comment picks a list member and assigns the comment to them
+#[test]
+fn test_assign_comment_to_member() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::User;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let member = |id, name: &str| User {
+        id,
+        username: name.to_string(),
+        email: None,
+        color: None,
+        profile_picture: None,
+        initials: None,
+    };
+
+    rt.block_on(async {
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_list_members(vec![member(1, "alice"), member(2, "bob")])
+                .with_update_comment_response(fixtures::test_comment()),
+        );
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        app.set_current_list_id(Some("list-1".to_string()));
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(fixtures::test_task());
+        app.set_comments(vec![fixtures::test_comment()]);
+        app.set_comment_focus(true);
+
+        app.update(key(KeyCode::Char('a')));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert!(app.key_hints().contains("Enter: Assign"), "{}", app.key_hints());
+
+        // Filter down to bob and assign
+        for c in "bo".chars() {
+            app.update(key(KeyCode::Char(c)));
+        }
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        let updates = mock.requested_comment_updates();
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].0, "test-comment-1");
+        assert_eq!(updates[0].1.assignee, Some(2));
+        assert_eq!(app.status_message(), "Comment assigned to bob");
+        assert_eq!(
+            app.comments()[0].assigned_commenter.as_ref().map(|u| u.id),
+            Some(2)
+        );
+    });
+}
+
+/// Test that `T` in task detail names a tag, picks its color and adds it to the task
+#[test]
+fn test_create_tag_with_color_for_task() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::SpaceReference;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use clickdown::tui::widgets::ListRow;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(MockClickUpClient::new());
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        let mut task = fixtures::test_task();
+        task.space = Some(SpaceReference {
+            id: "space-1".to_string(),
+            name: None,
+            access: None,
+        });
+        app.tasks_mut_for_test().push(task.clone());
+        app.rebuild_task_list_for_test();
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(task);
+
+        app.update(key(KeyCode::Char('T')));
+        assert!(app.is_text_input_active());
+        type_text(&mut app, "urgent");
+        app.update(key(KeyCode::Enter));
+        assert!(app.key_hints().contains("Arrows: Move"), "{}", app.key_hints());
+
+        // From blue, one step left and one up lands on olive
+        app.update(key(KeyCode::Left));
+        app.update(key(KeyCode::Up));
+        app.update(key(KeyCode::Enter));
+        assert!(!app.is_text_input_active());
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                "create_space_tag space-1 urgent #808000 #ffffff",
+                "tag_task test-task-1 urgent",
+            ]
+        );
+        assert_eq!(app.status_message(), "Tagged with urgent");
+        let tags = &app.task_detail().task.as_ref().unwrap().tags;
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].tag_bg.as_deref(), Some("#808000"));
+        // The task list shows the tag without a reload
+        let listed = app.task_list().rows().iter().find_map(|row| match row {
+            ListRow::Task(task) => Some(task.tags.len()),
+            _ => None,
+        });
+        assert_eq!(listed, Some(1));
+    });
+}
+
+/// Test that `l` in task detail manages the task's links: `n` links by task ID,
+/// `d` unlinks after confirmation and dependencies can't be unlinked there
+#[test]
+fn test_manage_task_links_from_task_detail() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::models::task::{LinkKind, TaskDependencyRef};
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+
+    rt.block_on(async {
+        let mock = Arc::new(MockClickUpClient::new());
+        let mut app = TuiApp::with_client(mock.clone()).unwrap();
+        let mut task = fixtures::test_task();
+        task.dependencies = vec![TaskDependencyRef {
+            id: None,
+            task_id: Some("test-task-1".to_string()),
+            depends_on: Some("blocker".to_string()),
+            name: None,
+            dependency_status: None,
+            type_field: Some(1),
+            date_created: None,
+            userid: None,
+            workspace_id: None,
+            chain_id: None,
+        }];
+        app.set_screen(Screen::TaskDetail);
+        app.set_task_detail_task(task);
+
+        app.update(key(KeyCode::Char('l')));
+        assert!(app.task_detail().links_focused);
+
+        app.update(key(KeyCode::Char('n')));
+        assert!(app.is_text_input_active());
+        type_text(&mut app, "other-task");
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(app.status_message(), "Linked to other-task");
+        let related = app.task_detail().task.as_ref().unwrap().related_tasks();
+        let kinds: Vec<(&str, LinkKind)> =
+            related.iter().map(|r| (r.task_id.as_str(), r.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![("blocker", LinkKind::WaitingOn), ("other-task", LinkKind::Related)]
+        );
+
+        // The dependency stays put
+        app.update(key(KeyCode::Char('d')));
+        assert!(!app.is_dialog_visible());
+        assert_eq!(app.status_message(), "blocker is a dependency, not a link");
+
+        app.update(key(KeyCode::Char('j')));
+        app.update(key(KeyCode::Char('d')));
+        assert!(app.is_dialog_visible());
+        app.dialog_mut_for_test().toggle(); // Switch from "No" to "Yes"
+        app.update(key(KeyCode::Enter));
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        assert_eq!(
+            mock.recorded_calls(),
+            vec![
+                "add_task_link test-task-1 other-task",
+                "remove_task_link test-task-1 other-task",
+            ]
+        );
+        assert!(app.task_detail().task.as_ref().unwrap().linked_tasks.is_empty());
+        assert_eq!(app.task_detail().link_selected, 0);
+
+        app.update(key(KeyCode::Esc));
+        assert!(!app.task_detail().links_focused);
+        assert_eq!(app.screen(), Screen::TaskDetail);
+    });
+}
+
+/// Test that c opens a due-date calendar of the loaded tasks, overdue ones
+/// counted on today, and Enter narrows the task list to the selected day
+#[test]
+fn test_calendar_counts_due_tasks_and_filters_list_by_day() {
+    use chrono::{Days, Local, NaiveTime};
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let key = |code| InputEvent::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let today = Local::now().date_naive();
+    let noon = |day: chrono::NaiveDate| {
+        day.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+            .and_local_timezone(Local)
+            .unwrap()
+            .timestamp_millis()
+    };
+    let task = |id: &str, due: Option<chrono::NaiveDate>| {
+        let mut task = fixtures::test_task();
+        task.id = id.to_string();
+        task.name = id.to_string();
+        task.due_date = due.map(noon);
+        task
+    };
+
+    let mut app = TuiApp::with_client(Arc::new(MockClickUpClient::new())).unwrap();
+    app.set_screen(Screen::Tasks);
+    app.tasks_mut_for_test().extend([
+        task("due-today", Some(today)),
+        task("due-tomorrow", today.checked_add_days(Days::new(1))),
+        task("overdue", today.checked_sub_days(Days::new(3))),
+        task("no-due-date", None),
+    ]);
+    app.rebuild_task_list_for_test();
+
+    app.update(key(KeyCode::Char('c')));
+    assert_eq!(app.screen(), Screen::Calendar);
+    let snapshot = app.debug_snapshot();
+    let today_line = format!("> {}: 1 due · 1 overdue (today)", today.format("%a %-d"));
+    assert!(snapshot.lines().any(|l| l == today_line), "{}", snapshot);
+
+    // Tomorrow's tasks only, until Esc
+    app.update(key(KeyCode::Char('l')));
+    app.update(key(KeyCode::Enter));
+    assert_eq!(app.screen(), Screen::Tasks);
+    assert!(app.status_message().contains("1 task(s)"), "{}", app.status_message());
+    let snapshot = app.debug_snapshot();
+    assert!(snapshot.lines().any(|l| l == "items: 1"), "{}", snapshot);
+    assert!(snapshot.contains("due-tomorrow"), "{}", snapshot);
+
+    app.update(key(KeyCode::Esc));
+    assert_eq!(app.screen(), Screen::Tasks);
+    let snapshot = app.debug_snapshot();
+    assert!(snapshot.lines().any(|l| l == "items: 4"), "{}", snapshot);
+}
+
+/// Test that a list's tasks show as they arrive, and the selection made
+/// meanwhile survives the load completing
+#[test]
+fn test_tasks_show_as_they_stream_in() {
+    use clickdown::api::mock_client::MockClickUpClient;
+    use clickdown::tui::app::Screen;
+    use clickdown::tui::input::InputEvent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::sync::Arc;
+
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let tasks: Vec<_> = (0..60)
+            .map(|i| {
+                let mut task = fixtures::test_task();
+                task.id = format!("task-{}", i);
+                task.name = format!("Task {}", i);
+                task
+            })
+            .collect();
+        let mock = Arc::new(
+            MockClickUpClient::new()
+                .with_workspaces(vec![fixtures::test_workspace()])
+                .with_spaces(vec![fixtures::test_space()])
+                .with_lists_in_space(vec![fixtures::test_list()])
+                .with_tasks(tasks),
+        );
+        let mut app = TuiApp::with_client(mock).unwrap();
+        open_first_space(&mut app).await;
+        app.navigate_into();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+
+        // The message queue holds fewer than all of them
+        assert_eq!(app.screen(), Screen::Tasks);
+        assert!((1..60).contains(&app.task_count()), "{}", app.task_count());
+        assert!(app.status_message().starts_with("Loading tasks..."), "{}", app.status_message());
+
+        app.update(InputEvent::Key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)));
+        let selected = app.task_list().selected_task().map(|t| t.id.clone());
+        assert!(selected.is_some());
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        app.process_async_messages();
+        assert_eq!(app.task_count(), 60);
+        assert_eq!(app.status_message(), "Loaded 60 task(s)");
+        assert_eq!(app.task_list().selected_task().map(|t| t.id.clone()), selected);
+    });
+}